@@ -0,0 +1,191 @@
+//! Auth group - One bot deploying through several managed miners
+//!
+//! A bot with `auth_ids` set owns one managed miner per auth_id. Each round
+//! it either deploys the whole round through the next auth_id in turn
+//! (`round_robin`) or splits the round's bets evenly and sends one deploy per
+//! auth_id (`split`). The group's miners are shown and tracked as one
+//! aggregate miner, so round accounting works the same as for a single auth.
+
+use serde::{Deserialize, Serialize};
+
+use evore::ore_api::Miner;
+
+/// How a multi-auth bot spreads a round over its auth_ids
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthSplit {
+    /// The whole round through one auth_id, the next one each round
+    #[default]
+    RoundRobin,
+    /// The round's bets split evenly, one deploy per auth_id
+    Split,
+}
+
+/// One deploy of a round: the auth_id it goes through and, for a split,
+/// the share of the bets it places (None = the bot's normal deploy)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeployLeg {
+    pub auth_id: u64,
+    pub manual: Option<[u64; 25]>,
+}
+
+/// The round's deploys
+///
+/// `manual` is the bot's manual amounts (if any) and `bets` the round's
+/// expected bets; a split needs the bets and returns None without them.
+/// Shares that come out empty are dropped.
+pub fn round_legs(
+    auth_ids: &[u64],
+    split: AuthSplit,
+    round_id: u64,
+    manual: Option<[u64; 25]>,
+    bets: Option<[u64; 25]>,
+) -> Option<Vec<DeployLeg>> {
+    if auth_ids.len() <= 1 || split == AuthSplit::RoundRobin {
+        let auth_id = auth_ids[(round_id % auth_ids.len() as u64) as usize];
+        return Some(vec![DeployLeg { auth_id, manual }]);
+    }
+    let shares = split_bets(&bets?, auth_ids.len());
+    Some(auth_ids.iter()
+        .zip(shares)
+        .filter(|(_, share)| share.iter().any(|&b| b > 0))
+        .map(|(&auth_id, share)| DeployLeg { auth_id, manual: Some(share) })
+        .collect())
+}
+
+/// Split each square's bet into `n` shares, the remainder going to the first shares
+pub fn split_bets(bets: &[u64; 25], n: usize) -> Vec<[u64; 25]> {
+    let n = n.max(1);
+    let mut shares = vec![[0u64; 25]; n];
+    for (square, &bet) in bets.iter().enumerate() {
+        let (base, rest) = (bet / n as u64, (bet % n as u64) as usize);
+        for (i, share) in shares.iter_mut().enumerate() {
+            share[square] = base + (i < rest) as u64;
+        }
+    }
+    shares
+}
+
+/// The group's miners as one miner
+///
+/// Rewards add up; `deployed` sums the miners that played the latest round.
+/// `checkpoint_id` is behind `round_id` only while some miner still needs a
+/// checkpoint. None when no miner exists yet.
+pub fn aggregate_miners(miners: &[Option<Miner>]) -> Option<Miner> {
+    let existing: Vec<&Miner> = miners.iter().flatten().collect();
+    let latest = existing.iter().max_by_key(|m| m.round_id)?;
+    let mut group = **latest;
+    if existing.len() == 1 {
+        return Some(group);
+    }
+
+    group.deployed = [0; 25];
+    group.checkpoint_fee = 0;
+    group.rewards_sol = 0;
+    group.rewards_ore = 0;
+    group.refined_ore = 0;
+    group.lifetime_rewards_sol = 0;
+    group.lifetime_rewards_ore = 0;
+    group.lifetime_deployed = 0;
+    for miner in &existing {
+        if miner.round_id == group.round_id {
+            for (total, amount) in group.deployed.iter_mut().zip(miner.deployed) {
+                *total = total.saturating_add(amount);
+            }
+        }
+        group.checkpoint_fee = group.checkpoint_fee.saturating_add(miner.checkpoint_fee);
+        group.rewards_sol = group.rewards_sol.saturating_add(miner.rewards_sol);
+        group.rewards_ore = group.rewards_ore.saturating_add(miner.rewards_ore);
+        group.refined_ore = group.refined_ore.saturating_add(miner.refined_ore);
+        group.lifetime_rewards_sol = group.lifetime_rewards_sol.saturating_add(miner.lifetime_rewards_sol);
+        group.lifetime_rewards_ore = group.lifetime_rewards_ore.saturating_add(miner.lifetime_rewards_ore);
+        group.lifetime_deployed = group.lifetime_deployed.saturating_add(miner.lifetime_deployed);
+    }
+    group.checkpoint_id = existing.iter()
+        .filter(|m| m.round_id > m.checkpoint_id)
+        .map(|m| m.checkpoint_id)
+        .min()
+        .unwrap_or(group.round_id);
+    Some(group)
+}
+
+/// (auth_id, round_id) checkpoints the group needs: each miner with a round
+/// pending, or every auth_id with `last_round` when the miners couldn't be read
+pub fn checkpoint_targets(auth_ids: &[u64], miners: Option<&[Option<Miner>]>, last_round: u64) -> Vec<(u64, u64)> {
+    match miners {
+        None => auth_ids.iter().map(|&auth_id| (auth_id, last_round)).collect(),
+        Some(miners) => auth_ids.iter()
+            .zip(miners)
+            .filter_map(|(&auth_id, miner)| miner.filter(|m| m.round_id > m.checkpoint_id).map(|m| (auth_id, m.round_id)))
+            .collect(),
+    }
+}
+
+/// "3" for one auth_id, "3,4,5" for a group
+pub fn auth_ids_label(auth_ids: &[u64]) -> String {
+    auth_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use steel::Zeroable;
+
+    fn miner(round_id: u64, checkpoint_id: u64, deployed: u64, rewards_sol: u64) -> Option<Miner> {
+        let mut miner = Miner::zeroed();
+        miner.round_id = round_id;
+        miner.checkpoint_id = checkpoint_id;
+        miner.deployed[0] = deployed;
+        miner.rewards_sol = rewards_sol;
+        Some(miner)
+    }
+
+    #[test]
+    fn test_round_legs() {
+        let manual = Some([1; 25]);
+        let rr = round_legs(&[4, 5, 6], AuthSplit::RoundRobin, 10, manual, None).unwrap();
+        assert_eq!(rr, vec![DeployLeg { auth_id: 5, manual }]);
+        assert_eq!(round_legs(&[7], AuthSplit::Split, 10, None, None).unwrap()[0].auth_id, 7);
+
+        // A split needs the round's bets
+        assert!(round_legs(&[4, 5], AuthSplit::Split, 10, None, None).is_none());
+
+        let mut bets = [0; 25];
+        bets[0] = 7;
+        bets[3] = 1;
+        let legs = round_legs(&[4, 5, 6], AuthSplit::Split, 10, None, Some(bets)).unwrap();
+        let shares: Vec<_> = legs.iter().map(|l| (l.auth_id, l.manual.unwrap()[0], l.manual.unwrap()[3])).collect();
+        assert_eq!(shares, vec![(4, 3, 1), (5, 2, 0), (6, 2, 0)]);
+
+        // Empty shares are dropped
+        let mut dust = [0; 25];
+        dust[2] = 1;
+        assert_eq!(round_legs(&[4, 5], AuthSplit::Split, 10, None, Some(dust)).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_aggregate_miners() {
+        assert!(aggregate_miners(&[None, None]).is_none());
+        let single = aggregate_miners(&[None, miner(9, 8, 50, 3)]).unwrap();
+        assert_eq!((single.round_id, single.checkpoint_id, single.deployed[0]), (9, 8, 50));
+
+        // Both played round 9: deploys and rewards add up, 9 still needs a checkpoint
+        let group = aggregate_miners(&[miner(9, 8, 50, 3), miner(9, 8, 25, 4)]).unwrap();
+        assert_eq!((group.round_id, group.checkpoint_id, group.deployed[0], group.rewards_sol), (9, 8, 75, 7));
+
+        // Round-robin: only the latest round's deploy counts, and nothing is pending
+        let group = aggregate_miners(&[miner(8, 8, 50, 3), miner(9, 9, 25, 4)]).unwrap();
+        assert_eq!((group.round_id, group.checkpoint_id, group.deployed[0]), (9, 9, 25));
+
+        // An older round left un-checkpointed keeps the group pending
+        let group = aggregate_miners(&[miner(8, 7, 50, 3), miner(9, 9, 25, 4)]).unwrap();
+        assert_eq!(group.checkpoint_id, 7);
+        assert!(group.round_id > group.checkpoint_id);
+
+        assert_eq!(auth_ids_label(&[3, 4, 5]), "3,4,5");
+
+        let miners = [miner(8, 7, 50, 3), miner(9, 9, 25, 4), None];
+        assert_eq!(checkpoint_targets(&[1, 2, 3], Some(&miners), 9), vec![(1, 8)]);
+        assert_eq!(checkpoint_targets(&[1, 2], None, 9), vec![(1, 9), (2, 9)]);
+    }
+}
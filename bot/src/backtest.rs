@@ -0,0 +1,283 @@
+//! Backtest - Replay stored rounds against bot strategies
+//!
+//! Reads finished rounds and their deployments from the ore-stats ClickHouse
+//! database, rebuilds each round's board as it stood when a bot would have
+//! deployed (`slots_left` before the end), runs the bot's strategy on it
+//! through the same functions the live bot uses, and scores the bets against
+//! the actual winning square with `ev_calculator::round_return`.
+//!
+//! Returns are SOL only (no ORE rewards), and the simulated bets don't change
+//! what anyone else deployed afterwards.
+
+use clickhouse::{Client, Row};
+use serde::Deserialize;
+use steel::Zeroable;
+
+use evore::ore_api::Round;
+
+use crate::bot_runner::{bounded_ev_amounts, lead_square_amounts, manual_amounts, preview_bets};
+use crate::config::BotConfig;
+use crate::ev_calculator::round_return;
+
+/// A finished round as stored in `rounds`
+#[derive(Debug, Clone, Row, Deserialize)]
+pub struct HistoricalRound {
+    pub round_id: u64,
+    pub end_slot: u64,
+    pub winning_square: u8,
+}
+
+/// Amount deployed to one square of a round in one slot
+#[derive(Debug, Clone, Row, Deserialize)]
+pub struct SlotDeployment {
+    pub round_id: u64,
+    pub square_id: u8,
+    /// 0 if the slot is unknown
+    pub deployed_slot: u64,
+    pub amount: u64,
+}
+
+/// A round with every deployment made into it
+#[derive(Debug, Clone)]
+pub struct RoundHistory {
+    pub round: HistoricalRound,
+    pub deployments: Vec<SlotDeployment>,
+}
+
+impl RoundHistory {
+    /// Board before `cutoff_slot`; deployments with an unknown slot count as early
+    pub fn board_at(&self, cutoff_slot: u64) -> [u64; 25] {
+        let mut board = [0u64; 25];
+        for d in &self.deployments {
+            if d.deployed_slot < cutoff_slot {
+                if let Some(square) = board.get_mut(d.square_id as usize) {
+                    *square = square.saturating_add(d.amount);
+                }
+            }
+        }
+        board
+    }
+
+    /// Board once the round ended
+    pub fn final_board(&self) -> [u64; 25] {
+        self.board_at(u64::MAX)
+    }
+}
+
+/// Reads round history from the ore-stats ClickHouse database
+pub struct HistoryReader {
+    client: Client,
+}
+
+impl HistoryReader {
+    pub fn new(url: &str, database: &str, user: &str, password: &str) -> Self {
+        let client = Client::default()
+            .with_url(url)
+            .with_database(database)
+            .with_user(user)
+            .with_password(password);
+        Self { client }
+    }
+
+    /// Reader for `url` with CLICKHOUSE_DB, CLICKHOUSE_USER and CLICKHOUSE_PASSWORD
+    /// taken from the environment (same variables as ore-stats)
+    pub fn from_env(url: &str) -> Self {
+        let var = |name: &str, default: &str| std::env::var(name).unwrap_or_else(|_| default.to_string());
+        Self::new(
+            url,
+            &var("CLICKHOUSE_DB", "ore_stats"),
+            &var("CLICKHOUSE_USER", "default"),
+            &var("CLICKHOUSE_PASSWORD", ""),
+        )
+    }
+
+    /// Rounds `from..=to` with their deployments, ordered by round id
+    /// Rounds without a valid winning square are left out
+    pub async fn load(&self, from: u64, to: u64) -> Result<Vec<RoundHistory>, clickhouse::error::Error> {
+        let rounds = self.client
+            .query("SELECT round_id, end_slot, winning_square FROM rounds FINAL WHERE round_id >= ? AND round_id <= ? ORDER BY round_id")
+            .bind(from)
+            .bind(to)
+            .fetch_all::<HistoricalRound>()
+            .await?;
+
+        let deployments = self.client
+            .query(
+                "SELECT round_id, square_id, deployed_slot, sum(amount) AS amount FROM deployments \
+                 WHERE round_id >= ? AND round_id <= ? \
+                 GROUP BY round_id, square_id, deployed_slot ORDER BY round_id",
+            )
+            .bind(from)
+            .bind(to)
+            .fetch_all::<SlotDeployment>()
+            .await?;
+
+        Ok(group_history(rounds, deployments))
+    }
+}
+
+/// Attach each round's deployments; both inputs are ordered by round id
+fn group_history(rounds: Vec<HistoricalRound>, deployments: Vec<SlotDeployment>) -> Vec<RoundHistory> {
+    let mut deployments = deployments.into_iter().peekable();
+    let mut history = Vec::with_capacity(rounds.len());
+    for round in rounds {
+        while deployments.next_if(|d| d.round_id < round.round_id).is_some() {}
+        let mut round_deployments = Vec::new();
+        while let Some(d) = deployments.next_if(|d| d.round_id == round.round_id) {
+            round_deployments.push(d);
+        }
+        if (round.winning_square as usize) < 25 {
+            history.push(RoundHistory { round, deployments: round_deployments });
+        }
+    }
+    history
+}
+
+/// Simulated result of one bot over a range of rounds
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BacktestResult {
+    /// Rounds replayed
+    pub rounds: u32,
+    /// Rounds the strategy placed bets in
+    pub rounds_bet: u32,
+    /// Rounds with a positive return
+    pub wins: u32,
+    /// Total lamports bet
+    pub deployed: u64,
+    /// Net SOL return (lamports, signed)
+    pub pnl: i64,
+    /// Largest peak-to-trough drop of the running PnL (lamports)
+    pub max_drawdown: u64,
+}
+
+/// Bets `bot` would have placed in `history`, against the board `slots_left` before the end
+pub fn replay_bets(bot: &BotConfig, history: &RoundHistory) -> [u64; 25] {
+    let mut round = Round::zeroed();
+    round.id = history.round.round_id;
+    round.deployed = history.board_at(history.round.end_slot.saturating_sub(bot.slots_left));
+    round.total_deployed = round.deployed.iter().sum();
+
+    let manual = manual_amounts(bot.strategy, &bot.strategy_params, bot.rotate_squares, round.id)
+        .or_else(|| bounded_ev_amounts(bot.strategy, &bot.strategy_params, bot.bankroll, bot.slots_left,
+            bot.min_squares, bot.max_squares, &bot.square_blacklist, &round))
+        .or_else(|| lead_square_amounts(bot.strategy, &bot.strategy_params, bot.bankroll, &round));
+    preview_bets(bot.strategy, &bot.strategy_params, manual.as_ref(), bot.bankroll, bot.slots_left, &round)
+}
+
+/// Replay `bot` over every round in `history`
+pub fn backtest_bot(bot: &BotConfig, history: &[RoundHistory]) -> BacktestResult {
+    let mut result = BacktestResult::default();
+    let mut peak = 0i64;
+    for round in history {
+        result.rounds += 1;
+        let bets = replay_bets(bot, round);
+        let total: u64 = bets.iter().sum();
+        if total == 0 {
+            continue;
+        }
+
+        let ret = round_return(&bets, &round.final_board(), round.round.winning_square as usize);
+        result.rounds_bet += 1;
+        result.deployed = result.deployed.saturating_add(total);
+        result.pnl = result.pnl.saturating_add(ret);
+        if ret > 0 {
+            result.wins += 1;
+        }
+        peak = peak.max(result.pnl);
+        result.max_drawdown = result.max_drawdown.max(peak.saturating_sub(result.pnl) as u64);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{DeployStrategy, StrategyParams};
+
+    fn deployment(round_id: u64, square_id: u8, deployed_slot: u64, amount: u64) -> SlotDeployment {
+        SlotDeployment { round_id, square_id, deployed_slot, amount }
+    }
+
+    fn history(round_id: u64, winning_square: u8, deployments: Vec<SlotDeployment>) -> RoundHistory {
+        RoundHistory {
+            round: HistoricalRound { round_id, end_slot: 100, winning_square },
+            deployments,
+        }
+    }
+
+    fn manual_bot(amounts: [u64; 25], slots_left: u64) -> BotConfig {
+        let mut bot = BotConfig::new_ev("manual", 1, 0, 0, 0, 0);
+        bot.strategy = DeployStrategy::Manual;
+        bot.strategy_params = StrategyParams::Manual { amounts };
+        bot.slots_left = slots_left;
+        bot
+    }
+
+    #[test]
+    fn test_board_at_cutoff() {
+        let round = history(1, 0, vec![
+            deployment(1, 0, 0, 5),   // unknown slot counts as early
+            deployment(1, 0, 90, 10),
+            deployment(1, 1, 98, 20), // at the cutoff, so after it
+            deployment(1, 24, 99, 30),
+        ]);
+
+        let board = round.board_at(98);
+        assert_eq!(board[0], 15);
+        assert_eq!(board[1], 0);
+        assert_eq!(board[24], 0);
+
+        let board = round.final_board();
+        assert_eq!(board[1], 20);
+        assert_eq!(board[24], 30);
+    }
+
+    #[test]
+    fn test_group_history_skips_invalid_rounds() {
+        let rounds = vec![
+            HistoricalRound { round_id: 2, end_slot: 100, winning_square: 3 },
+            HistoricalRound { round_id: 3, end_slot: 100, winning_square: 255 },
+            HistoricalRound { round_id: 4, end_slot: 100, winning_square: 0 },
+        ];
+        let deployments = vec![
+            deployment(1, 0, 10, 1),
+            deployment(2, 0, 10, 2),
+            deployment(2, 1, 10, 3),
+            deployment(3, 0, 10, 4),
+            deployment(4, 0, 10, 5),
+        ];
+
+        let history = group_history(rounds, deployments);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].round.round_id, 2);
+        assert_eq!(history[0].deployments.len(), 2);
+        assert_eq!(history[1].round.round_id, 4);
+        assert_eq!(history[1].deployments[0].amount, 5);
+    }
+
+    #[test]
+    fn test_backtest_scores_against_final_board() {
+        let mut amounts = [0u64; 25];
+        amounts[0] = 1_000_000;
+        let bot = manual_bot(amounts, 2);
+
+        let rounds = vec![
+            // Win on square 0
+            history(1, 0, vec![deployment(1, 0, 50, 10_000_000), deployment(1, 1, 99, 40_000_000)]),
+            // Lose to square 1
+            history(2, 1, vec![deployment(2, 1, 50, 10_000_000)]),
+        ];
+
+        let result = backtest_bot(&bot, &rounds);
+        let win = round_return(&amounts, &rounds[0].final_board(), 0);
+        let loss = round_return(&amounts, &rounds[1].final_board(), 1);
+        assert!(win > 0);
+        assert_eq!(loss, -1_000_000);
+        assert_eq!(result.rounds, 2);
+        assert_eq!(result.rounds_bet, 2);
+        assert_eq!(result.wins, 1);
+        assert_eq!(result.deployed, 2_000_000);
+        assert_eq!(result.pnl, win + loss);
+        assert_eq!(result.max_drawdown, 1_000_000);
+    }
+}
@@ -2,19 +2,26 @@
 //!
 //! Features:
 //! - Polls every 1 second for fresh blockhash
+//! - Keeps the last few distinct blockhashes so deploys can be signed against
+//!   more than one (a processed blockhash can belong to a skipped fork)
 //! - Uses shared RPS tracker for unified request monitoring
 //! - Shared via Arc for all bots
 
 use solana_sdk::hash::Hash;
+use std::collections::VecDeque;
 use std::sync::Arc;
 use std::sync::RwLock;
 use std::time::{Duration, Instant};
 
 use crate::client::{EvoreClient, RpsTracker};
 
+/// Number of recent distinct blockhashes kept
+pub const MAX_RECENT_BLOCKHASHES: usize = 3;
+
 /// Cache for latest blockhash
 pub struct BlockhashCache {
-    blockhash: Arc<RwLock<Hash>>,
+    /// Newest first, distinct, at most MAX_RECENT_BLOCKHASHES
+    recent: Arc<RwLock<VecDeque<Hash>>>,
     last_update: Arc<RwLock<Instant>>,
     rpc_url: String,
     rps_tracker: Arc<RpsTracker>,
@@ -24,7 +31,7 @@ impl BlockhashCache {
     pub fn new(rpc_url: &str, rps_tracker: Arc<RpsTracker>) -> Self {
         Self {
             rpc_url: rpc_url.to_string(),
-            blockhash: Arc::new(RwLock::new(Hash::default())),
+            recent: Arc::new(RwLock::new(VecDeque::with_capacity(MAX_RECENT_BLOCKHASHES))),
             last_update: Arc::new(RwLock::new(Instant::now())),
             rps_tracker,
         }
@@ -32,13 +39,19 @@ impl BlockhashCache {
 
     /// Get latest cached blockhash
     pub fn get_blockhash(&self) -> Hash {
-        *self.blockhash.read().unwrap()
+        self.recent.read().unwrap().front().copied().unwrap_or_default()
+    }
+
+    /// Up to `count` most recent distinct blockhashes, newest first
+    /// (empty until the first successful fetch)
+    pub fn get_recent_blockhashes(&self, count: usize) -> Vec<Hash> {
+        self.recent.read().unwrap().iter().take(count).copied().collect()
     }
 
     /// Start background polling thread
     /// Quietly handles RPC errors and continues polling
     pub fn start_polling(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let blockhash = Arc::clone(&self.blockhash);
+        let recent = Arc::clone(&self.recent);
         let last_update = Arc::clone(&self.last_update);
         let rpc_url = self.rpc_url.clone();
         let rps_tracker = Arc::clone(&self.rps_tracker);
@@ -50,10 +63,7 @@ impl BlockhashCache {
             loop {
                 // Fetch blockhash - silently ignore errors (will retry on next poll)
                 if let Ok(hash) = client.get_latest_blockhash() {
-                    {
-                        let mut bh = blockhash.write().unwrap();
-                        *bh = hash;
-                    }
+                    push_recent(&mut recent.write().unwrap(), hash);
                     {
                         let mut t = last_update.write().unwrap();
                         *t = Instant::now();
@@ -69,6 +79,15 @@ impl BlockhashCache {
     }
 }
 
+/// Record `hash` as the newest blockhash, ignoring repeats of the current one
+fn push_recent(recent: &mut VecDeque<Hash>, hash: Hash) {
+    if recent.front() == Some(&hash) {
+        return;
+    }
+    recent.push_front(hash);
+    recent.truncate(MAX_RECENT_BLOCKHASHES);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -79,4 +98,18 @@ mod tests {
         let cache = BlockhashCache::new("https://example.com", tracker);
         assert_eq!(cache.get_blockhash(), Hash::default());
     }
+
+    #[test]
+    fn test_push_recent_keeps_newest_distinct() {
+        let hashes: Vec<Hash> = (0..5).map(|_| Hash::new_unique()).collect();
+        let mut recent = VecDeque::new();
+        push_recent(&mut recent, hashes[0]);
+        push_recent(&mut recent, hashes[0]);
+        assert_eq!(recent, [hashes[0]]);
+
+        for hash in &hashes[1..] {
+            push_recent(&mut recent, *hash);
+        }
+        assert_eq!(recent, [hashes[4], hashes[3], hashes[2]]);
+    }
 }
@@ -7,8 +7,9 @@
 //! - tx_pipeline for sending transactions
 //! - BotState for state machine
 
+use std::path::Path;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use solana_sdk::{
     hash::Hash,
@@ -19,18 +20,28 @@ use solana_sdk::{
 use tokio::sync::{mpsc, RwLock};
 use tokio::time::sleep;
 
-use crate::blockhash_cache::BlockhashCache;
+use crate::auth_group::{aggregate_miners, checkpoint_targets, round_legs, AuthSplit, DeployLeg};
+use crate::blockhash_cache::{BlockhashCache, MAX_RECENT_BLOCKHASHES};
 use crate::board_tracker::BoardTracker;
 use crate::bot_state::{BotPhase, BotState};
 use crate::client::{EvoreClient, RpsTracker};
-use crate::config::StrategyParams;
+use crate::confirmation_tracker::ConfirmationTracker;
+use crate::config::{in_active_hours, HourWindow, PriorityFeeSource, RoundFilter, StrategyParams};
 use crate::config::DeployStrategy;
-use crate::deploy::{build_checkpoint_tx, build_claim_sol_tx, build_ev_deploy_tx, build_percentage_deploy_tx, EvDeployParams, PercentageDeployParams};
+use crate::deploy_audit::{bets_mask, deploy_shortfall, now_ms, AuditResult, DeployAuditLog, DeployAuditRecord, EvSnapshotRecord};
+use crate::deploy_export::{DeployExportConfig, DeployExporter, Landing, RoundOutcome};
+use evore::ore_api::{Miner, Round};
+use evore::processor::process_mm_deploy::{calculate_ev_deployments, calculate_percentage_deployments};
+use crate::deploy::{build_checkpoint_tx, build_claim_sol_tx, build_ev_deploy_tx, build_manual_deploy_tx, build_percentage_deploy_tx, EvDeployParams, PercentageDeployParams};
+use crate::ev_calculator::{amount_to_lead, clamp_square_count, exclude_squares, percentage_bets, rotate_squares};
+use crate::fee_estimate::{effective_priority_fee, expected_stake, scale_stake};
+use crate::priority_fee_cache::PriorityFeeCache;
 use crate::round_tracker::RoundTracker;
 use crate::sender::PingStats;
-use crate::slot_tracker::SlotTracker;
+use crate::slot_tracker::{last_slot_decision, LastSlotDecision, SlotTracker};
+use crate::slots_left_tuner::SlotsLeftTuner;
 use crate::tui::{BotStatus, TuiUpdate, TxType, TxStatus};
-use crate::tx_pipeline::{create_tx_pipeline, TxRequest};
+use crate::tx_pipeline::{check_deploy_cap, check_dust_floor, check_tx_size, classify_redundant_per_deploy, create_tx_pipeline, PresignedDeploy, RedundantOutcome, SigStatus, TxRequest, TxSizeEstimate};
 
 /// Shared services for all bots
 pub struct SharedServices {
@@ -42,11 +53,24 @@ pub struct SharedServices {
     pub fast_sender: Arc<crate::sender::FastSender>,
     pub ping_stats: Arc<PingStats>,
     pub client: Arc<EvoreClient>,
+    /// Recent prioritization fees for bots with a dynamic fee source
+    pub priority_fees: Arc<PriorityFeeCache>,
+    /// Deploy attempt log (None = disabled)
+    pub audit_log: Option<Arc<DeployAuditLog>>,
+    /// Deploy outcome sink (None = disabled)
+    pub deploy_export: Option<Arc<DeployExporter>>,
+    /// Recently sent deploy signatures for the dashboard's confirmation panel
+    pub confirmations: Arc<ConfirmationTracker>,
 }
 
 impl SharedServices {
     /// Create and start all shared services
-    pub fn new(rpc_url: &str, ws_url: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+    pub fn new(
+        rpc_url: &str,
+        ws_url: &str,
+        audit_log_path: Option<&Path>,
+        deploy_export: Option<&DeployExportConfig>,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
         // Create main client with shared RPS tracker
         let client = Arc::new(EvoreClient::new(rpc_url));
         let rps_tracker = client.get_rps_tracker();
@@ -60,6 +84,15 @@ impl SharedServices {
         // Create tx pipeline with shared RPS tracker
         let (tx_channel, fast_sender, ping_stats) = create_tx_pipeline(Arc::clone(&rps_tracker), rpc_url);
         
+        let audit_log = audit_log_path
+            .map(|path| {
+                DeployAuditLog::open(path)
+                    .map(Arc::new)
+                    .map_err(|e| format!("Deploy audit log {}: {}", path.display(), e))
+            })
+            .transpose()?;
+        let deploy_export = deploy_export.map(|config| Arc::new(DeployExporter::start(config)));
+        
         Ok(Self {
             slot_tracker,
             board_tracker,
@@ -69,11 +102,19 @@ impl SharedServices {
             fast_sender,
             ping_stats,
             client,
+            priority_fees: Arc::new(PriorityFeeCache::new()),
+            audit_log,
+            deploy_export,
+            confirmations: Arc::new(ConfirmationTracker::new()),
         })
     }
 
     /// Start all background subscriptions
     pub fn start(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        // Fail now rather than on every deploy if ORE moved an account we hardcode
+        self.client
+            .check_static_accounts()
+            .map_err(|e| format!("Static account check: {}", e))?;
         self.slot_tracker
             .start_slot_subscription()
             .map_err(|e| format!("Slot subscription: {}", e))?;
@@ -85,21 +126,50 @@ impl SharedServices {
     }
 }
 
+/// How many slots before its trigger a presign bot builds and signs its deploy
+const PRESIGN_LEAD_SLOTS: u64 = 2;
+
+/// How often to re-check the signer balance while the guard is active
+const SIGNER_BALANCE_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
 /// Runtime configuration for a bot instance
 pub struct BotRunConfig {
     pub name: String,
     pub bot_index: usize,
-    pub auth_id: u64,
+    pub auth_ids: Vec<u64>, // Auth group deployed through (just auth_id unless auth_ids is set)
+    pub auth_split: AuthSplit, // Round-robin or split the round over auth_ids
     pub manager: Pubkey,
     pub signer: Arc<Keypair>,
     pub slots_left: u64,
+    pub auto_slots_left: bool, // Learn slots_left from round lengths and landing latency
+    pub auto_slots_left_target: u64, // auto_slots_left: land this many slots before the last deployable one
     pub strategy: DeployStrategy,
     pub strategy_params: StrategyParams,
     pub bankroll: u64,
     pub attempts: u64,   // Number of deploy txs to send (default 4)
+    pub retry_delay_ms: u64, // Delay between deploy attempts
+    pub blockhashes: u64, // Recent blockhashes each attempt is signed against (default 2)
     pub priority_fee: u64,  // Priority fee in micro-lamports per CU
+    pub priority_fee_source: PriorityFeeSource, // Fixed priority_fee or dynamic from recent fees
     pub jito_tip: u64,   // Jito tip in lamports (default 200_000 = 0.0002 SOL)
     pub is_paused: bool, // Whether bot is paused
+    pub min_signer_balance: u64, // Hold deploys below this signer balance (0 = disabled)
+    pub preview_ms: u64, // How long to preview bets before submitting (0 = no preview)
+    pub deploy_vetoed: bool, // Set by the coordinator when the pending preview is vetoed
+    pub fleet_scale_bps: u64, // Set by the coordinator each round: stake scale for fleet_max_per_round (10_000 = unscaled)
+    pub max_consecutive_losses: u64, // Auto-pause after N losing rounds in a row (0 = disabled)
+    pub rotate_squares: bool, // Shift manual squares by a per-round offset
+    pub min_squares: u64, // EV: spread bets over at least this many squares (0 = no floor)
+    pub max_squares: u64, // EV: concentrate bets on at most this many squares (0 = no ceiling)
+    pub square_blacklist: Vec<u8>, // EV: squares never deployed to (bets move to the next-best allowed)
+    pub require_last_slot: bool, // Only send in the last hard_last_slots slots, else skip the round
+    pub hard_last_slots: u64, // Deploy window for require_last_slot
+    pub presign: bool, // Sign the deploy PRESIGN_LEAD_SLOTS early and submit the bytes at the trigger
+    pub spam_mode: bool, // Allow more than one deploy per round (disables the dedup guard)
+    pub absolute_max_deploy: u64, // Hard ceiling on a round's deploy, checked before signing
+    pub dust_floor: u64, // Skip a round whose expected deploy total is below this
+    pub round_filter: RoundFilter, // Only deploy in rounds whose round_id matches
+    pub active_hours: Vec<HourWindow>, // Only deploy during these UTC windows (empty = always)
 }
 
 /// Run a single bot using shared services
@@ -111,12 +181,12 @@ pub async fn run_bot_with_services(
     let mut state = BotState::new();
     
     // Extract static values that don't change at runtime
-    let (signer, manager, auth_id, bot_index, bot_name, initial_paused) = {
+    let (signer, manager, auth_ids, bot_index, bot_name, initial_paused) = {
         let cfg = config.read().await;
         (
             Arc::clone(&cfg.signer),
             cfg.manager,
-            cfg.auth_id,
+            cfg.auth_ids.clone(),
             cfg.bot_index,
             cfg.name.clone(),
             cfg.is_paused,
@@ -136,7 +206,10 @@ pub async fn run_bot_with_services(
         });
     }
     
-    let (managed_miner_auth, _) = evore::state::managed_miner_auth_pda(manager, auth_id);
+    // One managed_miner_auth per auth_id, read together as one miner
+    let authorities: Vec<Pubkey> = auth_ids.iter()
+        .map(|auth_id| evore::state::managed_miner_auth_pda(manager, *auth_id).0)
+        .collect();
     
     // Get initial signer balance (even if paused, show balance)
     if let Ok(balance) = services.client.get_balance(&signer.pubkey()) {
@@ -161,7 +234,7 @@ pub async fn run_bot_with_services(
     }
 
     // Initialize miner state (load once even if paused for display)
-    if let Ok(Some(miner)) = services.client.get_miner(&managed_miner_auth) {
+    if let Some(miner) = group_miner(&services.client, &authorities) {
         state.init_starting_values(miner.rewards_sol, miner.rewards_ore);
         
         let _ = tui_tx.send(TuiUpdate::BotStatsUpdate {
@@ -203,6 +276,12 @@ pub async fn run_bot_with_services(
         }
     }
 
+    let mut last_balance_check: Option<Instant> = None;
+    
+    // auto_slots_left: learned threshold and the last value shown in the TUI
+    let mut slots_left_tuner = SlotsLeftTuner::new();
+    let mut shown_slots_left: Option<u64> = None;
+    
     // Main loop
     loop {
         // Check for pause state changes from coordinator
@@ -245,7 +324,7 @@ pub async fn run_bot_with_services(
         // Handle loading state (reload data after unpause)
         if state.take_needs_reload() {
             // Reload miner data
-            if let Ok(Some(miner)) = services.client.get_miner(&managed_miner_auth) {
+            if let Some(miner) = group_miner(&services.client, &authorities) {
                 let _ = tui_tx.send(TuiUpdate::BotMinerUpdate {
                     bot_index,
                     miner: miner.clone(),
@@ -294,7 +373,7 @@ pub async fn run_bot_with_services(
             
             // At start of new round, check if previous round needs checkpointing
             // This handles cases where deploy failed but checkpoint is still needed
-            if let Ok(Some(miner)) = services.client.get_miner(&managed_miner_auth) {
+            if let Some(miner) = group_miner(&services.client, &authorities) {
                 if miner.round_id > miner.checkpoint_id {
                     // Miner deployed to a round that wasn't checkpointed yet
                     state.last_deployed_round = Some(miner.round_id);
@@ -320,13 +399,62 @@ pub async fn run_bot_with_services(
         let _ = tui_tx.send(TuiUpdate::SlotUpdate { slot: current_slot, blockhash });
 
         // Read dynamic config values (can be updated via config reload)
-        let (slots_left, strategy, strategy_params, bankroll, attempts, priority_fee, jito_tip) = {
+        let (slots_left, strategy, strategy_params, bankroll, attempts, retry_delay_ms, num_blockhashes, priority_fee, priority_fee_source, jito_tip, preview_ms, min_signer_balance, rotate, min_squares, max_squares, square_blacklist, require_last_slot, hard_last_slots, presign, spam_mode, absolute_max_deploy, dust_floor, round_filter, active_hours, auto_slots_left, auto_slots_left_target, auth_split, fleet_scale_bps) = {
             let cfg = config.read().await;
-            (cfg.slots_left, cfg.strategy.clone(), cfg.strategy_params.clone(), cfg.bankroll, cfg.attempts, cfg.priority_fee, cfg.jito_tip)
+            (cfg.slots_left, cfg.strategy.clone(), cfg.strategy_params.clone(), cfg.bankroll, cfg.attempts, cfg.retry_delay_ms, cfg.blockhashes, cfg.priority_fee, cfg.priority_fee_source, cfg.jito_tip, cfg.preview_ms, cfg.min_signer_balance, cfg.rotate_squares, cfg.min_squares, cfg.max_squares, cfg.square_blacklist.clone(), cfg.require_last_slot, cfg.hard_last_slots, cfg.presign, cfg.spam_mode, cfg.absolute_max_deploy, cfg.dust_floor, cfg.round_filter, cfg.active_hours.clone(), cfg.auto_slots_left, cfg.auto_slots_left_target, cfg.auth_split, cfg.fleet_scale_bps)
         };
+        
+        // A reloaded strategy takes over once no deploy of this round is under way
+        let (strategy, strategy_params, switched) = state.sync_strategy(strategy, &strategy_params, board.round_id);
+        if switched {
+            let _ = tui_tx.send(TuiUpdate::BotStrategyUpdate { bot_index, strategy });
+        }
+        
+        // Fleet cap: bets shrink with the rest of the fleet's when the bots'
+        // combined stake is over fleet_max_per_round
+        let (strategy_params, bankroll) = scale_stake(&strategy_params, bankroll, fleet_scale_bps);
+        
+        // Auto-tuned threshold replaces the configured one (which stays the
+        // fallback until a deploy has landed)
+        let slots_left = if auto_slots_left {
+            slots_left_tuner.learned(auto_slots_left_target, slots_left)
+        } else {
+            slots_left
+        };
+        if auto_slots_left && shown_slots_left != Some(slots_left) {
+            shown_slots_left = Some(slots_left);
+            let _ = tui_tx.send(TuiUpdate::BotSlotsLeftLearned { bot_index, slots_left });
+        } else if !auto_slots_left {
+            shown_slots_left = None;
+        }
+        
+        // Signer balance guard - poll while enabled (or still flagged after a config
+        // reload disabled it) so a refund resumes deploys without intervention
+        let balance_check_due = last_balance_check
+            .map_or(true, |checked| checked.elapsed() >= SIGNER_BALANCE_CHECK_INTERVAL);
+        if (min_signer_balance > 0 || state.signer_balance_low) && balance_check_due {
+            last_balance_check = Some(Instant::now());
+            if let Ok(balance) = services.client.get_balance(&signer.pubkey()) {
+                let _ = tui_tx.send(TuiUpdate::BotSignerBalanceUpdate {
+                    bot_index,
+                    balance,
+                });
+                if let Some(is_low) = state.update_signer_balance(balance, min_signer_balance) {
+                    let _ = tui_tx.send(TuiUpdate::BotSignerBalanceLow {
+                        bot_index,
+                        is_low,
+                        min_signer_balance,
+                    });
+                }
+            }
+        }
 
         // State machine logic
-        match determine_phase(&board, current_slot, &state, slots_left) {
+        // Strict mode must reach Deploying before its window opens
+        let deploy_threshold = if require_last_slot { slots_left.max(hard_last_slots) } else { slots_left };
+        // Deploy-ahead enters Deploying early to sign, then holds until the real trigger
+        let phase_threshold = if presign { deploy_threshold + PRESIGN_LEAD_SLOTS } else { deploy_threshold };
+        match determine_phase(&board, current_slot, &state, phase_threshold) {
             BotPhase::Idle => {
                 state.set_phase(BotPhase::Idle);
                 send_status(&tui_tx, bot_index, BotStatus::Idle);
@@ -339,93 +467,126 @@ pub async fn run_bot_with_services(
                 
                 if let Some(last_round) = state.last_deployed_round {
                     // Store pre-checkpoint values
-                    if let Ok(Some(miner)) = services.client.get_miner(&managed_miner_auth) {
+                    let miners = services.client.get_miners(&authorities).ok();
+                    if let Some(miner) = miners.as_deref().and_then(aggregate_miners) {
                         state.store_pre_checkpoint(miner.rewards_sol, miner.rewards_ore);
                     }
                     
-                    // Send checkpoint
-                    let bh = wait_for_blockhash(&services.blockhash_cache).await;
-                    let checkpoint_tx = build_checkpoint_tx(
-                        &signer,
-                        &manager,
-                        auth_id,
-                        last_round,
-                        bh,
-                    );
+                    // Send a checkpoint for every auth with a round pending
+                    let mut checkpointed = true;
+                    for (auth_id, round_id) in checkpoint_targets(&auth_ids, miners.as_deref(), last_round) {
+                        let bh = wait_for_blockhash(&services.blockhash_cache).await;
+                        let checkpoint_tx = build_checkpoint_tx(
+                            &signer,
+                            &manager,
+                            auth_id,
+                            round_id,
+                            bh,
+                        );
+                        
+                        let checkpoint_result = services.client.send_and_confirm_transaction(&checkpoint_tx)
+                            .map_err(|e| e.to_string());
+                        
+                        match checkpoint_result {
+                            Ok(sig) => {
+                                send_tx_event_typed(&tui_tx, &bot_name, TxType::Checkpoint, TxStatus::Confirmed, sig, None, 
+                                    Some(current_slot), Some(round_id), None, None);
+                            }
+                            Err(err_msg) => {
+                                checkpointed = false;
+                                send_tx_event_typed(&tui_tx, &bot_name, TxType::Checkpoint, TxStatus::Failed, Signature::default(), Some(err_msg),
+                                    Some(current_slot), Some(round_id), None, None);
+                            }
+                        }
+                    }
+                    
+                    if !checkpointed {
+                        // Auths that made it aren't pending next time
+                        sleep(Duration::from_millis(500)).await;
+                        continue;
+                    }
+                    
+                    // Update state after checkpoint
+                    sleep(Duration::from_millis(500)).await;
                     
-                    let checkpoint_result = services.client.send_and_confirm_transaction(&checkpoint_tx)
-                        .map_err(|e| e.to_string());
+                    // Get miner data and extract values before any await
+                    let miners = services.client.get_miners(&authorities).unwrap_or_default();
                     
-                    match checkpoint_result {
-                        Ok(sig) => {
-                            send_tx_event_typed(&tui_tx, &bot_name, TxType::Checkpoint, TxStatus::Confirmed, sig, None, 
-                                Some(current_slot), Some(last_round), None, None);
-                            
-                            // Update state after checkpoint
-                            sleep(Duration::from_millis(500)).await;
+                    if let Some(miner) = aggregate_miners(&miners) {
+                        let rewards_sol = miner.rewards_sol;
+                        let rewards_ore = miner.rewards_ore;
+                        
+                        if let Some(export) = &services.deploy_export {
+                            let outcome = RoundOutcome::from_deltas(
+                                rewards_sol.saturating_sub(state.pre_checkpoint_sol),
+                                rewards_ore.saturating_sub(state.pre_checkpoint_ore),
+                            );
+                            export.settle(&bot_name, last_round, outcome);
+                        }
+                        state.process_checkpoint(last_round, rewards_sol, rewards_ore);
+                        
+                        // Stop-loss: pause after too many losing rounds in a row
+                        let max_losses = config.read().await.max_consecutive_losses;
+                        if state.stop_loss_triggered(max_losses) {
+                            config.write().await.is_paused = true;
+                            let _ = tui_tx.send(TuiUpdate::BotStopLoss {
+                                bot_index,
+                                losses: max_losses,
+                            });
+                        }
+                        
+                        let _ = tui_tx.send(TuiUpdate::BotStatsUpdate {
+                            bot_index,
+                            rounds_participated: state.rounds_participated,
+                            rounds_won: state.rounds_won,
+                            rounds_skipped: state.rounds_skipped,
+                            rounds_missed: state.rounds_missed,
+                            current_claimable_sol: state.current_claimable_sol,
+                            current_ore: state.current_ore,
+                        });
+
+                        let _ = tui_tx.send(TuiUpdate::BotMinerUpdate {
+                            bot_index,
+                            miner: miner.clone(),
+                        });
+                        
+                        // Claim from every auth with rewards available
+                        let claims: Vec<(u64, u64)> = auth_ids.iter()
+                            .zip(&miners)
+                            .filter_map(|(auth_id, miner)| miner.filter(|m| m.rewards_sol > 0).map(|m| (*auth_id, m.rewards_sol)))
+                            .collect();
+                        if !claims.is_empty() {
+                            state.set_phase(BotPhase::Claiming);
+                        }
+                        for (auth_id, rewards_sol) in claims {
+                            let bh = wait_for_blockhash(&services.blockhash_cache).await;
+                            let claim_tx = build_claim_sol_tx(
+                                &signer,
+                                &manager,
+                                auth_id,
+                                bh,
+                            );
                             
-                            // Get miner data and extract values before any await
-                            let miner_data = services.client.get_miner(&managed_miner_auth).ok().flatten();
+                            let claim_result = services.client.send_and_confirm_transaction(&claim_tx)
+                                .map_err(|e| e.to_string());
                             
-                            if let Some(miner) = miner_data {
-                                let rewards_sol = miner.rewards_sol;
-                                let rewards_ore = miner.rewards_ore;
-                                
-                                state.process_checkpoint(last_round, rewards_sol, rewards_ore);
-                                
-                                let _ = tui_tx.send(TuiUpdate::BotStatsUpdate {
-                                    bot_index,
-                                    rounds_participated: state.rounds_participated,
-                                    rounds_won: state.rounds_won,
-                                    rounds_skipped: state.rounds_skipped,
-                                    rounds_missed: state.rounds_missed,
-                                    current_claimable_sol: state.current_claimable_sol,
-                                    current_ore: state.current_ore,
-                                });
-
-                                let _ = tui_tx.send(TuiUpdate::BotMinerUpdate {
-                                    bot_index,
-                                    miner: miner.clone(),
-                                });
-                                
-                                // Claim if rewards available
-                                if rewards_sol > 0 {
-                                    state.set_phase(BotPhase::Claiming);
-                                    let bh = wait_for_blockhash(&services.blockhash_cache).await;
-                                    let claim_tx = build_claim_sol_tx(
-                                        &signer,
-                                        &manager,
-                                        auth_id,
-                                        bh,
-                                    );
-                                    
-                                    let claim_result = services.client.send_and_confirm_transaction(&claim_tx)
-                                        .map_err(|e| e.to_string());
-                                    
-                                    match claim_result {
-                                        Ok(sig) => {
-                                            send_tx_event_typed(&tui_tx, &bot_name, TxType::ClaimSol, TxStatus::Confirmed, sig, None,
-                                                Some(current_slot), None, Some(rewards_sol), None);
-                                            // Track claimed amount for accurate P&L
-                                            let _ = tui_tx.send(TuiUpdate::BotClaimedSol {
-                                                bot_index,
-                                                amount: rewards_sol,
-                                            });
-                                            update_signer_balance(&services, bot_index, &signer, &tui_tx).await;
-                                        }
-                                        Err(err_msg) => {
-                                            send_tx_event_typed(&tui_tx, &bot_name, TxType::ClaimSol, TxStatus::Failed, Signature::default(), Some(err_msg),
-                                                Some(current_slot), None, Some(rewards_sol), None);
-                                        }
-                                    }
+                            match claim_result {
+                                Ok(sig) => {
+                                    send_tx_event_typed(&tui_tx, &bot_name, TxType::ClaimSol, TxStatus::Confirmed, sig, None,
+                                        Some(current_slot), None, Some(rewards_sol), None);
+                                    // Track claimed amount for accurate P&L
+                                    let _ = tui_tx.send(TuiUpdate::BotClaimedSol {
+                                        bot_index,
+                                        amount: rewards_sol,
+                                    });
+                                    update_signer_balance(&services, bot_index, &signer, &tui_tx).await;
+                                }
+                                Err(err_msg) => {
+                                    send_tx_event_typed(&tui_tx, &bot_name, TxType::ClaimSol, TxStatus::Failed, Signature::default(), Some(err_msg),
+                                        Some(current_slot), None, Some(rewards_sol), None);
                                 }
                             }
                         }
-                        Err(err_msg) => {
-                            send_tx_event_typed(&tui_tx, &bot_name, TxType::Checkpoint, TxStatus::Failed, Signature::default(), Some(err_msg),
-                                Some(current_slot), Some(last_round), None, None);
-                            sleep(Duration::from_millis(500)).await;
-                        }
                     }
                 }
             }
@@ -437,99 +598,450 @@ pub async fn run_bot_with_services(
             }
             
             BotPhase::Deploying => {
+                // Don't burn fees on deploys the signer can't pay for
+                if state.signer_balance_low {
+                    send_status(&tui_tx, bot_index, BotStatus::LowBalance);
+                    sleep(Duration::from_millis(500)).await;
+                    continue;
+                }
+                
+                // Outside active_hours: hold deploys (checkpoints and claims still run)
+                let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+                if !in_active_hours(&active_hours, now) {
+                    send_status(&tui_tx, bot_index, BotStatus::OutsideHours);
+                    sleep(Duration::from_millis(500)).await;
+                    continue;
+                }
+                
+                // At most one deploy per round: a repeated trigger (e.g. the round
+                // still unconfirmed after a deploy was sent) waits instead
+                if !state.claim_deploy(board.round_id, spam_mode) {
+                    sleep(Duration::from_millis(100)).await;
+                    continue;
+                }
+                
                 state.set_phase(BotPhase::Deploying);
                 send_status(&tui_tx, bot_index, BotStatus::Deploying);
                 
-                // Send deploy transactions at 100ms intervals
-                // Each tx has a unique attempts value to generate different signatures
+                // Round filter: sit out rounds whose round_id doesn't match
+                if !round_filter.matches(board.round_id) {
+                    let _ = tui_tx.send(TuiUpdate::Error(format!(
+                        "{}: round {} doesn't match round_filter {}, skipping",
+                        bot_name, board.round_id, round_filter,
+                    )));
+                    state.rounds_skipped += 1;
+                    send_status(&tui_tx, bot_index, BotStatus::Skipped);
+                    let _ = tui_tx.send(TuiUpdate::BotStatsUpdate {
+                        bot_index,
+                        rounds_participated: state.rounds_participated,
+                        rounds_won: state.rounds_won,
+                        rounds_skipped: state.rounds_skipped,
+                        rounds_missed: state.rounds_missed,
+                        current_claimable_sol: state.current_claimable_sol,
+                        current_ore: state.current_ore,
+                    });
+                    state.last_deployed_round = Some(board.round_id);
+                    state.last_checkpointed_round = Some(board.round_id);
+                    continue;
+                }
+                
+                // Manual amounts for this round (rotation depends only on round_id).
+                // A bounded EV bot deploys its clamped EV bets as manual amounts
+                // A Percentage bot with a fixed square set deploys its sized bets as manual amounts
+                // A LeadSquare bot deploys the amount that takes the lead as a manual amount
+                let manual = manual_amounts(strategy, &strategy_params, rotate, board.round_id)
+                    .or_else(|| services.round_tracker.get_round().and_then(|round| {
+                        bounded_ev_amounts(strategy, &strategy_params, bankroll, slots_left, min_squares, max_squares, &square_blacklist, &round)
+                            .or_else(|| fixed_percentage_amounts(strategy, &strategy_params, bankroll, &round))
+                            .or_else(|| lead_square_amounts(strategy, &strategy_params, bankroll, &round))
+                    }));
+                
+                // Optional dry-run preview: show the bets we expect to place and
+                // give the operator preview_ms to veto before anything is sent
+                if preview_ms > 0 {
+                    let bets = services.round_tracker.get_round()
+                        .map(|round| preview_bets(strategy, &strategy_params, manual.as_ref(), bankroll, slots_left, &round))
+                        .unwrap_or([0; 25]);
+                    
+                    config.write().await.deploy_vetoed = false;
+                    let _ = tui_tx.send(TuiUpdate::PendingDeploy {
+                        bot_index,
+                        round_id: board.round_id,
+                        bets,
+                        total: bets.iter().sum(),
+                        preview_ms,
+                    });
+                    
+                    let vetoed = wait_for_veto(&config, &services, board.end_slot, preview_ms).await;
+                    let _ = tui_tx.send(TuiUpdate::PendingDeployCleared { bot_index });
+                    
+                    if vetoed {
+                        // Treat a veto like an EV skip so we don't retry or checkpoint this round
+                        state.rounds_skipped += 1;
+                        send_status(&tui_tx, bot_index, BotStatus::Skipped);
+                        let _ = tui_tx.send(TuiUpdate::BotStatsUpdate {
+                            bot_index,
+                            rounds_participated: state.rounds_participated,
+                            rounds_won: state.rounds_won,
+                            rounds_skipped: state.rounds_skipped,
+                            rounds_missed: state.rounds_missed,
+                            current_claimable_sol: state.current_claimable_sol,
+                            current_ore: state.current_ore,
+                        });
+                        state.last_deployed_round = Some(board.round_id);
+                        state.last_checkpointed_round = Some(board.round_id);
+                        continue;
+                    }
+                }
+                
+                // Strict last-slot mode: hold the deploy until the last hard_last_slots
+                // slots and skip the round if there's no longer time for it to land
+                if require_last_slot {
+                    let decision = loop {
+                        match last_slot_decision(
+                            services.slot_tracker.get_slot(),
+                            board.end_slot,
+                            services.slot_tracker.time_since_last_slot(),
+                            hard_last_slots,
+                        ) {
+                            LastSlotDecision::Wait => sleep(Duration::from_millis(10)).await,
+                            decision => break decision,
+                        }
+                    };
+                    if decision == LastSlotDecision::Skip {
+                        state.rounds_skipped += 1;
+                        send_status(&tui_tx, bot_index, BotStatus::Skipped);
+                        let _ = tui_tx.send(TuiUpdate::BotStatsUpdate {
+                            bot_index,
+                            rounds_participated: state.rounds_participated,
+                            rounds_won: state.rounds_won,
+                            rounds_skipped: state.rounds_skipped,
+                            rounds_missed: state.rounds_missed,
+                            current_claimable_sol: state.current_claimable_sol,
+                            current_ore: state.current_ore,
+                        });
+                        state.last_deployed_round = Some(board.round_id);
+                        state.last_checkpointed_round = Some(board.round_id);
+                        continue;
+                    }
+                }
+                
+                // Cleared to send; presign resets this once it reaches the trigger slot
+                let mut triggered_at = Instant::now();
+                
+                // Dynamic fee source: price this deploy off recent prioritization fees
+                let priority_fee = match priority_fee_source {
+                    PriorityFeeSource::Fixed => priority_fee,
+                    source => {
+                        let recent = services.priority_fees.get_fees(&services.client);
+                        let fee = effective_priority_fee(source, priority_fee, &recent);
+                        let _ = tui_tx.send(TuiUpdate::BotPriorityFee { bot_index, priority_fee: fee });
+                        fee
+                    }
+                };
+                
+                // Expected bets (the program recomputes them on-chain), used for the
+                // audit log and to check the deploy wasn't short once it confirms
+                let decided_on = services.round_tracker.get_round();
+                let intended = decided_on.as_ref()
+                    .map(|round| preview_bets(strategy, &strategy_params, manual.as_ref(), bankroll, slots_left, round));
+                
+                // One deploy per auth_id this round (a single one unless splitting over auth_ids)
+                let Some(legs) = round_legs(&auth_ids, auth_split, board.round_id, manual, intended) else {
+                    // A split needs the round's bets - retry once the round tracker has them
+                    state.release_deploy(board.round_id);
+                    sleep(Duration::from_millis(100)).await;
+                    continue;
+                };
+                let audit_bases: Vec<_> = legs.iter().map(|leg| (services.audit_log.is_some() || services.deploy_export.is_some()).then(|| {
+                    let bets = leg.manual.or(intended).unwrap_or([0; 25]);
+                    DeployAuditRecord {
+                        timestamp_ms: 0,
+                        bot: bot_name.clone(),
+                        round_id: board.round_id,
+                        auth_id: leg.auth_id,
+                        mask: bets_mask(&bets),
+                        amount: bets.iter().sum(),
+                        priority_fee,
+                        jito_tip,
+                        attempt: 0,
+                        signature: String::new(),
+                        result: AuditResult::Failed,
+                        error: None,
+                    }
+                })).collect();
+                
+                // Send deploy transactions every retry_delay_ms
+                // Each attempt is signed against the most recent blockhashes, and each tx
+                // has a unique (attempts, blockhash) pair to generate different signatures
                 let mut signatures = Vec::new();
+                let mut first_send_slot: Option<u64> = None;
                 
-                // Get blockhash once and reuse for all attempts
-                let bh = services.blockhash_cache.get_blockhash();
-                if bh == Hash::default() {
+                // Get blockhashes once and reuse for all attempts
+                let blockhash_count = num_blockhashes.clamp(1, MAX_RECENT_BLOCKHASHES as u64) as usize;
+                let mut blockhashes = services.blockhash_cache.get_recent_blockhashes(blockhash_count);
+                if blockhashes.is_empty() {
                     // No blockhash available, skip this round
                     state.reset_for_round(board.round_id);
+                    state.release_deploy(board.round_id);
+                    continue;
+                }
+                
+                // Fat-finger guard: refuse to sign more than the hard ceiling and
+                // pause the bot until the config is fixed
+                let stake = expected_stake(&strategy_params, bankroll);
+                if let Err(e) = check_deploy_cap(stake, absolute_max_deploy) {
+                    config.write().await.is_paused = true;
+                    let _ = tui_tx.send(TuiUpdate::BotDeployCapExceeded {
+                        bot_index,
+                        total: stake,
+                        absolute_max_deploy,
+                    });
+                    let _ = tui_tx.send(TuiUpdate::Error(format!("{}: {}", bot_name, e)));
+                    state.release_deploy(board.round_id);
                     continue;
                 }
                 
+                // Dust guard: a deploy expected to total zero or dust only costs fees,
+                // so skip the round (falls back to the stake when there's no preview).
+                // A LeadSquare bot without amounts has no lead it can afford
+                let total = match strategy {
+                    DeployStrategy::LeadSquare => manual.map_or(0, |bets| bets.iter().sum()),
+                    _ => intended.map_or(stake, |bets| bets.iter().sum()),
+                };
+                if let Err(e) = check_dust_floor(total, dust_floor) {
+                    let _ = tui_tx.send(TuiUpdate::Error(format!("{}: {}", bot_name, e)));
+                    state.rounds_skipped += 1;
+                    send_status(&tui_tx, bot_index, BotStatus::Skipped);
+                    let _ = tui_tx.send(TuiUpdate::BotStatsUpdate {
+                        bot_index,
+                        rounds_participated: state.rounds_participated,
+                        rounds_won: state.rounds_won,
+                        rounds_skipped: state.rounds_skipped,
+                        rounds_missed: state.rounds_missed,
+                        current_claimable_sol: state.current_claimable_sol,
+                        current_ore: state.current_ore,
+                    });
+                    state.last_deployed_round = Some(board.round_id);
+                    state.last_checkpointed_round = Some(board.round_id);
+                    continue;
+                }
+                
+                // The board and EV behind each leg's bets, for `evore-bot decision`
+                if let (Some(log), Some(round)) = (&services.audit_log, &decided_on) {
+                    for leg in &legs {
+                        let snapshot = EvSnapshotRecord::new(
+                            bot_name.clone(),
+                            board.round_id,
+                            leg.auth_id,
+                            strategy,
+                            &strategy_params,
+                            bankroll,
+                            slots_left,
+                            round.deployed,
+                            leg.manual.or(intended).unwrap_or([0; 25]),
+                        );
+                        let _ = log.record_snapshot(&snapshot);
+                    }
+                }
+                
+                // Build deploy transaction based on strategy
+                let build_deploy = |leg: &DeployLeg, attempt: u64, bh: Hash| match strategy {
+                    _ if leg.manual.is_some() => {
+                        build_manual_deploy_tx(
+                            &signer,
+                            &manager,
+                            leg.auth_id,
+                            board.round_id,
+                            leg.manual.unwrap(),
+                            false,  // allow_multi_deploy - default to false
+                            bh,
+                            priority_fee,
+                            jito_tip,
+                        )
+                    }
+                    DeployStrategy::EV => {
+                        let mut params = build_ev_params_from_values(&strategy_params, bankroll, slots_left);
+                        params.attempts = attempt;  // Each tx has unique attempts value
+                        build_ev_deploy_tx(
+                            &signer,
+                            &manager,
+                            leg.auth_id,
+                            board.round_id,
+                            &params,
+                            false,  // allow_multi_deploy - default to false
+                            bh,
+                            priority_fee,
+                            jito_tip,
+                        )
+                    }
+                    DeployStrategy::Percentage => {
+                        let params = build_percentage_params_from_values(&strategy_params, bankroll);
+                        build_percentage_deploy_tx(
+                            &signer,
+                            &manager,
+                            leg.auth_id,
+                            board.round_id,
+                            &params,
+                            false,  // allow_multi_deploy - default to false
+                            bh,
+                            priority_fee,
+                            jito_tip,
+                        )
+                    }
+                    DeployStrategy::LeadSquare => {
+                        // Not reached: the dust guard skips a LeadSquare round without amounts
+                        build_manual_deploy_tx(
+                            &signer,
+                            &manager,
+                            leg.auth_id,
+                            board.round_id,
+                            [0; 25],
+                            false,  // allow_multi_deploy - default to false
+                            bh,
+                            priority_fee,
+                            jito_tip,
+                        )
+                    }
+                    DeployStrategy::Manual => {
+                        // No manual amounts configured - fall back to EV params
+                        let mut params = build_ev_params_from_values(&strategy_params, bankroll, slots_left);
+                        params.attempts = attempt;
+                        build_ev_deploy_tx(
+                            &signer,
+                            &manager,
+                            leg.auth_id,
+                            board.round_id,
+                            &params,
+                            false,  // allow_multi_deploy - default to false
+                            bh,
+                            priority_fee,
+                            jito_tip,
+                        )
+                    }
+                };
+                
                 // All strategies respect the attempts config for redundancy
                 // (duplicate txns are fine - provides resilience against dropped packets)
                 let num_attempts = attempts;
                 
+                // Size guard: one built transaction per deploy is checked against the
+                // packet and account limits, so an oversized deploy fails here with a
+                // clear reason instead of as a send error
+                let size_check = legs.iter()
+                    .map(|leg| check_tx_size(TxSizeEstimate::of(&build_deploy(leg, 0, blockhashes[0]))))
+                    .collect::<Result<Vec<_>, _>>();
+                match size_check {
+                    Ok(warnings) => {
+                        for warning in warnings.into_iter().flatten() {
+                            let _ = tui_tx.send(TuiUpdate::Error(format!("{}: {}", bot_name, warning)));
+                        }
+                    }
+                    Err(e) => {
+                        let _ = tui_tx.send(TuiUpdate::Error(format!("{}: {}", bot_name, e)));
+                        state.rounds_skipped += 1;
+                        send_status(&tui_tx, bot_index, BotStatus::Skipped);
+                        let _ = tui_tx.send(TuiUpdate::BotStatsUpdate {
+                            bot_index,
+                            rounds_participated: state.rounds_participated,
+                            rounds_won: state.rounds_won,
+                            rounds_skipped: state.rounds_skipped,
+                            rounds_missed: state.rounds_missed,
+                            current_claimable_sol: state.current_claimable_sol,
+                            current_ore: state.current_ore,
+                        });
+                        state.last_deployed_round = Some(board.round_id);
+                        state.last_checkpointed_round = Some(board.round_id);
+                        continue;
+                    }
+                }
+                
+                // Deploy-ahead: sign everything now and hold until the trigger slot,
+                // re-signing whenever a newer blockhash comes in
+                let mut resigns = 0;
+                let presigned = if presign {
+                    let trigger_slot = board.end_slot.saturating_sub(deploy_threshold);
+                    let sign_all = |blockhashes: &[Hash]| -> Vec<PresignedDeploy> {
+                        legs.iter()
+                            .map(|leg| PresignedDeploy::sign(num_attempts, blockhashes.to_vec(), |attempt, bh| build_deploy(leg, attempt, bh)))
+                            .collect()
+                    };
+                    let mut presigned = sign_all(&blockhashes);
+                    while services.slot_tracker.get_slot() < trigger_slot {
+                        let recent = services.blockhash_cache.get_recent_blockhashes(blockhash_count);
+                        if presigned.iter().any(|p| p.is_stale(&recent)) {
+                            presigned = sign_all(&recent);
+                            resigns += 1;
+                        }
+                        sleep(Duration::from_millis(10)).await;
+                    }
+                    triggered_at = Instant::now();
+                    if let Some(first) = presigned.first() {
+                        blockhashes = first.blockhashes.clone();
+                    }
+                    Some(presigned)
+                } else {
+                    None
+                };
+                
                 for attempt in 0..num_attempts {
                     let current = services.slot_tracker.get_slot();
                     if current >= board.end_slot {
                         break;
                     }
                     
-                    // Build deploy transaction based on strategy
-                    let deploy_tx = match strategy {
-                        DeployStrategy::EV => {
-                            let mut params = build_ev_params_from_values(&strategy_params, bankroll, slots_left);
-                            params.attempts = attempt;  // Each tx has unique attempts value
-                            build_ev_deploy_tx(
-                                &signer,
-                                &manager,
-                                auth_id,
-                                board.round_id,
-                                &params,
-                                false,  // allow_multi_deploy - default to false
-                                bh,
-                                priority_fee,
-                                jito_tip,
-                            )
-                        }
-                        DeployStrategy::Percentage => {
-                            let params = build_percentage_params_from_values(&strategy_params, bankroll);
-                            build_percentage_deploy_tx(
-                                &signer,
-                                &manager,
-                                auth_id,
-                                board.round_id,
-                                &params,
-                                false,  // allow_multi_deploy - default to false
-                                bh,
-                                priority_fee,
-                                jito_tip,
-                            )
-                        }
-                        DeployStrategy::Manual => {
-                            // TODO: Implement manual strategy - falls back to EV for now
-                            let mut params = build_ev_params_from_values(&strategy_params, bankroll, slots_left);
-                            params.attempts = attempt;
-                            build_ev_deploy_tx(
-                                &signer,
-                                &manager,
-                                auth_id,
-                                board.round_id,
-                                &params,
-                                false,  // allow_multi_deploy - default to false
-                                bh,
-                                priority_fee,
-                                jito_tip,
-                            )
+                    for (leg_index, leg) in legs.iter().enumerate() {
+                        for (i, bh) in blockhashes.iter().enumerate() {
+                            let built;
+                            let deploy_tx = match &presigned {
+                                Some(presigned) => &presigned[leg_index].transactions[attempt as usize][i],
+                                None => {
+                                    built = build_deploy(leg, attempt, *bh);
+                                    &built
+                                }
+                            };
+                            
+                            // Use FastSender for deploy transactions (automatic 4x retry via Helius)
+                            match services.fast_sender.send_transaction(deploy_tx) {
+                                Ok(sig) => {
+                                    first_send_slot.get_or_insert(current);
+                                    signatures.push((leg_index, attempt, sig));
+                                    services.confirmations.track(&bot_name, board.round_id, sig);
+                                    send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Sent, sig, None,
+                                        Some(current), Some(board.round_id), Some(bankroll), Some(attempt));
+                                }
+                                Err(e) => {
+                                    audit_deploy(&services, &audit_bases[leg_index], attempt, &Signature::default(),
+                                        AuditResult::Failed, Some(e.to_string()));
+                                    send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Failed, Signature::default(), Some(e.to_string()),
+                                        Some(current), Some(board.round_id), Some(bankroll), Some(attempt));
+                                }
+                            }
                         }
-                    };
+                    }
                     
-                    // Use FastSender for deploy transactions (automatic 4x retry via Helius)
-                    match services.fast_sender.send_transaction(&deploy_tx) {
-                        Ok(sig) => {
-                            signatures.push(sig);
-                            send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Sent, sig, None,
-                                Some(current), Some(board.round_id), Some(bankroll), Some(attempt));
-                        }
-                        Err(e) => {
-                            send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Failed, Signature::default(), Some(e.to_string()),
-                                Some(current), Some(board.round_id), Some(bankroll), Some(attempt));
-                        }
+                    // Trigger-to-send latency of the first wave, to compare presign against build-at-trigger
+                    if attempt == 0 {
+                        let _ = tui_tx.send(TuiUpdate::BotDeployLatency {
+                            bot_index,
+                            presigned: presigned.is_some(),
+                            send_us: triggered_at.elapsed().as_micros() as u64,
+                            sign_us: presigned.as_ref().map(|p| p.iter().map(|p| p.sign_time.as_micros() as u64).sum()),
+                            resigns,
+                        });
                     }
                     
                     // Sleep between attempts (except after last one)
                     if attempt < num_attempts - 1 {
-                        sleep(Duration::from_millis(400)).await;
+                        sleep(Duration::from_millis(retry_delay_ms)).await;
                     }
                 }
                 
+                // Nothing made it out - let the next trigger retry this round
+                if signatures.is_empty() {
+                    state.release_deploy(board.round_id);
+                }
+                
                 // Check confirmations
                 if !signatures.is_empty() {
                     sleep(Duration::from_secs(3)).await;
@@ -538,47 +1050,83 @@ pub async fn run_bot_with_services(
                     let mut ev_skip = false;
                     let mut had_other_error = false;
                     
-                    for sig in &signatures {
-                        match services.client.get_transaction_status(sig) {
-                            Ok(Some(status)) => {
-                                if status.err.is_none() {
-                                    any_confirmed = true;
-                                    send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Confirmed, *sig, None,
-                                        Some(status.slot), Some(board.round_id), Some(bankroll), None);
+                    // Look up every signature first - whether a failure is expected
+                    // depends on whether one of the others landed
+                    let lookups: Vec<_> = signatures.iter()
+                        .map(|(leg, attempt, sig)| (*leg, *attempt, *sig, services.client.get_transaction_status(sig).map_err(|e| e.to_string())))
+                        .collect();
+                    let statuses: Vec<SigStatus> = lookups.iter()
+                        .map(|(_, _, _, lookup)| match lookup {
+                            Ok(Some(status)) => match &status.err {
+                                None => SigStatus::Landed,
+                                // Custom(9): AlreadyDeployed - one of our txs landed
+                                Some(err) if format!("{:?}", err).contains("Custom(9)") => SigStatus::AlreadyDeployed,
+                                Some(_) => SigStatus::Failed,
+                            },
+                            Ok(None) | Err(_) => SigStatus::Missing,
+                        })
+                        .collect();
+                    let deploys: Vec<usize> = lookups.iter().map(|(leg, _, _, _)| *leg).collect();
+                    let outcomes = classify_redundant_per_deploy(&deploys, &statuses);
+                    
+                    for ((leg, attempt, sig, lookup), outcome) in lookups.into_iter().zip(outcomes) {
+                        let audit_base = &audit_bases[leg];
+                        match (lookup, outcome) {
+                            (Ok(Some(status)), RedundantOutcome::Landed) => {
+                                any_confirmed = true;
+                                if let Some(sent) = first_send_slot {
+                                    slots_left_tuner.record_latency(sent, status.slot);
+                                    slots_left_tuner.record_round(board.start_slot, board.end_slot);
+                                }
+                                audit_deploy(&services, audit_base, attempt, &sig, AuditResult::Confirmed, None);
+                                export_landing(&services, audit_base, attempt, &sig, Landing {
+                                    start_slot: board.start_slot,
+                                    end_slot: board.end_slot,
+                                    sent_slot: first_send_slot,
+                                    landed_slot: status.slot,
+                                });
+                                send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Confirmed, sig, None,
+                                    Some(status.slot), Some(board.round_id), Some(bankroll), Some(attempt));
+                            }
+                            (lookup, RedundantOutcome::Duplicate) => {
+                                // Another deploy for this round landed - expected, not an error
+                                any_confirmed = true;
+                                let slot = lookup.ok().flatten().map(|status| status.slot);
+                                audit_deploy(&services, audit_base, attempt, &sig, AuditResult::Duplicate, None);
+                                send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Duplicate, sig, None,
+                                    slot, Some(board.round_id), Some(bankroll), Some(attempt));
+                            }
+                            (Ok(Some(status)), _) => {
+                                // Transaction landed but failed on-chain
+                                let err_msg = status.err.map(|e| format!("{:?}", e)).unwrap_or_default();
+                                
+                                // Map Evore program error codes to human-readable names
+                                let friendly_err = parse_evore_error(&err_msg);
+                                
+                                // Custom(7): NoDeployments (EV skip) - count as skip
+                                if err_msg.contains("Custom(7)") {
+                                    ev_skip = true;
                                 } else {
-                                    // Transaction landed but failed on-chain
-                                    let err = status.err.unwrap();
-                                    let err_msg = format!("{:?}", err);
-                                    
-                                    // Map Evore program error codes to human-readable names
-                                    let friendly_err = parse_evore_error(&err_msg);
-                                    
-                                    // Check error type:
-                                    // - Custom(7): NoDeployments (EV skip) - count as skip
-                                    // - Custom(9): AlreadyDeployed - means one of our txs landed, treat as success
-                                    if err_msg.contains("Custom(7)") {
-                                        ev_skip = true;
-                                    } else if err_msg.contains("Custom(9)") {
-                                        // Already deployed this round - one of our earlier txs landed
-                                        // This is expected when sending multiple attempts
-                                        any_confirmed = true;
-                                    } else {
-                                        had_other_error = true;
-                                    }
-                                    
-                                    send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Failed, *sig, Some(friendly_err),
-                                        Some(status.slot), Some(board.round_id), Some(bankroll), None);
+                                    had_other_error = true;
                                 }
+                                
+                                audit_deploy(&services, audit_base, attempt, &sig,
+                                    AuditResult::Failed, Some(friendly_err.clone()));
+                                send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Failed, sig, Some(friendly_err),
+                                    Some(status.slot), Some(board.round_id), Some(bankroll), None);
                             }
-                            Ok(None) => {
+                            (Ok(None), _) => {
                                 // Transaction not found - expired or dropped
                                 had_other_error = true;
-                                send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Failed, *sig, Some("Tx expired/dropped".to_string()),
+                                audit_deploy(&services, audit_base, attempt, &sig, AuditResult::Timeout, None);
+                                send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Failed, sig, Some("Tx expired/dropped".to_string()),
                                     None, Some(board.round_id), Some(bankroll), None);
                             }
-                            Err(e) => {
+                            (Err(e), _) => {
                                 had_other_error = true;
-                                send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Failed, *sig, Some(format!("RPC: {}", e)),
+                                audit_deploy(&services, audit_base, attempt, &sig,
+                                    AuditResult::Failed, Some(format!("RPC: {}", e)));
+                                send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Failed, sig, Some(format!("RPC: {}", e)),
                                     None, Some(board.round_id), Some(bankroll), None);
                             }
                         }
@@ -626,8 +1174,17 @@ pub async fn run_bot_with_services(
                         send_status(&tui_tx, bot_index, BotStatus::Deployed);
                         
                         // Get deployed amount from miner
-                        if let Ok(Some(miner)) = services.client.get_miner(&managed_miner_auth) {
+                        if let Some(miner) = group_miner(&services.client, &authorities) {
                             let deployed: u64 = miner.deployed.iter().sum();
+                            
+                            // Re-read our contribution and warn if it came up short
+                            // (e.g. bankroll ran out or the pot moved before landing)
+                            let actual = if miner.round_id == board.round_id { miner.deployed } else { [0; 25] };
+                            if let Some((actual, intended)) = intended.as_ref().and_then(|bets| deploy_shortfall(bets, &actual)) {
+                                send_tx_event_typed(&tui_tx, &bot_name, TxType::Deploy, TxStatus::Partial, Signature::default(),
+                                    Some(format!("partial {:.4}/{:.4}◎", actual as f64 / 1e9, intended as f64 / 1e9)),
+                                    None, Some(board.round_id), Some(actual), None);
+                            }
                             state.record_deployment(board.round_id, deployed);
                             
                             let _ = tui_tx.send(TuiUpdate::BotDeployedUpdate {
@@ -673,6 +1230,59 @@ pub async fn run_bot_with_services(
     }
 }
 
+/// The bot's miners read as one (see `aggregate_miners`); None if none exist or the read failed
+fn group_miner(client: &EvoreClient, authorities: &[Pubkey]) -> Option<Miner> {
+    client.get_miners(authorities).ok().and_then(|miners| aggregate_miners(&miners))
+}
+
+/// Append a deploy attempt to the audit log and deploy export (no-op when both are disabled)
+fn audit_deploy(
+    services: &SharedServices,
+    base: &Option<DeployAuditRecord>,
+    attempt: u64,
+    signature: &Signature,
+    result: AuditResult,
+    error: Option<String>,
+) {
+    if let Some(base) = base {
+        let record = DeployAuditRecord {
+            timestamp_ms: now_ms(),
+            attempt,
+            signature: signature.to_string(),
+            result,
+            error,
+            ..base.clone()
+        };
+        if let Some(log) = &services.audit_log {
+            let _ = log.record(&record);
+        }
+        if let Some(export) = &services.deploy_export {
+            export.record(&record);
+        }
+    }
+}
+
+/// Write a confirmed deploy's landed slot to the deploy export (no-op when disabled)
+fn export_landing(
+    services: &SharedServices,
+    base: &Option<DeployAuditRecord>,
+    attempt: u64,
+    signature: &Signature,
+    landing: Landing,
+) {
+    if let (Some(export), Some(base)) = (&services.deploy_export, base) {
+        let record = DeployAuditRecord {
+            timestamp_ms: now_ms(),
+            attempt,
+            signature: signature.to_string(),
+            result: AuditResult::Confirmed,
+            error: None,
+            ..base.clone()
+        };
+        export.record_landing(&record, landing);
+    }
+}
+
 /// Determine what phase the bot should be in
 fn determine_phase(
     board: &evore::ore_api::Board,
@@ -770,6 +1380,150 @@ async fn update_signer_balance(
     }
 }
 
+/// Compute the per-square bets the on-chain program would place for this round
+///
+/// Uses the same allocation functions as mm_deploy, so the result matches what
+/// gets deployed as long as the round doesn't change before the tx lands.
+pub(crate) fn preview_bets(
+    strategy: DeployStrategy,
+    strategy_params: &StrategyParams,
+    manual: Option<&[u64; 25]>,
+    bankroll: u64,
+    slots_left: u64,
+    round: &Round,
+) -> [u64; 25] {
+    if let Some(amounts) = manual {
+        return *amounts;
+    }
+    
+    let (batches, _) = match strategy {
+        // LeadSquare bets only come through `manual`
+        DeployStrategy::LeadSquare => return [0; 25],
+        // Manual without manual amounts deploys with EV params (see deploy loop)
+        DeployStrategy::EV | DeployStrategy::Manual => {
+            let params = build_ev_params_from_values(strategy_params, bankroll, slots_left);
+            calculate_ev_deployments(round, params.bankroll, params.min_bet, params.max_per_square, params.ore_value)
+        }
+        DeployStrategy::Percentage => {
+            let params = build_percentage_params_from_values(strategy_params, bankroll);
+            calculate_percentage_deployments(round, params.bankroll, params.percentage, params.squares_count)
+        }
+    };
+    
+    let mut bets = [0u64; 25];
+    for batch in &batches {
+        for (bet, selected) in bets.iter_mut().zip(batch.squares.iter()) {
+            if *selected {
+                *bet = bet.saturating_add(batch.amount);
+            }
+        }
+    }
+    bets
+}
+
+/// Wait out the preview window, returning true if the deploy was vetoed
+/// Gives up once the round has ended since there's nothing left to veto
+async fn wait_for_veto(
+    config: &RwLock<BotRunConfig>,
+    services: &SharedServices,
+    end_slot: u64,
+    preview_ms: u64,
+) -> bool {
+    let deadline = Instant::now() + Duration::from_millis(preview_ms);
+    loop {
+        if config.read().await.deploy_vetoed {
+            return true;
+        }
+        if Instant::now() >= deadline || services.slot_tracker.get_slot() >= end_slot {
+            return false;
+        }
+        sleep(Duration::from_millis(25)).await;
+    }
+}
+
+/// Per-square amounts for a Manual bot, rotated for the round if enabled
+/// None when the bot isn't Manual or has no manual amounts configured
+pub(crate) fn manual_amounts(
+    strategy: DeployStrategy,
+    strategy_params: &StrategyParams,
+    rotate: bool,
+    round_id: u64,
+) -> Option<[u64; 25]> {
+    match (strategy, strategy_params) {
+        (DeployStrategy::Manual, StrategyParams::Manual { amounts }) if rotate => {
+            Some(rotate_squares(amounts, round_id))
+        }
+        (DeployStrategy::Manual, StrategyParams::Manual { amounts }) => Some(*amounts),
+        _ => None,
+    }
+}
+
+/// EV bets for the round clamped to min_squares/max_squares and moved off
+/// square_blacklist, for an EV bot with any of them set. None otherwise, or
+/// when EV finds nothing to bet
+pub(crate) fn bounded_ev_amounts(
+    strategy: DeployStrategy,
+    strategy_params: &StrategyParams,
+    bankroll: u64,
+    slots_left: u64,
+    min_squares: u64,
+    max_squares: u64,
+    square_blacklist: &[u8],
+    round: &Round,
+) -> Option<[u64; 25]> {
+    if strategy != DeployStrategy::EV || (min_squares == 0 && max_squares == 0 && square_blacklist.is_empty()) {
+        return None;
+    }
+    let bets = preview_bets(strategy, strategy_params, None, bankroll, slots_left, round);
+    if bets.iter().all(|&b| b == 0) {
+        return None;
+    }
+    let bets = exclude_squares(&bets, &round.deployed, square_blacklist);
+    Some(clamp_square_count(&bets, &round.deployed, min_squares as usize, max_squares as usize))
+}
+
+/// Percentage bets over the configured `squares`, for a Percentage bot with
+/// a fixed square set. None otherwise, or when nothing on the set can be bought
+pub(crate) fn fixed_percentage_amounts(
+    strategy: DeployStrategy,
+    strategy_params: &StrategyParams,
+    bankroll: u64,
+    round: &Round,
+) -> Option<[u64; 25]> {
+    match (strategy, strategy_params) {
+        (DeployStrategy::Percentage, StrategyParams::Percentage { percentage, squares, .. }) if !squares.is_empty() => {
+            let bets = percentage_bets(&round.deployed, bankroll, *percentage, squares);
+            bets.iter().any(|&b| b > 0).then_some(bets)
+        }
+        _ => None,
+    }
+}
+
+/// The bet that makes a LeadSquare bot's square the most-deployed. None
+/// otherwise, when the square already leads, or when taking the lead costs
+/// more than the bankroll or max_per_square
+pub(crate) fn lead_square_amounts(
+    strategy: DeployStrategy,
+    strategy_params: &StrategyParams,
+    bankroll: u64,
+    round: &Round,
+) -> Option<[u64; 25]> {
+    match (strategy, strategy_params) {
+        (DeployStrategy::LeadSquare, StrategyParams::LeadSquare { square, max_per_square }) => {
+            let square = *square as usize;
+            let needed = amount_to_lead(&round.deployed, square, u64::MAX, u64::MAX);
+            let amount = amount_to_lead(&round.deployed, square, bankroll, *max_per_square);
+            if needed == 0 || amount < needed {
+                return None;
+            }
+            let mut bets = [0u64; 25];
+            bets[square] = amount;
+            Some(bets)
+        }
+        _ => None,
+    }
+}
+
 /// Build EV deploy params from values (for runtime config updates)
 fn build_ev_params_from_values(strategy_params: &StrategyParams, bankroll: u64, slots_left: u64) -> EvDeployParams {
     match strategy_params {
@@ -781,6 +1535,7 @@ fn build_ev_params_from_values(strategy_params: &StrategyParams, bankroll: u64,
                 ore_value: *ore_value,
                 slots_left,
                 attempts: 0,  // Will be set per-tx in deploy loop
+                memo: None,
             }
         }
         _ => EvDeployParams {
@@ -790,6 +1545,7 @@ fn build_ev_params_from_values(strategy_params: &StrategyParams, bankroll: u64,
             ore_value: 800_000_000,
             slots_left,
             attempts: 0,
+            memo: None,
         }
     }
 }
@@ -797,7 +1553,7 @@ fn build_ev_params_from_values(strategy_params: &StrategyParams, bankroll: u64,
 /// Build Percentage deploy params from values (for runtime config updates)
 fn build_percentage_params_from_values(strategy_params: &StrategyParams, bankroll: u64) -> PercentageDeployParams {
     match strategy_params {
-        StrategyParams::Percentage { percentage, squares_count } => {
+        StrategyParams::Percentage { percentage, squares_count, .. } => {
             PercentageDeployParams {
                 bankroll,
                 percentage: *percentage,
@@ -816,7 +1572,7 @@ fn build_percentage_params_from_values(strategy_params: &StrategyParams, bankrol
 
 /// Parse Evore program error codes into human-readable messages
 /// Error codes from program/src/error.rs
-fn parse_evore_error(err_str: &str) -> String {
+pub(crate) fn parse_evore_error(err_str: &str) -> String {
     // Extract custom error code if present
     if let Some(code) = extract_custom_error(err_str) {
         match code {
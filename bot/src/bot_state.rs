@@ -9,6 +9,8 @@
 
 use solana_sdk::signature::Signature;
 
+use crate::config::{DeployStrategy, StrategyParams};
+
 /// Bot phase in the round lifecycle
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BotPhase {
@@ -73,6 +75,10 @@ pub struct BotState {
     /// Last round where bot checkpointed
     pub last_checkpointed_round: Option<u64>,
     
+    /// Last round a deploy was started for - set before anything is sent, unlike
+    /// last_deployed_round which waits for confirmation (dedup guard)
+    pub deploy_claimed_round: Option<u64>,
+    
     /// Pending transaction signatures awaiting confirmation
     pub pending_signatures: Vec<Signature>,
     
@@ -94,6 +100,15 @@ pub struct BotState {
     /// Pre-checkpoint values for delta calculation
     pub pre_checkpoint_sol: u64,
     pub pre_checkpoint_ore: u64,
+    
+    /// Signer balance is below min_signer_balance (deploys held)
+    pub signer_balance_low: bool,
+    
+    /// Checkpointed rounds in a row without any reward (stop-loss counter)
+    pub consecutive_losses: u64,
+    
+    /// Strategy and parameters deploys use (None until the first sync)
+    pub strategy: Option<(DeployStrategy, StrategyParams)>,
 }
 
 impl Default for BotState {
@@ -105,6 +120,7 @@ impl Default for BotState {
             current_round_id: 0,
             last_deployed_round: None,
             last_checkpointed_round: None,
+            deploy_claimed_round: None,
             pending_signatures: Vec::new(),
             deployed_amount: 0,
             rounds_participated: 0,
@@ -117,6 +133,9 @@ impl Default for BotState {
             current_ore: 0,
             pre_checkpoint_sol: 0,
             pre_checkpoint_ore: 0,
+            signer_balance_low: false,
+            consecutive_losses: 0,
+            strategy: None,
         }
     }
 }
@@ -132,6 +151,46 @@ impl BotState {
         self.last_deployed_round == Some(round_id)
     }
 
+    /// Claim the deploy for a round before sending; false if this round was
+    /// already claimed, so a repeated trigger can't deploy twice.
+    /// `spam_mode` allows any number of deploys per round
+    pub fn claim_deploy(&mut self, round_id: u64, spam_mode: bool) -> bool {
+        if !spam_mode && self.deploy_claimed_round == Some(round_id) {
+            return false;
+        }
+        self.deploy_claimed_round = Some(round_id);
+        true
+    }
+    
+    /// Give back a claim when nothing was sent, so the round can be retried
+    pub fn release_deploy(&mut self, round_id: u64) {
+        if self.deploy_claimed_round == Some(round_id) {
+            self.deploy_claimed_round = None;
+        }
+    }
+
+    /// Adopt the configured strategy for `round_id`, returning the one to
+    /// deploy with and whether it just switched. A switch to another strategy
+    /// waits for the next round once a deploy for `round_id` was claimed, so
+    /// the round finishes under the strategy it started with
+    pub fn sync_strategy(
+        &mut self,
+        strategy: DeployStrategy,
+        params: &StrategyParams,
+        round_id: u64,
+    ) -> (DeployStrategy, StrategyParams, bool) {
+        match &self.strategy {
+            Some((active, active_params)) if *active != strategy && self.deploy_claimed_round == Some(round_id) => {
+                (*active, active_params.clone(), false)
+            }
+            current => {
+                let switched = current.as_ref().map_or(false, |(active, _)| *active != strategy);
+                self.strategy = Some((strategy, params.clone()));
+                (strategy, params.clone(), switched)
+            }
+        }
+    }
+
     /// Check if needs checkpoint for previous round
     pub fn needs_checkpoint(&self) -> bool {
         match (self.last_deployed_round, self.last_checkpointed_round) {
@@ -166,6 +225,9 @@ impl BotState {
         // Count as win if gained anything
         if sol_delta > 0 || ore_delta > 0 {
             self.rounds_won += 1;
+            self.consecutive_losses = 0;
+        } else {
+            self.consecutive_losses += 1;
         }
         
         // Update current values for P&L
@@ -227,6 +289,27 @@ impl BotState {
         }
     }
     
+    /// Apply a signer balance reading against the guard threshold (0 = disabled)
+    /// Returns the new low-balance state only when it changed
+    pub fn update_signer_balance(&mut self, balance: u64, min_signer_balance: u64) -> Option<bool> {
+        let low = min_signer_balance > 0 && balance < min_signer_balance;
+        if low == self.signer_balance_low {
+            return None;
+        }
+        self.signer_balance_low = low;
+        Some(low)
+    }
+    
+    /// Check the loss streak against max_consecutive_losses (0 = disabled)
+    /// Returns true once when the limit is hit and resets the streak
+    pub fn stop_loss_triggered(&mut self, max_consecutive_losses: u64) -> bool {
+        if max_consecutive_losses == 0 || self.consecutive_losses < max_consecutive_losses {
+            return false;
+        }
+        self.consecutive_losses = 0;
+        true
+    }
+    
     /// Check if reload is needed (and clear the flag)
     pub fn take_needs_reload(&mut self) -> bool {
         let needs = self.needs_reload;
@@ -247,6 +330,92 @@ mod tests {
         assert!(!state.needs_checkpoint());
     }
 
+    #[test]
+    fn test_deploy_dedup_guard() {
+        let mut state = BotState::new();
+        
+        // Two rapid triggers in the same round: only the first deploys
+        let deploys = (0..2).filter(|_| state.claim_deploy(7, false)).count();
+        assert_eq!(deploys, 1);
+        
+        // The next round can deploy again
+        assert!(state.claim_deploy(8, false));
+        
+        // Nothing sent - the round can be retried
+        state.release_deploy(8);
+        assert!(state.claim_deploy(8, false));
+        
+        // Releasing an older round keeps the current claim
+        state.release_deploy(7);
+        assert!(!state.claim_deploy(8, false));
+        
+        // Spam mode deploys on every trigger
+        let deploys = (0..3).filter(|_| state.claim_deploy(8, true)).count();
+        assert_eq!(deploys, 3);
+    }
+
+    #[test]
+    fn test_sync_strategy() {
+        let ev = StrategyParams::default();
+        let pct = StrategyParams::Percentage { percentage: 1000, squares_count: 5, squares: vec![] };
+        let mut state = BotState::new();
+        assert_eq!(state.sync_strategy(DeployStrategy::EV, &ev, 7).0, DeployStrategy::EV);
+        
+        // Round 7's deploy already started: it stays EV until round 8
+        assert!(state.claim_deploy(7, false));
+        let (strategy, params, switched) = state.sync_strategy(DeployStrategy::Percentage, &pct, 7);
+        assert_eq!((strategy, params.strategy(), switched), (DeployStrategy::EV, DeployStrategy::EV, false));
+        
+        let (strategy, params, switched) = state.sync_strategy(DeployStrategy::Percentage, &pct, 8);
+        assert_eq!((strategy, params.strategy(), switched), (DeployStrategy::Percentage, DeployStrategy::Percentage, true));
+        assert!(!state.sync_strategy(DeployStrategy::Percentage, &pct, 8).2);
+        
+        // Nothing claimed yet this round: the switch is immediate
+        assert!(state.sync_strategy(DeployStrategy::EV, &ev, 8).2);
+    }
+
+    #[test]
+    fn test_signer_balance_guard() {
+        let mut state = BotState::new();
+        
+        // Guard disabled
+        assert_eq!(state.update_signer_balance(0, 0), None);
+        
+        // Drops below threshold, then stays low
+        assert_eq!(state.update_signer_balance(5_000_000, 10_000_000), Some(true));
+        assert_eq!(state.update_signer_balance(4_000_000, 10_000_000), None);
+        assert!(state.signer_balance_low);
+        
+        // Refunded to exactly the threshold resumes
+        assert_eq!(state.update_signer_balance(10_000_000, 10_000_000), Some(false));
+        assert!(!state.signer_balance_low);
+    }
+
+    #[test]
+    fn test_stop_loss_streak() {
+        let mut state = BotState::new();
+        
+        // Two losses, a win resets the streak
+        for round in 1..=2 {
+            state.store_pre_checkpoint(0, 0);
+            state.process_checkpoint(round, 0, 0);
+        }
+        assert_eq!(state.consecutive_losses, 2);
+        assert!(!state.stop_loss_triggered(3));
+        state.store_pre_checkpoint(0, 0);
+        state.process_checkpoint(3, 1_000, 0);
+        assert_eq!(state.consecutive_losses, 0);
+        
+        // Three losses in a row trip the limit once
+        for round in 4..=6 {
+            state.store_pre_checkpoint(1_000, 0);
+            state.process_checkpoint(round, 1_000, 0);
+        }
+        assert!(!state.stop_loss_triggered(0));
+        assert!(state.stop_loss_triggered(3));
+        assert!(!state.stop_loss_triggered(3));
+    }
+
     #[test]
     fn test_deployment_tracking() {
         let mut state = BotState::new();
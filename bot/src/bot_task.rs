@@ -4,9 +4,11 @@
 //! - Spawned as tokio task, doesn't block TUI
 //! - Sends TuiUpdate messages via channel
 //! - Handles full lifecycle: checkpoint → wait → deploy → claim
+//! - Optional ramp: several single submissions across the round, each with a
+//!   larger `max_per_square` as the round fills (see `ramp_params`)
 
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use solana_sdk::{
     hash::Hash,
@@ -17,7 +19,7 @@ use solana_sdk::{
 use tokio::sync::mpsc;
 use tokio::time::sleep;
 
-use crate::client::EvoreClient;
+use crate::client::{EvoreClient, RoundState};
 use crate::deploy::{build_checkpoint_tx, build_claim_sol_tx, build_ev_deploy_tx, EvDeployParams};
 use crate::slot_tracker::SlotTracker;
 use crate::tui::{BotStatus, TuiUpdate, TxAction, TxType, TxStatus};
@@ -30,6 +32,123 @@ pub struct BotConfig {
     pub auth_id: u64,
     pub manager: Pubkey,
     pub params: EvDeployParams,
+    /// Fire at exactly `end_slot - target_slot_offset` using the slot scheduler
+    /// instead of polling the `slots_left` threshold
+    pub target_slot_offset: Option<u64>,
+    /// Deploy `ramp_submissions` times across the round instead of once at the
+    /// end, scaling `max_per_square` with how far the round has progressed
+    pub ramp: bool,
+    /// Number of ramp submissions per round (the last one at the deploy slot)
+    pub ramp_submissions: u32,
+}
+
+/// Minimum slot transitions observed before the scheduler trusts its estimate
+const MIN_CADENCE_SAMPLES: u32 = 4;
+/// Ignore slot gaps longer than this (WS hiccups, skipped updates)
+const MAX_SLOT_GAP: Duration = Duration::from_secs(2);
+/// Longest single sleep while waiting for the target slot, so board/slot
+/// state keeps refreshing between estimates
+const MAX_SCHEDULE_SLEEP: Duration = Duration::from_millis(500);
+
+/// Estimates wall-clock slot timing from observed slot transitions
+///
+/// Keeps an exponential moving average of the time per slot so the bot can
+/// sleep until the estimated start of a target slot instead of polling.
+#[derive(Debug, Default)]
+pub struct SlotScheduler {
+    last_slot: Option<(u64, Instant)>,
+    avg_slot_ms: Option<f64>,
+    samples: u32,
+}
+
+impl SlotScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a slot observation along with the time it was first seen
+    pub fn observe(&mut self, slot: u64, seen_at: Instant) {
+        match self.last_slot {
+            Some((prev_slot, prev_at)) if slot > prev_slot => {
+                let elapsed = seen_at.saturating_duration_since(prev_at);
+                if elapsed <= MAX_SLOT_GAP {
+                    let per_slot_ms = elapsed.as_secs_f64() * 1000.0 / (slot - prev_slot) as f64;
+                    self.avg_slot_ms = Some(match self.avg_slot_ms {
+                        Some(avg) => avg * 0.8 + per_slot_ms * 0.2,
+                        None => per_slot_ms,
+                    });
+                    self.samples = self.samples.saturating_add(1);
+                }
+                self.last_slot = Some((slot, seen_at));
+            }
+            Some((prev_slot, _)) if slot <= prev_slot => {}
+            _ => self.last_slot = Some((slot, seen_at)),
+        }
+    }
+
+    /// Average observed slot duration, if enough samples have been collected
+    pub fn slot_duration(&self) -> Option<Duration> {
+        if self.samples < MIN_CADENCE_SAMPLES {
+            return None;
+        }
+        self.avg_slot_ms
+            .filter(|ms| *ms > 0.0)
+            .map(|ms| Duration::from_secs_f64(ms / 1000.0))
+    }
+
+    /// Estimated wall-clock instant at which `target_slot` begins
+    ///
+    /// Returns None when slot timing data is unavailable, in which case
+    /// callers should fall back to threshold-based triggering.
+    pub fn estimate_slot_start(&self, target_slot: u64) -> Option<Instant> {
+        let slot_duration = self.slot_duration()?;
+        let (last_slot, last_at) = self.last_slot?;
+        let slots_ahead = target_slot.saturating_sub(last_slot) as u32;
+        Some(last_at + slot_duration * slots_ahead)
+    }
+}
+
+/// How many of `submissions` evenly spaced ramp points between `start_slot`
+/// and `deploy_slot` (the last one) have been reached at `current_slot`
+pub fn ramp_steps_due(start_slot: u64, deploy_slot: u64, current_slot: u64, submissions: u32) -> u32 {
+    let submissions = submissions.max(1);
+    let span = deploy_slot.saturating_sub(start_slot) as u128;
+    (1..=submissions)
+        .take_while(|k| {
+            let point = start_slot as u128 + span * *k as u128 / submissions as u128;
+            current_slot as u128 >= point
+        })
+        .count() as u32
+}
+
+/// Params for a ramp submission at `current_slot`
+///
+/// `max_per_square` scales with `1 - slots_remaining / round_length`, and the
+/// bankroll is whatever `spent` (already deployed this round) leaves of it.
+/// `slots_left` is the current slots remaining so the on-chain window check
+/// passes early in the round. None when nothing is left to deploy.
+pub fn ramp_params(
+    params: &EvDeployParams,
+    start_slot: u64,
+    end_slot: u64,
+    current_slot: u64,
+    spent: u64,
+    step: u32,
+) -> Option<EvDeployParams> {
+    let round_length = end_slot.saturating_sub(start_slot).max(1) as u128;
+    let elapsed = current_slot.clamp(start_slot, end_slot).saturating_sub(start_slot) as u128;
+    let max_per_square = (params.max_per_square as u128 * elapsed / round_length) as u64;
+    let bankroll = params.bankroll.saturating_sub(spent);
+    if bankroll < params.min_bet || max_per_square < params.min_bet {
+        return None;
+    }
+    Some(EvDeployParams {
+        bankroll,
+        max_per_square,
+        slots_left: end_slot.saturating_sub(current_slot).max(params.slots_left),
+        attempts: step as u64,
+        ..params.clone()
+    })
 }
 
 /// Run the bot deployment loop
@@ -69,7 +188,7 @@ pub async fn run_bot_task(
         let _ = tx.send(TuiUpdate::BoardUpdate(board.clone()));
         
         // Fetch and send round data
-        if let Ok(round) = client.get_round(board.round_id) {
+        if let Some(round) = client.get_round(board.round_id).ok().and_then(RoundState::active) {
             let _ = tx.send(TuiUpdate::RoundUpdate(round));
         }
         
@@ -119,6 +238,12 @@ pub async fn run_bot_task(
     // Track last round data fetch for periodic updates
     let mut last_round_fetch: Option<u64> = None;
     
+    // Slot cadence tracking for target_slot_offset scheduling
+    let mut scheduler = SlotScheduler::new();
+    
+    // Ramp submissions already sent, per round
+    let mut ramp_sent: (u64, u32) = (0, 0);
+    
     loop {
         // Get current state
         let board = match client.get_board() {
@@ -144,7 +269,7 @@ pub async fn run_bot_task(
         
         // Fetch round data periodically or when round changes
         if last_round_fetch != Some(board.round_id) {
-            if let Ok(round) = client.get_round(board.round_id) {
+            if let Some(round) = client.get_round(board.round_id).ok().and_then(RoundState::active) {
                 let _ = tx.send(TuiUpdate::RoundUpdate(round));
                 last_round_fetch = Some(board.round_id);
             }
@@ -341,7 +466,141 @@ pub async fn run_bot_task(
             }
         }
         
+        // === RAMP: SMALL EARLY, LARGER AS THE ROUND FILLS ===
+        // One single send per ramp point (never spammed, since each one is
+        // allowed to add to the round), sized against what's already deployed
+        if config.ramp {
+            let deploy_slot = board.end_slot.saturating_sub(config.params.slots_left);
+            let due = ramp_steps_due(board.start_slot, deploy_slot, current_slot, config.ramp_submissions);
+            if ramp_sent.0 != board.round_id {
+                ramp_sent = (board.round_id, 0);
+            }
+            if due <= ramp_sent.1 {
+                let _ = tx.send(TuiUpdate::BotStatusUpdate {
+                    bot_index: config.bot_index,
+                    status: BotStatus::Waiting,
+                });
+                sleep(Duration::from_millis(100)).await;
+                continue;
+            }
+            // Missed points collapse into one submission
+            ramp_sent.1 = due;
+            let final_step = due >= config.ramp_submissions.max(1);
+            
+            let miner = client.get_miner(&managed_miner_auth).ok().flatten()
+                .filter(|m| m.round_id == board.round_id);
+            let spent: u64 = miner.map(|m| m.deployed.iter().sum()).unwrap_or(0);
+            let params = match ramp_params(&config.params, board.start_slot, board.end_slot, current_slot, spent, due) {
+                Some(params) => params,
+                None => {
+                    if final_step {
+                        last_round_deployed = Some(board.round_id);
+                    }
+                    continue;
+                }
+            };
+            
+            let _ = tx.send(TuiUpdate::BotStatusUpdate {
+                bot_index: config.bot_index,
+                status: BotStatus::Deploying,
+            });
+            let bh = wait_for_blockhash_from_client(&client).await;
+            let deploy_tx = build_ev_deploy_tx(
+                &signer,
+                &config.manager,
+                config.auth_id,
+                board.round_id,
+                &params,
+                true,    // allow_multi_deploy - each ramp step adds to the round
+                bh,
+                5000,    // default priority fee
+                200_000, // default jito tip (0.0002 SOL)
+            );
+            match client.send_and_confirm_transaction(&deploy_tx).map_err(|e| e.to_string()) {
+                Ok(sig) => {
+                    if spent == 0 {
+                        rounds_participated += 1;
+                    }
+                    let _ = tx.send(TuiUpdate::TxEventTyped {
+                        bot_name: config.name.clone(),
+                        tx_type: TxType::Deploy,
+                        status: TxStatus::Confirmed,
+                        signature: sig,
+                        error: None,
+                        slot: Some(current_slot),
+                        round_id: Some(board.round_id),
+                        amount: Some(params.bankroll),
+                        attempt: Some(due as u64),
+                    });
+                    if let Some(miner) = client.get_miner(&managed_miner_auth).ok().flatten() {
+                        let _ = tx.send(TuiUpdate::BotDeployedUpdate {
+                            bot_index: config.bot_index,
+                            amount: miner.deployed.iter().sum(),
+                            round_id: board.round_id,
+                        });
+                        let _ = tx.send(TuiUpdate::BotMinerUpdate {
+                            bot_index: config.bot_index,
+                            miner,
+                        });
+                    }
+                }
+                Err(err_msg) => {
+                    let _ = tx.send(TuiUpdate::TxEventTyped {
+                        bot_name: config.name.clone(),
+                        tx_type: TxType::Deploy,
+                        status: TxStatus::Failed,
+                        signature: Signature::default(),
+                        error: Some(err_msg),
+                        slot: Some(current_slot),
+                        round_id: Some(board.round_id),
+                        amount: Some(params.bankroll),
+                        attempt: Some(due as u64),
+                    });
+                }
+            }
+            
+            if final_step {
+                last_round_deployed = Some(board.round_id);
+            }
+            continue;
+        }
+        
         // === WAIT FOR DEPLOY WINDOW ===
+        // Slot-accurate scheduling: sleep until the estimated start of
+        // end_slot - target_slot_offset, falling back to threshold polling below
+        let target_slot = config
+            .target_slot_offset
+            .map(|offset| board.end_slot.saturating_sub(offset));
+        if let Some(target_slot) = target_slot {
+            let seen_at = Instant::now()
+                .checked_sub(slot_tracker.time_since_last_slot())
+                .unwrap_or_else(Instant::now);
+            scheduler.observe(current_slot, seen_at);
+            if current_slot < target_slot {
+                if let Some(fire_at) = scheduler.estimate_slot_start(target_slot) {
+                    let _ = tx.send(TuiUpdate::BotStatusUpdate {
+                        bot_index: config.bot_index,
+                        status: BotStatus::Waiting,
+                    });
+                    let remaining = fire_at.saturating_duration_since(Instant::now());
+                    if remaining > MAX_SCHEDULE_SLEEP {
+                        sleep(MAX_SCHEDULE_SLEEP).await;
+                        continue;
+                    }
+                    // Close enough - sleep the rest and fire without re-polling
+                    sleep(remaining).await;
+                } else {
+                    // No slot timing yet - poll until the target slot is reached
+                    let _ = tx.send(TuiUpdate::BotStatusUpdate {
+                        bot_index: config.bot_index,
+                        status: BotStatus::Waiting,
+                    });
+                    sleep(Duration::from_millis(50)).await;
+                    continue;
+                }
+            }
+        }
+        
         // Calculate deploy window (same logic as single_deploy)
         // For single send (slots_left > 10), wait 1 extra slot to ensure on-chain check passes
         // let deploy_start_slot = config.params.slots_left > 10 {
@@ -356,7 +615,7 @@ pub async fn run_bot_task(
         //let wait_until_slot = deploy_start_slot.saturating_sub(1);
         let wait_until_slot = deploy_start_slot;
         
-        if current_slot < wait_until_slot {
+        if target_slot.is_none() && current_slot < wait_until_slot {
             let _ = tx.send(TuiUpdate::BotStatusUpdate {
                 bot_index: config.bot_index,
                 status: BotStatus::Waiting,
@@ -367,7 +626,7 @@ pub async fn run_bot_task(
         }
         
         // We're at or past wait_until_slot - wait 50ms to be mid-slot, then deploy
-        if current_slot == wait_until_slot {
+        if target_slot.is_none() && current_slot == wait_until_slot {
             sleep(Duration::from_millis(50)).await;
         }
         
@@ -379,9 +638,10 @@ pub async fn run_bot_task(
         
         // Determine send strategy based on slots_left
         // slots_left <= 2 → 100ms, slots_left <= 4 → 400ms, else single send
-        let send_interval_ms: u64 = if config.params.slots_left <= 2 {
+        let slots_left = config.target_slot_offset.unwrap_or(config.params.slots_left);
+        let send_interval_ms: u64 = if slots_left <= 2 {
             100 // Fast spam: every 100ms
-        } else if config.params.slots_left <= 4 {
+        } else if slots_left <= 4 {
             400 // Medium spam: every 400ms
         } else {
             0 // Single send, no spam
@@ -561,7 +821,7 @@ pub async fn run_bot_task(
                 }
                 
                 // Refresh round data to show updated deployments
-                if let Ok(round) = client.get_round(board.round_id) {
+                if let Some(round) = client.get_round(board.round_id).ok().and_then(RoundState::active) {
                     let _ = tx.send(TuiUpdate::RoundUpdate(round));
                 }
                 
@@ -592,3 +852,85 @@ async fn wait_for_blockhash_from_client(client: &EvoreClient) -> Hash {
         sleep(Duration::from_millis(50)).await;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scheduler_needs_samples_before_estimating() {
+        let mut scheduler = SlotScheduler::new();
+        let start = Instant::now();
+        for i in 0..MIN_CADENCE_SAMPLES as u64 {
+            scheduler.observe(100 + i, start + Duration::from_millis(400 * i));
+        }
+        // First observation only seeds the baseline
+        assert!(scheduler.estimate_slot_start(110).is_none());
+
+        let n = MIN_CADENCE_SAMPLES as u64;
+        scheduler.observe(100 + n, start + Duration::from_millis(400 * n));
+        assert_eq!(scheduler.slot_duration(), Some(Duration::from_millis(400)));
+
+        let fire_at = scheduler.estimate_slot_start(110 + n).unwrap();
+        let last_at = start + Duration::from_millis(400 * n);
+        assert_eq!(fire_at.duration_since(last_at), Duration::from_millis(4000));
+    }
+
+    #[test]
+    fn test_ramp_steps_due() {
+        // Points at 125, 150, 175, 200
+        assert_eq!(ramp_steps_due(100, 200, 100, 4), 0);
+        assert_eq!(ramp_steps_due(100, 200, 124, 4), 0);
+        assert_eq!(ramp_steps_due(100, 200, 125, 4), 1);
+        assert_eq!(ramp_steps_due(100, 200, 180, 4), 3);
+        assert_eq!(ramp_steps_due(100, 200, 200, 4), 4);
+        assert_eq!(ramp_steps_due(100, 200, 250, 4), 4);
+        // Zero submissions behaves like a single one at the deploy slot
+        assert_eq!(ramp_steps_due(100, 200, 199, 0), 0);
+        assert_eq!(ramp_steps_due(100, 200, 200, 0), 1);
+    }
+
+    #[test]
+    fn test_ramp_params_scale_and_bankroll() {
+        let params = EvDeployParams {
+            bankroll: 1_000_000,
+            max_per_square: 400_000,
+            min_bet: 10_000,
+            ore_value: 0,
+            slots_left: 2,
+            attempts: 0,
+            memo: None,
+        };
+
+        // A quarter of the way in: a quarter of max_per_square, full bankroll
+        let step = ramp_params(&params, 100, 200, 125, 0, 1).unwrap();
+        assert_eq!(step.max_per_square, 100_000);
+        assert_eq!(step.bankroll, 1_000_000);
+        assert_eq!(step.slots_left, 75);
+        assert_eq!(step.attempts, 1);
+
+        // Near the end: almost all of max_per_square, only what's left of the bankroll
+        let step = ramp_params(&params, 100, 200, 199, 700_000, 4).unwrap();
+        assert_eq!(step.max_per_square, 396_000);
+        assert_eq!(step.bankroll, 300_000);
+        assert_eq!(step.slots_left, 2);
+
+        // Bankroll spent, or too early for a minimum bet
+        assert!(ramp_params(&params, 100, 200, 199, 995_000, 4).is_none());
+        assert!(ramp_params(&params, 100, 200, 101, 0, 1).is_none());
+    }
+
+    #[test]
+    fn test_scheduler_ignores_gaps_and_stale_slots() {
+        let mut scheduler = SlotScheduler::new();
+        let start = Instant::now();
+        scheduler.observe(10, start);
+        // Long gap (e.g. WS reconnect) is not counted as a sample
+        scheduler.observe(11, start + Duration::from_secs(5));
+        // Repeated / older slots are ignored
+        scheduler.observe(11, start + Duration::from_secs(6));
+        scheduler.observe(9, start + Duration::from_secs(7));
+        assert_eq!(scheduler.samples, 0);
+        assert_eq!(scheduler.last_slot.map(|(slot, _)| slot), Some(11));
+    }
+}
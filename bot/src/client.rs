@@ -16,13 +16,100 @@ use solana_sdk::{
     transaction::{Transaction, TransactionError},
 };
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
-use evore::ore_api::{board_pda, miner_pda, round_pda, Board, Miner, Round, Treasury, TREASURY_ADDRESS};
-use evore::state::{managed_miner_auth_pda, Manager};
+use crate::rpc_headers;
+use evore::network::NetworkConfig;
+use evore::ore_api::{Board, Config, Miner, Round, Treasury};
+use evore::state::Manager;
 use steel::AccountDeserialize;
 
+/// Round account as read from chain
+#[derive(Debug, Clone, Copy)]
+pub enum RoundState {
+    /// Consistent round data for the requested round
+    Active(Round),
+    /// Account is mid-reset or not yet initialized for this round - its
+    /// numbers are transient and shouldn't be used
+    Resetting { round_id: u64 },
+}
+
+impl RoundState {
+    /// Classify raw round data fetched for `round_id`
+    ///
+    /// The account is treated as resetting when its id doesn't match the
+    /// requested round (e.g. still zeroed) or when the per-square deployments
+    /// don't add up to `total_deployed`.
+    pub fn from_account(round: Round, round_id: u64) -> Self {
+        let deployed_sum = round.deployed.iter().fold(0u64, |sum, d| sum.saturating_add(*d));
+        if round.id != round_id || deployed_sum != round.total_deployed {
+            RoundState::Resetting { round_id }
+        } else {
+            RoundState::Active(round)
+        }
+    }
+
+    /// Round data if it's safe to use
+    pub fn active(self) -> Option<Round> {
+        match self {
+            RoundState::Active(round) => Some(round),
+            RoundState::Resetting { .. } => None,
+        }
+    }
+}
+
+/// How long `get_round` answers a repeat read from memory (about one slot)
+const ROUND_CACHE_TTL: Duration = Duration::from_millis(400);
+/// Rounds `get_round` keeps cached
+const ROUND_CACHE_ENTRIES: usize = 4;
+
+/// Short-lived `get_round` results, keyed by round id
+///
+/// Only consistent (`Active`) reads are kept, for `ROUND_CACHE_TTL`, so a
+/// command that reads the same round several times makes one RPC call while
+/// an active round still refreshes every slot. A new round id from
+/// `get_board` clears it.
+#[derive(Debug, Default)]
+struct RoundCache {
+    /// (round_id, fetched_at, round), oldest first
+    entries: Mutex<Vec<(u64, Instant, Round)>>,
+    /// Round id the board last reported
+    board_round: AtomicU64,
+}
+
+impl RoundCache {
+    fn get(&self, round_id: u64, now: Instant) -> Option<Round> {
+        let entries = self.entries.lock().unwrap();
+        entries.iter()
+            .find(|(id, fetched_at, _)| *id == round_id && now.duration_since(*fetched_at) < ROUND_CACHE_TTL)
+            .map(|(_, _, round)| *round)
+    }
+
+    fn insert(&self, round_id: u64, state: RoundState, now: Instant) {
+        let RoundState::Active(round) = state else {
+            return;
+        };
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|(id, _, _)| *id != round_id);
+        if entries.len() == ROUND_CACHE_ENTRIES {
+            entries.remove(0);
+        }
+        entries.push((round_id, now, round));
+    }
+
+    /// Drop everything when the board moves to another round
+    fn on_board_round(&self, round_id: u64) {
+        if self.board_round.swap(round_id, Ordering::Relaxed) != round_id {
+            self.invalidate();
+        }
+    }
+
+    fn invalidate(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
 /// Transaction status result
 #[derive(Debug, Clone)]
 pub struct TxStatusResult {
@@ -121,39 +208,55 @@ impl Default for RpsTracker {
 pub struct EvoreClient {
     rpc: RpcClient,
     pub rps_tracker: Arc<RpsTracker>,
+    /// Program ids / mint used for account derivations (mainnet by default)
+    network: NetworkConfig,
+    round_cache: RoundCache,
 }
 
 impl EvoreClient {
     pub fn new(rpc_url: &str) -> Self {
-        let rpc = RpcClient::new_with_timeout_and_commitment(
-            rpc_url.to_string(),
+        let rpc = rpc_headers::rpc_client(
+            rpc_url,
             Duration::from_secs(30),
             CommitmentConfig::confirmed(),
         );
         Self { 
             rpc,
             rps_tracker: Arc::new(RpsTracker::new()),
+            network: NetworkConfig::default(),
+            round_cache: RoundCache::default(),
         }
     }
     
     /// Create with a shared RPS tracker (for sharing across multiple clients)
     pub fn new_with_tracker(rpc_url: &str, rps_tracker: Arc<RpsTracker>) -> Self {
-        let rpc = RpcClient::new_with_timeout_and_commitment(
-            rpc_url.to_string(),
+        let rpc = rpc_headers::rpc_client(
+            rpc_url,
             Duration::from_secs(30),
             CommitmentConfig::confirmed(),
         );
-        Self { rpc, rps_tracker }
+        Self { rpc, rps_tracker, network: NetworkConfig::default(), round_cache: RoundCache::default() }
     }
     
     /// Create with processed commitment (for blockhash fetching)
     pub fn new_processed(rpc_url: &str, rps_tracker: Arc<RpsTracker>) -> Self {
-        let rpc = RpcClient::new_with_timeout_and_commitment(
-            rpc_url.to_string(),
+        let rpc = rpc_headers::rpc_client(
+            rpc_url,
             Duration::from_secs(30),
             CommitmentConfig::processed(),
         );
-        Self { rpc, rps_tracker }
+        Self { rpc, rps_tracker, network: NetworkConfig::default(), round_cache: RoundCache::default() }
+    }
+    
+    /// Derive ORE/Evore accounts from a non-default network config
+    pub fn with_network(mut self, network: NetworkConfig) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// Network config used for account derivations
+    pub fn network(&self) -> &NetworkConfig {
+        &self.network
     }
     
     /// Get the RPS tracker for monitoring
@@ -211,12 +314,36 @@ impl EvoreClient {
         Ok(self.rpc.get_multiple_accounts(pubkeys)?)
     }
 
+    /// Recent prioritization fees (micro-lamports per CU) paid by
+    /// transactions writing the board, unsorted
+    pub fn get_recent_priority_fees(&self) -> Result<Vec<u64>, Box<dyn std::error::Error>> {
+        self.rps_tracker.record_request();
+        let (board_address, _) = self.network.board_pda();
+        Ok(self.rpc
+            .get_recent_prioritization_fees(&[board_address])?
+            .into_iter()
+            .map(|f| f.prioritization_fee)
+            .collect())
+    }
+
+    /// Median priority fee (micro-lamports per CU) landed in recent slots
+    /// for transactions writing the ORE board, None if there were none
+    pub fn get_median_priority_fee(&self) -> Result<Option<u64>, Box<dyn std::error::Error>> {
+        let mut fees = self.get_recent_priority_fees()?;
+        if fees.is_empty() {
+            return Ok(None);
+        }
+        fees.sort_unstable();
+        Ok(Some(fees[fees.len() / 2]))
+    }
+
     /// Send transaction without waiting for confirmation
     /// Skips preflight and sets 0 retries - we handle retries manually
     pub fn send_transaction_no_wait(
         &self,
         transaction: &Transaction,
     ) -> Result<Signature, Box<dyn std::error::Error>> {
+        crate::safe_mode::guard(transaction)?;
         self.rps_tracker.record_request();
         let config = RpcSendTransactionConfig {
             skip_preflight: true,
@@ -232,6 +359,7 @@ impl EvoreClient {
         &self,
         transaction: &Transaction,
     ) -> Result<Signature, Box<dyn std::error::Error>> {
+        crate::safe_mode::guard(transaction)?;
         self.rps_tracker.record_request();
         Ok(self.rpc.send_and_confirm_transaction(transaction)?)
     }
@@ -281,36 +409,65 @@ impl EvoreClient {
     /// Get board state (contains current round_id and end_slot)
     pub fn get_board(&self) -> Result<Board, Box<dyn std::error::Error>> {
         self.rps_tracker.record_request();
-        let board_address = board_pda().0;
+        let board_address = self.network.board_pda().0;
         let account = self.rpc.get_account(&board_address)?;
         
         // try_from_bytes handles discriminator
         let board = Board::try_from_bytes(&account.data)?;
+        self.round_cache.on_board_round(board.round_id);
         Ok(*board)
     }
 
-    /// Get round state
-    pub fn get_round(&self, round_id: u64) -> Result<Round, Box<dyn std::error::Error>> {
+    /// Get round state (`RoundState::Resetting` while the account is transient)
+    ///
+    /// Repeat reads of an active round within `ROUND_CACHE_TTL` come from memory.
+    pub fn get_round(&self, round_id: u64) -> Result<RoundState, Box<dyn std::error::Error>> {
+        if let Some(round) = self.round_cache.get(round_id, Instant::now()) {
+            return Ok(RoundState::Active(round));
+        }
+        
         self.rps_tracker.record_request();
-        let round_address = round_pda(round_id).0;
+        let round_address = self.network.round_pda(round_id).0;
         let account = self.rpc.get_account(&round_address)?;
         
         // try_from_bytes handles discriminator
         let round = Round::try_from_bytes(&account.data)?;
-        Ok(*round)
+        let state = RoundState::from_account(*round, round_id);
+        self.round_cache.insert(round_id, state, Instant::now());
+        Ok(state)
+    }
+    
+    /// Forget cached rounds so the next `get_round` reads the chain
+    pub fn invalidate_round_cache(&self) {
+        self.round_cache.invalidate();
     }
     
     /// Get Treasury account
     pub fn get_treasury(&self) -> Result<Treasury, Box<dyn std::error::Error>> {
         self.rps_tracker.record_request();
-        let account = self.rpc.get_account(&TREASURY_ADDRESS)?;
+        let account = self.rpc.get_account(&self.network.treasury_pda().0)?;
         let treasury = Treasury::try_from_bytes(&account.data)?;
         Ok(*treasury)
     }
 
+    /// Get ORE Config account
+    pub fn get_config(&self) -> Result<Config, Box<dyn std::error::Error>> {
+        self.rps_tracker.record_request();
+        let account = self.rpc.get_account(&self.network.config_pda().0)?;
+        let config = Config::try_from_bytes(&account.data)?;
+        Ok(*config)
+    }
+    
+    /// Check the static accounts deploys are built with against ORE's on-chain Config
+    pub fn check_static_accounts(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let config = self.get_config()?;
+        self.network.check_ore_config(&config)?;
+        Ok(())
+    }
+
     /// Get managed miner auth PDA address for a manager and auth_id
-    pub fn get_managed_miner_auth_address(manager: &Pubkey, auth_id: u64) -> (Pubkey, u8) {
-        managed_miner_auth_pda(*manager, auth_id)
+    pub fn get_managed_miner_auth_address(&self, manager: &Pubkey, auth_id: u64) -> (Pubkey, u8) {
+        self.network.managed_miner_auth_pda(*manager, auth_id)
     }
     
     /// Get Manager account data (returns None if account doesn't exist)
@@ -333,10 +490,26 @@ impl EvoreClient {
         }
     }
     
+    /// Verify the signer is the on-chain authority of the manager
+    /// Fails before any transaction is built instead of erroring on-chain
+    pub fn ensure_manager_authority(&self, manager_address: &Pubkey, signer: &Pubkey) -> Result<(), Box<dyn std::error::Error>> {
+        match self.get_manager(manager_address)? {
+            None => Err(format!(
+                "Manager account {} does not exist (run `create-manager` first)",
+                manager_address
+            ).into()),
+            Some(manager) if manager.authority != *signer => Err(format!(
+                "Signer {} is not the authority of manager {} (on-chain authority: {}). Use the keypair for {} as KEYPAIR_PATH",
+                signer, manager_address, manager.authority, manager.authority
+            ).into()),
+            Some(_) => Ok(()),
+        }
+    }
+    
     /// Get ORE Miner account for an authority (returns None if doesn't exist)
     pub fn get_miner(&self, authority: &Pubkey) -> Result<Option<Miner>, Box<dyn std::error::Error>> {
         self.rps_tracker.record_request();
-        let (miner_address, _) = miner_pda(*authority);
+        let (miner_address, _) = self.network.miner_pda(*authority);
         match self.rpc.get_account(&miner_address) {
             Ok(account) => {
                 let miner = Miner::try_from_bytes(&account.data)?;
@@ -356,7 +529,7 @@ impl EvoreClient {
     /// Get multiple Miner accounts at once (for miner tracker)
     pub fn get_miners(&self, authorities: &[Pubkey]) -> Result<Vec<Option<Miner>>, Box<dyn std::error::Error>> {
         let miner_addresses: Vec<Pubkey> = authorities.iter()
-            .map(|auth| miner_pda(*auth).0)
+            .map(|auth| self.network.miner_pda(*auth).0)
             .collect();
         
         self.rps_tracker.record_request();
@@ -371,11 +544,149 @@ impl EvoreClient {
 }
 
 /// Display helper for managed miner auth PDA
-pub fn print_managed_miner_info(manager: &Pubkey, auth_id: u64) {
-    let (pda, bump) = EvoreClient::get_managed_miner_auth_address(manager, auth_id);
+/// Max accounts per getMultipleAccounts request
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Balance and miner state for one managed miner auth_id
+#[derive(Debug, Clone)]
+pub struct ManagedMinerSummary {
+    pub auth_id: u64,
+    pub managed_miner_auth: Pubkey,
+    /// Lamports held by the managed_miner_auth PDA (0 if the account doesn't exist)
+    pub balance: u64,
+    /// ORE Miner account for this auth (None if it has never deployed)
+    pub miner: Option<Miner>,
+}
+
+impl EvoreClient {
+    /// Fetch balances and miner accounts for auth_ids 0..=max_auth_id under a manager
+    ///
+    /// Auth PDAs and their miner PDAs are fetched together via batched
+    /// getMultipleAccounts calls.
+    pub fn list_managed_miners(
+        &self,
+        manager: &Pubkey,
+        max_auth_id: u64,
+    ) -> Result<Vec<ManagedMinerSummary>, Box<dyn std::error::Error>> {
+        let auths: Vec<(u64, Pubkey)> = (0..=max_auth_id)
+            .map(|auth_id| (auth_id, self.network.managed_miner_auth_pda(*manager, auth_id).0))
+            .collect();
+
+        // Interleave [auth_pda, miner_pda] so each pair stays in the same batch
+        let addresses: Vec<Pubkey> = auths.iter()
+            .flat_map(|(_, auth)| [*auth, self.network.miner_pda(*auth).0])
+            .collect();
+
+        let mut accounts = Vec::with_capacity(addresses.len());
+        for chunk in addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            accounts.extend(self.get_multiple_accounts(chunk)?);
+        }
+
+        Ok(auths.into_iter()
+            .zip(accounts.chunks(2))
+            .map(|((auth_id, managed_miner_auth), pair)| ManagedMinerSummary {
+                auth_id,
+                managed_miner_auth,
+                balance: pair[0].as_ref().map(|a| a.lamports).unwrap_or(0),
+                miner: pair[1].as_ref()
+                    .and_then(|a| Miner::try_from_bytes(&a.data).ok().copied()),
+            })
+            .collect())
+    }
+
+    /// Miner accounts of (manager, auth_id) pairs, in order, read in batched
+    /// getMultipleAccounts calls (None if the miner doesn't exist)
+    pub fn get_managed_miners(
+        &self,
+        miners: &[(Pubkey, u64)],
+    ) -> Result<Vec<Option<Miner>>, Box<dyn std::error::Error>> {
+        let addresses: Vec<Pubkey> = miners.iter()
+            .map(|(manager, auth_id)| self.network.miner_pda(self.network.managed_miner_auth_pda(*manager, *auth_id).0).0)
+            .collect();
+
+        let mut accounts = Vec::with_capacity(addresses.len());
+        for chunk in addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            accounts.extend(self.get_multiple_accounts(chunk)?);
+        }
+
+        Ok(accounts.into_iter()
+            .map(|account| account.and_then(|a| Miner::try_from_bytes(&a.data).ok().copied()))
+            .collect())
+    }
+}
+
+pub fn print_managed_miner_info(client: &EvoreClient, manager: &Pubkey, auth_id: u64) {
+    let (pda, bump) = client.get_managed_miner_auth_address(manager, auth_id);
     println!("Manager:              {}", manager);
     println!("Auth ID:              {}", auth_id);
     println!("Managed Miner Auth:   {}", pda);
     println!("Bump:                 {}", bump);
     println!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use steel::Zeroable;
+
+    fn round(id: u64, deployed: &[(usize, u64)]) -> Round {
+        let mut round = Round::zeroed();
+        round.id = id;
+        for (square, amount) in deployed {
+            round.deployed[*square] = *amount;
+            round.total_deployed += amount;
+        }
+        round
+    }
+
+    #[test]
+    fn test_round_cache() {
+        let cache = RoundCache::default();
+        let now = Instant::now();
+        let active = RoundState::from_account(round(42, &[(0, 1_000)]), 42);
+
+        // Resetting reads are never cached
+        cache.insert(42, RoundState::Resetting { round_id: 42 }, now);
+        assert!(cache.get(42, now).is_none());
+
+        cache.insert(42, active, now);
+        assert_eq!(cache.get(42, now + Duration::from_millis(100)).map(|r| r.id), Some(42));
+        assert!(cache.get(42, now + ROUND_CACHE_TTL).is_none());
+        assert!(cache.get(43, now).is_none());
+
+        // Bounded: the oldest round falls out
+        for id in 100..100 + ROUND_CACHE_ENTRIES as u64 {
+            cache.insert(id, RoundState::from_account(round(id, &[]), id), now);
+        }
+        assert!(cache.get(42, now).is_none());
+        assert!(cache.get(100, now).is_some());
+
+        // A round transition clears it, the same round again doesn't
+        cache.on_board_round(100);
+        cache.insert(100, RoundState::from_account(round(100, &[]), 100), now);
+        cache.on_board_round(100);
+        assert!(cache.get(100, now).is_some());
+        cache.on_board_round(101);
+        assert!(cache.get(100, now).is_none());
+    }
+
+    #[test]
+    fn test_round_state_from_account() {
+        let active = round(42, &[(0, 1_000), (24, 5_000)]);
+        assert!(matches!(RoundState::from_account(active, 42), RoundState::Active(r) if r.id == 42));
+
+        // Empty round that was initialized for this id is fine
+        assert!(RoundState::from_account(round(42, &[]), 42).active().is_some());
+
+        // Zeroed account for a nonzero round
+        assert!(matches!(
+            RoundState::from_account(Round::zeroed(), 42),
+            RoundState::Resetting { round_id: 42 }
+        ));
+
+        // Squares don't add up to the total
+        let mut inconsistent = active;
+        inconsistent.deployed[3] = 7;
+        assert!(RoundState::from_account(inconsistent, 42).active().is_none());
+    }
+}
@@ -3,7 +3,15 @@
 //! Supports loading from TOML config file with per-bot keypair paths.
 
 use serde::{Deserialize, Serialize};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::signature::{read_keypair_file, Signer};
 use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::auth_group::AuthSplit;
+use crate::deploy_export::DeployExportConfig;
+use crate::display_units::DisplayConfig;
+use crate::tx_pipeline::{DEFAULT_ABSOLUTE_MAX_DEPLOY, DEFAULT_DUST_FLOOR};
 
 /// Deployment strategy for a bot
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -15,6 +23,9 @@ pub enum DeployStrategy {
     Percentage,
     /// Manual square selection
     Manual,
+    /// Just enough to make one square the most-deployed
+    #[serde(rename = "lead_square")]
+    LeadSquare,
 }
 
 impl Default for DeployStrategy {
@@ -23,6 +34,121 @@ impl Default for DeployStrategy {
     }
 }
 
+/// Where a bot's priority fee comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum PriorityFeeSource {
+    /// Always `priority_fee`
+    #[default]
+    Fixed,
+    /// The `percentile`-th (0-100) recent prioritization fee on the board,
+    /// clamped to `floor..=cap` (micro-lamports per CU)
+    Dynamic {
+        percentile: u8,
+        floor: u64,
+        cap: u64,
+    },
+}
+
+impl PriorityFeeSource {
+    /// Highest fee this source can pick, for funding checks
+    pub fn max_fee(&self, priority_fee: u64) -> u64 {
+        match self {
+            PriorityFeeSource::Fixed => priority_fee,
+            PriorityFeeSource::Dynamic { cap, .. } => *cap,
+        }
+    }
+}
+
+/// Which rounds a bot deploys in, by round_id
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundFilter {
+    /// Every round
+    #[default]
+    All,
+    /// Even round_ids only
+    Even,
+    /// Odd round_ids only
+    Odd,
+    /// Every k-th round (round_id divisible by k)
+    EveryN(u64),
+}
+
+impl RoundFilter {
+    /// Whether the bot deploys in `round_id`
+    pub fn matches(&self, round_id: u64) -> bool {
+        match self {
+            RoundFilter::All => true,
+            RoundFilter::Even => round_id % 2 == 0,
+            RoundFilter::Odd => round_id % 2 == 1,
+            RoundFilter::EveryN(k) => *k > 0 && round_id % k == 0,
+        }
+    }
+}
+
+impl std::fmt::Display for RoundFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoundFilter::All => write!(f, "all"),
+            RoundFilter::Even => write!(f, "even"),
+            RoundFilter::Odd => write!(f, "odd"),
+            RoundFilter::EveryN(k) => write!(f, "every_n({})", k),
+        }
+    }
+}
+
+/// Daily UTC window, written "HH:MM-HH:MM"
+///
+/// The end is exclusive and may be "24:00"; an end before the start wraps
+/// past midnight ("22:00-02:00"), and equal start and end cover the whole day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct HourWindow {
+    /// Minutes after midnight UTC
+    start: u32,
+    end: u32,
+}
+
+impl HourWindow {
+    /// Whether `minute` (minutes after midnight UTC) falls in the window
+    pub fn contains(&self, minute: u32) -> bool {
+        if self.start <= self.end && self.start != self.end {
+            (self.start..self.end).contains(&minute)
+        } else {
+            minute >= self.start || minute < self.end
+        }
+    }
+}
+
+impl TryFrom<String> for HourWindow {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let minute = |time: &str| -> Option<u32> {
+            let (h, m) = time.trim().split_once(':')?;
+            let (h, m): (u32, u32) = (h.parse().ok()?, m.parse().ok()?);
+            (m < 60 && (h < 24 || (h, m) == (24, 0))).then_some(h * 60 + m)
+        };
+        value.split_once('-')
+            .and_then(|(start, end)| Some(HourWindow { start: minute(start)?, end: minute(end)? }))
+            .filter(|w| w.start < 24 * 60)
+            .ok_or_else(|| format!("invalid active_hours window '{}', expected \"HH:MM-HH:MM\" (UTC)", value))
+    }
+}
+
+impl From<HourWindow> for String {
+    fn from(w: HourWindow) -> Self {
+        format!("{:02}:{:02}-{:02}:{:02}", w.start / 60, w.start % 60, w.end / 60, w.end % 60)
+    }
+}
+
+/// Whether `unix_secs` falls in any of `windows` (no windows = always)
+pub fn in_active_hours(windows: &[HourWindow], unix_secs: u64) -> bool {
+    let minute = (unix_secs % 86_400 / 60) as u32;
+    windows.is_empty() || windows.iter().any(|w| w.contains(minute))
+}
+
 /// Strategy-specific parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
@@ -41,13 +167,46 @@ pub enum StrategyParams {
         /// Percentage in basis points (1000 = 10%)
         percentage: u64,
         /// Number of squares to deploy to
+        #[serde(default)]
         squares_count: u64,
+        /// Deploy to exactly these squares (0-24) instead of `squares_count`
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        squares: Vec<u8>,
     },
     /// Manual strategy parameters
     Manual {
         /// Exact amounts to deploy per square
         amounts: [u64; 25],
     },
+    /// LeadSquare strategy parameters
+    #[serde(rename = "lead_square")]
+    LeadSquare {
+        /// Square (0-24) to make the most-deployed
+        square: u8,
+        /// Most to put on it in one round (lamports); rounds that need more are skipped
+        max_per_square: u64,
+    },
+}
+
+impl StrategyParams {
+    /// Strategy these parameters belong to
+    pub fn strategy(&self) -> DeployStrategy {
+        match self {
+            StrategyParams::EV { .. } => DeployStrategy::EV,
+            StrategyParams::Percentage { .. } => DeployStrategy::Percentage,
+            StrategyParams::Manual { .. } => DeployStrategy::Manual,
+            StrategyParams::LeadSquare { .. } => DeployStrategy::LeadSquare,
+        }
+    }
+    
+    /// Squares a Percentage bot deploys to: the `squares` list when set, else `squares_count`
+    pub fn percentage_squares_count(&self) -> u64 {
+        match self {
+            StrategyParams::Percentage { squares, .. } if !squares.is_empty() => squares.len() as u64,
+            StrategyParams::Percentage { squares_count, .. } => *squares_count,
+            _ => 0,
+        }
+    }
 }
 
 impl Default for StrategyParams {
@@ -69,6 +228,17 @@ pub struct BotConfig {
     /// Auth ID for this bot's managed miner
     pub auth_id: u64,
     
+    /// Deploy through these auth_ids (one managed miner each) instead of
+    /// `auth_id`, which still names the bot (default empty = just `auth_id`)
+    #[serde(default)]
+    pub auth_ids: Vec<u64>,
+    
+    /// How a round is spread over `auth_ids`: `round_robin` deploys it all
+    /// through the next auth_id each round, `split` sends one deploy per
+    /// auth_id with an even share of the bets (default round_robin)
+    #[serde(default)]
+    pub auth_split: AuthSplit,
+    
     /// Deployment strategy
     #[serde(default)]
     pub strategy: DeployStrategy,
@@ -77,17 +247,40 @@ pub struct BotConfig {
     #[serde(default = "default_slots_left")]
     pub slots_left: u64,
     
+    /// Learn `slots_left` from recent round lengths and how long deploys take
+    /// to land, using `slots_left` until a deploy has landed (default false)
+    #[serde(default)]
+    pub auto_slots_left: bool,
+    
+    /// auto_slots_left: slots before the last deployable slot a deploy should
+    /// land (default 0 = the last deployable slot)
+    #[serde(default)]
+    pub auto_slots_left_target: u64,
+    
     /// Bankroll for this bot (lamports)
     pub bankroll: u64,
     
     /// Number of deploy transactions to send (default 4)
-    #[serde(default = "default_attempts")]
+    #[serde(default = "default_attempts", alias = "max_attempts")]
     pub attempts: u64,
     
+    /// Milliseconds between deploy attempts (default 400)
+    #[serde(default = "default_retry_delay_ms")]
+    pub retry_delay_ms: u64,
+    
+    /// Recent blockhashes each attempt is signed against (default 2, max 3)
+    #[serde(default = "default_blockhashes")]
+    pub blockhashes: u64,
+    
     /// Priority fee in micro-lamports per CU (default 5000 = ~0.000007 SOL @ 1.4M CU)
     #[serde(default = "default_priority_fee")]
     pub priority_fee: u64,
     
+    /// Fixed (`priority_fee`) or dynamic from getRecentPrioritizationFees
+    /// (default fixed; a dynamic bot falls back to `priority_fee` if the fetch fails)
+    #[serde(default)]
+    pub priority_fee_source: PriorityFeeSource,
+    
     /// Jito tip in lamports (default 200_000 = 0.0002 SOL, 0 to disable)
     #[serde(default = "default_jito_tip")]
     pub jito_tip: u64,
@@ -96,6 +289,81 @@ pub struct BotConfig {
     #[serde(default)]
     pub paused_on_startup: bool,
     
+    /// Hold deploys while the signer (fee payer) balance is below this many
+    /// lamports; resumes once refunded (default 0 = disabled)
+    #[serde(default)]
+    pub min_signer_balance: u64,
+    
+    /// Milliseconds to show the computed bets in the TUI before submitting,
+    /// giving a chance to veto (default 0 = submit immediately)
+    #[serde(default)]
+    pub preview_ms: u64,
+    
+    /// Pause the bot after this many checkpointed rounds in a row without
+    /// any reward (default 0 = disabled)
+    #[serde(default)]
+    pub max_consecutive_losses: u64,
+    
+    /// Shift the manual-strategy squares by a per-round offset so the same
+    /// positions aren't played every round (default false)
+    #[serde(default)]
+    pub rotate_squares: bool,
+    
+    /// EV strategy: spread the round's bets over at least this many squares,
+    /// best-EV squares first (default 0 = no floor)
+    #[serde(default)]
+    pub min_squares: u64,
+    
+    /// EV strategy: concentrate the round's bets on at most this many squares,
+    /// best-EV squares first (default 0 = no ceiling)
+    #[serde(default)]
+    pub max_squares: u64,
+    
+    /// EV strategy: never deploy to these squares (0-24); their bets move to
+    /// the next-best allowed squares (default empty)
+    #[serde(default)]
+    pub square_blacklist: Vec<u8>,
+    
+    /// Strict last-slot mode: only send once at most `hard_last_slots` slots
+    /// remain and slot timing says the tx can still land in-round, otherwise
+    /// skip the round instead of deploying early (default false)
+    #[serde(default)]
+    pub require_last_slot: bool,
+    
+    /// Deploy window for `require_last_slot`, in slots before round end (default 2)
+    #[serde(default = "default_hard_last_slots")]
+    pub hard_last_slots: u64,
+    
+    /// Deploy-ahead mode: build and sign the deploy a couple of slots before
+    /// the trigger and submit the signed bytes when it fires (default false)
+    #[serde(default)]
+    pub presign: bool,
+    
+    /// Let the bot start another deploy in a round it already sent one for,
+    /// instead of deploying at most once per round (default false)
+    #[serde(default)]
+    pub spam_mode: bool,
+    
+    /// Hard ceiling on a round's deploy in lamports, checked before signing;
+    /// a deploy above it is refused and the bot paused (default 1 SOL)
+    #[serde(default = "default_absolute_max_deploy")]
+    pub absolute_max_deploy: u64,
+    
+    /// Skip a round whose expected deploy total is zero or below this many
+    /// lamports, instead of paying fees for a useless deploy (default 10_000)
+    #[serde(default = "default_dust_floor")]
+    pub dust_floor: u64,
+    
+    /// Only deploy in matching rounds: "all", "even", "odd" or
+    /// `{ every_n = k }`; other rounds are skipped (default "all")
+    #[serde(default)]
+    pub round_filter: RoundFilter,
+    
+    /// Only deploy during these daily UTC windows, e.g. ["13:00-21:00"];
+    /// checkpoints and claims keep running outside them (default empty = always)
+    #[serde(default)]
+    pub active_hours: Vec<HourWindow>,
+    
     /// Strategy-specific parameters
     #[serde(default)]
     pub strategy_params: StrategyParams,
@@ -111,10 +379,30 @@ fn default_slots_left() -> u64 {
     2
 }
 
+fn default_hard_last_slots() -> u64 {
+    2
+}
+
+fn default_absolute_max_deploy() -> u64 {
+    DEFAULT_ABSOLUTE_MAX_DEPLOY
+}
+
+fn default_dust_floor() -> u64 {
+    DEFAULT_DUST_FLOOR
+}
+
+fn default_retry_delay_ms() -> u64 {
+    400
+}
+
 fn default_attempts() -> u64 {
     4
 }
 
+fn default_blockhashes() -> u64 {
+    2
+}
+
 fn default_priority_fee() -> u64 {
     5000  // ~0.000007 SOL @ 1.4M CU (above Helius SWQOS minimum)
 }
@@ -136,13 +424,35 @@ impl BotConfig {
         Self {
             name: name.into(),
             auth_id,
+            auth_ids: Vec::new(),
+            auth_split: AuthSplit::RoundRobin,
             strategy: DeployStrategy::EV,
             slots_left: 2,
+            auto_slots_left: false,
+            auto_slots_left_target: 0,
             bankroll,
             attempts: 4,
+            retry_delay_ms: default_retry_delay_ms(),
+            blockhashes: 2,
             priority_fee: 5000,
+            priority_fee_source: PriorityFeeSource::Fixed,
             jito_tip: 200_000,
             paused_on_startup: false,
+            min_signer_balance: 0,
+            preview_ms: 0,
+            max_consecutive_losses: 0,
+            rotate_squares: false,
+            min_squares: 0,
+            max_squares: 0,
+            square_blacklist: Vec::new(),
+            require_last_slot: false,
+            hard_last_slots: 2,
+            presign: false,
+            spam_mode: false,
+            absolute_max_deploy: DEFAULT_ABSOLUTE_MAX_DEPLOY,
+            dust_floor: DEFAULT_DUST_FLOOR,
+            round_filter: RoundFilter::All,
+            active_hours: Vec::new(),
             strategy_params: StrategyParams::EV {
                 max_per_square,
                 min_bet,
@@ -158,6 +468,15 @@ impl BotConfig {
     pub fn get_display_name(&self) -> String {
         format!("{} (auth_id={})", self.name, self.auth_id)
     }
+    
+    /// The auth_ids this bot deploys through (`auth_ids`, or just `auth_id`)
+    pub fn auth_group(&self) -> Vec<u64> {
+        if self.auth_ids.is_empty() {
+            vec![self.auth_id]
+        } else {
+            self.auth_ids.clone()
+        }
+    }
 }
 
 /// Manage command configuration
@@ -168,6 +487,58 @@ pub struct ManageConfig {
     
     /// Secondary/legacy program ID for claim-only operations
     pub secondary_program_id: Option<String>,
+    
+    /// Commitment a transaction must reach before it's reported complete
+    #[serde(default)]
+    pub commitment: ManageCommitment,
+}
+
+/// Commitment level the manage TUI waits for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ManageCommitment {
+    Processed,
+    #[default]
+    Confirmed,
+    /// Can't be rolled back
+    Finalized,
+}
+
+impl ManageCommitment {
+    pub const ALL: [ManageCommitment; 3] = [Self::Processed, Self::Confirmed, Self::Finalized];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Processed => "processed",
+            Self::Confirmed => "confirmed",
+            Self::Finalized => "finalized",
+        }
+    }
+
+    pub fn commitment_config(&self) -> CommitmentConfig {
+        match self {
+            Self::Processed => CommitmentConfig::processed(),
+            Self::Confirmed => CommitmentConfig::confirmed(),
+            Self::Finalized => CommitmentConfig::finalized(),
+        }
+    }
+
+    /// How long to wait for it (finalization takes ~32 slots)
+    pub fn timeout(&self) -> Duration {
+        match self {
+            Self::Finalized => Duration::from_secs(60),
+            _ => Duration::from_secs(30),
+        }
+    }
+
+    /// Next level, wrapping around (for the TUI toggle)
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Processed => Self::Confirmed,
+            Self::Confirmed => Self::Finalized,
+            Self::Finalized => Self::Processed,
+        }
+    }
 }
 
 impl ManageConfig {
@@ -195,6 +566,24 @@ pub struct Config {
     /// Manage command configuration
     #[serde(default)]
     pub manage: ManageConfig,
+    
+    /// Append every deploy attempt to this JSON-lines file (disabled if unset)
+    #[serde(default)]
+    pub audit_log_path: Option<PathBuf>,
+    
+    /// Write every deploy attempt and its round outcome to ClickHouse (disabled if unset)
+    #[serde(default)]
+    pub deploy_export: Option<DeployExportConfig>,
+    
+    /// How the dashboard shows amounts
+    #[serde(default)]
+    pub display: DisplayConfig,
+    
+    /// Most the bots may deploy together in one round (lamports). When their
+    /// planned stakes add up to more, every bot's bets are scaled down by the
+    /// same factor (unset = no cap)
+    #[serde(default)]
+    pub fleet_max_per_round: Option<u64>,
 }
 
 fn default_signer_path() -> PathBuf {
@@ -214,6 +603,10 @@ impl Default for Config {
             default_manager_path: default_manager_path(),
             bots: Vec::new(),
             manage: ManageConfig::default(),
+            audit_log_path: None,
+            deploy_export: None,
+            display: DisplayConfig::default(),
+            fleet_max_per_round: None,
         }
     }
 }
@@ -223,9 +616,86 @@ impl Config {
     pub fn load(path: &std::path::Path) -> Result<Self, Box<dyn std::error::Error>> {
         let contents = std::fs::read_to_string(path)?;
         let config: Config = toml::from_str(&contents)?;
+        config.validate()?;
+        config.validate_miners(|bot| {
+            let path = config.get_manager_path(bot);
+            read_keypair_file(&path)
+                .map(|keypair| keypair.pubkey().to_string())
+                // Missing files are reported when the bot starts; compare by path until then
+                .unwrap_or_else(|_| path.display().to_string())
+        })?;
         Ok(config)
     }
 
+    /// Reject two bots deploying through the same managed miner
+    ///
+    /// `manager_of` resolves a bot's manager (from its keypair file); bots
+    /// racing on one (manager, auth_id) break each other's round tracking.
+    pub fn validate_miners(&self, manager_of: impl Fn(&BotConfig) -> String) -> Result<(), String> {
+        let mut seen: Vec<(String, u64, &str)> = Vec::new();
+        for bot in &self.bots {
+            let manager = manager_of(bot);
+            for auth_id in bot.auth_group() {
+                if let Some((_, _, first)) = seen.iter().find(|(m, id, _)| *m == manager && *id == auth_id) {
+                    return Err(format!(
+                        "bots '{}' and '{}' both deploy through manager {} auth_id {}",
+                        first, bot.name, manager, auth_id,
+                    ));
+                }
+                seen.push((manager.clone(), auth_id, &bot.name));
+            }
+        }
+        Ok(())
+    }
+
+    /// Reject settings serde accepts but the bot can't use
+    pub fn validate(&self) -> Result<(), String> {
+        if self.fleet_max_per_round == Some(0) {
+            return Err("fleet_max_per_round must be greater than 0".to_string());
+        }
+        for bot in &self.bots {
+            if let Some(square) = bot.square_blacklist.iter().find(|&&s| s > 24) {
+                return Err(format!("bot '{}': square_blacklist has square {}, expected 0-24", bot.name, square));
+            }
+            if bot.round_filter == RoundFilter::EveryN(0) {
+                return Err(format!("bot '{}': round_filter every_n must be at least 1", bot.name));
+            }
+            if let Some((i, id)) = bot.auth_ids.iter().enumerate().find(|(i, id)| bot.auth_ids[..*i].contains(id)) {
+                return Err(format!("bot '{}': auth_ids lists {} twice (position {})", bot.name, id, i));
+            }
+            if let StrategyParams::Percentage { squares_count, squares, .. } = &bot.strategy_params {
+                if let Some(square) = squares.iter().find(|&&s| s > 24) {
+                    return Err(format!("bot '{}': squares has square {}, expected 0-24", bot.name, square));
+                }
+                if let Some((i, square)) = squares.iter().enumerate().find(|(i, s)| squares[..*i].contains(s)) {
+                    return Err(format!("bot '{}': squares lists {} twice (position {})", bot.name, square, i));
+                }
+                if squares.is_empty() && *squares_count == 0 {
+                    return Err(format!("bot '{}': percentage strategy needs squares_count or squares", bot.name));
+                }
+            }
+            if let StrategyParams::LeadSquare { square, .. } = &bot.strategy_params {
+                if *square > 24 {
+                    return Err(format!("bot '{}': lead_square square is {}, expected 0-24", bot.name, square));
+                }
+            }
+        }
+        Ok(())
+    }
+    
+    /// Settings that load fine but probably aren't what was meant
+    pub fn warnings(&self) -> Vec<String> {
+        self.bots.iter()
+            .filter_map(|bot| match &bot.strategy_params {
+                StrategyParams::Percentage { squares_count, squares, .. } if !squares.is_empty() && *squares_count > 0 => Some(format!(
+                    "bot '{}': both squares and squares_count are set, deploying to the {} listed squares (squares_count = {} is ignored)",
+                    bot.name, squares.len(), squares_count,
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Get the signer path for a bot (falls back to default)
     pub fn get_signer_path(&self, bot: &BotConfig) -> PathBuf {
         bot.signer_path
@@ -239,6 +709,65 @@ impl Config {
             .clone()
             .unwrap_or_else(|| self.default_manager_path.clone())
     }
+
+    /// Enable square rotation on every bot (`--rotate-squares`)
+    pub fn force_rotate_squares(&mut self) {
+        for bot in &mut self.bots {
+            bot.rotate_squares = true;
+        }
+    }
+}
+
+/// Configs a bot has run with this session, newest last, so a bad reload can
+/// be reverted from the dashboard
+///
+/// Versions count up from 1 (the startup config); reverting goes back to the
+/// previous version and a later reload gets a new number.
+#[derive(Debug, Clone)]
+pub struct ConfigHistory {
+    entries: Vec<(u32, BotConfig)>,
+    next_version: u32,
+}
+
+impl ConfigHistory {
+    /// Versions kept, including the active one
+    pub const MAX_LEN: usize = 5;
+
+    pub fn new(initial: BotConfig) -> Self {
+        Self { entries: vec![(1, initial)], next_version: 2 }
+    }
+
+    /// Version of the config the bot runs with
+    pub fn current_version(&self) -> u32 {
+        self.entries.last().map_or(0, |(version, _)| *version)
+    }
+
+    /// Record a newly applied config and return its version
+    pub fn push(&mut self, config: BotConfig) -> u32 {
+        let version = self.next_version;
+        self.next_version += 1;
+        self.entries.push((version, config));
+        if self.entries.len() > Self::MAX_LEN {
+            self.entries.remove(0);
+        }
+        version
+    }
+
+    /// Config a revert would go back to
+    pub fn previous(&self) -> Option<(u32, &BotConfig)> {
+        let i = self.entries.len().checked_sub(2)?;
+        let (version, config) = &self.entries[i];
+        Some((*version, config))
+    }
+
+    /// Drop the active config once its predecessor has been re-applied
+    pub fn revert(&mut self) -> Option<u32> {
+        if self.entries.len() < 2 {
+            return None;
+        }
+        self.entries.pop();
+        Some(self.current_version())
+    }
 }
 
 #[cfg(test)]
@@ -253,6 +782,159 @@ mod tests {
         assert_eq!(config.strategy, DeployStrategy::EV);
     }
 
+    #[test]
+    fn test_manage_commitment_parse() {
+        let config: Config = toml::from_str("[manage]\nsigners_path = \"./signers\"\ncommitment = \"finalized\"\n").unwrap();
+        assert_eq!(config.manage.commitment, ManageCommitment::Finalized);
+
+        // Defaults to confirmed
+        let config: Config = toml::from_str("[manage]\nsigners_path = \"./signers\"\n").unwrap();
+        assert_eq!(config.manage.commitment, ManageCommitment::Confirmed);
+        assert!(ManageCommitment::Finalized > ManageCommitment::Confirmed);
+    }
+
+    #[test]
+    fn test_percentage_squares() {
+        let bot = "[[bots]]\nname = \"a\"\nauth_id = 1\nbankroll = 1\n[bots.strategy_params]\ntype = \"percentage\"\npercentage = 1000\n";
+        let config: Config = toml::from_str(&format!("{bot}squares = [3, 9, 12]\n")).unwrap();
+        assert!(config.validate().is_ok());
+        assert!(config.warnings().is_empty());
+        assert_eq!(config.bots[0].strategy_params.percentage_squares_count(), 3);
+
+        // Both given: the list wins, with a warning
+        let config: Config = toml::from_str(&format!("{bot}squares_count = 5\nsquares = [3, 9]\n")).unwrap();
+        assert_eq!(config.bots[0].strategy_params.percentage_squares_count(), 2);
+        assert_eq!(config.warnings().len(), 1);
+
+        let config: Config = toml::from_str(&format!("{bot}squares_count = 5\n")).unwrap();
+        assert_eq!(config.bots[0].strategy_params.percentage_squares_count(), 5);
+        assert!(config.warnings().is_empty());
+
+        let config: Config = toml::from_str(bot).unwrap();
+        assert_eq!(config.validate().unwrap_err(), "bot 'a': percentage strategy needs squares_count or squares");
+        let config: Config = toml::from_str(&format!("{bot}squares = [25]\n")).unwrap();
+        assert_eq!(config.validate().unwrap_err(), "bot 'a': squares has square 25, expected 0-24");
+        let config: Config = toml::from_str(&format!("{bot}squares = [4, 4]\n")).unwrap();
+        assert_eq!(config.validate().unwrap_err(), "bot 'a': squares lists 4 twice (position 1)");
+    }
+
+    #[test]
+    fn test_square_blacklist_validation() {
+        let bot = "[[bots]]\nname = \"a\"\nauth_id = 1\nbankroll = 1\n";
+        let config: Config = toml::from_str(&format!("{bot}square_blacklist = [0, 24]\n")).unwrap();
+        assert_eq!(config.bots[0].square_blacklist, vec![0, 24]);
+        assert!(config.validate().is_ok());
+
+        let config: Config = toml::from_str(&format!("{bot}square_blacklist = [25]\n")).unwrap();
+        assert_eq!(config.validate().unwrap_err(), "bot 'a': square_blacklist has square 25, expected 0-24");
+    }
+
+    #[test]
+    fn test_round_filter() {
+        assert!((0..4).all(|id| RoundFilter::All.matches(id)));
+        assert_eq!((0..6).filter(|&id| RoundFilter::Even.matches(id)).collect::<Vec<_>>(), vec![0, 2, 4]);
+        assert_eq!((0..6).filter(|&id| RoundFilter::Odd.matches(id)).collect::<Vec<_>>(), vec![1, 3, 5]);
+        assert_eq!((0..10).filter(|&id| RoundFilter::EveryN(3).matches(id)).collect::<Vec<_>>(), vec![0, 3, 6, 9]);
+        assert!((0..4).all(|id| RoundFilter::EveryN(1).matches(id)));
+        assert!(!RoundFilter::EveryN(0).matches(0));
+        assert_eq!(RoundFilter::EveryN(4).to_string(), "every_n(4)");
+
+        let bot = "[[bots]]\nname = \"a\"\nauth_id = 1\nbankroll = 1\n";
+        let config: Config = toml::from_str(bot).unwrap();
+        assert_eq!(config.bots[0].round_filter, RoundFilter::All);
+        let config: Config = toml::from_str(&format!("{bot}round_filter = \"odd\"\n")).unwrap();
+        assert_eq!(config.bots[0].round_filter, RoundFilter::Odd);
+        let config: Config = toml::from_str(&format!("{bot}round_filter = {{ every_n = 3 }}\n")).unwrap();
+        assert_eq!(config.bots[0].round_filter, RoundFilter::EveryN(3));
+        assert!(config.validate().is_ok());
+
+        let config: Config = toml::from_str(&format!("{bot}round_filter = {{ every_n = 0 }}\n")).unwrap();
+        assert_eq!(config.validate().unwrap_err(), "bot 'a': round_filter every_n must be at least 1");
+    }
+
+    #[test]
+    fn test_validate_miners() {
+        let config: Config = toml::from_str(concat!(
+            "[[bots]]\nname = \"a\"\nauth_id = 0\nbankroll = 1\nmanager_path = \"m1.json\"\n",
+            "[[bots]]\nname = \"b\"\nauth_id = 1\nbankroll = 1\nmanager_path = \"m1.json\"\n",
+            "[[bots]]\nname = \"c\"\nauth_id = 0\nbankroll = 1\nmanager_path = \"m2.json\"\n",
+        )).unwrap();
+        let manager_of = |bot: &BotConfig| config.get_manager_path(bot).display().to_string();
+        assert!(config.validate_miners(manager_of).is_ok());
+
+        // Same keypair under a different path still collides
+        assert_eq!(
+            config.validate_miners(|_| "M".to_string()).unwrap_err(),
+            "bots 'a' and 'c' both deploy through manager M auth_id 0",
+        );
+
+        // An auth group overlapping another bot's auth_id
+        let mut config = config.clone();
+        config.bots[2].manager_path = Some(PathBuf::from("m1.json"));
+        config.bots[2].auth_ids = vec![2, 1];
+        assert_eq!(
+            config.validate_miners(|bot| config.get_manager_path(bot).display().to_string()).unwrap_err(),
+            "bots 'b' and 'c' both deploy through manager m1.json auth_id 1",
+        );
+    }
+
+    #[test]
+    fn test_active_hours() {
+        let window = |s: &str| HourWindow::try_from(s.to_string()).unwrap();
+        let day = window("09:30-17:00");
+        assert!(!day.contains(9 * 60 + 29));
+        assert!(day.contains(9 * 60 + 30));
+        assert!(day.contains(16 * 60 + 59));
+        assert!(!day.contains(17 * 60));
+
+        // Overnight ranges wrap past midnight
+        let night = window("22:00-02:00");
+        assert!(night.contains(22 * 60));
+        assert!(night.contains(23 * 60 + 59));
+        assert!(night.contains(0));
+        assert!(night.contains(60 + 59));
+        assert!(!night.contains(2 * 60));
+        assert!(!night.contains(12 * 60));
+
+        assert!((0..24 * 60).step_by(7).all(|m| window("06:00-06:00").contains(m)));
+        assert!(window("20:00-24:00").contains(23 * 60 + 59));
+        assert!(!window("20:00-24:00").contains(0));
+
+        for bad in ["9-17", "24:00-01:00", "09:60-10:00", "09:00", "25:00-01:00"] {
+            assert!(HourWindow::try_from(bad.to_string()).is_err(), "{}", bad);
+        }
+
+        // Unix seconds are taken as UTC; no windows means always active
+        let windows = [night, window("12:00-13:00")];
+        let at = |h: u64, m: u64| 19_000 * 86_400 + h * 3600 + m * 60;
+        assert!(in_active_hours(&windows, at(1, 0)));
+        assert!(in_active_hours(&windows, at(12, 30)));
+        assert!(!in_active_hours(&windows, at(13, 0)));
+        assert!(in_active_hours(&[], at(13, 0)));
+
+        let bot = "[[bots]]\nname = \"a\"\nauth_id = 1\nbankroll = 1\n";
+        let config: Config = toml::from_str(&format!("{bot}active_hours = [\"22:00-02:00\"]\n")).unwrap();
+        assert_eq!(config.bots[0].active_hours, vec![night]);
+        assert_eq!(String::from(night), "22:00-02:00");
+        assert!(toml::from_str::<Config>(&format!("{bot}active_hours = [\"22-2\"]\n")).is_err());
+    }
+
+    #[test]
+    fn test_auth_ids() {
+        let bot = "[[bots]]\nname = \"a\"\nauth_id = 1\nbankroll = 1\n";
+        let config: Config = toml::from_str(bot).unwrap();
+        assert_eq!(config.bots[0].auth_group(), vec![1]);
+        assert_eq!(config.bots[0].auth_split, AuthSplit::RoundRobin);
+
+        let config: Config = toml::from_str(&format!("{bot}auth_ids = [4, 5]\nauth_split = \"split\"\n")).unwrap();
+        assert_eq!(config.bots[0].auth_group(), vec![4, 5]);
+        assert_eq!(config.bots[0].auth_split, AuthSplit::Split);
+        assert!(config.validate().is_ok());
+
+        let config: Config = toml::from_str(&format!("{bot}auth_ids = [4, 5, 4]\n")).unwrap();
+        assert_eq!(config.validate().unwrap_err(), "bot 'a': auth_ids lists 4 twice (position 2)");
+    }
+
     #[test]
     fn test_config_default() {
         let config = Config::default();
@@ -269,4 +951,32 @@ mod tests {
         let serialized = toml::to_string(&params).unwrap();
         assert!(serialized.contains("max_per_square"));
     }
+
+    #[test]
+    fn test_config_history() {
+        let bankroll = |n| BotConfig::new_ev("a", 1, n, 0, 0, 0);
+        let mut history = ConfigHistory::new(bankroll(1));
+        assert_eq!(history.current_version(), 1);
+        assert!(history.previous().is_none());
+        assert_eq!(history.revert(), None);
+
+        assert_eq!(history.push(bankroll(2)), 2);
+        assert_eq!(history.push(bankroll(3)), 3);
+        assert_eq!(history.previous().map(|(v, c)| (v, c.bankroll)), Some((2, 2)));
+        assert_eq!(history.revert(), Some(2));
+        assert_eq!(history.revert(), Some(1));
+        assert_eq!(history.revert(), None);
+
+        // Versions keep counting after a revert; only MAX_LEN are kept
+        assert_eq!(history.push(bankroll(4)), 4);
+        for n in 5..12 {
+            history.push(bankroll(n));
+        }
+        assert_eq!(history.current_version(), 11);
+        let mut reverted = Vec::new();
+        while let Some(version) = history.revert() {
+            reverted.push(version);
+        }
+        assert_eq!(reverted, vec![10, 9, 8, 7]);
+    }
 }
@@ -0,0 +1,239 @@
+//! Confirmation Tracker - Confirmation status of recently submitted signatures
+//!
+//! Bots hand every deploy signature they send to `track`. The coordinator's
+//! poll task checks the pending ones with one getSignatureStatuses call per
+//! interval and pushes every change to the dashboard as
+//! `TuiUpdate::SignatureStatus`. At most `MAX_TRACKED` signatures are kept;
+//! resolved ones from earlier rounds are dropped on round transition, and one
+//! still unseen after `PENDING_TIMEOUT` is reported failed as expired.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use solana_sdk::signature::Signature;
+use tokio::sync::mpsc;
+use tokio::time::sleep;
+
+use crate::board_tracker::BoardTracker;
+use crate::client::{EvoreClient, TxStatusResult};
+use crate::tui::TuiUpdate;
+
+/// Signatures kept (oldest dropped first)
+pub const MAX_TRACKED: usize = 64;
+
+/// How long a signature may go unseen before it's reported expired
+pub const PENDING_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How often pending signatures are polled
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Confirmation status of a tracked signature
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureState {
+    Pending,
+    Confirmed { slot: u64 },
+    Failed { error: String },
+}
+
+impl SignatureState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignatureState::Pending => "PEND",
+            SignatureState::Confirmed { .. } => "OK",
+            SignatureState::Failed { .. } => "FAIL",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Tracked {
+    signature: Signature,
+    bot_name: String,
+    round_id: u64,
+    sent_at: Instant,
+    state: SignatureState,
+    /// Current state has been sent to the dashboard
+    reported: bool,
+}
+
+/// Recently submitted signatures, shared between the bots and the poll task
+#[derive(Debug, Default)]
+pub struct ConfirmationTracker {
+    entries: Mutex<Vec<Tracked>>,
+}
+
+impl ConfirmationTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start tracking a submitted signature
+    pub fn track(&self, bot_name: &str, round_id: u64, signature: Signature) {
+        self.track_at(bot_name, round_id, signature, Instant::now());
+    }
+
+    fn track_at(&self, bot_name: &str, round_id: u64, signature: Signature, now: Instant) {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.iter().any(|e| e.signature == signature) {
+            return;
+        }
+        if entries.len() == MAX_TRACKED {
+            entries.remove(0);
+        }
+        entries.push(Tracked {
+            signature,
+            bot_name: bot_name.to_string(),
+            round_id,
+            sent_at: now,
+            state: SignatureState::Pending,
+            reported: false,
+        });
+    }
+
+    /// Signatures still waiting for a status
+    fn pending(&self) -> Vec<Signature> {
+        self.entries.lock().unwrap().iter()
+            .filter(|e| e.state == SignatureState::Pending)
+            .map(|e| e.signature)
+            .collect()
+    }
+
+    /// Record looked-up statuses (None = not seen yet), expiring pending
+    /// signatures past `PENDING_TIMEOUT`
+    fn apply(&self, statuses: &[(Signature, Option<TxStatusResult>)], now: Instant) {
+        let mut entries = self.entries.lock().unwrap();
+        for (signature, status) in statuses {
+            let Some(entry) = entries.iter_mut().find(|e| e.signature == *signature) else {
+                continue;
+            };
+            let state = match status {
+                Some(TxStatusResult { err: None, slot }) => SignatureState::Confirmed { slot: *slot },
+                Some(TxStatusResult { err: Some(err), .. }) => SignatureState::Failed {
+                    error: crate::bot_runner::parse_evore_error(&format!("{:?}", err)),
+                },
+                None if now.duration_since(entry.sent_at) >= PENDING_TIMEOUT => SignatureState::Failed {
+                    error: "expired".to_string(),
+                },
+                None => continue,
+            };
+            entry.state = state;
+            entry.reported = false;
+        }
+    }
+
+    /// Drop resolved signatures from before `round_id`
+    fn on_round(&self, round_id: u64) {
+        self.entries.lock().unwrap()
+            .retain(|e| e.round_id >= round_id || e.state == SignatureState::Pending);
+    }
+
+    /// Updates for every signature whose current state hasn't been sent yet
+    fn take_updates(&self) -> Vec<TuiUpdate> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.iter_mut()
+            .filter(|e| !e.reported)
+            .map(|e| {
+                e.reported = true;
+                TuiUpdate::SignatureStatus {
+                    bot_name: e.bot_name.clone(),
+                    signature: e.signature,
+                    round_id: e.round_id,
+                    state: e.state.clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Poll pending signatures and push their status changes until the dashboard goes away
+pub async fn poll_loop(
+    tracker: Arc<ConfirmationTracker>,
+    client: Arc<EvoreClient>,
+    board_tracker: Arc<BoardTracker>,
+    tui_tx: mpsc::UnboundedSender<TuiUpdate>,
+) {
+    let mut last_round_id = None;
+    loop {
+        sleep(POLL_INTERVAL).await;
+
+        let round_id = board_tracker.get_round_id();
+        if round_id > 0 && last_round_id != Some(round_id) {
+            last_round_id = Some(round_id);
+            tracker.on_round(round_id);
+        }
+
+        let pending = tracker.pending();
+        if !pending.is_empty() {
+            // getSignatureStatuses takes at most 256 signatures (MAX_TRACKED is below that)
+            if let Ok(statuses) = client.get_signature_statuses_batch(&pending) {
+                let statuses: Vec<_> = pending.into_iter().zip(statuses).collect();
+                tracker.apply(&statuses, Instant::now());
+            }
+        }
+
+        for update in tracker.take_updates() {
+            if tui_tx.send(update).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+    fn states(tracker: &ConfirmationTracker) -> Vec<(u64, SignatureState)> {
+        tracker.take_updates().into_iter()
+            .map(|update| match update {
+                TuiUpdate::SignatureStatus { round_id, state, .. } => (round_id, state),
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_confirmation_tracker() {
+        let tracker = ConfirmationTracker::new();
+        let now = Instant::now();
+        let (landed, failed, dropped) = (Signature::new_unique(), Signature::new_unique(), Signature::new_unique());
+        tracker.track_at("a", 10, landed, now);
+        tracker.track_at("a", 10, failed, now);
+        tracker.track_at("a", 10, dropped, now);
+        tracker.track_at("a", 10, dropped, now);
+        assert_eq!(states(&tracker), vec![(10, SignatureState::Pending); 3]);
+        assert!(states(&tracker).is_empty());
+
+        let custom = TransactionError::InstructionError(0, InstructionError::Custom(9));
+        tracker.apply(&[
+            (landed, Some(TxStatusResult { err: None, slot: 77 })),
+            (failed, Some(TxStatusResult { err: Some(custom), slot: 78 })),
+            (dropped, None),
+        ], now + Duration::from_secs(1));
+        assert_eq!(states(&tracker), vec![
+            (10, SignatureState::Confirmed { slot: 77 }),
+            (10, SignatureState::Failed { error: "AlreadyDeployed".to_string() }),
+        ]);
+        assert_eq!(tracker.pending(), vec![dropped]);
+
+        // Never seen: expires
+        tracker.apply(&[(dropped, None)], now + PENDING_TIMEOUT);
+        assert_eq!(states(&tracker), vec![(10, SignatureState::Failed { error: "expired".to_string() })]);
+
+        // Round transition drops resolved signatures of earlier rounds only
+        let next = Signature::new_unique();
+        let still_pending = Signature::new_unique();
+        tracker.track_at("b", 10, still_pending, now);
+        tracker.track_at("b", 11, next, now);
+        tracker.on_round(11);
+        assert_eq!(tracker.pending(), vec![still_pending, next]);
+
+        // Bounded
+        for _ in 0..MAX_TRACKED {
+            tracker.track_at("c", 11, Signature::new_unique(), now);
+        }
+        assert_eq!(tracker.entries.lock().unwrap().len(), MAX_TRACKED);
+        assert!(!tracker.pending().contains(&still_pending));
+    }
+}
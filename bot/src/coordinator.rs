@@ -5,18 +5,29 @@
 //! - Spawns bot tasks from configuration
 //! - Handles graceful shutdown
 //! - Provides runtime config updates
+//! - Checks at each round transition that active bots can afford a deploy
+//! - Scales every bot's bets when the fleet would deploy more than fleet_max_per_round
+//! - Tracks confirmation of the signatures bots submit
 
+use std::path::Path;
 use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use solana_sdk::signature::{read_keypair_file, Keypair};
 use solana_sdk::signer::Signer;
 use tokio::sync::{mpsc, RwLock};
 use tokio::task::JoinHandle;
 
+use crate::auth_group::AuthSplit;
+use crate::blockhash_cache::MAX_RECENT_BLOCKHASHES;
 use crate::bot_runner::{run_bot_with_services, BotRunConfig, SharedServices};
-use crate::config::{BotConfig, Config, StrategyParams};
+use crate::confirmation_tracker;
+use crate::config::{in_active_hours, BotConfig, Config, PriorityFeeSource, RoundFilter, StrategyParams};
+use crate::deploy_export::DeployExportConfig;
+use crate::fee_estimate::{available_funds, deploy_cost, expected_stake, fleet_scale_bps};
 use crate::sender::PingStats;
 use crate::tui::TuiUpdate;
+use crate::tx_pipeline::{DEFAULT_ABSOLUTE_MAX_DEPLOY, DEFAULT_DUST_FLOOR};
 
 /// Coordinator for running multiple bots
 pub struct RoundCoordinator {
@@ -25,6 +36,12 @@ pub struct RoundCoordinator {
     /// Shared configs that can be updated at runtime
     bot_configs: Vec<Arc<RwLock<BotRunConfig>>>,
     tui_tx: mpsc::UnboundedSender<TuiUpdate>,
+    /// Round transition watcher (see `spawn_round_transition_handler`)
+    round_handle: Option<JoinHandle<()>>,
+    /// Signature confirmation poller (see `spawn_confirmation_tracker`)
+    confirmation_handle: Option<JoinHandle<()>>,
+    /// Cap on the bots' combined deploy per round (see `apply_fleet_cap`)
+    fleet_max_per_round: Option<u64>,
 }
 
 impl RoundCoordinator {
@@ -32,15 +49,20 @@ impl RoundCoordinator {
     pub fn new(
         rpc_url: &str,
         ws_url: &str,
+        audit_log_path: Option<&Path>,
+        deploy_export: Option<&DeployExportConfig>,
         tui_tx: mpsc::UnboundedSender<TuiUpdate>,
     ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-        let services = Arc::new(SharedServices::new(rpc_url, ws_url)?);
+        let services = Arc::new(SharedServices::new(rpc_url, ws_url, audit_log_path, deploy_export)?);
         
         Ok(Self {
             services,
             bot_handles: Vec::new(),
             bot_configs: Vec::new(),
             tui_tx,
+            round_handle: None,
+            confirmation_handle: None,
+            fleet_max_per_round: None,
         })
     }
 
@@ -60,17 +82,40 @@ impl RoundCoordinator {
         let run_config = Arc::new(RwLock::new(BotRunConfig {
             name: bot_config.name.clone(),
             bot_index,
-            auth_id: bot_config.auth_id,
+            auth_ids: bot_config.auth_group(),
+            auth_split: bot_config.auth_split,
             manager: manager_pubkey,
             signer,
             slots_left: bot_config.slots_left,
+            auto_slots_left: bot_config.auto_slots_left,
+            auto_slots_left_target: bot_config.auto_slots_left_target,
             strategy: bot_config.strategy,
             strategy_params: bot_config.strategy_params.clone(),
             bankroll: bot_config.bankroll,
             attempts: bot_config.attempts,
+            retry_delay_ms: bot_config.retry_delay_ms,
+            blockhashes: bot_config.blockhashes,
             priority_fee: bot_config.priority_fee,
+            priority_fee_source: bot_config.priority_fee_source,
             jito_tip: bot_config.jito_tip,
             is_paused: bot_config.paused_on_startup,
+            min_signer_balance: bot_config.min_signer_balance,
+            preview_ms: bot_config.preview_ms,
+            max_consecutive_losses: bot_config.max_consecutive_losses,
+            rotate_squares: bot_config.rotate_squares,
+            min_squares: bot_config.min_squares,
+            max_squares: bot_config.max_squares,
+            square_blacklist: bot_config.square_blacklist.clone(),
+            require_last_slot: bot_config.require_last_slot,
+            hard_last_slots: bot_config.hard_last_slots,
+            presign: bot_config.presign,
+            spam_mode: bot_config.spam_mode,
+            absolute_max_deploy: bot_config.absolute_max_deploy,
+            dust_floor: bot_config.dust_floor,
+            round_filter: bot_config.round_filter,
+            active_hours: bot_config.active_hours.clone(),
+            deploy_vetoed: false,
+            fleet_scale_bps: 10_000,
         }));
 
         // Store config for runtime updates
@@ -92,11 +137,41 @@ impl RoundCoordinator {
             .ok_or_else(|| format!("Bot {} not found", bot_index))?;
         
         let mut cfg = config.write().await;
+        // A switch needs the new strategy's parameters; the bot adopts it at
+        // its next round if it already started deploying this one
+        if new_config.strategy != cfg.strategy && new_config.strategy_params.strategy() != new_config.strategy {
+            return Err(format!(
+                "switching to {:?} needs {:?} strategy_params",
+                new_config.strategy, new_config.strategy,
+            ));
+        }
         cfg.bankroll = new_config.bankroll;
         cfg.slots_left = new_config.slots_left;
+        cfg.auto_slots_left = new_config.auto_slots_left;
+        cfg.auto_slots_left_target = new_config.auto_slots_left_target;
         cfg.priority_fee = new_config.priority_fee;
+        cfg.priority_fee_source = new_config.priority_fee_source;
         cfg.jito_tip = new_config.jito_tip;
         cfg.attempts = new_config.attempts;
+        cfg.retry_delay_ms = new_config.retry_delay_ms;
+        cfg.blockhashes = new_config.blockhashes;
+        cfg.min_signer_balance = new_config.min_signer_balance;
+        cfg.preview_ms = new_config.preview_ms;
+        cfg.max_consecutive_losses = new_config.max_consecutive_losses;
+        cfg.rotate_squares = new_config.rotate_squares;
+        cfg.min_squares = new_config.min_squares;
+        cfg.max_squares = new_config.max_squares;
+        cfg.square_blacklist = new_config.square_blacklist.clone();
+        cfg.require_last_slot = new_config.require_last_slot;
+        cfg.hard_last_slots = new_config.hard_last_slots;
+        cfg.presign = new_config.presign;
+        cfg.spam_mode = new_config.spam_mode;
+        cfg.absolute_max_deploy = new_config.absolute_max_deploy;
+        cfg.dust_floor = new_config.dust_floor;
+        cfg.round_filter = new_config.round_filter;
+        cfg.active_hours = new_config.active_hours.clone();
+        cfg.auth_split = new_config.auth_split;
+        cfg.strategy = new_config.strategy;
         cfg.strategy_params = new_config.strategy_params.clone();
         
         Ok(())
@@ -112,6 +187,31 @@ impl RoundCoordinator {
         Ok(cfg.is_paused)
     }
     
+    /// Set pause state for every bot
+    /// Returns the indices of bots whose state actually changed
+    pub async fn set_all_paused(&self, paused: bool) -> Vec<usize> {
+        let mut changed = Vec::new();
+        for (index, config) in self.bot_configs.iter().enumerate() {
+            let mut cfg = config.write().await;
+            if cfg.is_paused != paused {
+                cfg.is_paused = paused;
+                changed.push(index);
+            }
+        }
+        changed
+    }
+    
+    /// Veto the deploy a bot is currently previewing
+    /// The bot clears the flag itself before each preview, so a stale veto never carries over
+    pub async fn veto_pending_deploy(&self, bot_index: usize) -> Result<(), String> {
+        let config = self.bot_configs.get(bot_index)
+            .ok_or_else(|| format!("Bot {} not found", bot_index))?;
+        
+        let mut cfg = config.write().await;
+        cfg.deploy_vetoed = true;
+        Ok(())
+    }
+    
     /// Get pause state for a bot
     pub async fn is_bot_paused(&self, bot_index: usize) -> Result<bool, String> {
         let config = self.bot_configs.get(bot_index)
@@ -126,6 +226,7 @@ impl RoundCoordinator {
         &mut self,
         config: &Config,
     ) -> Result<(), Box<dyn std::error::Error>> {
+        self.fleet_max_per_round = config.fleet_max_per_round;
         for (index, bot_config) in config.bots.iter().enumerate() {
             // Load keypairs
             let signer_path = config.get_signer_path(bot_config);
@@ -146,6 +247,47 @@ impl RoundCoordinator {
         Ok(())
     }
 
+    /// Watch the board for round transitions and run the fleet cap and
+    /// funding check on each
+    /// Call after spawning bots; bots added later aren't checked.
+    pub fn spawn_round_transition_handler(&mut self) {
+        let services = Arc::clone(&self.services);
+        let configs = self.bot_configs.clone();
+        let tui_tx = self.tui_tx.clone();
+        let fleet_max_per_round = self.fleet_max_per_round;
+
+        self.round_handle = Some(tokio::spawn(async move {
+            let mut last_round_id = None;
+            loop {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                let Some(board) = services.board_tracker.get_board() else { continue };
+                if last_round_id == Some(board.round_id) {
+                    continue;
+                }
+                last_round_id = Some(board.round_id);
+                if let Some(cap) = fleet_max_per_round {
+                    apply_fleet_cap(&configs, board.round_id, cap, &tui_tx).await;
+                }
+                check_bot_funding(&services, &configs, board.round_id, &tui_tx).await;
+            }
+        }));
+    }
+
+    /// Poll the confirmation status of the signatures bots submit and push it to the TUI
+    pub fn spawn_confirmation_tracker(&mut self) {
+        let services = Arc::clone(&self.services);
+        let tui_tx = self.tui_tx.clone();
+
+        self.confirmation_handle = Some(tokio::spawn(async move {
+            confirmation_tracker::poll_loop(
+                Arc::clone(&services.confirmations),
+                Arc::clone(&services.client),
+                Arc::clone(&services.board_tracker),
+                tui_tx,
+            ).await;
+        }));
+    }
+
     /// Wait for all bots to complete (they run forever, so this blocks until shutdown)
     pub async fn wait_for_bots(&mut self) {
         for handle in self.bot_handles.drain(..) {
@@ -158,6 +300,12 @@ impl RoundCoordinator {
         for handle in &self.bot_handles {
             handle.abort();
         }
+        if let Some(handle) = &self.round_handle {
+            handle.abort();
+        }
+        if let Some(handle) = &self.confirmation_handle {
+            handle.abort();
+        }
     }
 
     /// Get number of running bots
@@ -217,6 +365,104 @@ impl RoundCoordinator {
     }
 }
 
+/// Hold the fleet's combined deploy for the round to `cap`
+///
+/// Sums the stake of every bot expected to deploy this round (unpaused, round
+/// filter and active hours allowing) and, when it's over `cap`, scales every
+/// bot's bets by the same factor. Each bot gets a BotFleetScaled so the TUI
+/// shows (or clears) the scaling.
+async fn apply_fleet_cap(
+    configs: &[Arc<RwLock<BotRunConfig>>],
+    round_id: u64,
+    cap: u64,
+    tui_tx: &mpsc::UnboundedSender<TuiUpdate>,
+) {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let mut total = 0u64;
+    for config in configs {
+        let cfg = config.read().await;
+        if cfg.is_paused || !cfg.round_filter.matches(round_id) || !in_active_hours(&cfg.active_hours, now) {
+            continue;
+        }
+        total = total.saturating_add(expected_stake(&cfg.strategy_params, cfg.bankroll));
+    }
+
+    let scale_bps = fleet_scale_bps(total, cap);
+    for config in configs {
+        let mut cfg = config.write().await;
+        cfg.fleet_scale_bps = scale_bps;
+        let _ = tui_tx.send(TuiUpdate::BotFleetScaled {
+            bot_index: cfg.bot_index,
+            round_id,
+            scale_bps,
+            total,
+            cap,
+        });
+    }
+}
+
+/// Check every unpaused bot can pay for a full deploy this round
+///
+/// Signer and managed_miner_auth balances are fetched in one batched call; each
+/// bot gets a BotFundingCheck so the TUI can flag (or clear) an underfunded bot
+/// before its deploy window opens.
+async fn check_bot_funding(
+    services: &SharedServices,
+    configs: &[Arc<RwLock<BotRunConfig>>],
+    round_id: u64,
+    tui_tx: &mpsc::UnboundedSender<TuiUpdate>,
+) {
+    // (bot_index, signer, managed_miner_auths, required)
+    let mut bots = Vec::new();
+    for config in configs {
+        let cfg = config.read().await;
+        if cfg.is_paused {
+            continue;
+        }
+        let auths: Vec<_> = cfg.auth_ids.iter()
+            .map(|auth_id| evore::state::managed_miner_auth_pda(cfg.manager, *auth_id).0)
+            .collect();
+        // Each attempt is sent once per recent blockhash
+        let txs = cfg.attempts.saturating_mul(cfg.blockhashes.clamp(1, MAX_RECENT_BLOCKHASHES as u64));
+        let stake = expected_stake(&cfg.strategy_params, cfg.bankroll);
+        let required = deploy_cost(stake, cfg.priority_fee_source.max_fee(cfg.priority_fee), cfg.jito_tip, txs);
+        bots.push((cfg.bot_index, cfg.signer.pubkey(), auths, required));
+    }
+    if bots.is_empty() {
+        return;
+    }
+
+    let pubkeys: Vec<_> = bots.iter()
+        .flat_map(|(_, signer, auths, _)| std::iter::once(*signer).chain(auths.iter().copied()))
+        .collect();
+    let mut balances = Vec::with_capacity(pubkeys.len());
+    for chunk in pubkeys.chunks(100) {
+        match services.client.get_multiple_accounts(chunk) {
+            Ok(accounts) => balances.extend(accounts.iter().map(|a| a.as_ref().map_or(0, |a| a.lamports))),
+            Err(e) => {
+                let _ = tui_tx.send(TuiUpdate::Error(format!("Funding check: {}", e)));
+                return;
+            }
+        }
+    }
+
+    // A group's auths fund the round together
+    let mut balances = balances.into_iter();
+    for (bot_index, _, auths, required) in &bots {
+        let signer_balance = balances.next().unwrap_or(0);
+        let available = balances.by_ref()
+            .take(auths.len())
+            .map(|auth_balance| available_funds(0, auth_balance))
+            .fold(signer_balance, u64::saturating_add);
+        let _ = tui_tx.send(TuiUpdate::BotFundingCheck {
+            bot_index: *bot_index,
+            round_id,
+            available,
+            required: *required,
+        });
+    }
+}
+
 /// Create a coordinator and run with a single bot (for CLI compatibility)
 pub async fn run_single_bot(
     rpc_url: &str,
@@ -228,19 +474,41 @@ pub async fn run_single_bot(
     strategy_params: StrategyParams,
     tui_tx: mpsc::UnboundedSender<TuiUpdate>,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let mut coordinator = RoundCoordinator::new(rpc_url, ws_url, tui_tx)?;
+    let mut coordinator = RoundCoordinator::new(rpc_url, ws_url, None, None, tui_tx)?;
     coordinator.start_services()?;
 
     let bot_config = BotConfig {
         name: "Bot1".to_string(),
         auth_id,
+        auth_ids: Vec::new(),
+        auth_split: AuthSplit::RoundRobin,
         strategy: crate::config::DeployStrategy::EV,
         slots_left,
+        auto_slots_left: false,
+        auto_slots_left_target: 0,
         bankroll: 0, // Will be determined from account
         attempts: 4,
+        retry_delay_ms: 400,
+        blockhashes: 2,
         priority_fee: 5000,  // Default priority fee
+        priority_fee_source: PriorityFeeSource::Fixed,
         jito_tip: 200_000,   // Default jito tip (0.0002 SOL)
         paused_on_startup: false,
+        min_signer_balance: 0,
+        preview_ms: 0,
+        max_consecutive_losses: 0,
+        rotate_squares: false,
+        min_squares: 0,
+        max_squares: 0,
+        square_blacklist: Vec::new(),
+        require_last_slot: false,
+        hard_last_slots: 2,
+        presign: false,
+        spam_mode: false,
+        absolute_max_deploy: DEFAULT_ABSOLUTE_MAX_DEPLOY,
+        dust_floor: DEFAULT_DUST_FLOOR,
+        round_filter: RoundFilter::All,
+        active_hours: Vec::new(),
         strategy_params,
         signer_path: None,
         manager_path: None,
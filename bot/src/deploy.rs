@@ -1,6 +1,8 @@
 use solana_sdk::{
     compute_budget::ComputeBudgetInstruction,
     hash::Hash,
+    instruction::Instruction,
+    pubkey,
     pubkey::Pubkey,
     signature::{Keypair, Signature},
     signer::Signer,
@@ -10,9 +12,116 @@ use std::time::{Duration, Instant};
 use tokio::time::sleep;
 
 use crate::client::EvoreClient;
-use crate::sender::create_tip_instruction;
+use crate::sender::get_random_tip_account;
 use crate::slot_tracker::SlotTracker;
 
+/// Compute unit limit requested by every deploy transaction (the max per tx)
+pub const DEPLOY_CU_LIMIT: u32 = 1_400_000;
+
+/// SPL Memo program (v2)
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// Longest deploy memo (bytes); an EV deploy with a Jito tip and a memo this
+/// long stays clear of the transaction size limit
+pub const MAX_MEMO_LEN: usize = 128;
+
+/// Validate a `--memo` tag
+///
+/// Usable directly as a clap `value_parser`.
+pub fn parse_memo(input: &str) -> Result<String, String> {
+    if input.is_empty() {
+        return Err("memo is empty".to_string());
+    }
+    if input.len() > MAX_MEMO_LEN {
+        return Err(format!("memo is {} bytes, at most {} fit in a deploy transaction", input.len(), MAX_MEMO_LEN));
+    }
+    Ok(input.to_string())
+}
+
+/// SPL Memo instruction carrying `memo` (no signer accounts needed)
+pub fn memo_instruction(memo: &str) -> Instruction {
+    Instruction {
+        program_id: MEMO_PROGRAM_ID,
+        accounts: vec![],
+        data: memo.as_bytes().to_vec(),
+    }
+}
+
+/// Assembles a signed transaction from its core instructions plus the
+/// shared extras, in the order Memo → CU limit → CU price → Jito tip → core
+///
+/// Every `build_*_tx` goes through this, so fee, tip and memo handling is the
+/// same for deploys, checkpoints and claims. The defaults add nothing.
+#[derive(Debug, Clone, Default)]
+pub struct TxBuilder {
+    memo: Option<String>,
+    cu_limit: Option<u32>,
+    priority_fee: u64,
+    tip: Option<(Pubkey, u64)>,
+}
+
+impl TxBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prepend an SPL Memo
+    pub fn memo(mut self, memo: Option<&str>) -> Self {
+        self.memo = memo.map(str::to_string);
+        self
+    }
+
+    /// Request `limit` compute units; the CU price is always sent alongside it
+    pub fn cu_limit(mut self, limit: u32) -> Self {
+        self.cu_limit = Some(limit);
+        self
+    }
+
+    /// Priority fee in micro-lamports per CU (without a CU limit, 0 adds nothing)
+    pub fn priority_fee(mut self, priority_fee: u64) -> Self {
+        self.priority_fee = priority_fee;
+        self
+    }
+
+    /// Tip `amount` lamports to a Jito tip account (0 = no tip)
+    pub fn jito_tip(self, amount: u64) -> Self {
+        self.tip_to(get_random_tip_account(), amount)
+    }
+
+    /// Tip `amount` lamports to `account` (0 = no tip)
+    pub fn tip_to(mut self, account: Pubkey, amount: u64) -> Self {
+        self.tip = (amount > 0).then_some((account, amount));
+        self
+    }
+
+    /// Instructions for `core` with the extras in front
+    pub fn instructions(&self, payer: &Pubkey, core: impl IntoIterator<Item = Instruction>) -> Vec<Instruction> {
+        let mut instructions = Vec::new();
+        if let Some(memo) = &self.memo {
+            instructions.push(memo_instruction(memo));
+        }
+        if let Some(limit) = self.cu_limit {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(limit));
+        }
+        if self.cu_limit.is_some() || self.priority_fee > 0 {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(self.priority_fee));
+        }
+        if let Some((account, amount)) = self.tip {
+            instructions.push(solana_sdk::system_instruction::transfer(payer, &account, amount));
+        }
+        instructions.extend(core);
+        instructions
+    }
+
+    /// Signed transaction paid for by `signer`
+    pub fn build(&self, signer: &Keypair, core: impl IntoIterator<Item = Instruction>, recent_blockhash: Hash) -> Transaction {
+        let instructions = self.instructions(&signer.pubkey(), core);
+        let mut tx = Transaction::new_with_payer(&instructions, Some(&signer.pubkey()));
+        tx.sign(&[signer], recent_blockhash);
+        tx
+    }
+}
+
 /// Result of a transaction send attempt
 #[derive(Debug, Clone)]
 pub struct TxSendResult {
@@ -30,6 +139,40 @@ pub struct DeployResult {
     pub error: Option<String>,
 }
 
+/// How often `single_deploy` re-sends within the round window
+/// (`--max-attempts` / `--retry-delay-ms`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Stop after this many sends (0 = keep sending until the round ends)
+    pub max_attempts: u64,
+    /// Delay between sends; None picks one from slots_left
+    pub retry_delay_ms: Option<u64>,
+}
+
+impl RetryPolicy {
+    /// Delay between sends, or None to send once
+    ///
+    /// Without an explicit delay: 100ms at slots_left <= 2, 400ms at
+    /// slots_left <= 4 (or when more than one attempt was asked for), and a
+    /// single send otherwise.
+    pub fn send_interval(&self, slots_left: u64) -> Option<Duration> {
+        if self.max_attempts == 1 {
+            return None;
+        }
+        match self.retry_delay_ms {
+            Some(ms) => Some(Duration::from_millis(ms)),
+            None if slots_left <= 2 => Some(Duration::from_millis(100)),
+            None if slots_left <= 4 || self.max_attempts > 1 => Some(Duration::from_millis(400)),
+            None => None,
+        }
+    }
+
+    /// Whether `sent` transactions use up the attempts
+    pub fn exhausted(&self, sent: u64) -> bool {
+        self.max_attempts > 0 && sent >= self.max_attempts
+    }
+}
+
 /// Parameters for EV deployment
 #[derive(Debug, Clone)]
 pub struct EvDeployParams {
@@ -39,6 +182,8 @@ pub struct EvDeployParams {
     pub ore_value: u64,
     pub slots_left: u64,
     pub attempts: u64,  // Attempt counter - makes each tx unique for same blockhash
+    /// Tag prepended to the deploy as an SPL Memo (None = no memo)
+    pub memo: Option<String>,
 }
 
 impl Default for EvDeployParams {
@@ -50,6 +195,7 @@ impl Default for EvDeployParams {
             ore_value: 800_000_000,      // 0.8 SOL
             slots_left: 2,
             attempts: 0,
+            memo: None,
         }
     }
 }
@@ -66,8 +212,6 @@ pub fn build_ev_deploy_tx(
     priority_fee: u64,  // micro-lamports per CU
     jito_tip: u64,      // lamports for Jito tip (0 to disable)
 ) -> Transaction {
-    let cu_limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
-    let cu_price_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_fee);
     let deploy_ix = evore::instruction::ev_deploy(
         signer.pubkey(),
         *manager,
@@ -82,20 +226,12 @@ pub fn build_ev_deploy_tx(
         allow_multi_deploy,
     );
 
-    // Build instructions: CU limit → CU price → Jito tip → Deploy
-    let mut instructions = vec![cu_limit_ix, cu_price_ix];
-    
-    // Add Jito tip if enabled (before deploy)
-    if jito_tip > 0 {
-        let tip_ix = create_tip_instruction(&signer.pubkey(), jito_tip);
-        instructions.push(tip_ix);
-    }
-    
-    instructions.push(deploy_ix);
-
-    let mut tx = Transaction::new_with_payer(&instructions, Some(&signer.pubkey()));
-    tx.sign(&[signer], recent_blockhash);
-    tx
+    TxBuilder::new()
+        .memo(params.memo.as_deref())
+        .cu_limit(DEPLOY_CU_LIMIT)
+        .priority_fee(priority_fee)
+        .jito_tip(jito_tip)
+        .build(signer, [deploy_ix], recent_blockhash)
 }
 
 /// Parameters for Percentage deployment
@@ -124,8 +260,6 @@ pub fn build_percentage_deploy_tx(
 ) -> Transaction {
     // Max CU for Solana is 1.4M. Each square CPI costs ~50-60k CU.
     // Safe limit: ~20-22 squares max. Consider reducing squares_count if this fails.
-    let cu_limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
-    let cu_price_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_fee);
     let deploy_ix = evore::instruction::percentage_deploy(
         signer.pubkey(),
         *manager,
@@ -137,20 +271,40 @@ pub fn build_percentage_deploy_tx(
         allow_multi_deploy,
     );
 
-    // Build instructions: CU limit → CU price → Jito tip → Deploy
-    let mut instructions = vec![cu_limit_ix, cu_price_ix];
-    
-    // Add Jito tip if enabled (before deploy)
-    if jito_tip > 0 {
-        let tip_ix = create_tip_instruction(&signer.pubkey(), jito_tip);
-        instructions.push(tip_ix);
-    }
-    
-    instructions.push(deploy_ix);
+    TxBuilder::new()
+        .cu_limit(DEPLOY_CU_LIMIT)
+        .priority_fee(priority_fee)
+        .jito_tip(jito_tip)
+        .build(signer, [deploy_ix], recent_blockhash)
+}
+
+/// Build Manual deploy transaction with Jito tip
+/// Deploys exactly `amounts[i]` to square i (0 = skip).
+pub fn build_manual_deploy_tx(
+    signer: &Keypair,
+    manager: &Pubkey,
+    auth_id: u64,
+    round_id: u64,
+    amounts: [u64; 25],
+    allow_multi_deploy: bool,
+    recent_blockhash: Hash,
+    priority_fee: u64,  // micro-lamports per CU
+    jito_tip: u64,      // lamports for Jito tip (0 to disable)
+) -> Transaction {
+    let deploy_ix = evore::instruction::manual_deploy(
+        signer.pubkey(),
+        *manager,
+        auth_id,
+        round_id,
+        amounts,
+        allow_multi_deploy,
+    );
 
-    let mut tx = Transaction::new_with_payer(&instructions, Some(&signer.pubkey()));
-    tx.sign(&[signer], recent_blockhash);
-    tx
+    TxBuilder::new()
+        .cu_limit(DEPLOY_CU_LIMIT)
+        .priority_fee(priority_fee)
+        .jito_tip(jito_tip)
+        .build(signer, [deploy_ix], recent_blockhash)
 }
 
 /// Build checkpoint transaction
@@ -168,9 +322,7 @@ pub fn build_checkpoint_tx(
         auth_id,
     );
 
-    let mut tx = Transaction::new_with_payer(&[checkpoint_ix], Some(&signer.pubkey()));
-    tx.sign(&[signer], recent_blockhash);
-    tx
+    TxBuilder::new().build(signer, [checkpoint_ix], recent_blockhash)
 }
 
 /// Build claim SOL transaction
@@ -186,27 +338,40 @@ pub fn build_claim_sol_tx(
         auth_id,
     );
 
-    let mut tx = Transaction::new_with_payer(&[claim_ix], Some(&signer.pubkey()));
-    tx.sign(&[signer], recent_blockhash);
-    tx
+    TxBuilder::new().build(signer, [claim_ix], recent_blockhash)
 }
 
 /// Build a claim ORE transaction
+/// If `create_ata` is set, an idempotent create of the signer's ORE token account
+/// is prepended so first-time claimers succeed in a single transaction.
 pub fn build_claim_ore_tx(
     signer: &Keypair,
     manager: &Pubkey,
     auth_id: u64,
+    create_ata: bool,
     recent_blockhash: Hash,
 ) -> Transaction {
-    let claim_ix = evore::instruction::mm_claim_ore(
+    let mut instructions = Vec::new();
+    if create_ata {
+        instructions.push(create_signer_ore_ata_ix(signer));
+    }
+    instructions.push(evore::instruction::mm_claim_ore(
         signer.pubkey(),
         *manager,
         auth_id,
-    );
+    ));
+
+    TxBuilder::new().build(signer, instructions, recent_blockhash)
+}
 
-    let mut tx = Transaction::new_with_payer(&[claim_ix], Some(&signer.pubkey()));
-    tx.sign(&[signer], recent_blockhash);
-    tx
+/// Idempotent create of the signer's ORE associated token account (no-op if it exists)
+fn create_signer_ore_ata_ix(signer: &Keypair) -> solana_sdk::instruction::Instruction {
+    spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+        &signer.pubkey(),
+        &signer.pubkey(),
+        &evore::ore_api::MINT_ADDRESS,
+        &spl_token::id(),
+    )
 }
 
 /// Build claim SOL transaction with custom program ID (for legacy programs)
@@ -245,9 +410,7 @@ pub fn build_claim_sol_tx_with_program(
         data,
     };
 
-    let mut tx = Transaction::new_with_payer(&[claim_ix], Some(&signer.pubkey()));
-    tx.sign(&[signer], recent_blockhash);
-    tx
+    TxBuilder::new().build(signer, [claim_ix], recent_blockhash)
 }
 
 /// Build a claim ORE transaction with custom program ID (for legacy programs)
@@ -256,6 +419,7 @@ pub fn build_claim_ore_tx_with_program(
     manager: &Pubkey,
     auth_id: u64,
     program_id: &Pubkey,
+    create_ata: bool,
     recent_blockhash: Hash,
 ) -> Transaction {
     use solana_sdk::instruction::{AccountMeta, Instruction};
@@ -298,13 +462,17 @@ pub fn build_claim_ore_tx_with_program(
         data,
     };
 
-    let mut tx = Transaction::new_with_payer(&[claim_ix], Some(&signer.pubkey()));
-    tx.sign(&[signer], recent_blockhash);
-    tx
+    let mut instructions = Vec::new();
+    if create_ata {
+        instructions.push(create_signer_ore_ata_ix(signer));
+    }
+    instructions.push(claim_ix);
+
+    TxBuilder::new().build(signer, instructions, recent_blockhash)
 }
 
 /// Single deployment using websocket slot tracking
-/// Sends transactions every 100ms until slot changes past end_slot
+/// Re-sends per `retry` until the attempts run out or the slot passes end_slot
 pub async fn single_deploy(
     client: &EvoreClient,
     slot_tracker: &SlotTracker,
@@ -312,6 +480,7 @@ pub async fn single_deploy(
     manager: &Pubkey,
     auth_id: u64,
     params: &EvDeployParams,
+    retry: &RetryPolicy,
 ) -> Result<Vec<Signature>, Box<dyn std::error::Error>> {
     println!("=== Single Deploy ===\n");
     
@@ -364,7 +533,7 @@ pub async fn single_deploy(
                 if b.end_slot != u64::MAX {
                     println!("✓ Round started! New end_slot: {}", b.end_slot);
                     // Recurse with updated board
-                    return Box::pin(single_deploy(client, slot_tracker, signer, manager, auth_id, params)).await;
+                    return Box::pin(single_deploy(client, slot_tracker, signer, manager, auth_id, params, retry)).await;
                 }
             }
             print!("\r  Waiting... slot {}   ", slot_tracker.get_slot());
@@ -433,21 +602,15 @@ pub async fn single_deploy(
         println!("✓ Starting to send...");
     }
     
-    // Determine send strategy based on slots_left
-    // slots_left <= 2 → 100ms, slots_left <= 4 → 400ms, else single send
-    let send_interval_ms = if params.slots_left <= 2 {
-        100 // Fast spam: every 100ms
-    } else if params.slots_left <= 4 {
-        400 // Medium spam: every 400ms
-    } else {
-        0 // Single send, no spam
-    };
+    // Determine send strategy from the retry policy and slots_left
+    let send_interval = retry.send_interval(params.slots_left);
     
     println!();
-    if send_interval_ms == 0 {
-        println!("📤 Single send mode (slots_left > 4)");
-    } else {
-        println!("🚀 Spam mode: sending every {}ms until slot {} (end)", send_interval_ms, board.end_slot);
+    match send_interval {
+        None => println!("📤 Single send mode"),
+        Some(interval) if retry.max_attempts > 0 => println!("🚀 Spam mode: up to {} sends every {}ms until slot {} (end)",
+            retry.max_attempts, interval.as_millis(), board.end_slot),
+        Some(interval) => println!("🚀 Spam mode: sending every {}ms until slot {} (end)", interval.as_millis(), board.end_slot),
     }
     println!();
     
@@ -497,13 +660,16 @@ pub async fn single_deploy(
             }
         }
         
-        // If single send mode, just break after first tx
-        if send_interval_ms == 0 {
+        // Single send mode, or out of attempts
+        let Some(interval) = send_interval else {
+            break;
+        };
+        if retry.exhausted(tx_count) {
             break;
         }
         
         // Wait before next tx
-        sleep(Duration::from_millis(send_interval_ms)).await;
+        sleep(interval).await;
     }
     
     println!("\n✅ Sent {} transactions in {:?}", signatures.len(), start.elapsed());
@@ -660,6 +826,7 @@ pub async fn continuous_deploy(
     manager: &Pubkey,
     auth_id: u64,
     params: &EvDeployParams,
+    retry: &RetryPolicy,
 ) -> Result<(), Box<dyn std::error::Error>> {
     println!("=== Continuous Deploy Mode ===\n");
     println!("Signer:  {}", signer.pubkey());
@@ -766,7 +933,7 @@ pub async fn continuous_deploy(
         println!("\n\n🎯 Round {} active - starting deploy (single_deploy will wait for {} slots left)", 
                  board.round_id, params.slots_left);
         
-        match single_deploy(client, slot_tracker, signer, manager, auth_id, params).await {
+        match single_deploy(client, slot_tracker, signer, manager, auth_id, params, retry).await {
             Ok(sigs) => {
                 if !sigs.is_empty() {
                     last_round_deployed = Some(board.round_id);
@@ -778,3 +945,78 @@ pub async fn continuous_deploy(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_policy() {
+        let ms = |ms| Some(Duration::from_millis(ms));
+
+        // Defaults follow slots_left
+        let default = RetryPolicy::default();
+        assert_eq!(default.send_interval(2), ms(100));
+        assert_eq!(default.send_interval(4), ms(400));
+        assert_eq!(default.send_interval(12), None);
+        assert!(!default.exhausted(1_000));
+
+        // An explicit delay wins; more than one attempt never means a single send
+        let policy = RetryPolicy { max_attempts: 3, retry_delay_ms: Some(250) };
+        assert_eq!(policy.send_interval(2), ms(250));
+        assert_eq!(RetryPolicy { max_attempts: 3, retry_delay_ms: None }.send_interval(12), ms(400));
+        assert!(!policy.exhausted(2));
+        assert!(policy.exhausted(3));
+
+        assert_eq!(RetryPolicy { max_attempts: 1, retry_delay_ms: Some(250) }.send_interval(2), None);
+    }
+
+    #[test]
+    fn test_deploy_memo() {
+        assert!(parse_memo("").is_err());
+        assert!(parse_memo(&"x".repeat(MAX_MEMO_LEN + 1)).is_err());
+        let memo = parse_memo(&"x".repeat(MAX_MEMO_LEN)).unwrap();
+
+        // The memo goes first and the longest one leaves the deploy clear of the size limit
+        let signer = Keypair::new();
+        let params = EvDeployParams { memo: Some(memo.clone()), ..Default::default() };
+        let tx = build_ev_deploy_tx(&signer, &Pubkey::new_unique(), 1, 1, &params, false, Hash::default(), 5000, 200_000);
+        let first = &tx.message.instructions[0];
+        assert_eq!(tx.message.account_keys[first.program_id_index as usize], MEMO_PROGRAM_ID);
+        assert_eq!(first.data, memo.as_bytes());
+        let estimate = crate::tx_pipeline::TxSizeEstimate::of(&tx);
+        assert_eq!(crate::tx_pipeline::check_tx_size(estimate), Ok(None), "{:?}", estimate);
+
+        // Off by default
+        let tx = build_ev_deploy_tx(&signer, &Pubkey::new_unique(), 1, 1, &EvDeployParams::default(), false, Hash::default(), 5000, 200_000);
+        assert!(!tx.message.account_keys.contains(&MEMO_PROGRAM_ID));
+    }
+
+    #[test]
+    fn test_tx_builder() {
+        let payer = Pubkey::new_unique();
+        let tip_account = Pubkey::new_unique();
+        let core = Instruction::new_with_bytes(Pubkey::new_unique(), &[7], vec![]);
+        let programs = |ixs: &[Instruction]| ixs.iter().map(|ix| ix.program_id).collect::<Vec<_>>();
+
+        // Nothing added by default
+        assert_eq!(TxBuilder::new().instructions(&payer, [core.clone()]), vec![core.clone()]);
+
+        // Memo → CU limit → CU price → tip → core
+        let ixs = TxBuilder::new()
+            .memo(Some("tag"))
+            .cu_limit(DEPLOY_CU_LIMIT)
+            .priority_fee(5000)
+            .tip_to(tip_account, 1000)
+            .instructions(&payer, [core.clone()]);
+        let compute_budget = solana_sdk::compute_budget::id();
+        assert_eq!(programs(&ixs), vec![MEMO_PROGRAM_ID, compute_budget, compute_budget, solana_sdk::system_program::id(), core.program_id]);
+        assert_eq!(ixs[1], ComputeBudgetInstruction::set_compute_unit_limit(DEPLOY_CU_LIMIT));
+        assert_eq!(ixs[2], ComputeBudgetInstruction::set_compute_unit_price(5000));
+        assert_eq!(ixs[3].accounts.iter().map(|a| a.pubkey).collect::<Vec<_>>(), vec![payer, tip_account]);
+
+        // A zero tip is dropped; a fee without a CU limit still sets the price
+        let ixs = TxBuilder::new().priority_fee(5000).jito_tip(0).instructions(&payer, [core.clone()]);
+        assert_eq!(ixs, vec![ComputeBudgetInstruction::set_compute_unit_price(5000), core]);
+    }
+}
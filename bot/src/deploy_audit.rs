@@ -0,0 +1,329 @@
+//! Deploy audit log - one JSON line per deploy attempt
+//!
+//! Records the inputs and outcome of every deploy transaction so rounds can be
+//! reviewed after the fact. Read it back with `evore-bot audit-log <path>`.
+//!
+//! Before a deploy is sent the bot also writes an `EvSnapshotRecord` line with
+//! the board and EV it decided on; `evore-bot decision <path> --round N`
+//! prints it. Each reader skips the other's lines.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use evore::board::Board25;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{DeployStrategy, StrategyParams};
+use crate::ev_calculator::ev_details;
+
+/// Outcome of a deploy attempt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditResult {
+    Confirmed,
+    Failed,
+    /// Not found on chain after the confirmation window
+    Timeout,
+    /// Didn't land because another deploy for the round did (expected)
+    Duplicate,
+}
+
+/// One deploy attempt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeployAuditRecord {
+    /// Unix time in milliseconds when the outcome was known
+    pub timestamp_ms: u64,
+    pub bot: String,
+    pub round_id: u64,
+    pub auth_id: u64,
+    /// Squares we expected to deploy to (bit i = square i)
+    pub mask: u32,
+    /// Total lamports we expected to deploy
+    pub amount: u64,
+    /// Priority fee in micro-lamports per CU
+    pub priority_fee: u64,
+    /// Jito tip in lamports
+    pub jito_tip: u64,
+    pub attempt: u64,
+    pub signature: String,
+    pub result: AuditResult,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// The board and EV a deploy decision was made from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvSnapshotRecord {
+    /// Unix time in milliseconds when the deploy was about to be sent
+    pub timestamp_ms: u64,
+    pub bot: String,
+    pub round_id: u64,
+    pub auth_id: u64,
+    pub strategy: DeployStrategy,
+    /// Value of 1 ORE in lamports the EV strategy used (0 for other strategies)
+    pub ore_value: u64,
+    pub bankroll: u64,
+    pub slots_left: u64,
+    /// Lamports already deployed per square when the bets were computed
+    pub deployed: [u64; 25],
+    /// Expected SOL profit of an extra `EV_UNIT_BET` per square (lamports)
+    pub marginal_ev: [i64; 25],
+    /// Bet chosen per square
+    pub bets: [u64; 25],
+}
+
+impl EvSnapshotRecord {
+    pub fn new(
+        bot: String,
+        round_id: u64,
+        auth_id: u64,
+        strategy: DeployStrategy,
+        strategy_params: &StrategyParams,
+        bankroll: u64,
+        slots_left: u64,
+        deployed: [u64; 25],
+        bets: [u64; 25],
+    ) -> Self {
+        let details = ev_details(&deployed, &bets);
+        Self {
+            timestamp_ms: now_ms(),
+            bot,
+            round_id,
+            auth_id,
+            strategy,
+            ore_value: match strategy_params {
+                StrategyParams::EV { ore_value, .. } => *ore_value,
+                _ => 0,
+            },
+            bankroll,
+            slots_left,
+            deployed,
+            marginal_ev: details.map(|d| d.marginal_ev),
+            bets,
+        }
+    }
+}
+
+/// Current unix time in milliseconds
+pub fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Bitmask of squares with a non-zero bet
+pub fn bets_mask(bets: &[u64; 25]) -> u32 {
+    Board25::new(*bets).to_mask()
+}
+
+/// `(actual, intended)` totals if a confirmed deploy placed less than intended
+pub fn deploy_shortfall(intended: &[u64; 25], actual: &[u64; 25]) -> Option<(u64, u64)> {
+    let intended = Board25::new(*intended).total();
+    let actual = Board25::new(*actual).total();
+    (actual < intended).then_some((actual, intended))
+}
+
+/// Append-only JSON-lines writer shared by all bots
+pub struct DeployAuditLog {
+    file: Mutex<File>,
+}
+
+impl DeployAuditLog {
+    /// Open (or create) the log for appending
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Append a record as a single line
+    pub fn record(&self, record: &DeployAuditRecord) -> io::Result<()> {
+        self.append(record)
+    }
+
+    /// Append the EV snapshot behind a deploy
+    pub fn record_snapshot(&self, snapshot: &EvSnapshotRecord) -> io::Result<()> {
+        self.append(snapshot)
+    }
+
+    fn append(&self, value: &impl Serialize) -> io::Result<()> {
+        let mut line = serde_json::to_string(value)?;
+        line.push('\n');
+        let mut file = self.file.lock().unwrap();
+        file.write_all(line.as_bytes())?;
+        file.flush()
+    }
+}
+
+/// EV snapshots in the log for `round_id`, optionally only `bot`'s, oldest first
+pub fn read_snapshots(path: &Path, round_id: u64, bot: Option<&str>) -> io::Result<Vec<EvSnapshotRecord>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut snapshots = Vec::new();
+    for line in reader.lines() {
+        let Ok(snapshot) = serde_json::from_str::<EvSnapshotRecord>(&line?) else { continue };
+        if snapshot.round_id == round_id && bot.map_or(true, |bot| snapshot.bot == bot) {
+            snapshots.push(snapshot);
+        }
+    }
+    Ok(snapshots)
+}
+
+/// Incremental reader for tailing the log
+pub struct AuditTail {
+    path: PathBuf,
+    offset: u64,
+}
+
+impl AuditTail {
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            offset: 0,
+        }
+    }
+
+    /// Last `n` records in the file; later `read_new` calls continue from the end
+    pub fn read_last(&mut self, n: usize) -> io::Result<Vec<DeployAuditRecord>> {
+        let mut records = self.read_new()?;
+        let skip = records.len().saturating_sub(n);
+        Ok(records.split_off(skip))
+    }
+
+    /// Records appended since the previous read
+    ///
+    /// Malformed lines are skipped. A partially written last line is left for
+    /// the next call.
+    pub fn read_new(&mut self) -> io::Result<Vec<DeployAuditRecord>> {
+        let mut file = File::open(&self.path)?;
+        // File was truncated or rotated - start over
+        if file.metadata()?.len() < self.offset {
+            self.offset = 0;
+        }
+        file.seek(SeekFrom::Start(self.offset))?;
+
+        let mut reader = BufReader::new(file);
+        let mut records = Vec::new();
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            if read == 0 || !line.ends_with('\n') {
+                break;
+            }
+            self.offset += read as u64;
+            if let Ok(record) = serde_json::from_str(line.trim_end()) {
+                records.push(record);
+            }
+        }
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(round_id: u64, result: AuditResult) -> DeployAuditRecord {
+        DeployAuditRecord {
+            timestamp_ms: 1_700_000_000_000,
+            bot: "bot1".to_string(),
+            round_id,
+            auth_id: 1,
+            mask: 0b101,
+            amount: 20_000,
+            priority_fee: 5_000,
+            jito_tip: 200_000,
+            attempt: 0,
+            signature: "sig".to_string(),
+            result,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_bets_mask() {
+        let mut bets = [0u64; 25];
+        bets[0] = 1;
+        bets[24] = 5;
+        assert_eq!(bets_mask(&bets), 1 | (1 << 24));
+        assert_eq!(bets_mask(&[0; 25]), 0);
+    }
+
+    #[test]
+    fn test_deploy_shortfall() {
+        let mut intended = [0u64; 25];
+        intended[0] = 1_000;
+        intended[3] = 2_000;
+        assert_eq!(deploy_shortfall(&intended, &intended), None);
+
+        let mut actual = intended;
+        actual[3] = 500;
+        assert_eq!(deploy_shortfall(&intended, &actual), Some((1_500, 3_000)));
+        assert_eq!(deploy_shortfall(&intended, &[0; 25]), Some((0, 3_000)));
+
+        // Landing more on other squares (EV recomputed on-chain) isn't short
+        actual[7] = 2_000;
+        assert_eq!(deploy_shortfall(&intended, &actual), None);
+    }
+
+    #[test]
+    fn test_write_and_tail() {
+        let path = std::env::temp_dir().join(format!("evore-audit-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let log = DeployAuditLog::open(&path).unwrap();
+        for round_id in 1..=3 {
+            log.record(&record(round_id, AuditResult::Confirmed)).unwrap();
+        }
+
+        let mut tail = AuditTail::new(&path);
+        let last = tail.read_last(2).unwrap();
+        assert_eq!(last.iter().map(|r| r.round_id).collect::<Vec<_>>(), vec![2, 3]);
+        assert!(tail.read_new().unwrap().is_empty());
+
+        let mut failed = record(4, AuditResult::Failed);
+        failed.error = Some("Custom(7)".to_string());
+        log.record(&failed).unwrap();
+        assert_eq!(tail.read_new().unwrap(), vec![failed]);
+
+        let line = std::fs::read_to_string(&path).unwrap();
+        assert!(line.contains("\"result\":\"confirmed\""));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_ev_snapshots() {
+        let path = std::env::temp_dir().join(format!("evore-audit-snapshots-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut deployed = [1_000_000_000u64; 25];
+        deployed[4] = 0;
+        let mut bets = [0u64; 25];
+        bets[4] = 50_000_000;
+        let params = StrategyParams::EV { max_per_square: 100_000_000, min_bet: 10_000, ore_value: 800_000_000 };
+        let snapshot = |bot: &str, round_id| EvSnapshotRecord::new(bot.to_string(), round_id, 1, DeployStrategy::EV, &params, 1_000_000_000, 2, deployed, bets);
+
+        let log = DeployAuditLog::open(&path).unwrap();
+        log.record(&record(7, AuditResult::Confirmed)).unwrap();
+        log.record_snapshot(&snapshot("bot1", 7)).unwrap();
+        log.record_snapshot(&snapshot("bot2", 7)).unwrap();
+        log.record_snapshot(&snapshot("bot1", 8)).unwrap();
+
+        // The two kinds of line don't get mixed up
+        let found = read_snapshots(&path, 7, Some("bot1")).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].ore_value, found[0].bets), (800_000_000, bets));
+        assert!(found[0].marginal_ev[4] > 0);
+        assert!(found[0].marginal_ev[4] > found[0].marginal_ev[0]);
+        assert_eq!(read_snapshots(&path, 7, None).unwrap().len(), 2);
+        assert_eq!(AuditTail::new(&path).read_last(10).unwrap(), vec![record(7, AuditResult::Confirmed)]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,493 @@
+//! Deploy export - deploy attempts and their round outcome in ClickHouse
+//!
+//! Optional sink next to the audit log: every deploy attempt is held until the
+//! bot checkpoints the round, then written as one `bot_deploys` row carrying
+//! whether the round was won and the SOL/ORE it realized. Attempts for rounds
+//! that never landed are written with outcome `none` on the bot's next
+//! checkpoint. Rows still held when the bot exits are not written.
+//!
+//! Each confirmed deploy is also written straight away as a
+//! `bot_deploy_landings` row: the slot it landed in against the round's
+//! end_slot, tagged with the host and session, so landing timing can be
+//! compared across runs and machines (`evore-bot landings`).
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use clickhouse::{Client, Row};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+use crate::deploy_audit::{now_ms, DeployAuditRecord};
+
+/// `[deploy_export]` section of the app config
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployExportConfig {
+    /// ClickHouse HTTP url, e.g. http://localhost:8123
+    pub url: String,
+    #[serde(default = "default_database")]
+    pub database: String,
+    #[serde(default = "default_user")]
+    pub user: String,
+    #[serde(default)]
+    pub password: String,
+    /// Created on the first write if missing
+    #[serde(default = "default_table")]
+    pub table: String,
+    /// Also write a row per confirmed deploy with the slot it landed in
+    #[serde(default = "default_landings")]
+    pub landings: bool,
+    #[serde(default = "default_landings_table")]
+    pub landings_table: String,
+    /// Stored with each landing to tell machines apart (default: $HOSTNAME)
+    #[serde(default = "default_host")]
+    pub host: String,
+}
+
+impl DeployExportConfig {
+    fn client(&self) -> Client {
+        Client::default()
+            .with_url(&self.url)
+            .with_database(&self.database)
+            .with_user(&self.user)
+            .with_password(&self.password)
+    }
+}
+
+fn default_database() -> String {
+    "ore_stats".to_string()
+}
+
+fn default_user() -> String {
+    "default".to_string()
+}
+
+fn default_table() -> String {
+    "bot_deploys".to_string()
+}
+
+fn default_landings() -> bool {
+    true
+}
+
+fn default_landings_table() -> String {
+    "bot_deploy_landings".to_string()
+}
+
+fn default_host() -> String {
+    std::env::var("HOSTNAME").unwrap_or_default()
+}
+
+/// How a round went for a bot, from its checkpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundOutcome {
+    /// Checkpoint credited SOL or ORE
+    Won { sol: u64, ore: u64 },
+    Lost,
+    /// No deploy landed in the round
+    None,
+}
+
+impl RoundOutcome {
+    /// Outcome from the claimable rewards gained by a checkpoint
+    pub fn from_deltas(sol: u64, ore: u64) -> Self {
+        if sol > 0 || ore > 0 {
+            Self::Won { sol, ore }
+        } else {
+            Self::Lost
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Won { .. } => "won",
+            Self::Lost => "lost",
+            Self::None => "none",
+        }
+    }
+}
+
+/// One row of `bot_deploys`
+#[derive(Debug, Clone, PartialEq, Row, Serialize)]
+pub struct BotDeployRow {
+    /// When the attempt's result was known (unix ms)
+    pub timestamp_ms: u64,
+    /// When the round outcome was known (unix ms)
+    pub settled_ms: u64,
+    pub bot: String,
+    pub round_id: u64,
+    pub auth_id: u64,
+    pub mask: u32,
+    pub amount: u64,
+    pub priority_fee: u64,
+    pub jito_tip: u64,
+    pub attempt: u64,
+    pub signature: String,
+    /// `confirmed`, `failed`, `timeout` or `duplicate`
+    pub result: String,
+    pub error: String,
+    /// `won`, `lost` or `none`
+    pub outcome: String,
+    /// Lamports credited by the round's checkpoint
+    pub realized_sol: u64,
+    /// ORE (grams) credited by the round's checkpoint
+    pub realized_ore: u64,
+}
+
+impl BotDeployRow {
+    pub fn new(record: &DeployAuditRecord, outcome: RoundOutcome, settled_ms: u64) -> Self {
+        let (realized_sol, realized_ore) = match outcome {
+            RoundOutcome::Won { sol, ore } => (sol, ore),
+            _ => (0, 0),
+        };
+        let result = serde_json::to_value(record.result)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        Self {
+            timestamp_ms: record.timestamp_ms,
+            settled_ms,
+            bot: record.bot.clone(),
+            round_id: record.round_id,
+            auth_id: record.auth_id,
+            mask: record.mask,
+            amount: record.amount,
+            priority_fee: record.priority_fee,
+            jito_tip: record.jito_tip,
+            attempt: record.attempt,
+            signature: record.signature.clone(),
+            result,
+            error: record.error.clone().unwrap_or_default(),
+            outcome: outcome.as_str().to_string(),
+            realized_sol,
+            realized_ore,
+        }
+    }
+}
+
+/// Where a confirmed deploy landed relative to its round
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Landing {
+    pub start_slot: u64,
+    pub end_slot: u64,
+    /// First slot a deploy for the round was sent in
+    pub sent_slot: Option<u64>,
+    pub landed_slot: u64,
+}
+
+/// One row of `bot_deploy_landings`
+#[derive(Debug, Clone, PartialEq, Row, Serialize)]
+pub struct BotDeployLandingRow {
+    /// When the deploy was confirmed (unix ms)
+    pub timestamp_ms: u64,
+    /// When this bot process started exporting (unix ms), one per session
+    pub session_ms: u64,
+    pub host: String,
+    pub bot: String,
+    pub round_id: u64,
+    pub auth_id: u64,
+    pub attempt: u64,
+    pub signature: String,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    /// 0 if unknown
+    pub sent_slot: u64,
+    pub landed_slot: u64,
+    /// end_slot - landed_slot; negative when it landed after the end
+    pub slots_before_end: i64,
+    pub amount: u64,
+    pub priority_fee: u64,
+    pub jito_tip: u64,
+}
+
+impl BotDeployLandingRow {
+    pub fn new(record: &DeployAuditRecord, landing: Landing, host: &str, session_ms: u64) -> Self {
+        Self {
+            timestamp_ms: record.timestamp_ms,
+            session_ms,
+            host: host.to_string(),
+            bot: record.bot.clone(),
+            round_id: record.round_id,
+            auth_id: record.auth_id,
+            attempt: record.attempt,
+            signature: record.signature.clone(),
+            start_slot: landing.start_slot,
+            end_slot: landing.end_slot,
+            sent_slot: landing.sent_slot.unwrap_or(0),
+            landed_slot: landing.landed_slot,
+            slots_before_end: landing.end_slot as i64 - landing.landed_slot as i64,
+            amount: record.amount,
+            priority_fee: record.priority_fee,
+            jito_tip: record.jito_tip,
+        }
+    }
+}
+
+/// Landing timing of one bot on one host, from `landing_stats`
+#[derive(Debug, Clone, Row, Deserialize)]
+pub struct LandingStats {
+    pub bot: String,
+    pub host: String,
+    pub landings: u64,
+    pub sessions: u64,
+    pub avg_slots_before_end: f64,
+    pub min_slots_before_end: i64,
+    pub max_slots_before_end: i64,
+    /// Landed at or after end_slot
+    pub late: u64,
+    /// Slots from first send to landing (NaN when no send slot was recorded)
+    pub avg_latency_slots: f64,
+}
+
+/// Landing timing per bot and host for landings since `since_ms`, optionally one bot's
+pub async fn landing_stats(
+    config: &DeployExportConfig,
+    since_ms: u64,
+    bot: Option<&str>,
+) -> Result<Vec<LandingStats>, clickhouse::error::Error> {
+    config
+        .client()
+        .query(&format!(
+            "SELECT bot, host, count() AS landings, uniqExact(session_ms) AS sessions,
+                avg(slots_before_end) AS avg_slots_before_end,
+                min(slots_before_end) AS min_slots_before_end,
+                max(slots_before_end) AS max_slots_before_end,
+                countIf(slots_before_end <= 0) AS late,
+                avgIf(toInt64(landed_slot) - toInt64(sent_slot), sent_slot > 0) AS avg_latency_slots
+            FROM {} WHERE timestamp_ms >= ? AND (? = '' OR bot = ?)
+            GROUP BY bot, host ORDER BY bot, host",
+            config.landings_table
+        ))
+        .bind(since_ms)
+        .bind(bot.unwrap_or(""))
+        .bind(bot.unwrap_or(""))
+        .fetch_all::<LandingStats>()
+        .await
+}
+
+/// Attempts waiting for their round to be checkpointed, per bot
+#[derive(Debug, Default)]
+struct PendingAttempts {
+    by_bot: HashMap<String, Vec<DeployAuditRecord>>,
+}
+
+impl PendingAttempts {
+    fn push(&mut self, record: DeployAuditRecord) {
+        self.by_bot.entry(record.bot.clone()).or_default().push(record);
+    }
+
+    /// Rows for `bot`'s attempts up to `round_id`; earlier rounds that were never
+    /// checkpointed didn't land, so they get outcome `none`
+    fn settle(&mut self, bot: &str, round_id: u64, outcome: RoundOutcome, settled_ms: u64) -> Vec<BotDeployRow> {
+        let Some(records) = self.by_bot.get_mut(bot) else {
+            return Vec::new();
+        };
+        let (done, keep): (Vec<_>, Vec<_>) = records.drain(..).partition(|r| r.round_id <= round_id);
+        *records = keep;
+        done.iter()
+            .map(|r| {
+                let outcome = if r.round_id == round_id { outcome } else { RoundOutcome::None };
+                BotDeployRow::new(r, outcome, settled_ms)
+            })
+            .collect()
+    }
+}
+
+/// Buffers deploy attempts and writes them to ClickHouse in the background
+pub struct DeployExporter {
+    pending: Mutex<PendingAttempts>,
+    tx: mpsc::UnboundedSender<Vec<BotDeployRow>>,
+    /// None when landings are disabled
+    landings_tx: Option<mpsc::UnboundedSender<Vec<BotDeployLandingRow>>>,
+    host: String,
+    session_ms: u64,
+}
+
+impl DeployExporter {
+    /// Start the writer tasks (must be called inside the tokio runtime)
+    pub fn start(config: &DeployExportConfig) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(write_rows(config.client(), config.table.clone(), DEPLOYS_SCHEMA, rx));
+        let landings_tx = config.landings.then(|| {
+            let (tx, rx) = mpsc::unbounded_channel();
+            tokio::spawn(write_rows(config.client(), config.landings_table.clone(), LANDINGS_SCHEMA, rx));
+            tx
+        });
+        Self {
+            pending: Mutex::new(PendingAttempts::default()),
+            tx,
+            landings_tx,
+            host: config.host.clone(),
+            session_ms: now_ms(),
+        }
+    }
+
+    /// Hold a finished attempt until its round is settled
+    pub fn record(&self, record: &DeployAuditRecord) {
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.push(record.clone());
+        }
+    }
+
+    /// Write `bot`'s attempts for `round_id` (and any older unsettled rounds)
+    pub fn settle(&self, bot: &str, round_id: u64, outcome: RoundOutcome) {
+        let rows = match self.pending.lock() {
+            Ok(mut pending) => pending.settle(bot, round_id, outcome, now_ms()),
+            Err(_) => return,
+        };
+        if !rows.is_empty() {
+            let _ = self.tx.send(rows);
+        }
+    }
+
+    /// Write a confirmed deploy's landing right away (no-op when landings are disabled)
+    pub fn record_landing(&self, record: &DeployAuditRecord, landing: Landing) {
+        if let Some(tx) = &self.landings_tx {
+            let _ = tx.send(vec![BotDeployLandingRow::new(record, landing, &self.host, self.session_ms)]);
+        }
+    }
+}
+
+/// Columns and engine of `bot_deploys`
+const DEPLOYS_SCHEMA: &str = "(
+    timestamp_ms UInt64,
+    settled_ms UInt64,
+    bot String,
+    round_id UInt64,
+    auth_id UInt64,
+    mask UInt32,
+    amount UInt64,
+    priority_fee UInt64,
+    jito_tip UInt64,
+    attempt UInt64,
+    signature String,
+    result LowCardinality(String),
+    error String,
+    outcome LowCardinality(String),
+    realized_sol UInt64,
+    realized_ore UInt64
+) ENGINE = MergeTree ORDER BY (bot, round_id, attempt)";
+
+/// Columns and engine of `bot_deploy_landings`
+const LANDINGS_SCHEMA: &str = "(
+    timestamp_ms UInt64,
+    session_ms UInt64,
+    host LowCardinality(String),
+    bot String,
+    round_id UInt64,
+    auth_id UInt64,
+    attempt UInt64,
+    signature String,
+    start_slot UInt64,
+    end_slot UInt64,
+    sent_slot UInt64,
+    landed_slot UInt64,
+    slots_before_end Int64,
+    amount UInt64,
+    priority_fee UInt64,
+    jito_tip UInt64
+) ENGINE = MergeTree ORDER BY (bot, round_id, timestamp_ms)";
+
+async fn write_rows<T: Row + Serialize>(
+    client: Client,
+    table: String,
+    schema: &'static str,
+    mut rx: mpsc::UnboundedReceiver<Vec<T>>,
+) {
+    let mut table_ready = false;
+    while let Some(rows) = rx.recv().await {
+        // A failed batch is dropped; the audit log still has the attempts
+        if !table_ready {
+            table_ready = create_table(&client, &table, schema).await.is_ok();
+        }
+        let _ = insert_rows(&client, &table, &rows).await;
+    }
+}
+
+async fn create_table(client: &Client, table: &str, schema: &str) -> Result<(), clickhouse::error::Error> {
+    client
+        .query(&format!("CREATE TABLE IF NOT EXISTS {table} {schema}"))
+        .execute()
+        .await
+}
+
+async fn insert_rows<T: Row + Serialize>(client: &Client, table: &str, rows: &[T]) -> Result<(), clickhouse::error::Error> {
+    let mut insert = client.insert(table)?;
+    for row in rows {
+        insert.write(row).await?;
+    }
+    insert.end().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deploy_audit::AuditResult;
+
+    fn attempt(bot: &str, round_id: u64, attempt: u64, result: AuditResult) -> DeployAuditRecord {
+        DeployAuditRecord {
+            timestamp_ms: 1_000 + attempt,
+            bot: bot.to_string(),
+            round_id,
+            auth_id: 1,
+            mask: 0b101,
+            amount: 2_000_000,
+            priority_fee: 5_000,
+            jito_tip: 200_000,
+            attempt,
+            signature: format!("sig{attempt}"),
+            result,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_row_carries_outcome() {
+        let mut record = attempt("a", 10, 2, AuditResult::Failed);
+        record.error = Some("blockhash not found".to_string());
+
+        let row = BotDeployRow::new(&record, RoundOutcome::from_deltas(3_000_000, 0), 5_000);
+        assert_eq!(row.result, "failed");
+        assert_eq!(row.error, "blockhash not found");
+        assert_eq!(row.outcome, "won");
+        assert_eq!(row.realized_sol, 3_000_000);
+        assert_eq!(row.settled_ms, 5_000);
+
+        let row = BotDeployRow::new(&record, RoundOutcome::from_deltas(0, 0), 5_000);
+        assert_eq!(row.outcome, "lost");
+        assert_eq!(row.realized_sol, 0);
+    }
+
+    #[test]
+    fn test_landing_row() {
+        let record = attempt("a", 10, 1, AuditResult::Confirmed);
+        let landing = Landing { start_slot: 1_000, end_slot: 1_150, sent_slot: Some(1_146), landed_slot: 1_148 };
+        let row = BotDeployLandingRow::new(&record, landing, "box-1", 42);
+        assert_eq!((row.host.as_str(), row.session_ms, row.signature.as_str()), ("box-1", 42, "sig1"));
+        assert_eq!((row.sent_slot, row.landed_slot, row.slots_before_end), (1_146, 1_148, 2));
+
+        // Landed after the end, send slot unknown
+        let late = Landing { sent_slot: None, landed_slot: 1_152, ..landing };
+        let row = BotDeployLandingRow::new(&record, late, "", 42);
+        assert_eq!((row.sent_slot, row.slots_before_end), (0, -2));
+    }
+
+    #[test]
+    fn test_settle_flushes_older_rounds_as_none() {
+        let mut pending = PendingAttempts::default();
+        pending.push(attempt("a", 9, 0, AuditResult::Timeout));
+        pending.push(attempt("a", 10, 0, AuditResult::Failed));
+        pending.push(attempt("a", 10, 1, AuditResult::Confirmed));
+        pending.push(attempt("a", 11, 0, AuditResult::Confirmed));
+        pending.push(attempt("b", 10, 0, AuditResult::Confirmed));
+
+        let rows = pending.settle("a", 10, RoundOutcome::Lost, 0);
+        let outcomes: Vec<_> = rows.iter().map(|r| (r.round_id, r.outcome.as_str())).collect();
+        assert_eq!(outcomes, vec![(9, "none"), (10, "lost"), (10, "lost")]);
+
+        // Round 11 and the other bot are still held
+        assert_eq!(pending.by_bot["a"].len(), 1);
+        assert_eq!(pending.by_bot["b"].len(), 1);
+        assert!(pending.settle("c", 10, RoundOutcome::Lost, 0).is_empty());
+    }
+}
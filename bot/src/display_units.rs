@@ -0,0 +1,146 @@
+//! Display units - How the dashboard shows SOL amounts
+//!
+//! Amounts are lamports everywhere in the bot; only the TUI converts them.
+//! `units = "usd"` needs a SOL/USD price, polled from `sol_price_url` and
+//! pushed to the dashboard as `TuiUpdate::SolPrice`. Until the first price
+//! arrives (or if the source fails) USD amounts fall back to SOL.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use tokio::sync::mpsc;
+use tokio::time::sleep;
+
+use crate::tui::TuiUpdate;
+
+/// Unit the dashboard renders SOL amounts in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DisplayUnits {
+    Lamports,
+    #[default]
+    Sol,
+    Usd,
+}
+
+/// `[display]` section of the app config
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisplayConfig {
+    #[serde(default)]
+    pub units: DisplayUnits,
+    /// JSON endpoint with the SOL/USD price (only polled for `usd`)
+    #[serde(default = "default_sol_price_url")]
+    pub sol_price_url: String,
+    /// JSON pointer to the price in the response
+    #[serde(default = "default_sol_price_pointer")]
+    pub sol_price_pointer: String,
+    #[serde(default = "default_sol_price_poll_secs")]
+    pub sol_price_poll_secs: u64,
+}
+
+fn default_sol_price_url() -> String {
+    "https://api.coingecko.com/api/v3/simple/price?ids=solana&vs_currencies=usd".to_string()
+}
+
+fn default_sol_price_pointer() -> String {
+    "/solana/usd".to_string()
+}
+
+fn default_sol_price_poll_secs() -> u64 {
+    60
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            units: DisplayUnits::default(),
+            sol_price_url: default_sol_price_url(),
+            sol_price_pointer: default_sol_price_pointer(),
+            sol_price_poll_secs: default_sol_price_poll_secs(),
+        }
+    }
+}
+
+impl DisplayUnits {
+    /// `lamports` in this unit with its symbol, e.g. "1.2500 ◎"
+    ///
+    /// `price` is SOL/USD; USD without one shows SOL.
+    pub fn format(&self, lamports: u64, price: Option<f64>) -> String {
+        self.format_signed(lamports as i64, price).trim_start_matches('+').to_string()
+    }
+
+    /// Like `format`, always with a sign (for P&L)
+    pub fn format_signed(&self, lamports: i64, price: Option<f64>) -> String {
+        let sign = if lamports < 0 { "-" } else { "+" };
+        let abs = lamports.unsigned_abs();
+        let sol = abs as f64 / LAMPORTS_PER_SOL as f64;
+        match (self, price) {
+            (DisplayUnits::Lamports, _) => format!("{}{} lam", sign, abs),
+            (DisplayUnits::Usd, Some(price)) => format!("{}${:.2}", sign, sol * price),
+            (DisplayUnits::Sol, _) | (DisplayUnits::Usd, None) => format!("{}{:.4} ◎", sign, sol),
+        }
+    }
+}
+
+/// SOL/USD price from a price source response
+pub fn parse_price(body: &Value, pointer: &str) -> Option<f64> {
+    let price = match body.pointer(pointer)? {
+        Value::String(s) => s.parse().ok()?,
+        value => value.as_f64()?,
+    };
+    (price.is_finite() && price > 0.0).then_some(price)
+}
+
+/// Poll the SOL/USD price and push it to the dashboard until it goes away
+pub async fn price_loop(config: DisplayConfig, tui_tx: mpsc::UnboundedSender<TuiUpdate>) {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()
+        .expect("Failed to create HTTP client");
+    let interval = Duration::from_secs(config.sol_price_poll_secs.max(1));
+
+    loop {
+        let response = client.get(&config.sol_price_url).send().await;
+        let body = match response {
+            Ok(response) => response.json::<Value>().await.ok(),
+            Err(_) => None,
+        };
+        // Errors keep the last price; retried next interval
+        if let Some(price) = body.as_ref().and_then(|b| parse_price(b, &config.sol_price_pointer)) {
+            if tui_tx.send(TuiUpdate::SolPrice(price)).is_err() {
+                return;
+            }
+        }
+        sleep(interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_display_units() {
+        let amount = 1_250_000_000;
+        assert_eq!(DisplayUnits::Lamports.format(amount, None), "1250000000 lam");
+        assert_eq!(DisplayUnits::Sol.format(amount, Some(150.0)), "1.2500 ◎");
+        assert_eq!(DisplayUnits::Usd.format(amount, Some(150.0)), "$187.50");
+        // No price yet: SOL
+        assert_eq!(DisplayUnits::Usd.format(amount, None), "1.2500 ◎");
+
+        assert_eq!(DisplayUnits::Sol.format_signed(-5_000_000, None), "-0.0050 ◎");
+        assert_eq!(DisplayUnits::Usd.format_signed(20_000_000, Some(100.0)), "+$2.00");
+
+        let config: DisplayConfig = toml::from_str("units = \"usd\"\n").unwrap();
+        assert_eq!(config.units, DisplayUnits::Usd);
+        assert_eq!(config.sol_price_pointer, "/solana/usd");
+
+        assert_eq!(parse_price(&json!({ "solana": { "usd": 142.5 } }), "/solana/usd"), Some(142.5));
+        assert_eq!(parse_price(&json!({ "price": "99.1" }), "/price"), Some(99.1));
+        assert_eq!(parse_price(&json!({ "price": 0 }), "/price"), None);
+        assert_eq!(parse_price(&json!({}), "/price"), None);
+    }
+}
@@ -0,0 +1,150 @@
+//! Dump miner - Everything about one managed miner in a single JSON document
+//!
+//! `dump-miner` derives the managed miner's PDAs, fetches the Manager,
+//! Deployer, managed_miner_auth and ORE Miner accounts (plus any lookup
+//! tables passed with `--lut`) in one getMultipleAccounts call, and prints
+//! them together. The bot doesn't create lookup tables itself, so only the
+//! ones given on the command line are included. Attach the output to issues.
+
+use serde_json::{json, Value};
+use solana_sdk::{
+    account::Account,
+    address_lookup_table::state::AddressLookupTable,
+    pubkey::Pubkey,
+};
+use steel::AccountDeserialize;
+
+use evore::network::NetworkConfig;
+use evore::ore_api::Miner;
+use evore::state::{Deployer, Manager};
+
+use crate::client::EvoreClient;
+
+/// Addresses of one managed miner, in the order `dump_json` expects them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinerAddresses {
+    pub manager: Pubkey,
+    pub auth_id: u64,
+    pub managed_miner_auth: (Pubkey, u8),
+    pub deployer: (Pubkey, u8),
+    pub miner: (Pubkey, u8),
+    pub luts: Vec<Pubkey>,
+}
+
+impl MinerAddresses {
+    pub fn derive(network: &NetworkConfig, manager: Pubkey, auth_id: u64, luts: Vec<Pubkey>) -> Self {
+        let managed_miner_auth = network.managed_miner_auth_pda(manager, auth_id);
+        Self {
+            manager,
+            auth_id,
+            managed_miner_auth,
+            deployer: network.deployer_pda(manager),
+            miner: network.miner_pda(managed_miner_auth.0),
+            luts,
+        }
+    }
+
+    /// [manager, deployer, managed_miner_auth, miner, luts..]
+    pub fn fetch_list(&self) -> Vec<Pubkey> {
+        let mut addresses = vec![self.manager, self.deployer.0, self.managed_miner_auth.0, self.miner.0];
+        addresses.extend(&self.luts);
+        addresses
+    }
+}
+
+/// Decode an account's data, or describe why it couldn't be
+fn decoded<T: serde::Serialize>(account: Option<&Account>, parse: impl FnOnce(&[u8]) -> Option<T>) -> Value {
+    match account {
+        None => Value::Null,
+        Some(a) => match parse(&a.data) {
+            Some(data) => json!({ "lamports": a.lamports, "owner": a.owner.to_string(), "data": data }),
+            None => json!({
+                "lamports": a.lamports,
+                "owner": a.owner.to_string(),
+                "error": format!("could not decode {} bytes", a.data.len()),
+            }),
+        },
+    }
+}
+
+fn lut_json(address: &Pubkey, account: Option<&Account>) -> Value {
+    let table = decoded(account, |data| {
+        let table = AddressLookupTable::deserialize(data).ok()?;
+        Some(json!({
+            "authority": table.meta.authority.map(|a| a.to_string()),
+            "deactivation_slot": table.meta.deactivation_slot,
+            "last_extended_slot": table.meta.last_extended_slot,
+            "addresses": table.addresses.iter().map(|a| a.to_string()).collect::<Vec<_>>(),
+        }))
+    });
+    json!({ "address": address.to_string(), "account": table })
+}
+
+/// JSON dump from the accounts fetched for `addresses.fetch_list()` (same order)
+pub fn dump_json(addresses: &MinerAddresses, accounts: &[Option<Account>], slot: u64) -> Value {
+    let get = |i: usize| accounts.get(i).and_then(Option::as_ref);
+    let pda = |(address, bump): (Pubkey, u8)| json!({ "address": address.to_string(), "bump": bump });
+
+    json!({
+        "slot": slot,
+        "manager": addresses.manager.to_string(),
+        "auth_id": addresses.auth_id,
+        "pdas": {
+            "managed_miner_auth": pda(addresses.managed_miner_auth),
+            "deployer": pda(addresses.deployer),
+            "miner": pda(addresses.miner),
+        },
+        "manager_account": decoded(get(0), |d| Manager::try_from_bytes(d).ok().copied()),
+        "deployer_account": decoded(get(1), |d| Deployer::try_from_bytes(d).ok().copied()),
+        "managed_miner_auth_balance": get(2).map_or(0, |a| a.lamports),
+        "miner_account": decoded(get(3), |d| Miner::try_from_bytes(d).ok().copied()),
+        "luts": addresses.luts.iter()
+            .enumerate()
+            .map(|(i, lut)| lut_json(lut, get(4 + i)))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Fetch and dump one managed miner
+pub fn dump_miner(
+    client: &EvoreClient,
+    manager: Pubkey,
+    auth_id: u64,
+    luts: Vec<Pubkey>,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let addresses = MinerAddresses::derive(client.network(), manager, auth_id, luts);
+    let slot = client.get_slot()?;
+    let accounts = client.get_multiple_accounts(&addresses.fetch_list())?;
+    Ok(dump_json(&addresses, &accounts, slot))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dump_json_missing_and_undecodable() {
+        let network = NetworkConfig::mainnet();
+        let manager = Pubkey::new_unique();
+        let lut = Pubkey::new_unique();
+        let addresses = MinerAddresses::derive(&network, manager, 3, vec![lut]);
+
+        let list = addresses.fetch_list();
+        assert_eq!(list.len(), 5);
+        assert_eq!(list[2], evore::state::managed_miner_auth_pda(manager, 3).0);
+        assert_eq!(list[3], network.miner_pda(list[2]).0);
+
+        let mut accounts = vec![None; 5];
+        accounts[2] = Some(Account { lamports: 5_000_000, ..Account::default() });
+        accounts[4] = Some(Account { lamports: 1, data: vec![0; 4], ..Account::default() });
+
+        let dump = dump_json(&addresses, &accounts, 42);
+        assert_eq!(dump["slot"], 42);
+        assert_eq!(dump["auth_id"], 3);
+        assert!(dump["manager_account"].is_null());
+        assert!(dump["miner_account"].is_null());
+        assert_eq!(dump["managed_miner_auth_balance"], 5_000_000);
+        assert_eq!(dump["luts"][0]["address"], lut.to_string());
+        assert_eq!(dump["luts"][0]["account"]["error"], "could not decode 4 bytes");
+    }
+}
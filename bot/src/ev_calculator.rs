@@ -8,12 +8,33 @@
 //!
 //! Optimal stake (no limits, maximizes EV):
 //! x* = sqrt(T * 891 * L / 24010) - T
+//!
+//! `ev_details` gives the per-square view behind a bet vector (pot, marginal
+//! EV, chosen amount) for `status --output json` and the dashboard's EV view.
+//!
+//! `clamp_square_count` bounds how many squares a bet vector covers
+//! (`min_squares` / `max_squares`), moving capital along the EV ranking,
+//! and `exclude_squares` moves bets off a bot's `square_blacklist`.
+//!
+//! Also holds `rotate_squares`, the per-round square shift used by
+//! bots with `rotate_squares` enabled, and `simulate_round`, a Monte Carlo
+//! view of a bet vector's SOL return distribution (`evore-bot simulate`).
+//!
+//! `percentage_bets` sizes the percentage strategy over a fixed set of
+//! squares (`squares` in a Percentage bot's config), and `amount_to_lead`
+//! sizes the LeadSquare strategy's single bet.
 
 /// Constants from the on-chain EV calculation
 const NUM: u128 = 891;       // 89.1% - fraction of losers' pool to winners
 const DEN24: u128 = 24_010;  // derived from 1/P(win) adjusted for 89.1%
 const C_LAM: u128 = 25_000;  // 25 squares * 1000 fixed-point multiplier
 
+/// Per-mille of a winning stake lost to fees (the part of DEN24 above 24 * 1000)
+const WIN_FEE_PER_MILLE: u128 = DEN24 - 24_000;
+
+/// Equal chance for every square to win
+pub const UNIFORM_WEIGHTS: [f64; 25] = [1.0; 25];
+
 /// EV calculation result for a single square
 #[derive(Clone, Debug, Default)]
 pub struct SquareEV {
@@ -42,6 +63,46 @@ pub struct BoardEV {
     pub positive_ev_count: usize,
 }
 
+/// Bet size used for `EvDetail::marginal_ev` (0.01 SOL)
+pub const EV_UNIT_BET: u64 = 10_000_000;
+
+/// Per-square inputs and outcome of a bet decision
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize)]
+pub struct EvDetail {
+    /// Square index (0-24)
+    pub square: usize,
+    /// Current total deployed on this square
+    pub pot: u64,
+    /// Expected SOL profit of an extra `EV_UNIT_BET` on this square (lamports, signed)
+    pub marginal_ev: i64,
+    /// Amount the strategy put on this square (lamports)
+    pub chosen: u64,
+}
+
+/// Per-square EV breakdown of `chosen` against the current board
+pub fn ev_details(deployed: &[u64; 25], chosen: &[u64; 25]) -> [EvDetail; 25] {
+    let total_sum: u64 = deployed.iter().sum();
+    std::array::from_fn(|i| EvDetail {
+        square: i,
+        pot: deployed[i],
+        marginal_ev: stake_ev(total_sum, deployed[i], EV_UNIT_BET),
+        chosen: chosen[i],
+    })
+}
+
+/// EV of `stake` on a square holding `ti` of `total_sum` (same formula as
+/// `calculate_ev`, but an empty square is valued too)
+fn stake_ev(total_sum: u64, ti: u64, stake: u64) -> i64 {
+    let l = total_sum.saturating_sub(ti) as i128;
+    let x = stake as i128;
+    let tx = ti as i128 + x;
+    if tx == 0 {
+        return 0;
+    }
+    let ev = x * (NUM as i128 * l - DEN24 as i128 * tx) / (C_LAM as i128 * tx);
+    ev.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
 /// Calculate optimal stake for a single square (pure SOL EV, no ore value)
 ///
 /// x* = sqrt(T * NUM * L / DEN24) - T
@@ -162,6 +223,268 @@ pub fn calculate_board_ev(deployed: &[u64; 25]) -> BoardEV {
     result
 }
 
+/// Bound the number of squares `bets` covers, keeping the same total
+///
+/// Squares are ranked by marginal EV against `deployed` (best first).
+/// Above `max_squares`, only the best selected squares are kept and the
+/// dropped capital is spread over them pro rata. Below `min_squares`, the
+/// best unselected squares are added with an equal share each, taken pro
+/// rata from the existing bets. 0 disables either bound; `min_squares` is
+/// capped at `max_squares` when both are set. Rounding dust goes to the
+/// best kept square.
+pub fn clamp_square_count(
+    bets: &[u64; 25],
+    deployed: &[u64; 25],
+    min_squares: usize,
+    max_squares: usize,
+) -> [u64; 25] {
+    let total: u64 = bets.iter().sum();
+    if total == 0 {
+        return *bets;
+    }
+
+    let total_sum: u64 = deployed.iter().sum();
+    let mut ranked: Vec<usize> = (0..25).collect();
+    ranked.sort_by_key(|&i| (std::cmp::Reverse(stake_ev(total_sum, deployed[i], EV_UNIT_BET)), i));
+
+    let selected: Vec<usize> = ranked.iter().copied().filter(|&i| bets[i] > 0).collect();
+    let max_squares = if max_squares == 0 { 25 } else { max_squares.min(25) };
+    let min_squares = min_squares.min(max_squares);
+
+    let mut out = [0u64; 25];
+    if selected.len() > max_squares {
+        // Ceiling: concentrate on the best max_squares
+        let kept = &selected[..max_squares];
+        let kept_sum: u64 = kept.iter().map(|&i| bets[i]).sum();
+        for &i in kept {
+            out[i] = (bets[i] as u128 * total as u128 / kept_sum as u128) as u64;
+        }
+    } else if selected.len() < min_squares {
+        // Floor: add the next-best squares with an equal share each
+        let added: Vec<usize> = ranked.iter().copied()
+            .filter(|&i| bets[i] == 0)
+            .take(min_squares - selected.len())
+            .collect();
+        let share = total / min_squares as u64;
+        let remaining = total - share * added.len() as u64;
+        for &i in &selected {
+            out[i] = (bets[i] as u128 * remaining as u128 / total as u128) as u64;
+        }
+        for &i in &added {
+            out[i] = share;
+        }
+    } else {
+        return *bets;
+    }
+
+    let dust = total - out.iter().sum::<u64>();
+    if let Some(&best) = ranked.iter().find(|&&i| out[i] > 0) {
+        out[best] += dust;
+    }
+    out
+}
+
+/// Move the bets on `blacklist` squares to the best allowed squares, keeping the same total
+///
+/// Squares are ranked by marginal EV against `deployed` (best first). Each
+/// blacklisted bet moves whole to the next-best allowed square that has no
+/// bet yet, so the number of squares is unchanged; once every allowed square
+/// has a bet, the rest goes to the best allowed square. Indices above 24 are
+/// ignored. Returns all zeros when every square is blacklisted.
+pub fn exclude_squares(bets: &[u64; 25], deployed: &[u64; 25], blacklist: &[u8]) -> [u64; 25] {
+    let blocked = |i: usize| blacklist.contains(&(i as u8));
+    if !(0..25).any(|i| bets[i] > 0 && blocked(i)) {
+        return *bets;
+    }
+
+    let total_sum: u64 = deployed.iter().sum();
+    let mut ranked: Vec<usize> = (0..25).filter(|&i| !blocked(i)).collect();
+    ranked.sort_by_key(|&i| (std::cmp::Reverse(stake_ev(total_sum, deployed[i], EV_UNIT_BET)), i));
+
+    let mut out = [0u64; 25];
+    for &i in &ranked {
+        out[i] = bets[i];
+    }
+    let mut moved: Vec<usize> = (0..25).filter(|&i| bets[i] > 0 && blocked(i)).collect();
+    moved.sort_by_key(|&i| (std::cmp::Reverse(bets[i]), i));
+
+    let mut unselected = ranked.iter().copied().filter(|&i| bets[i] == 0);
+    for i in moved {
+        match unselected.next().or_else(|| ranked.first().copied()) {
+            Some(target) => out[target] = out[target].saturating_add(bets[i]),
+            None => return [0; 25],
+        }
+    }
+    out
+}
+
+/// Bets owning `percentage` (basis points) of each square in `squares`
+///
+/// Same sizing as the on-chain percentage strategy, over the given squares
+/// instead of the first `squares_count`: each gets P * T / (10000 - P), and
+/// P is lowered to what `bankroll` covers across the set. Empty squares are
+/// skipped (there's no share of nothing to buy), as are indices above 24.
+pub fn percentage_bets(deployed: &[u64; 25], bankroll: u64, percentage: u64, squares: &[u8]) -> [u64; 25] {
+    let mut bets = [0u64; 25];
+    if percentage == 0 || percentage >= 10_000 || bankroll == 0 {
+        return bets;
+    }
+
+    let targets: Vec<usize> = (0..25)
+        .filter(|&i| squares.contains(&(i as u8)) && deployed[i] > 0)
+        .collect();
+    let total: u128 = targets.iter().map(|&i| deployed[i] as u128).sum();
+    if total == 0 {
+        return bets;
+    }
+
+    // Lower the percentage when the bankroll can't buy it on every square
+    let (p, b) = (percentage as u128, bankroll as u128);
+    let p = if p * total / (10_000 - p) > b {
+        (10_000 * b / (total + b)).min(p)
+    } else {
+        p
+    };
+    if p == 0 {
+        return bets;
+    }
+
+    for &i in &targets {
+        bets[i] = (p * deployed[i] as u128 / (10_000 - p)).min(u64::MAX as u128) as u64;
+    }
+    bets
+}
+
+/// Lamports that make `square` the most-deployed square on the board
+///
+/// One more than the gap to the largest other square, so a tie needs 1 and a
+/// square that already leads outright needs 0. The result is clamped to
+/// `bankroll` and `max_per_square`, so a clamped amount may fall short of
+/// the lead. Returns 0 for indices above 24.
+pub fn amount_to_lead(deployed: &[u64; 25], square: usize, bankroll: u64, max_per_square: u64) -> u64 {
+    if square >= 25 {
+        return 0;
+    }
+    let leader = (0..25).filter(|&i| i != square).map(|i| deployed[i]).max().unwrap_or(0);
+    if deployed[square] > leader {
+        return 0;
+    }
+    (leader - deployed[square]).saturating_add(1).min(bankroll).min(max_per_square)
+}
+
+/// Shift per-square amounts by a round-dependent offset
+///
+/// Every amount moves by the same offset, so the number of squares and the
+/// amounts are unchanged but their positions differ from round to round.
+/// The offset is derived from round_id only, so all attempts for a round agree.
+pub fn rotate_squares(amounts: &[u64; 25], round_id: u64) -> [u64; 25] {
+    let offset = rotation_offset(round_id);
+    let mut rotated = [0u64; 25];
+    for (i, amount) in amounts.iter().enumerate() {
+        rotated[(i + offset) % 25] = *amount;
+    }
+    rotated
+}
+
+/// Square offset for a round (splitmix64 of round_id, mod 25)
+fn rotation_offset(round_id: u64) -> usize {
+    let mut state = round_id;
+    (splitmix64(&mut state) % 25) as usize
+}
+
+/// Next value of a splitmix64 sequence
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Distribution of SOL returns over simulated rounds (lamports, signed)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OutcomeDistribution {
+    pub trials: u32,
+    pub mean: f64,
+    pub p5: i64,
+    pub p50: i64,
+    pub p95: i64,
+    /// Fraction of trials with a positive return
+    pub win_rate: f64,
+}
+
+/// Net SOL return of `bets` if `winner` is the winning square
+///
+/// `pot` is everyone else's deployment. A winning stake is returned with its
+/// share of 89.1% of the losing squares (ours included) minus a 1% fee;
+/// stakes on every other square are lost. ORE rewards are not included.
+pub fn round_return(bets: &[u64; 25], pot: &[u64; 25], winner: usize) -> i64 {
+    let stake = bets[winner] as u128;
+    let lost: u128 = bets.iter().enumerate()
+        .filter(|(i, _)| *i != winner)
+        .map(|(_, b)| *b as u128)
+        .sum();
+
+    let won = if stake == 0 {
+        0
+    } else {
+        let losers: u128 = (0..25)
+            .filter(|i| *i != winner)
+            .map(|i| pot[i] as u128 + bets[i] as u128)
+            .sum();
+        let share = stake * NUM * losers / (1000 * (pot[winner] as u128 + stake));
+        share.saturating_sub(stake * WIN_FEE_PER_MILLE / 1000)
+    };
+
+    (won as i128 - lost as i128).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Monte Carlo of one round's SOL return for `bets`
+///
+/// Each trial draws a winning square with probability proportional to
+/// `weights` (all zero = uniform). The same `seed` gives the same result.
+pub fn simulate_round(
+    bets: &[u64; 25],
+    pot: &[u64; 25],
+    weights: &[f64; 25],
+    trials: u32,
+    seed: u64,
+) -> OutcomeDistribution {
+    let trials = trials.max(1);
+    let weights = if weights.iter().any(|w| *w > 0.0) { weights } else { &UNIFORM_WEIGHTS };
+    let total_weight: f64 = weights.iter().map(|w| w.max(0.0)).sum();
+    let returns_by_square: Vec<i64> = (0..25).map(|i| round_return(bets, pot, i)).collect();
+
+    let mut state = seed;
+    let mut returns: Vec<i64> = (0..trials)
+        .map(|_| {
+            // Uniform in [0, total_weight) from the top 53 bits
+            let mut target = (splitmix64(&mut state) >> 11) as f64 / (1u64 << 53) as f64 * total_weight;
+            let mut winner = 24;
+            for (i, w) in weights.iter().enumerate() {
+                let w = w.max(0.0);
+                if w > 0.0 && target < w {
+                    winner = i;
+                    break;
+                }
+                target -= w;
+            }
+            returns_by_square[winner]
+        })
+        .collect();
+    returns.sort_unstable();
+
+    let percentile = |q: f64| returns[((returns.len() - 1) as f64 * q).round() as usize];
+    OutcomeDistribution {
+        trials,
+        mean: returns.iter().map(|r| *r as f64).sum::<f64>() / trials as f64,
+        p5: percentile(0.05),
+        p50: percentile(0.50),
+        p95: percentile(0.95),
+        win_rate: returns.iter().filter(|r| **r > 0).count() as f64 / trials as f64,
+    }
+}
+
 /// Integer floor sqrt for u128 (Newton's method)
 fn isqrt_u128(n: u128) -> u128 {
     if n < 2 {
@@ -190,6 +513,119 @@ mod tests {
         assert_eq!(isqrt_u128(101), 10); // floor
     }
 
+    #[test]
+    fn test_ev_details() {
+        let mut deployed = [1_000_000_000u64; 25];
+        deployed[3] = 0;
+        deployed[7] = 20_000_000_000;
+        let mut chosen = [0u64; 25];
+        chosen[3] = 5_000;
+
+        let details = ev_details(&deployed, &chosen);
+        assert_eq!((details[3].square, details[3].pot, details[3].chosen), (3, 0, 5_000));
+        // Same formula as calculate_ev where both apply
+        let total: u64 = deployed.iter().sum();
+        assert_eq!(details[0].marginal_ev, calculate_ev(total, deployed[0], EV_UNIT_BET));
+        // An empty square takes the whole share, a crowded one loses
+        assert!(details[3].marginal_ev > details[0].marginal_ev);
+        assert!(details[7].marginal_ev < 0);
+    }
+
+    #[test]
+    fn test_clamp_square_count_floor() {
+        // Square 0 is the most crowded, 24 the emptiest
+        let deployed: [u64; 25] = std::array::from_fn(|i| (25 - i as u64) * 100_000_000);
+        let mut bets = [0u64; 25];
+        bets[20] = 900_000_000;
+
+        let clamped = clamp_square_count(&bets, &deployed, 3, 0);
+        assert_eq!(clamped.iter().sum::<u64>(), 900_000_000);
+        assert_eq!(clamped.iter().filter(|&&b| b > 0).count(), 3);
+        // The added squares are the best-ranked unselected ones
+        assert_eq!(clamped[24], 300_000_000);
+        assert_eq!(clamped[23], 300_000_000);
+        assert_eq!(clamped[20], 300_000_000);
+
+        // Already wide enough - unchanged
+        assert_eq!(clamp_square_count(&clamped, &deployed, 3, 0), clamped);
+        // Nothing to spread
+        assert_eq!(clamp_square_count(&[0; 25], &deployed, 3, 0), [0; 25]);
+    }
+
+    #[test]
+    fn test_clamp_square_count_ceiling() {
+        let deployed: [u64; 25] = std::array::from_fn(|i| (25 - i as u64) * 100_000_000);
+        let mut bets = [0u64; 25];
+        bets[10] = 100_000_000;
+        bets[22] = 200_000_000;
+        bets[23] = 300_000_000;
+        bets[24] = 400_000_001;
+
+        let clamped = clamp_square_count(&bets, &deployed, 0, 2);
+        assert_eq!(clamped.iter().sum::<u64>(), 1_000_000_001);
+        assert_eq!(clamped.iter().filter(|&&b| b > 0).count(), 2);
+        // Worst squares dropped, capital concentrated pro rata on the best two
+        assert_eq!((clamped[10], clamped[22]), (0, 0));
+        assert_eq!(clamped[23], 428_571_428);
+        assert_eq!(clamped[24], 571_428_573);
+
+        // min above max is capped at max
+        assert_eq!(clamp_square_count(&bets, &deployed, 5, 2), clamped);
+        // Both bounds off - unchanged
+        assert_eq!(clamp_square_count(&bets, &deployed, 0, 0), bets);
+    }
+
+    #[test]
+    fn test_percentage_bets() {
+        let mut deployed = [0u64; 25];
+        deployed[2] = 9_000_000;
+        deployed[7] = 18_000_000;
+        deployed[20] = 1_000_000;
+
+        // 10% of squares 2 and 7: P * T / (10000 - P); square 20 isn't in the set
+        let bets = percentage_bets(&deployed, 1_000_000_000, 1000, &[2, 7]);
+        assert_eq!((bets[2], bets[7], bets[20]), (1_000_000, 2_000_000, 0));
+        assert_eq!(bets.iter().sum::<u64>(), 3_000_000);
+
+        // Empty squares and indices above 24 are skipped
+        let bets = percentage_bets(&deployed, 1_000_000_000, 1000, &[0, 7, 30]);
+        assert_eq!((bets[0], bets[7]), (0, 2_000_000));
+        assert_eq!(bets.iter().filter(|&&b| b > 0).count(), 1);
+
+        // A short bankroll lowers the percentage across the whole set
+        let bets = percentage_bets(&deployed, 1_500_000, 1000, &[2, 7]);
+        assert!(bets.iter().sum::<u64>() <= 1_500_000);
+        assert_eq!(bets[7], bets[2] * 2);
+
+        assert_eq!(percentage_bets(&deployed, 1_000_000_000, 0, &[2]), [0; 25]);
+        assert_eq!(percentage_bets(&deployed, 1_000_000_000, 10_000, &[2]), [0; 25]);
+        assert_eq!(percentage_bets(&deployed, 1_000_000_000, 1000, &[]), [0; 25]);
+    }
+
+    #[test]
+    fn test_exclude_squares() {
+        // Square 24 is the EV-optimal square, 23 the second-best
+        let deployed: [u64; 25] = std::array::from_fn(|i| (25 - i as u64) * 100_000_000);
+        let mut bets = [0u64; 25];
+        bets[24] = 500_000_000;
+
+        let moved = exclude_squares(&bets, &deployed, &[24]);
+        assert_eq!(moved[24], 0);
+        assert_eq!(moved[23], 500_000_000);
+        assert_eq!(moved.iter().sum::<u64>(), 500_000_000);
+
+        // The second-best already has a bet, so the third-best takes it
+        bets[23] = 200_000_000;
+        let moved = exclude_squares(&bets, &deployed, &[24, 99]);
+        assert_eq!((moved[24], moved[23], moved[22]), (0, 200_000_000, 500_000_000));
+
+        // Nothing blacklisted is bet on - unchanged
+        assert_eq!(exclude_squares(&bets, &deployed, &[0]), bets);
+        // Every square blacklisted
+        let all: Vec<u8> = (0..25).collect();
+        assert_eq!(exclude_squares(&bets, &deployed, &all), [0; 25]);
+    }
+
     #[test]
     fn test_empty_board() {
         let deployed = [0u64; 25];
@@ -232,5 +668,91 @@ mod tests {
         println!("Total: optimal_stake={}, expected_profit={}, +ev_count={}",
             result.total_optimal_stake, result.total_expected_profit, result.positive_ev_count);
     }
-}
 
+    #[test]
+    fn test_rotate_squares_deterministic_and_count_preserving() {
+        let mut amounts = [0u64; 25];
+        amounts[0] = 1_000;
+        amounts[3] = 2_000;
+        amounts[24] = 3_000;
+
+        for round_id in [0u64, 1, 2, 1_000, u64::MAX] {
+            let rotated = rotate_squares(&amounts, round_id);
+            assert_eq!(rotated, rotate_squares(&amounts, round_id));
+            assert_eq!(rotated.iter().filter(|a| **a > 0).count(), 3);
+            assert_eq!(rotated.iter().sum::<u64>(), 6_000);
+        }
+
+        // Positions change across rounds
+        let distinct: std::collections::HashSet<[u64; 25]> =
+            (0..10).map(|round_id| rotate_squares(&amounts, round_id)).collect();
+        assert!(distinct.len() > 1);
+    }
+
+    #[test]
+    fn test_round_return() {
+        let mut pot = [0u64; 25];
+        pot[0] = 1_000_000_000;
+        pot[1] = 1_000_000_000;
+        let mut bets = [0u64; 25];
+        bets[0] = 1_000_000_000;
+        bets[2] = 500_000_000;
+
+        // Win on 0: half of 89.1% of 1.5 SOL, minus 1% of the stake
+        assert_eq!(round_return(&bets, &pot, 0), 668_250_000 - 10_000_000 - 500_000_000);
+        // Win elsewhere: everything is lost
+        assert_eq!(round_return(&bets, &pot, 1), -1_500_000_000);
+        assert_eq!(round_return(&[0; 25], &pot, 0), 0);
+    }
+
+    #[test]
+    fn test_simulate_round() {
+        let mut bets = [0u64; 25];
+        bets[0] = 1_000;
+        let pot = [1_000u64; 25];
+
+        // Only square 0 can win: every trial returns the same amount
+        let mut weights = [0.0; 25];
+        weights[0] = 1.0;
+        let certain = simulate_round(&bets, &pot, &weights, 100, 7);
+        let win = round_return(&bets, &pot, 0);
+        assert_eq!((certain.p5, certain.p50, certain.p95), (win, win, win));
+        assert_eq!(certain.win_rate, 1.0);
+
+        // Uniform: a 1-in-25 win, reproducible from the seed
+        let uniform = simulate_round(&bets, &pot, &UNIFORM_WEIGHTS, 10_000, 7);
+        assert_eq!(uniform, simulate_round(&bets, &pot, &[0.0; 25], 10_000, 7));
+        assert_eq!((uniform.p5, uniform.p50, uniform.p95), (-1_000, -1_000, -1_000));
+        assert!((uniform.win_rate - 0.04).abs() < 0.01);
+        assert!(uniform.mean > -1_000.0 && uniform.mean < win as f64);
+    }
+
+    #[test]
+    fn test_amount_to_lead() {
+        let mut deployed = [0u64; 25];
+        deployed[3] = 5_000;
+        deployed[7] = 2_000;
+
+        // Behind the leader: the gap plus one
+        assert_eq!(amount_to_lead(&deployed, 7, u64::MAX, u64::MAX), 3_001);
+        // Empty square
+        assert_eq!(amount_to_lead(&deployed, 0, u64::MAX, u64::MAX), 5_001);
+        // Already leading outright
+        assert_eq!(amount_to_lead(&deployed, 3, u64::MAX, u64::MAX), 0);
+
+        // A tie for the lead needs one lamport, whichever square holds it
+        deployed[7] = 5_000;
+        assert_eq!(amount_to_lead(&deployed, 7, u64::MAX, u64::MAX), 1);
+        assert_eq!(amount_to_lead(&deployed, 3, u64::MAX, u64::MAX), 1);
+
+        // Empty board: any lamport leads
+        assert_eq!(amount_to_lead(&[0; 25], 12, u64::MAX, u64::MAX), 1);
+
+        // Clamped by bankroll and max_per_square
+        assert_eq!(amount_to_lead(&deployed, 0, 4_000, u64::MAX), 4_000);
+        assert_eq!(amount_to_lead(&deployed, 0, u64::MAX, 2_500), 2_500);
+
+        // Out of range
+        assert_eq!(amount_to_lead(&deployed, 25, u64::MAX, u64::MAX), 0);
+    }
+}
@@ -0,0 +1,202 @@
+//! Fee Estimator - per-round cost of a bot's deploy settings
+//!
+//! Used by `evore-bot estimate-fees`. Every deploy attempt pays the base
+//! signature fee plus its priority fee on the full CU limit. The Jito tip and
+//! the Evore deploy fee only move on the attempt that lands - later duplicates
+//! fail and revert both - so the estimate counts them once. ORE-side fees
+//! (e.g. the checkpoint fee) are not included.
+//!
+//! The coordinator also uses these to check at round start that each bot can
+//! pay for a full deploy before its deploy window opens.
+
+use evore::consts::DEPLOY_FEE;
+use evore::ore_api::CHECKPOINT_FEE;
+
+use crate::config::{PriorityFeeSource, StrategyParams};
+use crate::deploy::DEPLOY_CU_LIMIT;
+
+/// Base fee per transaction signature (lamports)
+pub const BASE_FEE_LAMPORTS: u64 = 5_000;
+
+/// Rent-exempt minimum the managed_miner_auth PDA keeps (0-byte account)
+const AUTH_PDA_RENT: u64 = 890_880;
+
+/// Per-round fee breakdown for one bot (all lamports)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeEstimate {
+    /// Base + priority fee for a single attempt
+    pub per_attempt: u64,
+    /// Priority fee part of `per_attempt`
+    pub priority: u64,
+    pub attempts: u64,
+    pub jito_tip: u64,
+    /// Evore flat fee per landed deploy
+    pub deploy_fee: u64,
+    /// Upper bound for a round where every attempt lands
+    pub per_round: u64,
+}
+
+/// Lamports paid for `priority_fee` micro-lamports per CU over `cu_limit` CUs (rounded up)
+pub fn priority_fee_lamports(priority_fee: u64, cu_limit: u32) -> u64 {
+    let micro = priority_fee as u128 * cu_limit as u128;
+    micro.div_ceil(1_000_000).min(u64::MAX as u128) as u64
+}
+
+/// Estimate one round of deploys with the given settings
+pub fn estimate_round_fees(priority_fee: u64, jito_tip: u64, attempts: u64) -> FeeEstimate {
+    let attempts = attempts.max(1);
+    let priority = priority_fee_lamports(priority_fee, DEPLOY_CU_LIMIT);
+    let per_attempt = BASE_FEE_LAMPORTS.saturating_add(priority);
+    let per_round = per_attempt
+        .saturating_mul(attempts)
+        .saturating_add(jito_tip)
+        .saturating_add(DEPLOY_FEE);
+
+    FeeEstimate {
+        per_attempt,
+        priority,
+        attempts,
+        jito_tip,
+        deploy_fee: DEPLOY_FEE,
+        per_round,
+    }
+}
+
+/// Stake a bot places in a full round: the manual amounts, otherwise its bankroll
+pub fn expected_stake(strategy_params: &StrategyParams, bankroll: u64) -> u64 {
+    match strategy_params {
+        StrategyParams::Manual { amounts } => amounts.iter().fold(0u64, |sum, a| sum.saturating_add(*a)),
+        _ => bankroll,
+    }
+}
+
+/// Basis points every bot's stake is scaled to so the fleet's `total` fits `cap`
+/// (10_000 = unscaled)
+pub fn fleet_scale_bps(total: u64, cap: u64) -> u64 {
+    if total <= cap {
+        return 10_000;
+    }
+    (cap as u128 * 10_000 / total as u128) as u64
+}
+
+/// Strategy params and bankroll with the stake scaled to `scale_bps`
+///
+/// EV and Percentage bets are sized from the bankroll, Manual from its amounts.
+pub fn scale_stake(strategy_params: &StrategyParams, bankroll: u64, scale_bps: u64) -> (StrategyParams, u64) {
+    if scale_bps >= 10_000 {
+        return (strategy_params.clone(), bankroll);
+    }
+    let scale = |amount: u64| (amount as u128 * scale_bps as u128 / 10_000) as u64;
+    let strategy_params = match strategy_params {
+        StrategyParams::Manual { amounts } => StrategyParams::Manual { amounts: amounts.map(scale) },
+        params => params.clone(),
+    };
+    (strategy_params, scale(bankroll))
+}
+
+/// Lamports needed for one round's deploy: the stake plus every fee the signer pays
+pub fn deploy_cost(stake: u64, priority_fee: u64, jito_tip: u64, attempts: u64) -> u64 {
+    stake.saturating_add(estimate_round_fees(priority_fee, jito_tip, attempts).per_round)
+}
+
+/// Lamports a deploy can draw on
+///
+/// mm_deploy tops the managed_miner_auth up from the signer, so both count,
+/// minus what the PDA must keep for rent and the ORE checkpoint fee.
+pub fn available_funds(signer_balance: u64, auth_balance: u64) -> u64 {
+    signer_balance.saturating_add(auth_balance.saturating_sub(AUTH_PDA_RENT + CHECKPOINT_FEE))
+}
+
+/// Priority fee (micro-lamports per CU) to use for a deploy
+///
+/// `recent` are the recent prioritization fees (unsorted); a dynamic source
+/// without any falls back to `priority_fee`, still clamped to its floor/cap.
+pub fn effective_priority_fee(source: PriorityFeeSource, priority_fee: u64, recent: &[u64]) -> u64 {
+    match source {
+        PriorityFeeSource::Fixed => priority_fee,
+        PriorityFeeSource::Dynamic { percentile, floor, cap } => {
+            let fee = if recent.is_empty() {
+                priority_fee
+            } else {
+                let mut fees = recent.to_vec();
+                fees.sort_unstable();
+                let rank = (fees.len() - 1) * percentile.min(100) as usize / 100;
+                fees[rank]
+            };
+            fee.max(floor).min(cap.max(floor))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_fee_rounds_up() {
+        assert_eq!(priority_fee_lamports(0, DEPLOY_CU_LIMIT), 0);
+        // 5000 micro-lamports x 1.4M CU = 7000 lamports
+        assert_eq!(priority_fee_lamports(5_000, DEPLOY_CU_LIMIT), 7_000);
+        assert_eq!(priority_fee_lamports(1, 1), 1);
+    }
+
+    #[test]
+    fn test_estimate_counts_tip_and_deploy_fee_once() {
+        let estimate = estimate_round_fees(5_000, 200_000, 4);
+        assert_eq!(estimate.per_attempt, 12_000);
+        assert_eq!(estimate.per_round, 4 * 12_000 + 200_000 + DEPLOY_FEE);
+
+        // attempts = 0 still sends one transaction
+        assert_eq!(estimate_round_fees(0, 0, 0).per_round, BASE_FEE_LAMPORTS + DEPLOY_FEE);
+    }
+
+    #[test]
+    fn test_effective_priority_fee() {
+        let recent = [900, 100, 500, 300, 700];
+        assert_eq!(effective_priority_fee(PriorityFeeSource::Fixed, 5_000, &recent), 5_000);
+
+        let dynamic = |percentile, floor, cap| PriorityFeeSource::Dynamic { percentile, floor, cap };
+        assert_eq!(effective_priority_fee(dynamic(0, 0, u64::MAX), 5_000, &recent), 100);
+        assert_eq!(effective_priority_fee(dynamic(50, 0, u64::MAX), 5_000, &recent), 500);
+        assert_eq!(effective_priority_fee(dynamic(100, 0, u64::MAX), 5_000, &recent), 900);
+        // Clamped to floor..=cap
+        assert_eq!(effective_priority_fee(dynamic(0, 200, 800), 5_000, &recent), 200);
+        assert_eq!(effective_priority_fee(dynamic(100, 200, 800), 5_000, &recent), 800);
+        // No recent fees: fall back to priority_fee, still clamped
+        assert_eq!(effective_priority_fee(dynamic(50, 0, 4_000), 5_000, &[]), 4_000);
+    }
+
+    #[test]
+    fn test_deploy_cost_and_funds() {
+        let mut amounts = [0u64; 25];
+        amounts[0] = 1_000_000;
+        amounts[5] = 2_000_000;
+        assert_eq!(expected_stake(&StrategyParams::Manual { amounts }, 50_000_000), 3_000_000);
+        assert_eq!(expected_stake(&StrategyParams::default(), 50_000_000), 50_000_000);
+
+        let cost = deploy_cost(3_000_000, 5_000, 200_000, 4);
+        assert_eq!(cost, 3_000_000 + estimate_round_fees(5_000, 200_000, 4).per_round);
+
+        // The PDA's rent and checkpoint reserve can't be deployed
+        assert_eq!(available_funds(1_000, AUTH_PDA_RENT + CHECKPOINT_FEE + 500), 1_500);
+        assert_eq!(available_funds(1_000, 100), 1_000);
+    }
+
+    #[test]
+    fn test_fleet_scale() {
+        assert_eq!(fleet_scale_bps(0, 0), 10_000);
+        assert_eq!(fleet_scale_bps(1_000, 1_000), 10_000);
+        assert_eq!(fleet_scale_bps(4_000, 1_000), 2_500);
+        assert_eq!(fleet_scale_bps(3_000, 1_000), 3_333);
+
+        let mut amounts = [0u64; 25];
+        amounts[3] = 1_000_000;
+        let manual = StrategyParams::Manual { amounts };
+        let (params, bankroll) = scale_stake(&manual, 50_000_000, 10_000);
+        assert_eq!((expected_stake(&params, bankroll), bankroll), (1_000_000, 50_000_000));
+        let (params, bankroll) = scale_stake(&manual, 50_000_000, 2_500);
+        assert_eq!((expected_stake(&params, bankroll), bankroll), (250_000, 12_500_000));
+        let (params, bankroll) = scale_stake(&StrategyParams::default(), 50_000_000, 2_500);
+        assert_eq!(expected_stake(&params, bankroll), 12_500_000);
+    }
+}
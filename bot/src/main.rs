@@ -8,6 +8,8 @@ use solana_sdk::{
 };
 use tokio::sync::mpsc;
 
+mod auth_group;
+mod backtest;
 mod blockhash_cache;
 mod board_tracker;
 mod bot_runner;
@@ -15,33 +17,58 @@ mod bot_state;
 mod bot_task;
 mod client;
 mod config;
+mod confirmation_tracker;
 mod coordinator;
 mod deploy;
+mod deploy_audit;
+mod deploy_export;
+mod display_units;
+mod dump_miner;
+mod fee_estimate;
 mod ev_calculator;
 mod manage;
 mod manage_tui;
 mod miner_tracker;
+mod monitor;
+mod prepare;
+mod priority_fee_cache;
 mod round_tracker;
+mod rpc_headers;
+mod safe_mode;
 mod sender;
 mod shutdown;
 mod slot_tracker;
+mod slots_left_tuner;
 mod treasury_tracker;
 mod tui;
 mod tx_pipeline;
+mod units;
+mod verify_constants;
+mod watch_square;
 
 use bot_task::{run_bot_task, BotConfig};
-use client::{print_managed_miner_info, EvoreClient};
-use deploy::{continuous_deploy, single_deploy, EvDeployParams};
+use evore::board::Board25;
+use evore::network::{Network, NetworkConfig};
+use client::{print_managed_miner_info, EvoreClient, RoundState};
+use deploy::{continuous_deploy, single_deploy, EvDeployParams, RetryPolicy};
 use slot_tracker::{http_to_ws_url, SlotTracker};
 use tui::{App, BotState, TuiUpdate};
 
+/// How often connected monitor clients get a full dashboard snapshot
+const MONITOR_SNAPSHOT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
 #[derive(Parser, Debug)]
 #[command(name = "evore-bot")]
 #[command(about = "Evore deployment bot for ORE v3")]
 struct Args {
-    /// RPC URL (HTTP)
-    #[arg(long, env = "RPC_URL", default_value = "https://api.mainnet-beta.solana.com")]
-    rpc_url: String,
+    /// RPC URL (HTTP, defaults to the public endpoint of --network)
+    #[arg(long, env = "RPC_URL")]
+    rpc_url: Option<String>,
+
+    /// Extra HTTP header sent with every RPC request (repeatable, key=value);
+    /// merged over the RPC_HEADERS JSON object
+    #[arg(long = "rpc-header", value_name = "KEY=VALUE")]
+    rpc_headers: Vec<String>,
 
     /// WebSocket URL (optional, derived from RPC URL if not provided)
     #[arg(long, env = "WS_URL")]
@@ -55,6 +82,31 @@ struct Args {
     #[arg(long, env = "MANAGER_PATH")]
     manager_path: Option<String>,
 
+    /// Cluster preset for program ids and mint (mainnet | devnet)
+    #[arg(long, env = "NETWORK", default_value = "mainnet")]
+    network: Network,
+
+    /// Override the ORE program id
+    #[arg(long, env = "ORE_PROGRAM_ID")]
+    ore_program_id: Option<Pubkey>,
+
+    /// Override the Evore program id
+    #[arg(long, env = "EVORE_PROGRAM_ID")]
+    evore_program_id: Option<Pubkey>,
+
+    /// Override the ORE mint
+    #[arg(long, env = "ORE_MINT")]
+    ore_mint: Option<Pubkey>,
+
+    /// Refuse to deploy a bankroll above this (lamports, or SOL e.g. 2.5sol);
+    /// --config bots use their own `absolute_max_deploy`
+    #[arg(long, env = "ABSOLUTE_MAX_DEPLOY", value_parser = units::parse_lamports, default_value = "1sol")]
+    absolute_max_deploy: u64,
+
+    /// Observe only: refuse to send any transaction and report its instructions instead
+    #[arg(long, env = "SAFE_MODE", value_parser = clap::builder::BoolishValueParser::new())]
+    safe_mode: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -62,7 +114,15 @@ struct Args {
 #[derive(Subcommand, Debug)]
 enum Commands {
     /// Show current round status
-    Status,
+    Status {
+        /// Output format
+        #[arg(long, value_enum, default_value = "text")]
+        output: OutputFormat,
+        
+        /// Include each bot's chosen bets from this TOML config in the EV breakdown
+        #[arg(long)]
+        config: Option<String>,
+    },
     
     /// Show managed miner auth PDA info
     Info {
@@ -71,22 +131,71 @@ enum Commands {
         auth_id: u64,
     },
     
+    /// List all auth_ids under a manager with balances and miner state
+    ListMiners {
+        /// Manager pubkey (default: pubkey of the manager keypair)
+        #[arg(long)]
+        manager: Option<Pubkey>,
+        
+        /// Highest auth_id to scan (inclusive)
+        #[arg(long, default_value = "10")]
+        max_auth_id: u64,
+    },
+    
+    /// Total claimable SOL and ORE across managed miners, with a per-miner breakdown
+    ClaimableSummary {
+        /// Sum every bot's auth_ids (and manager) in this TOML config
+        #[arg(long, conflicts_with = "manager")]
+        config: Option<String>,
+        
+        /// Manager pubkey (default: pubkey of the manager keypair)
+        #[arg(long)]
+        manager: Option<Pubkey>,
+        
+        /// Highest auth_id to scan without --config (inclusive)
+        #[arg(long, default_value = "10")]
+        max_auth_id: u64,
+        
+        /// Output format
+        #[arg(long, value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+    
+    /// Dump a managed miner's PDAs, accounts and lookup tables (attach to issues)
+    DumpMiner {
+        /// Manager pubkey (default: pubkey of the manager keypair)
+        #[arg(long)]
+        manager: Option<Pubkey>,
+        
+        /// Auth ID (default: 1)
+        #[arg(long, default_value = "1")]
+        auth_id: u64,
+        
+        /// Address lookup table used with this miner (repeatable)
+        #[arg(long)]
+        lut: Vec<Pubkey>,
+        
+        /// Output format
+        #[arg(long, value_enum, default_value = "json")]
+        output: OutputFormat,
+    },
+    
     /// Single EV deployment (spam mode at round end)
     Deploy {
-        /// Bankroll in lamports
-        #[arg(long)]
+        /// Bankroll (lamports, or SOL e.g. 0.25 / 2.5sol)
+        #[arg(long, value_parser = units::parse_lamports)]
         bankroll: u64,
         
-        /// Max per square in lamports
-        #[arg(long, default_value = "100000000")]
+        /// Max per square (lamports, or SOL e.g. 0.25 / 2.5sol)
+        #[arg(long, value_parser = units::parse_lamports, default_value = "100000000")]
         max_per_square: u64,
         
-        /// Min bet in lamports
-        #[arg(long, default_value = "10000")]
+        /// Min bet (lamports, or SOL e.g. 0.25 / 2.5sol)
+        #[arg(long, value_parser = units::parse_lamports, default_value = "10000")]
         min_bet: u64,
         
-        /// ORE value in lamports (for EV calculation)
-        #[arg(long, default_value = "800000000")]
+        /// ORE value for EV calculation (lamports, or SOL e.g. 0.5)
+        #[arg(long, value_parser = units::parse_lamports, default_value = "800000000")]
         ore_value: u64,
         
         /// Slots left threshold for deployment
@@ -96,24 +205,36 @@ enum Commands {
         /// Auth ID
         #[arg(long, default_value = "1")]
         auth_id: u64,
+        
+        /// Stop re-sending after N transactions (0 = until the round ends)
+        #[arg(long, default_value = "0")]
+        max_attempts: u64,
+        
+        /// Milliseconds between re-sends (default: 100 at slots_left <= 2, else 400)
+        #[arg(long)]
+        retry_delay_ms: Option<u64>,
+        
+        /// Tag each deploy with an SPL Memo, for filtering your transactions later (adds a small fee)
+        #[arg(long, value_parser = deploy::parse_memo)]
+        memo: Option<String>,
     },
     
     /// Continuous deployment loop (auto checkpoint & claim)
     Run {
-        /// Bankroll in lamports
-        #[arg(long)]
+        /// Bankroll (lamports, or SOL e.g. 0.25 / 2.5sol)
+        #[arg(long, value_parser = units::parse_lamports)]
         bankroll: u64,
         
-        /// Max per square in lamports
-        #[arg(long, default_value = "0_010_000_000")]
+        /// Max per square (lamports, or SOL e.g. 0.25 / 2.5sol)
+        #[arg(long, value_parser = units::parse_lamports, default_value = "0.01sol")]
         max_per_square: u64,
         
-        /// Min bet in lamports
-        #[arg(long, default_value = "0_001_000_000")]
+        /// Min bet (lamports, or SOL e.g. 0.25 / 2.5sol)
+        #[arg(long, value_parser = units::parse_lamports, default_value = "0.001sol")]
         min_bet: u64,
         
-        /// ORE value in lamports (for EV calculation)
-        #[arg(long, default_value = "0_500_000_000")]
+        /// ORE value for EV calculation (lamports, or SOL e.g. 0.5)
+        #[arg(long, value_parser = units::parse_lamports, default_value = "0.5sol")]
         ore_value: u64,
         
         /// Slots left threshold for deployment
@@ -123,6 +244,18 @@ enum Commands {
         /// Auth ID
         #[arg(long, default_value = "1")]
         auth_id: u64,
+        
+        /// Stop re-sending after N transactions per round (0 = until the round ends)
+        #[arg(long, default_value = "0")]
+        max_attempts: u64,
+        
+        /// Milliseconds between re-sends (default: 100 at slots_left <= 2, else 400)
+        #[arg(long)]
+        retry_delay_ms: Option<u64>,
+        
+        /// Tag each deploy with an SPL Memo, for filtering your transactions later (adds a small fee)
+        #[arg(long, value_parser = deploy::parse_memo)]
+        memo: Option<String>,
     },
     
     /// Checkpoint a round (auto-detects round_id from miner account if not specified)
@@ -146,39 +279,98 @@ enum Commands {
     /// Create a new Manager account
     CreateManager,
     
+    /// Hand a Manager (and every miner under it) to a new authority keypair
+    RotateAuthority {
+        /// Pubkey of the new authority
+        #[arg(long)]
+        new_authority: Pubkey,
+        
+        /// Manager pubkey (default: pubkey of the manager keypair)
+        #[arg(long)]
+        manager: Option<Pubkey>,
+    },
+    
+    /// Set the executor (Deployer deploy_authority) allowed to autodeploy for the manager
+    SetExecutor {
+        /// Pubkey of the executor
+        #[arg(long)]
+        executor: Pubkey,
+        
+        /// Manager pubkey (default: pubkey of the manager keypair)
+        #[arg(long)]
+        manager: Option<Pubkey>,
+        
+        /// Highest bps fee the executor may charge (0 accepts any; default 1 when creating, else unchanged)
+        #[arg(long)]
+        max_bps_fee: Option<u64>,
+        
+        /// Highest flat fee in lamports the executor may charge (0 accepts any; default 1 when creating, else unchanged)
+        #[arg(long)]
+        max_flat_fee: Option<u64>,
+        
+        /// Most the executor may deploy per round (lamports or SOL, 0 = unlimited; default 0 when creating, else unchanged)
+        #[arg(long, value_parser = units::parse_lamports)]
+        max_per_round: Option<u64>,
+    },
+    
     /// Live TUI dashboard with real-time updates
     Dashboard {
         /// Path to TOML config file (overrides CLI args)
         #[arg(long)]
         config: Option<String>,
         
-        /// Bankroll in lamports (ignored if --config provided)
-        #[arg(long, default_value = "220000000")]
+        /// Bankroll, lamports or SOL e.g. 2.5sol (ignored if --config provided)
+        #[arg(long, value_parser = units::parse_lamports, default_value = "220000000")]
         bankroll: u64,
         
-        /// Max per square in lamports
-        #[arg(long, default_value = "10000000")]
+        /// Max per square (lamports, or SOL e.g. 0.25 / 2.5sol)
+        #[arg(long, value_parser = units::parse_lamports, default_value = "10000000")]
         max_per_square: u64,
         
-        /// Min bet in lamports
-        #[arg(long, default_value = "1000000")]
+        /// Min bet (lamports, or SOL e.g. 0.25 / 2.5sol)
+        #[arg(long, value_parser = units::parse_lamports, default_value = "1000000")]
         min_bet: u64,
         
-        /// ORE value in lamports (for EV calculation)
-        #[arg(long, default_value = "500000000")]
+        /// ORE value for EV calculation (lamports, or SOL e.g. 0.5)
+        #[arg(long, value_parser = units::parse_lamports, default_value = "500000000")]
         ore_value: u64,
         
         /// Slots left threshold for deployment
         #[arg(long, default_value = "1")]
         slots_left: u64,
         
+        /// Submit at exactly end_slot - K, scheduled from observed slot timing
+        /// (falls back to polling if slot timing is unavailable)
+        #[arg(long)]
+        target_slot_offset: Option<u64>,
+        
         /// Auth ID
         #[arg(long, default_value = "1")]
         auth_id: u64,
         
+        /// Deploy several times across the round, betting more as it fills (ignored if --config provided)
+        #[arg(long)]
+        ramp: bool,
+        
+        /// Number of ramp submissions per round
+        #[arg(long, default_value = "4")]
+        ramp_submissions: u32,
+        
         /// Deploy strategy (EV, Percentage, Manual)
         #[arg(long, default_value = "EV")]
         strategy: String,
+        
+        /// Rotate manual-strategy squares each round (enables `rotate_squares` for every bot in --config)
+        #[arg(long)]
+        rotate_squares: bool,
+        
+        /// Serve dashboard updates as JSON over WebSocket on this address, e.g. 0.0.0.0:9400 (requires --config)
+        #[arg(long)]
+        ws_listen: Option<std::net::SocketAddr>,
+        
+        /// Max websocket subscriptions for managed_miner_auth balances; the rest are polled (requires --config)
+        #[arg(long, default_value_t = miner_tracker::DEFAULT_MAX_BALANCE_SUBSCRIPTIONS)]
+        max_balance_subs: usize,
     },
     
     /// Manage miners - TUI for checkpoint, claim SOL/ORE across all signers
@@ -186,9 +378,129 @@ enum Commands {
         /// Path to TOML config file (must have [manage] section)
         #[arg(long)]
         config: String,
+        
+        /// Create the signer's ORE token account (idempotent) when claiming ORE
+        #[arg(long, default_value_t = true, action = clap::ArgAction::Set)]
+        create_ata: bool,
+    },
+    
+    /// Print the deploy audit log (see `audit_log_path` in the config)
+    AuditLog {
+        /// Path to the JSON-lines audit file
+        path: std::path::PathBuf,
+        
+        /// Number of records to show
+        #[arg(long, default_value = "20")]
+        lines: usize,
+        
+        /// Keep printing new records as they are appended
+        #[arg(long)]
+        follow: bool,
+    },
+    
+    /// Print the board and EV a past deploy was decided on (from the audit log)
+    Decision {
+        /// Path to the JSON-lines audit file
+        path: std::path::PathBuf,
+        
+        /// Round the deploy was for
+        #[arg(long)]
+        round: u64,
+        
+        /// Only this bot's decisions (default: every bot's)
+        #[arg(long)]
+        bot: Option<String>,
+    },
+    
+    /// Landing timing per bot and host from the deploy export's `bot_deploy_landings` table
+    Landings {
+        /// TOML config whose `[deploy_export]` section points at ClickHouse
+        #[arg(long)]
+        config: String,
+        
+        /// Only landings from the last N hours
+        #[arg(long, default_value = "24")]
+        since_hours: u64,
+        
+        /// Only this bot's landings (default: every bot's)
+        #[arg(long)]
+        bot: Option<String>,
+    },
+    
+    /// Create missing ORE token accounts and fund every managed miner in a config
+    Prepare {
+        /// Path to TOML config file
+        #[arg(long)]
+        config: String,
+        
+        /// Top each managed_miner_auth up to this balance from its bot's signer (lamports or SOL, 0 = only create token accounts)
+        #[arg(long, value_parser = units::parse_lamports)]
+        fund: u64,
+        
+        /// Most instructions per transaction
+        #[arg(long, default_value = "8")]
+        batch_size: usize,
+    },
+    
+    /// Estimate the per-round fee cost of each bot in a config
+    EstimateFees {
+        /// Path to TOML config file
+        #[arg(long)]
+        config: String,
+    },
+    
+    /// Monte Carlo the SOL return of each bot's bets against the current round
+    Simulate {
+        /// Path to TOML config file
+        #[arg(long)]
+        config: String,
+        
+        /// Simulated rounds per bot
+        #[arg(long, default_value = "10000")]
+        trials: u32,
+        
+        /// RNG seed (default: current round id)
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    
+    /// Replay stored rounds from ore-stats ClickHouse against each bot's strategy
+    Backtest {
+        /// TOML config whose bots' strategies are replayed
+        #[arg(long)]
+        strategy: String,
+        
+        /// First round id (inclusive)
+        #[arg(long)]
+        from: u64,
+        
+        /// Last round id (inclusive)
+        #[arg(long)]
+        to: u64,
+        
+        /// ClickHouse HTTP URL (CLICKHOUSE_DB, CLICKHOUSE_USER and CLICKHOUSE_PASSWORD are read from the environment)
+        #[arg(long, env = "CLICKHOUSE_URL")]
+        clickhouse_url: String,
+    },
+    
+    /// Check the compiled program ids, PDAs and addresses against the chain
+    VerifyConstants,
+    
+    /// Live one-line view of a single square: stake, share of the pot, rank and slots left
+    WatchSquare {
+        /// Square index (0-24)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(0..25))]
+        square: u8,
     },
 }
 
+/// How read-only commands print their result
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
 fn load_signer_keypair(path: Option<&String>) -> Result<Keypair, Box<dyn std::error::Error>> {
     let keypair_path = path
         .map(|p| p.to_string())
@@ -216,8 +528,28 @@ fn load_manager_keypair(path: Option<&String>) -> Result<Keypair, Box<dyn std::e
     Ok(keypair)
 }
 
-fn get_ws_url(args: &Args) -> String {
-    args.ws_url.clone().unwrap_or_else(|| http_to_ws_url(&args.rpc_url))
+fn get_ws_url(args: &Args, rpc_url: &str) -> String {
+    args.ws_url.clone().unwrap_or_else(|| http_to_ws_url(rpc_url))
+}
+
+impl Args {
+    fn network_config(&self) -> NetworkConfig {
+        NetworkConfig::for_network(self.network)
+            .with_overrides(self.ore_program_id, self.evore_program_id, self.ore_mint)
+    }
+}
+
+impl Commands {
+    /// Commands that only read accounts work with any program ids
+    fn sends_transactions(&self) -> bool {
+        !matches!(
+            self,
+            Commands::Status { .. } | Commands::Info { .. } | Commands::ListMiners { .. } | Commands::ClaimableSummary { .. } | Commands::AuditLog { .. }
+                | Commands::Decision { .. } | Commands::Landings { .. }
+                | Commands::EstimateFees { .. } | Commands::Simulate { .. } | Commands::Backtest { .. }
+                | Commands::VerifyConstants
+        )
+    }
 }
 
 #[tokio::main]
@@ -225,32 +557,101 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenvy::dotenv().ok();
     
     let args = Args::parse();
-    let client = EvoreClient::new(&args.rpc_url);
+    let network = args.network_config();
+    let rpc_url = args.rpc_url.clone()
+        .unwrap_or_else(|| network.network.default_rpc_url().to_string());
+    
+    // Instruction builders are compiled against the mainnet program ids
+    if args.command.sends_transactions() && !network.uses_compiled_program_ids() {
+        return Err(format!(
+            "Program id overrides are read-only for now (ore: {}, evore: {}); only status, info and list-miners are supported",
+            network.ore_program_id, network.evore_program_id
+        ).into());
+    }
+    
+    let rpc_header_json = std::env::var("RPC_HEADERS").ok();
+    let rpc_headers = rpc_headers::parse(rpc_header_json.as_deref(), &args.rpc_headers)?;
+    rpc_headers::init(rpc_headers);
+    
+    if args.safe_mode {
+        safe_mode::enable();
+        eprintln!("Safe mode: no transactions will be sent");
+    }
+    
+    let client = EvoreClient::new(&rpc_url).with_network(network);
     
     match &args.command {
-        Commands::Status => {
-            println!("=== Evore Bot Status ===\n");
-            
+        Commands::Status { output, config: config_path } => {
             // Use RPC for status (no need for websocket)
             let slot = client.get_slot()?;
-            println!("Current Slot: {}", slot);
-            
             let board = client.get_board()?;
+            let slots_remaining = board.end_slot.saturating_sub(slot);
+            let round = match client.get_round(board.round_id)? {
+                RoundState::Active(round) => Some(round),
+                RoundState::Resetting { .. } => None,
+            };
+            
+            // Each bot's bets against the current board, with the EV behind them
+            let bots = match (config_path, &round) {
+                (Some(path), Some(round)) => config::Config::load(std::path::Path::new(path))?
+                    .bots
+                    .iter()
+                    .map(|bot| {
+                        let manual = bot_runner::manual_amounts(bot.strategy, &bot.strategy_params, bot.rotate_squares, board.round_id)
+                            .or_else(|| bot_runner::bounded_ev_amounts(bot.strategy, &bot.strategy_params, bot.bankroll, bot.slots_left,
+                                bot.min_squares, bot.max_squares, &bot.square_blacklist, round))
+                            .or_else(|| bot_runner::lead_square_amounts(bot.strategy, &bot.strategy_params, bot.bankroll, round));
+                        let bets = bot_runner::preview_bets(bot.strategy, &bot.strategy_params, manual.as_ref(),
+                            bot.bankroll, bot.slots_left, round);
+                        (bot.name.clone(), ev_calculator::ev_details(&round.deployed, &bets))
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            
+            if *output == OutputFormat::Json {
+                let status = serde_json::json!({
+                    "slot": slot,
+                    "round_id": board.round_id,
+                    "start_slot": board.start_slot,
+                    "end_slot": board.end_slot,
+                    "slots_left": slots_remaining,
+                    "resetting": round.is_none(),
+                    "total_deployed": round.as_ref().map_or(0, |r| Board25::new(r.deployed).total()),
+                    "squares": round.as_ref().map(|r| ev_calculator::ev_details(&r.deployed, &[0; 25]).to_vec()),
+                    "bots": bots.iter()
+                        .map(|(name, ev)| serde_json::json!({ "name": name, "ev": ev.to_vec() }))
+                        .collect::<Vec<_>>(),
+                });
+                println!("{}", serde_json::to_string_pretty(&status)?);
+                return Ok(());
+            }
+            
+            println!("=== Evore Bot Status ===\n");
+            println!("Current Slot: {}", slot);
             println!("Round ID:     {}", board.round_id);
             println!("Start Slot:   {}", board.start_slot);
             println!("End Slot:     {}", board.end_slot);
-            
-            let slots_remaining = board.end_slot.saturating_sub(slot);
             println!("Slots Left:   {}", slots_remaining);
             
             println!("\n--- Round {} Deployments ---", board.round_id);
-            let round = client.get_round(board.round_id)?;
-            let total: u64 = round.deployed.iter().sum();
+            let Some(round) = round else {
+                println!("Round {} is resetting - deployments not available yet", board.round_id);
+                return Ok(());
+            };
+            let deployed = Board25::new(round.deployed);
+            let total = deployed.total();
             println!("Total Deployed: {} lamports ({:.4} SOL)", total, total as f64 / 1e9);
             
-            for (i, amount) in round.deployed.iter().enumerate() {
-                if *amount > 0 {
-                    println!("  Square {}: {} ({:.4} SOL)", i, amount, *amount as f64 / 1e9);
+            for i in deployed.squares() {
+                println!("  Square {}: {} ({:.4} SOL, {:.1}%)", i, deployed[i], deployed[i] as f64 / 1e9, deployed.share(i) * 100.0);
+            }
+            
+            for (name, ev) in &bots {
+                println!("\n--- {} (marginal EV per {:.2} SOL) ---", name, ev_calculator::EV_UNIT_BET as f64 / 1e9);
+                for d in ev.iter().filter(|d| d.chosen > 0) {
+                    println!("  Square {:>2}: pot {:.4}  EV {:+.6}  chosen {:.4} SOL",
+                        d.square, d.pot as f64 / 1e9, d.marginal_ev as f64 / 1e9, d.chosen as f64 / 1e9);
                 }
             }
         }
@@ -260,13 +661,274 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             let manager_keypair = load_manager_keypair(args.manager_path.as_ref())?;
             let manager = manager_keypair.pubkey();
             println!("Signer:               {}", signer.pubkey());
-            print_managed_miner_info(&manager, *auth_id);
+            print_managed_miner_info(&client, &manager, *auth_id);
         }
         
-        Commands::Deploy { bankroll, max_per_square, min_bet, ore_value, slots_left, auth_id } => {
+        Commands::ListMiners { manager, max_auth_id } => {
+            let manager = match manager {
+                Some(m) => *m,
+                None => load_manager_keypair(args.manager_path.as_ref())?.pubkey(),
+            };
+            
+            println!("=== Managed Miners ===\n");
+            println!("Manager: {}", manager);
+            println!();
+            
+            let miners = client.list_managed_miners(&manager, *max_auth_id)?;
+            
+            println!(
+                "{:>7}  {:<44}  {:>12}  {:>10}  {:>10}  {:>14}  {:>14}",
+                "Auth ID", "Managed Miner Auth", "Balance", "Round", "Checkpoint", "Claim SOL", "Claim ORE"
+            );
+            for m in &miners {
+                match &m.miner {
+                    Some(miner) => println!(
+                        "{:>7}  {:<44}  {:>12.6}  {:>10}  {:>10}  {:>14.6}  {:>14.9}",
+                        m.auth_id,
+                        m.managed_miner_auth.to_string(),
+                        m.balance as f64 / 1e9,
+                        miner.round_id,
+                        miner.checkpoint_id,
+                        miner.rewards_sol as f64 / 1e9,
+                        miner.rewards_ore as f64 / 1e11,
+                    ),
+                    None => println!(
+                        "{:>7}  {:<44}  {:>12.6}  {:>10}  {:>10}  {:>14}  {:>14}",
+                        m.auth_id,
+                        m.managed_miner_auth.to_string(),
+                        m.balance as f64 / 1e9,
+                        "-", "-", "-", "-",
+                    ),
+                }
+            }
+            
+            let needs_checkpoint = miners.iter()
+                .filter(|m| m.miner.map_or(false, |miner| miner.round_id > miner.checkpoint_id))
+                .count();
+            let total_sol: u64 = miners.iter().filter_map(|m| m.miner.map(|miner| miner.rewards_sol)).sum();
+            let total_ore: u64 = miners.iter().filter_map(|m| m.miner.map(|miner| miner.rewards_ore)).sum();
+            println!();
+            println!("Miners with accounts: {}", miners.iter().filter(|m| m.miner.is_some()).count());
+            println!("Needs checkpoint:     {}", needs_checkpoint);
+            println!("Total claimable SOL:  {:.6} SOL", total_sol as f64 / 1e9);
+            println!("Total claimable ORE:  {:.9} ORE", total_ore as f64 / 1e11);
+        }
+        
+        Commands::ClaimableSummary { config: config_path, manager, max_auth_id, output } => {
+            // (bot, manager, auth_id) of every managed miner to read
+            let mut targets: Vec<(String, Pubkey, u64)> = Vec::new();
+            match config_path {
+                Some(path) => {
+                    let config = config::Config::load(std::path::Path::new(path))?;
+                    for bot in &config.bots {
+                        let manager_path = config.get_manager_path(bot);
+                        let manager = read_keypair_file(&manager_path)
+                            .map_err(|e| format!("Failed to read manager keypair from {:?}: {}", manager_path, e))?
+                            .pubkey();
+                        for auth_id in bot.auth_group() {
+                            if !targets.iter().any(|(_, m, a)| (*m, *a) == (manager, auth_id)) {
+                                targets.push((bot.name.clone(), manager, auth_id));
+                            }
+                        }
+                    }
+                }
+                None => {
+                    let manager = match manager {
+                        Some(m) => *m,
+                        None => load_manager_keypair(args.manager_path.as_ref())?.pubkey(),
+                    };
+                    targets.extend((0..=*max_auth_id).map(|auth_id| ("-".to_string(), manager, auth_id)));
+                }
+            }
+            
+            let pairs: Vec<(Pubkey, u64)> = targets.iter().map(|(_, m, a)| (*m, *a)).collect();
+            let miners = client.get_managed_miners(&pairs)?;
+            let found: Vec<_> = targets.iter()
+                .zip(&miners)
+                .filter_map(|(target, miner)| miner.map(|m| (target, m)))
+                .collect();
+            let total_sol: u64 = found.iter().map(|(_, m)| m.rewards_sol).sum();
+            let total_ore: u64 = found.iter().map(|(_, m)| m.rewards_ore).sum();
+            
+            if *output == OutputFormat::Json {
+                let summary = serde_json::json!({
+                    "total_rewards_sol": total_sol,
+                    "total_rewards_ore": total_ore,
+                    "miners": found.iter()
+                        .map(|((bot, manager, auth_id), miner)| serde_json::json!({
+                            "bot": bot,
+                            "manager": manager.to_string(),
+                            "auth_id": auth_id,
+                            "rewards_sol": miner.rewards_sol,
+                            "rewards_ore": miner.rewards_ore,
+                        }))
+                        .collect::<Vec<_>>(),
+                });
+                println!("{}", serde_json::to_string_pretty(&summary)?);
+                return Ok(());
+            }
+            
+            println!("=== Claimable Summary ===\n");
+            println!(
+                "{:<16}  {:<44}  {:>7}  {:>14}  {:>14}",
+                "Bot", "Manager", "Auth ID", "Claim SOL", "Claim ORE"
+            );
+            for ((bot, manager, auth_id), miner) in &found {
+                println!(
+                    "{:<16}  {:<44}  {:>7}  {:>14.6}  {:>14.9}",
+                    bot,
+                    manager.to_string(),
+                    auth_id,
+                    miner.rewards_sol as f64 / 1e9,
+                    miner.rewards_ore as f64 / 1e11,
+                );
+            }
+            println!();
+            println!("Miners read:          {} ({} with accounts)", targets.len(), found.len());
+            println!("Total claimable SOL:  {:.6} SOL", total_sol as f64 / 1e9);
+            println!("Total claimable ORE:  {:.9} ORE", total_ore as f64 / 1e11);
+        }
+        
+        Commands::Prepare { config: config_path, fund, batch_size } => {
+            let config = config::Config::load(std::path::Path::new(config_path))?;
+            
+            // Each signer pays for (and funds) the miners of the bots that use it
+            let mut signers: Vec<(std::path::PathBuf, Vec<(String, Pubkey, u64)>)> = Vec::new();
+            for bot in &config.bots {
+                let manager_path = config.get_manager_path(bot);
+                let manager = read_keypair_file(&manager_path)
+                    .map_err(|e| format!("Failed to read manager keypair from {:?}: {}", manager_path, e))?
+                    .pubkey();
+                let signer_path = config.get_signer_path(bot);
+                let index = match signers.iter().position(|(path, _)| *path == signer_path) {
+                    Some(index) => index,
+                    None => {
+                        signers.push((signer_path, Vec::new()));
+                        signers.len() - 1
+                    }
+                };
+                for auth_id in bot.auth_group() {
+                    let miners = &mut signers[index].1;
+                    if !miners.iter().any(|(_, m, a)| (*m, *a) == (manager, auth_id)) {
+                        miners.push((bot.name.clone(), manager, auth_id));
+                    }
+                }
+            }
+            
+            println!("=== Prepare ({} signer(s), funding target {:.6} SOL) ===\n", signers.len(), *fund as f64 / 1e9);
+            println!("{:<16}  {:>7}  {:<44}  {:<10}  {:<16}  {}", "Bot", "Auth ID", "Address", "ORE ATA", "Funding", "Result");
+            
+            let (mut created, mut funded, mut funded_lamports, mut ready, mut failed) = (0usize, 0usize, 0u64, 0usize, 0usize);
+            for (signer_path, targets) in &signers {
+                let signer = read_keypair_file(signer_path)
+                    .map_err(|e| format!("Failed to read signer keypair from {:?}: {}", signer_path, e))?;
+                
+                // Everything prepare decides on is read up front
+                let auths: Vec<Pubkey> = targets.iter()
+                    .map(|(_, manager, auth_id)| client.get_managed_miner_auth_address(manager, *auth_id).0)
+                    .collect();
+                let mut addresses = vec![prepare::ore_ata(&signer.pubkey())];
+                addresses.extend(auths.iter().map(prepare::ore_ata));
+                addresses.extend(auths.iter().copied());
+                let accounts = addresses.chunks(100)
+                    .map(|chunk| client.get_multiple_accounts(chunk))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .concat();
+                let (atas, balances) = accounts[1..].split_at(auths.len());
+                
+                let miners: Vec<prepare::MinerState> = targets.iter().zip(&auths).enumerate()
+                    .map(|(i, ((bot, _, auth_id), auth))| prepare::MinerState {
+                        bot: bot.clone(),
+                        auth_id: *auth_id,
+                        managed_miner_auth: *auth,
+                        balance: balances[i].as_ref().map_or(0, |a| a.lamports),
+                        ata_exists: atas[i].is_some(),
+                    })
+                    .collect();
+                let steps = prepare::plan(&signer.pubkey(), accounts[0].is_some(), &miners, *fund);
+                
+                // Send each batch, remembering how it went for its steps
+                let mut results: Vec<Option<Result<String, String>>> = vec![None; steps.len()];
+                for batch in prepare::batches(&steps, *batch_size) {
+                    let instructions: Vec<_> = batch.iter()
+                        .flat_map(|&i| steps[i].instructions.iter().cloned())
+                        .collect();
+                    let result = client.get_latest_blockhash()
+                        .and_then(|blockhash| client.send_and_confirm_transaction(
+                            &deploy::TxBuilder::new().build(&signer, instructions, blockhash),
+                        ))
+                        .map(|sig| sig.to_string())
+                        .map_err(|e| e.to_string());
+                    for &i in &batch {
+                        results[i] = Some(result.clone());
+                    }
+                }
+                
+                for (step, result) in steps.iter().zip(&results) {
+                    let ata = if step.create_ata { "create" } else { "exists" };
+                    let funding = if step.fund > 0 { format!("+{:.6} SOL", step.fund as f64 / 1e9) } else { "-".to_string() };
+                    let outcome = match result {
+                        None => {
+                            ready += 1;
+                            "ready".to_string()
+                        }
+                        Some(Ok(sig)) => {
+                            created += step.create_ata as usize;
+                            if step.fund > 0 {
+                                funded += 1;
+                                funded_lamports += step.fund;
+                            }
+                            format!("✓ {}", sig)
+                        }
+                        Some(Err(e)) => {
+                            failed += 1;
+                            format!("✗ {}", e)
+                        }
+                    };
+                    let auth_id = step.auth_id.map_or("-".to_string(), |a| a.to_string());
+                    println!("{:<16}  {:>7}  {:<44}  {:<10}  {:<16}  {}", step.bot, auth_id, step.address.to_string(), ata, funding, outcome);
+                }
+            }
+            
+            println!();
+            println!("Token accounts created:  {}", created);
+            println!("Miners funded:           {} ({:.6} SOL)", funded, funded_lamports as f64 / 1e9);
+            println!("Already ready:           {}", ready);
+            if failed > 0 {
+                println!("Failed:                  {}", failed);
+            }
+        }
+        
+        Commands::DumpMiner { manager, auth_id, lut, output } => {
+            let manager = match manager {
+                Some(m) => *m,
+                None => load_manager_keypair(args.manager_path.as_ref())?.pubkey(),
+            };
+            let dump = dump_miner::dump_miner(&client, manager, *auth_id, lut.clone())?;
+            
+            if *output == OutputFormat::Json {
+                println!("{}", serde_json::to_string_pretty(&dump)?);
+                return Ok(());
+            }
+            
+            let present = |key: &str| if dump[key].is_null() { "missing" } else { "found" };
+            println!("=== Managed Miner {} / {} ===\n", manager, auth_id);
+            println!("Slot:                 {}", dump["slot"]);
+            println!("Managed Miner Auth:   {}", dump["pdas"]["managed_miner_auth"]["address"].as_str().unwrap_or_default());
+            println!("Auth Balance:         {:.6} SOL", dump["managed_miner_auth_balance"].as_u64().unwrap_or(0) as f64 / 1e9);
+            println!("Manager Account:      {}", present("manager_account"));
+            println!("Deployer Account:     {} ({})", present("deployer_account"), dump["pdas"]["deployer"]["address"].as_str().unwrap_or_default());
+            println!("Miner Account:        {} ({})", present("miner_account"), dump["pdas"]["miner"]["address"].as_str().unwrap_or_default());
+            println!("Lookup Tables:        {}", lut.len());
+            println!("\nUse --output json for the full account data");
+        }
+        
+        Commands::Deploy { bankroll, max_per_square, min_bet, ore_value, slots_left, auth_id, max_attempts, retry_delay_ms, memo } => {
+            tx_pipeline::check_deploy_cap(*bankroll, args.absolute_max_deploy)?;
             let signer = load_signer_keypair(args.keypair.as_ref())?;
             let manager_keypair = load_manager_keypair(args.manager_path.as_ref())?;
             let manager = manager_keypair.pubkey();
+            client.ensure_manager_authority(&manager, &signer.pubkey())?;
             
             let params = EvDeployParams {
                 bankroll: *bankroll,
@@ -275,13 +937,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 ore_value: *ore_value,
                 slots_left: *slots_left,
                 attempts: 0,
+                memo: memo.clone(),
             };
             
             println!("Signer:  {}", signer.pubkey());
             println!("Manager: {}", manager);
             
             // Start slot tracker
-            let ws_url = get_ws_url(&args);
+            let ws_url = get_ws_url(&args, &rpc_url);
             println!("WS URL:  {}", ws_url);
             
             let slot_tracker = SlotTracker::new(&ws_url);
@@ -296,13 +959,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
             println!("Connected! Current slot: {}\n", slot_tracker.get_slot());
             
-            single_deploy(&client, &slot_tracker, &signer, &manager, *auth_id, &params).await?;
+            let retry = RetryPolicy { max_attempts: *max_attempts, retry_delay_ms: *retry_delay_ms };
+            single_deploy(&client, &slot_tracker, &signer, &manager, *auth_id, &params, &retry).await?;
         }
         
-        Commands::Run { bankroll, max_per_square, min_bet, ore_value, slots_left, auth_id } => {
+        Commands::Run { bankroll, max_per_square, min_bet, ore_value, slots_left, auth_id, max_attempts, retry_delay_ms, memo } => {
+            tx_pipeline::check_deploy_cap(*bankroll, args.absolute_max_deploy)?;
             let signer = load_signer_keypair(args.keypair.as_ref())?;
             let manager_keypair = load_manager_keypair(args.manager_path.as_ref())?;
             let manager = manager_keypair.pubkey();
+            client.ensure_manager_authority(&manager, &signer.pubkey())?;
             
             let params = EvDeployParams {
                 bankroll: *bankroll,
@@ -311,10 +977,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 ore_value: *ore_value,
                 slots_left: *slots_left,
                 attempts: 0,
+                memo: memo.clone(),
             };
             
             // Start slot tracker
-            let ws_url = get_ws_url(&args);
+            let ws_url = get_ws_url(&args, &rpc_url);
             println!("WS URL:  {}", ws_url);
             
             let slot_tracker = SlotTracker::new(&ws_url);
@@ -329,13 +996,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
             println!("Connected! Current slot: {}\n", slot_tracker.get_slot());
             
-            continuous_deploy(&client, &slot_tracker, &signer, &manager, *auth_id, &params).await?;
+            let retry = RetryPolicy { max_attempts: *max_attempts, retry_delay_ms: *retry_delay_ms };
+            continuous_deploy(&client, &slot_tracker, &signer, &manager, *auth_id, &params, &retry).await?;
         }
         
         Commands::Checkpoint { round_id, auth_id } => {
             let signer = load_signer_keypair(args.keypair.as_ref())?;
             let manager_keypair = load_manager_keypair(args.manager_path.as_ref())?;
             let manager = manager_keypair.pubkey();
+            client.ensure_manager_authority(&manager, &signer.pubkey())?;
             
             // Get managed miner auth PDA (this is the miner authority)
             let (managed_miner_auth, _) = evore::state::managed_miner_auth_pda(manager, *auth_id);
@@ -416,6 +1085,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             let signer = load_signer_keypair(args.keypair.as_ref())?;
             let manager_keypair = load_manager_keypair(args.manager_path.as_ref())?;
             let manager = manager_keypair.pubkey();
+            client.ensure_manager_authority(&manager, &signer.pubkey())?;
             
             println!("Claiming SOL for auth_id {}...", auth_id);
             println!("Signer:  {}", signer.pubkey());
@@ -488,12 +1158,112 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
         
-        Commands::Dashboard { config: config_path, bankroll, max_per_square, min_bet, ore_value, slots_left, auth_id, strategy } => {
+        Commands::RotateAuthority { new_authority, manager } => {
+            let signer = load_signer_keypair(args.keypair.as_ref())?;
+            let manager = match manager {
+                Some(manager) => *manager,
+                None => load_manager_keypair(args.manager_path.as_ref())?.pubkey(),
+            };
+            client.ensure_manager_authority(&manager, &signer.pubkey())?;
+            if *new_authority == signer.pubkey() {
+                return Err(format!("{} is already the authority of manager {}", new_authority, manager).into());
+            }
+            
+            println!("=== Rotate Manager Authority ===");
+            println!("Manager:           {}", manager);
+            println!("Current authority: {}", signer.pubkey());
+            println!("New authority:     {}", new_authority);
+            println!();
+            
+            let ix = evore::instruction::transfer_manager(signer.pubkey(), manager, *new_authority);
+            let blockhash = client.get_latest_blockhash()?;
+            let tx = solana_sdk::transaction::Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&signer.pubkey()),
+                &[&signer],
+                blockhash,
+            );
+            
+            match client.send_and_confirm_transaction(&tx) {
+                Ok(sig) => {
+                    println!("✓ Authority rotated: {}", sig);
+                    if let Ok(Some(manager_data)) = client.get_manager(&manager) {
+                        println!("Authority: {}", manager_data.authority);
+                    }
+                    println!("Use the keypair for {} as KEYPAIR_PATH from now on", new_authority);
+                }
+                Err(e) => println!("✗ Rotate authority failed: {}", e),
+            }
+        }
+        
+        Commands::SetExecutor { executor, manager, max_bps_fee, max_flat_fee, max_per_round } => {
+            use steel::AccountDeserialize;
+            
+            let signer = load_signer_keypair(args.keypair.as_ref())?;
+            let manager = match manager {
+                Some(manager) => *manager,
+                None => load_manager_keypair(args.manager_path.as_ref())?.pubkey(),
+            };
+            client.ensure_manager_authority(&manager, &signer.pubkey())?;
+            
+            let deployer_address = client.network().deployer_pda(manager).0;
+            let deployer = client.get_account_optional(&deployer_address)?
+                .map(|a| evore::state::Deployer::try_from_bytes(&a.data).copied())
+                .transpose()?;
+            
+            println!("=== Set Executor ===");
+            println!("Manager:      {}", manager);
+            println!("Deployer:     {}", deployer_address);
+            println!("Executor:     {}", executor);
+            
+            // The executor can raise its own fees up to the expected ones, and 0
+            // accepts any fee, so a new Deployer caps them at 1 bps + 1 lamport
+            let ix = match deployer {
+                None => {
+                    let (bps, flat, per_round) = (max_bps_fee.unwrap_or(1), max_flat_fee.unwrap_or(1), max_per_round.unwrap_or(0));
+                    println!("Creating Deployer (max fee {} bps + {} lamports, max per round {})", bps, flat, per_round);
+                    evore::instruction::create_deployer(signer.pubkey(), manager, *executor, bps, flat, per_round)
+                }
+                Some(d) => {
+                    println!("Previous:     {}", d.deploy_authority);
+                    evore::instruction::update_deployer(
+                        signer.pubkey(),
+                        manager,
+                        *executor,
+                        d.bps_fee,
+                        d.flat_fee,
+                        max_bps_fee.unwrap_or(d.expected_bps_fee),
+                        max_flat_fee.unwrap_or(d.expected_flat_fee),
+                        max_per_round.unwrap_or(d.max_per_round),
+                    )
+                }
+            };
+            println!();
+            
+            let blockhash = client.get_latest_blockhash()?;
+            let tx = solana_sdk::transaction::Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&signer.pubkey()),
+                &[&signer],
+                blockhash,
+            );
+            
+            match client.send_and_confirm_transaction(&tx) {
+                Ok(sig) => {
+                    println!("✓ Executor set: {}", sig);
+                    println!("{} can now sign MMAutodeploy for this manager's miners", executor);
+                }
+                Err(e) => println!("✗ Set executor failed: {}", e),
+            }
+        }
+        
+        Commands::Dashboard { config: config_path, bankroll, max_per_square, min_bet, ore_value, slots_left, target_slot_offset, auth_id, ramp, ramp_submissions, strategy, rotate_squares, ws_listen, max_balance_subs } => {
             // If config file provided, use the new multi-bot system
             if let Some(config_file) = config_path {
-                run_dashboard_with_config(&args.rpc_url, get_ws_url(&args), config_file).await?;
+                run_dashboard_with_config(&rpc_url, get_ws_url(&args, &rpc_url), config_file, *rotate_squares, *ws_listen, *max_balance_subs).await?;
             } else {
                 // Legacy single-bot mode using CLI args
+                tx_pipeline::check_deploy_cap(*bankroll, args.absolute_max_deploy)?;
                 let signer = load_signer_keypair(args.keypair.as_ref())?;
                 let manager_keypair = load_manager_keypair(args.manager_path.as_ref())?;
                 let manager = manager_keypair.pubkey();
@@ -505,23 +1275,257 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     ore_value: *ore_value,
                     slots_left: *slots_left,
                     attempts: 0,
+                    memo: None,
                 };
                 
                 run_dashboard(
-                    &args.rpc_url,
-                    get_ws_url(&args),
+                    &rpc_url,
+                    get_ws_url(&args, &rpc_url),
                     &signer,
                     manager,
                     *auth_id,
                     params,
+                    *target_slot_offset,
+                    *ramp,
+                    *ramp_submissions,
                     strategy.clone(),
                     client,
                 ).await?;
             }
         }
         
-        Commands::Manage { config: config_path } => {
-            run_manage_tui(&args.rpc_url, config_path).await?;
+        Commands::Manage { config: config_path, create_ata } => {
+            run_manage_tui(&rpc_url, config_path, *create_ata).await?;
+        }
+        
+        Commands::AuditLog { path, lines, follow } => {
+            let mut tail = deploy_audit::AuditTail::new(path);
+            let mut records = tail.read_last(*lines)?;
+            loop {
+                for r in &records {
+                    println!(
+                        "{}  {:<12} round {:>8}  auth {:>3}  mask {:#09x}  {:>12}  fee {:>8}  tip {:>8}  #{}  {:<9}  {}{}",
+                        r.timestamp_ms,
+                        r.bot,
+                        r.round_id,
+                        r.auth_id,
+                        r.mask,
+                        r.amount,
+                        r.priority_fee,
+                        r.jito_tip,
+                        r.attempt,
+                        format!("{:?}", r.result),
+                        r.signature,
+                        r.error.as_ref().map(|e| format!("  ({})", e)).unwrap_or_default(),
+                    );
+                }
+                if !*follow {
+                    break;
+                }
+                tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+                records = tail.read_new()?;
+            }
+        }
+        
+        Commands::Decision { path, round, bot } => {
+            let snapshots = deploy_audit::read_snapshots(path, *round, bot.as_deref())?;
+            if snapshots.is_empty() {
+                println!("No EV snapshot for round {} in {}", round, path.display());
+            }
+            let sol = |lamports: u64| lamports as f64 / 1e9;
+            for s in &snapshots {
+                println!("=== {} round {} auth {} ({}) ===", s.bot, s.round_id, s.auth_id, s.timestamp_ms);
+                println!("Strategy:   {:?}", s.strategy);
+                println!("Bankroll:   {:.4} SOL", sol(s.bankroll));
+                println!("ORE value:  {:.4} SOL", sol(s.ore_value));
+                println!("Slots left: {}", s.slots_left);
+                println!();
+                println!("Square  {:>12}  {:>16}  {:>12}", "Pot (SOL)", "EV/0.01 (SOL)", "Bet (SOL)");
+                for i in 0..25 {
+                    println!(
+                        "{:>6}  {:>12.4}  {:>+16.6}  {:>12}",
+                        i,
+                        sol(s.deployed[i]),
+                        s.marginal_ev[i] as f64 / 1e9,
+                        if s.bets[i] > 0 { format!("{:.4}", sol(s.bets[i])) } else { "-".to_string() },
+                    );
+                }
+                let total: u64 = s.bets.iter().sum();
+                println!("Total bet: {:.4} SOL on {} squares\n", sol(total), s.bets.iter().filter(|b| **b > 0).count());
+            }
+        }
+        
+        Commands::Landings { config: config_path, since_hours, bot } => {
+            let config = config::Config::load(std::path::Path::new(config_path))?;
+            let export = config.deploy_export.as_ref()
+                .ok_or("No [deploy_export] section in the config")?;
+            let since_ms = deploy_audit::now_ms().saturating_sub(since_hours * 3_600_000);
+            let stats = deploy_export::landing_stats(export, since_ms, bot.as_deref()).await?;
+            
+            println!("=== Deploy Landings (last {}h, {}) ===\n", since_hours, export.landings_table);
+            if stats.is_empty() {
+                println!("No landings recorded");
+                return Ok(());
+            }
+            println!("{:<20} {:<16} {:>8} {:>8} {:>10} {:>6} {:>6} {:>6} {:>9}",
+                "Bot", "Host", "Landed", "Sessions", "Avg left", "Min", "Max", "Late", "Latency");
+            for s in &stats {
+                let latency = if s.avg_latency_slots.is_finite() { format!("{:.2}", s.avg_latency_slots) } else { "-".to_string() };
+                println!("{:<20} {:<16} {:>8} {:>8} {:>10.2} {:>6} {:>6} {:>6} {:>9}",
+                    s.bot, s.host, s.landings, s.sessions, s.avg_slots_before_end,
+                    s.min_slots_before_end, s.max_slots_before_end, s.late, latency);
+            }
+            println!("\nSlots left = end_slot - landed slot; latency = slots from first send to landing.");
+        }
+        
+        Commands::EstimateFees { config: config_path } => {
+            let config = config::Config::load(std::path::Path::new(config_path))?;
+            
+            println!("=== Deploy Fee Estimate (per round) ===\n");
+            match client.get_median_priority_fee() {
+                Ok(Some(fee)) => println!("Network median priority fee (ORE board): {} micro-lamports/CU", fee),
+                Ok(None) => println!("Network median priority fee (ORE board): no recent data"),
+                Err(e) => println!("Network median priority fee (ORE board): unavailable ({})", e),
+            }
+            println!("CU limit: {}  |  base fee: {} lamports/tx  |  Evore deploy fee: {} lamports\n",
+                deploy::DEPLOY_CU_LIMIT, fee_estimate::BASE_FEE_LAMPORTS, evore::consts::DEPLOY_FEE);
+            
+            println!("{:<16} {:>8} {:>12} {:>8} {:>12} {:>10} {:>14} {:>10}",
+                "Bot", "Txs", "Prio/CU", "Per-tx", "Tip", "Per-round", "SOL", "% bankroll");
+            let mut total = 0u64;
+            for bot in &config.bots {
+                // Each attempt is sent once per recent blockhash
+                let txs = bot.attempts.saturating_mul(bot.blockhashes.clamp(1, blockhash_cache::MAX_RECENT_BLOCKHASHES as u64));
+                let estimate = fee_estimate::estimate_round_fees(bot.priority_fee, bot.jito_tip, txs);
+                total = total.saturating_add(estimate.per_round);
+                let pct = if bot.bankroll > 0 {
+                    format!("{:.3}%", estimate.per_round as f64 * 100.0 / bot.bankroll as f64)
+                } else {
+                    "-".to_string()
+                };
+                println!("{:<16} {:>8} {:>12} {:>8} {:>12} {:>10} {:>14.9} {:>10}",
+                    bot.name,
+                    estimate.attempts,
+                    bot.priority_fee,
+                    estimate.per_attempt,
+                    estimate.jito_tip,
+                    estimate.per_round,
+                    estimate.per_round as f64 / 1e9,
+                    pct,
+                );
+            }
+            println!("\nTotal: {} lamports ({:.9} SOL) per round across {} bot(s)",
+                total, total as f64 / 1e9, config.bots.len());
+            println!("Per-round assumes every tx lands; the tip and deploy fee are only paid once.");
+        }
+        
+        Commands::Simulate { config: config_path, trials, seed } => {
+            let config = config::Config::load(std::path::Path::new(config_path))?;
+            
+            let board = client.get_board()?;
+            let round = match client.get_round(board.round_id)? {
+                RoundState::Active(round) => round,
+                RoundState::Resetting { round_id } => {
+                    println!("Round {} is resetting - nothing to simulate against yet", round_id);
+                    return Ok(());
+                }
+            };
+            let seed = seed.unwrap_or(board.round_id);
+            let sol = |lamports: i64| lamports as f64 / 1e9;
+            
+            println!("=== Round {} Outcome Simulation ({} trials, seed {}) ===", board.round_id, trials, seed);
+            println!("Pot: {:.4} SOL deployed so far, every square equally likely to win\n",
+                Board25::new(round.deployed).total() as f64 / 1e9);
+            println!("{:<16} {:>8} {:>8} {:>10} {:>10} {:>10} {:>10} {:>7}",
+                "Bot", "Squares", "Bet SOL", "Mean", "p5", "p50", "p95", "Win %");
+            for bot in &config.bots {
+                let manual = bot_runner::manual_amounts(bot.strategy, &bot.strategy_params, bot.rotate_squares, board.round_id)
+                    .or_else(|| bot_runner::bounded_ev_amounts(bot.strategy, &bot.strategy_params, bot.bankroll, bot.slots_left,
+                        bot.min_squares, bot.max_squares, &bot.square_blacklist, &round))
+                    .or_else(|| bot_runner::lead_square_amounts(bot.strategy, &bot.strategy_params, bot.bankroll, &round));
+                let bets = bot_runner::preview_bets(bot.strategy, &bot.strategy_params, manual.as_ref(),
+                    bot.bankroll, bot.slots_left, &round);
+                let total = Board25::new(bets).total();
+                if total == 0 {
+                    println!("{:<16} no bets at the current board", bot.name);
+                    continue;
+                }
+                
+                let outcome = ev_calculator::simulate_round(&bets, &round.deployed, &ev_calculator::UNIFORM_WEIGHTS, *trials, seed);
+                println!("{:<16} {:>8} {:>8.4} {:>10.4} {:>10.4} {:>10.4} {:>10.4} {:>6.1}%",
+                    bot.name,
+                    Board25::new(bets).count(),
+                    total as f64 / 1e9,
+                    outcome.mean / 1e9,
+                    sol(outcome.p5),
+                    sol(outcome.p50),
+                    sol(outcome.p95),
+                    outcome.win_rate * 100.0,
+                );
+            }
+            println!("\nReturns are SOL only (no ORE rewards) against the board as it is now; later deploys change the pot.");
+        }
+        
+        Commands::Backtest { strategy, from, to, clickhouse_url } => {
+            let config = config::Config::load(std::path::Path::new(strategy))?;
+            if from > to {
+                return Err(format!("--from {} is after --to {}", from, to).into());
+            }
+            
+            let reader = backtest::HistoryReader::from_env(clickhouse_url);
+            let history = reader.load(*from, *to).await?;
+            if history.is_empty() {
+                println!("No finished rounds stored between {} and {}", from, to);
+                return Ok(());
+            }
+            
+            println!("=== Backtest: rounds {}-{} ({} with history) ===\n", from, to, history.len());
+            println!("{:<16} {:>7} {:>10} {:>10} {:>8} {:>10} {:>7}",
+                "Bot", "Bet", "Bet SOL", "PnL SOL", "ROI", "Max DD", "Win %");
+            for bot in &config.bots {
+                let result = backtest::backtest_bot(bot, &history);
+                if result.rounds_bet == 0 {
+                    println!("{:<16} no bets in {} rounds", bot.name, result.rounds);
+                    continue;
+                }
+                println!("{:<16} {:>7} {:>10.4} {:>10.4} {:>7.2}% {:>10.4} {:>6.1}%",
+                    bot.name,
+                    result.rounds_bet,
+                    result.deployed as f64 / 1e9,
+                    result.pnl as f64 / 1e9,
+                    result.pnl as f64 / result.deployed as f64 * 100.0,
+                    result.max_drawdown as f64 / 1e9,
+                    result.wins as f64 / result.rounds_bet as f64 * 100.0,
+                );
+            }
+            println!("\nReturns are SOL only (no ORE rewards); each bet is placed against the board slots_left before the end.");
+        }
+        
+        Commands::VerifyConstants => {
+            let network = client.network();
+            println!("=== Verify Constants ({:?}) ===", network.network);
+            println!("ORE program:   {}", network.ore_program_id);
+            println!("Evore program: {}\n", network.evore_program_id);
+            
+            let checks = verify_constants::verify_constants(&client)?;
+            for check in &checks {
+                match &check.result {
+                    Ok(detail) => println!("  ✓ {:<24} {}", check.name, detail),
+                    Err(mismatch) => println!("  ✗ {:<24} {}", check.name, mismatch),
+                }
+            }
+            
+            let failed = checks.iter().filter(|c| !c.is_ok()).count();
+            if failed > 0 {
+                return Err(format!("{} of {} checks failed - the program may have been redeployed or the constants drifted",
+                    failed, checks.len()).into());
+            }
+            println!("\nAll {} checks passed", checks.len());
+        }
+        
+        Commands::WatchSquare { square } => {
+            let ws_url = get_ws_url(&args, &rpc_url);
+            watch_square::run(&rpc_url, &ws_url, *square as usize).await?;
         }
     }
     
@@ -535,6 +1539,9 @@ async fn run_dashboard(
     manager: Pubkey,
     auth_id: u64,
     params: EvDeployParams,
+    target_slot_offset: Option<u64>,
+    ramp: bool,
+    ramp_submissions: u32,
     strategy: String,
     client: EvoreClient,
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -596,6 +1603,9 @@ async fn run_dashboard(
         auth_id,
         manager,
         params,
+        target_slot_offset,
+        ramp,
+        ramp_submissions,
     };
     
     // Spawn bot task
@@ -648,14 +1658,21 @@ async fn run_tui_loop(
         // Handle input (non-blocking check)
         match tui::handle_input(app)? {
             tui::InputResult::Quit => break,
-            tui::InputResult::ReloadConfig(_) => {
+            tui::InputResult::ReloadConfig(_) | tui::InputResult::RevertConfig(_) => {
                 // Config reload not supported in single-bot mode
                 app.set_status("Config reload not available".to_string(), true);
             }
-            tui::InputResult::TogglePause(_) => {
+            tui::InputResult::TogglePause(_)
+            | tui::InputResult::PauseAll
+            | tui::InputResult::ResumeAll => {
                 // Pause not supported in legacy single-bot mode
                 app.set_status("Pause not available in legacy mode".to_string(), true);
             }
+            tui::InputResult::VetoDeploy(_) => {
+                // Legacy bot task never previews deploys
+                app.set_status("Deploy preview not available in legacy mode".to_string(), true);
+            }
+            tui::InputResult::Resized => terminal.clear()?,
             tui::InputResult::Continue => {}
         }
         
@@ -671,14 +1688,23 @@ async fn run_dashboard_with_config(
     rpc_url: &str,
     ws_url: String,
     config_path: &str,
+    rotate_squares: bool,
+    ws_listen: Option<std::net::SocketAddr>,
+    max_balance_subs: usize,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    use crate::config::Config;
+    use crate::config::{Config, ConfigHistory};
     use crate::coordinator::RoundCoordinator;
     use crate::shutdown::spawn_shutdown_handler;
     use std::path::Path;
     
     // Load config
-    let config = Config::load(Path::new(config_path))?;
+    let mut config = Config::load(Path::new(config_path))?;
+    for warning in config.warnings() {
+        eprintln!("Warning: {}", warning);
+    }
+    if rotate_squares {
+        config.force_rotate_squares();
+    }
     
     if config.bots.is_empty() {
         return Err("No bots defined in config file".into());
@@ -749,6 +1775,10 @@ async fn run_dashboard_with_config(
     // Create app state
     let mut app = App::new(rpc_url);
     app.set_config_path(config_path.to_string());
+    app.display_units = config.display.units;
+    
+    // Configs each bot has run with, for reverting a reload
+    let mut config_history: Vec<ConfigHistory> = config.bots.iter().cloned().map(ConfigHistory::new).collect();
     
     // Add bot states to app
     for (index, bot_config) in config.bots.iter().enumerate() {
@@ -757,12 +1787,15 @@ async fn run_dashboard_with_config(
             crate::config::StrategyParams::EV { max_per_square, min_bet, ore_value } => {
                 (*max_per_square, *min_bet, *ore_value, 0, 0)
             }
-            crate::config::StrategyParams::Percentage { percentage, squares_count } => {
-                (0, 0, 0, *percentage, *squares_count)
+            params @ crate::config::StrategyParams::Percentage { percentage, .. } => {
+                (0, 0, 0, *percentage, params.percentage_squares_count())
             }
             crate::config::StrategyParams::Manual { .. } => {
                 (0, 0, 0, 0, 0)
             }
+            crate::config::StrategyParams::LeadSquare { max_per_square, .. } => {
+                (*max_per_square, 0, 0, 0, 0)
+            }
         };
         
         // Load manager to get pubkey
@@ -793,6 +1826,7 @@ async fn run_dashboard_with_config(
             percentage,
             squares_count,
         );
+        bot_state.auth_ids = bot_config.auth_group();
         // Set initial pause state from config
         if bot_config.paused_on_startup {
             bot_state.is_paused = true;
@@ -805,7 +1839,7 @@ async fn run_dashboard_with_config(
     let (update_tx, mut update_rx) = mpsc::unbounded_channel::<TuiUpdate>();
     
     // Create coordinator
-    let mut coordinator = RoundCoordinator::new(rpc_url, &ws_url, update_tx.clone())
+    let mut coordinator = RoundCoordinator::new(rpc_url, &ws_url, config.audit_log_path.as_deref(), config.deploy_export.as_ref(), update_tx.clone())
         .map_err(|e| format!("Failed to create coordinator: {}", e))?;
     coordinator.start_services()
         .map_err(|e| format!("Failed to start services: {}", e))?;
@@ -815,19 +1849,24 @@ async fn run_dashboard_with_config(
     
     // Spawn bots from config
     coordinator.spawn_bots_from_config(&config)?;
+    coordinator.spawn_round_transition_handler();
+    coordinator.spawn_confirmation_tracker();
     
     // Get RPS tracker from coordinator for shared tracking
     let rps_tracker = coordinator.get_rps_tracker();
     
     // Create miner tracker for per-bot deployment polling
-    let mut miner_tracker = miner_tracker::MinerTracker::new(rpc_url, Arc::clone(&rps_tracker), update_tx.clone());
+    let mut miner_tracker = miner_tracker::MinerTracker::new(rpc_url, &ws_url, Arc::clone(&rps_tracker), update_tx.clone())
+        .with_max_balance_subscriptions(max_balance_subs);
     for (index, bot_config) in config.bots.iter().enumerate() {
         // Get manager pubkey to derive miner PDA
         let manager_path = config.get_manager_path(bot_config);
         if let Ok(manager_keypair) = solana_sdk::signature::read_keypair_file(&manager_path) {
-            let (managed_miner_auth, _) = evore::state::managed_miner_auth_pda(manager_keypair.pubkey(), bot_config.auth_id);
-            // Add the authority (managed_miner_auth), the tracker will derive the miner PDA
-            miner_tracker.add_miner(index, managed_miner_auth);
+            // Add the authorities (managed_miner_auth per auth_id), the tracker will derive the miner PDAs
+            let authorities = bot_config.auth_group().iter()
+                .map(|auth_id| evore::state::managed_miner_auth_pda(manager_keypair.pubkey(), *auth_id).0)
+                .collect();
+            miner_tracker.add_miner(index, authorities);
         }
     }
     miner_tracker.start();
@@ -836,6 +1875,23 @@ async fn run_dashboard_with_config(
     let treasury_tracker = treasury_tracker::TreasuryTracker::new(rpc_url, Arc::clone(&rps_tracker), update_tx.clone());
     treasury_tracker.start();
     
+    // SOL/USD price for dashboard amounts in USD
+    if config.display.units == display_units::DisplayUnits::Usd {
+        tokio::spawn(display_units::price_loop(config.display.clone(), update_tx.clone()));
+    }
+    
+    // Optional remote monitor for viewing the dashboard from another machine
+    let monitor = match ws_listen {
+        Some(addr) => {
+            let server = monitor::MonitorServer::bind(addr).await
+                .map_err(|e| format!("Failed to bind monitor on {}: {}", addr, e))?;
+            println!("Monitor listening on ws://{}", addr);
+            Some(server)
+        }
+        None => None,
+    };
+    let mut last_snapshot = std::time::Instant::now() - MONITOR_SNAPSHOT_INTERVAL;
+    
     println!("Started {} bot(s). Press 'q' to quit.\n", coordinator.bot_count());
     
     // Setup shutdown handler
@@ -851,7 +1907,12 @@ async fn run_dashboard_with_config(
             // Poll for updates from bot tasks
             loop {
                 match update_rx.try_recv() {
-                    Ok(update) => app.apply_update(update),
+                    Ok(update) => {
+                        if let Some(monitor) = &monitor {
+                            monitor.publish_update(&update);
+                        }
+                        app.apply_update(update);
+                    }
                     Err(mpsc::error::TryRecvError::Empty) => break,
                     Err(mpsc::error::TryRecvError::Disconnected) => {
                         app.running = false;
@@ -897,6 +1958,13 @@ async fn run_dashboard_with_config(
             app.network_stats.rpc_total = coordinator.get_rpc_total();
             app.network_stats.sender_total = coordinator.get_sender_total();
             
+            if let Some(monitor) = &monitor {
+                if last_snapshot.elapsed() >= MONITOR_SNAPSHOT_INTERVAL {
+                    monitor.publish_snapshot(&app);
+                    last_snapshot = std::time::Instant::now();
+                }
+            }
+            
             // Draw UI
             terminal.draw(|frame| tui::draw(frame, &app))?;
             
@@ -934,12 +2002,52 @@ async fn run_dashboard_with_config(
                         }
                     }
                 }
+                action @ (tui::InputResult::PauseAll | tui::InputResult::ResumeAll) => {
+                    let pause = matches!(action, tui::InputResult::PauseAll);
+                    let changed = coordinator.set_all_paused(pause).await;
+                    
+                    // Update TUI state for every bot so per-bot toggles stay in sync
+                    for (idx, bot) in app.bots.iter_mut().enumerate() {
+                        bot.is_paused = pause;
+                        if changed.contains(&idx) {
+                            bot.status = if pause {
+                                tui::BotStatus::Paused
+                            } else {
+                                tui::BotStatus::Loading
+                            };
+                        }
+                    }
+                    
+                    // Show a single summary status message
+                    let msg = match (pause, changed.len()) {
+                        (true, 0) => "⏸️ All bots already paused".to_string(),
+                        (false, 0) => "▶️ No paused bots to resume".to_string(),
+                        (true, n) => format!("⏸️ Paused {} bot{}", n, if n == 1 { "" } else { "s" }),
+                        (false, n) => format!("▶️ Resumed {} bot{}", n, if n == 1 { "" } else { "s" }),
+                    };
+                    app.set_status(msg, false);
+                }
+                tui::InputResult::VetoDeploy(bot_idx) => {
+                    match coordinator.veto_pending_deploy(bot_idx).await {
+                        Ok(()) => {
+                            let bot_name = app.bots.get(bot_idx).map(|b| b.name.clone()).unwrap_or_default();
+                            app.set_status(format!("🛑 Vetoed deploy for {}", bot_name), false);
+                        }
+                        Err(e) => {
+                            app.set_status(format!("Veto error: {}", e), true);
+                        }
+                    }
+                }
                 tui::InputResult::ReloadConfig(bot_idx) => {
                     // Try to reload config from file
                     let config_path_clone = app.config_path.clone();
                     if let Some(config_path) = config_path_clone {
                         match Config::load(Path::new(&config_path)) {
-                            Ok(new_config) => {
+                            Ok(mut new_config) => {
+                                // Keep the --rotate-squares override across reloads
+                                if rotate_squares {
+                                    new_config.force_rotate_squares();
+                                }
                                 // Find the bot config by index
                                 if let Some(new_bot_config) = new_config.bots.get(bot_idx) {
                                     // Update the bot's runtime config (actual deployment values)
@@ -949,31 +2057,12 @@ async fn run_dashboard_with_config(
                                     }
                                     
                                     // Update the bot's display config (TUI)
-                                    let bot_name = app.bots.get(bot_idx).map(|b| b.name.clone());
+                                    let version = config_history.get_mut(bot_idx)
+                                        .map_or(0, |history| history.push(new_bot_config.clone()));
                                     if let Some(bot) = app.bots.get_mut(bot_idx) {
-                                        // Update bankroll and fees
-                                        bot.bankroll = new_bot_config.bankroll;
-                                        bot.slots_left_threshold = new_bot_config.slots_left;
-                                        bot.priority_fee = new_bot_config.priority_fee;
-                                        bot.jito_tip = new_bot_config.jito_tip;
-                                        
-                                        // Update strategy params
-                                        match &new_bot_config.strategy_params {
-                                            crate::config::StrategyParams::EV { max_per_square, min_bet, ore_value } => {
-                                                bot.max_per_square = *max_per_square;
-                                                bot.min_bet = *min_bet;
-                                                bot.ore_value = *ore_value;
-                                            }
-                                            crate::config::StrategyParams::Percentage { percentage, squares_count } => {
-                                                bot.percentage = *percentage;
-                                                bot.squares_count = *squares_count;
-                                            }
-                                            _ => {}
-                                        }
-                                    }
-                                    
-                                    if let Some(name) = bot_name {
-                                        app.set_status(format!("Config reloaded: {}", name), false);
+                                        bot.apply_config(new_bot_config, version);
+                                        let name = bot.name.clone();
+                                        app.set_status(format!("Config reloaded: {} (v{})", name, version), false);
                                     }
                                 } else {
                                     app.set_status(format!("Bot {} not found in config", bot_idx), true);
@@ -987,6 +2076,28 @@ async fn run_dashboard_with_config(
                         app.set_status("No config path set".to_string(), true);
                     }
                 }
+                tui::InputResult::RevertConfig(bot_idx) => {
+                    // Re-apply the previous config without touching the file
+                    let previous = config_history.get(bot_idx)
+                        .and_then(|history| history.previous())
+                        .map(|(version, config)| (version, config.clone()));
+                    match previous {
+                        Some((version, previous)) => {
+                            if let Err(e) = coordinator.update_bot_config(bot_idx, &previous).await {
+                                app.set_status(format!("Config revert error: {}", e), true);
+                                continue;
+                            }
+                            config_history[bot_idx].revert();
+                            if let Some(bot) = app.bots.get_mut(bot_idx) {
+                                bot.apply_config(&previous, version);
+                                let name = bot.name.clone();
+                                app.set_status(format!("Config reverted: {} (v{})", name, version), false);
+                            }
+                        }
+                        None => app.set_status("No earlier config to revert to".to_string(), true),
+                    }
+                }
+                tui::InputResult::Resized => terminal.clear()?,
                 tui::InputResult::Continue => {}
             }
             
@@ -1006,12 +2117,14 @@ async fn run_dashboard_with_config(
 async fn run_manage_tui(
     rpc_url: &str,
     config_path: &str,
+    create_ata: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    use crate::config::Config;
+    use crate::config::{Config, ManageCommitment};
     use crate::manage::{discover_accounts, load_signers_from_directory, DiscoveryResult};
     use crate::manage_tui::{self, ManageApp};
     use std::path::Path;
-    use solana_client::rpc_client::RpcClient;
+    use std::time::Duration;
+    use solana_sdk::commitment_config::CommitmentConfig;
     
     // Load config
     let config = Config::load(Path::new(config_path))?;
@@ -1029,7 +2142,7 @@ async fn run_manage_tui(
     println!();
     
     // Create RPC client
-    let rpc = RpcClient::new(rpc_url.to_string());
+    let rpc = rpc_headers::rpc_client(rpc_url, Duration::from_secs(30), CommitmentConfig::default());
     
     // Load signers
     println!("Loading signers...");
@@ -1067,7 +2180,7 @@ async fn run_manage_tui(
     let mut app = ManageApp::new(rpc_url, config.manage.clone(), discovery, signers);
     
     // Channel for async operation results
-    let (result_tx, mut result_rx) = tokio::sync::mpsc::channel::<(usize, manage_tui::MinerAction, Result<solana_sdk::signature::Signature, String>)>(32);
+    let (result_tx, mut result_rx) = tokio::sync::mpsc::channel::<(usize, manage_tui::MinerAction, Result<(solana_sdk::signature::Signature, ManageCommitment), String>)>(32);
     let (refresh_tx, mut refresh_rx) = tokio::sync::mpsc::channel::<Result<DiscoveryResult, String>>(1);
     
     // Main TUI loop
@@ -1077,13 +2190,13 @@ async fn run_manage_tui(
             while let Ok((miner_idx, action, tx_result)) = result_rx.try_recv() {
                 app.operation_in_progress = false;
                 match tx_result {
-                    Ok(sig) => {
-                        app.log_tx(miner_idx, action, Some(sig), None);
-                        app.set_status(format!("✓ {} complete: {}...", action.as_str(), &sig.to_string()[..8]), false);
+                    Ok((sig, commitment)) => {
+                        app.log_tx(miner_idx, action, Some(sig), Some(commitment), None);
+                        app.set_status(format!("✓ {} complete ({}): {}...", action.as_str(), commitment.as_str(), &sig.to_string()[..8]), false);
                     }
                     Err(e) => {
                         let formatted_error = manage_tui::format_rpc_error(&e);
-                        app.log_tx(miner_idx, action, None, Some(formatted_error.clone()));
+                        app.log_tx(miner_idx, action, None, None, Some(formatted_error.clone()));
                         app.set_status(format!("✗ {} failed: {}", action.as_str(), formatted_error), true);
                     }
                 }
@@ -1095,9 +2208,8 @@ async fn run_manage_tui(
                 app.operation_in_progress = false;
                 match refresh_result {
                     Ok(new_discovery) => {
-                        app.discovery = new_discovery.clone();
-                        app.all_miners = new_discovery.miners.clone();
-                        app.all_miners.extend(new_discovery.legacy_miners.clone());
+                        app.discovery = new_discovery;
+                        app.rebuild_miner_list();
                         app.set_status(format!("Refreshed: {} miners", app.all_miners.len()), false);
                     }
                     Err(e) => {
@@ -1119,6 +2231,12 @@ async fn run_manage_tui(
                 manage_tui::InputResult::ToggleSkipPreflight => {
                     app.toggle_skip_preflight();
                 }
+                manage_tui::InputResult::ToggleCheckpointFirst => {
+                    app.toggle_checkpoint_first();
+                }
+                manage_tui::InputResult::CycleCommitment => {
+                    app.cycle_commitment();
+                }
                 manage_tui::InputResult::Refresh => {
                     if !app.operation_in_progress {
                         app.set_status("Refreshing...".to_string(), false);
@@ -1132,7 +2250,7 @@ async fn run_manage_tui(
                         
                         tokio::spawn(async move {
                             let result = tokio::task::spawn_blocking(move || {
-                                let rpc = RpcClient::new(rpc_url_clone);
+                                let rpc = rpc_headers::rpc_client(&rpc_url_clone, Duration::from_secs(30), CommitmentConfig::default());
                                 discover_accounts(&rpc, &manage_config)
                             }).await;
                             
@@ -1176,11 +2294,12 @@ async fn run_manage_tui(
                                         // Spawn async transaction operation
                                         let rpc_url_clone = rpc_url.to_string();
                                         let skip_preflight = app.skip_preflight;
+                                        let commitment = app.commitment;
                                         let tx = result_tx.clone();
                                         
                                         tokio::spawn(async move {
                                             let result = tokio::task::spawn_blocking(move || {
-                                                let rpc = RpcClient::new(rpc_url_clone);
+                                                let rpc = rpc_headers::rpc_client(&rpc_url_clone, Duration::from_secs(30), CommitmentConfig::default());
                                                 
                                                 // Get blockhash
                                                 let blockhash = match rpc.get_latest_blockhash() {
@@ -1195,6 +2314,8 @@ async fn run_manage_tui(
                                                     action,
                                                     blockhash,
                                                     skip_preflight,
+                                                    create_ata,
+                                                    commitment,
                                                 )
                                             }).await;
                                             
@@ -1210,6 +2331,7 @@ async fn run_manage_tui(
                         }
                     }
                 }
+                manage_tui::InputResult::Resized => terminal.clear()?,
                 manage_tui::InputResult::Continue => {}
             }
             
@@ -1232,9 +2354,12 @@ fn execute_miner_action_with_opts(
     action: manage_tui::MinerAction,
     blockhash: solana_sdk::hash::Hash,
     skip_preflight: bool,
-) -> Result<solana_sdk::signature::Signature, String> {
+    create_ata: bool,
+    commitment: config::ManageCommitment,
+) -> Result<(solana_sdk::signature::Signature, config::ManageCommitment), String> {
     use solana_client::rpc_config::RpcSendTransactionConfig;
     use solana_sdk::commitment_config::CommitmentLevel;
+    use crate::config::ManageCommitment;
     
     let tx = match action {
         manage_tui::MinerAction::Checkpoint => {
@@ -1279,6 +2404,7 @@ fn execute_miner_action_with_opts(
                     &miner.manager,
                     miner.auth_id,
                     &miner.program_id,
+                    create_ata,
                     blockhash,
                 )
             } else {
@@ -1286,6 +2412,7 @@ fn execute_miner_action_with_opts(
                     signer.as_ref(),
                     &miner.manager,
                     miner.auth_id,
+                    create_ata,
                     blockhash,
                 )
             }
@@ -1300,41 +2427,39 @@ fn execute_miner_action_with_opts(
     };
     
     // Send and confirm transaction
+    safe_mode::guard(&tx)?;
     let sig = rpc.send_transaction_with_config(&tx, config)
         .map_err(|e| format!("{}", e))?;
     
-    // Wait for confirmation (with timeout)
-    use std::time::{Duration, Instant};
+    // Wait until the transaction reaches the requested commitment (with timeout)
+    use std::time::Instant;
     
     let start = Instant::now();
-    let timeout = Duration::from_secs(30);
+    let timeout = commitment.timeout();
+    let mut reached: Option<ManageCommitment> = None;
     
     loop {
         if start.elapsed() > timeout {
-            return Err(format!("Transaction {} not confirmed in 30s", sig));
+            let reached = reached.map(|c| c.as_str()).unwrap_or("not found");
+            return Err(format!("Transaction {} not {} in {}s (reached {})",
+                sig, commitment.as_str(), timeout.as_secs(), reached));
         }
         
-        match rpc.get_signature_statuses(&[sig]) {
-            Ok(response) => {
-                if let Some(Some(status)) = response.value.first() {
-                    if let Some(err) = &status.err {
-                        return Err(format!("Transaction failed: {:?}", err));
-                    }
-                    // Check if has any confirmations or confirmation status is set
-                    // (confirmation_status being Some means it's at least processed)
-                    let is_confirmed = status.confirmations.unwrap_or(0) > 0 
-                        || status.confirmation_status.is_some();
-                    if is_confirmed {
-                        return Ok(sig);
-                    }
+        // Network hiccups are ignored, we just keep polling
+        if let Ok(response) = rpc.get_signature_statuses(&[sig]) {
+            if let Some(Some(status)) = response.value.first() {
+                if let Some(err) = &status.err {
+                    return Err(format!("Transaction failed: {:?}", err));
+                }
+                reached = ManageCommitment::ALL.into_iter()
+                    .rev()
+                    .find(|level| status.satisfies_commitment(level.commitment_config()));
+                if reached >= Some(commitment) {
+                    return Ok((sig, reached.unwrap_or(commitment)));
                 }
-            }
-            Err(e) => {
-                // Log but continue waiting - network hiccup
-                let _ = e; // silence unused warning
             }
         }
         
-        std::thread::sleep(Duration::from_millis(500));
+        std::thread::sleep(std::time::Duration::from_millis(500));
     }
 }
@@ -14,7 +14,7 @@ use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
 use solana_client::rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
 use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::Pubkey;
-use solana_sdk::signature::{read_keypair_file, Keypair};
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
 
 use evore::ore_api::{miner_pda, Miner};
 use evore::state::Manager;
@@ -66,6 +66,15 @@ impl DiscoveredMiner {
         self.miner.round_id > self.miner.checkpoint_id
     }
     
+    /// Rounds deployed since the last checkpoint (None for legacy miners,
+    /// which can't be checkpointed from here)
+    pub fn rounds_behind(&self) -> Option<u64> {
+        if self.is_legacy {
+            return None;
+        }
+        Some(self.miner.round_id.saturating_sub(self.miner.checkpoint_id))
+    }
+    
     /// Get claimable SOL in lamports
     pub fn claimable_sol(&self) -> u64 {
         self.miner.rewards_sol
@@ -77,6 +86,11 @@ impl DiscoveredMiner {
     }
 }
 
+/// Stable sort putting miners that need a checkpoint first, furthest behind first
+pub fn sort_by_checkpoint_needed(miners: &mut [DiscoveredMiner]) {
+    miners.sort_by_key(|m| std::cmp::Reverse(m.rounds_behind().unwrap_or(0)));
+}
+
 /// Account discovery result
 #[derive(Debug, Clone)]
 pub struct DiscoveryResult {
@@ -106,7 +120,42 @@ impl DiscoveryResult {
     }
 }
 
+/// A keypair file skipped because an earlier file holds the same key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateSigner {
+    pub pubkey: Pubkey,
+    /// The skipped file
+    pub path: PathBuf,
+    /// The file that was kept for this key
+    pub kept: PathBuf,
+}
+
+/// Drop signers whose pubkey was already seen, keeping the first occurrence
+pub fn dedup_signers(
+    signers: Vec<(Arc<Keypair>, PathBuf)>,
+) -> (Vec<(Arc<Keypair>, PathBuf)>, Vec<DuplicateSigner>) {
+    let mut kept: Vec<(Arc<Keypair>, PathBuf)> = Vec::new();
+    let mut duplicates = Vec::new();
+    
+    for (keypair, path) in signers {
+        let pubkey = keypair.pubkey();
+        match kept.iter().find(|(k, _)| k.pubkey() == pubkey) {
+            Some((_, first)) => duplicates.push(DuplicateSigner {
+                pubkey,
+                path,
+                kept: first.clone(),
+            }),
+            None => kept.push((keypair, path)),
+        }
+    }
+    
+    (kept, duplicates)
+}
+
 /// Load all signer keypairs from a directory
+///
+/// Files are read in path order; if two files hold the same key only the first
+/// is kept and the other is reported as a warning.
 pub fn load_signers_from_directory(dir: &Path) -> Result<Vec<(Arc<Keypair>, PathBuf)>, String> {
     if !dir.exists() {
         return Err(format!("Signers directory does not exist: {:?}", dir));
@@ -116,30 +165,43 @@ pub fn load_signers_from_directory(dir: &Path) -> Result<Vec<(Arc<Keypair>, Path
         return Err(format!("Signers path is not a directory: {:?}", dir));
     }
     
-    let mut signers = Vec::new();
-    
-    // Read all .json files in directory
+    // Read all .json files in directory, sorted so duplicate handling is deterministic
     let entries = std::fs::read_dir(dir)
         .map_err(|e| format!("Failed to read directory {:?}: {}", dir, e))?;
     
+    let mut paths = Vec::new();
     for entry in entries {
         let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
         let path = entry.path();
         
         // Only process .json files
         if path.extension().map_or(false, |ext| ext == "json") {
-            match read_keypair_file(&path) {
-                Ok(keypair) => {
-                    signers.push((Arc::new(keypair), path));
-                }
-                Err(e) => {
-                    // Log but continue - might not be a keypair file
-                    eprintln!("Warning: Failed to load keypair from {:?}: {}", path, e);
-                }
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    
+    let mut signers = Vec::new();
+    for path in paths {
+        match read_keypair_file(&path) {
+            Ok(keypair) => {
+                signers.push((Arc::new(keypair), path));
+            }
+            Err(e) => {
+                // Log but continue - might not be a keypair file
+                eprintln!("Warning: Failed to load keypair from {:?}: {}", path, e);
             }
         }
     }
     
+    let (signers, duplicates) = dedup_signers(signers);
+    for duplicate in &duplicates {
+        eprintln!(
+            "Warning: Skipping {:?}: signer {} is already loaded from {:?}",
+            duplicate.path, duplicate.pubkey, duplicate.kept
+        );
+    }
+    
     Ok(signers)
 }
 
@@ -364,4 +426,74 @@ mod tests {
         let pda3 = managed_miner_auth_pda(&manager, 2, &EVORE_PROGRAM_ID);
         assert_ne!(pda, pda3);
     }
+    
+    fn test_miner(round_id: u64, checkpoint_id: u64, is_legacy: bool) -> DiscoveredMiner {
+        use steel::Zeroable;
+        
+        let mut miner = Miner::zeroed();
+        miner.round_id = round_id;
+        miner.checkpoint_id = checkpoint_id;
+        DiscoveredMiner {
+            authority_pda: Pubkey::new_unique(),
+            miner_pda: Pubkey::new_unique(),
+            miner,
+            manager: Pubkey::new_unique(),
+            auth_id: 0,
+            signer: Pubkey::new_unique(),
+            program_id: EVORE_PROGRAM_ID,
+            is_legacy,
+            auth_pda_balance: 0,
+        }
+    }
+    
+    #[test]
+    fn test_sort_by_checkpoint_needed() {
+        let mut miners = vec![
+            test_miner(10, 10, false),
+            test_miner(12, 10, false),
+            test_miner(20, 5, true),
+            test_miner(15, 10, false),
+            test_miner(7, 7, false),
+        ];
+        assert_eq!(miners[1].rounds_behind(), Some(2));
+        assert_eq!(miners[2].rounds_behind(), None);
+        
+        let order: Vec<_> = miners.iter().map(|m| m.authority_pda).collect();
+        sort_by_checkpoint_needed(&mut miners);
+        let sorted: Vec<_> = miners.iter().map(|m| m.authority_pda).collect();
+        // Furthest behind first, the rest keep discovery order
+        assert_eq!(sorted, vec![order[3], order[1], order[0], order[2], order[4]]);
+    }
+    
+    #[test]
+    fn test_load_signers_dedups_by_pubkey() {
+        use solana_sdk::signature::write_keypair_file;
+        
+        let dir = std::env::temp_dir().join(format!("evore-signers-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let keypair = Keypair::new();
+        let other = Keypair::new();
+        write_keypair_file(&keypair, dir.join("a.json")).unwrap();
+        write_keypair_file(&keypair, dir.join("b.json")).unwrap();
+        write_keypair_file(&other, dir.join("c.json")).unwrap();
+        
+        let signers = load_signers_from_directory(&dir).unwrap();
+        let loaded: Vec<_> = signers.iter().map(|(k, p)| (k.pubkey(), p.clone())).collect();
+        assert_eq!(loaded, vec![(keypair.pubkey(), dir.join("a.json")), (other.pubkey(), dir.join("c.json"))]);
+        
+        // The skipped file is reported against the one that was kept
+        let all = vec![
+            (Arc::new(keypair.insecure_clone()), dir.join("a.json")),
+            (Arc::new(keypair.insecure_clone()), dir.join("b.json")),
+        ];
+        let (kept, duplicates) = dedup_signers(all);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(duplicates, vec![DuplicateSigner {
+            pubkey: keypair.pubkey(),
+            path: dir.join("b.json"),
+            kept: dir.join("a.json"),
+        }]);
+        
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
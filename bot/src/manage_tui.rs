@@ -29,8 +29,8 @@ use solana_sdk::{
     signature::{Keypair, Signature},
 };
 
-use crate::config::ManageConfig;
-use crate::manage::{DiscoveredMiner, DiscoveryResult};
+use crate::config::{ManageCommitment, ManageConfig};
+use crate::manage::{sort_by_checkpoint_needed, DiscoveredMiner, DiscoveryResult};
 
 /// Helper to format pubkey as shortened version (7...7)
 pub fn shorten_pubkey(pubkey: &Pubkey) -> String {
@@ -81,6 +81,8 @@ pub struct TxResult {
     pub miner_index: usize,
     pub action: MinerAction,
     pub signature: Option<Signature>,
+    /// Commitment the transaction reached before it was reported complete
+    pub commitment: Option<ManageCommitment>,
     pub error: Option<String>,
     pub timestamp: Instant,
 }
@@ -116,6 +118,12 @@ pub struct ManageApp {
     
     /// Flag indicating an async operation is in progress (non-blocking)
     pub operation_in_progress: bool,
+    
+    /// List miners needing a checkpoint first (furthest behind first)
+    pub checkpoint_first: bool,
+    
+    /// Commitment to wait for before reporting a transaction complete
+    pub commitment: ManageCommitment,
 }
 
 impl ManageApp {
@@ -125,17 +133,14 @@ impl ManageApp {
         discovery: DiscoveryResult,
         signers: Vec<(Arc<Keypair>, PathBuf)>,
     ) -> Self {
-        // Combine current and legacy miners
-        let mut all_miners = discovery.miners.clone();
-        all_miners.extend(discovery.legacy_miners.clone());
-        
-        Self {
+        let commitment = config.commitment;
+        let mut app = Self {
             running: true,
             rpc_url: rpc_url.to_string(),
             config,
             discovery,
             signers,
-            all_miners,
+            all_miners: Vec::new(),
             selection: None,
             scroll_offset: 0,
             status_msg: None,
@@ -143,9 +148,42 @@ impl ManageApp {
             refreshing: false,
             skip_preflight: false,
             operation_in_progress: false,
+            checkpoint_first: false,
+            commitment,
+        };
+        app.rebuild_miner_list();
+        app
+    }
+    
+    /// Rebuild the display list from discovery (current + legacy miners),
+    /// applying the checkpoint ordering if enabled
+    pub fn rebuild_miner_list(&mut self) {
+        self.all_miners = self.discovery.miners.clone();
+        self.all_miners.extend(self.discovery.legacy_miners.clone());
+        if self.checkpoint_first {
+            sort_by_checkpoint_needed(&mut self.all_miners);
         }
     }
     
+    /// Number of (non-legacy) miners that need a checkpoint
+    pub fn checkpoint_needed_count(&self) -> usize {
+        self.all_miners.iter().filter(|m| m.rounds_behind().unwrap_or(0) > 0).count()
+    }
+    
+    /// Toggle listing miners that need a checkpoint first
+    pub fn toggle_checkpoint_first(&mut self) {
+        self.checkpoint_first = !self.checkpoint_first;
+        self.rebuild_miner_list();
+        // Indices changed, so drop the selection rather than point at another miner
+        self.selection = None;
+        self.scroll_offset = 0;
+        let state = if self.checkpoint_first { "ON" } else { "OFF" };
+        self.set_status(
+            format!("Checkpoint first: {} ({} need checkpoint)", state, self.checkpoint_needed_count()),
+            false,
+        );
+    }
+    
     /// Get the pubkey of the currently selected element (if a pubkey is selected)
     pub fn get_selected_pubkey(&self) -> Option<Pubkey> {
         match &self.selection {
@@ -159,6 +197,12 @@ impl ManageApp {
         }
     }
     
+    /// Cycle the commitment transactions wait for
+    pub fn cycle_commitment(&mut self) {
+        self.commitment = self.commitment.next();
+        self.set_status(format!("Wait for: {}", self.commitment.as_str()), false);
+    }
+    
     /// Toggle skip preflight setting
     pub fn toggle_skip_preflight(&mut self) {
         self.skip_preflight = !self.skip_preflight;
@@ -289,11 +333,19 @@ impl ManageApp {
     }
     
     /// Log transaction result
-    pub fn log_tx(&mut self, miner_index: usize, action: MinerAction, signature: Option<Signature>, error: Option<String>) {
+    pub fn log_tx(
+        &mut self,
+        miner_index: usize,
+        action: MinerAction,
+        signature: Option<Signature>,
+        commitment: Option<ManageCommitment>,
+        error: Option<String>,
+    ) {
         self.tx_log.push(TxResult {
             miner_index,
             action,
             signature,
+            commitment,
             error,
             timestamp: Instant::now(),
         });
@@ -337,26 +389,78 @@ pub fn init() -> io::Result<Tui> {
 
 /// Restore terminal
 pub fn restore() -> io::Result<()> {
-    disable_raw_mode()?;
+    // Leave the alternate screen even if raw mode can't be disabled (this
+    // also runs from the panic hook, e.g. after a render panic on resize)
+    let raw = disable_raw_mode();
     execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
-    Ok(())
+    raw
 }
 
 /// Draw the manage TUI
 pub fn draw(frame: &mut Frame, app: &ManageApp) {
-    // Main layout: Header, Miner List, Footer
+    if crate::tui::draw_too_small(frame, MIN_SIZE) {
+        return;
+    }
+    
+    // Main layout: Header, Miner List, Tx Log, Footer
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(4),   // Header
             Constraint::Min(10),     // Miner list
+            Constraint::Length(TX_LOG_ROWS as u16 + 2), // Tx log
             Constraint::Length(3),   // Footer/help
         ])
         .split(frame.area());
     
     draw_header(frame, chunks[0], app);
     draw_miner_list(frame, chunks[1], app);
-    draw_footer(frame, chunks[2], app);
+    draw_tx_log(frame, chunks[2], app);
+    draw_footer(frame, chunks[3], app);
+}
+
+/// Transactions shown in the tx log panel
+const TX_LOG_ROWS: usize = 4;
+
+/// Smallest terminal (columns, rows) the manage layout fits in
+const MIN_SIZE: (u16, u16) = (80, 4 + 10 + TX_LOG_ROWS as u16 + 2 + 3);
+
+/// Draw the most recent transaction results, newest first
+fn draw_tx_log(frame: &mut Frame, area: Rect, app: &ManageApp) {
+    let lines: Vec<Line> = app.tx_log.iter().rev().take(TX_LOG_ROWS).map(|tx| {
+        let miner = app.all_miners.get(tx.miner_index)
+            .map(|m| shorten_pubkey(&m.authority_pda))
+            .unwrap_or_else(|| "?".to_string());
+        let age = Span::styled(
+            format!("  {:>4}s ago ", tx.timestamp.elapsed().as_secs()),
+            Style::default().fg(Color::DarkGray),
+        );
+        let action = Span::styled(format!("{:<10} {} ", tx.action.as_str(), miner), Style::default().fg(Color::White));
+        let result = match (&tx.signature, &tx.error) {
+            (Some(sig), _) => {
+                let commitment = tx.commitment.map(|c| c.as_str()).unwrap_or("?");
+                let color = if tx.commitment == Some(ManageCommitment::Finalized) { Color::Green } else { Color::Yellow };
+                vec![
+                    Span::styled(format!("✓ {} ", commitment), Style::default().fg(color)),
+                    Span::styled(format!("{}...", &sig.to_string()[..16]), Style::default().fg(Color::DarkGray)),
+                ]
+            }
+            (None, error) => vec![Span::styled(
+                format!("✗ {}", error.as_deref().unwrap_or("failed")),
+                Style::default().fg(Color::Red),
+            )],
+        };
+        let mut spans = vec![age, action];
+        spans.extend(result);
+        Line::from(spans)
+    }).collect();
+    
+    let block = Block::default()
+        .title(" Transactions ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray));
+    
+    frame.render_widget(Paragraph::new(lines).block(block), area);
 }
 
 /// Draw header with stats
@@ -369,6 +473,7 @@ fn draw_header(frame: &mut Frame, area: Rect, app: &ManageApp) {
     // Calculate totals
     let total_sol: u64 = app.all_miners.iter().map(|m| m.claimable_sol()).sum();
     let total_ore: u64 = app.all_miners.iter().map(|m| m.claimable_ore()).sum();
+    let checkpoint_needed = app.checkpoint_needed_count();
     
     let line1 = Line::from(vec![
         Span::styled("  ⛏️  MINER MANAGEMENT ", Style::default().fg(Color::Cyan).bold()),
@@ -390,6 +495,11 @@ fn draw_header(frame: &mut Frame, area: Rect, app: &ManageApp) {
         Span::styled(format!("{:.4} ◎", total_sol as f64 / 1e9), Style::default().fg(Color::Yellow)),
         Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
         Span::styled(format!("{:.2} ORE", total_ore as f64 / 1e11), Style::default().fg(Color::Rgb(255, 165, 0))),
+        Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!("Need Chk: {}", checkpoint_needed),
+            if checkpoint_needed > 0 { Style::default().fg(Color::Yellow) } else { Style::default().fg(Color::DarkGray) },
+        ),
         // Status message
         if let Some((msg, _, is_error)) = &app.status_msg {
             let color = if *is_error { Color::Red } else { Color::Green };
@@ -504,9 +614,11 @@ fn draw_miner_list(frame: &mut Frame, area: Rect, app: &ManageApp) {
                 if ore > 0.0 { Style::default().fg(Color::Rgb(255, 165, 0)) } else { Style::default().fg(Color::DarkGray) }
             ));
             
-            // Checkpoint indicator
-            if miner.needs_checkpoint() && !miner.is_legacy {
-                spans.push(Span::styled("⚠", Style::default().fg(Color::Yellow)));
+            // Checkpoint indicator (rounds since last checkpoint)
+            match miner.rounds_behind() {
+                None => spans.push(Span::styled("Chk:n/a", Style::default().fg(Color::DarkGray))),
+                Some(0) => spans.push(Span::styled("Chk:ok", Style::default().fg(Color::DarkGray))),
+                Some(n) => spans.push(Span::styled(format!("Chk:⚠{}", n), Style::default().fg(Color::Yellow))),
             }
             
             // Actions (inline)
@@ -579,6 +691,22 @@ fn draw_footer(frame: &mut Frame, area: Rect, app: &ManageApp) {
     };
     let preflight_text = if app.skip_preflight { "[S]kip Preflight: ON " } else { "[S]kip Preflight: OFF " };
     
+    // Checkpoint ordering indicator
+    let checkpoint_style = if app.checkpoint_first {
+        Style::default().fg(Color::Green).bold()
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    let checkpoint_text = if app.checkpoint_first { "[C]hk First: ON " } else { "[C]hk First: OFF " };
+    
+    // Commitment indicator
+    let commitment_style = if app.commitment == ManageCommitment::Finalized {
+        Style::default().fg(Color::Green).bold()
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    let commitment_text = format!("[F]inality: {} ", app.commitment.as_str());
+    
     // Operation in progress indicator
     let busy_indicator = if app.operation_in_progress {
         Span::styled("⏳ ", Style::default().fg(Color::Yellow))
@@ -592,6 +720,8 @@ fn draw_footer(frame: &mut Frame, area: Rect, app: &ManageApp) {
         Span::styled("[Enter] Execute/Copy ", Style::default().fg(Color::Cyan)),
         Span::styled("[R] Refresh ", Style::default().fg(Color::Yellow)),
         Span::styled(preflight_text, preflight_style),
+        Span::styled(checkpoint_text, checkpoint_style),
+        Span::styled(commitment_text, commitment_style),
         Span::styled("[Q] Quit ", Style::default().fg(Color::Red)),
     ];
     
@@ -612,12 +742,19 @@ pub enum InputResult {
     Refresh,
     CopyPubkey(Pubkey),
     ToggleSkipPreflight,
+    ToggleCheckpointFirst,
+    CycleCommitment,
+    Resized,
 }
 
 /// Handle keyboard input
 pub fn handle_input(app: &mut ManageApp) -> io::Result<InputResult> {
     if event::poll(Duration::from_millis(50))? {
-        if let Event::Key(key) = event::read()? {
+        let event = event::read()?;
+        if let Event::Resize(_, _) = event {
+            return Ok(InputResult::Resized);
+        }
+        if let Event::Key(key) = event {
             if key.kind == KeyEventKind::Press {
                 match key.code {
                     KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
@@ -660,6 +797,14 @@ pub fn handle_input(app: &mut ManageApp) -> io::Result<InputResult> {
                     KeyCode::Char('s') | KeyCode::Char('S') => {
                         return Ok(InputResult::ToggleSkipPreflight);
                     }
+                    // Toggle needs-checkpoint ordering
+                    KeyCode::Char('c') | KeyCode::Char('C') => {
+                        return Ok(InputResult::ToggleCheckpointFirst);
+                    }
+                    // Cycle the commitment to wait for
+                    KeyCode::Char('f') | KeyCode::Char('F') => {
+                        return Ok(InputResult::CycleCommitment);
+                    }
                     _ => {}
                 }
             }
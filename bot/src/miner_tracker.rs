@@ -2,15 +2,28 @@
 //!
 //! Provides periodic updates of miner deployed[25] arrays for board display.
 //! Uses getMultipleAccounts RPC call to efficiently poll all miners at once.
+//!
+//! Also tracks each managed_miner_auth PDA balance. Balances are pushed by a
+//! websocket accountSubscribe per auth, up to `max_balance_subscriptions`;
+//! any auths past that limit are polled with the miners instead.
+//!
+//! A bot with an auth group (`auth_ids`) is tracked as one aggregate miner:
+//! its miners are combined with `aggregate_miners` and its auth balances
+//! summed, so group balances are always polled.
 
 use std::sync::Arc;
 use std::time::Duration;
 
 use evore::ore_api::miner_pda;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::pubsub_client::PubsubClient;
+use solana_client::rpc_config::RpcAccountInfoConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::Pubkey;
 use tokio::sync::mpsc;
 use tokio::time::sleep;
 
+use crate::auth_group::aggregate_miners;
 use crate::client::{EvoreClient, RpsTracker};
 use crate::tui::TuiUpdate;
 
@@ -19,38 +32,53 @@ use crate::tui::TuiUpdate;
 pub struct MinerTrackConfig {
     /// Bot index in the TUI
     pub bot_index: usize,
-    /// The miner authority (managed_miner_auth PDA)
-    pub miner_authority: Pubkey,
+    /// The miner authorities (managed_miner_auth PDAs), one per auth_id of the bot
+    pub miner_authorities: Vec<Pubkey>,
 }
 
+/// Default auth balance subscriptions before falling back to polling
+/// (each one is its own websocket connection)
+pub const DEFAULT_MAX_BALANCE_SUBSCRIPTIONS: usize = 20;
+
 /// Miner tracker that polls miner accounts for all bots
 pub struct MinerTracker {
     rpc_url: String,
+    ws_url: String,
     rps_tracker: Arc<RpsTracker>,
     miners: Vec<MinerTrackConfig>,
     tui_tx: mpsc::UnboundedSender<TuiUpdate>,
     poll_interval: Duration,
+    max_balance_subscriptions: usize,
 }
 
 impl MinerTracker {
     /// Create a new miner tracker
     pub fn new(
         rpc_url: &str,
+        ws_url: &str,
         rps_tracker: Arc<RpsTracker>,
         tui_tx: mpsc::UnboundedSender<TuiUpdate>,
     ) -> Self {
         Self {
             rpc_url: rpc_url.to_string(),
+            ws_url: ws_url.to_string(),
             rps_tracker,
             miners: Vec::new(),
             tui_tx,
             poll_interval: Duration::from_millis(1000),
+            max_balance_subscriptions: DEFAULT_MAX_BALANCE_SUBSCRIPTIONS,
         }
     }
 
-    /// Add a miner to track (pass the authority/managed_miner_auth PDA)
-    pub fn add_miner(&mut self, bot_index: usize, miner_authority: Pubkey) {
-        self.miners.push(MinerTrackConfig { bot_index, miner_authority });
+    /// Limit websocket balance subscriptions (0 = poll every auth balance)
+    pub fn with_max_balance_subscriptions(mut self, max: usize) -> Self {
+        self.max_balance_subscriptions = max;
+        self
+    }
+
+    /// Add a bot's miners to track (pass the authority/managed_miner_auth PDAs)
+    pub fn add_miner(&mut self, bot_index: usize, miner_authorities: Vec<Pubkey>) {
+        self.miners.push(MinerTrackConfig { bot_index, miner_authorities });
     }
 
     /// Start the polling loop (spawns a tokio task)
@@ -59,6 +87,11 @@ impl MinerTracker {
             return;
         }
 
+        let (subscribed, polled) = split_balance_subscriptions(&self.miners, self.max_balance_subscriptions);
+        for miner in subscribed {
+            spawn_balance_subscription(self.ws_url.clone(), miner, self.tui_tx.clone());
+        }
+
         let rpc_url = self.rpc_url.clone();
         let rps_tracker = Arc::clone(&self.rps_tracker);
         let miners = self.miners.clone();
@@ -66,29 +99,51 @@ impl MinerTracker {
         let poll_interval = self.poll_interval;
 
         tokio::spawn(async move {
-            Self::poll_loop(rpc_url, rps_tracker, miners, tui_tx, poll_interval).await;
+            Self::poll_loop(rpc_url, rps_tracker, miners, polled, tui_tx, poll_interval).await;
         });
     }
 
     /// Main polling loop
+    ///
+    /// Every auth balance is fetched once up front (subscriptions only push
+    /// changes); after that only `polled_balances` are fetched each interval.
     async fn poll_loop(
         rpc_url: String,
         rps_tracker: Arc<RpsTracker>,
         miners: Vec<MinerTrackConfig>,
+        polled_balances: Vec<MinerTrackConfig>,
         tui_tx: mpsc::UnboundedSender<TuiUpdate>,
         poll_interval: Duration,
     ) {
         let client = EvoreClient::new_with_tracker(&rpc_url, rps_tracker);
-        let authorities: Vec<Pubkey> = miners.iter().map(|m| m.miner_authority).collect();
+        let authorities: Vec<Pubkey> = miners.iter().flat_map(|m| m.miner_authorities.iter().copied()).collect();
+        let mut balances_to_poll = &miners;
 
         loop {
+            let balance_authorities: Vec<Pubkey> = balances_to_poll.iter()
+                .flat_map(|m| m.miner_authorities.iter().copied())
+                .collect();
+            if !balance_authorities.is_empty() {
+                if let Ok(accounts) = client.get_multiple_accounts(&balance_authorities) {
+                    let mut balances = accounts.into_iter().map(|a| a.map(|a| a.lamports).unwrap_or(0));
+                    for config in balances_to_poll {
+                        let _ = tui_tx.send(TuiUpdate::BotAuthBalanceUpdate {
+                            bot_index: config.bot_index,
+                            balance: balances.by_ref().take(config.miner_authorities.len()).sum(),
+                        });
+                    }
+                    balances_to_poll = &polled_balances;
+                }
+            }
+
+
             // Poll all miner accounts at once
             match client.get_miners(&authorities) {
                 Ok(miner_opts) => {
-                    for (i, miner_opt) in miner_opts.iter().enumerate() {
-                        if let Some(miner) = miner_opt {
-                            let config = &miners[i];
-                            
+                    let mut miner_opts = miner_opts.into_iter();
+                    for config in &miners {
+                        let group: Vec<_> = miner_opts.by_ref().take(config.miner_authorities.len()).collect();
+                        if let Some(miner) = aggregate_miners(&group) {
                             // Send miner data update
                             let _ = tui_tx.send(TuiUpdate::MinerDataUpdate {
                                 bot_index: config.bot_index,
@@ -108,6 +163,65 @@ impl MinerTracker {
     }
 }
 
+/// Split miners into those whose auth balance gets a websocket subscription
+/// and those that fall back to polling (auth groups always poll)
+fn split_balance_subscriptions(
+    miners: &[MinerTrackConfig],
+    max_subscriptions: usize,
+) -> (Vec<MinerTrackConfig>, Vec<MinerTrackConfig>) {
+    let (single, groups): (Vec<_>, Vec<_>) = miners.iter()
+        .cloned()
+        .partition(|m| m.miner_authorities.len() == 1);
+    let split = max_subscriptions.min(single.len());
+    let mut polled = single[split..].to_vec();
+    polled.extend(groups);
+    (single[..split].to_vec(), polled)
+}
+
+/// Push auth balance changes for one miner (runs in background thread)
+/// Quietly reconnects on error with exponential backoff (max 30s)
+fn spawn_balance_subscription(
+    ws_url: String,
+    miner: MinerTrackConfig,
+    tui_tx: mpsc::UnboundedSender<TuiUpdate>,
+) {
+    std::thread::spawn(move || {
+        let mut retry_delay_secs = 1u64;
+        const MAX_RETRY_DELAY: u64 = 30;
+
+        loop {
+            let config = RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(CommitmentConfig::confirmed()),
+                data_slice: None,
+                min_context_slot: None,
+            };
+
+            match PubsubClient::account_subscribe(&ws_url, &miner.miner_authorities[0], Some(config)) {
+                Ok((_subscription, receiver)) => {
+                    // Reset backoff on successful connection
+                    retry_delay_secs = 1;
+
+                    for response in receiver {
+                        let update = TuiUpdate::BotAuthBalanceUpdate {
+                            bot_index: miner.bot_index,
+                            balance: response.value.lamports,
+                        };
+                        if tui_tx.send(update).is_err() {
+                            // Dashboard is gone
+                            return;
+                        }
+                    }
+                }
+                Err(_) => {
+                    std::thread::sleep(std::time::Duration::from_secs(retry_delay_secs));
+                    retry_delay_secs = (retry_delay_secs * 2).min(MAX_RETRY_DELAY);
+                }
+            }
+        }
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,8 +230,28 @@ mod tests {
     fn test_miner_track_config() {
         let config = MinerTrackConfig {
             bot_index: 0,
-            miner_authority: Pubkey::new_unique(),
+            miner_authorities: vec![Pubkey::new_unique()],
         };
         assert_eq!(config.bot_index, 0);
     }
+
+    #[test]
+    fn test_split_balance_subscriptions() {
+        let mut miners: Vec<MinerTrackConfig> = (0..3)
+            .map(|bot_index| MinerTrackConfig { bot_index, miner_authorities: vec![Pubkey::new_unique()] })
+            .collect();
+
+        let (subscribed, polled) = split_balance_subscriptions(&miners, 2);
+        assert_eq!(subscribed.iter().map(|m| m.bot_index).collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(polled.iter().map(|m| m.bot_index).collect::<Vec<_>>(), vec![2]);
+
+        let (subscribed, polled) = split_balance_subscriptions(&miners, 10);
+        assert_eq!((subscribed.len(), polled.len()), (3, 0));
+
+        // Auth groups are polled (their balance is a sum)
+        miners.insert(0, MinerTrackConfig { bot_index: 3, miner_authorities: vec![Pubkey::new_unique(); 2] });
+        let (subscribed, polled) = split_balance_subscriptions(&miners, 10);
+        assert_eq!(subscribed.iter().map(|m| m.bot_index).collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(polled.iter().map(|m| m.bot_index).collect::<Vec<_>>(), vec![3]);
+    }
 }
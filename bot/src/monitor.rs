@@ -0,0 +1,468 @@
+//! Remote Monitor - pushes dashboard state to WebSocket clients
+//!
+//! Enabled with `dashboard --config <file> --ws-listen <addr>`. The local TUI
+//! keeps running; every `TuiUpdate` it applies is also sent to connected
+//! clients as JSON, along with a full snapshot on connect and once a second:
+//! - `{"kind":"snapshot","slot":..,"board":..,"bots":[..],..}`
+//! - `{"kind":"update","type":"bot_status","bot_index":0,"status":"deploying"}`
+//!
+//! Each client is served by its own task off a broadcast channel, so a slow
+//! client drops messages instead of holding up the dashboard.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use evore::ore_api::{Board, Miner, Round};
+use futures::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, watch};
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::confirmation_tracker::SignatureState;
+use crate::tui::{App, BotState, TuiUpdate};
+
+/// Updates buffered per client before it starts skipping
+const EVENT_BUFFER: usize = 1024;
+
+/// WebSocket server fanning dashboard state out to any number of clients
+pub struct MonitorServer {
+    events: broadcast::Sender<String>,
+    snapshot: watch::Sender<String>,
+}
+
+impl MonitorServer {
+    /// Bind `addr` and start accepting clients in the background
+    pub async fn bind(addr: SocketAddr) -> std::io::Result<Arc<Self>> {
+        let listener = TcpListener::bind(addr).await?;
+        let (events, _) = broadcast::channel(EVENT_BUFFER);
+        let (snapshot, _) = watch::channel(String::new());
+        let server = Arc::new(Self { events, snapshot });
+
+        let acceptor = Arc::clone(&server);
+        tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        let server = Arc::clone(&acceptor);
+                        tokio::spawn(async move { server.serve(stream).await });
+                    }
+                    Err(_) => tokio::time::sleep(std::time::Duration::from_millis(100)).await,
+                }
+            }
+        });
+
+        Ok(server)
+    }
+
+    /// Forward an update to connected clients (no-op when nobody is listening)
+    pub fn publish_update(&self, update: &TuiUpdate) {
+        if self.events.receiver_count() == 0 {
+            return;
+        }
+        let mut message = update_json(update);
+        message["kind"] = json!("update");
+        let _ = self.events.send(message.to_string());
+    }
+
+    /// Replace the snapshot sent to new clients and push it to existing ones
+    pub fn publish_snapshot(&self, app: &App) {
+        self.snapshot.send_replace(snapshot_json(app).to_string());
+    }
+
+    async fn serve(&self, stream: TcpStream) {
+        let Ok(ws) = tokio_tungstenite::accept_async(stream).await else {
+            return;
+        };
+        let (mut sink, mut source) = ws.split();
+        let mut events = self.events.subscribe();
+        let mut snapshot = self.snapshot.subscribe();
+
+        let initial = snapshot.borrow_and_update().clone();
+        if !initial.is_empty() && sink.send(Message::Text(initial)).await.is_err() {
+            return;
+        }
+
+        loop {
+            let message = tokio::select! {
+                event = events.recv() => match event {
+                    Ok(message) => message,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+                changed = snapshot.changed() => {
+                    if changed.is_err() {
+                        break;
+                    }
+                    snapshot.borrow_and_update().clone()
+                },
+                incoming = source.next() => match incoming {
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    // Clients are read-only; pings are answered on the next write
+                    Some(Ok(_)) => continue,
+                },
+            };
+
+            if sink.send(Message::Text(message)).await.is_err() {
+                break;
+            }
+        }
+    }
+}
+
+fn board_json(board: &Board) -> Value {
+    json!({
+        "round_id": board.round_id,
+        "start_slot": board.start_slot,
+        "end_slot": board.end_slot,
+    })
+}
+
+fn round_json(round: &Round) -> Value {
+    json!({
+        "id": round.id,
+        "deployed": round.deployed,
+        "count": round.count,
+        "motherlode": round.motherlode,
+        "total_deployed": round.total_deployed,
+        "total_miners": round.total_miners,
+    })
+}
+
+fn miner_json(miner: &Miner) -> Value {
+    json!({
+        "round_id": miner.round_id,
+        "checkpoint_id": miner.checkpoint_id,
+        "deployed": miner.deployed,
+        "rewards_sol": miner.rewards_sol,
+        "rewards_ore": miner.rewards_ore,
+        "refined_ore": miner.refined_ore,
+    })
+}
+
+/// Lowercase name of a unit-like enum value (e.g. `BotStatus::LowBalance` -> "lowbalance")
+fn name_of(value: impl std::fmt::Debug) -> String {
+    format!("{:?}", value).to_lowercase()
+}
+
+/// JSON form of one update, tagged with its variant in `type`
+fn update_json(update: &TuiUpdate) -> Value {
+    match update {
+        TuiUpdate::SlotUpdate { slot, blockhash } => json!({
+            "type": "slot",
+            "slot": slot,
+            "blockhash": blockhash.to_string(),
+        }),
+        TuiUpdate::BoardUpdate(board) => json!({ "type": "board", "board": board_json(board) }),
+        TuiUpdate::RoundUpdate(round) => json!({ "type": "round", "round": round_json(round) }),
+        TuiUpdate::BotStatusUpdate { bot_index, status } => json!({
+            "type": "bot_status",
+            "bot_index": bot_index,
+            "status": name_of(status),
+        }),
+        TuiUpdate::BotMinerUpdate { bot_index, miner } => json!({
+            "type": "bot_miner",
+            "bot_index": bot_index,
+            "miner": miner_json(miner),
+        }),
+        TuiUpdate::MinerDataUpdate { bot_index, deployed, round_id } => json!({
+            "type": "miner_data",
+            "bot_index": bot_index,
+            "deployed": deployed,
+            "round_id": round_id,
+        }),
+        TuiUpdate::TreasuryUpdate(treasury) => json!({
+            "type": "treasury",
+            "balance": treasury.balance,
+            "motherlode": treasury.motherlode,
+            "total_staked": treasury.total_staked,
+            "total_unclaimed": treasury.total_unclaimed,
+            "total_refined": treasury.total_refined,
+        }),
+        TuiUpdate::SolPrice(price) => json!({ "type": "sol_price", "price": price }),
+        TuiUpdate::BotDeployedUpdate { bot_index, amount, round_id } => json!({
+            "type": "bot_deployed",
+            "bot_index": bot_index,
+            "amount": amount,
+            "round_id": round_id,
+        }),
+        TuiUpdate::BotStatsUpdate {
+            bot_index,
+            rounds_participated,
+            rounds_won,
+            rounds_skipped,
+            rounds_missed,
+            current_claimable_sol,
+            current_ore,
+        } => json!({
+            "type": "bot_stats",
+            "bot_index": bot_index,
+            "rounds_participated": rounds_participated,
+            "rounds_won": rounds_won,
+            "rounds_skipped": rounds_skipped,
+            "rounds_missed": rounds_missed,
+            "current_claimable_sol": current_claimable_sol,
+            "current_ore": current_ore,
+        }),
+        TuiUpdate::BotSignerBalanceUpdate { bot_index, balance } => json!({
+            "type": "bot_signer_balance",
+            "bot_index": bot_index,
+            "balance": balance,
+        }),
+        TuiUpdate::BotAuthBalanceUpdate { bot_index, balance } => json!({
+            "type": "bot_auth_balance",
+            "bot_index": bot_index,
+            "balance": balance,
+        }),
+        TuiUpdate::BotClaimedSol { bot_index, amount } => json!({
+            "type": "bot_claimed_sol",
+            "bot_index": bot_index,
+            "amount": amount,
+        }),
+        TuiUpdate::BotClaimedOre { bot_index, amount } => json!({
+            "type": "bot_claimed_ore",
+            "bot_index": bot_index,
+            "amount": amount,
+        }),
+        TuiUpdate::TxEvent { bot_name, action, signature, error } => json!({
+            "type": "tx",
+            "bot_name": bot_name,
+            "tx_type": null,
+            "status": action.to_status().as_str(),
+            "signature": signature.to_string(),
+            "error": error,
+        }),
+        TuiUpdate::TxEventTyped { bot_name, tx_type, status, signature, error, slot, round_id, amount, attempt } => json!({
+            "type": "tx",
+            "bot_name": bot_name,
+            "tx_type": tx_type.as_str(),
+            "status": status.as_str(),
+            "signature": signature.to_string(),
+            "error": error,
+            "slot": slot,
+            "round_id": round_id,
+            "amount": amount,
+            "attempt": attempt,
+        }),
+        TuiUpdate::SignatureStatus { bot_name, signature, round_id, state } => json!({
+            "type": "signature_status",
+            "bot_name": bot_name,
+            "signature": signature.to_string(),
+            "round_id": round_id,
+            "status": state.as_str(),
+            "slot": match state { SignatureState::Confirmed { slot } => Some(*slot), _ => None },
+            "error": match state { SignatureState::Failed { error } => Some(error), _ => None },
+        }),
+        TuiUpdate::Error(message) => json!({ "type": "error", "message": message }),
+        TuiUpdate::NetworkStatsUpdate {
+            slot_ws,
+            board_ws,
+            round_ws,
+            rpc,
+            sender_east_latency_ms,
+            sender_west_latency_ms,
+            rpc_rps,
+            sender_rps,
+        } => json!({
+            "type": "network_stats",
+            "slot_ws": slot_ws.map(name_of),
+            "board_ws": board_ws.map(name_of),
+            "round_ws": round_ws.map(name_of),
+            "rpc": rpc.map(name_of),
+            "sender_east_latency_ms": sender_east_latency_ms,
+            "sender_west_latency_ms": sender_west_latency_ms,
+            "rpc_rps": rpc_rps,
+            "sender_rps": sender_rps,
+        }),
+        TuiUpdate::TxCounterUpdate { sent, confirmed, failed } => json!({
+            "type": "tx_counter",
+            "sent": sent,
+            "confirmed": confirmed,
+            "failed": failed,
+        }),
+        TuiUpdate::BotPauseUpdate { bot_index, is_paused } => json!({
+            "type": "bot_pause",
+            "bot_index": bot_index,
+            "is_paused": is_paused,
+        }),
+        TuiUpdate::PendingDeploy { bot_index, round_id, bets, total, preview_ms } => json!({
+            "type": "pending_deploy",
+            "bot_index": bot_index,
+            "round_id": round_id,
+            "bets": bets,
+            "total": total,
+            "preview_ms": preview_ms,
+        }),
+        TuiUpdate::PendingDeployCleared { bot_index } => json!({
+            "type": "pending_deploy_cleared",
+            "bot_index": bot_index,
+        }),
+        TuiUpdate::BotSignerBalanceLow { bot_index, is_low, min_signer_balance } => json!({
+            "type": "bot_signer_balance_low",
+            "bot_index": bot_index,
+            "is_low": is_low,
+            "min_signer_balance": min_signer_balance,
+        }),
+        TuiUpdate::BotStopLoss { bot_index, losses } => json!({
+            "type": "bot_stop_loss",
+            "bot_index": bot_index,
+            "losses": losses,
+        }),
+        TuiUpdate::BotFundingCheck { bot_index, round_id, available, required } => json!({
+            "type": "bot_funding_check",
+            "bot_index": bot_index,
+            "round_id": round_id,
+            "available": available,
+            "required": required,
+        }),
+        TuiUpdate::BotFleetScaled { bot_index, round_id, scale_bps, total, cap } => json!({
+            "type": "bot_fleet_scaled",
+            "bot_index": bot_index,
+            "round_id": round_id,
+            "scale_bps": scale_bps,
+            "total": total,
+            "cap": cap,
+        }),
+        TuiUpdate::BotDeployCapExceeded { bot_index, total, absolute_max_deploy } => json!({
+            "type": "bot_deploy_cap_exceeded",
+            "bot_index": bot_index,
+            "total": total,
+            "absolute_max_deploy": absolute_max_deploy,
+        }),
+        TuiUpdate::BotPriorityFee { bot_index, priority_fee } => json!({
+            "type": "bot_priority_fee",
+            "bot_index": bot_index,
+            "priority_fee": priority_fee,
+        }),
+        TuiUpdate::BotDeployLatency { bot_index, presigned, send_us, sign_us, resigns } => json!({
+            "type": "bot_deploy_latency",
+            "bot_index": bot_index,
+            "presigned": presigned,
+            "send_us": send_us,
+            "sign_us": sign_us,
+            "resigns": resigns,
+        }),
+        TuiUpdate::BotSlotsLeftLearned { bot_index, slots_left } => json!({
+            "type": "bot_slots_left_learned",
+            "bot_index": bot_index,
+            "slots_left": slots_left,
+        }),
+        TuiUpdate::BotStrategyUpdate { bot_index, strategy } => json!({
+            "type": "bot_strategy",
+            "bot_index": bot_index,
+            "strategy": format!("{:?}", strategy),
+        }),
+    }
+}
+
+fn bot_json(index: usize, bot: &BotState) -> Value {
+    let stats = &bot.session_stats;
+    json!({
+        "index": index,
+        "name": bot.name,
+        "auth_id": bot.auth_id,
+        "auth_ids": bot.auth_ids,
+        "strategy": bot.strategy,
+        "status": name_of(bot.status),
+        "is_paused": bot.is_paused,
+        "bankroll": bot.bankroll,
+        "slots_left": bot.slots_left_threshold,
+        "slots_left_auto": bot.slots_left_auto,
+        "priority_fee": bot.priority_fee,
+        "jito_tip": bot.jito_tip,
+        "signer": bot.signer.to_string(),
+        "manager": bot.manager.to_string(),
+        "managed_miner_auth": bot.managed_miner_auth.to_string(),
+        "signer_balance": bot.signer_balance,
+        "auth_balance": bot.auth_balance,
+        "deployed_this_round": bot.deployed_this_round,
+        "deployed_per_square": bot.deployed_per_square,
+        "miner_round_id": bot.miner_round_id,
+        "miner": bot.miner.as_ref().map(miner_json),
+        "session": {
+            "running_secs": stats.running_time().as_secs(),
+            "rounds_participated": stats.effective_rounds_participated(),
+            "rounds_won": stats.effective_rounds_won(),
+            "rounds_skipped": stats.effective_rounds_skipped(),
+            "rounds_missed": stats.effective_rounds_missed(),
+            "current_claimable_sol": stats.current_claimable_sol,
+            "current_ore": stats.current_ore,
+            "sol_pnl": stats.sol_pnl(),
+            "ore_pnl": stats.ore_pnl(),
+        },
+        "pending_deploy": bot.pending_deploy.as_ref().map(|p| json!({
+            "round_id": p.round_id,
+            "bets": p.bets,
+            "total": p.total,
+        })),
+        "low_balance_threshold": bot.low_balance_threshold,
+        "stop_loss_streak": bot.stop_loss_streak,
+        "underfunded": bot.underfunded.map(|(available, required)| json!({
+            "available": available,
+            "required": required,
+        })),
+    })
+}
+
+/// Full dashboard state for new and existing clients
+fn snapshot_json(app: &App) -> Value {
+    let stats = &app.network_stats;
+    json!({
+        "kind": "snapshot",
+        "slot": app.current_slot,
+        "board": app.board.as_ref().map(board_json),
+        "round": app.round.as_ref().map(round_json),
+        "treasury": app.treasury.as_ref().map(|t| json!({
+            "balance": t.balance,
+            "motherlode": t.motherlode,
+            "total_staked": t.total_staked,
+            "total_unclaimed": t.total_unclaimed,
+            "total_refined": t.total_refined,
+        })),
+        "network": {
+            "slot_ws": name_of(stats.slot_ws),
+            "board_ws": name_of(stats.board_ws),
+            "round_ws": name_of(stats.round_ws),
+            "rpc": name_of(stats.rpc),
+            "rpc_rps": stats.rpc_rps,
+            "sender_rps": stats.sender_rps,
+        },
+        "bots": app.bots.iter().enumerate().map(|(i, bot)| bot_json(i, bot)).collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::BotStatus;
+
+    #[test]
+    fn test_update_json_is_tagged() {
+        let value = update_json(&TuiUpdate::BotStatusUpdate {
+            bot_index: 2,
+            status: BotStatus::LowBalance,
+        });
+        assert_eq!(value["type"], "bot_status");
+        assert_eq!(value["bot_index"], 2);
+        assert_eq!(value["status"], "lowbalance");
+
+        let value = update_json(&TuiUpdate::PendingDeploy {
+            bot_index: 0,
+            round_id: 7,
+            bets: [1; 25],
+            total: 25,
+            preview_ms: 500,
+        });
+        assert_eq!(value["type"], "pending_deploy");
+        assert_eq!(value["bets"].as_array().unwrap().len(), 25);
+    }
+
+    #[test]
+    fn test_snapshot_without_bots() {
+        let app = App::new("http://localhost:8899");
+        let value = snapshot_json(&app);
+        assert_eq!(value["kind"], "snapshot");
+        assert!(value["board"].is_null());
+        assert!(value["bots"].as_array().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,155 @@
+//! Prepare - Get a config's managed miners ready before a session
+//!
+//! `evore-bot prepare --config` creates any missing ORE token accounts (each
+//! signer's and each managed_miner_auth's; `claim_ore` would otherwise create
+//! them on the first claim) and tops each managed_miner_auth up to `--fund`
+//! from its bot's signer. The work is planned here from account state read
+//! up front, so what's already in place is reported as ready and left alone.
+
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+use spl_associated_token_account::get_associated_token_address;
+
+use evore::ore_api::MINT_ADDRESS;
+
+/// A managed miner as read before preparing
+#[derive(Debug, Clone)]
+pub struct MinerState {
+    pub bot: String,
+    pub auth_id: u64,
+    pub managed_miner_auth: Pubkey,
+    /// managed_miner_auth lamports (0 when the account doesn't exist)
+    pub balance: u64,
+    /// Whether managed_miner_auth's ORE token account exists
+    pub ata_exists: bool,
+}
+
+/// What prepare does for one account; `instructions` is empty when it's ready
+#[derive(Debug, Clone)]
+pub struct PrepareStep {
+    pub bot: String,
+    /// None for the signer's own token account
+    pub auth_id: Option<u64>,
+    pub address: Pubkey,
+    pub create_ata: bool,
+    /// Lamports transferred to top the account up to the funding target
+    pub fund: u64,
+    pub instructions: Vec<Instruction>,
+}
+
+impl PrepareStep {
+    pub fn is_ready(&self) -> bool {
+        self.instructions.is_empty()
+    }
+}
+
+/// ORE token account of `owner`
+pub fn ore_ata(owner: &Pubkey) -> Pubkey {
+    get_associated_token_address(owner, &MINT_ADDRESS)
+}
+
+/// Steps for one signer: its own ORE token account, then each of its miners
+///
+/// Token accounts are created idempotently with the signer paying, and each
+/// managed_miner_auth below `fund` gets the difference from the signer.
+pub fn plan(signer: &Pubkey, signer_ata_exists: bool, miners: &[MinerState], fund: u64) -> Vec<PrepareStep> {
+    let create_ata_ix = |owner: &Pubkey| {
+        spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+            signer,
+            owner,
+            &MINT_ADDRESS,
+            &spl_token::id(),
+        )
+    };
+
+    let mut steps = vec![PrepareStep {
+        bot: "signer".to_string(),
+        auth_id: None,
+        address: *signer,
+        create_ata: !signer_ata_exists,
+        fund: 0,
+        instructions: if signer_ata_exists { vec![] } else { vec![create_ata_ix(signer)] },
+    }];
+
+    for miner in miners {
+        let top_up = fund.saturating_sub(miner.balance);
+        let mut instructions = Vec::new();
+        if !miner.ata_exists {
+            instructions.push(create_ata_ix(&miner.managed_miner_auth));
+        }
+        if top_up > 0 {
+            instructions.push(solana_sdk::system_instruction::transfer(signer, &miner.managed_miner_auth, top_up));
+        }
+        steps.push(PrepareStep {
+            bot: miner.bot.clone(),
+            auth_id: Some(miner.auth_id),
+            address: miner.managed_miner_auth,
+            create_ata: !miner.ata_exists,
+            fund: top_up,
+            instructions,
+        });
+    }
+    steps
+}
+
+/// Indices of the steps that need work, grouped so each group fits in one
+/// transaction of at most `max_instructions` (a step is never split)
+pub fn batches(steps: &[PrepareStep], max_instructions: usize) -> Vec<Vec<usize>> {
+    let max_instructions = max_instructions.max(2);
+    let mut batches: Vec<Vec<usize>> = Vec::new();
+    let mut count = 0;
+    for (i, step) in steps.iter().enumerate().filter(|(_, s)| !s.is_ready()) {
+        if batches.is_empty() || count + step.instructions.len() > max_instructions {
+            batches.push(Vec::new());
+            count = 0;
+        }
+        batches.last_mut().unwrap().push(i);
+        count += step.instructions.len();
+    }
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn miner(auth_id: u64, balance: u64, ata_exists: bool) -> MinerState {
+        MinerState {
+            bot: "a".to_string(),
+            auth_id,
+            managed_miner_auth: Pubkey::new_unique(),
+            balance,
+            ata_exists,
+        }
+    }
+
+    #[test]
+    fn test_plan_and_batches() {
+        let signer = Pubkey::new_unique();
+        let miners = vec![
+            miner(1, 0, false),           // needs both
+            miner(2, 10_000_000, true),   // ready
+            miner(3, 4_000_000, true),    // partial top-up
+        ];
+        let steps = plan(&signer, false, &miners, 10_000_000);
+
+        assert_eq!(steps.len(), 4);
+        assert_eq!((steps[0].auth_id, steps[0].create_ata, steps[0].instructions.len()), (None, true, 1));
+        assert_eq!((steps[1].create_ata, steps[1].fund, steps[1].instructions.len()), (true, 10_000_000, 2));
+        assert!(steps[2].is_ready());
+        assert_eq!((steps[3].create_ata, steps[3].fund, steps[3].instructions.len()), (false, 6_000_000, 1));
+
+        // Token accounts are created for the right owner, paid by the signer
+        let ata_ix = &steps[1].instructions[0];
+        assert_eq!(ata_ix.accounts[0].pubkey, signer);
+        assert_eq!(ata_ix.accounts[1].pubkey, ore_ata(&miners[0].managed_miner_auth));
+
+        // Ready steps are skipped and a step's instructions stay together
+        assert_eq!(batches(&steps, 3), vec![vec![0, 1], vec![3]]);
+        assert_eq!(batches(&steps, 10), vec![vec![0, 1, 3]]);
+
+        // Nothing to do
+        let steps = plan(&signer, true, &[miner(1, 5, true)], 0);
+        assert!(steps.iter().all(PrepareStep::is_ready));
+        assert!(batches(&steps, 4).is_empty());
+    }
+}
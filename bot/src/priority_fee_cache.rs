@@ -0,0 +1,47 @@
+//! PriorityFeeCache - Short-lived cache of recent prioritization fees
+//!
+//! Features:
+//! - Fetched on demand by bots with a dynamic `priority_fee_source`
+//! - Reused for `PRIORITY_FEE_TTL` so deploys don't each cost an RPC call
+//! - Shared via Arc for all bots
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::client::EvoreClient;
+
+/// How long a fetch of recent prioritization fees is reused
+pub const PRIORITY_FEE_TTL: Duration = Duration::from_secs(2);
+
+/// Cache for recent prioritization fees on the board
+#[derive(Default)]
+pub struct PriorityFeeCache {
+    /// Fees from the last successful fetch and when it happened
+    last: Mutex<Option<(Instant, Vec<u64>)>>,
+}
+
+impl PriorityFeeCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Recent prioritization fees, fetched through `client` when the cached
+    /// ones are older than PRIORITY_FEE_TTL (empty if the fetch fails)
+    pub fn get_fees(&self, client: &EvoreClient) -> Vec<u64> {
+        let mut last = self.last.lock().unwrap();
+        if let Some((fetched_at, fees)) = last.as_ref() {
+            if fetched_at.elapsed() < PRIORITY_FEE_TTL {
+                return fees.clone();
+            }
+        }
+
+        match client.get_recent_priority_fees() {
+            Ok(fees) => {
+                *last = Some((Instant::now(), fees.clone()));
+                fees
+            }
+            // Keep the stale fees rather than none at all
+            Err(_) => last.as_ref().map(|(_, fees)| fees.clone()).unwrap_or_default(),
+        }
+    }
+}
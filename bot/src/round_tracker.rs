@@ -112,9 +112,10 @@ impl RoundTracker {
 
                 // Poll the round account
                 match client.get_round(round_id) {
-                    Ok(r) => {
+                    Ok(state) => {
+                        // Drop transient data while the account is resetting
                         let mut round_lock = round.write().unwrap();
-                        *round_lock = Some(r);
+                        *round_lock = state.active();
                         connected.store(true, Ordering::Relaxed);
                         consecutive_failures = 0;
                     }
@@ -0,0 +1,101 @@
+//! Custom RPC headers - Auth tokens and the like for private RPC providers
+//!
+//! Set with `RPC_HEADERS='{"x-token":"..."}'` (a JSON object) and/or repeated
+//! `--rpc-header key=value`; command line pairs win over the JSON map. Every
+//! HTTP RpcClient the bot builds goes through `rpc_client`, so the headers
+//! ride along on all JSON-RPC calls. Websocket subscriptions use Solana's
+//! PubsubClient, which only takes a URL - put ws auth in `WS_URL` instead.
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use solana_client::client_error::reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use solana_client::client_error::reqwest;
+use solana_client::rpc_client::{RpcClient, RpcClientConfig};
+use solana_rpc_client::http_sender::HttpSender;
+use solana_sdk::commitment_config::CommitmentConfig;
+
+static HEADERS: OnceLock<HeaderMap> = OnceLock::new();
+
+/// Parse the `RPC_HEADERS` JSON object and `key=value` pairs into a header map
+pub fn parse(json: Option<&str>, pairs: &[String]) -> Result<HeaderMap, String> {
+    let mut entries: Vec<(String, String)> = Vec::new();
+
+    if let Some(json) = json.filter(|j| !j.trim().is_empty()) {
+        let map: serde_json::Map<String, serde_json::Value> = serde_json::from_str(json)
+            .map_err(|e| format!("RPC_HEADERS must be a JSON object: {}", e))?;
+        for (key, value) in map {
+            let value = match value {
+                serde_json::Value::String(s) => s,
+                other => other.to_string(),
+            };
+            entries.push((key, value));
+        }
+    }
+
+    for pair in pairs {
+        let (key, value) = pair.split_once('=')
+            .ok_or_else(|| format!("Invalid --rpc-header '{}': expected key=value", pair))?;
+        entries.push((key.trim().to_string(), value.trim().to_string()));
+    }
+
+    let mut headers = HeaderMap::new();
+    for (key, value) in entries {
+        let name = HeaderName::from_bytes(key.as_bytes())
+            .map_err(|_| format!("Invalid RPC header name '{}'", key))?;
+        let value = HeaderValue::from_str(&value)
+            .map_err(|_| format!("Invalid value for RPC header '{}'", key))?;
+        headers.insert(name, value);
+    }
+    Ok(headers)
+}
+
+/// Use these headers for every RPC client built afterwards
+pub fn init(headers: HeaderMap) {
+    let _ = HEADERS.set(headers);
+}
+
+/// HTTP RpcClient carrying the configured headers
+pub fn rpc_client(url: &str, timeout: Duration, commitment: CommitmentConfig) -> RpcClient {
+    let headers = match HEADERS.get() {
+        Some(headers) if !headers.is_empty() => headers,
+        _ => return RpcClient::new_with_timeout_and_commitment(url.to_string(), timeout, commitment),
+    };
+
+    let mut default_headers = HttpSender::default_headers();
+    default_headers.extend(headers.clone());
+    let client = reqwest::Client::builder()
+        .default_headers(default_headers)
+        .timeout(timeout)
+        .pool_idle_timeout(timeout)
+        .build()
+        .expect("Failed to create RPC HTTP client");
+
+    RpcClient::new_sender(
+        HttpSender::new_with_client(url, client),
+        RpcClientConfig::with_commitment(commitment),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let headers = parse(
+            Some(r#"{"x-token":"abc","x-retries":3}"#),
+            &["Authorization=Bearer xyz".to_string(), "x-token = override".to_string()],
+        ).unwrap();
+        assert_eq!(headers.len(), 3);
+        assert_eq!(headers["x-token"], "override");
+        assert_eq!(headers["x-retries"], "3");
+        assert_eq!(headers["authorization"], "Bearer xyz");
+
+        assert!(parse(None, &[]).unwrap().is_empty());
+        assert!(parse(Some("  "), &[]).unwrap().is_empty());
+        assert!(parse(Some("[1]"), &[]).is_err());
+        assert!(parse(None, &["no-equals".to_string()]).is_err());
+        assert!(parse(None, &["bad header=1".to_string()]).is_err());
+    }
+}
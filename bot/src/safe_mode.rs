@@ -0,0 +1,73 @@
+//! Safe mode - Observe-only runs that never send a transaction
+//!
+//! Enabled with `--safe-mode` / `SAFE_MODE=1`. Every send path (RPC client,
+//! FastSender, manage TUI) calls `guard` right before the transaction leaves
+//! the process; in safe mode it refuses, and the error carries the fully
+//! built transaction's instructions so they show up wherever the error does.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use solana_sdk::{message::Message, transaction::Transaction};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turn safe mode on for the rest of the process
+pub fn enable() {
+    ENABLED.store(true, Ordering::SeqCst);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::SeqCst)
+}
+
+/// Err (with the instructions that would have been sent) when safe mode is on
+pub fn guard(transaction: &Transaction) -> Result<(), String> {
+    check(is_enabled(), transaction)
+}
+
+fn check(enabled: bool, transaction: &Transaction) -> Result<(), String> {
+    if !enabled {
+        return Ok(());
+    }
+    Err(format!(
+        "Safe mode: transaction not sent. Instructions: {}",
+        describe_instructions(&transaction.message).join("; ")
+    ))
+}
+
+/// One line per instruction: program, accounts and data (hex)
+pub fn describe_instructions(message: &Message) -> Vec<String> {
+    let key = |index: u8| {
+        message.account_keys.get(index as usize)
+            .map(|k| k.to_string())
+            .unwrap_or_else(|| format!("#{}", index))
+    };
+    message.instructions.iter().enumerate().map(|(i, ix)| {
+        let accounts: Vec<String> = ix.accounts.iter().map(|a| key(*a)).collect();
+        let data: String = ix.data.iter().map(|b| format!("{:02x}", b)).collect();
+        format!("[{}] {} accounts=[{}] data={}", i, key(ix.program_id_index), accounts.join(", "), data)
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    };
+
+    #[test]
+    fn test_check_describes_instructions() {
+        let program = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let ix = Instruction::new_with_bytes(program, &[1, 0xab], vec![AccountMeta::new(payer, true)]);
+        let tx = Transaction::new_unsigned(Message::new(&[ix], Some(&payer)));
+
+        assert!(check(false, &tx).is_ok());
+
+        let err = check(true, &tx).unwrap_err();
+        assert!(err.starts_with("Safe mode: transaction not sent"));
+        assert!(err.contains(&format!("[0] {} accounts=[{}] data=01ab", program, payer)));
+    }
+}
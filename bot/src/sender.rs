@@ -234,6 +234,8 @@ impl FastSender {
     pub fn send_transaction(&self, transaction: &Transaction) -> Result<Signature, SendError> {
         use base64::Engine;
         
+        crate::safe_mode::guard(transaction).map_err(SendError::SafeMode)?;
+        
         // Get signature from the signed transaction
         let signature = transaction.signatures.first()
             .ok_or_else(|| SendError::Serialization("Transaction has no signatures".to_string()))?;
@@ -568,15 +570,6 @@ pub fn get_random_tip_account() -> Pubkey {
     Pubkey::from_str(JITO_TIP_ACCOUNTS[index]).unwrap()
 }
 
-/// Create a Jito tip instruction (simple SOL transfer to tip account)
-pub fn create_tip_instruction(
-    from: &Pubkey,
-    tip_amount: u64,
-) -> solana_sdk::instruction::Instruction {
-    let tip_account = get_random_tip_account();
-    solana_sdk::system_instruction::transfer(from, &tip_account, tip_amount)
-}
-
 // =============================================================================
 // Error Types
 // =============================================================================
@@ -587,6 +580,8 @@ pub enum SendError {
     Network(String),
     Parse(String),
     RpcError(String),
+    /// Refused in safe mode (message lists the instructions)
+    SafeMode(String),
 }
 
 impl std::fmt::Display for SendError {
@@ -596,6 +591,7 @@ impl std::fmt::Display for SendError {
             SendError::Network(e) => write!(f, "Network error: {}", e),
             SendError::Parse(e) => write!(f, "Parse error: {}", e),
             SendError::RpcError(e) => write!(f, "RPC error: {}", e),
+            SendError::SafeMode(e) => write!(f, "{}", e),
         }
     }
 }
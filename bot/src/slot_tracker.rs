@@ -6,7 +6,13 @@
 use solana_client::pubsub_client::PubsubClient;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+/// Target slot time on mainnet
+pub const SLOT_DURATION_MS: u64 = 400;
+
+/// Time a deploy needs from send to inclusion, used by strict last-slot mode
+pub const LAST_SLOT_LANDING_MS: u64 = 200;
 
 /// Tracks current slot via websocket subscription
 pub struct SlotTracker {
@@ -116,3 +122,61 @@ pub fn http_to_ws_url(http_url: &str) -> String {
         url
     }
 }
+
+/// What strict last-slot mode should do with a deploy right now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LastSlotDecision {
+    /// More than `hard_last_slots` slots remain
+    Wait,
+    /// Inside the window with enough time left to land
+    Send,
+    /// Too little time left to be confident it lands in-round
+    Skip,
+}
+
+/// Decide whether a deploy sent now lands in the last `hard_last_slots` slots
+///
+/// The time left is estimated from the slots remaining and how long ago the
+/// current slot started; a stale slot feed eats into it, so a lagging
+/// websocket leads to a skip rather than a deploy that may land early or late.
+pub fn last_slot_decision(
+    current_slot: u64,
+    end_slot: u64,
+    since_slot_start: Duration,
+    hard_last_slots: u64,
+) -> LastSlotDecision {
+    let slots_remaining = end_slot.saturating_sub(current_slot);
+    if slots_remaining > hard_last_slots {
+        return LastSlotDecision::Wait;
+    }
+    let elapsed_ms = since_slot_start.as_millis().min(u64::MAX as u128) as u64;
+    let time_left_ms = slots_remaining.saturating_mul(SLOT_DURATION_MS).saturating_sub(elapsed_ms);
+    if time_left_ms >= LAST_SLOT_LANDING_MS {
+        LastSlotDecision::Send
+    } else {
+        LastSlotDecision::Skip
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_last_slot_decision() {
+        let ms = Duration::from_millis;
+
+        // Outside the window
+        assert_eq!(last_slot_decision(90, 100, ms(0), 2), LastSlotDecision::Wait);
+        assert_eq!(last_slot_decision(97, 100, ms(0), 2), LastSlotDecision::Wait);
+
+        // Inside it with time to land
+        assert_eq!(last_slot_decision(98, 100, ms(300), 2), LastSlotDecision::Send);
+        assert_eq!(last_slot_decision(99, 100, ms(100), 2), LastSlotDecision::Send);
+
+        // Too late in the last slot, round over, or a stale slot feed
+        assert_eq!(last_slot_decision(99, 100, ms(250), 2), LastSlotDecision::Skip);
+        assert_eq!(last_slot_decision(100, 100, ms(0), 2), LastSlotDecision::Skip);
+        assert_eq!(last_slot_decision(98, 100, ms(700), 2), LastSlotDecision::Skip);
+    }
+}
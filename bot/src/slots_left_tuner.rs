@@ -0,0 +1,90 @@
+//! Slots-left tuner - Learn a bot's deploy threshold from recent rounds
+//!
+//! With `auto_slots_left` on, the bot records how long each round it deploys
+//! in lasts (end_slot - start_slot) and how many slots its deploys take to
+//! land (first send to landing slot). The learned threshold sends early
+//! enough for the slower deploys of the window to land `auto_slots_left_target`
+//! slots before the last deployable slot, and never more than the shortest
+//! observed round allows. Until a deploy has landed, the configured
+//! `slots_left` is used.
+
+use std::collections::VecDeque;
+
+/// Rounds / deploys remembered
+pub const TUNER_WINDOW: usize = 8;
+
+#[derive(Debug, Default, Clone)]
+pub struct SlotsLeftTuner {
+    round_lengths: VecDeque<u64>,
+    latencies: VecDeque<u64>,
+}
+
+fn push_bounded(window: &mut VecDeque<u64>, value: u64) {
+    if window.len() == TUNER_WINDOW {
+        window.pop_front();
+    }
+    window.push_back(value);
+}
+
+impl SlotsLeftTuner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Length in slots of a round the bot deployed in
+    pub fn record_round(&mut self, start_slot: u64, end_slot: u64) {
+        if end_slot != u64::MAX && end_slot > start_slot {
+            push_bounded(&mut self.round_lengths, end_slot - start_slot);
+        }
+    }
+
+    /// Slots from a deploy's first send until it landed
+    pub fn record_latency(&mut self, sent_slot: u64, landed_slot: u64) {
+        push_bounded(&mut self.latencies, landed_slot.saturating_sub(sent_slot));
+    }
+
+    /// Threshold landing the 75th percentile deploy `target` slots before the
+    /// last deployable slot; `fallback` until a latency has been observed
+    pub fn learned(&self, target: u64, fallback: u64) -> u64 {
+        if self.latencies.is_empty() {
+            return fallback;
+        }
+        let mut sorted: Vec<u64> = self.latencies.iter().copied().collect();
+        sorted.sort_unstable();
+        let latency = sorted[(sorted.len() - 1) * 3 / 4];
+
+        // Sent at end - n, lands at end - n + latency, must be <= end - 1 - target
+        let wanted = latency.saturating_add(1).saturating_add(target);
+        let ceiling = self.round_lengths.iter().min().copied().unwrap_or(u64::MAX).max(1);
+        wanted.clamp(1, ceiling)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_learned_threshold() {
+        let mut tuner = SlotsLeftTuner::new();
+        assert_eq!(tuner.learned(0, 2), 2);
+
+        // Deploys land 1-3 slots after they're sent
+        for latency in [1, 1, 2, 3] {
+            tuner.record_latency(100, 100 + latency);
+        }
+        assert_eq!(tuner.learned(0, 2), 3);
+        assert_eq!(tuner.learned(1, 2), 4);
+
+        // Never more than the shortest round
+        tuner.record_round(1_000, 1_003);
+        tuner.record_round(2_000, u64::MAX);
+        assert_eq!(tuner.learned(5, 2), 3);
+
+        // Old samples fall out of the window
+        for _ in 0..TUNER_WINDOW {
+            tuner.record_latency(100, 100);
+        }
+        assert_eq!(tuner.learned(0, 2), 1);
+    }
+}
@@ -28,6 +28,11 @@ use cli_clipboard;
 
 use evore::ore_api::{Board, Miner, Round, INTERMISSION_SLOTS};
 
+use crate::auth_group::auth_ids_label;
+use crate::confirmation_tracker::{SignatureState, MAX_TRACKED};
+use crate::config::{BotConfig, DeployStrategy, StrategyParams};
+use crate::display_units::DisplayUnits;
+
 // =============================================================================
 // Bot Icon Pool
 // =============================================================================
@@ -76,6 +81,9 @@ pub enum TuiUpdate {
     /// Treasury data update (from periodic polling)
     TreasuryUpdate(crate::treasury_tracker::TreasuryData),
     
+    /// SOL/USD price for `usd` display units (from periodic polling)
+    SolPrice(f64),
+    
     /// Bot deployed this round (amount deployed)
     BotDeployedUpdate { bot_index: usize, amount: u64, round_id: u64 },
     
@@ -93,6 +101,9 @@ pub enum TuiUpdate {
     /// Bot signer (fee payer) balance updated
     BotSignerBalanceUpdate { bot_index: usize, balance: u64 },
     
+    /// Bot managed_miner_auth PDA balance updated (websocket push or polling)
+    BotAuthBalanceUpdate { bot_index: usize, balance: u64 },
+    
     /// Bot successfully claimed SOL (for P&L tracking)
     BotClaimedSol { bot_index: usize, amount: u64 },
     
@@ -148,6 +159,62 @@ pub enum TuiUpdate {
     
     /// Bot pause state changed
     BotPauseUpdate { bot_index: usize, is_paused: bool },
+    
+    /// Bot is previewing a deploy and will submit after preview_ms unless vetoed
+    PendingDeploy {
+        bot_index: usize,
+        round_id: u64,
+        bets: [u64; 25],
+        total: u64,
+        preview_ms: u64,
+    },
+    
+    /// Preview window closed (submitted or vetoed)
+    PendingDeployCleared { bot_index: usize },
+    
+    /// Signer balance crossed min_signer_balance (deploys held while low)
+    BotSignerBalanceLow { bot_index: usize, is_low: bool, min_signer_balance: u64 },
+    
+    /// Bot auto-paused after max_consecutive_losses losing rounds
+    BotStopLoss { bot_index: usize, losses: u64 },
+    
+    /// Round-start check of whether the bot can pay for a full deploy
+    BotFundingCheck { bot_index: usize, round_id: u64, available: u64, required: u64 },
+    
+    /// Round-start fleet cap: the bot's bets are scaled to scale_bps (10_000 = unscaled)
+    /// because the fleet's planned total is over fleet_max_per_round
+    BotFleetScaled { bot_index: usize, round_id: u64, scale_bps: u64, total: u64, cap: u64 },
+    
+    /// Bot paused: its deploy total is above absolute_max_deploy
+    BotDeployCapExceeded { bot_index: usize, total: u64, absolute_max_deploy: u64 },
+    
+    /// Priority fee a dynamic-fee bot picked for its deploy
+    BotPriorityFee { bot_index: usize, priority_fee: u64 },
+    
+    /// Deploy threshold an auto_slots_left bot is currently using
+    BotSlotsLeftLearned { bot_index: usize, slots_left: u64 },
+    
+    /// Bot switched to a reloaded strategy
+    BotStrategyUpdate { bot_index: usize, strategy: DeployStrategy },
+    
+    /// Confirmation status of a submitted signature changed (confirmation tracker)
+    SignatureStatus {
+        bot_name: String,
+        signature: Signature,
+        round_id: u64,
+        state: SignatureState,
+    },
+    
+    /// Time from the deploy trigger until the first wave was sent
+    BotDeployLatency {
+        bot_index: usize,
+        presigned: bool,
+        send_us: u64,
+        /// Time spent signing ahead of the trigger (presign only)
+        sign_us: Option<u64>,
+        /// Times the presigned set was re-signed for a newer blockhash
+        resigns: u32,
+    },
 }
 
 /// View mode for bottom section (toggled with Tab)
@@ -156,13 +223,16 @@ pub enum ViewMode {
     #[default]
     TxLog,
     Board,
+    /// Per-square EV behind the selected bot's bets
+    EvDetail,
 }
 
 impl ViewMode {
     pub fn toggle(&mut self) {
         *self = match self {
             ViewMode::TxLog => ViewMode::Board,
-            ViewMode::Board => ViewMode::TxLog,
+            ViewMode::Board => ViewMode::EvDetail,
+            ViewMode::EvDetail => ViewMode::TxLog,
         };
     }
     
@@ -170,6 +240,7 @@ impl ViewMode {
         match self {
             ViewMode::TxLog => "Tx Log",
             ViewMode::Board => "Board",
+            ViewMode::EvDetail => "EV Detail",
         }
     }
 }
@@ -325,6 +396,8 @@ pub enum BotStatus {
     Skipped,
     Missed,
     Checkpointing,
+    LowBalance,
+    OutsideHours,
 }
 
 impl BotStatus {
@@ -339,6 +412,8 @@ impl BotStatus {
             BotStatus::Skipped => "Skipped",
             BotStatus::Missed => "Missed",
             BotStatus::Checkpointing => "Checkpointing",
+            BotStatus::LowBalance => "Low Balance",
+            BotStatus::OutsideHours => "Outside Hours",
         }
     }
     
@@ -353,6 +428,8 @@ impl BotStatus {
             BotStatus::Skipped => Color::DarkGray,
             BotStatus::Missed => Color::Red,
             BotStatus::Checkpointing => Color::Magenta,
+            BotStatus::LowBalance => Color::Red,
+            BotStatus::OutsideHours => Color::DarkGray,
         }
     }
 }
@@ -536,6 +613,16 @@ impl BotSessionStats {
     }
 }
 
+/// Latest confirmation status of a submitted signature (signature panel)
+#[derive(Clone, Debug)]
+pub struct SignatureEntry {
+    pub bot_name: String,
+    pub signature: Signature,
+    pub round_id: u64,
+    pub state: SignatureState,
+    pub updated_at: Instant,
+}
+
 /// Transaction log entry with detailed info
 #[derive(Clone, Debug)]
 pub struct TxLogEntry {
@@ -581,6 +668,10 @@ pub enum TxStatus {
     Sent,
     Confirmed,
     Failed,
+    /// Redundant deploy that didn't land because another one did (expected)
+    Duplicate,
+    /// Deploy landed but placed less than intended
+    Partial,
 }
 
 impl TxStatus {
@@ -589,6 +680,8 @@ impl TxStatus {
             TxStatus::Sent => "SENT",
             TxStatus::Confirmed => "OK",
             TxStatus::Failed => "FAIL",
+            TxStatus::Duplicate => "DUP",
+            TxStatus::Partial => "PART",
         }
     }
     
@@ -597,6 +690,8 @@ impl TxStatus {
             TxStatus::Sent => Color::Cyan,
             TxStatus::Confirmed => Color::Green,
             TxStatus::Failed => Color::Red,
+            TxStatus::Duplicate => Color::DarkGray,
+            TxStatus::Partial => Color::Yellow,
         }
     }
 }
@@ -625,9 +720,13 @@ pub struct BotState {
     pub name: String,
     pub icon: &'static str,
     pub auth_id: u64,
+    /// Auth group the bot deploys through (just auth_id unless auth_ids is set)
+    pub auth_ids: Vec<u64>,
     pub strategy: String,
     pub bankroll: u64,
     pub slots_left_threshold: u64,
+    /// slots_left_threshold was learned by auto_slots_left
+    pub slots_left_auto: bool,
     pub status: BotStatus,
     /// Whether this bot is paused
     pub is_paused: bool,
@@ -639,6 +738,8 @@ pub struct BotState {
     pub managed_miner_auth: Pubkey,  // Auth PDA
     /// Signer (fee payer) SOL balance (lamports)
     pub signer_balance: u64,
+    /// managed_miner_auth PDA SOL balance (lamports)
+    pub auth_balance: u64,
     // Fee params
     pub priority_fee: u64,     // Compute unit price (micro-lamports)
     pub jito_tip: u64,         // Jito tip amount (lamports)
@@ -654,6 +755,37 @@ pub struct BotState {
     pub deployed_per_square: [u64; 25],
     /// Round ID the bot last played in (from miner polling)
     pub miner_round_id: u64,
+    /// Deploy waiting out its preview window (None when not previewing)
+    pub pending_deploy: Option<PendingDeploy>,
+    /// Threshold the signer balance fell below (Some while deploys are held)
+    pub low_balance_threshold: Option<u64>,
+    /// Loss streak that triggered the stop-loss pause (Some until unpaused)
+    pub stop_loss_streak: Option<u64>,
+    /// (available, required) lamports when the last round-start check found the bot underfunded
+    pub underfunded: Option<(u64, u64)>,
+    /// (scale_bps, fleet total, cap) when the fleet cap scaled this round's bets
+    pub fleet_scaled: Option<(u64, u64, u64)>,
+    /// Version of the active config (see `ConfigHistory`, 1 = startup config)
+    pub config_version: u32,
+    /// Trigger-to-send latency of the last deploy
+    pub deploy_latency: Option<DeployLatency>,
+}
+
+/// Trigger-to-send timing of a bot's last deploy
+#[derive(Clone, Copy, Debug)]
+pub struct DeployLatency {
+    pub send_us: u64,
+    pub sign_us: Option<u64>,
+    pub resigns: u32,
+}
+
+/// Bets a bot is about to submit, shown so they can be vetoed
+#[derive(Clone, Debug)]
+pub struct PendingDeploy {
+    pub round_id: u64,
+    pub bets: [u64; 25],
+    pub total: u64,
+    pub expires_at: Instant,
 }
 
 /// Helper to format pubkey as shortened version (7...7)
@@ -698,9 +830,11 @@ impl BotState {
             name,
             icon,
             auth_id,
+            auth_ids: vec![auth_id],
             strategy,
             bankroll,
             slots_left_threshold,
+            slots_left_auto: false,
             status: BotStatus::Idle,
             is_paused: false,
             deployed_this_round: 0,
@@ -710,6 +844,7 @@ impl BotState {
             manager,
             managed_miner_auth,
             signer_balance: 0,
+            auth_balance: 0,
             priority_fee,
             jito_tip,
             max_per_square,
@@ -719,6 +854,40 @@ impl BotState {
             squares_count,
             deployed_per_square: [0; 25],
             miner_round_id: 0,
+            pending_deploy: None,
+            low_balance_threshold: None,
+            stop_loss_streak: None,
+            underfunded: None,
+            fleet_scaled: None,
+            config_version: 1,
+            deploy_latency: None,
+        }
+    }
+    
+    /// Show the values of a reloaded (or reverted) config
+    pub fn apply_config(&mut self, config: &BotConfig, version: u32) {
+        self.config_version = version;
+        self.bankroll = config.bankroll;
+        self.slots_left_auto = config.auto_slots_left;
+        if !self.slots_left_auto {
+            self.slots_left_threshold = config.slots_left;
+        }
+        self.priority_fee = config.priority_fee;
+        self.jito_tip = config.jito_tip;
+        match &config.strategy_params {
+            StrategyParams::EV { max_per_square, min_bet, ore_value } => {
+                self.max_per_square = *max_per_square;
+                self.min_bet = *min_bet;
+                self.ore_value = *ore_value;
+            }
+            params @ StrategyParams::Percentage { percentage, .. } => {
+                self.percentage = *percentage;
+                self.squares_count = params.percentage_squares_count();
+            }
+            StrategyParams::Manual { .. } => {}
+            StrategyParams::LeadSquare { max_per_square, .. } => {
+                self.max_per_square = *max_per_square;
+            }
         }
     }
     
@@ -774,6 +943,9 @@ pub struct App {
     // Transaction log
     pub tx_log: Vec<TxLogEntry>,
     
+    // Recent signatures and their confirmation status, oldest first
+    pub signatures: Vec<SignatureEntry>,
+    
     // Cursor/selection state
     pub selected: Option<SelectableElement>,
     pub status_msg: Option<(String, Instant, bool)>,  // Message, when it was set, is_error
@@ -789,6 +961,10 @@ pub struct App {
     
     // Treasury data
     pub treasury: Option<crate::treasury_tracker::TreasuryData>,
+    
+    // Unit amounts are shown in, and the SOL/USD price for `usd`
+    pub display_units: DisplayUnits,
+    pub sol_price: Option<f64>,
 }
 
 impl App {
@@ -816,20 +992,40 @@ impl App {
             round: None,
             bots: Vec::new(),
             tx_log: Vec::new(),
+            signatures: Vec::new(),
             selected: None,
             status_msg: None,
             config_path: None,
             view_mode: ViewMode::default(),
             network_stats: NetworkStats::new(),
             treasury: None,
+            display_units: DisplayUnits::default(),
+            sol_price: None,
         }
     }
     
+    /// `lamports` in the configured display units
+    pub fn amount(&self, lamports: u64) -> String {
+        self.display_units.format(lamports, self.sol_price)
+    }
+    
     /// Toggle view mode (Board <-> TxLog)
     pub fn toggle_view(&mut self) {
         self.view_mode.toggle();
     }
     
+    /// Bot the current selection belongs to, if any
+    pub fn selected_bot(&self) -> Option<usize> {
+        match &self.selected {
+            Some(SelectableElement::BotPauseToggle(i)) |
+            Some(SelectableElement::BotSigner(i)) |
+            Some(SelectableElement::BotAuthPda(i)) |
+            Some(SelectableElement::BotConfigReload(i)) |
+            Some(SelectableElement::BotSessionRefresh(i)) => Some(*i),
+            _ => None,
+        }
+    }
+    
     /// Move selection up
     pub fn select_prev(&mut self) {
         // Navigation order per bot: PauseToggle -> Signer -> AuthPda -> ConfigReload -> SessionRefresh
@@ -1125,6 +1321,7 @@ impl App {
                     self.network_stats.txs_failed += 1;
                 }
             }
+            TxStatus::Duplicate | TxStatus::Partial => {}
         }
         
         self.tx_log.push(TxLogEntry {
@@ -1146,6 +1343,36 @@ impl App {
         }
     }
     
+    /// Update a signature's status in the signature panel
+    ///
+    /// A resolved signature from a round already cleared isn't added back.
+    pub fn record_signature_status(&mut self, bot_name: String, signature: Signature, round_id: u64, state: SignatureState) {
+        if let Some(entry) = self.signatures.iter_mut().find(|e| e.signature == signature) {
+            entry.state = state;
+            entry.updated_at = Instant::now();
+            return;
+        }
+        let current_round = self.board.map_or(0, |b| b.round_id);
+        if state != SignatureState::Pending && round_id < current_round {
+            return;
+        }
+        if self.signatures.len() == MAX_TRACKED {
+            self.signatures.remove(0);
+        }
+        self.signatures.push(SignatureEntry {
+            bot_name,
+            signature,
+            round_id,
+            state,
+            updated_at: Instant::now(),
+        });
+    }
+    
+    /// Round transition: drop resolved signatures from earlier rounds
+    pub fn clear_signatures_before(&mut self, round_id: u64) {
+        self.signatures.retain(|e| e.round_id >= round_id || e.state == SignatureState::Pending);
+    }
+    
     /// Log a transaction (legacy format - converts to Deploy type)
     pub fn log_tx(&mut self, bot_name: String, action: TxAction, signature: Signature, error: Option<String>) {
         self.log_tx_typed(bot_name, TxType::Deploy, action.to_status(), signature, error, None, None, None, None);
@@ -1169,6 +1396,9 @@ impl App {
                 self.latest_blockhash = blockhash;
             }
             TuiUpdate::BoardUpdate(board) => {
+                if self.board.map_or(true, |b| b.round_id != board.round_id) {
+                    self.clear_signatures_before(board.round_id);
+                }
                 self.board = Some(board);
             }
             TuiUpdate::RoundUpdate(round) => {
@@ -1231,6 +1461,11 @@ impl App {
                     bot.signer_balance = balance;
                 }
             }
+            TuiUpdate::BotAuthBalanceUpdate { bot_index, balance } => {
+                if let Some(bot) = self.bots.get_mut(bot_index) {
+                    bot.auth_balance = balance;
+                }
+            }
             TuiUpdate::BotClaimedSol { bot_index, amount } => {
                 if let Some(bot) = self.bots.get_mut(bot_index) {
                     bot.session_stats.total_claimed_sol += amount;
@@ -1247,6 +1482,9 @@ impl App {
             TuiUpdate::TxEventTyped { bot_name, tx_type, status, signature, error, slot, round_id, amount, attempt } => {
                 self.log_tx_typed(bot_name, tx_type, status, signature, error, slot, round_id, amount, attempt);
             }
+            TuiUpdate::SignatureStatus { bot_name, signature, round_id, state } => {
+                self.record_signature_status(bot_name, signature, round_id, state);
+            }
             TuiUpdate::Error(msg) => {
                 // Log error as a failed tx entry for now
                 self.log_tx_typed("system".to_string(), TxType::Deploy, TxStatus::Failed, Signature::default(), Some(msg), None, None, None, None);
@@ -1300,14 +1538,123 @@ impl App {
             TuiUpdate::TreasuryUpdate(data) => {
                 self.treasury = Some(data);
             }
+            TuiUpdate::SolPrice(price) => {
+                self.sol_price = Some(price);
+            }
             TuiUpdate::BotPauseUpdate { bot_index, is_paused } => {
                 if let Some(bot) = self.bots.get_mut(bot_index) {
                     bot.is_paused = is_paused;
                     if is_paused {
                         bot.status = BotStatus::Paused;
+                    } else {
+                        bot.stop_loss_streak = None;
                     }
                 }
             }
+            TuiUpdate::PendingDeploy { bot_index, round_id, bets, total, preview_ms } => {
+                if let Some(bot) = self.bots.get_mut(bot_index) {
+                    bot.pending_deploy = Some(PendingDeploy {
+                        round_id,
+                        bets,
+                        total,
+                        expires_at: Instant::now() + Duration::from_millis(preview_ms),
+                    });
+                }
+            }
+            TuiUpdate::PendingDeployCleared { bot_index } => {
+                if let Some(bot) = self.bots.get_mut(bot_index) {
+                    bot.pending_deploy = None;
+                }
+            }
+            TuiUpdate::BotSignerBalanceLow { bot_index, is_low, min_signer_balance } => {
+                let name = match self.bots.get_mut(bot_index) {
+                    Some(bot) => {
+                        bot.low_balance_threshold = is_low.then_some(min_signer_balance);
+                        bot.name.clone()
+                    }
+                    None => return,
+                };
+                let msg = if is_low {
+                    format!("⚠ {} signer below {:.4} ◎ - deploys held", name, min_signer_balance as f64 / 1e9)
+                } else {
+                    format!("{} signer refunded - deploys resumed", name)
+                };
+                self.set_status(msg, is_low);
+            }
+            TuiUpdate::BotStopLoss { bot_index, losses } => {
+                let name = match self.bots.get_mut(bot_index) {
+                    Some(bot) => {
+                        bot.stop_loss_streak = Some(losses);
+                        bot.name.clone()
+                    }
+                    None => return,
+                };
+                self.set_status(format!("⛔ {} paused: stop-loss after {} losses in a row", name, losses), true);
+            }
+            TuiUpdate::BotFundingCheck { bot_index, round_id, available, required } => {
+                let underfunded = available < required;
+                let name = match self.bots.get_mut(bot_index) {
+                    Some(bot) => {
+                        bot.underfunded = underfunded.then_some((available, required));
+                        bot.name.clone()
+                    }
+                    None => return,
+                };
+                if underfunded {
+                    self.set_status(format!(
+                        "⚠ {} underfunded for round {}: {:.4} ◎ of {:.4} ◎ needed",
+                        name, round_id, available as f64 / 1e9, required as f64 / 1e9,
+                    ), true);
+                }
+            }
+            TuiUpdate::BotFleetScaled { bot_index, round_id, scale_bps, total, cap } => {
+                let scaled = scale_bps < 10_000;
+                let Some(bot) = self.bots.get_mut(bot_index) else { return };
+                bot.fleet_scaled = scaled.then_some((scale_bps, total, cap));
+                // One status line per round rather than one per bot
+                if scaled && bot_index == 0 {
+                    self.set_status(format!(
+                        "⚖ Fleet cap for round {}: {:.4} ◎ planned > {:.4} ◎, bets scaled to {:.1}%",
+                        round_id, total as f64 / 1e9, cap as f64 / 1e9, scale_bps as f64 / 100.0,
+                    ), false);
+                }
+            }
+            TuiUpdate::BotDeployCapExceeded { bot_index, total, absolute_max_deploy } => {
+                let name = match self.bots.get(bot_index) {
+                    Some(bot) => bot.name.clone(),
+                    None => return,
+                };
+                self.set_status(format!(
+                    "⛔ {} paused: deploy of {:.4} ◎ exceeds absolute_max_deploy {:.4} ◎",
+                    name, total as f64 / 1e9, absolute_max_deploy as f64 / 1e9,
+                ), true);
+            }
+            TuiUpdate::BotPriorityFee { bot_index, priority_fee } => {
+                if let Some(bot) = self.bots.get_mut(bot_index) {
+                    bot.priority_fee = priority_fee;
+                }
+            }
+            TuiUpdate::BotSlotsLeftLearned { bot_index, slots_left } => {
+                if let Some(bot) = self.bots.get_mut(bot_index) {
+                    bot.slots_left_threshold = slots_left;
+                    bot.slots_left_auto = true;
+                }
+            }
+            TuiUpdate::BotStrategyUpdate { bot_index, strategy } => {
+                let name = match self.bots.get_mut(bot_index) {
+                    Some(bot) => {
+                        bot.strategy = format!("{:?}", strategy);
+                        bot.name.clone()
+                    }
+                    None => return,
+                };
+                self.set_status(format!("{} switched to {:?} strategy", name, strategy), false);
+            }
+            TuiUpdate::BotDeployLatency { bot_index, send_us, sign_us, resigns, .. } => {
+                if let Some(bot) = self.bots.get_mut(bot_index) {
+                    bot.deploy_latency = Some(DeployLatency { send_us, sign_us, resigns });
+                }
+            }
         }
     }
 }
@@ -1325,9 +1672,34 @@ pub fn init() -> io::Result<Tui> {
 }
 
 pub fn restore() -> io::Result<()> {
-    disable_raw_mode()?;
+    // Leave the alternate screen even if raw mode can't be disabled (this
+    // also runs from the panic hook, e.g. after a render panic on resize)
+    let raw = disable_raw_mode();
     execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
-    Ok(())
+    raw
+}
+
+/// Smallest terminal (columns, rows) the dashboard layout fits in
+pub const MIN_SIZE: (u16, u16) = (80, 32);
+
+/// If the frame is smaller than `min` (columns, rows), draw a "terminal too
+/// small" message instead of the layout and return true
+pub fn draw_too_small(frame: &mut Frame, min: (u16, u16)) -> bool {
+    let area = frame.area();
+    if area.width >= min.0 && area.height >= min.1 {
+        return false;
+    }
+    let message = vec![
+        Line::from("Terminal too small".bold()),
+        Line::from(format!("{}x{}, need {}x{}", area.width, area.height, min.0, min.1)),
+    ];
+    let top = area.height.saturating_sub(message.len() as u16) / 2;
+    let text_area = Rect { y: area.y + top, height: area.height - top, ..area };
+    frame.render_widget(
+        Paragraph::new(message).alignment(Alignment::Center).style(Style::default().fg(Color::Yellow)),
+        text_area,
+    );
+    true
 }
 
 // =============================================================================
@@ -1335,8 +1707,12 @@ pub fn restore() -> io::Result<()> {
 // =============================================================================
 
 pub fn draw(frame: &mut Frame, app: &App) {
+    if draw_too_small(frame, MIN_SIZE) {
+        return;
+    }
+    
     // Main layout: Header, Bot Blocks, Content (TxLog OR Board), Footer
-    // Tab key toggles between TxLog and Board views
+    // Tab key cycles between TxLog, Board and EV Detail views
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -1352,8 +1728,17 @@ pub fn draw(frame: &mut Frame, app: &App) {
     
     // Draw either TxLog or Board based on view mode
     match app.view_mode {
-        ViewMode::TxLog => draw_tx_log(frame, chunks[2], app),
+        ViewMode::TxLog => {
+            // Signature panel beside the log
+            let cols = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(60), Constraint::Length(44)])
+                .split(chunks[2]);
+            draw_tx_log(frame, cols[0], app);
+            draw_signature_panel(frame, cols[1], app);
+        }
         ViewMode::Board => draw_board_grid_expanded(frame, chunks[2], app),
+        ViewMode::EvDetail => draw_ev_detail(frame, chunks[2], app),
     }
     
     draw_footer(frame, chunks[3], app);
@@ -1384,18 +1769,23 @@ fn draw_header(frame: &mut Frame, area: Rect, app: &App) {
     
     // Format treasury data
     let treasury_str = app.treasury.as_ref().map(|t| {
-        let balance_sol = t.balance as f64 / 1e9;
         let motherlode_ore = t.motherlode as f64 / 1e11; // ORE has 11 decimals
-        format!("│ Treasury: {:.2}◎ | ML: {:.0} ORE ", balance_sol, motherlode_ore)
+        format!("│ Treasury: {} | ML: {:.0} ORE ", app.amount(t.balance), motherlode_ore)
     }).unwrap_or_default();
     
     // Format total deployed from round data
     let total_deployed_str = app.round.as_ref().map(|r| {
         let total: u64 = r.deployed.iter().sum();
-        let total_sol = total as f64 / 1e9;
-        format!("│ Deployed: {:.4}◎ ", total_sol)
+        format!("│ Deployed: {} ", app.amount(total))
     }).unwrap_or_default();
     
+    // Current price when amounts are in USD
+    let price_str = match (app.display_units, app.sol_price) {
+        (DisplayUnits::Usd, Some(price)) => format!("│ SOL ${:.2} ", price),
+        (DisplayUnits::Usd, None) => "│ SOL $? (showing ◎) ".to_string(),
+        _ => String::new(),
+    };
+    
     let line1 = Line::from(vec![
         Span::styled("  ⚡ EVORE ", Style::default().fg(Color::Magenta).bold()),
         Span::styled("│ ", Style::default().fg(Color::DarkGray)),
@@ -1413,6 +1803,7 @@ fn draw_header(frame: &mut Frame, area: Rect, app: &App) {
         ),
         Span::styled(total_deployed_str, Style::default().fg(Color::Green)),
         Span::styled(treasury_str, Style::default().fg(Color::Rgb(255, 165, 0))),
+        Span::styled(price_str, Style::default().fg(Color::Cyan)),
     ]);
     
     let line2 = Line::from(vec![
@@ -1432,7 +1823,7 @@ fn draw_header(frame: &mut Frame, area: Rect, app: &App) {
             Span::styled("", Style::default())
         },
         // Help text
-        Span::styled("  ↑↓:nav Tab:view Enter:act P:pause q:quit", Style::default().fg(Color::DarkGray)),
+        Span::styled("  ↑↓:nav Tab:view Enter:act p:pause P/R:all v:veto u:undo cfg q:quit", Style::default().fg(Color::DarkGray)),
     ]);
     
     let block = Block::default()
@@ -1488,27 +1879,26 @@ fn draw_single_bot_block(frame: &mut Frame, area: Rect, bot: &BotState, app: &Ap
     };
     
     // Format amounts
-    let bankroll_sol = bot.bankroll as f64 / 1e9;
-    let deployed_sol = bot.deployed_this_round as f64 / 1e9;
-    let signer_sol = bot.signer_balance as f64 / 1e9;
+    let bankroll_str = app.amount(bot.bankroll);
+    let deployed_str = app.amount(bot.deployed_this_round);
+    let signer_str = app.amount(bot.signer_balance);
+    let auth_str = app.amount(bot.auth_balance);
     
     // Session stats
     let stats = &bot.session_stats;
     
     // Simple P&L: current signer balance - starting signer balance
-    let sol_pnl = if stats.starting_signer_balance > 0 {
-        (bot.signer_balance as i64 - stats.starting_signer_balance as i64) as f64 / 1e9
+    let sol_pnl_lamports = if stats.starting_signer_balance > 0 {
+        bot.signer_balance as i64 - stats.starting_signer_balance as i64
     } else {
-        0.0
+        0
     };
+    let sol_pnl = sol_pnl_lamports as f64 / 1e9;
     let ore_pnl = stats.ore_pnl() as f64 / 1e11;
     
     // Format P&L with sign
-    let (sol_pnl_str, sol_pnl_color) = if sol_pnl >= 0.0 {
-        (format!("+{:.4}", sol_pnl), Color::Green)
-    } else {
-        (format!("{:.4}", sol_pnl), Color::Red)
-    };
+    let sol_pnl_str = app.display_units.format_signed(sol_pnl_lamports, app.sol_price);
+    let sol_pnl_color = if sol_pnl >= 0.0 { Color::Green } else { Color::Red };
     let (ore_pnl_str, ore_pnl_color) = if ore_pnl >= 0.0 {
         (format!("+{:.2}", ore_pnl), Color::Rgb(255, 165, 0))
     } else {
@@ -1524,7 +1914,7 @@ fn draw_single_bot_block(frame: &mut Frame, area: Rect, bot: &BotState, app: &Ap
         0.0
     };
     
-    let title = format!(" {} {} ({}) ", bot.icon, bot.name, bot.auth_id);
+    let title = format!(" {} {} ({}) ", bot.icon, bot.name, auth_ids_label(&bot.auth_ids));
     
     // Check selection state for highlighting
     let pause_selected = app.selected == Some(SelectableElement::BotPauseToggle(bot_index));
@@ -1563,7 +1953,7 @@ fn draw_single_bot_block(frame: &mut Frame, area: Rect, bot: &BotState, app: &Ap
                 if signer_selected { Style::default().fg(Color::White).bold().on_blue() } 
                 else { Style::default().fg(Color::Gray) }
             ),
-            Span::styled(format!("  {:.4} ◎", signer_sol), Style::default().fg(Color::Yellow)),
+            Span::styled(format!("  {}", signer_str), Style::default().fg(Color::Yellow)),
         ]),
         Line::from(vec![
             if auth_selected { Span::styled("► ", Style::default().fg(Color::White).bold()) } 
@@ -1574,13 +1964,14 @@ fn draw_single_bot_block(frame: &mut Frame, area: Rect, bot: &BotState, app: &Ap
                 if auth_selected { Style::default().fg(Color::White).bold().on_blue() } 
                 else { Style::default().fg(Color::Gray) }
             ),
+            Span::styled(format!("  {}", auth_str), Style::default().fg(Color::Yellow)),
         ]),
         // ═══ ACTIONS (selectable) ═══
         Line::from(vec![
             if config_selected { Span::styled("► ", Style::default().fg(Color::White).bold()) } 
             else { Span::styled("  ", Style::default()) },
             Span::styled(
-                "🔄 Reload Config", 
+                format!("🔄 Reload Config v{}", bot.config_version),
                 if config_selected { Style::default().fg(Color::White).bold().on_blue() } 
                 else { Style::default().fg(Color::DarkGray) }
             ),
@@ -1596,12 +1987,75 @@ fn draw_single_bot_block(frame: &mut Frame, area: Rect, bot: &BotState, app: &Ap
         // ═══ BALANCES ═══
         Line::from(vec![
             Span::styled("◈ Bankroll  ", Style::default().fg(Color::DarkGray)),
-            Span::styled(format!("{:.4} ◎", bankroll_sol), Style::default().fg(Color::Cyan)),
+            Span::styled(bankroll_str, Style::default().fg(Color::Cyan)),
             Span::styled("   Deployed  ", Style::default().fg(Color::DarkGray)),
-            Span::styled(format!("{:.4} ◎", deployed_sol), Style::default().fg(Color::Yellow)),
+            Span::styled(deployed_str, Style::default().fg(Color::Yellow)),
         ]),
+    ];
+    
+    // Low signer balance warning (deploys are held until refunded)
+    if let Some(threshold) = bot.low_balance_threshold {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" ⚠ SIGNER LOW: {} < {} - refund to resume ", signer_str, app.amount(threshold)),
+                Style::default().fg(Color::White).bg(Color::Red).bold(),
+            ),
+        ]));
+    }
+    
+    // Round-start funding check (cleared once a later round's check passes)
+    if let Some((available, required)) = bot.underfunded {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" ⚠ UNDERFUNDED: {} of {} for a full deploy - top up ", app.amount(available), app.amount(required)),
+                Style::default().fg(Color::Black).bg(Color::Yellow).bold(),
+            ),
+        ]));
+    }
+    
+    // Fleet cap scaling (cleared once a later round fits the cap)
+    if let Some((scale_bps, total, cap)) = bot.fleet_scaled {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" ⚖ FLEET CAP: bets at {:.1}% ({} planned, cap {}) ", scale_bps as f64 / 100.0, app.amount(total), app.amount(cap)),
+                Style::default().fg(Color::Black).bg(Color::Cyan).bold(),
+            ),
+        ]));
+    }
+    
+    // Stop-loss pause reason (cleared when the bot is unpaused)
+    if let Some(losses) = bot.stop_loss_streak {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" ⛔ STOP-LOSS: {} losses in a row - p to resume ", losses),
+                Style::default().fg(Color::White).bg(Color::Red).bold(),
+            ),
+        ]));
+    }
+    
+    // Pending deploy preview (bet vector + veto countdown)
+    if let Some(pending) = &bot.pending_deploy {
+        let remaining = pending.expires_at.saturating_duration_since(Instant::now());
+        let square_count = pending.bets.iter().filter(|b| **b > 0).count();
+        lines.push(Line::from(vec![
+            Span::styled("◈ Preview   ", Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("{} sq  {}", square_count, app.amount(pending.total)), Style::default().fg(Color::Magenta).bold()),
+            Span::styled(format!("  v:veto ({:.1}s) #{}", remaining.as_secs_f64(), pending.round_id), Style::default().fg(Color::Yellow)),
+        ]));
+        let bet_spans: Vec<Span> = pending.bets.iter().enumerate()
+            .filter(|(_, bet)| **bet > 0)
+            .map(|(i, bet)| Span::styled(format!("{}:{} ", i, app.amount(*bet)), Style::default().fg(Color::Gray)))
+            .collect();
+        lines.push(Line::from(
+            std::iter::once(Span::styled("  ", Style::default())).chain(bet_spans).collect::<Vec<_>>()
+        ));
+    }
+    
+    lines.extend([
         Line::from(vec![
             Span::styled("◈ Claimable ", Style::default().fg(Color::DarkGray)),
+            Span::styled(app.amount(bot.rewards_sol()), Style::default().fg(Color::Green)),
+            Span::styled("  ", Style::default()),
             Span::styled(format!("{:.2} ORE", bot.rewards_ore() as f64 / 1e11), Style::default().fg(Color::Rgb(255, 165, 0))),
         ]),
         // Fees line
@@ -1610,9 +2064,25 @@ fn draw_single_bot_block(frame: &mut Frame, area: Rect, bot: &BotState, app: &Ap
             Span::styled("prio=", Style::default().fg(Color::DarkGray)),
             Span::styled(format!("{}", bot.priority_fee), Style::default().fg(Color::White)),
             Span::styled(" tip=", Style::default().fg(Color::DarkGray)),
-            Span::styled(format!("{:.4}◎", bot.jito_tip as f64 / 1e9), Style::default().fg(Color::Cyan)),
+            Span::styled(app.amount(bot.jito_tip), Style::default().fg(Color::Cyan)),
         ]),
-    ];
+    ]);
+    
+    // Last deploy's trigger-to-send time; presign also shows the signing it kept off the critical path
+    if let Some(latency) = bot.deploy_latency {
+        let mut spans = vec![
+            Span::styled("◈ Send      ", Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("{}µs", latency.send_us), Style::default().fg(Color::White)),
+        ];
+        match latency.sign_us {
+            Some(sign_us) => spans.push(Span::styled(
+                format!(" presigned (saved {}µs signing, {} re-sign{})", sign_us, latency.resigns, if latency.resigns == 1 { "" } else { "s" }),
+                Style::default().fg(Color::Green),
+            )),
+            None => spans.push(Span::styled(" built at trigger", Style::default().fg(Color::DarkGray))),
+        }
+        lines.push(Line::from(spans));
+    }
     
     // Strategy-specific config
     match bot.strategy.as_str() {
@@ -1629,7 +2099,7 @@ fn draw_single_bot_block(frame: &mut Frame, area: Rect, bot: &BotState, app: &Ap
                 Span::styled(" ore=", Style::default().fg(Color::DarkGray)),
                 Span::styled(format!("{:.2}", ore_val), Style::default().fg(Color::Rgb(255, 165, 0))),
                 Span::styled(" @", Style::default().fg(Color::DarkGray)),
-                Span::styled(format!("{}slots", bot.slots_left_threshold), Style::default().fg(Color::Yellow)),
+                Span::styled(slots_left_label(bot), Style::default().fg(Color::Yellow)),
             ]));
         }
         "Percentage" => {
@@ -1640,14 +2110,14 @@ fn draw_single_bot_block(frame: &mut Frame, area: Rect, bot: &BotState, app: &Ap
                 Span::styled(" × ", Style::default().fg(Color::DarkGray)),
                 Span::styled(format!("{} sq", bot.squares_count), Style::default().fg(Color::Cyan)),
                 Span::styled(" @", Style::default().fg(Color::DarkGray)),
-                Span::styled(format!("{}slots", bot.slots_left_threshold), Style::default().fg(Color::Yellow)),
+                Span::styled(slots_left_label(bot), Style::default().fg(Color::Yellow)),
             ]));
         }
         _ => {
             lines.push(Line::from(vec![
                 Span::styled("◈ Config   ", Style::default().fg(Color::DarkGray)),
                 Span::styled("@", Style::default().fg(Color::DarkGray)),
-                Span::styled(format!("{}slots", bot.slots_left_threshold), Style::default().fg(Color::Yellow)),
+                Span::styled(slots_left_label(bot), Style::default().fg(Color::Yellow)),
             ]));
         }
     };
@@ -1716,20 +2186,21 @@ fn draw_single_bot_block(frame: &mut Frame, area: Rect, bot: &BotState, app: &Ap
     // P&L section
     lines.push(Line::from(vec![
         Span::styled("P&L  ", Style::default().fg(Color::DarkGray)),
-        Span::styled(format!("{} ◎", sol_pnl_str), Style::default().fg(sol_pnl_color).bold()),
+        Span::styled(sol_pnl_str, Style::default().fg(sol_pnl_color).bold()),
         Span::styled("   ", Style::default()),
         Span::styled(format!("{} ORE", ore_pnl_str), Style::default().fg(ore_pnl_color)),
     ]));
     
     // SOL Spent (total cost, only if negative P&L)
-    let sol_spent = if sol_pnl < 0.0 { -sol_pnl } else { 0.0 };
-    if sol_spent > 0.0 {
+    let sol_spent = sol_pnl_lamports.min(0).unsigned_abs();
+    let cost_per_ore_str = app.amount((cost_per_ore * 1e9) as u64);
+    if sol_spent > 0 {
         lines.push(Line::from(vec![
             Span::styled("Spent ", Style::default().fg(Color::DarkGray)),
-            Span::styled(format!("{:.4} ◎", sol_spent), Style::default().fg(Color::Yellow)),
+            Span::styled(app.amount(sol_spent), Style::default().fg(Color::Yellow)),
             // Cost per ORE (if earned ORE)
             if cost_per_ore > 0.0 {
-                Span::styled(format!("  ({:.4} ◎/ORE)", cost_per_ore), Style::default().fg(Color::Cyan))
+                Span::styled(format!("  ({}/ORE)", cost_per_ore_str), Style::default().fg(Color::Cyan))
             } else {
                 Span::styled("", Style::default())
             },
@@ -1738,7 +2209,7 @@ fn draw_single_bot_block(frame: &mut Frame, area: Rect, bot: &BotState, app: &Ap
         // Show cost per ORE even if in profit
         lines.push(Line::from(vec![
             Span::styled("Cost ", Style::default().fg(Color::DarkGray)),
-            Span::styled(format!("{:.4} ◎/ORE", cost_per_ore), Style::default().fg(Color::Cyan)),
+            Span::styled(format!("{}/ORE", cost_per_ore_str), Style::default().fg(Color::Cyan)),
         ]));
     }
     
@@ -1757,6 +2228,15 @@ fn draw_single_bot_block(frame: &mut Frame, area: Rect, bot: &BotState, app: &Ap
 // Board Grid Section
 // =============================================================================
 
+/// Deploy threshold for the config line, marked when auto_slots_left learned it
+fn slots_left_label(bot: &BotState) -> String {
+    if bot.slots_left_auto {
+        format!("{}slots(auto)", bot.slots_left_threshold)
+    } else {
+        format!("{}slots", bot.slots_left_threshold)
+    }
+}
+
 /// Draw expanded board grid (when in Board view mode)
 /// Shows total deployed, EV per square, per-bot deployment breakdown, and EV totals
 fn draw_board_grid_expanded(frame: &mut Frame, area: Rect, app: &App) {
@@ -1768,12 +2248,22 @@ fn draw_board_grid_expanded(frame: &mut Frame, area: Rect, app: &App) {
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Blue));
     
-    let inner = block.inner(area);
+    let mut inner = block.inner(area);
     frame.render_widget(block, area);
     
     // Get current board for round comparison
     let current_round_id = app.board.as_ref().map(|b| b.round_id).unwrap_or(0);
     
+    // Heatmap on the left when there's room, EV table on the right
+    if app.round.is_some() && inner.width >= HEATMAP_WIDTH + 80 {
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(HEATMAP_WIDTH), Constraint::Min(0)])
+            .split(inner);
+        draw_round_heatmap(frame, cols[0], app);
+        inner = cols[1];
+    }
+    
     // If no round data, show placeholder
     let round = match &app.round {
         Some(r) => r,
@@ -1930,6 +2420,132 @@ fn draw_board_grid_expanded(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(table, inner);
 }
 
+/// Bets a bot chose this round: the previewed deploy if any, else what its miner shows
+fn chosen_bets(bot: &BotState, round_id: u64) -> [u64; 25] {
+    match &bot.pending_deploy {
+        Some(pending) if pending.round_id == round_id => pending.bets,
+        _ if bot.miner_round_id == round_id => bot.deployed_per_square,
+        _ => [0; 25],
+    }
+}
+
+/// 5x5 grid of pot, marginal EV and chosen amount for the selected bot (or the first)
+fn draw_ev_detail(frame: &mut Frame, area: Rect, app: &App) {
+    use crate::ev_calculator::{ev_details, EV_UNIT_BET};
+    
+    let bot = app.bots.get(app.selected_bot().unwrap_or(0));
+    let title = format!(
+        " EV Detail{} - pot │ EV per {:.2}◎ │ chosen [Tab: {}] ",
+        bot.map(|b| format!(" {} {}", b.icon, b.name)).unwrap_or_default(),
+        EV_UNIT_BET as f64 / 1e9,
+        app.view_mode.as_str(),
+    );
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    
+    let (Some(round), Some(board)) = (&app.round, &app.board) else {
+        let placeholder = Paragraph::new("No round data")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        frame.render_widget(placeholder, inner);
+        return;
+    };
+    
+    let chosen = bot.map(|b| chosen_bets(b, board.round_id)).unwrap_or([0; 25]);
+    let details = ev_details(&round.deployed, &chosen);
+    
+    let rows: Vec<Row> = details.chunks(5).map(|row| {
+        let cells: Vec<ratatui::widgets::Cell> = row.iter().map(|d| {
+            let ev = d.marginal_ev as f64 / 1e9;
+            let mut spans = vec![
+                Span::styled(format!("{:>2}:{:.3} ", d.square, d.pot as f64 / 1e9), Style::default().fg(Color::White)),
+                Span::styled(format!("{:+.4}", ev), Style::default().fg(if ev > 0.0 { Color::Green } else { Color::Red })),
+            ];
+            if d.chosen > 0 {
+                spans.push(Span::styled(format!(" ▸{:.3}", d.chosen as f64 / 1e9), Style::default().fg(Color::Yellow)));
+            }
+            ratatui::widgets::Cell::from(Line::from(spans))
+        }).collect();
+        Row::new(cells).height(1)
+    }).collect();
+    
+    let table = Table::new(rows, [Constraint::Ratio(1, 5); 5]).column_spacing(0);
+    frame.render_widget(table, inner);
+}
+
+/// Heatmap width: 5 cells of 5 chars plus borders
+const HEATMAP_WIDTH: u16 = 27;
+
+/// Per-square heat (0.0..=1.0) relative to the most-deployed square
+/// All-zero boards return a flat grid of zeros
+pub fn heatmap_intensity(deployed: &[u64; 25]) -> [f64; 25] {
+    let max = deployed.iter().copied().max().unwrap_or(0);
+    let mut heat = [0.0; 25];
+    if max == 0 {
+        return heat;
+    }
+    for (h, d) in heat.iter_mut().zip(deployed.iter()) {
+        *h = *d as f64 / max as f64;
+    }
+    heat
+}
+
+/// Background color for a heatmap cell (dark blue → hot red)
+fn heatmap_color(heat: f64) -> Color {
+    let t = heat.clamp(0.0, 1.0);
+    let lerp = |from: f64, to: f64| (from + (to - from) * t) as u8;
+    Color::Rgb(lerp(25.0, 220.0), lerp(30.0, 50.0), lerp(60.0, 40.0))
+}
+
+/// 5x5 colored heatmap of the current round, bots' squares outlined
+fn draw_round_heatmap(frame: &mut Frame, area: Rect, app: &App) {
+    let block = Block::default()
+        .title(" Heat ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    
+    let round = match &app.round {
+        Some(r) => r,
+        None => return,
+    };
+    let heat = heatmap_intensity(&round.deployed);
+    
+    // Squares any bot deployed to this round
+    let current_round_id = app.board.as_ref().map(|b| b.round_id).unwrap_or(0);
+    let mut targeted = [false; 25];
+    for bot in app.bots.iter().filter(|b| b.miner_round_id == current_round_id) {
+        for (t, d) in targeted.iter_mut().zip(bot.deployed_per_square.iter()) {
+            *t |= *d > 0;
+        }
+    }
+    
+    let lines: Vec<Line> = (0..5)
+        .map(|row| {
+            let spans: Vec<Span> = (0..5)
+                .map(|col| {
+                    let idx = row * 5 + col;
+                    let style = Style::default().bg(heatmap_color(heat[idx]));
+                    if targeted[idx] {
+                        Span::styled(format!("[{:>2}]", idx), style.fg(Color::Yellow).bold())
+                    } else {
+                        Span::styled(format!(" {:>2} ", idx), style.fg(Color::White))
+                    }
+                })
+                .flat_map(|cell| [cell, Span::raw(" ")])
+                .collect();
+            Line::from(spans)
+        })
+        .collect();
+    
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
 // =============================================================================
 // Footer Section (Network Stats)
 // =============================================================================
@@ -2001,6 +2617,56 @@ fn draw_footer(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(paragraph, area);
 }
 
+// =============================================================================
+// Signature Panel
+// =============================================================================
+
+fn draw_signature_panel(frame: &mut Frame, area: Rect, app: &App) {
+    let pending = app.signatures.iter().filter(|e| e.state == SignatureState::Pending).count();
+    let block = Block::default()
+        .title(format!(" Signatures ({} pending) ", pending))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray));
+    
+    if app.signatures.is_empty() {
+        let paragraph = Paragraph::new("No signatures yet")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+    
+    let items: Vec<ListItem> = app.signatures
+        .iter()
+        .rev()
+        .take(area.height.saturating_sub(2) as usize)
+        .map(|entry| {
+            let elapsed = entry.updated_at.elapsed().as_secs();
+            let time_str = if elapsed < 60 {
+                format!("{:>3}s", elapsed)
+            } else {
+                format!("{:>3}m", elapsed / 60)
+            };
+            let (color, detail) = match &entry.state {
+                SignatureState::Pending => (Color::Cyan, format!("r{}", entry.round_id)),
+                SignatureState::Confirmed { slot } => (Color::Green, format!("@{}", slot)),
+                SignatureState::Failed { error } => (Color::Red, error.clone()),
+            };
+            
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("[{}] ", time_str), Style::default().fg(Color::DarkGray)),
+                Span::styled(format!("{} ", app.get_bot_icon(&entry.bot_name)), Style::default()),
+                Span::styled(format!("{} ", &entry.signature.to_string()[..8]), Style::default().fg(Color::White)),
+                Span::styled(format!("{:<4} ", entry.state.as_str()), Style::default().fg(color)),
+                Span::styled(detail, Style::default().fg(Color::DarkGray)),
+            ]))
+        })
+        .collect();
+    
+    frame.render_widget(List::new(items).block(block), area);
+}
+
 // =============================================================================
 // Transaction Log Section
 // =============================================================================
@@ -2093,8 +2759,8 @@ fn draw_tx_log(frame: &mut Frame, area: Rect, app: &App) {
             // Add amount info based on tx type
             if let Some(amount) = entry.amount {
                 let amount_str = match entry.tx_type {
-                    TxType::Deploy => format!("{:.4}◎ ", amount as f64 / 1e9),
-                    TxType::ClaimSol => format!("+{:.4}◎ ", amount as f64 / 1e9),
+                    TxType::Deploy => format!("{} ", app.amount(amount)),
+                    TxType::ClaimSol => format!("+{} ", app.amount(amount)),
                     TxType::ClaimOre => format!("+{:.4}ORE ", amount as f64 / 1e11),
                     TxType::Checkpoint => format!("r:{} ", amount),
                 };
@@ -2150,13 +2816,22 @@ pub enum InputResult {
     Quit,
     ReloadConfig(usize),  // Bot index to reload config for
     TogglePause(usize),   // Bot index to toggle pause for
+    PauseAll,             // Pause every bot
+    ResumeAll,            // Resume every bot
+    VetoDeploy(usize),    // Bot index whose pending deploy should be vetoed
+    RevertConfig(usize),  // Bot index to re-apply the previous config for
+    Resized,              // Terminal resized, clear before the next draw
 }
 
 /// Handle keyboard input
 /// Returns InputResult indicating what action to take
 pub fn handle_input(app: &mut App) -> io::Result<InputResult> {
     if event::poll(Duration::from_millis(50))? {
-        if let Event::Key(key) = event::read()? {
+        let event = event::read()?;
+        if let Event::Resize(_, _) = event {
+            return Ok(InputResult::Resized);
+        }
+        if let Event::Key(key) = event {
             if key.kind == KeyEventKind::Press {
                 match key.code {
                     KeyCode::Char('q') | KeyCode::Esc => {
@@ -2186,8 +2861,8 @@ pub fn handle_input(app: &mut App) -> io::Result<InputResult> {
                         // Otherwise execute normally (copy or session refresh)
                         app.execute_select_action();
                     }
-                    // P to toggle pause for selected bot (if any bot element is selected)
-                    KeyCode::Char('p') | KeyCode::Char('P') => {
+                    // p to toggle pause for selected bot (if any bot element is selected)
+                    KeyCode::Char('p') => {
                         // Get bot index from current selection
                         let bot_idx = match &app.selected {
                             Some(SelectableElement::BotPauseToggle(i)) |
@@ -2201,6 +2876,45 @@ pub fn handle_input(app: &mut App) -> io::Result<InputResult> {
                             return Ok(InputResult::TogglePause(idx));
                         }
                     }
+                    // Shift+P / Shift+R to pause or resume every bot
+                    KeyCode::Char('P') => {
+                        return Ok(InputResult::PauseAll);
+                    }
+                    KeyCode::Char('R') => {
+                        return Ok(InputResult::ResumeAll);
+                    }
+                    // v to veto a previewed deploy (selected bot first, else any bot previewing)
+                    KeyCode::Char('v') => {
+                        let selected_idx = match &app.selected {
+                            Some(SelectableElement::BotPauseToggle(i)) |
+                            Some(SelectableElement::BotSigner(i)) |
+                            Some(SelectableElement::BotAuthPda(i)) |
+                            Some(SelectableElement::BotConfigReload(i)) |
+                            Some(SelectableElement::BotSessionRefresh(i)) => Some(*i),
+                            _ => None,
+                        };
+                        let bot_idx = selected_idx
+                            .filter(|i| app.bots.get(*i).map_or(false, |b| b.pending_deploy.is_some()))
+                            .or_else(|| app.bots.iter().position(|b| b.pending_deploy.is_some()));
+                        if let Some(idx) = bot_idx {
+                            return Ok(InputResult::VetoDeploy(idx));
+                        }
+                    }
+                    // u to revert the selected bot to its previous config
+                    KeyCode::Char('u') => {
+                        let bot_idx = match &app.selected {
+                            Some(SelectableElement::BotPauseToggle(i)) |
+                            Some(SelectableElement::BotSigner(i)) |
+                            Some(SelectableElement::BotAuthPda(i)) |
+                            Some(SelectableElement::BotConfigReload(i)) |
+                            Some(SelectableElement::BotSessionRefresh(i)) => Some(*i),
+                            _ => None,
+                        };
+                        match bot_idx {
+                            Some(idx) => return Ok(InputResult::RevertConfig(idx)),
+                            None => app.set_status("Select a bot to revert its config".to_string(), true),
+                        }
+                    }
                     // Tab to toggle view mode
                     KeyCode::Tab => {
                         app.toggle_view();
@@ -2224,3 +2938,63 @@ pub fn handle_input(app: &mut App) -> io::Result<InputResult> {
 
     Ok(InputResult::Continue)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heatmap_intensity_scales_to_max() {
+        let mut deployed = [0u64; 25];
+        deployed[3] = 50;
+        deployed[7] = 200;
+        let heat = heatmap_intensity(&deployed);
+        assert_eq!(heat[7], 1.0);
+        assert_eq!(heat[3], 0.25);
+        assert_eq!(heat[0], 0.0);
+    }
+
+    #[test]
+    fn test_heatmap_intensity_all_zero_is_flat() {
+        let heat = heatmap_intensity(&[0u64; 25]);
+        assert!(heat.iter().all(|h| *h == 0.0));
+    }
+
+    #[test]
+    fn test_draw_any_size() {
+        let app = App::new("http://localhost:8899");
+        let render = |width, height| {
+            let mut terminal = Terminal::new(ratatui::backend::TestBackend::new(width, height)).unwrap();
+            terminal.draw(|frame| draw(frame, &app)).unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            buffer.content().iter().map(|cell| cell.symbol()).collect::<String>()
+        };
+
+        assert!(!render(MIN_SIZE.0, MIN_SIZE.1).contains("Terminal too small"));
+        assert!(render(MIN_SIZE.0 - 1, MIN_SIZE.1).contains("Terminal too small"));
+        assert!(render(40, 5).contains("need 80x32"));
+        render(1, 1);
+        render(0, 0);
+    }
+
+    #[test]
+    fn test_signature_panel_updates_and_round_clear() {
+        let mut app = App::new("http://localhost:8899");
+        let (old, pending, current) = (Signature::new_unique(), Signature::new_unique(), Signature::new_unique());
+        app.record_signature_status("a".into(), old, 10, SignatureState::Pending);
+        app.record_signature_status("a".into(), pending, 10, SignatureState::Pending);
+        app.record_signature_status("a".into(), old, 10, SignatureState::Confirmed { slot: 5 });
+        assert_eq!(app.signatures.len(), 2);
+        assert_eq!(app.signatures[0].state, SignatureState::Confirmed { slot: 5 });
+
+        // Round 11: the resolved round-10 signature goes, the pending one stays
+        app.clear_signatures_before(11);
+        app.board = Some(Board { round_id: 11, ..steel::Zeroable::zeroed() });
+        assert_eq!(app.signatures.iter().map(|e| e.signature).collect::<Vec<_>>(), vec![pending]);
+
+        // A late result for a cleared round isn't added back; current ones are
+        app.record_signature_status("a".into(), old, 10, SignatureState::Failed { error: "expired".into() });
+        app.record_signature_status("a".into(), current, 11, SignatureState::Pending);
+        assert_eq!(app.signatures.iter().map(|e| e.signature).collect::<Vec<_>>(), vec![pending, current]);
+    }
+}
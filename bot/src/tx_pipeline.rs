@@ -3,13 +3,18 @@
 //! Components:
 //! - TxSender: Reads from channel, sends instantly via Helius fast endpoint
 //! - TxConfirmer: Batch getSignatureStatuses, returns results via oneshot
+//! - classify_redundant: sorts out redundant deploy signatures for one round
+//! - check_deploy_cap: hard ceiling on a round's deploy, checked before signing
+//! - check_dust_floor: refuses a round's deploy that's zero or dust
+//! - check_tx_size: refuses a transaction over the size/account limits, warns near them
+//! - PresignedDeploy: deploy transactions signed ahead of the trigger slot
 //!
 //! This decouples transaction sending from confirmation checking.
 
-use solana_sdk::{signature::Signature, transaction::Transaction};
+use solana_sdk::{hash::Hash, signature::Signature, transaction::Transaction};
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, oneshot};
 
 use crate::client::{EvoreClient, RpsTracker};
@@ -33,6 +38,190 @@ pub struct TxResult {
     pub slot_landed: Option<u64>,
 }
 
+/// On-chain status of one of several signatures sent for the same deploy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigStatus {
+    /// Landed without error
+    Landed,
+    /// Failed with AlreadyDeployed - another deploy for this round landed
+    AlreadyDeployed,
+    /// Failed on-chain for any other reason
+    Failed,
+    /// Not found (expired, dropped, or the status lookup failed)
+    Missing,
+}
+
+/// What a redundant deploy signature means for the round
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedundantOutcome {
+    /// This signature's deploy landed
+    Landed,
+    /// Another deploy landed, so this one failing or never landing is expected
+    Duplicate,
+    /// Failed, or missing with nothing landed
+    Failed,
+}
+
+/// Classify the signatures sent for one round's deploy
+///
+/// Deploys are sent several times (attempts x recent blockhashes) but only one
+/// can land per round; the rest fail the round state check or expire.
+pub fn classify_redundant(statuses: &[SigStatus]) -> Vec<RedundantOutcome> {
+    let any_landed = statuses
+        .iter()
+        .any(|s| matches!(s, SigStatus::Landed | SigStatus::AlreadyDeployed));
+
+    statuses
+        .iter()
+        .map(|status| match status {
+            SigStatus::Landed => RedundantOutcome::Landed,
+            SigStatus::AlreadyDeployed => RedundantOutcome::Duplicate,
+            SigStatus::Missing if any_landed => RedundantOutcome::Duplicate,
+            SigStatus::Missing | SigStatus::Failed => RedundantOutcome::Failed,
+        })
+        .collect()
+}
+
+/// `classify_redundant` for several deploys at once
+///
+/// `deploys[i]` names the deploy signature i was sent for (e.g. one per
+/// auth_id of a split round); each deploy can land once.
+pub fn classify_redundant_per_deploy(deploys: &[usize], statuses: &[SigStatus]) -> Vec<RedundantOutcome> {
+    let mut outcomes = vec![RedundantOutcome::Failed; statuses.len()];
+    let mut ids = deploys.to_vec();
+    ids.sort_unstable();
+    ids.dedup();
+    for id in ids {
+        let members: Vec<usize> = (0..statuses.len()).filter(|&i| deploys[i] == id).collect();
+        let member_statuses: Vec<SigStatus> = members.iter().map(|&i| statuses[i]).collect();
+        for (i, outcome) in members.into_iter().zip(classify_redundant(&member_statuses)) {
+            outcomes[i] = outcome;
+        }
+    }
+    outcomes
+}
+
+/// Default hard ceiling on one round's deploy (1 SOL)
+pub const DEFAULT_ABSOLUTE_MAX_DEPLOY: u64 = 1_000_000_000;
+
+/// Refuse a deploy whose total exceeds `absolute_max_deploy`
+///
+/// Independent of bankroll: catches a mistyped bankroll or manual amount before
+/// anything is signed. Going above the default means raising the cap explicitly.
+pub fn check_deploy_cap(total: u64, absolute_max_deploy: u64) -> Result<(), String> {
+    if total > absolute_max_deploy {
+        return Err(format!(
+            "Deploy of {} lamports ({:.4} SOL) exceeds absolute_max_deploy of {} lamports ({:.4} SOL) - refusing to sign",
+            total,
+            total as f64 / 1e9,
+            absolute_max_deploy,
+            absolute_max_deploy as f64 / 1e9,
+        ));
+    }
+    Ok(())
+}
+
+/// Default floor on one round's deploy total (0.00001 SOL, two signature fees)
+pub const DEFAULT_DUST_FLOOR: u64 = 10_000;
+
+/// Refuse a deploy whose total is zero or below `dust_floor`
+///
+/// Separate from `min_bet`, which is per square: this catches EV math or a
+/// misconfig that leaves a whole deploy too small to be worth its fees.
+pub fn check_dust_floor(total: u64, dust_floor: u64) -> Result<(), String> {
+    if total == 0 || total < dust_floor {
+        return Err(format!(
+            "Deploy below dust floor, skipping: {} lamports < dust_floor of {} lamports",
+            total, dust_floor,
+        ));
+    }
+    Ok(())
+}
+
+/// Largest serialized transaction the network accepts (bytes)
+pub const MAX_TX_SIZE: usize = solana_sdk::packet::PACKET_DATA_SIZE;
+
+/// Most accounts one transaction may lock
+pub const MAX_TX_ACCOUNTS: usize = 64;
+
+/// Warn when a transaction comes within this many bytes of `MAX_TX_SIZE`
+pub const TX_SIZE_MARGIN: usize = 100;
+
+/// Warn when a transaction comes within this many accounts of `MAX_TX_ACCOUNTS`
+pub const TX_ACCOUNTS_MARGIN: usize = 4;
+
+/// Serialized size and account count of a built transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxSizeEstimate {
+    pub size: usize,
+    pub accounts: usize,
+}
+
+impl TxSizeEstimate {
+    pub fn of(tx: &Transaction) -> Self {
+        Self {
+            size: bincode::serialized_size(tx).map_or(usize::MAX, |size| size as usize),
+            accounts: tx.message.account_keys.len(),
+        }
+    }
+}
+
+/// Check a built transaction against the size and account limits before sending
+///
+/// Err when it's over a limit (the RPC would reject it as too large), and a
+/// warning when it's within the margin of one, so the next added square or
+/// instruction doesn't turn into a cryptic send failure.
+pub fn check_tx_size(estimate: TxSizeEstimate) -> Result<Option<String>, String> {
+    let TxSizeEstimate { size, accounts } = estimate;
+    if size > MAX_TX_SIZE || accounts > MAX_TX_ACCOUNTS {
+        return Err(format!(
+            "Transaction too large - refusing to send: {} bytes (max {}), {} accounts (max {})",
+            size, MAX_TX_SIZE, accounts, MAX_TX_ACCOUNTS,
+        ));
+    }
+    if size + TX_SIZE_MARGIN > MAX_TX_SIZE || accounts + TX_ACCOUNTS_MARGIN > MAX_TX_ACCOUNTS {
+        return Ok(Some(format!(
+            "Transaction near the size limit: {} of {} bytes, {} of {} accounts",
+            size, MAX_TX_SIZE, accounts, MAX_TX_ACCOUNTS,
+        )));
+    }
+    Ok(None)
+}
+
+/// Deploy transactions signed ahead of the trigger slot (deploy-ahead mode)
+///
+/// Submitting pre-signed bytes takes building and signing off the critical
+/// path; the set is re-signed whenever a newer blockhash comes in so it's no
+/// staler at the trigger than a build-at-trigger deploy would be.
+pub struct PresignedDeploy {
+    /// Blockhashes the transactions are signed against, newest first
+    pub blockhashes: Vec<Hash>,
+    /// Signed transactions, one row per attempt with one tx per blockhash
+    pub transactions: Vec<Vec<Transaction>>,
+    /// How long building and signing the set took (what build-at-trigger pays after the trigger)
+    pub sign_time: Duration,
+}
+
+impl PresignedDeploy {
+    /// Build and sign every (attempt, blockhash) pair
+    pub fn sign(attempts: u64, blockhashes: Vec<Hash>, build: impl Fn(u64, Hash) -> Transaction) -> Self {
+        let started = Instant::now();
+        let transactions = (0..attempts)
+            .map(|attempt| blockhashes.iter().map(|bh| build(attempt, *bh)).collect())
+            .collect();
+        Self {
+            blockhashes,
+            transactions,
+            sign_time: started.elapsed(),
+        }
+    }
+    
+    /// Whether a newer blockhash than the newest one signed against is available
+    pub fn is_stale(&self, recent: &[Hash]) -> bool {
+        !recent.is_empty() && self.blockhashes.first() != recent.first()
+    }
+}
+
 /// Pending signature waiting for confirmation (internal)
 pub(crate) struct PendingSig {
     signature: Signature,
@@ -227,4 +416,99 @@ mod tests {
         };
         assert!(!result.confirmed);
     }
+
+    #[test]
+    fn test_classify_redundant() {
+        use RedundantOutcome::*;
+
+        let outcomes = classify_redundant(&[
+            SigStatus::Missing,
+            SigStatus::Landed,
+            SigStatus::AlreadyDeployed,
+            SigStatus::Failed,
+        ]);
+        assert_eq!(outcomes, vec![Duplicate, Landed, Duplicate, Failed]);
+
+        // Nothing landed - missing signatures are real failures
+        let outcomes = classify_redundant(&[SigStatus::Missing, SigStatus::Failed]);
+        assert_eq!(outcomes, vec![Failed, Failed]);
+
+        // Split round: a missing signature is only a duplicate if its own deploy landed
+        let outcomes = classify_redundant_per_deploy(
+            &[0, 1, 0, 1],
+            &[SigStatus::Landed, SigStatus::Missing, SigStatus::Missing, SigStatus::Failed],
+        );
+        assert_eq!(outcomes, vec![Landed, Failed, Duplicate, Failed]);
+    }
+
+    #[test]
+    fn test_check_deploy_cap() {
+        assert!(check_deploy_cap(0, DEFAULT_ABSOLUTE_MAX_DEPLOY).is_ok());
+        assert!(check_deploy_cap(DEFAULT_ABSOLUTE_MAX_DEPLOY, DEFAULT_ABSOLUTE_MAX_DEPLOY).is_ok());
+
+        let err = check_deploy_cap(DEFAULT_ABSOLUTE_MAX_DEPLOY + 1, DEFAULT_ABSOLUTE_MAX_DEPLOY).unwrap_err();
+        assert!(err.contains("absolute_max_deploy"));
+
+        // A fat-fingered bankroll (25 SOL instead of 0.25) is refused
+        assert!(check_deploy_cap(25_000_000_000, DEFAULT_ABSOLUTE_MAX_DEPLOY).is_err());
+    }
+
+    #[test]
+    fn test_check_dust_floor() {
+        assert!(check_dust_floor(DEFAULT_DUST_FLOOR, DEFAULT_DUST_FLOOR).is_ok());
+
+        let err = check_dust_floor(DEFAULT_DUST_FLOOR - 1, DEFAULT_DUST_FLOOR).unwrap_err();
+        assert!(err.starts_with("Deploy below dust floor, skipping"));
+
+        // Zero is refused even with the floor turned off
+        assert!(check_dust_floor(0, 0).is_err());
+        assert!(check_dust_floor(1, 0).is_ok());
+    }
+
+    #[test]
+    fn test_check_tx_size() {
+        use solana_sdk::signature::{Keypair, Signer};
+
+        let signer = Keypair::new();
+        let tx = crate::deploy::build_manual_deploy_tx(
+            &signer, &signer.pubkey(), 0, 1, [1_000; 25], false, Hash::new_unique(), 1_000, 200_000,
+        );
+        let estimate = TxSizeEstimate::of(&tx);
+        assert_eq!(estimate.size, bincode::serialize(&tx).unwrap().len());
+        assert_eq!(estimate.accounts, tx.message.account_keys.len());
+        assert_eq!(check_tx_size(estimate), Ok(None));
+
+        let near = TxSizeEstimate { size: MAX_TX_SIZE - 10, accounts: 20 };
+        assert!(check_tx_size(near).unwrap().is_some());
+        let near = TxSizeEstimate { size: 600, accounts: MAX_TX_ACCOUNTS - 1 };
+        assert!(check_tx_size(near).unwrap().is_some());
+
+        assert!(check_tx_size(TxSizeEstimate { size: MAX_TX_SIZE + 1, accounts: 20 }).is_err());
+        assert!(check_tx_size(TxSizeEstimate { size: 600, accounts: MAX_TX_ACCOUNTS + 1 }).is_err());
+    }
+
+    #[test]
+    fn test_presigned_deploy() {
+        use solana_sdk::pubkey::Pubkey;
+
+        let payer = Pubkey::new_unique();
+        let (old, new, newer) = (Hash::new_unique(), Hash::new_unique(), Hash::new_unique());
+        let build = |attempt: u64, bh: Hash| {
+            let mut tx = Transaction::new_with_payer(&[], Some(&payer));
+            tx.message.recent_blockhash = bh;
+            tx.signatures = vec![Signature::from([attempt as u8; 64])];
+            tx
+        };
+
+        let presigned = PresignedDeploy::sign(3, vec![new, old], build);
+        assert_eq!(presigned.transactions.len(), 3);
+        assert!(presigned.transactions.iter().all(|row| row.len() == 2));
+        assert_eq!(presigned.transactions[2][1].message.recent_blockhash, old);
+        assert_eq!(presigned.transactions[2][1].signatures[0], Signature::from([2; 64]));
+
+        // Only a newer blockhash makes it stale; an empty cache keeps what we have
+        assert!(!presigned.is_stale(&[new, old]));
+        assert!(!presigned.is_stale(&[]));
+        assert!(presigned.is_stale(&[newer, new]));
+    }
 }
@@ -0,0 +1,112 @@
+//! Amount parsing for CLI args
+//!
+//! Accepts raw lamports or SOL amounts:
+//! - `250000000` / `250_000_000` → lamports
+//! - `0.25` → SOL (any value with a decimal point)
+//! - `2.5sol` / `2.5 SOL` / `1sol` → SOL
+
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+
+/// Number of decimal places in one SOL
+const SOL_DECIMALS: usize = 9;
+
+/// Parse an amount string into lamports
+///
+/// Usable directly as a clap `value_parser`.
+pub fn parse_lamports(input: &str) -> Result<u64, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err("amount is empty".to_string());
+    }
+
+    let lower = trimmed.to_ascii_lowercase();
+    let (number, is_sol) = match lower.strip_suffix("sol") {
+        Some(n) => (n.trim_end(), true),
+        None => (lower.as_str(), lower.contains('.')),
+    };
+
+    if number.is_empty() {
+        return Err(format!("'{}' has no numeric value", input));
+    }
+    if number.contains(['e', '+', '-']) {
+        return Err(format!("'{}' must be a plain decimal (no sign or scientific notation)", input));
+    }
+
+    let digits: String = number.chars().filter(|c| *c != '_').collect();
+    if !digits.chars().all(|c| c.is_ascii_digit() || c == '.') || digits.matches('.').count() > 1 {
+        return Err(format!("'{}' is not a valid amount", input));
+    }
+
+    if is_sol {
+        sol_to_lamports(&digits).ok_or_else(|| format!("'{}' is out of range or too precise", input))
+    } else {
+        digits.parse::<u64>().map_err(|_| format!("'{}' is out of range for lamports", input))
+    }
+}
+
+/// Convert a validated decimal SOL string to lamports without floating point
+fn sol_to_lamports(digits: &str) -> Option<u64> {
+    let (whole, frac) = digits.split_once('.').unwrap_or((digits, ""));
+    if whole.is_empty() && frac.is_empty() {
+        return None;
+    }
+    // Sub-lamport precision would be silently truncated
+    if frac.len() > SOL_DECIMALS {
+        return None;
+    }
+
+    let whole: u64 = if whole.is_empty() { 0 } else { whole.parse().ok()? };
+    let frac: u64 = if frac.is_empty() {
+        0
+    } else {
+        format!("{:0<width$}", frac, width = SOL_DECIMALS).parse().ok()?
+    };
+
+    whole.checked_mul(LAMPORTS_PER_SOL)?.checked_add(frac)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_raw_lamports() {
+        assert_eq!(parse_lamports("100000000"), Ok(100_000_000));
+        assert_eq!(parse_lamports("0_010_000_000"), Ok(10_000_000));
+        assert_eq!(parse_lamports("0"), Ok(0));
+    }
+
+    #[test]
+    fn test_parse_fractional_sol() {
+        assert_eq!(parse_lamports("2.5sol"), Ok(2_500_000_000));
+        assert_eq!(parse_lamports("2.5 SOL"), Ok(2_500_000_000));
+        assert_eq!(parse_lamports("1sol"), Ok(LAMPORTS_PER_SOL));
+        assert_eq!(parse_lamports("0.01"), Ok(10_000_000));
+        assert_eq!(parse_lamports(".5"), Ok(500_000_000));
+        assert_eq!(parse_lamports("0.000000001"), Ok(1));
+    }
+
+    #[test]
+    fn test_rejects_scientific_notation_and_signs() {
+        assert!(parse_lamports("1e9").is_err());
+        assert!(parse_lamports("2.5e-3sol").is_err());
+        assert!(parse_lamports("-1").is_err());
+        assert!(parse_lamports("+0.5").is_err());
+    }
+
+    #[test]
+    fn test_rejects_overflow_and_excess_precision() {
+        assert!(parse_lamports("18446744073709551616").is_err());
+        assert!(parse_lamports("18446744074sol").is_err());
+        assert!(parse_lamports("0.0000000001").is_err());
+    }
+
+    #[test]
+    fn test_rejects_malformed() {
+        assert!(parse_lamports("").is_err());
+        assert!(parse_lamports("sol").is_err());
+        assert!(parse_lamports("1.2.3").is_err());
+        assert!(parse_lamports("abc").is_err());
+        assert!(parse_lamports(".").is_err());
+    }
+}
@@ -0,0 +1,171 @@
+//! Verify constants - Compare compiled addresses against the chain
+//!
+//! The instruction builders use program ids, PDAs and addresses compiled into
+//! `consts.rs` / `ore_api.rs`. `verify-constants` re-derives them for the
+//! selected network, compares them with the compiled values, and fetches the
+//! accounts they point at to make sure they exist with the expected owner and
+//! layout. A mismatch means the program was redeployed or the constants drifted.
+
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use steel::AccountDeserialize;
+
+use evore::entropy_api;
+use evore::network::NetworkConfig;
+use evore::ore_api::{self, Board, Config, Treasury};
+
+use crate::client::EvoreClient;
+
+/// Result of one check: Ok(detail) or Err(mismatch)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstantCheck {
+    pub name: &'static str,
+    pub result: Result<String, String>,
+}
+
+impl ConstantCheck {
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Compiled address vs the one derived for this network
+fn address_check(name: &'static str, compiled: Pubkey, derived: Pubkey) -> ConstantCheck {
+    let result = if compiled == derived {
+        Ok(compiled.to_string())
+    } else {
+        Err(format!("compiled {} but derived {}", compiled, derived))
+    };
+    ConstantCheck { name, result }
+}
+
+/// Derived PDAs and configured ids against the compiled constants
+pub fn compiled_address_checks(network: &NetworkConfig) -> Vec<ConstantCheck> {
+    vec![
+        address_check("ORE program id", ore_api::PROGRAM_ID, network.ore_program_id),
+        address_check("Evore program id", evore::ID, network.evore_program_id),
+        address_check("ORE mint", ore_api::MINT_ADDRESS, network.ore_mint),
+        address_check("Board PDA", ore_api::board_pda().0, network.board_pda().0),
+        address_check("Config PDA", ore_api::config_pda().0, network.config_pda().0),
+        address_check("Treasury address", ore_api::TREASURY_ADDRESS, network.treasury_pda().0),
+        address_check("Treasury tokens", ore_api::treasury_tokens_address(), network.treasury_tokens_address()),
+    ]
+}
+
+/// Accounts `account_checks` expects, in order
+pub fn checked_accounts(network: &NetworkConfig) -> [Pubkey; 8] {
+    [
+        network.ore_program_id,
+        network.evore_program_id,
+        network.config_pda().0,
+        network.board_pda().0,
+        network.treasury_pda().0,
+        network.treasury_tokens_address(),
+        network.ore_mint,
+        network.entropy_var_address(),
+    ]
+}
+
+/// `account` exists and is owned by `owner`
+fn owned_by<'a>(account: &'a Option<Account>, owner: &Pubkey) -> Result<&'a Account, String> {
+    match account {
+        None => Err("account not found".to_string()),
+        Some(a) if a.owner != *owner => Err(format!("owned by {}, expected {}", a.owner, owner)),
+        Some(a) => Ok(a),
+    }
+}
+
+fn program_check(name: &'static str, account: &Option<Account>) -> ConstantCheck {
+    let result = match account {
+        None => Err("program not found".to_string()),
+        Some(a) if !a.executable => Err("account is not executable".to_string()),
+        Some(_) => Ok("deployed".to_string()),
+    };
+    ConstantCheck { name, result }
+}
+
+/// Fetched accounts (as ordered by `checked_accounts`) against what the builders assume
+pub fn account_checks(network: &NetworkConfig, accounts: &[Option<Account>]) -> Vec<ConstantCheck> {
+    let get = |i: usize| accounts.get(i).cloned().flatten();
+    let ore = network.ore_program_id;
+
+    let config = owned_by(&get(2), &ore).and_then(|a| {
+        let config = Config::try_from_bytes(&a.data).map_err(|e| format!("not a Config: {}", e))?;
+        let entropy_var = network.entropy_var_address();
+        if config.var_address == entropy_var {
+            Ok(format!("var_address {}", config.var_address))
+        } else {
+            Err(format!("var_address {} but deploys pass {}", config.var_address, entropy_var))
+        }
+    });
+    let board = owned_by(&get(3), &ore).and_then(|a| {
+        let board = Board::try_from_bytes(&a.data).map_err(|e| format!("not a Board: {}", e))?;
+        Ok(format!("round {}", board.round_id))
+    });
+    let treasury = owned_by(&get(4), &ore).and_then(|a| {
+        Treasury::try_from_bytes(&a.data).map_err(|e| format!("not a Treasury: {}", e))?;
+        Ok("ok".to_string())
+    });
+    let treasury_tokens = owned_by(&get(5), &spl_token::ID).and_then(|a| {
+        let mint = a.data.get(..32).map(Pubkey::try_from).and_then(Result::ok);
+        match mint {
+            Some(mint) if mint == network.ore_mint => Ok(format!("mint {}", mint)),
+            Some(mint) => Err(format!("holds mint {}, expected {}", mint, network.ore_mint)),
+            None => Err("not a token account".to_string()),
+        }
+    });
+    let mint = owned_by(&get(6), &spl_token::ID).map(|_| "ok".to_string());
+    let entropy_var = owned_by(&get(7), &entropy_api::PROGRAM_ID).map(|_| "ok".to_string());
+
+    vec![
+        program_check("ORE program account", &get(0)),
+        program_check("Evore program account", &get(1)),
+        ConstantCheck { name: "Config account", result: config },
+        ConstantCheck { name: "Board account", result: board },
+        ConstantCheck { name: "Treasury account", result: treasury },
+        ConstantCheck { name: "Treasury tokens account", result: treasury_tokens },
+        ConstantCheck { name: "ORE mint account", result: mint },
+        ConstantCheck { name: "Entropy var account", result: entropy_var },
+    ]
+}
+
+/// Run every check against the client's network
+pub fn verify_constants(client: &EvoreClient) -> Result<Vec<ConstantCheck>, Box<dyn std::error::Error>> {
+    let network = client.network();
+    let accounts = client.get_multiple_accounts(&checked_accounts(network))?;
+    let mut checks = compiled_address_checks(network);
+    checks.extend(account_checks(network, &accounts));
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compiled_address_checks() {
+        let checks = compiled_address_checks(&NetworkConfig::mainnet());
+        assert!(checks.iter().all(ConstantCheck::is_ok));
+
+        let moved = NetworkConfig::mainnet().with_overrides(Some(Pubkey::new_unique()), None, None);
+        let failed: Vec<_> = compiled_address_checks(&moved)
+            .into_iter()
+            .filter(|c| !c.is_ok())
+            .map(|c| c.name)
+            .collect();
+        assert_eq!(failed, vec!["ORE program id", "Board PDA", "Config PDA", "Treasury address", "Treasury tokens"]);
+    }
+
+    #[test]
+    fn test_account_checks_missing_and_wrong_owner() {
+        let network = NetworkConfig::mainnet();
+        let mut accounts = vec![None; 8];
+        accounts[0] = Some(Account { executable: true, owner: Pubkey::new_unique(), ..Account::default() });
+        accounts[6] = Some(Account { owner: Pubkey::new_unique(), ..Account::default() });
+
+        let checks = account_checks(&network, &accounts);
+        assert!(checks[0].is_ok());
+        assert_eq!(checks[1].result, Err("program not found".to_string()));
+        assert_eq!(checks[2].result, Err("account not found".to_string()));
+        assert!(checks[6].result.as_ref().unwrap_err().starts_with("owned by"));
+    }
+}
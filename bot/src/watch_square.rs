@@ -0,0 +1,141 @@
+//! Watch Square - Minimal live view of one square
+//!
+//! `evore-bot watch-square --square <i>` follows the board (websocket) and
+//! the round (RPC polling) with the same trackers the bots use, and rewrites
+//! a single status line in place: the square's stake and miners, its share
+//! of the pot, its rank among the 25 squares and the slots left. No config,
+//! keypairs or dashboard; Ctrl+C to stop.
+
+use std::io::Write;
+use std::time::Duration;
+
+use evore::board::Board25;
+use evore::ore_api::Board;
+
+use crate::board_tracker::BoardTracker;
+use crate::client::EvoreClient;
+use crate::round_tracker::RoundTracker;
+use crate::shutdown;
+use crate::slot_tracker::SlotTracker;
+
+/// How often the line is redrawn
+const REFRESH_INTERVAL: Duration = Duration::from_millis(250);
+
+/// One square of the live round
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SquareView {
+    pub amount: u64,
+    pub miners: u64,
+    /// Fraction of the round's total deployed (0.0 - 1.0)
+    pub share: f64,
+    /// 1 = most deployed; tied squares share a rank
+    pub rank: usize,
+    pub total: u64,
+}
+
+/// The square's standing in the round
+pub fn square_view(deployed: &[u64; 25], count: &[u64; 25], square: usize) -> SquareView {
+    let board = Board25::new(*deployed);
+    let amount = deployed[square];
+    SquareView {
+        amount,
+        miners: count[square],
+        share: board.share(square),
+        rank: 1 + deployed.iter().filter(|&&d| d > amount).count(),
+        total: board.total(),
+    }
+}
+
+/// Slots left in the round; None until the first deploy starts it
+pub fn slots_left(board: &Board, slot: u64) -> Option<u64> {
+    (board.end_slot != u64::MAX).then(|| board.end_slot.saturating_sub(slot))
+}
+
+fn render(square: usize, round_id: u64, view: &SquareView, slots_left: Option<u64>) -> String {
+    let slots = match slots_left {
+        Some(0) => "ended".to_string(),
+        Some(left) => format!("{} slots left", left),
+        None => "waiting for first deploy".to_string(),
+    };
+    format!(
+        "Round {} | square {}: {:.4} SOL, {} miners, {:.1}% of {:.4} SOL, rank {}/25 | {}",
+        round_id,
+        square,
+        view.amount as f64 / 1e9,
+        view.miners,
+        view.share * 100.0,
+        view.total as f64 / 1e9,
+        view.rank,
+        slots,
+    )
+}
+
+/// Redraw `square`'s line until Ctrl+C
+pub async fn run(rpc_url: &str, ws_url: &str, square: usize) -> Result<(), Box<dyn std::error::Error>> {
+    let client = EvoreClient::new(rpc_url);
+
+    let slot_tracker = SlotTracker::new(ws_url);
+    slot_tracker.start_slot_subscription()?;
+    let board_tracker = BoardTracker::new(ws_url);
+    board_tracker.start_subscription().map_err(|e| e.to_string())?;
+    let round_tracker = RoundTracker::new(rpc_url, client.get_rps_tracker());
+    round_tracker.start();
+
+    // The board websocket only pushes on change, so start from an RPC read
+    let mut board = client.get_board()?;
+    round_tracker.switch_round(board.round_id);
+
+    let shutdown = shutdown::spawn_shutdown_handler();
+    println!("Watching square {} (Ctrl+C to stop)", square);
+
+    while !shutdown.is_shutdown() {
+        if let Some(latest) = board_tracker.get_board() {
+            board = latest;
+        }
+        round_tracker.switch_round(board.round_id);
+
+        let slot = slot_tracker.get_slot();
+        let line = match round_tracker.get_round() {
+            _ if slot == 0 => "Connecting to websocket...".to_string(),
+            Some(round) if round.id == board.round_id => {
+                let view = square_view(&round.deployed, &round.count, square);
+                render(square, board.round_id, &view, slots_left(&board, slot))
+            }
+            _ => format!("Round {} | loading...", board.round_id),
+        };
+        print!("\r\x1b[2K{}", line);
+        std::io::stdout().flush()?;
+
+        tokio::time::sleep(REFRESH_INTERVAL).await;
+    }
+    println!();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_square_view() {
+        let mut deployed = [0u64; 25];
+        let mut count = [0u64; 25];
+        deployed[0] = 5_000_000_000;
+        deployed[3] = 2_000_000_000;
+        deployed[7] = 2_000_000_000;
+        deployed[9] = 1_000_000_000;
+        count[3] = 4;
+
+        let view = square_view(&deployed, &count, 3);
+        assert_eq!((view.amount, view.miners, view.rank, view.total), (2_000_000_000, 4, 2, 10_000_000_000));
+        assert!((view.share - 0.2).abs() < 1e-9);
+        // Ties share the rank; the next square down skips past them
+        assert_eq!(square_view(&deployed, &count, 7).rank, 2);
+        assert_eq!(square_view(&deployed, &count, 9).rank, 4);
+        assert_eq!(square_view(&deployed, &count, 12).rank, 5);
+
+        // Empty round
+        let view = square_view(&[0; 25], &[0; 25], 0);
+        assert_eq!((view.rank, view.share), (1, 0.0));
+    }
+}
@@ -1,7 +1,8 @@
 //! Configuration for the crank program
 
-use clap::{Parser, Subcommand};
-use solana_sdk::{pubkey::Pubkey, signature::Keypair};
+use clap::{Parser, Subcommand, ValueEnum};
+use evore::network::{Network, NetworkConfig};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Keypair};
 use std::path::PathBuf;
 
 /// Evore Autodeploy Crank
@@ -13,9 +14,33 @@ pub struct Config {
     #[command(subcommand)]
     pub command: Option<Command>,
     
-    /// RPC URL
-    #[arg(long, env = "RPC_URL", default_value = "https://api.mainnet-beta.solana.com")]
-    pub rpc_url: String,
+    /// RPC URL (defaults to the public endpoint of --network)
+    #[arg(long, env = "RPC_URL")]
+    rpc_url: Option<String>,
+    
+    /// Extra HTTP headers for RPC requests as a JSON object, e.g. '{"x-token":"..."}'
+    #[arg(long, env = "RPC_HEADERS")]
+    pub rpc_headers: Option<String>,
+    
+    /// Extra HTTP header for RPC requests (repeatable, key=value; wins over RPC_HEADERS)
+    #[arg(long = "rpc-header", value_name = "KEY=VALUE")]
+    pub rpc_header: Vec<String>,
+    
+    /// Cluster preset for program ids and mint (mainnet | devnet)
+    #[arg(long, env = "NETWORK", default_value = "mainnet")]
+    pub network: Network,
+    
+    /// Override the ORE program id
+    #[arg(long, env = "ORE_PROGRAM_ID")]
+    pub ore_program_id: Option<Pubkey>,
+    
+    /// Override the Evore program id
+    #[arg(long, env = "EVORE_PROGRAM_ID")]
+    pub evore_program_id: Option<Pubkey>,
+    
+    /// Override the ORE mint
+    #[arg(long, env = "ORE_MINT")]
+    pub ore_mint: Option<Pubkey>,
     
     /// Deploy authority keypair path
     #[arg(long, env = "DEPLOY_AUTHORITY_KEYPAIR")]
@@ -33,6 +58,57 @@ pub struct Config {
     #[arg(long, env = "POLL_INTERVAL_MS", default_value = "400")]
     pub poll_interval_ms: u64,
     
+    /// Commitment level at which pending transactions are marked confirmed
+    #[arg(long, env = "CONFIRM_COMMITMENT", value_enum, default_value = "confirmed")]
+    pub confirm_commitment: ConfirmCommitment,
+    
+    /// Seconds a transaction may stay pending (not found on chain) before it's marked dropped
+    #[arg(long, env = "PENDING_TX_TIMEOUT_SECS", default_value = "90")]
+    pub pending_tx_timeout_secs: u64,
+    
+    /// Seconds to wait for pending transactions to resolve after the first Ctrl-C
+    /// before exiting anyway (a second Ctrl-C exits immediately)
+    #[arg(long, env = "DRAIN_TIMEOUT_SECS", default_value = "120")]
+    pub drain_timeout_secs: u64,
+    
+    /// Observe only: refuse to send any transaction and log its instructions instead
+    #[arg(long, env = "SAFE_MODE", value_parser = clap::builder::BoolishValueParser::new())]
+    pub safe_mode: bool,
+    
+    /// Keep re-scanning on startup until a deployer names us as deploy_authority
+    /// instead of exiting when there are none
+    #[arg(long, env = "WAIT_FOR_DEPLOYERS", value_parser = clap::builder::BoolishValueParser::new())]
+    pub wait_for_deployers: bool,
+    
+    /// Seconds between deployer scans while waiting (--wait-for-deployers)
+    #[arg(long, env = "DEPLOYER_RESCAN_SECS", default_value = "30")]
+    pub deployer_rescan_secs: u64,
+    
+    /// Skip the startup sweep that checkpoints miners left behind while the crank was down
+    #[arg(long, env = "NO_CATCHUP")]
+    pub no_catchup: bool,
+    
+    /// Deploy only: never checkpoint or recycle (also skips the startup catch-up).
+    /// Miners behind on checkpoints can't deploy until they're checkpointed elsewhere.
+    #[arg(long, env = "NO_CHECKPOINT")]
+    pub no_checkpoint: bool,
+    
+    /// Every N rounds, recycle claimable SOL rewards for all managed miners into
+    /// their managed_miner_auth (off by default). ORE can only be claimed by the
+    /// manager authority, so it isn't swept.
+    #[arg(long, env = "CLAIM_INTERVAL_ROUNDS")]
+    pub claim_interval_rounds: Option<u64>,
+    
+    /// Consolidate per-miner LUTs into shared ones (51 miners each) on startup
+    /// when more than this many LUTs are in use
+    #[arg(long, env = "MAX_LUTS")]
+    pub max_luts: Option<usize>,
+    
+    /// Only manage deployers whose manager authority is in this comma-separated
+    /// list (unset = every deployer that names us as deploy_authority)
+    #[arg(long, env = "MANAGER_ALLOWLIST", value_delimiter = ',')]
+    pub manager_allowlist: Vec<Pubkey>,
+    
     /// [LEGACY] Address Lookup Table for manual LUT commands (show-lut, deactivate-lut, close-lut)
     /// Not needed for 'run' - the crank auto-discovers and creates LUTs as needed
     #[arg(long, env = "LUT_ADDRESS")]
@@ -72,11 +148,75 @@ pub enum Command {
     DeactivateUnused,
     /// Show deactivating LUTs status and close any that are ready
     CleanupDeactivated,
+    /// Merge per-miner LUTs into consolidated LUTs and deactivate the old ones
+    ConsolidateLuts,
+    /// Write the shared and per-miner LUT addresses to a JSON file
+    ExportLuts {
+        /// Output file
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Load LUT addresses from an export-luts file into the database after validating them on-chain
+    ImportLuts {
+        /// File written by export-luts
+        #[arg(long = "in")]
+        input: PathBuf,
+    },
     /// Check all Evore program accounts
     CheckAccounts,
+    /// Build and simulate (without sending) checkpoint+recycle for our deployers' miners
+    SimulateCheckpoint {
+        /// Only this manager (default: every deployer we manage)
+        #[arg(long)]
+        manager: Option<Pubkey>,
+    },
+    /// Print the most recent deploy audit entries
+    AuditLog {
+        /// Number of entries to show
+        #[arg(long, default_value = "20")]
+        limit: i64,
+        /// Keep polling for new entries
+        #[arg(long)]
+        follow: bool,
+    },
+    /// Histogram of how many slots before round end confirmed deploys landed
+    Metrics {
+        /// Only deploys from the last N hours
+        #[arg(long, default_value = "24")]
+        hours: u64,
+    },
+}
+
+/// Commitment level used when checking pending transaction status
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmCommitment {
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+impl ConfirmCommitment {
+    pub fn commitment_config(&self) -> CommitmentConfig {
+        match self {
+            ConfirmCommitment::Processed => CommitmentConfig::processed(),
+            ConfirmCommitment::Confirmed => CommitmentConfig::confirmed(),
+            ConfirmCommitment::Finalized => CommitmentConfig::finalized(),
+        }
+    }
 }
 
 impl Config {
+    /// RPC URL, falling back to the selected network's public endpoint
+    pub fn rpc_url(&self) -> &str {
+        self.rpc_url.as_deref().unwrap_or_else(|| self.network.default_rpc_url())
+    }
+    
+    /// Network preset with any explicit address overrides applied
+    pub fn network_config(&self) -> NetworkConfig {
+        NetworkConfig::for_network(self.network)
+            .with_overrides(self.ore_program_id, self.evore_program_id, self.ore_mint)
+    }
+    
     /// Load the deploy authority keypair from the configured path
     pub fn load_keypair(&self) -> Result<Keypair, Box<dyn std::error::Error>> {
         let keypair_data = std::fs::read_to_string(&self.keypair_path)?;
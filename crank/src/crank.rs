@@ -9,34 +9,127 @@ use evore::{
         // Legacy instructions (kept for backward compatibility)
         mm_autodeploy, mm_autocheckpoint, recycle_sol,
     },
-    ore_api::{board_pda, miner_pda, round_pda, Board, Miner, Round},
-    state::{managed_miner_auth_pda, Deployer},
+    network::NetworkConfig,
+    ore_api::{Board, Config as OreConfig, Miner, Round},
+    state::{Deployer, Manager},
+};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcSimulateTransactionAccountsConfig, RpcSimulateTransactionConfig},
 };
-use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
     commitment_config::CommitmentConfig,
     compute_budget::ComputeBudgetInstruction,
     hash::Hash,
+    instruction::Instruction,
+    message::{Message, VersionedMessage},
     pubkey::Pubkey,
     signature::{Keypair, Signer},
     system_instruction,
     transaction::Transaction,
 };
 use sqlx::{Pool, Sqlite};
+use std::collections::{HashMap, HashSet};
 use std::time::{SystemTime, UNIX_EPOCH};
 use steel::AccountDeserialize;
 use tracing::{debug, error, info, warn};
 
 use crate::{
-    config::{Config, DeployerInfo},
+    config::{ConfirmCommitment, Config, DeployerInfo},
     db,
-    lut::{LutManager, LutRegistry, get_miner_accounts, get_miner_auth_pda},
-    sender::TxSender,
+    lut::{LutManager, LutRegistry, MINER_ACCOUNT_COUNT, consolidation_groups, get_miner_accounts, get_miner_auth_pda},
+    sender::{SendError, TxSender},
 };
 
+/// Max accounts a transaction may reference, lookup table entries included
+pub const MAX_TX_ACCOUNTS: usize = 64;
+
+/// Accounts every batched autodeploy references: the 10 static shared
+/// accounts (see `get_static_shared_accounts`) plus the current round
+const AUTODEPLOY_BASE_ACCOUNTS: usize = 11;
+
+/// Accounts referenced by a batched autodeploy
+///
+/// `checkpoint_rounds` is the number of distinct checkpoint rounds other than
+/// the current one. `compute_budget` adds the ComputeBudget program id for the
+/// compute limit/price instructions; an invoked program id is always a static
+/// key (it can't be loaded from a LUT), and LUT entries count toward the limit
+/// anyway, so it adds exactly one account either way.
+pub const fn autodeploy_account_count(deploys: usize, checkpoint_rounds: usize, compute_budget: bool) -> usize {
+    AUTODEPLOY_BASE_ACCOUNTS + checkpoint_rounds + deploys * MINER_ACCOUNT_COUNT + compute_budget as usize
+}
+
+/// Accounts referenced by a compiled transaction (static keys + LUT entries)
+pub fn tx_account_count(message: &VersionedMessage) -> usize {
+    match message {
+        VersionedMessage::V0(msg) => {
+            msg.account_keys.len() +
+            msg.address_table_lookups.iter().map(|l| l.writable_indexes.len() + l.readonly_indexes.len()).sum::<usize>()
+        }
+        VersionedMessage::Legacy(msg) => msg.account_keys.len(),
+    }
+}
+
+/// Base fee per signature (lamports)
+const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// Compute limit for one checkpoint, with or without the recycle
+pub const fn checkpoint_recycle_cu_limit(should_recycle: bool) -> u32 {
+    // ~150k CU for checkpoint + recycle, ~100k for checkpoint only
+    if should_recycle { 200_000 } else { 150_000 }
+}
+
+/// Instructions for one miner's checkpoint, plus the recycle if `should_recycle`
+pub fn checkpoint_recycle_instructions(
+    payer: Pubkey,
+    manager: Pubkey,
+    auth_id: u64,
+    checkpoint_round: u64,
+    should_recycle: bool,
+    priority_fee: u64,
+) -> Vec<Instruction> {
+    let mut instructions = vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(checkpoint_recycle_cu_limit(should_recycle)),
+        ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
+        mm_autocheckpoint(payer, manager, checkpoint_round, auth_id),
+    ];
+    // Only include recycle if there's SOL to recycle
+    if should_recycle {
+        instructions.push(recycle_sol(payer, manager, auth_id));
+    }
+    instructions
+}
+
+/// Base fee plus the priority fee paid at `cu_limit` (lamports)
+pub fn transaction_fee(signatures: u64, cu_limit: u32, priority_fee: u64) -> u64 {
+    signatures * LAMPORTS_PER_SIGNATURE + (cu_limit as u64 * priority_fee).div_ceil(1_000_000)
+}
+
+/// What a checkpoint+recycle would do, from `Crank::simulate_checkpoint_recycle`
+#[derive(Debug, Clone)]
+pub struct CheckpointRecycleEstimate {
+    /// Round the checkpoint targets (the miner's last played round)
+    pub checkpoint_round: u64,
+    /// That round isn't checkpointed yet
+    pub pending: bool,
+    pub instructions: usize,
+    pub accounts: usize,
+    pub cu_limit: u32,
+    /// Compute units the simulation used
+    pub units_consumed: Option<u64>,
+    /// Base fee plus priority fee at `cu_limit` (lamports)
+    pub fee: u64,
+    /// SOL recycled into managed_miner_auth (lamports)
+    pub reclaim: u64,
+    /// Simulation error, if the transaction would fail
+    pub error: Option<String>,
+}
+
 /// The crank runner
 pub struct Crank {
     config: Config,
+    network: NetworkConfig,
     rpc_client: RpcClient,
     deploy_authority: Keypair,
     sender: TxSender,
@@ -48,20 +141,36 @@ impl Crank {
         let deploy_authority = config.load_keypair()
             .map_err(|e| CrankError::KeypairLoad(e.to_string()))?;
         
-        let rpc_client = RpcClient::new_with_commitment(
-            config.rpc_url.clone(),
+        // Deploy instructions and LUT contents are built against the compiled ids
+        let network = config.network_config();
+        if !network.uses_compiled_program_ids() {
+            return Err(CrankError::UnsupportedNetwork(format!(
+                "program id overrides (ore: {}, evore: {}) are not supported by the crank",
+                network.ore_program_id, network.evore_program_id
+            )));
+        }
+        
+        let rpc_client = crate::sender::rpc_client(
+            config.rpc_url(),
             CommitmentConfig::confirmed(),
         );
         
-        let sender = TxSender::new(config.rpc_url.clone());
+        let sender = TxSender::new(config.rpc_url().to_string());
         
-        Ok(Self {
+        let crank = Self {
             config,
+            network,
             rpc_client,
             deploy_authority,
             sender,
             db_pool,
-        })
+        };
+        
+        // Fail now rather than on every deploy if ORE moved an account we hardcode
+        let ore_config = crank.get_ore_config()?;
+        network.check_ore_config(&ore_config).map_err(CrankError::ConfigMismatch)?;
+        
+        Ok(crank)
     }
     
     /// Send a simple test transaction (0 lamport transfer to self)
@@ -193,7 +302,7 @@ impl Crank {
             }
         }
         
-        Ok(deployers)
+        apply_manager_allowlist(&self.rpc_client, deployers, &self.config.manager_allowlist)
     }
     
     /// Check all Evore program accounts
@@ -259,7 +368,7 @@ impl Crank {
     
     /// Get current ORE board state
     pub fn get_board(&self) -> Result<(Board, u64), CrankError> {
-        let (board_address, _) = board_pda();
+        let (board_address, _) = self.network.board_pda();
         
         let account = self.rpc_client.get_account(&board_address)
             .map_err(|e| CrankError::Rpc(e.to_string()))?;
@@ -273,9 +382,22 @@ impl Crank {
         Ok((*board, current_slot))
     }
     
+    /// Get ORE Config account
+    pub fn get_ore_config(&self) -> Result<OreConfig, CrankError> {
+        let (config_address, _) = self.network.config_pda();
+        
+        let account = self.rpc_client.get_account(&config_address)
+            .map_err(|e| CrankError::Rpc(e.to_string()))?;
+        
+        let config = OreConfig::try_from_bytes(&account.data)
+            .map_err(|e| CrankError::Deserialize(format!("{:?}", e)))?;
+        
+        Ok(*config)
+    }
+    
     /// Get current ORE round state
     pub fn get_round(&self, round_id: u64) -> Result<Round, CrankError> {
-        let (round_address, _) = round_pda(round_id);
+        let (round_address, _) = self.network.round_pda(round_id);
         
         let account = self.rpc_client.get_account(&round_address)
             .map_err(|e| CrankError::Rpc(e.to_string()))?;
@@ -288,7 +410,7 @@ impl Crank {
     
     /// Get balance for a managed miner auth PDA
     pub fn get_miner_balance(&self, deployer: &DeployerInfo, auth_id: u64) -> Result<u64, CrankError> {
-        let (managed_miner_auth, _) = managed_miner_auth_pda(deployer.manager_address, auth_id);
+        let (managed_miner_auth, _) = self.network.managed_miner_auth_pda(deployer.manager_address, auth_id);
         self.rpc_client.get_balance(&managed_miner_auth)
             .map_err(|e| CrankError::Rpc(e.to_string()))
     }
@@ -316,11 +438,11 @@ impl Crank {
         let protocol_fee = DEPLOY_FEE;
         
         // Check managed_miner_auth balance
-        let (managed_miner_auth, _) = managed_miner_auth_pda(deployer.manager_address, auth_id);
+        let (managed_miner_auth, _) = self.network.managed_miner_auth_pda(deployer.manager_address, auth_id);
         let current_auth_balance = self.rpc_client.get_balance(&managed_miner_auth).unwrap_or(0);
         
         // Check if ORE miner exists
-        let (ore_miner_address, _) = miner_pda(managed_miner_auth);
+        let (ore_miner_address, _) = self.network.miner_pda(managed_miner_auth);
         let miner_exists = self.rpc_client.get_account(&ore_miner_address).is_ok();
         
         // Calculate miner rent if account doesn't exist
@@ -379,8 +501,8 @@ impl Crank {
     /// Get miner checkpoint status for a manager/auth_id
     /// Returns (checkpoint_id, last_played_round_id) or None if the miner account doesn't exist yet
     pub fn get_miner_checkpoint_status(&self, manager: Pubkey, auth_id: u64) -> Result<Option<(u64, u64)>, CrankError> {
-        let (managed_miner_auth, _) = managed_miner_auth_pda(manager, auth_id);
-        let (ore_miner_address, _) = miner_pda(managed_miner_auth);
+        let (managed_miner_auth, _) = self.network.managed_miner_auth_pda(manager, auth_id);
+        let (ore_miner_address, _) = self.network.miner_pda(managed_miner_auth);
         
         match self.rpc_client.get_account(&ore_miner_address) {
             Ok(account) => {
@@ -436,29 +558,14 @@ impl Crank {
             .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
             .map_err(|e| CrankError::Rpc(e.to_string()))?;
         
-        let mut instructions = Vec::new();
-        
-        // ~150k CU for checkpoint + recycle, ~100k for checkpoint only
-        let cu_limit = if should_recycle { 200_000 } else { 150_000 };
-        instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(cu_limit));
-        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(self.config.priority_fee));
-        
-        // Checkpoint
-        instructions.push(mm_autocheckpoint(
+        let instructions = checkpoint_recycle_instructions(
             payer.pubkey(),
             deployer.manager_address,
-            checkpoint_round,
             auth_id,
-        ));
-        
-        // Only include recycle if there's SOL to recycle
-        if should_recycle {
-            instructions.push(recycle_sol(
-                payer.pubkey(),
-                deployer.manager_address,
-                auth_id,
-            ));
-        }
+            checkpoint_round,
+            should_recycle,
+            self.config.priority_fee,
+        );
         
         let mut tx = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
         tx.sign(&[payer], recent_blockhash);
@@ -477,6 +584,73 @@ impl Crank {
         }
     }
     
+    /// Build and simulate (never send) a checkpoint+recycle for one miner
+    ///
+    /// Checkpoints the miner's last played round. Returns None if the miner
+    /// account doesn't exist yet.
+    pub fn simulate_checkpoint_recycle(
+        &self,
+        deployer: &DeployerInfo,
+        auth_id: u64,
+    ) -> Result<Option<CheckpointRecycleEstimate>, CrankError> {
+        let payer = self.deploy_authority.pubkey();
+        let (managed_miner_auth, _) = self.network.managed_miner_auth_pda(deployer.manager_address, auth_id);
+        let (ore_miner_address, _) = self.network.miner_pda(managed_miner_auth);
+        
+        let miner = match self.rpc_client.get_account(&ore_miner_address) {
+            Ok(account) => *Miner::try_from_bytes(&account.data)
+                .map_err(|e| CrankError::Deserialize(format!("{:?}", e)))?,
+            Err(e) if e.to_string().contains("AccountNotFound") => return Ok(None),
+            Err(e) => return Err(CrankError::Rpc(e.to_string())),
+        };
+        let auth_balance = self.rpc_client.get_balance(&managed_miner_auth)
+            .map_err(|e| CrankError::Rpc(e.to_string()))?;
+        
+        let instructions = checkpoint_recycle_instructions(
+            payer,
+            deployer.manager_address,
+            auth_id,
+            miner.round_id,
+            true,
+            self.config.priority_fee,
+        );
+        let tx = Transaction::new_unsigned(Message::new(&instructions, Some(&payer)));
+        
+        let sim = self.rpc_client.simulate_transaction_with_config(&tx, RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            commitment: Some(CommitmentConfig::confirmed()),
+            accounts: Some(RpcSimulateTransactionAccountsConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                addresses: vec![managed_miner_auth.to_string()],
+            }),
+            ..Default::default()
+        }).map_err(|e| CrankError::Rpc(e.to_string()))?.value;
+        
+        // Recycled SOL lands in managed_miner_auth; without a simulated balance
+        // fall back to the rewards already credited
+        let simulated_balance = sim.accounts.as_ref()
+            .and_then(|accounts| accounts.first().cloned().flatten())
+            .map(|account| account.lamports);
+        let reclaim = match (&sim.err, simulated_balance) {
+            (None, Some(balance)) => balance.saturating_sub(auth_balance),
+            _ => miner.rewards_sol,
+        };
+        
+        let cu_limit = checkpoint_recycle_cu_limit(true);
+        Ok(Some(CheckpointRecycleEstimate {
+            checkpoint_round: miner.round_id,
+            pending: miner.checkpoint_id < miner.round_id,
+            instructions: tx.message.instructions.len(),
+            accounts: tx.message.account_keys.len(),
+            cu_limit,
+            units_consumed: sim.units_consumed,
+            fee: transaction_fee(tx.message.header.num_required_signatures as u64, cu_limit, self.config.priority_fee),
+            reclaim,
+            error: sim.err.map(|e| e.to_string()),
+        }))
+    }
+    
     /// Execute batched checkpoint+recycle for multiple deployers
     pub async fn execute_batched_checkpoint_recycle(
         &self,
@@ -523,6 +697,75 @@ impl Crank {
         }
     }
     
+    /// Sweep SOL rewards into managed_miner_auth for multiple deployers
+    /// Miners behind on checkpoints are checkpointed first in the same transaction.
+    /// Each claim is recorded in the database once the transaction confirms.
+    pub async fn execute_batched_claim_sol(
+        &self,
+        claims: &[(&DeployerInfo, u64, Option<u64>, u64)], // (deployer, auth_id, checkpoint_round, rewards_sol)
+        round_id: u64,
+    ) -> Result<String, CrankError> {
+        if claims.is_empty() {
+            return Err(CrankError::Send("No claims to batch".to_string()));
+        }
+        
+        let payer = &self.deploy_authority;
+        
+        let (recent_blockhash, _) = self.rpc_client
+            .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
+            .map_err(|e| CrankError::Rpc(e.to_string()))?;
+        
+        let mut instructions = Vec::new();
+        
+        // ~150k CU per checkpoint+recycle
+        let cu_limit = (claims.len() as u32 * 150_000).min(1_400_000);
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(cu_limit));
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(self.config.priority_fee));
+        
+        for (deployer, auth_id, checkpoint_round, _) in claims {
+            if let Some(checkpoint_round) = checkpoint_round {
+                instructions.push(mm_autocheckpoint(
+                    payer.pubkey(),
+                    deployer.manager_address,
+                    *checkpoint_round,
+                    *auth_id,
+                ));
+            }
+            instructions.push(recycle_sol(
+                payer.pubkey(),
+                deployer.manager_address,
+                *auth_id,
+            ));
+        }
+        
+        let mut tx = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+        tx.sign(&[payer], recent_blockhash);
+        
+        let sig = self.sender.send_and_confirm_rpc(&tx, 60).await
+            .map_err(|e| CrankError::Send(e.to_string()))?
+            .to_string();
+        
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        for (deployer, auth_id, _, rewards_sol) in claims {
+            if let Err(e) = db::insert_claim(
+                &self.db_pool,
+                now,
+                &sig,
+                &deployer.manager_address.to_string(),
+                *auth_id,
+                round_id,
+                *rewards_sol,
+            ).await {
+                warn!("Failed to record claim for {}: {}", deployer.manager_address, e);
+            }
+        }
+        
+        Ok(sig)
+    }
+    
     /// Execute autodeploy WITHOUT checkpoint (checkpoint done separately)
     pub async fn execute_autodeploy_no_checkpoint(
         &self,
@@ -890,15 +1133,16 @@ impl Crank {
             let signature = solana_sdk::signature::Signature::from_str(&tx.signature)
                 .map_err(|e| CrankError::Parse(e.to_string()))?;
             
-            // Check transaction status first
+            // Check transaction status at the configured commitment
+            let confirm_commitment = self.config.confirm_commitment;
             match self.rpc_client.get_signature_status_with_commitment(
                 &signature,
-                CommitmentConfig::confirmed(),
+                confirm_commitment.commitment_config(),
             ) {
                 Ok(Some(result)) => {
                     match result {
                         Ok(()) => {
-                            info!("Transaction {} confirmed", tx.signature);
+                            info!("Transaction {} confirmed ({:?})", tx.signature, confirm_commitment);
                             
                             db::update_tx_confirmed(
                                 &self.db_pool,
@@ -908,11 +1152,16 @@ impl Crank {
                                 None,
                             ).await.ok();
                             
-                            // Check finalization
-                            if let Ok(Some(Ok(()))) = self.rpc_client.get_signature_status_with_commitment(
-                                &signature,
-                                CommitmentConfig::finalized(),
-                            ) {
+                            // Check finalization (already implied at finalized commitment)
+                            let finalized = confirm_commitment == ConfirmCommitment::Finalized
+                                || matches!(
+                                    self.rpc_client.get_signature_status_with_commitment(
+                                        &signature,
+                                        CommitmentConfig::finalized(),
+                                    ),
+                                    Ok(Some(Ok(())))
+                                );
+                            if finalized {
                                 info!("Transaction {} finalized", tx.signature);
                                 db::update_tx_finalized(&self.db_pool, &tx.signature, now)
                                     .await
@@ -937,6 +1186,13 @@ impl Crank {
                         db::update_tx_expired(&self.db_pool, &tx.signature)
                             .await
                             .ok();
+                    } else if now - tx.sent_at > self.config.pending_tx_timeout_secs as i64 {
+                        // Still in flight past the timeout - mark dropped so it
+                        // doesn't keep blocking this deployer in later rounds
+                        warn!("Transaction {} dropped (pending for {}s)", tx.signature, now - tx.sent_at);
+                        db::update_tx_dropped(&self.db_pool, &tx.signature)
+                            .await
+                            .ok();
                     } else {
                         debug!("Transaction {} still pending (blockheight {}, valid until {})", 
                             tx.signature, current_blockheight, last_valid);
@@ -1153,6 +1409,33 @@ impl Crank {
         Ok(lut_balance)
     }
     
+    /// Look up the slot a confirmed autodeploy landed in and record how far
+    /// before the round's end_slot that was
+    ///
+    /// Returns the slots before end (negative if it landed after end_slot).
+    pub async fn record_deploy_landing(&self, signature: &str, round_id: u64, end_slot: u64) -> Result<i64, CrankError> {
+        let sig = solana_sdk::signature::Signature::from_str(signature)
+            .map_err(|e| CrankError::Parse(e.to_string()))?;
+        let landed_slot = self.rpc_client.get_signature_statuses(&[sig])
+            .map_err(|e| CrankError::Rpc(e.to_string()))?
+            .value
+            .into_iter()
+            .next()
+            .flatten()
+            .map(|status| status.slot)
+            .ok_or_else(|| CrankError::Rpc(format!("No status for {}", signature)))?;
+        
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        db::insert_deploy_landing(&self.db_pool, now, signature, round_id, end_slot, landed_slot)
+            .await
+            .map_err(|e| CrankError::Database(e.to_string()))?;
+        
+        Ok(end_slot as i64 - landed_slot as i64)
+    }
+    
     /// Get the current slot
     pub fn get_current_slot(&self) -> Result<u64, CrankError> {
         self.rpc_client.get_slot()
@@ -1234,6 +1517,74 @@ impl Crank {
         Ok(())
     }
     
+    /// Deactivate a registry LUT (close it with `cleanup-deactivated` after the cooldown)
+    pub async fn deactivate_lut_for_registry(
+        &self,
+        registry: &LutRegistry,
+        lut_address: Pubkey,
+    ) -> Result<(), CrankError> {
+        let payer = &self.deploy_authority;
+        
+        let recent_blockhash = self.rpc_client.get_latest_blockhash()
+            .map_err(|e| CrankError::Rpc(e.to_string()))?;
+        
+        let instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(50_000),
+            ComputeBudgetInstruction::set_compute_unit_price(self.config.priority_fee),
+            registry.deactivate_lut_instruction(lut_address),
+        ];
+        
+        let tx = LutRegistry::build_versioned_tx_no_lut(payer, instructions, recent_blockhash)
+            .map_err(|e| CrankError::Send(e.to_string()))?;
+        
+        self.sender.send_and_confirm_versioned_rpc(&tx, 60).await
+            .map_err(|e| CrankError::Send(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    /// Move miners with their own LUT into consolidated LUTs (up to 51 miners each)
+    /// and deactivate the per-miner LUTs they replace.
+    /// Returns (consolidated LUTs created, per-miner LUTs deactivated)
+    pub async fn consolidate_luts(&self, registry: &mut LutRegistry) -> Result<(usize, usize), CrankError> {
+        let singles = registry.single_miner_luts();
+        let miner_auths: Vec<Pubkey> = singles.iter().map(|(miner_auth, _)| *miner_auth).collect();
+        let old_luts: HashMap<Pubkey, Pubkey> = singles.into_iter().collect();
+        
+        let mut created = 0;
+        let mut deactivated = 0;
+        
+        for group in consolidation_groups(&miner_auths) {
+            // Reuse the per-miner LUT contents so no deployer lookups are needed
+            let addresses: Vec<Pubkey> = group.iter()
+                .filter_map(|miner_auth| registry.cached_lut(&old_luts[miner_auth]))
+                .flat_map(|lut| lut.addresses.iter().copied())
+                .collect();
+            
+            info!("Consolidating {} miner LUTs ({} addresses)", group.len(), addresses.len());
+            let lut_address = self.create_lut_for_registry(registry).await?;
+            
+            // Wait for LUT to be active
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            
+            self.extend_lut_for_registry(registry, lut_address, addresses.clone()).await?;
+            registry.register_consolidated_lut(lut_address, group, addresses);
+            created += 1;
+            info!("Created consolidated LUT {} for {} miners", lut_address, group.len());
+            
+            // The old LUTs are no longer referenced by the registry
+            for miner_auth in group {
+                let old_lut = old_luts[miner_auth];
+                match self.deactivate_lut_for_registry(registry, old_lut).await {
+                    Ok(()) => deactivated += 1,
+                    Err(e) => warn!("Failed to deactivate replaced LUT {}: {}", old_lut, e),
+                }
+            }
+        }
+        
+        Ok((created, deactivated))
+    }
+    
     /// Ensure the shared LUT exists and has all static accounts
     pub async fn ensure_shared_lut(&self, registry: &mut LutRegistry) -> Result<Pubkey, CrankError> {
         // If no shared LUT, create one
@@ -1321,6 +1672,56 @@ impl Crank {
         Ok(created)
     }
 
+    /// Checkpoint (and recycle) every miner that is behind before the deploy loop starts
+    /// Miners still playing the current round are skipped - it can't be checkpointed yet.
+    /// Batches of `batch_size` are sent together; a failed batch is retried one miner at a time.
+    /// Returns count of miners checkpointed
+    pub async fn ensure_checkpoints_current(
+        &self,
+        deployers: &[DeployerInfo],
+        auth_id: u64,
+        batch_size: usize,
+    ) -> Result<usize, CrankError> {
+        let (board, _) = self.get_board()?;
+
+        let mut lagging = Vec::new();
+        for deployer in deployers {
+            match self.needs_checkpoint(deployer, auth_id)? {
+                Some(round) if round < board.round_id => lagging.push((deployer, auth_id, round)),
+                _ => {}
+            }
+        }
+
+        if lagging.is_empty() {
+            return Ok(0);
+        }
+        info!("Catching up {} miners behind on checkpoints", lagging.len());
+
+        let mut checkpointed = 0;
+        for batch in lagging.chunks(batch_size.max(1)) {
+            match self.execute_batched_checkpoint_recycle(batch.to_vec()).await {
+                Ok(sig) => {
+                    info!("✓ Catch-up checkpoint+recycle for {} miners: {}", batch.len(), sig);
+                    checkpointed += batch.len();
+                }
+                Err(e) => {
+                    warn!("Catch-up batch of {} failed ({}), retrying individually", batch.len(), e);
+                    for (deployer, auth_id, round) in batch {
+                        match self.execute_checkpoint_recycle(deployer, *auth_id, *round, true).await {
+                            Ok(sig) => {
+                                info!("✓ Catch-up checkpoint+recycle for {} (round {}): {}", deployer.manager_address, round, sig);
+                                checkpointed += 1;
+                            }
+                            Err(e) => error!("✗ Catch-up checkpoint failed for {}: {}", deployer.manager_address, e),
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(checkpointed)
+    }
+
     /// Execute batched autodeploys using LutRegistry (multiple LUTs)
     /// Uses individual mm_full_autodeploy instructions for each deploy
     pub async fn execute_batched_autodeploys_multi_lut(
@@ -1332,6 +1733,17 @@ impl Crank {
             return Err(CrankError::Send("No deploys to batch".to_string()));
         }
 
+        let checkpoint_rounds: HashSet<u64> = deploys.iter()
+            .filter_map(|(_, _, round_id, _, _, checkpoint_round)| checkpoint_round.filter(|r| r != round_id))
+            .collect();
+        let expected_accounts = autodeploy_account_count(deploys.len(), checkpoint_rounds.len(), true);
+        if expected_accounts > MAX_TX_ACCOUNTS {
+            return Err(CrankError::Send(format!(
+                "Batch of {} deploys needs {} accounts (limit {})",
+                deploys.len(), expected_accounts, MAX_TX_ACCOUNTS
+            )));
+        }
+
         let payer = &self.deploy_authority;
 
         let (recent_blockhash, last_valid_blockheight) = self.rpc_client
@@ -1373,14 +1785,9 @@ impl Crank {
         
         // Log transaction size and account count
         let tx_bytes = bincode::serialize(&tx).unwrap_or_default();
-        let account_count = match &tx.message {
-            solana_sdk::message::VersionedMessage::V0(msg) => {
-                msg.account_keys.len() + 
-                msg.address_table_lookups.iter().map(|l| l.writable_indexes.len() + l.readonly_indexes.len()).sum::<usize>()
-            }
-            solana_sdk::message::VersionedMessage::Legacy(msg) => msg.account_keys.len(),
-        };
-        info!("Sending versioned tx: {} bytes (limit 1232), {} accounts (limit 64)", tx_bytes.len(), account_count);
+        let account_count = tx_account_count(&tx.message);
+        info!("Sending versioned tx: {} bytes (limit 1232), {} accounts (expected {}, limit {})",
+            tx_bytes.len(), account_count, expected_accounts, MAX_TX_ACCOUNTS);
         
         let signature = tx.signatures[0].to_string();
         
@@ -1417,7 +1824,38 @@ impl Crank {
         }
         
         // Send transaction
-        match self.sender.send_and_confirm_versioned_rpc(&tx, 60).await {
+        let send_result = self.sender.send_and_confirm_versioned_rpc(&tx, 60).await;
+        
+        // Audit every deploy in the batch with the tx outcome
+        let (audit_result, audit_error) = match &send_result {
+            Ok(_) => (db::AuditResult::Confirmed, None),
+            Err(SendError::Timeout(_)) => (db::AuditResult::Timeout, None),
+            Err(e) => (db::AuditResult::Failed, Some(e.to_string())),
+        };
+        let finished_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        for (deployer, auth_id, round_id, amount, squares_mask, _) in &deploys {
+            if let Err(e) = db::insert_deploy_audit(
+                &self.db_pool,
+                finished_at,
+                &signature,
+                &deployer.manager_address.to_string(),
+                *auth_id,
+                *round_id,
+                *squares_mask,
+                *amount,
+                self.config.priority_fee,
+                0, // No Jito tip
+                audit_result,
+                audit_error.as_deref(),
+            ).await {
+                warn!("Failed to write deploy audit for {}: {}", signature, e);
+            }
+        }
+        
+        match send_result {
             Ok(sig) => {
                 info!("✓ Multi-LUT autodeploy ({} deploys, {} LUTs) confirmed: {}", 
                     deploys.len(), registry.get_luts_for_miners(&miner_auths).len(), sig);
@@ -1593,6 +2031,45 @@ impl Crank {
 
 use std::str::FromStr;
 
+/// Keep only deployers whose manager authority is in `allowlist` (empty =
+/// keep all), logging every deployer that's skipped
+pub fn apply_manager_allowlist(
+    rpc_client: &RpcClient,
+    deployers: Vec<DeployerInfo>,
+    allowlist: &[Pubkey],
+) -> Result<Vec<DeployerInfo>, CrankError> {
+    if allowlist.is_empty() {
+        return Ok(deployers);
+    }
+    
+    // getMultipleAccounts is capped at 100 keys
+    let mut kept = Vec::with_capacity(deployers.len());
+    for chunk in deployers.chunks(100) {
+        let managers: Vec<Pubkey> = chunk.iter().map(|d| d.manager_address).collect();
+        let accounts = rpc_client.get_multiple_accounts(&managers)
+            .map_err(|e| CrankError::Rpc(e.to_string()))?;
+        for (deployer, account) in chunk.iter().zip(accounts) {
+            let authority = account.as_ref()
+                .and_then(|a| Manager::try_from_bytes(&a.data).ok())
+                .map(|m| m.authority);
+            match authority {
+                Some(authority) if allowlist.contains(&authority) => kept.push(deployer.clone()),
+                Some(authority) => warn!(
+                    "Skipping deployer {}: manager {} authority {} is not in the allowlist",
+                    deployer.deployer_address, deployer.manager_address, authority
+                ),
+                None => warn!(
+                    "Skipping deployer {}: manager {} could not be read",
+                    deployer.deployer_address, deployer.manager_address
+                ),
+            }
+        }
+    }
+    
+    info!("Manager allowlist kept {} of {} deployers", kept.len(), deployers.len());
+    Ok(kept)
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum CrankError {
     #[error("Failed to load keypair: {0}")]
@@ -1607,4 +2084,64 @@ pub enum CrankError {
     Send(String),
     #[error("Parse error: {0}")]
     Parse(String),
+    #[error("Unsupported network: {0}")]
+    UnsupportedNetwork(String),
+    #[error("ORE config mismatch: {0}")]
+    ConfigMismatch(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lut::get_static_shared_accounts;
+    use solana_sdk::{
+        address_lookup_table::AddressLookupTableAccount,
+        message::v0::Message as V0Message,
+    };
+
+    /// Compile a batch against a shared LUT and one consolidated miner LUT
+    fn compiled_account_count(deploys: &[(Pubkey, u64)], round_id: u64, compute_budget: bool) -> usize {
+        let payer = Pubkey::new_unique();
+        let mut instructions = Vec::new();
+        if compute_budget {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(1));
+        }
+        for (manager, checkpoint_round_id) in deploys {
+            instructions.push(mm_full_autodeploy(payer, *manager, 0, round_id, *checkpoint_round_id, 1_000, 0x1FFFFFF));
+        }
+        let luts = vec![
+            AddressLookupTableAccount { key: Pubkey::new_unique(), addresses: get_static_shared_accounts(payer) },
+            AddressLookupTableAccount {
+                key: Pubkey::new_unique(),
+                addresses: deploys.iter().flat_map(|(manager, _)| get_miner_accounts(*manager, 0)).collect(),
+            },
+        ];
+        let message = V0Message::try_compile(&payer, &instructions, &luts, Hash::default()).unwrap();
+        tx_account_count(&VersionedMessage::V0(message))
+    }
+
+    #[test]
+    fn test_autodeploy_account_count() {
+        let round_id = 100;
+        let managers: Vec<Pubkey> = (0..7).map(|_| Pubkey::new_unique()).collect();
+
+        // No checkpoints: checkpoint_round_id is the current round
+        let batch: Vec<_> = managers.iter().map(|m| (*m, round_id)).collect();
+        assert_eq!(compiled_account_count(&batch, round_id, true), autodeploy_account_count(7, 0, true));
+        assert_eq!(compiled_account_count(&batch, round_id, false), autodeploy_account_count(7, 0, false));
+        assert_eq!(autodeploy_account_count(7, 0, true), 47);
+
+        // Everyone checkpoints the previous round
+        let batch: Vec<_> = managers.iter().map(|m| (*m, round_id - 1)).collect();
+        assert_eq!(compiled_account_count(&batch, round_id, true), autodeploy_account_count(7, 1, true));
+
+        // Worst case: a different checkpoint round per deploy
+        let batch: Vec<_> = managers.iter().enumerate().map(|(i, m)| (*m, round_id - 1 - i as u64)).collect();
+        assert_eq!(compiled_account_count(&batch, round_id, true), autodeploy_account_count(7, 7, true));
+        assert!(autodeploy_account_count(7, 7, true) <= MAX_TX_ACCOUNTS);
+
+        let batch = &batch[..2];
+        assert_eq!(compiled_account_count(batch, round_id, true), autodeploy_account_count(2, 2, true));
+    }
 }
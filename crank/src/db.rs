@@ -5,6 +5,8 @@
 use sqlx::{sqlite::SqlitePoolOptions, Pool, Sqlite};
 use std::path::Path;
 
+use crate::lut::{LutExport, MinerLutEntry};
+
 /// Transaction status enum
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TxStatus {
@@ -18,6 +20,8 @@ pub enum TxStatus {
     Failed = 3,
     /// Transaction expired (blockhash expired)
     Expired = 4,
+    /// Transaction never landed within the pending timeout
+    Dropped = 5,
 }
 
 impl TxStatus {
@@ -28,6 +32,7 @@ impl TxStatus {
             2 => TxStatus::Finalized,
             3 => TxStatus::Failed,
             4 => TxStatus::Expired,
+            5 => TxStatus::Dropped,
             _ => TxStatus::Pending,
         }
     }
@@ -39,6 +44,7 @@ impl TxStatus {
             TxStatus::Finalized => "finalized",
             TxStatus::Failed => "failed",
             TxStatus::Expired => "expired",
+            TxStatus::Dropped => "dropped",
         }
     }
 }
@@ -81,7 +87,7 @@ pub struct AutodeployTx {
     pub confirmed_at: Option<i64>,
     /// Unix timestamp when transaction was finalized (null if not finalized)
     pub finalized_at: Option<i64>,
-    /// Transaction status (0=pending, 1=confirmed, 2=finalized, 3=failed, 4=expired)
+    /// Transaction status (0=pending, 1=confirmed, 2=finalized, 3=failed, 4=expired, 5=dropped)
     pub status: i32,
     /// Error message if transaction failed
     pub error_message: Option<String>,
@@ -91,6 +97,49 @@ pub struct AutodeployTx {
     pub slot: Option<i64>,
 }
 
+/// Outcome of a deploy attempt in the audit log
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditResult {
+    Confirmed,
+    Failed,
+    Timeout,
+}
+
+impl AuditResult {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditResult::Confirmed => "confirmed",
+            AuditResult::Failed => "failed",
+            AuditResult::Timeout => "timeout",
+        }
+    }
+}
+
+/// One deploy attempt from the audit log
+#[derive(Debug, Clone)]
+pub struct DeployAuditEntry {
+    pub id: i64,
+    /// Unix timestamp when the attempt finished
+    pub timestamp: i64,
+    /// Transaction signature (base58)
+    pub signature: String,
+    /// Manager account pubkey
+    pub manager_key: String,
+    pub auth_id: i64,
+    pub round_id: i64,
+    /// Bitmask of squares deployed to
+    pub squares_mask: i64,
+    /// Amount per square (lamports)
+    pub amount: i64,
+    /// Priority fee (microlamports per CU)
+    pub priority_fee: i64,
+    /// Jito tip (lamports)
+    pub jito_tip: i64,
+    /// confirmed / failed / timeout
+    pub result: String,
+    pub error_message: Option<String>,
+}
+
 /// Initialize the database and create tables
 pub async fn init_db(db_path: &Path) -> Result<Pool<Sqlite>, sqlx::Error> {
     // Create database file if it doesn't exist
@@ -153,6 +202,80 @@ pub async fn init_db(db_path: &Path) -> Result<Pool<Sqlite>, sqlx::Error> {
         .execute(&pool)
         .await?;
     
+    // Append-only audit of every deploy attempt (one row per deployer per tx)
+    sqlx::query(r#"
+        CREATE TABLE IF NOT EXISTS deploy_audit (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            timestamp INTEGER NOT NULL,
+            signature TEXT NOT NULL,
+            manager_key TEXT NOT NULL,
+            auth_id INTEGER NOT NULL,
+            round_id INTEGER NOT NULL,
+            squares_mask INTEGER NOT NULL,
+            amount INTEGER NOT NULL,
+            priority_fee INTEGER NOT NULL,
+            jito_tip INTEGER NOT NULL,
+            result TEXT NOT NULL,
+            error_message TEXT
+        )
+    "#)
+    .execute(&pool)
+    .await?;
+    
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_deploy_audit_round ON deploy_audit(round_id)")
+        .execute(&pool)
+        .await?;
+    
+    // Last known LUT assignments, so startup can skip the on-chain LUT scan
+    // (miner_auth is '' for the shared LUT)
+    sqlx::query(r#"
+        CREATE TABLE IF NOT EXISTS lut_registry (
+            authority TEXT NOT NULL,
+            miner_auth TEXT NOT NULL,
+            lut_address TEXT NOT NULL,
+            PRIMARY KEY (authority, miner_auth)
+        )
+    "#)
+    .execute(&pool)
+    .await?;
+    
+    // SOL rewards swept by the scheduled claim job
+    sqlx::query(r#"
+        CREATE TABLE IF NOT EXISTS claims (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            timestamp INTEGER NOT NULL,
+            signature TEXT NOT NULL,
+            manager_key TEXT NOT NULL,
+            auth_id INTEGER NOT NULL,
+            round_id INTEGER NOT NULL,
+            amount INTEGER NOT NULL
+        )
+    "#)
+    .execute(&pool)
+    .await?;
+    
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_claims_manager ON claims(manager_key)")
+        .execute(&pool)
+        .await?;
+    
+    // Where confirmed autodeploys landed relative to round end (one row per tx)
+    sqlx::query(r#"
+        CREATE TABLE IF NOT EXISTS deploy_landings (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            timestamp INTEGER NOT NULL,
+            signature TEXT NOT NULL,
+            round_id INTEGER NOT NULL,
+            end_slot INTEGER NOT NULL,
+            landed_slot INTEGER NOT NULL
+        )
+    "#)
+    .execute(&pool)
+    .await?;
+    
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_deploy_landings_timestamp ON deploy_landings(timestamp)")
+        .execute(&pool)
+        .await?;
+    
     Ok(pool)
 }
 
@@ -282,6 +405,23 @@ pub async fn update_tx_expired(
     Ok(())
 }
 
+/// Update transaction status to dropped (still pending past the timeout)
+pub async fn update_tx_dropped(
+    pool: &Pool<Sqlite>,
+    signature: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(r#"
+        UPDATE autodeploy_txs 
+        SET status = 5
+        WHERE signature = ? AND status = 0
+    "#)
+    .bind(signature)
+    .execute(pool)
+    .await?;
+    
+    Ok(())
+}
+
 /// Get all pending transactions
 pub async fn get_pending_txs(pool: &Pool<Sqlite>) -> Result<Vec<AutodeployTx>, sqlx::Error> {
     let rows = sqlx::query(r#"
@@ -377,6 +517,209 @@ pub async fn get_recent_txs(pool: &Pool<Sqlite>, limit: i32) -> Result<Vec<Autod
     Ok(txs)
 }
 
+/// Append a deploy attempt to the audit log
+pub async fn insert_deploy_audit(
+    pool: &Pool<Sqlite>,
+    timestamp: i64,
+    signature: &str,
+    manager_key: &str,
+    auth_id: u64,
+    round_id: u64,
+    squares_mask: u32,
+    amount: u64,
+    priority_fee: u64,
+    jito_tip: u64,
+    result: AuditResult,
+    error_message: Option<&str>,
+) -> Result<i64, sqlx::Error> {
+    let row = sqlx::query(r#"
+        INSERT INTO deploy_audit (
+            timestamp, signature, manager_key, auth_id, round_id,
+            squares_mask, amount, priority_fee, jito_tip, result, error_message
+        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+    "#)
+    .bind(timestamp)
+    .bind(signature)
+    .bind(manager_key)
+    .bind(auth_id as i64)
+    .bind(round_id as i64)
+    .bind(squares_mask as i64)
+    .bind(amount as i64)
+    .bind(priority_fee as i64)
+    .bind(jito_tip as i64)
+    .bind(result.as_str())
+    .bind(error_message)
+    .execute(pool)
+    .await?;
+    
+    Ok(row.last_insert_rowid())
+}
+
+/// Record a confirmed SOL claim
+///
+/// `amount` is the miner's claimable SOL when the claim was sent; rewards from
+/// a checkpoint in the same transaction aren't included.
+pub async fn insert_claim(
+    pool: &Pool<Sqlite>,
+    timestamp: i64,
+    signature: &str,
+    manager_key: &str,
+    auth_id: u64,
+    round_id: u64,
+    amount: u64,
+) -> Result<i64, sqlx::Error> {
+    let row = sqlx::query(r#"
+        INSERT INTO claims (timestamp, signature, manager_key, auth_id, round_id, amount)
+        VALUES (?, ?, ?, ?, ?, ?)
+    "#)
+    .bind(timestamp)
+    .bind(signature)
+    .bind(manager_key)
+    .bind(auth_id as i64)
+    .bind(round_id as i64)
+    .bind(amount as i64)
+    .execute(pool)
+    .await?;
+    
+    Ok(row.last_insert_rowid())
+}
+
+/// Record the slot a confirmed autodeploy landed in
+pub async fn insert_deploy_landing(
+    pool: &Pool<Sqlite>,
+    timestamp: i64,
+    signature: &str,
+    round_id: u64,
+    end_slot: u64,
+    landed_slot: u64,
+) -> Result<i64, sqlx::Error> {
+    let row = sqlx::query(r#"
+        INSERT INTO deploy_landings (timestamp, signature, round_id, end_slot, landed_slot)
+        VALUES (?, ?, ?, ?, ?)
+    "#)
+    .bind(timestamp)
+    .bind(signature)
+    .bind(round_id as i64)
+    .bind(end_slot as i64)
+    .bind(landed_slot as i64)
+    .execute(pool)
+    .await?;
+    
+    Ok(row.last_insert_rowid())
+}
+
+/// Slots between landing and round end (negative = after end_slot) for
+/// every autodeploy recorded since `since_timestamp`
+pub async fn get_deploy_landings_since(
+    pool: &Pool<Sqlite>,
+    since_timestamp: i64,
+) -> Result<Vec<i64>, sqlx::Error> {
+    let rows = sqlx::query("SELECT end_slot - landed_slot AS slots_before_end FROM deploy_landings WHERE timestamp >= ?")
+        .bind(since_timestamp)
+        .fetch_all(pool)
+        .await?;
+    
+    use sqlx::Row;
+    Ok(rows.into_iter().map(|row| row.get("slots_before_end")).collect())
+}
+
+/// Get audit entries with id greater than `after_id`, oldest first
+///
+/// With `after_id = 0` and a limit this returns the tail of the log.
+pub async fn get_deploy_audit_after(
+    pool: &Pool<Sqlite>,
+    after_id: i64,
+    limit: i64,
+) -> Result<Vec<DeployAuditEntry>, sqlx::Error> {
+    let rows = sqlx::query(r#"
+        SELECT * FROM (
+            SELECT
+                id, timestamp, signature, manager_key, auth_id, round_id,
+                squares_mask, amount, priority_fee, jito_tip, result, error_message
+            FROM deploy_audit
+            WHERE id > ?
+            ORDER BY id DESC
+            LIMIT ?
+        ) ORDER BY id ASC
+        "#)
+    .bind(after_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    
+    let entries = rows.into_iter().map(|row| {
+        use sqlx::Row;
+        DeployAuditEntry {
+            id: row.get("id"),
+            timestamp: row.get("timestamp"),
+            signature: row.get("signature"),
+            manager_key: row.get("manager_key"),
+            auth_id: row.get("auth_id"),
+            round_id: row.get("round_id"),
+            squares_mask: row.get("squares_mask"),
+            amount: row.get("amount"),
+            priority_fee: row.get("priority_fee"),
+            jito_tip: row.get("jito_tip"),
+            result: row.get("result"),
+            error_message: row.get("error_message"),
+        }
+    }).collect();
+    
+    Ok(entries)
+}
+
+/// Replace the stored LUT assignments for the snapshot's authority
+pub async fn save_lut_registry(pool: &Pool<Sqlite>, snapshot: &LutExport) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    
+    sqlx::query("DELETE FROM lut_registry WHERE authority = ?")
+        .bind(&snapshot.authority)
+        .execute(&mut *tx)
+        .await?;
+    
+    let rows = snapshot.shared_lut.iter()
+        .map(|lut| ("", lut.as_str()))
+        .chain(snapshot.miner_luts.iter().map(|e| (e.miner_auth.as_str(), e.lut.as_str())));
+    for (miner_auth, lut_address) in rows {
+        sqlx::query("INSERT INTO lut_registry (authority, miner_auth, lut_address) VALUES (?, ?, ?)")
+            .bind(&snapshot.authority)
+            .bind(miner_auth)
+            .bind(lut_address)
+            .execute(&mut *tx)
+            .await?;
+    }
+    
+    tx.commit().await
+}
+
+/// Stored LUT assignments for `authority` (None if nothing is stored)
+pub async fn get_lut_registry(pool: &Pool<Sqlite>, authority: &str) -> Result<Option<LutExport>, sqlx::Error> {
+    let rows = sqlx::query("SELECT miner_auth, lut_address FROM lut_registry WHERE authority = ? ORDER BY miner_auth")
+        .bind(authority)
+        .fetch_all(pool)
+        .await?;
+    if rows.is_empty() {
+        return Ok(None);
+    }
+    
+    use sqlx::Row;
+    let mut snapshot = LutExport {
+        authority: authority.to_string(),
+        ..Default::default()
+    };
+    for row in rows {
+        let miner_auth: String = row.get("miner_auth");
+        let lut: String = row.get("lut_address");
+        if miner_auth.is_empty() {
+            snapshot.shared_lut = Some(lut);
+        } else {
+            snapshot.miner_luts.push(MinerLutEntry { miner_auth, lut });
+        }
+    }
+    
+    Ok(Some(snapshot))
+}
+
 /// Get transaction stats for a time range
 pub async fn get_tx_stats(
     pool: &Pool<Sqlite>,
@@ -388,6 +731,7 @@ pub async fn get_tx_stats(
             SUM(CASE WHEN status = 2 THEN 1 ELSE 0 END) as finalized_count,
             SUM(CASE WHEN status = 3 THEN 1 ELSE 0 END) as failed_count,
             SUM(CASE WHEN status = 4 THEN 1 ELSE 0 END) as expired_count,
+            SUM(CASE WHEN status = 5 THEN 1 ELSE 0 END) as dropped_count,
             SUM(CASE WHEN status = 2 THEN total_deployed ELSE 0 END) as total_deployed_finalized,
             SUM(CASE WHEN status = 2 THEN deployer_fee ELSE 0 END) as total_deployer_fee,
             SUM(CASE WHEN status = 2 THEN protocol_fee ELSE 0 END) as total_protocol_fee
@@ -404,6 +748,7 @@ pub async fn get_tx_stats(
         finalized_count: row.get::<Option<i64>, _>("finalized_count").unwrap_or(0) as u64,
         failed_count: row.get::<Option<i64>, _>("failed_count").unwrap_or(0) as u64,
         expired_count: row.get::<Option<i64>, _>("expired_count").unwrap_or(0) as u64,
+        dropped_count: row.get::<Option<i64>, _>("dropped_count").unwrap_or(0) as u64,
         total_deployed_finalized: row.get::<Option<i64>, _>("total_deployed_finalized").unwrap_or(0) as u64,
         total_deployer_fee: row.get::<Option<i64>, _>("total_deployer_fee").unwrap_or(0) as u64,
         total_protocol_fee: row.get::<Option<i64>, _>("total_protocol_fee").unwrap_or(0) as u64,
@@ -417,6 +762,7 @@ pub struct TxStats {
     pub finalized_count: u64,
     pub failed_count: u64,
     pub expired_count: u64,
+    pub dropped_count: u64,
     pub total_deployed_finalized: u64,
     pub total_deployer_fee: u64,
     pub total_protocol_fee: u64,
@@ -4,7 +4,9 @@
 //! 
 //! Architecture:
 //! - One shared LUT for static accounts (10 accounts that never change)
-//! - One LUT per miner containing their 7 specific accounts
+//! - One LUT per miner containing their 5 specific accounts
+//! - Consolidated LUTs holding the 5-account groups of up to 51 miners
+//!   (created by `consolidate-luts` or when `MAX_LUTS` is exceeded)
 //! - Round address is NOT in any LUT (changes each round, can't remove from LUT)
 //!
 //! The LutRegistry tracks:
@@ -32,6 +34,7 @@ use solana_sdk::{
     system_program,
     transaction::VersionedTransaction,
 };
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use tracing::{info, debug, warn};
 
@@ -84,6 +87,42 @@ pub fn get_miner_accounts(manager: Pubkey, auth_id: u64) -> Vec<Pubkey> {
     ]
 }
 
+/// Accounts per miner in a miner LUT (see `get_miner_accounts`)
+pub const MINER_ACCOUNT_COUNT: usize = 5;
+
+/// Max addresses an Address Lookup Table can hold
+pub const MAX_LUT_ADDRESSES: usize = 256;
+
+/// Miners whose accounts fit in one consolidated LUT (51 x 5 = 255 addresses)
+pub const MAX_MINERS_PER_LUT: usize = MAX_LUT_ADDRESSES / MINER_ACCOUNT_COUNT;
+
+/// Miner auth PDAs covered by a miner LUT, in order
+/// Per-miner and consolidated LUTs are both runs of `get_miner_accounts` groups.
+/// Returns None unless the addresses are a whole number of valid groups.
+pub fn miner_auths_in_lut(addresses: &[Pubkey]) -> Option<Vec<Pubkey>> {
+    if addresses.is_empty() || addresses.len() % MINER_ACCOUNT_COUNT != 0 {
+        return None;
+    }
+    addresses
+        .chunks(MINER_ACCOUNT_COUNT)
+        .map(|group| {
+            // manager, deployer, miner_auth, ore_miner, automation
+            let miner_auth = group[2];
+            let valid = group[3] == miner_pda(miner_auth).0 && group[4] == automation_pda(miner_auth).0;
+            valid.then_some(miner_auth)
+        })
+        .collect()
+}
+
+/// Split single-miner LUTs into consolidation batches
+/// A lone leftover miner is skipped - moving it wouldn't reduce the LUT count.
+pub fn consolidation_groups(miner_auths: &[Pubkey]) -> Vec<&[Pubkey]> {
+    miner_auths
+        .chunks(MAX_MINERS_PER_LUT)
+        .filter(|group| group.len() > 1)
+        .collect()
+}
+
 /// Get the miner_auth PDA for a manager/auth_id (used as key in miner_luts map)
 pub fn get_miner_auth_pda(manager: Pubkey, auth_id: u64) -> Pubkey {
     let (managed_miner_auth, _) = managed_miner_auth_pda(manager, auth_id);
@@ -97,11 +136,40 @@ pub struct LutStatus {
     pub account_count: usize,
     pub deactivation_slot: Option<u64>,
     pub is_shared: bool,
+    /// First miner in the LUT (the only one for per-miner LUTs)
     pub miner_auth: Option<Pubkey>,
     pub is_valid: bool,
     pub validation_error: Option<String>,
 }
 
+/// Registry snapshot written by `export-luts` and read by `import-luts`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LutExport {
+    /// Deploy authority owning the LUTs
+    pub authority: String,
+    pub shared_lut: Option<String>,
+    /// One entry per miner (a consolidated LUT appears under each of its miners)
+    pub miner_luts: Vec<MinerLutEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinerLutEntry {
+    /// managed_miner_auth PDA
+    pub miner_auth: String,
+    pub lut: String,
+}
+
+/// Outcome of `LutRegistry::import`
+#[derive(Debug, Default)]
+pub struct LutImport {
+    /// Whether the shared LUT was accepted
+    pub shared: bool,
+    /// Miner entries accepted
+    pub miners: usize,
+    /// (LUT address, reason) for entries that failed validation
+    pub rejected: Vec<(Pubkey, String)>,
+}
+
 /// Registry that manages multiple LUTs:
 /// - One shared LUT for static accounts
 /// - Per-miner LUTs for miner-specific accounts
@@ -120,8 +188,8 @@ pub struct LutRegistry {
 
 impl LutRegistry {
     pub fn new(rpc_url: &str, authority: Pubkey) -> Self {
-        let rpc_client = RpcClient::new_with_commitment(
-            rpc_url.to_string(),
+        let rpc_client = crate::sender::rpc_client(
+            rpc_url,
             CommitmentConfig::confirmed(),
         );
         
@@ -235,6 +303,12 @@ impl LutRegistry {
             
             let addresses: Vec<Pubkey> = lookup_table.addresses.to_vec();
             
+            // Deactivated LUTs (e.g. replaced by a consolidated one) can't be relied on
+            if lookup_table.meta.deactivation_slot != u64::MAX {
+                debug!("  Skipping deactivated LUT: {}", lut_address);
+                continue;
+            }
+            
             // Cache the LUT
             self.lut_cache.insert(lut_address, AddressLookupTableAccount {
                 key: lut_address,
@@ -253,12 +327,17 @@ impl LutRegistry {
                     self.shared_lut_accounts.insert(*addr);
                 }
                 info!("  Identified shared LUT: {} ({} addresses)", lut_address, addresses.len());
-            } else if addresses.len() == 5 {
-                // This looks like a miner LUT (5 accounts per miner)
-                // miner_auth is at index 2 (after manager, deployer)
-                let miner_auth = addresses[2];
-                self.miner_luts.insert(miner_auth, lut_address);
-                debug!("  Identified miner LUT: {} for miner_auth {}", lut_address, miner_auth);
+            } else if let Some(miner_auths) = miner_auths_in_lut(&addresses) {
+                // Miner LUT (5 accounts per miner) - consolidated LUTs take
+                // precedence over a leftover per-miner LUT for the same miner
+                let consolidated = miner_auths.len() > 1;
+                for miner_auth in &miner_auths {
+                    let replace = consolidated || !self.miner_luts.contains_key(miner_auth);
+                    if replace {
+                        self.miner_luts.insert(*miner_auth, lut_address);
+                    }
+                }
+                debug!("  Identified miner LUT: {} for {} miner(s)", lut_address, miner_auths.len());
             } else if addresses.len() == 6 || addresses.len() == 7 {
                 // Legacy LUT formats - will be marked invalid
                 let miner_auth = if addresses.len() == 6 { addresses[3] } else { addresses[4] };
@@ -274,6 +353,42 @@ impl LutRegistry {
         Ok(num_luts)
     }
     
+    /// Register a consolidated LUT holding several miners' accounts
+    pub fn register_consolidated_lut(&mut self, lut_address: Pubkey, miner_auths: &[Pubkey], addresses: Vec<Pubkey>) {
+        for miner_auth in miner_auths {
+            self.miner_luts.insert(*miner_auth, lut_address);
+        }
+        self.lut_cache.insert(lut_address, AddressLookupTableAccount {
+            key: lut_address,
+            addresses,
+        });
+    }
+    
+    /// Cached contents of a LUT
+    pub fn cached_lut(&self, lut_address: &Pubkey) -> Option<&AddressLookupTableAccount> {
+        self.lut_cache.get(lut_address)
+    }
+    
+    /// Number of LUTs in use (shared + distinct miner LUTs)
+    pub fn lut_count(&self) -> usize {
+        let miner_luts: HashSet<&Pubkey> = self.miner_luts.values().collect();
+        miner_luts.len() + usize::from(self.shared_lut.is_some())
+    }
+    
+    /// Miners whose LUT holds only their own accounts, sorted by miner_auth
+    /// Returns (miner_auth, lut_address)
+    pub fn single_miner_luts(&self) -> Vec<(Pubkey, Pubkey)> {
+        let mut singles: Vec<(Pubkey, Pubkey)> = self.miner_luts.iter()
+            .filter(|(_, lut)| {
+                self.lut_cache.get(lut)
+                    .map_or(false, |account| account.addresses.len() == MINER_ACCOUNT_COUNT)
+            })
+            .map(|(miner_auth, lut)| (*miner_auth, *lut))
+            .collect();
+        singles.sort();
+        singles
+    }
+    
     /// Register a miner LUT (after creating it)
     pub fn register_miner_lut(&mut self, miner_auth: Pubkey, lut_address: Pubkey, addresses: Vec<Pubkey>) {
         self.miner_luts.insert(miner_auth, lut_address);
@@ -283,6 +398,107 @@ impl LutRegistry {
         });
     }
     
+    /// Snapshot of the shared and miner LUT assignments (see `export-luts`)
+    pub fn export(&self) -> LutExport {
+        let mut miner_luts: Vec<MinerLutEntry> = self.miner_luts.iter()
+            .map(|(miner_auth, lut)| MinerLutEntry {
+                miner_auth: miner_auth.to_string(),
+                lut: lut.to_string(),
+            })
+            .collect();
+        miner_luts.sort_by(|a, b| a.miner_auth.cmp(&b.miner_auth));
+        
+        LutExport {
+            authority: self.authority.to_string(),
+            shared_lut: self.shared_lut.map(|lut| lut.to_string()),
+            miner_luts,
+        }
+    }
+    
+    /// Load a snapshot without scanning for LUTs, checking each one on-chain
+    /// A LUT is accepted if it exists, is still active, belongs to our authority
+    /// and (for miner entries) holds that miner's accounts. Rejected entries are
+    /// returned with the reason and left out of the registry.
+    pub fn import(&mut self, snapshot: &LutExport) -> Result<LutImport, LutError> {
+        let parse = |s: &str| s.parse::<Pubkey>().map_err(|_| LutError::Invalid(format!("bad pubkey {}", s)));
+        
+        if parse(&snapshot.authority)? != self.authority {
+            return Err(LutError::Invalid(format!(
+                "snapshot belongs to authority {}, not {}", snapshot.authority, self.authority
+            )));
+        }
+        let shared = snapshot.shared_lut.as_deref().map(parse).transpose()?;
+        let miner_luts = snapshot.miner_luts.iter()
+            .map(|entry| Ok((parse(&entry.miner_auth)?, parse(&entry.lut)?)))
+            .collect::<Result<Vec<(Pubkey, Pubkey)>, LutError>>()?;
+        
+        let mut lut_addresses: Vec<Pubkey> = shared.into_iter()
+            .chain(miner_luts.iter().map(|(_, lut)| *lut))
+            .collect();
+        lut_addresses.sort();
+        lut_addresses.dedup();
+        
+        // Fetch every LUT once (getMultipleAccounts is capped at 100 keys)
+        let mut tables: HashMap<Pubkey, Result<Vec<Pubkey>, String>> = HashMap::new();
+        for chunk in lut_addresses.chunks(100) {
+            let accounts = self.rpc_client.get_multiple_accounts(chunk)
+                .map_err(|e| LutError::Rpc(e.to_string()))?;
+            for (lut_address, account) in chunk.iter().zip(accounts) {
+                let table = match account {
+                    None => Err("not found".to_string()),
+                    Some(account) => match AddressLookupTable::deserialize(&account.data) {
+                        Err(e) => Err(format!("not a lookup table: {:?}", e)),
+                        Ok(lt) if lt.meta.authority != Some(self.authority) => Err("wrong authority".to_string()),
+                        Ok(lt) if lt.meta.deactivation_slot != u64::MAX => Err("deactivated".to_string()),
+                        Ok(lt) => Ok(lt.addresses.to_vec()),
+                    },
+                };
+                tables.insert(*lut_address, table);
+            }
+        }
+        
+        let mut result = LutImport::default();
+        if let Some(lut_address) = shared {
+            match &tables[&lut_address] {
+                Ok(addresses) => {
+                    self.shared_lut = Some(lut_address);
+                    self.shared_lut_accounts = addresses.iter().copied().collect();
+                    self.lut_cache.insert(lut_address, AddressLookupTableAccount {
+                        key: lut_address,
+                        addresses: addresses.clone(),
+                    });
+                    result.shared = true;
+                }
+                Err(reason) => result.rejected.push((lut_address, reason.clone())),
+            }
+        }
+        
+        for (miner_auth, lut_address) in miner_luts {
+            let addresses = match &tables[&lut_address] {
+                Ok(addresses) => addresses,
+                Err(reason) => {
+                    result.rejected.push((lut_address, format!("{} (miner {})", reason, miner_auth)));
+                    continue;
+                }
+            };
+            let covers_miner = miner_auths_in_lut(addresses)
+                .map_or(false, |miner_auths| miner_auths.contains(&miner_auth));
+            if !covers_miner {
+                result.rejected.push((lut_address, format!("doesn't hold miner {}", miner_auth)));
+                continue;
+            }
+            
+            self.miner_luts.insert(miner_auth, lut_address);
+            self.lut_cache.insert(lut_address, AddressLookupTableAccount {
+                key: lut_address,
+                addresses: addresses.clone(),
+            });
+            result.miners += 1;
+        }
+        
+        Ok(result)
+    }
+    
     /// Get missing static addresses from the shared LUT
     pub fn get_missing_shared_addresses(&self) -> Vec<Pubkey> {
         get_static_shared_accounts(self.authority)
@@ -308,9 +524,13 @@ impl LutRegistry {
             }
         }
         
-        // Add miner-specific LUTs
+        // Add miner-specific LUTs (once each - consolidated LUTs cover several miners)
+        let mut added = HashSet::new();
         for miner_auth in miner_auths {
             if let Some(lut_addr) = self.miner_luts.get(miner_auth) {
+                if !added.insert(*lut_addr) {
+                    continue;
+                }
                 if let Some(lut_account) = self.lut_cache.get(lut_addr) {
                     luts.push(lut_account.clone());
                 }
@@ -470,21 +690,14 @@ impl LutRegistry {
             let mut validation_error = None;
 
             if !is_shared {
-                if addresses.len() == 5 {
-                    // Valid per-miner LUT (5 accounts)
-                    // miner_auth at index 2, automation at index 4
-                    let miner_auth_in_lut = addresses[2];
-                    let automation_in_lut = addresses[4];
-                    let expected_automation = automation_pda(miner_auth_in_lut).0;
-
-                    if automation_in_lut != expected_automation {
-                        is_valid = false;
-                        validation_error = Some(format!(
-                            "Wrong automation: expected {}, got {}",
-                            expected_automation, automation_in_lut
-                        ));
-                    }
-                    miner_auth = Some(miner_auth_in_lut);
+                if let Some(miner_auths) = miner_auths_in_lut(&addresses) {
+                    // Per-miner (5 accounts) or consolidated (5 per miner) LUT
+                    miner_auth = miner_auths.first().copied();
+                } else if addresses.len() % MINER_ACCOUNT_COUNT == 0 && !addresses.is_empty() {
+                    // Right shape, but some miner/automation address doesn't match its miner_auth
+                    is_valid = false;
+                    validation_error = Some("Wrong miner or automation address".to_string());
+                    miner_auth = Some(addresses[2]);
                 } else if addresses.len() == 6 || addresses.len() == 7 {
                     // Legacy formats
                     is_valid = false;
@@ -552,8 +765,8 @@ pub struct LutManager {
 
 impl LutManager {
     pub fn new(rpc_url: &str, authority: Pubkey) -> Self {
-        let rpc_client = RpcClient::new_with_commitment(
-            rpc_url.to_string(),
+        let rpc_client = crate::sender::rpc_client(
+            rpc_url,
             CommitmentConfig::confirmed(),
         );
         
@@ -730,6 +943,8 @@ pub enum LutError {
     Sign(String),
     #[error("LUT not deactivated yet")]
     NotDeactivated,
+    #[error("Invalid LUT snapshot: {0}")]
+    Invalid(String),
     #[error("LUT still in cooldown (deactivated at slot {0}, need to wait ~512 slots)")]
     StillInCooldown(u64),
 }
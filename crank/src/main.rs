@@ -16,6 +16,7 @@ mod config;
 mod crank;
 mod db;
 mod lut;
+mod metrics;
 mod miner_cache;
 mod pipeline;
 mod sender;
@@ -24,8 +25,9 @@ use clap::Parser;
 use config::Config;
 use lut::{LutManager, LutRegistry, get_miner_auth_pda};
 use solana_sdk::signature::Signer;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 use tracing::{error, info, warn, Level};
 use tracing_subscriber::FmtSubscriber;
@@ -52,12 +54,17 @@ const MIN_SLOTS_TO_DEPLOY: u64 = 10;
 /// Maximum deployers to batch in one transaction without LUT
 const MAX_BATCH_SIZE_NO_LUT: usize = 2;
 
+/// Maximum claims to batch in one transaction (legacy tx, no LUT)
+const MAX_CLAIM_BATCH_SIZE: usize = MAX_BATCH_SIZE_NO_LUT;
+
 /// Maximum deployers to batch in one transaction with LUT
 /// With consolidated LUTs (multiple miners per LUT):
-/// - Account limit: 64 max, each deploy adds 7 accounts
-/// - Base: ~10 shared + 2 rounds + compute budget = ~14 accounts
-/// - (64 - 14) / 7 = 7.1, safe max is 7 deploys
+/// - Account limit: 64 max, each deploy adds 5 miner accounts
+/// - Base: 10 shared + current round + ComputeBudget program = 12 accounts
+/// - Worst case every deploy checkpoints a different round: 12 + 7 * 6 = 54
+/// - 7 deploys leaves room under the instruction trace limit too
 const MAX_BATCH_SIZE: usize = 7;
+const _: () = assert!(crank::autodeploy_account_count(MAX_BATCH_SIZE, MAX_BATCH_SIZE, true) <= crank::MAX_TX_ACCOUNTS);
 
 // =============================================================================
 
@@ -79,13 +86,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let config = Config::parse();
     
     info!("Evore Autodeploy Crank");
-    info!("RPC URL: {}", config.rpc_url);
+    info!("RPC URL: {}", config.rpc_url());
+    info!("Network: {}", config.network);
+    let rpc_headers = sender::parse_rpc_headers(config.rpc_headers.as_deref(), &config.rpc_header)?;
+    if !rpc_headers.is_empty() {
+        info!("RPC headers: {}", rpc_headers.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>().join(", "));
+    }
+    sender::set_rpc_headers(rpc_headers);
+    if config.safe_mode {
+        sender::enable_safe_mode();
+        warn!("Safe mode: no transactions will be sent, their instructions are logged instead");
+    }
     
     // Initialize database
     let db_pool = db::init_db(&config.db_path).await?;
     
     // Create crank instance
-    let crank = crank::Crank::new(config.clone(), db_pool).await?;
+    let crank = crank::Crank::new(config.clone(), db_pool.clone()).await?;
     info!("Deploy authority: {}", crank.deploy_authority_pubkey());
     
     // Handle subcommand
@@ -108,7 +125,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             let deployers = crank.find_deployers().await?;
             
             // Also load LUT registry to show LUT status
-            let mut registry = LutRegistry::new(&config.rpc_url, crank.deploy_authority_pubkey());
+            let mut registry = LutRegistry::new(config.rpc_url(), crank.deploy_authority_pubkey());
             let _ = registry.load_all_luts();
             
             if deployers.is_empty() {
@@ -140,7 +157,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             } else {
                 info!("Shared LUT: Not found (will create on run)");
             }
-            info!("Miner LUTs: {} found", registry.miner_luts().len());
+            info!("Miner LUTs: {} miners across {} LUTs", registry.miner_luts().len(),
+                registry.lut_count() - usize::from(registry.shared_lut().is_some()));
             
             return Ok(());
         }
@@ -180,7 +198,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Some(config::Command::CreateLut) => {
             info!("[LEGACY] Creating new Address Lookup Table...");
             info!("Note: 'run' command auto-creates LUTs. This is for manual management.");
-            let mut lut_manager = LutManager::new(&config.rpc_url, crank.deploy_authority_pubkey());
+            let mut lut_manager = LutManager::new(config.rpc_url(), crank.deploy_authority_pubkey());
             match crank.create_lut(&mut lut_manager).await {
                 Ok(lut_address) => {
                     info!("✓ LUT created: {}", lut_address);
@@ -195,7 +213,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         Some(config::Command::ExtendLut) => {
             let lut_address = config.lut_address.ok_or("LUT_ADDRESS not set in .env")?;
-            let mut lut_manager = LutManager::new(&config.rpc_url, crank.deploy_authority_pubkey());
+            let mut lut_manager = LutManager::new(config.rpc_url(), crank.deploy_authority_pubkey());
             lut_manager.load_lut(lut_address)?;
             
             info!("Adding static shared accounts to LUT...");
@@ -226,7 +244,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         Some(config::Command::ShowLut) => {
             let lut_address = config.lut_address.ok_or("LUT_ADDRESS not set in .env")?;
-            let mut lut_manager = LutManager::new(&config.rpc_url, crank.deploy_authority_pubkey());
+            let mut lut_manager = LutManager::new(config.rpc_url(), crank.deploy_authority_pubkey());
             let lut_account = lut_manager.load_lut(lut_address)?;
             
             info!("LUT Address: {}", lut_address);
@@ -245,7 +263,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         Some(config::Command::DeactivateLut) => {
             let lut_address = config.lut_address.ok_or("LUT_ADDRESS not set in .env")?;
-            let mut lut_manager = LutManager::new(&config.rpc_url, crank.deploy_authority_pubkey());
+            let mut lut_manager = LutManager::new(config.rpc_url(), crank.deploy_authority_pubkey());
             lut_manager.load_lut(lut_address)?;
             
             // Check if already deactivated
@@ -271,7 +289,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         Some(config::Command::CloseLut) => {
             let lut_address = config.lut_address.ok_or("LUT_ADDRESS not set in .env")?;
-            let mut lut_manager = LutManager::new(&config.rpc_url, crank.deploy_authority_pubkey());
+            let mut lut_manager = LutManager::new(config.rpc_url(), crank.deploy_authority_pubkey());
             lut_manager.load_lut(lut_address)?;
             
             // Check deactivation status
@@ -313,7 +331,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Some(config::Command::DeactivateUnused) => {
             info!("Scanning for unused/invalid LUTs...");
             
-            let registry = LutRegistry::new(&config.rpc_url, crank.deploy_authority_pubkey());
+            let registry = LutRegistry::new(config.rpc_url(), crank.deploy_authority_pubkey());
             
             let unused_luts = registry.get_unused_luts()?;
             
@@ -334,7 +352,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             
             let mut deactivated = 0;
             for lut in &unused_luts {
-                let mut lut_manager = LutManager::new(&config.rpc_url, crank.deploy_authority_pubkey());
+                let mut lut_manager = LutManager::new(config.rpc_url(), crank.deploy_authority_pubkey());
                 lut_manager.load_lut(lut.address)?;
                 
                 match crank.deactivate_lut(&lut_manager).await {
@@ -355,7 +373,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Some(config::Command::CleanupDeactivated) => {
             info!("Scanning for deactivating LUTs...");
             
-            let registry = LutRegistry::new(&config.rpc_url, crank.deploy_authority_pubkey());
+            let registry = LutRegistry::new(config.rpc_url(), crank.deploy_authority_pubkey());
             
             let deactivating_luts = registry.get_deactivating_luts()?;
             
@@ -387,7 +405,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 let mut total_reclaimed = 0u64;
                 
                 for (lut, _) in &ready_to_close {
-                    let mut lut_manager = LutManager::new(&config.rpc_url, crank.deploy_authority_pubkey());
+                    let mut lut_manager = LutManager::new(config.rpc_url(), crank.deploy_authority_pubkey());
                     lut_manager.load_lut(lut.address)?;
                     
                     match crank.close_lut(&lut_manager).await {
@@ -411,11 +429,112 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             
             return Ok(());
         }
+        Some(config::Command::ConsolidateLuts) => {
+            let mut registry = LutRegistry::new(config.rpc_url(), crank.deploy_authority_pubkey());
+            let count = registry.load_all_luts()?;
+            info!("{} LUTs in use ({} owned)", registry.lut_count(), count);
+            
+            let (created, deactivated) = crank.consolidate_luts(&mut registry).await?;
+            if created == 0 {
+                info!("Nothing to consolidate");
+            } else {
+                info!("Created {} consolidated LUTs, deactivated {} per-miner LUTs", created, deactivated);
+                info!("{} LUTs in use", registry.lut_count());
+                info!("Run 'cleanup-deactivated' after ~512 slots (~3.5 minutes) to close and reclaim rent");
+            }
+            return Ok(());
+        }
+        Some(config::Command::ExportLuts { ref out }) => {
+            let mut registry = LutRegistry::new(config.rpc_url(), crank.deploy_authority_pubkey());
+            registry.load_all_luts()?;
+            
+            let snapshot = registry.export();
+            std::fs::write(out, serde_json::to_string_pretty(&snapshot)?)?;
+            db::save_lut_registry(&db_pool, &snapshot).await?;
+            info!("Exported shared LUT {} and {} miner LUT entries to {}",
+                snapshot.shared_lut.as_deref().unwrap_or("(none)"), snapshot.miner_luts.len(), out.display());
+            return Ok(());
+        }
+        Some(config::Command::ImportLuts { ref input }) => {
+            let snapshot: lut::LutExport = serde_json::from_str(&std::fs::read_to_string(input)?)?;
+            let mut registry = LutRegistry::new(config.rpc_url(), crank.deploy_authority_pubkey());
+            
+            info!("Validating {} miner LUT entries from {}...", snapshot.miner_luts.len(), input.display());
+            let imported = registry.import(&snapshot)?;
+            for (lut_address, reason) in &imported.rejected {
+                warn!("  Skipped LUT {}: {}", lut_address, reason);
+            }
+            
+            db::save_lut_registry(&db_pool, &registry.export()).await?;
+            info!("Imported shared LUT: {}, miner LUT entries: {}/{} ({} rejected)",
+                if imported.shared { "✓" } else { "✗" },
+                imported.miners, snapshot.miner_luts.len(), imported.rejected.len());
+            return Ok(());
+        }
         Some(config::Command::CheckAccounts) => {
             info!("Checking all Evore program accounts...\n");
             crank.check_all_accounts()?;
             return Ok(());
         }
+        Some(config::Command::SimulateCheckpoint { manager }) => {
+            let deployers: Vec<_> = crank.find_deployers().await?.into_iter()
+                .filter(|d| manager.map_or(true, |m| d.manager_address == m))
+                .collect();
+            if deployers.is_empty() {
+                warn!("No matching deployers found");
+                return Ok(());
+            }
+            
+            info!("Simulating checkpoint+recycle for {} deployer(s) (priority fee {} µlamports/CU)...",
+                deployers.len(), config.priority_fee);
+            let mut estimates = Vec::new();
+            for d in &deployers {
+                match crank.simulate_checkpoint_recycle(d, AUTH_ID) {
+                    Ok(Some(estimate)) => {
+                        info!("  Manager: {}", d.manager_address);
+                        info!("    Round {} ({})", estimate.checkpoint_round,
+                            if estimate.pending { "checkpoint pending" } else { "already checkpointed" });
+                        info!("    {} instructions, {} accounts, CU {} used / {} limit",
+                            estimate.instructions, estimate.accounts,
+                            estimate.units_consumed.map_or("?".to_string(), |u| u.to_string()), estimate.cu_limit);
+                        info!("    Fee: {} lamports, reclaim: {} lamports ({:.6} SOL)",
+                            estimate.fee, estimate.reclaim, estimate.reclaim as f64 / 1_000_000_000.0);
+                        if let Some(err) = &estimate.error {
+                            warn!("    Simulation failed: {}", err);
+                        }
+                        estimates.push(estimate);
+                    }
+                    Ok(None) => info!("  Manager: {} - no miner yet", d.manager_address),
+                    Err(e) => warn!("  Manager: {} - {}", d.manager_address, e),
+                }
+            }
+            
+            if !estimates.is_empty() {
+                let n = estimates.len() as u64;
+                let fees: u64 = estimates.iter().map(|e| e.fee).sum();
+                let reclaim: u64 = estimates.iter().map(|e| e.reclaim).sum();
+                let units: Vec<u64> = estimates.iter().filter_map(|e| e.units_consumed).collect();
+                info!("Per miner: {} lamports fee, {} accounts, {} CU (avg)",
+                    fees / n, estimates.iter().map(|e| e.accounts).max().unwrap_or(0),
+                    if units.is_empty() { 0 } else { units.iter().sum::<u64>() / units.len() as u64 });
+                info!("Total over {} miners: {} lamports fees, {} lamports ({:.6} SOL) reclaimed",
+                    n, fees, reclaim, reclaim as f64 / 1_000_000_000.0);
+            }
+            return Ok(());
+        }
+        Some(config::Command::AuditLog { limit, follow }) => {
+            tail_deploy_audit(&db_pool, limit, follow).await?;
+            return Ok(());
+        }
+        Some(config::Command::Metrics { hours }) => {
+            let since = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)?
+                .as_secs() as i64 - (hours * 3600) as i64;
+            info!("Deploy landings over the last {} hours (trigger {} slots before end, min {})",
+                hours, DEPLOY_SLOTS_BEFORE_END, MIN_SLOTS_TO_DEPLOY);
+            metrics::LandingHistogram::from_samples(db::get_deploy_landings_since(&db_pool, since).await?).log();
+            return Ok(());
+        }
         Some(config::Command::Pipeline) => {
             info!("Starting new pipeline architecture...");
             
@@ -424,8 +543,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             info!("Deploy authority: {}", deploy_authority.pubkey());
             
             // Create RPC client
-            let rpc_client = Arc::new(solana_client::rpc_client::RpcClient::new_with_commitment(
-                config.rpc_url.clone(),
+            let rpc_client = Arc::new(sender::rpc_client(
+                config.rpc_url(),
                 solana_sdk::commitment_config::CommitmentConfig::confirmed(),
             ));
             
@@ -445,25 +564,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Database: {}", config.db_path.display());
     info!("Priority fee: {} microlamports/CU", config.priority_fee);
     
-    // Initialize LUT Registry (multi-LUT support)
-    let mut registry = LutRegistry::new(&config.rpc_url, crank.deploy_authority_pubkey());
-    
-    // Load all existing LUTs owned by our authority
-    info!("Loading existing LUTs...");
-    match registry.load_all_luts() {
-        Ok(count) => info!("Found {} LUTs owned by deploy authority", count),
-        Err(e) => warn!("Error loading LUTs: {}. Will create as needed.", e),
-    }
-    
     // Find deployers we manage
-    let deployers = crank.find_deployers().await?;
+    let mut deployers = crank.find_deployers().await?;
     
     if deployers.is_empty() {
         warn!("No deployers found where we are the deploy_authority");
         warn!("Create a deployer with deploy_authority set to: {}", crank.deploy_authority_pubkey());
-        return Ok(());
+        if !config.wait_for_deployers {
+            return Ok(());
+        }
+        deployers = wait_for_deployers(&crank, Duration::from_secs(config.deployer_rescan_secs)).await?;
     }
     
+    // Initialize LUT Registry (multi-LUT support)
+    let mut registry = load_lut_registry(&config, &db_pool, crank.deploy_authority_pubkey(), &deployers).await;
+    
     info!("Managing {} deployers", deployers.len());
     for d in &deployers {
         let fee_str = if d.bps_fee == 0 {
@@ -499,6 +614,39 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             return Err(e.into());
         }
     }
+    
+    // Consolidate per-miner LUTs once there are too many to manage
+    if let Some(max_luts) = config.max_luts {
+        let lut_count = registry.lut_count();
+        if lut_count > max_luts {
+            info!("{} LUTs in use (max {}), consolidating...", lut_count, max_luts);
+            match crank.consolidate_luts(&mut registry).await {
+                Ok((created, deactivated)) => info!(
+                    "Created {} consolidated LUTs, deactivated {} per-miner LUTs ({} in use)",
+                    created, deactivated, registry.lut_count()
+                ),
+                Err(e) => warn!("LUT consolidation failed: {}. Continuing with existing LUTs.", e),
+            }
+        }
+    }
+
+    if let Err(e) = db::save_lut_registry(&db_pool, &registry.export()).await {
+        warn!("Failed to store LUT registry: {}", e);
+    }
+
+    // Checkpoint miners that fell behind while the crank wasn't running
+    if config.no_checkpoint {
+        info!("Skipping startup checkpoint catch-up (--no-checkpoint)");
+    } else if config.no_catchup {
+        info!("Skipping startup checkpoint catch-up (--no-catchup)");
+    } else {
+        info!("Checking for miners behind on checkpoints...");
+        match crank.ensure_checkpoints_current(&deployers, AUTH_ID, MAX_BATCH_SIZE_NO_LUT).await {
+            Ok(0) => info!("All miners are checkpointed"),
+            Ok(count) => info!("Checkpointed {} lagging miners", count),
+            Err(e) => warn!("Startup checkpoint catch-up failed: {}. Continuing.", e),
+        }
+    }
 
     // Wrap registry in Arc<RwLock> for sharing across async tasks
     let registry = Arc::new(RwLock::new(registry));
@@ -511,33 +659,257 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Starting main loop (poll interval: {}ms)", config.poll_interval_ms);
     info!("Strategy: deploy {} lamports/square, {} squares, {} slots before end",
         DEPLOY_AMOUNT_LAMPORTS, SQUARES_MASK.count_ones(), DEPLOY_SLOTS_BEFORE_END);
-    info!("Max batch size: {} (limited by 64 account limit)", MAX_BATCH_SIZE);
+    info!("Max batch size: {} ({} of {} accounts worst case)", MAX_BATCH_SIZE,
+        crank::autodeploy_account_count(MAX_BATCH_SIZE, MAX_BATCH_SIZE, true), crank::MAX_TX_ACCOUNTS);
+    if config.no_checkpoint {
+        info!("Checkpoints: disabled (--no-checkpoint), deploying only");
+    }
+    match config.claim_interval_rounds {
+        Some(n) => info!("Claim sweep: every {} rounds", n),
+        None => info!("Claim sweep: disabled"),
+    }
     
     let mut last_round_id: Option<u64> = None;
+    let mut last_claim_round: Option<u64> = None;
+    
+    // First Ctrl-C drains pending transactions, a second one exits immediately
+    let draining = Arc::new(AtomicBool::new(false));
+    {
+        let draining = draining.clone();
+        tokio::spawn(async move {
+            if tokio::signal::ctrl_c().await.is_err() {
+                return;
+            }
+            info!("Shutdown requested: no new deploys, draining pending transactions (Ctrl-C again to force exit)");
+            draining.store(true, Ordering::SeqCst);
+            if tokio::signal::ctrl_c().await.is_ok() {
+                warn!("Forced exit, pending transactions were not drained");
+                std::process::exit(130);
+            }
+        });
+    }
     
     loop {
+        if draining.load(Ordering::SeqCst) {
+            drain_pending_txs(&crank, &db_pool, Duration::from_secs(config.drain_timeout_secs)).await;
+            db_pool.close().await;
+            info!("Crank stopped");
+            return Ok(());
+        }
+        
         // Check pending transactions first
         if let Err(e) = crank.check_pending_txs().await {
             error!("Error checking pending txs: {}", e);
         }
         
         // Run the deployment strategy with cached miner data
-        if let Err(e) = run_strategy(&crank, &deployers, &mut last_round_id, &mut miner_cache, &registry).await {
+        if let Err(e) = run_strategy(&crank, &deployers, &mut last_round_id, &mut miner_cache, &registry, config.no_checkpoint).await {
             error!("Strategy error: {}", e);
         }
         
+        // Periodically sweep SOL rewards (opt-in)
+        if let (Some(interval), Some(round_id)) = (config.claim_interval_rounds, last_round_id) {
+            if claim_sweep_due(last_claim_round, round_id, interval) {
+                last_claim_round = Some(round_id);
+                sweep_claims(&crank, &deployers, &mut miner_cache, round_id).await;
+            }
+        }
+        
         tokio::time::sleep(poll_interval).await;
     }
 }
 
+/// Re-scan for deployers every `interval` until at least one shows up
+async fn wait_for_deployers(
+    crank: &crank::Crank,
+    interval: Duration,
+) -> Result<Vec<config::DeployerInfo>, crank::CrankError> {
+    let started = Instant::now();
+    loop {
+        info!("Waiting for deployers, re-scanning in {}s ({}s elapsed)", interval.as_secs(), started.elapsed().as_secs());
+        tokio::time::sleep(interval).await;
+        
+        match crank.find_deployers().await {
+            Ok(deployers) if !deployers.is_empty() => {
+                info!("Found {} deployers after {}s", deployers.len(), started.elapsed().as_secs());
+                return Ok(deployers);
+            }
+            Ok(_) => {}
+            Err(e) => warn!("Deployer scan failed: {}", e),
+        }
+    }
+}
+
+/// Poll pending transactions until none are left or `timeout` elapses
+async fn drain_pending_txs(
+    crank: &crank::Crank,
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    timeout: Duration,
+) {
+    let started = Instant::now();
+    let mut last_remaining = None;
+    loop {
+        if let Err(e) = crank.check_pending_txs().await {
+            error!("Error checking pending txs: {}", e);
+        }
+        
+        let remaining = match db::get_pending_txs(pool).await {
+            Ok(pending) => pending.len(),
+            Err(e) => {
+                error!("Failed to read pending txs: {}", e);
+                return;
+            }
+        };
+        if remaining == 0 {
+            info!("Drain complete, no pending transactions");
+            return;
+        }
+        if last_remaining != Some(remaining) {
+            info!("Draining: {} pending transactions ({}s elapsed)", remaining, started.elapsed().as_secs());
+            last_remaining = Some(remaining);
+        }
+        if started.elapsed() >= timeout {
+            warn!("Drain timed out after {}s with {} transactions still pending", timeout.as_secs(), remaining);
+            return;
+        }
+        
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
+
+/// LUT registry for the run loop
+/// Uses the LUTs stored in the database (validated on-chain) when they cover the
+/// shared LUT and every deployer's miner, and scans the chain otherwise so no
+/// miner gets a duplicate LUT.
+async fn load_lut_registry(
+    config: &Config,
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    authority: solana_sdk::pubkey::Pubkey,
+    deployers: &[config::DeployerInfo],
+) -> LutRegistry {
+    match db::get_lut_registry(pool, &authority.to_string()).await {
+        Ok(Some(snapshot)) => {
+            let mut registry = LutRegistry::new(config.rpc_url(), authority);
+            match registry.import(&snapshot) {
+                Ok(imported) => {
+                    let complete = imported.shared && imported.rejected.is_empty() && deployers.iter()
+                        .all(|d| registry.has_miner_lut(&get_miner_auth_pda(d.manager_address, AUTH_ID)));
+                    if complete {
+                        info!("Loaded {} LUTs from database", registry.lut_count());
+                        return registry;
+                    }
+                    info!("Stored LUTs are incomplete or stale, rescanning");
+                }
+                Err(e) => warn!("Failed to validate stored LUTs: {}", e),
+            }
+        }
+        Ok(None) => {}
+        Err(e) => warn!("Failed to read stored LUTs: {}", e),
+    }
+    
+    let mut registry = LutRegistry::new(config.rpc_url(), authority);
+    
+    // Load all existing LUTs owned by our authority
+    info!("Loading existing LUTs...");
+    match registry.load_all_luts() {
+        Ok(count) => info!("Found {} LUTs owned by deploy authority", count),
+        Err(e) => warn!("Error loading LUTs: {}. Will create as needed.", e),
+    }
+    registry
+}
+
+/// Print the last `limit` deploy audit entries, optionally following new ones
+async fn tail_deploy_audit(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    limit: i64,
+    follow: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut last_id = 0;
+    let mut batch = limit;
+    loop {
+        for entry in db::get_deploy_audit_after(pool, last_id, batch).await? {
+            info!(
+                "{} round={} manager={} auth={} mask={:#09x} amount={} fee={} tip={} {} {}{}",
+                entry.timestamp,
+                entry.round_id,
+                entry.manager_key,
+                entry.auth_id,
+                entry.squares_mask,
+                entry.amount,
+                entry.priority_fee,
+                entry.jito_tip,
+                entry.result,
+                entry.signature,
+                entry.error_message.map(|e| format!(" ({})", e)).unwrap_or_default(),
+            );
+            last_id = entry.id;
+        }
+        
+        if !follow {
+            return Ok(());
+        }
+        // Only the initial read is capped; follow everything after that
+        batch = i64::MAX;
+        tokio::time::sleep(Duration::from_secs(2)).await;
+    }
+}
+
+/// Whether the claim sweep should run this round
+fn claim_sweep_due(last_claim_round: Option<u64>, round_id: u64, interval: u64) -> bool {
+    last_claim_round.map_or(true, |last| round_id >= last.saturating_add(interval.max(1)))
+}
+
+/// Recycle claimable SOL for every cached miner with nonzero rewards
+async fn sweep_claims(
+    crank: &crank::Crank,
+    deployers: &[config::DeployerInfo],
+    miner_cache: &mut miner_cache::MinerCache,
+    round_id: u64,
+) {
+    let claims: Vec<_> = deployers.iter()
+        .filter_map(|d| {
+            let miner = miner_cache.get_by_deployer(&d.deployer_address)?;
+            if !miner_cache.has_sol_to_recycle(&miner.miner_address) {
+                return None;
+            }
+            // The current round can't be checkpointed until it ends
+            let checkpoint_round = miner_cache.needs_checkpoint(&miner.miner_address)
+                .filter(|round| *round < round_id);
+            Some((d, AUTH_ID, checkpoint_round, miner.rewards_sol))
+        })
+        .collect();
+    
+    if claims.is_empty() {
+        info!("Claim sweep (round {}): nothing to claim", round_id);
+        return;
+    }
+    
+    let total: u64 = claims.iter().map(|(_, _, _, rewards)| rewards).sum();
+    info!("Claim sweep (round {}): {} miners, {:.6} SOL", round_id, claims.len(), total as f64 / 1_000_000_000.0);
+    
+    for batch in claims.chunks(MAX_CLAIM_BATCH_SIZE) {
+        match crank.execute_batched_claim_sol(batch, round_id).await {
+            Ok(sig) => info!("✓ Claimed SOL for {} miners: {}", batch.len(), sig),
+            Err(e) => error!("✗ Claim failed for {} miners: {}", batch.len(), e),
+        }
+    }
+    
+    // Balances and checkpoints changed
+    miner_cache.invalidate_balances();
+}
+
 /// Deployment strategy - customize this for your use case
 /// Uses miner cache to minimize RPC calls
+///
+/// With `no_checkpoint` no checkpoint/recycle instructions are sent: miners
+/// are deployed as-is and ones that can't afford a deploy are left alone.
 async fn run_strategy(
     crank: &crank::Crank,
     deployers: &[config::DeployerInfo],
     last_round_id: &mut Option<u64>,
     miner_cache: &mut miner_cache::MinerCache,
     registry: &Arc<RwLock<LutRegistry>>,
+    no_checkpoint: bool,
 ) -> Result<(), crank::CrankError> {
     // Get current board state (single RPC call)
     let (board, current_slot) = crank.get_board()?;
@@ -599,11 +971,20 @@ async fn run_strategy(
         }
         
         // Check if checkpoint is needed using cache
-        let checkpoint_round = miner_cache.needs_checkpoint(&miner_address);
+        let mut checkpoint_round = miner_cache.needs_checkpoint(&miner_address);
         
         // Get cached balance
         let balance = miner_cache.get_balance(&miner_address).unwrap_or(0);
         
+        if no_checkpoint {
+            if let Some(round) = checkpoint_round.take().filter(|_| balance >= required) {
+                // The deploy is still attempted but likely fails until the
+                // miner is checkpointed out-of-band
+                warn!("Miner {} ({}) is behind on checkpoints (round {}) and --no-checkpoint is set; its deploy may fail",
+                    miner_address, deployer.manager_address, round);
+            }
+        }
+        
         // Check if miner has SOL rewards to recycle
         let has_sol_to_recycle = miner_cache.has_sol_to_recycle(&miner_address);
         
@@ -647,8 +1028,10 @@ async fn run_strategy(
         let reg = registry.read().await;
         
         for batch in to_deploy.chunks(MAX_BATCH_SIZE) {
-            let miner_addresses: Vec<_> = batch.iter()
-                .filter_map(|(d, _, _, _, _, _)| miner_cache.get_miner_address_for_deployer(&d.deployer_address))
+            let miner_deploys: Vec<_> = batch.iter()
+                .filter_map(|(d, _, _, _, mask, _)| {
+                    miner_cache.get_miner_address_for_deployer(&d.deployer_address).map(|addr| (addr, *mask))
+                })
                 .collect();
             let batch_vec: Vec<_> = batch.to_vec();
             let checkpoints_in_batch = batch.iter().filter(|(_, _, _, _, _, cp)| cp.is_some()).count();
@@ -658,8 +1041,25 @@ async fn run_strategy(
                 Ok(sig) => {
                     info!("✓ Autodeploy ({} deployers, {} checkpoints): {}", 
                         batch.len(), checkpoints_in_batch, sig);
-                    // Mark miners as deployed in cache
-                    miner_cache.mark_deployed(&miner_addresses, board.round_id);
+                    match crank.record_deploy_landing(&sig, board.round_id, board.end_slot).await {
+                        Ok(slots_before_end) => info!("Landed {} slots before round end", slots_before_end),
+                        Err(e) => warn!("Failed to record landing slot for {}: {}", sig, e),
+                    }
+                    // Only miners whose deploy shows up on-chain count as done,
+                    // the rest are picked up again on the next poll
+                    match miner_cache.verify_deployed(crank.rpc_client(), &miner_deploys, board.round_id) {
+                        Ok(missing) => {
+                            for miner_address in &missing {
+                                warn!("Deploy for miner {} not found in round {} after {}, re-queuing",
+                                    miner_address, board.round_id, sig);
+                            }
+                        }
+                        Err(e) => {
+                            warn!("Failed to verify deploys for {}: {}", sig, e);
+                            let miner_addresses: Vec<_> = miner_deploys.iter().map(|(addr, _)| *addr).collect();
+                            miner_cache.mark_deployed(&miner_addresses, board.round_id);
+                        }
+                    }
                 }
                 Err(e) => {
                     error!("✗ Autodeploy failed: {}", e);
@@ -0,0 +1,69 @@
+//! Deploy landing metrics
+//!
+//! Each confirmed autodeploy records how many slots before the round's
+//! end_slot it landed (`deploy_landings` table). The histogram here shows
+//! whether DEPLOY_SLOTS_BEFORE_END / MIN_SLOTS_TO_DEPLOY put deploys where
+//! they're meant to land.
+
+use tracing::info;
+
+/// Lower edges of the histogram buckets, in slots before round end
+/// (the last bucket is open-ended, deploys after end_slot count as late)
+const BUCKET_EDGES: [i64; 7] = [0, 5, 10, 25, 50, 100, 150];
+
+/// Histogram of slots-before-end for landed deploys
+#[derive(Debug, Clone, Default)]
+pub struct LandingHistogram {
+    /// Landed after end_slot
+    pub late: u64,
+    /// Count per `BUCKET_EDGES` bucket
+    pub buckets: [u64; BUCKET_EDGES.len()],
+    samples: Vec<i64>,
+}
+
+impl LandingHistogram {
+    pub fn from_samples(samples: Vec<i64>) -> Self {
+        let mut histogram = Self::default();
+        for &slots in &samples {
+            match BUCKET_EDGES.iter().rposition(|&edge| slots >= edge) {
+                Some(i) => histogram.buckets[i] += 1,
+                None => histogram.late += 1,
+            }
+        }
+        histogram.samples = samples;
+        histogram.samples.sort_unstable();
+        histogram
+    }
+
+    pub fn count(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Value at percentile `p` (0-100)
+    pub fn percentile(&self, p: usize) -> Option<i64> {
+        let last = self.samples.len().checked_sub(1)?;
+        Some(self.samples[last * p.min(100) / 100])
+    }
+
+    /// Log the histogram, one line per bucket
+    pub fn log(&self) {
+        let (Some(min), Some(p50), Some(max)) = (self.percentile(0), self.percentile(50), self.percentile(100)) else {
+            info!("No landed deploys recorded");
+            return;
+        };
+        info!("{} landed deploys, slots before end: min {} / median {} / max {}", self.count(), min, p50, max);
+
+        let width = self.buckets.iter().chain([&self.late]).copied().max().unwrap_or(0).max(1);
+        let bar = |n: u64| "#".repeat((n * 40).div_ceil(width) as usize);
+        if self.late > 0 {
+            info!("  {:>9}: {:>6} {}", "late", self.late, bar(self.late));
+        }
+        for (i, &n) in self.buckets.iter().enumerate() {
+            let label = match BUCKET_EDGES.get(i + 1) {
+                Some(next) => format!("{}-{}", BUCKET_EDGES[i], next - 1),
+                None => format!("{}+", BUCKET_EDGES[i]),
+            };
+            info!("  {:>9}: {:>6} {}", label, n, bar(n));
+        }
+    }
+}
@@ -115,6 +115,46 @@ impl MinerCache {
         self.needs_balance_refresh = true;
     }
 
+    /// Confirm deploys landed by reading the miner accounts back
+    /// Takes (miner_address, squares_mask) pairs and returns the miners whose
+    /// account doesn't show a deploy in `round_id` on every masked square.
+    /// Landed miners are marked deployed; the rest are left for the next poll.
+    pub fn verify_deployed(
+        &mut self,
+        rpc_client: &RpcClient,
+        deploys: &[(Pubkey, u32)],
+        round_id: u64,
+    ) -> Result<Vec<Pubkey>, CrankError> {
+        let mut missing = Vec::new();
+        for chunk in deploys.chunks(100) {
+            let addresses: Vec<Pubkey> = chunk.iter().map(|(addr, _)| *addr).collect();
+            let accounts = rpc_client
+                .get_multiple_accounts(&addresses)
+                .map_err(|e| CrankError::Rpc(format!("Failed to fetch miners: {}", e)))?;
+
+            for ((miner_address, squares_mask), account) in chunk.iter().zip(accounts.iter()) {
+                let miner = account.as_ref().and_then(|a| Miner::try_from_bytes(&a.data).ok());
+                let landed = miner.map_or(false, |m| {
+                    m.round_id == round_id
+                        && (0..25).filter(|i| squares_mask & (1 << i) != 0).all(|i| m.deployed[i] > 0)
+                });
+                if let (Some(cached), Some(miner)) = (self.miners.get_mut(miner_address), miner) {
+                    cached.checkpoint_id = miner.checkpoint_id;
+                    cached.round_id = miner.round_id;
+                    cached.has_deployed = landed;
+                    cached.rewards_sol = miner.rewards_sol;
+                    cached.exists = true;
+                }
+                if !landed {
+                    missing.push(*miner_address);
+                }
+            }
+        }
+        // Balance will have changed after deploy
+        self.needs_balance_refresh = true;
+        Ok(missing)
+    }
+
     /// Refresh cache using batch RPC calls
     /// Returns the number of miners fetched
     pub fn refresh(
@@ -36,7 +36,7 @@ pub mod types;
 use std::sync::Arc;
 
 use solana_client::rpc_client::RpcClient;
-use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::{pubkey::Pubkey, signature::{Keypair, Signer}};
 use tokio::sync::mpsc;
 use tracing::{error, info};
 
@@ -63,7 +63,7 @@ pub async fn run_pipeline(
 
     // Initialize shared state
     let shared = Arc::new(SharedState::new(
-        &config.rpc_url,
+        config.rpc_url(),
         deploy_authority.pubkey(),
     ));
 
@@ -195,14 +195,14 @@ pub async fn run_pipeline(
             shared.clone(),
             senders.clone(),
             tx_sender_rx,
-            config.rpc_url.clone(),
+            config.rpc_url().to_string(),
         )),
         // Confirmation System
         tokio::spawn(confirmation::run(
             shared.clone(),
             senders.clone(),
             confirmation_rx,
-            config.rpc_url.clone(),
+            config.rpc_url().to_string(),
         )),
         // Failure Handler (processes failed batches)
         tokio::spawn(failure_handler::run(
@@ -235,7 +235,7 @@ pub async fn run_pipeline(
                 shared.stats.reset();
 
                 // Discover deployers
-                let deployers = match discover_deployers(&rpc_client, &deploy_authority).await {
+                let deployers = match discover_deployers(&rpc_client, &deploy_authority, &config.manager_allowlist).await {
                     Ok(d) => d,
                     Err(e) => {
                         error!("Failed to discover deployers: {}", e);
@@ -314,6 +314,7 @@ pub async fn run_pipeline(
 async fn discover_deployers(
     rpc_client: &RpcClient,
     deploy_authority: &Keypair,
+    manager_allowlist: &[Pubkey],
 ) -> Result<Vec<DeployerInfo>, CrankError> {
     use evore::state::Deployer;
     use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
@@ -382,6 +383,6 @@ async fn discover_deployers(
         }
     }
 
-    Ok(deployers)
+    crate::crank::apply_manager_allowlist(rpc_client, deployers, manager_allowlist)
 }
 
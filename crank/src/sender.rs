@@ -1,14 +1,122 @@
 //! Transaction sender module
 //!
-//! Handles sending transactions via standard RPC
+//! Handles sending transactions via standard RPC. Every transaction the crank
+//! sends goes through `send_rpc` / `send_versioned_rpc`, so safe mode is
+//! enforced there. Custom RPC headers (`--rpc-header` / `RPC_HEADERS`) are
+//! set once here and applied to both the sender and every `rpc_client`.
 
+use solana_client::rpc_client::{RpcClient, RpcClientConfig};
+use solana_rpc_client::http_sender::HttpSender;
 use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    message::VersionedMessage,
     signature::Signature,
     transaction::{Transaction, VersionedTransaction},
 };
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
 use std::time::Duration;
-use tracing::info;
+use tracing::{info, warn};
+
+/// Set by `--safe-mode`: refuse every send and log the instructions instead
+static SAFE_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Turn safe mode on for the rest of the process
+pub fn enable_safe_mode() {
+    SAFE_MODE.store(true, Ordering::SeqCst);
+}
+
+/// Extra headers sent with every RPC request (auth tokens for private RPCs)
+static RPC_HEADERS: OnceLock<Vec<(String, String)>> = OnceLock::new();
+
+/// Parse the `RPC_HEADERS` JSON object and `key=value` pairs (pairs win)
+pub fn parse_rpc_headers(json: Option<&str>, pairs: &[String]) -> Result<Vec<(String, String)>, String> {
+    let mut headers: Vec<(String, String)> = Vec::new();
+    let mut push = |key: String, value: String| {
+        reqwest::header::HeaderName::from_bytes(key.as_bytes())
+            .map_err(|_| format!("Invalid RPC header name '{}'", key))?;
+        reqwest::header::HeaderValue::from_str(&value)
+            .map_err(|_| format!("Invalid value for RPC header '{}'", key))?;
+        headers.retain(|(k, _)| !k.eq_ignore_ascii_case(&key));
+        headers.push((key, value));
+        Ok::<(), String>(())
+    };
+    
+    if let Some(json) = json.filter(|j| !j.trim().is_empty()) {
+        let map: serde_json::Map<String, serde_json::Value> = serde_json::from_str(json)
+            .map_err(|e| format!("RPC_HEADERS must be a JSON object: {}", e))?;
+        for (key, value) in map {
+            let value = match value {
+                serde_json::Value::String(s) => s,
+                other => other.to_string(),
+            };
+            push(key, value)?;
+        }
+    }
+    
+    for pair in pairs {
+        let (key, value) = pair.split_once('=')
+            .ok_or_else(|| format!("Invalid --rpc-header '{}': expected key=value", pair))?;
+        push(key.trim().to_string(), value.trim().to_string())?;
+    }
+    Ok(headers)
+}
+
+/// Use these headers for every RPC client / sender built afterwards
+pub fn set_rpc_headers(headers: Vec<(String, String)>) {
+    let _ = RPC_HEADERS.set(headers);
+}
+
+fn rpc_headers() -> &'static [(String, String)] {
+    RPC_HEADERS.get().map(|h| h.as_slice()).unwrap_or(&[])
+}
+
+/// RpcClient carrying the configured RPC headers.
+///
+/// Websocket subscriptions (PubsubClient) only take a URL, so headers can't be
+/// added there; put ws auth in the URL instead.
+pub fn rpc_client(rpc_url: &str, commitment: CommitmentConfig) -> RpcClient {
+    use solana_client::client_error::reqwest::{header, Client};
+    
+    if rpc_headers().is_empty() {
+        return RpcClient::new_with_commitment(rpc_url.to_string(), commitment);
+    }
+    let mut headers = HttpSender::default_headers();
+    for (key, value) in rpc_headers() {
+        // Validated in parse_rpc_headers
+        if let (Ok(name), Ok(value)) = (header::HeaderName::from_bytes(key.as_bytes()), header::HeaderValue::from_str(value)) {
+            headers.insert(name, value);
+        }
+    }
+    let timeout = Duration::from_secs(30);
+    let client = Client::builder()
+        .default_headers(headers)
+        .timeout(timeout)
+        .pool_idle_timeout(timeout)
+        .build()
+        .expect("Failed to create RPC HTTP client");
+    RpcClient::new_sender(HttpSender::new_with_client(rpc_url, client), RpcClientConfig::with_commitment(commitment))
+}
+
+/// Refuse to send in safe mode, logging what would have been sent
+fn safe_mode_guard(message: &VersionedMessage) -> Result<(), SendError> {
+    if !SAFE_MODE.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+    let keys = message.static_account_keys();
+    // Indices past the static keys come from address lookup tables
+    let key = |index: u8| keys.get(index as usize)
+        .map(|k| k.to_string())
+        .unwrap_or_else(|| format!("lut#{}", index as usize - keys.len()));
+    warn!("Safe mode: not sending transaction with {} instruction(s)", message.instructions().len());
+    for (i, ix) in message.instructions().iter().enumerate() {
+        let accounts: Vec<String> = ix.accounts.iter().map(|a| key(*a)).collect();
+        let data: String = ix.data.iter().map(|b| format!("{:02x}", b)).collect();
+        warn!("  [{}] {} accounts=[{}] data={}", i, key(ix.program_id_index), accounts.join(", "), data);
+    }
+    Err(SendError::SafeMode)
+}
 
 /// Transaction sender
 pub struct TxSender {
@@ -18,7 +126,14 @@ pub struct TxSender {
 
 impl TxSender {
     pub fn new(rpc_url: String) -> Self {
+        let mut headers = reqwest::header::HeaderMap::new();
+        for (key, value) in rpc_headers() {
+            if let (Ok(name), Ok(value)) = (reqwest::header::HeaderName::from_bytes(key.as_bytes()), reqwest::header::HeaderValue::from_str(value)) {
+                headers.insert(name, value);
+            }
+        }
         let client = reqwest::Client::builder()
+            .default_headers(headers)
             .timeout(Duration::from_secs(30))
             .build()
             .unwrap();
@@ -31,6 +146,8 @@ impl TxSender {
     
     /// Send a transaction via standard RPC (sendTransaction)
     pub async fn send_rpc(&self, tx: &Transaction) -> Result<Signature, SendError> {
+        safe_mode_guard(&VersionedMessage::Legacy(tx.message.clone()))?;
+        
         let tx_bytes = bincode::serialize(tx)
             .map_err(|e| SendError::Serialize(e.to_string()))?;
         let tx_base64 = base64::encode(&tx_bytes);
@@ -188,6 +305,8 @@ impl TxSender {
     
     /// Send a versioned transaction via standard RPC
     pub async fn send_versioned_rpc(&self, tx: &VersionedTransaction) -> Result<Signature, SendError> {
+        safe_mode_guard(&tx.message)?;
+        
         let tx_bytes = bincode::serialize(tx)
             .map_err(|e| SendError::Serialize(e.to_string()))?;
         let tx_base64 = base64::encode(&tx_bytes);
@@ -380,6 +499,8 @@ pub enum SendError {
     TransactionFailed(String),
     #[error("Timeout waiting for confirmation: {0}")]
     Timeout(String),
+    #[error("Safe mode: transaction not sent")]
+    SafeMode,
 }
 
 /// Confirmation result for batch operations
@@ -0,0 +1,5 @@
+-- ClickHouse Migration 032: RPC Request Credits
+-- Provider credit cost per request (Helius weights methods differently)
+
+ALTER TABLE ore_stats.rpc_requests
+    ADD COLUMN IF NOT EXISTS credits UInt32 DEFAULT 0 AFTER rate_limit_reset;
@@ -0,0 +1,5 @@
+-- ClickHouse Migration 033: Request Log IDs
+-- Per-request id returned in error bodies and the X-Request-Id header
+
+ALTER TABLE ore_stats.request_logs
+    ADD COLUMN IF NOT EXISTS request_id String DEFAULT '' AFTER user_agent;
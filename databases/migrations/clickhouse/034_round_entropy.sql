@@ -0,0 +1,26 @@
+-- ClickHouse Migration 034: Create round_entropy table
+-- Records the entropy var behind each finalized round's winning square
+-- so the outcome can be independently re-derived
+
+CREATE TABLE IF NOT EXISTS ore_stats.round_entropy (
+    round_id UInt64,
+    
+    -- Entropy var account as read at finalization
+    var_address String,
+    provider String,
+    commit FixedString(32),
+    seed FixedString(32),
+    slot_hash FixedString(32),
+    value FixedString(32),
+    samples UInt64,
+    start_at UInt64,
+    end_at UInt64,
+    
+    -- Round outcome the entropy is checked against
+    round_slot_hash FixedString(32),
+    winning_square UInt8,
+    
+    -- Metadata
+    created_at DateTime64(3) DEFAULT now64(3)
+) ENGINE = ReplacingMergeTree(created_at)
+ORDER BY round_id;
@@ -14,7 +14,11 @@ import { API_URL, API_RATE_LIMIT } from "./constants";
 // ============================================================================
 
 export interface ApiError {
-  error: string;
+  error: {
+    code: string;
+    message: string;
+    request_id?: string;
+  };
 }
 
 // Admin types
@@ -790,10 +794,9 @@ class ApiClient {
     }
 
     if (!response.ok) {
-      const error: ApiError = await response.json().catch(() => ({ 
-        error: `HTTP ${response.status}: ${response.statusText}` 
-      }));
-      throw new Error(error.error || `Request failed: ${response.status}`);
+      const body: Partial<ApiError> = await response.json().catch(() => ({}));
+      const message = body.error?.message || `HTTP ${response.status}: ${response.statusText}`;
+      throw new Error(body.error?.request_id ? `${message} (request ${body.error.request_id})` : message);
     }
 
     return response.json();
@@ -1185,6 +1188,7 @@ class ApiClient {
     roundIdGte?: number;
     roundIdLte?: number;
     miner?: string;
+    square?: number;
     winnerOnly?: boolean;
   }): Promise<CursorResponse<HistoricalDeployment>> {
     const params = new URLSearchParams();
@@ -1193,6 +1197,7 @@ class ApiClient {
     if (options?.roundIdGte) params.set("round_id_gte", options.roundIdGte.toString());
     if (options?.roundIdLte) params.set("round_id_lte", options.roundIdLte.toString());
     if (options?.miner) params.set("miner", options.miner);
+    if (options?.square !== undefined) params.set("square", options.square.toString());
     if (options?.winnerOnly) params.set("winner_only", "true");
     const query = params.toString() ? `?${params.toString()}` : "";
     return this.request("GET", `/history/deployments${query}`);
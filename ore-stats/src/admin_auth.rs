@@ -12,10 +12,9 @@ use std::sync::Arc;
 use argon2::{Argon2, PasswordHash, PasswordVerifier};
 use axum::{
     extract::{Request, State},
-    http::{HeaderMap, StatusCode},
+    http::HeaderMap,
     middleware::Next,
-    response::{IntoResponse, Response},
-    Json,
+    response::Response,
 };
 use chrono::{DateTime, Duration, Utc};
 use rand::RngCore;
@@ -24,6 +23,7 @@ use sha2::{Digest, Sha256};
 use sqlx::{PgPool, Row};
 use uuid::Uuid;
 
+use crate::app_error::AppError;
 use crate::app_state::AppState;
 
 // ============================================================================
@@ -43,17 +43,6 @@ const FAILED_ATTEMPTS_WINDOW_MINUTES: i64 = 15;
 // Types
 // ============================================================================
 
-#[derive(Debug, Serialize)]
-pub struct AuthError {
-    pub error: String,
-}
-
-impl IntoResponse for AuthError {
-    fn into_response(self) -> Response {
-        (StatusCode::UNAUTHORIZED, Json(self)).into_response()
-    }
-}
-
 #[derive(Debug, Deserialize)]
 pub struct LoginRequest {
     pub password: String,
@@ -406,16 +395,14 @@ pub async fn require_admin_auth(
     State(state): State<Arc<AppState>>,
     request: Request,
     next: Next,
-) -> Result<Response, AuthError> {
+) -> Result<Response, AppError> {
     let headers = request.headers();
     
     // Check IP blacklist first
     if let Some(ip) = extract_client_ip(headers) {
         match is_ip_blacklisted(&state.postgres, ip).await {
             Ok(true) => {
-                return Err(AuthError {
-                    error: "IP address is blacklisted".to_string(),
-                });
+                return Err(AppError::Unauthorized("IP address is blacklisted".to_string()));
             }
             Err(e) => {
                 tracing::error!("Failed to check IP blacklist: {}", e);
@@ -426,23 +413,17 @@ pub async fn require_admin_auth(
     }
     
     // Extract and validate token
-    let token = extract_bearer_token(headers).ok_or_else(|| AuthError {
-        error: "Missing or invalid Authorization header".to_string(),
-    })?;
+    let token = extract_bearer_token(headers).ok_or_else(|| AppError::Unauthorized("Missing or invalid Authorization header".to_string()))?;
     
     match validate_session(&state.postgres, &token).await {
         Ok(Some(_session)) => {
             // Valid session, proceed
             Ok(next.run(request).await)
         }
-        Ok(None) => Err(AuthError {
-            error: "Invalid or expired session".to_string(),
-        }),
+        Ok(None) => Err(AppError::Unauthorized("Invalid or expired session".to_string())),
         Err(e) => {
             tracing::error!("Session validation error: {}", e);
-            Err(AuthError {
-                error: "Authentication error".to_string(),
-            })
+            Err(AppError::Unauthorized("Authentication error".to_string()))
         }
     }
 }
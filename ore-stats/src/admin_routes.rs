@@ -8,18 +8,27 @@ use std::sync::Arc;
 
 use axum::{
     extract::{Path, Query, State},
-    http::{HeaderMap, StatusCode},
+    http::HeaderMap,
     response::Json,
     routing::{delete, get, post},
     Router,
 };
 use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use steel::AccountDeserialize;
+
+use evore::network::NetworkConfig;
+use evore::ore_api::{Automation, Board, Config, Miner, OreAccount, Round, Stake, Treasury};
+use evore::state::{Deployer, EvoreAccount, Manager};
 
 use crate::admin_auth::{
     self, extract_bearer_token, extract_client_ip, is_ip_blacklisted,
-    record_failed_attempt, verify_password, AuthError, BlacklistEntry, LoginResponse,
+    record_failed_attempt, verify_password, BlacklistEntry, LoginResponse,
 };
+use crate::app_error::AppError;
+use crate::app_rpc::{MinersLoadStats, RouteStatsEntry};
 use crate::app_state::AppState;
+use crate::sse::SseStatsSnapshot;
 
 // ============================================================================
 // Response Types
@@ -38,6 +47,12 @@ pub struct AdminMetricsResponse {
     pub ore_holders_cached: usize,
     pub pending_round_id: u64,
     pub pending_deployments: usize,
+    /// Events dropped for slow SSE clients, per stream
+    pub sse: SseStatsSnapshot,
+    /// RPC calls served and failed per route and provider
+    pub rpc_routes: Vec<RouteStatsEntry>,
+    /// Duration of the full miners GPA loads
+    pub miners_load: MinersLoadStats,
 }
 
 #[derive(Debug, Serialize)]
@@ -101,6 +116,15 @@ pub struct RpcDailyResponse {
     pub daily: Vec<crate::clickhouse::RpcDailyRow>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct RpcCreditsResponse {
+    pub days: u32,
+    pub total_credits: u64,
+    /// Average credits per day over the days with usage
+    pub avg_daily_credits: u64,
+    pub daily: Vec<crate::clickhouse::RpcCreditsRow>,
+}
+
 // ============================================================================
 // Request Types
 // ============================================================================
@@ -149,16 +173,13 @@ pub async fn login(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
     Json(req): Json<LoginRequest>,
-) -> Result<Json<LoginResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<LoginResponse>, AppError> {
     let client_ip = extract_client_ip(&headers)
         .unwrap_or_else(|| "127.0.0.1".parse().unwrap());
     
     // Check if IP is blacklisted
     if is_ip_blacklisted(&state.postgres, client_ip).await.unwrap_or(false) {
-        return Err((
-            StatusCode::FORBIDDEN,
-            Json(AuthError { error: "IP address is blacklisted".to_string() }),
-        ));
+        return Err(AppError::Forbidden("IP address is blacklisted".to_string()));
     }
     
     // Verify password against hash stored in state (hashed at startup)
@@ -172,10 +193,7 @@ pub async fn login(
             tracing::warn!("IP {} blacklisted after too many failed login attempts", client_ip);
         }
         
-        return Err((
-            StatusCode::UNAUTHORIZED,
-            Json(AuthError { error: "Invalid password".to_string() }),
-        ));
+        return Err(AppError::Unauthorized("Invalid password".to_string()));
     }
     
     // Create session
@@ -187,10 +205,7 @@ pub async fn login(
         .await
         .map_err(|e| {
             tracing::error!("Failed to create session: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to create session".to_string() }),
-            )
+            AppError::Internal("Failed to create session".to_string())
         })?;
     
     tracing::info!("Admin login successful from {}", client_ip);
@@ -205,22 +220,16 @@ pub async fn login(
 pub async fn logout(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-) -> Result<Json<MessageResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<MessageResponse>, AppError> {
     let token = extract_bearer_token(&headers).ok_or_else(|| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(AuthError { error: "Missing token".to_string() }),
-        )
+        AppError::BadRequest("Missing token".to_string())
     })?;
     
     admin_auth::revoke_session(&state.postgres, &token)
         .await
         .map_err(|e| {
             tracing::error!("Failed to revoke session: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to revoke session".to_string() }),
-            )
+            AppError::Internal("Failed to revoke session".to_string())
         })?;
     
     Ok(Json(MessageResponse {
@@ -245,21 +254,21 @@ pub async fn get_admin_metrics(
         ore_holders_cached,
         pending_round_id,
         pending_deployments,
+        sse: state.sse_stats.snapshot(),
+        rpc_routes: state.rpc.route_stats(),
+        miners_load: state.rpc.miners_load_stats(),
     })
 }
 
 /// GET /admin/blacklist - View all blacklisted IPs
 pub async fn get_blacklist(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<BlacklistResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<BlacklistResponse>, AppError> {
     let entries = admin_auth::get_blacklist(&state.postgres)
         .await
         .map_err(|e| {
             tracing::error!("Failed to get blacklist: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get blacklist".to_string() }),
-            )
+            AppError::Internal("Failed to get blacklist".to_string())
         })?;
     
     let total = entries.len();
@@ -272,22 +281,16 @@ pub async fn get_blacklist(
 pub async fn add_to_blacklist(
     State(state): State<Arc<AppState>>,
     Json(req): Json<BlacklistRequest>,
-) -> Result<Json<MessageResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<MessageResponse>, AppError> {
     let ip: IpAddr = req.ip.parse().map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(AuthError { error: "Invalid IP address".to_string() }),
-        )
+        AppError::BadRequest("Invalid IP address".to_string())
     })?;
     
     admin_auth::blacklist_ip(&state.postgres, ip, &req.reason, req.permanent)
         .await
         .map_err(|e| {
             tracing::error!("Failed to blacklist IP: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to blacklist IP".to_string() }),
-            )
+            AppError::Internal("Failed to blacklist IP".to_string())
         })?;
     
     tracing::info!("Admin blacklisted IP {}: {}", ip, req.reason);
@@ -301,22 +304,16 @@ pub async fn add_to_blacklist(
 pub async fn remove_from_blacklist(
     State(state): State<Arc<AppState>>,
     Path(ip_str): Path<String>,
-) -> Result<Json<MessageResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<MessageResponse>, AppError> {
     let ip: IpAddr = ip_str.parse().map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(AuthError { error: "Invalid IP address".to_string() }),
-        )
+        AppError::BadRequest("Invalid IP address".to_string())
     })?;
     
     let removed = admin_auth::unblacklist_ip(&state.postgres, ip)
         .await
         .map_err(|e| {
             tracing::error!("Failed to unblacklist IP: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to unblacklist IP".to_string() }),
-            )
+            AppError::Internal("Failed to unblacklist IP".to_string())
         })?;
     
     if removed {
@@ -325,25 +322,19 @@ pub async fn remove_from_blacklist(
             message: format!("IP {} removed from blacklist", ip),
         }))
     } else {
-        Err((
-            StatusCode::NOT_FOUND,
-            Json(AuthError { error: "IP not found in blacklist".to_string() }),
-        ))
+        Err(AppError::NotFound("IP not found in blacklist".to_string()))
     }
 }
 
 /// POST /admin/sessions/cleanup - Clean up expired sessions
 pub async fn cleanup_sessions(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<MessageResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<MessageResponse>, AppError> {
     let count = admin_auth::cleanup_expired_sessions(&state.postgres)
         .await
         .map_err(|e| {
             tracing::error!("Failed to cleanup sessions: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to cleanup sessions".to_string() }),
-            )
+            AppError::Internal("Failed to cleanup sessions".to_string())
         })?;
     
     Ok(Json(MessageResponse {
@@ -359,15 +350,12 @@ pub async fn cleanup_sessions(
 pub async fn get_rpc_summary(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RpcMetricsQuery>,
-) -> Result<Json<RpcSummaryResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<RpcSummaryResponse>, AppError> {
     let data = state.clickhouse.get_rpc_summary(params.hours)
         .await
         .map_err(|e| {
             tracing::error!("Failed to get RPC summary: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get RPC summary".to_string() }),
-            )
+            AppError::Internal("Failed to get RPC summary".to_string())
         })?;
     
     Ok(Json(RpcSummaryResponse {
@@ -380,15 +368,12 @@ pub async fn get_rpc_summary(
 pub async fn get_rpc_providers(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RpcMetricsQuery>,
-) -> Result<Json<RpcProvidersResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<RpcProvidersResponse>, AppError> {
     let providers = state.clickhouse.get_rpc_by_provider(params.hours)
         .await
         .map_err(|e| {
             tracing::error!("Failed to get RPC providers: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get RPC providers".to_string() }),
-            )
+            AppError::Internal("Failed to get RPC providers".to_string())
         })?;
     
     Ok(Json(RpcProvidersResponse {
@@ -401,15 +386,12 @@ pub async fn get_rpc_providers(
 pub async fn get_rpc_errors(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RpcMetricsQuery>,
-) -> Result<Json<RpcErrorsResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<RpcErrorsResponse>, AppError> {
     let errors = state.clickhouse.get_rpc_errors(params.hours, params.limit)
         .await
         .map_err(|e| {
             tracing::error!("Failed to get RPC errors: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get RPC errors".to_string() }),
-            )
+            AppError::Internal("Failed to get RPC errors".to_string())
         })?;
     
     Ok(Json(RpcErrorsResponse {
@@ -423,15 +405,12 @@ pub async fn get_rpc_errors(
 pub async fn get_rpc_timeseries(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RpcMetricsQuery>,
-) -> Result<Json<RpcTimeseriesResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<RpcTimeseriesResponse>, AppError> {
     let timeseries = state.clickhouse.get_rpc_timeseries(params.hours)
         .await
         .map_err(|e| {
             tracing::error!("Failed to get RPC timeseries: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get RPC timeseries".to_string() }),
-            )
+            AppError::Internal("Failed to get RPC timeseries".to_string())
         })?;
     
     Ok(Json(RpcTimeseriesResponse {
@@ -444,15 +423,12 @@ pub async fn get_rpc_timeseries(
 pub async fn get_rpc_daily(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RpcDailyQuery>,
-) -> Result<Json<RpcDailyResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<RpcDailyResponse>, AppError> {
     let daily = state.clickhouse.get_rpc_daily(params.days)
         .await
         .map_err(|e| {
             tracing::error!("Failed to get RPC daily: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get RPC daily".to_string() }),
-            )
+            AppError::Internal("Failed to get RPC daily".to_string())
         })?;
     
     Ok(Json(RpcDailyResponse {
@@ -461,6 +437,29 @@ pub async fn get_rpc_daily(
     }))
 }
 
+/// GET /admin/rpc/credits?days=7 - Provider credits consumed per day and method
+pub async fn get_rpc_credits(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<RpcDailyQuery>,
+) -> Result<Json<RpcCreditsResponse>, AppError> {
+    let daily = state.clickhouse.get_rpc_credits_daily(params.days)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get RPC credits: {}", e);
+            AppError::Internal("Failed to get RPC credits".to_string())
+        })?;
+    
+    let total_credits: u64 = daily.iter().map(|r| r.total_credits).sum();
+    let active_days = daily.iter().map(|r| r.day).collect::<std::collections::HashSet<_>>().len() as u64;
+    
+    Ok(Json(RpcCreditsResponse {
+        days: params.days,
+        total_credits,
+        avg_daily_credits: total_credits.checked_div(active_days).unwrap_or(0),
+        daily,
+    }))
+}
+
 #[derive(Debug, Serialize)]
 pub struct RpcRequestsResponse {
     pub hours: u32,
@@ -473,15 +472,12 @@ pub struct RpcRequestsResponse {
 pub async fn get_rpc_requests(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RpcMetricsQuery>,
-) -> Result<Json<RpcRequestsResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<RpcRequestsResponse>, AppError> {
     let requests = state.clickhouse.get_rpc_requests(params.hours, params.limit)
         .await
         .map_err(|e| {
             tracing::error!("Failed to get RPC requests: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get RPC requests".to_string() }),
-            )
+            AppError::Internal("Failed to get RPC requests".to_string())
         })?;
     
     Ok(Json(RpcRequestsResponse {
@@ -512,7 +508,7 @@ pub struct WsThroughputResponse {
 pub async fn get_ws_events(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RpcMetricsQuery>,
-) -> Result<Json<WsEventsResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<WsEventsResponse>, AppError> {
     let hours = params.hours;
     let limit = params.limit;
     
@@ -521,10 +517,7 @@ pub async fn get_ws_events(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get WS events: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get WS events".to_string() }),
-            )
+            AppError::Internal("Failed to get WS events".to_string())
         })?;
     
     Ok(Json(WsEventsResponse { hours, events }))
@@ -535,7 +528,7 @@ pub async fn get_ws_events(
 pub async fn get_ws_throughput(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RpcMetricsQuery>,
-) -> Result<Json<WsThroughputResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<WsThroughputResponse>, AppError> {
     let hours = params.hours;
     
     let throughput = state.clickhouse
@@ -543,10 +536,7 @@ pub async fn get_ws_throughput(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get WS throughput: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get WS throughput".to_string() }),
-            )
+            AppError::Internal("Failed to get WS throughput".to_string())
         })?;
     
     Ok(Json(WsThroughputResponse { hours, throughput }))
@@ -567,7 +557,7 @@ pub struct ServerMetricsResponse {
 pub async fn get_server_metrics(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RpcMetricsQuery>,
-) -> Result<Json<ServerMetricsResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<ServerMetricsResponse>, AppError> {
     let hours = params.hours;
     let limit = params.limit;
     
@@ -576,10 +566,7 @@ pub async fn get_server_metrics(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get server metrics: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get server metrics".to_string() }),
-            )
+            AppError::Internal("Failed to get server metrics".to_string())
         })?;
     
     Ok(Json(ServerMetricsResponse { hours, metrics }))
@@ -597,7 +584,7 @@ pub struct RequestsTimeseriesResponse {
 pub async fn get_requests_timeseries(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RpcMetricsQuery>,
-) -> Result<Json<RequestsTimeseriesResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<RequestsTimeseriesResponse>, AppError> {
     let hours = params.hours;
     
     // Fetch both RPS and time series in parallel
@@ -608,18 +595,12 @@ pub async fn get_requests_timeseries(
     
     let rps = rps_result.map_err(|e| {
         tracing::error!("Failed to get RPS: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(AuthError { error: "Failed to get RPS".to_string() }),
-        )
+        AppError::Internal("Failed to get RPS".to_string())
     })?;
     
     let timeseries = timeseries_result.map_err(|e| {
         tracing::error!("Failed to get requests timeseries: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(AuthError { error: "Failed to get requests timeseries".to_string() }),
-        )
+        AppError::Internal("Failed to get requests timeseries".to_string())
     })?;
     
     Ok(Json(RequestsTimeseriesResponse { hours, rps, timeseries }))
@@ -668,6 +649,7 @@ pub struct RequestLogsQuery {
     pub status_code: Option<u16>,
     pub status_gte: Option<u16>,
     pub status_lte: Option<u16>,
+    pub request_id: Option<String>,
 }
 
 /// GET /admin/requests/logs?hours=24&limit=500&ip_hash=xyz&endpoint=/round&status_code=200
@@ -677,10 +659,11 @@ pub struct RequestLogsQuery {
 /// - status_code: Filter by exact status code
 /// - status_gte: Filter by status >= value (e.g., 400 for all errors)
 /// - status_lte: Filter by status <= value
+/// - request_id: Find the request behind an error report
 pub async fn get_request_logs(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RequestLogsQuery>,
-) -> Result<Json<RequestLogsResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<RequestLogsResponse>, AppError> {
     let hours = params.hours;
     let limit = params.limit.min(2000); // Cap at 2000 to prevent abuse
     
@@ -693,14 +676,12 @@ pub async fn get_request_logs(
             params.status_code,
             params.status_gte,
             params.status_lte,
+            params.request_id.as_deref(),
         )
         .await
         .map_err(|e| {
             tracing::error!("Failed to get request logs: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get request logs".to_string() }),
-            )
+            AppError::Internal("Failed to get request logs".to_string())
         })?;
     
     Ok(Json(RequestLogsResponse { hours, logs }))
@@ -711,7 +692,7 @@ pub async fn get_request_logs(
 pub async fn get_endpoint_summary(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RpcMetricsQuery>,
-) -> Result<Json<EndpointSummaryResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<EndpointSummaryResponse>, AppError> {
     let hours = params.hours;
     
     let endpoints = state.clickhouse
@@ -719,10 +700,7 @@ pub async fn get_endpoint_summary(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get endpoint summary: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get endpoint summary".to_string() }),
-            )
+            AppError::Internal("Failed to get endpoint summary".to_string())
         })?;
     
     Ok(Json(EndpointSummaryResponse { hours, endpoints }))
@@ -733,7 +711,7 @@ pub async fn get_endpoint_summary(
 pub async fn get_rate_limit_events(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RpcMetricsQuery>,
-) -> Result<Json<RateLimitEventsResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<RateLimitEventsResponse>, AppError> {
     let hours = params.hours;
     let limit = params.limit;
     
@@ -742,10 +720,7 @@ pub async fn get_rate_limit_events(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get rate limit events: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get rate limit events".to_string() }),
-            )
+            AppError::Internal("Failed to get rate limit events".to_string())
         })?;
     
     Ok(Json(RateLimitEventsResponse { hours, events }))
@@ -756,7 +731,7 @@ pub async fn get_rate_limit_events(
 pub async fn get_ip_activity(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RpcMetricsQuery>,
-) -> Result<Json<IpActivityResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<IpActivityResponse>, AppError> {
     let hours = params.hours;
     let limit = params.limit;
     
@@ -765,10 +740,7 @@ pub async fn get_ip_activity(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get IP activity: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: "Failed to get IP activity".to_string() }),
-            )
+            AppError::Internal("Failed to get IP activity".to_string())
         })?;
     
     Ok(Json(IpActivityResponse { hours, activity }))
@@ -846,16 +818,13 @@ pub struct PostgresTableSize {
 /// Get transaction migration stats (old raw_transactions vs v2 tables)
 pub async fn get_transaction_migration_stats(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<crate::clickhouse::TransactionMigrationStats>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<crate::clickhouse::TransactionMigrationStats>, AppError> {
     let stats = state.clickhouse
         .get_transaction_migration_stats()
         .await
         .map_err(|e| {
             tracing::error!("Failed to get transaction migration stats: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: format!("ClickHouse error: {}", e) }),
-            )
+            AppError::Internal(format!("ClickHouse error: {}", e))
         })?;
     
     Ok(Json(stats))
@@ -865,17 +834,14 @@ pub async fn get_transaction_migration_stats(
 /// Comprehensive database storage metrics for production monitoring
 pub async fn get_database_sizes(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<DatabaseSizeResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<DatabaseSizeResponse>, AppError> {
     // Get ClickHouse database-level sizes
     let ch_databases = state.clickhouse
         .get_database_sizes()
         .await
         .map_err(|e| {
             tracing::error!("Failed to get ClickHouse database sizes: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: format!("ClickHouse error: {}", e) }),
-            )
+            AppError::Internal(format!("ClickHouse error: {}", e))
         })?;
     
     // Get detailed table sizes (all databases)
@@ -884,10 +850,7 @@ pub async fn get_database_sizes(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get ClickHouse table sizes: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: format!("ClickHouse error: {}", e) }),
-            )
+            AppError::Internal(format!("ClickHouse error: {}", e))
         })?;
     
     // Get table engine info
@@ -896,10 +859,7 @@ pub async fn get_database_sizes(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get ClickHouse table engines: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: format!("ClickHouse error: {}", e) }),
-            )
+            AppError::Internal(format!("ClickHouse error: {}", e))
         })?;
     
     // Transform to detailed tables with computed metrics
@@ -942,10 +902,7 @@ pub async fn get_database_sizes(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get PostgreSQL database size: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: format!("PostgreSQL error: {}", e) }),
-            )
+            AppError::Internal(format!("PostgreSQL error: {}", e))
         })?;
     
     // Get detailed PostgreSQL table info
@@ -968,10 +925,7 @@ pub async fn get_database_sizes(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get PostgreSQL table sizes: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: format!("PostgreSQL error: {}", e) }),
-            )
+            AppError::Internal(format!("PostgreSQL error: {}", e))
         })?;
     
     let pg_total_rows: i64 = pg_tables.iter().map(|t| t.4).sum();
@@ -1090,14 +1044,11 @@ pub async fn get_queue_status(
 pub async fn enqueue_actions(
     State(state): State<Arc<AppState>>,
     Json(req): Json<BulkEnqueueRequest>,
-) -> Result<Json<BulkEnqueueResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<BulkEnqueueResponse>, AppError> {
     // Validate action
     let valid_actions = ["fetch_txns", "reconstruct", "finalize"];
     if !valid_actions.contains(&req.action.as_str()) {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(AuthError { error: format!("Invalid action: {}. Valid: {:?}", req.action, valid_actions) }),
-        ));
+        return Err(AppError::BadRequest(format!("Invalid action: {}. Valid: {:?}", req.action, valid_actions)));
     }
     
     let pool = &state.postgres;
@@ -1119,7 +1070,7 @@ pub async fn enqueue_actions(
         .fetch_all(pool)
         .await
         .map_err(|e| {
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(AuthError { error: e.to_string() }))
+            AppError::Internal(e.to_string())
         })?
     } else {
         // All rounds in range
@@ -1215,13 +1166,13 @@ pub async fn enqueue_actions(
 /// POST /admin/backfill/queue/pause
 pub async fn pause_queue(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<MessageResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<MessageResponse>, AppError> {
     let pool = &state.postgres;
     
     sqlx::query("UPDATE backfill_queue_control SET paused = true, updated_at = NOW() WHERE id = 1")
         .execute(pool)
         .await
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, Json(AuthError { error: e.to_string() })))?;
+        .map_err(|e| AppError::Internal(e.to_string()))?;
     
     {
         let mut cache = state.backfill_queue_cache.write().await;
@@ -1234,13 +1185,13 @@ pub async fn pause_queue(
 /// POST /admin/backfill/queue/resume
 pub async fn resume_queue(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<MessageResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<MessageResponse>, AppError> {
     let pool = &state.postgres;
     
     sqlx::query("UPDATE backfill_queue_control SET paused = false, updated_at = NOW() WHERE id = 1")
         .execute(pool)
         .await
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, Json(AuthError { error: e.to_string() })))?;
+        .map_err(|e| AppError::Internal(e.to_string()))?;
     
     {
         let mut cache = state.backfill_queue_cache.write().await;
@@ -1253,13 +1204,13 @@ pub async fn resume_queue(
 /// POST /admin/backfill/queue/clear
 pub async fn clear_queue(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<MessageResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<MessageResponse>, AppError> {
     let pool = &state.postgres;
     
     let deleted = sqlx::query("DELETE FROM backfill_action_queue WHERE status = 'pending'")
         .execute(pool)
         .await
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, Json(AuthError { error: e.to_string() })))?
+        .map_err(|e| AppError::Internal(e.to_string()))?
         .rows_affected();
     
     {
@@ -1273,7 +1224,7 @@ pub async fn clear_queue(
 /// POST /admin/backfill/queue/retry-failed
 pub async fn retry_failed_items(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<MessageResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<MessageResponse>, AppError> {
     let pool = &state.postgres;
     
     let updated = sqlx::query(
@@ -1285,7 +1236,7 @@ pub async fn retry_failed_items(
     )
     .execute(pool)
     .await
-    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, Json(AuthError { error: e.to_string() })))?
+    .map_err(|e| AppError::Internal(e.to_string()))?
     .rows_affected();
     
     // Update cache
@@ -1330,7 +1281,7 @@ pub struct BulkVerifyResponse {
 pub async fn bulk_verify_rounds(
     State(state): State<Arc<AppState>>,
     Json(req): Json<BulkVerifyRequest>,
-) -> Result<Json<BulkVerifyResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<BulkVerifyResponse>, AppError> {
     let pool = &state.postgres;
     let mut verified = 0u64;
     
@@ -1365,7 +1316,7 @@ pub async fn bulk_verify_rounds(
         .bind(end as i64)
         .execute(pool)
         .await
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, Json(AuthError { error: e.to_string() })))?;
+        .map_err(|e| AppError::Internal(e.to_string()))?;
         
         verified = result.rows_affected();
     }
@@ -1396,7 +1347,7 @@ pub struct AddRangeToBackfillResponse {
 pub async fn add_range_to_backfill(
     State(state): State<Arc<AppState>>,
     Json(req): Json<AddRangeToBackfillRequest>,
-) -> Result<Json<AddRangeToBackfillResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<AddRangeToBackfillResponse>, AppError> {
     let pool = &state.postgres;
     let mut added = 0u64;
     let mut already_in_workflow = 0u64;
@@ -1468,7 +1419,7 @@ pub struct PipelineStatsResponse {
 /// Get counts for each stage of the pipeline
 pub async fn get_pipeline_stats(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<PipelineStatsResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<PipelineStatsResponse>, AppError> {
     let pool = &state.postgres;
     
     // Count rounds not in workflow but with invalid deployments (deployment_count = 0)
@@ -1619,6 +1570,92 @@ pub struct InvalidRound {
     pub in_workflow: bool,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RoundConsistencyQuery {
+    pub round_id_gte: u64,
+    pub round_id_lte: u64,
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RoundConsistencyResponse {
+    pub round_id_gte: u64,
+    pub round_id_lte: u64,
+    pub inconsistent: Vec<RoundDiscrepancy>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RoundDiscrepancy {
+    pub round_id: u64,
+    pub total_deployed: u64,
+    pub deployments_sum: u64,
+    /// deployments_sum - total_deployed
+    pub deployed_diff: i64,
+    pub total_deployments: u64,
+    pub deployments_count: u64,
+    pub unique_miners: u64,
+    pub distinct_miners: u64,
+    /// Which checks failed: "total_deployed", "total_deployments", "unique_miners"
+    pub failed_checks: Vec<&'static str>,
+}
+
+/// Widest round range checked in one request
+const MAX_CONSISTENCY_RANGE: u64 = 100_000;
+
+/// GET /admin/rounds/consistency
+/// Validate stored round totals against the deployments table for a round range.
+/// Returns rounds that likely need re-backfill.
+pub async fn get_round_consistency(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<RoundConsistencyQuery>,
+) -> Result<Json<RoundConsistencyResponse>, AppError> {
+    if params.round_id_lte < params.round_id_gte {
+        return Err(AppError::BadRequest("round_id_lte must be >= round_id_gte".to_string()));
+    }
+    if params.round_id_lte - params.round_id_gte > MAX_CONSISTENCY_RANGE {
+        return Err(AppError::BadRequest(format!("Range too large (max {} rounds)", MAX_CONSISTENCY_RANGE)));
+    }
+    
+    let rows = state.clickhouse
+        .get_inconsistent_rounds(params.round_id_gte, params.round_id_lte, params.limit.min(1000))
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check round consistency: {}", e);
+            if e.is_timeout() { AppError::Timeout(e.to_string()) } else { AppError::Internal(e.to_string()) }
+        })?;
+    
+    let inconsistent = rows.into_iter().map(|r| {
+        let mut failed_checks = Vec::new();
+        if r.deployments_sum != r.total_deployed {
+            failed_checks.push("total_deployed");
+        }
+        if r.deployments_count != r.total_deployments {
+            failed_checks.push("total_deployments");
+        }
+        if r.distinct_miners != r.unique_miners {
+            failed_checks.push("unique_miners");
+        }
+        RoundDiscrepancy {
+            round_id: r.round_id,
+            total_deployed: r.total_deployed,
+            deployments_sum: r.deployments_sum,
+            deployed_diff: r.deployments_sum as i64 - r.total_deployed as i64,
+            total_deployments: r.total_deployments,
+            deployments_count: r.deployments_count,
+            unique_miners: r.unique_miners,
+            distinct_miners: r.distinct_miners,
+            failed_checks,
+        }
+    }).collect();
+    
+    Ok(Json(RoundConsistencyResponse {
+        round_id_gte: params.round_id_gte,
+        round_id_lte: params.round_id_lte,
+        inconsistent,
+    }))
+}
+
 /// GET /admin/backfill/reconstructed
 /// Get list of rounds currently reconstructed in memory (awaiting finalize)
 pub async fn get_reconstructed_rounds(
@@ -1661,13 +1698,13 @@ pub struct ReconstructedRoundInfo {
 pub async fn get_invalid_rounds(
     State(state): State<Arc<AppState>>,
     Query(params): Query<InvalidRoundsQuery>,
-) -> Result<Json<InvalidRoundsResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<InvalidRoundsResponse>, AppError> {
     // Query ClickHouse for rounds with 0 deployments
     let invalid_from_ch = state.clickhouse.get_rounds_with_zero_deployments(params.limit, params.offset)
         .await
         .map_err(|e| {
             tracing::error!("Failed to get invalid rounds from ClickHouse: {}", e);
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(AuthError { error: e.to_string() }))
+            AppError::Internal(e.to_string())
         })?;
     
     let pool = &state.postgres;
@@ -1705,6 +1742,107 @@ pub async fn get_invalid_rounds(
 // Router
 // ============================================================================
 
+// ============================================================================
+// Raw Account Inspection
+// ============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct RawAccountResponse {
+    pub pubkey: String,
+    pub owner: String,
+    pub lamports: u64,
+    pub executable: bool,
+    pub data_len: usize,
+    /// Account data, base64
+    pub data: String,
+    /// Best-effort decode for EVORE and ORE program accounts
+    pub decoded: Option<DecodedAccount>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DecodedAccount {
+    pub program: &'static str,
+    pub account_type: &'static str,
+    pub fields: serde_json::Value,
+}
+
+/// GET /admin/account/{pubkey}/raw - Fetch an account straight from RPC
+///
+/// For debugging suspicious account state without a separate script.
+pub async fn get_raw_account(
+    State(state): State<Arc<AppState>>,
+    Path(pubkey): Path<String>,
+) -> Result<Json<RawAccountResponse>, AppError> {
+    use base64::Engine;
+
+    let address = Pubkey::try_from(pubkey.as_str())
+        .map_err(|_| AppError::BadRequest("Invalid pubkey".to_string()))?;
+    let account = state.rpc.get_account(&address).await
+        .map_err(|e| AppError::BadGateway(format!("Failed to fetch account: {}", e)))?
+        .ok_or_else(|| AppError::NotFound(format!("Account {} not found", address)))?;
+
+    Ok(Json(RawAccountResponse {
+        pubkey: address.to_string(),
+        owner: account.owner.to_string(),
+        lamports: account.lamports,
+        executable: account.executable,
+        data_len: account.data.len(),
+        data: base64::engine::general_purpose::STANDARD.encode(&account.data),
+        decoded: decode_account(state.rpc.network(), &account.owner, &account.data),
+    }))
+}
+
+/// Decode account data owned by the EVORE or ORE program
+///
+/// Returns None for other owners, unknown discriminators, or data that
+/// doesn't fit the struct.
+fn decode_account(network: &NetworkConfig, owner: &Pubkey, data: &[u8]) -> Option<DecodedAccount> {
+    fn decoded<T: Serialize>(program: &'static str, account_type: &'static str, account: &T) -> Option<DecodedAccount> {
+        let mut fields = serde_json::to_value(account).ok()?;
+        pubkeys_to_base58(&mut fields);
+        Some(DecodedAccount { program, account_type, fields })
+    }
+
+    let discriminator = *data.first()?;
+    if *owner == network.evore_program_id {
+        match EvoreAccount::try_from(discriminator).ok()? {
+            EvoreAccount::Manager => decoded("evore", "Manager", Manager::try_from_bytes(data).ok()?),
+            EvoreAccount::Deployer => decoded("evore", "Deployer", Deployer::try_from_bytes(data).ok()?),
+        }
+    } else if *owner == network.ore_program_id {
+        match OreAccount::try_from(discriminator).ok()? {
+            OreAccount::Automation => decoded("ore", "Automation", Automation::try_from_bytes(data).ok()?),
+            OreAccount::Config => decoded("ore", "Config", Config::try_from_bytes(data).ok()?),
+            OreAccount::Miner => decoded("ore", "Miner", Miner::try_from_bytes(data).ok()?),
+            OreAccount::Treasury => decoded("ore", "Treasury", Treasury::try_from_bytes(data).ok()?),
+            OreAccount::Board => decoded("ore", "Board", Board::try_from_bytes(data).ok()?),
+            OreAccount::Stake => decoded("ore", "Stake", Stake::try_from_bytes(data).ok()?),
+            OreAccount::Round => decoded("ore", "Round", Round::try_from_bytes(data).ok()?),
+        }
+    } else {
+        None
+    }
+}
+
+/// Pubkeys serialize as 32 byte arrays; show them as base58 instead
+///
+/// Any other 32 byte array (e.g. a slot hash) is shown the same way.
+fn pubkeys_to_base58(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Array(items) => {
+            let bytes: Option<Vec<u8>> = items.iter()
+                .map(|v| v.as_u64().and_then(|b| u8::try_from(b).ok()))
+                .collect();
+            match bytes.and_then(|b| <[u8; 32]>::try_from(b).ok()) {
+                Some(bytes) => *value = serde_json::Value::String(Pubkey::from(bytes).to_string()),
+                None => items.iter_mut().for_each(pubkeys_to_base58),
+            }
+        }
+        serde_json::Value::Object(fields) => fields.values_mut().for_each(pubkeys_to_base58),
+        _ => {}
+    }
+}
+
 /// Create the admin router with all routes
 /// Login is public, all other routes require authentication
 pub fn admin_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
@@ -1722,6 +1860,7 @@ pub fn admin_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
         .route("/rpc/errors", get(get_rpc_errors))
         .route("/rpc/timeseries", get(get_rpc_timeseries))
         .route("/rpc/daily", get(get_rpc_daily))
+        .route("/rpc/credits", get(get_rpc_credits))
         .route("/rpc/requests", get(get_rpc_requests))
         // WebSocket metrics
         .route("/ws/events", get(get_ws_events))
@@ -1746,12 +1885,14 @@ pub fn admin_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
         .route("/rounds/data", get(crate::backfill::get_rounds_with_data))
         .route("/rounds/missing", get(crate::backfill::get_missing_rounds))
         .route("/rounds/stats", get(crate::backfill::get_round_stats))
+        .route("/rounds/consistency", get(get_round_consistency))
         .route("/rounds/bulk-delete", post(crate::backfill::bulk_delete_rounds))
         .route("/rounds/{round_id}/status", get(crate::backfill::get_round_data_status))
         .route("/rounds/{round_id}", delete(crate::backfill::delete_round_data))
         .route("/fetch-txns/{round_id}", post(crate::backfill::fetch_round_transactions))
         .route("/reset-txns/{round_id}", post(crate::backfill::reset_txns_status))
         .route("/reconstruct/{round_id}", post(crate::backfill::reconstruct_round))
+        .route("/verify-reconstruction/{round_id}", get(crate::backfill::verify_reconstruction))
         .route("/verify/{round_id}", get(crate::backfill::get_round_for_verification))
         .route("/verify/{round_id}", post(crate::backfill::verify_round))
         .route("/finalize/{round_id}", post(crate::backfill::finalize_backfill_round))
@@ -1775,6 +1916,11 @@ pub fn admin_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
         .route("/automation/parse-queue", get(crate::automation_states::get_parse_queue_stats))
         .route("/automation/parse-queue/items", get(crate::automation_states::get_parse_queue_items))
         .route("/automation/queue-round/{round_id}", post(crate::automation_states::queue_round_for_parsing))
+        // In-memory automation cache
+        .route("/automation-cache", get(crate::automation_states::get_automation_cache))
+        .route("/automation-cache/flush", post(crate::automation_states::flush_automation_cache))
+        // Raw account inspection
+        .route("/account/{pubkey}/raw", get(get_raw_account))
         // Backfill action queue (Command Center)
         .route("/backfill/queue/status", get(get_queue_status))
         .route("/backfill/queue/enqueue", post(enqueue_actions))
@@ -1799,3 +1945,28 @@ pub fn admin_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
         .merge(protected)
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_decode_account() {
+        let network = NetworkConfig::mainnet();
+        let authority = Pubkey::new_unique();
+        let mut data = vec![EvoreAccount::Manager as u8, 0, 0, 0, 0, 0, 0, 0];
+        data.extend_from_slice(authority.as_ref());
+        
+        let decoded = decode_account(&network, &network.evore_program_id, &data).unwrap();
+        assert_eq!((decoded.program, decoded.account_type), ("evore", "Manager"));
+        assert_eq!(decoded.fields, serde_json::json!({ "authority": authority.to_string() }));
+        
+        // Other owners, unknown discriminators and short data aren't decoded
+        assert!(decode_account(&network, &network.ore_program_id, &data).is_none());
+        assert!(decode_account(&network, &Pubkey::new_unique(), &data).is_none());
+        data[0] = 7;
+        assert!(decode_account(&network, &network.evore_program_id, &data).is_none());
+        assert!(decode_account(&network, &network.evore_program_id, &data[..20]).is_none());
+        assert!(decode_account(&network, &network.evore_program_id, &[]).is_none());
+    }
+}
@@ -1,28 +1,168 @@
+//! API error type
+//!
+//! Every error response has the shape
+//! `{ "error": { "code": "...", "message": "...", "request_id": "..." } }`.
+//! `code` is one of a small stable set (see `AppError::code`) that clients can
+//! match on; `request_id` is the id the logging middleware assigned to the
+//! request, so users can quote it when reporting a problem.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
 use thiserror::Error;
-use serde::{Serialize};
+
+tokio::task_local! {
+    /// Id of the request being handled, set by `request_logging_middleware`
+    pub static REQUEST_ID: String;
+}
+
+/// Request id of the current task, if running inside the logging middleware
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|id| id.clone()).ok()
+}
 
 #[derive(Error, Debug)]
 pub enum AppError {
-    #[error("not found")]
-    NotFound,
+    #[error("{0}")]
+    BadRequest(String),
+    #[error("{0}")]
+    Unauthorized(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("{0}")]
+    Internal(String),
+    /// Upstream (RPC / ClickHouse) returned an error
+    #[error("{0}")]
+    BadGateway(String),
+    #[error("{0}")]
+    Unavailable(String),
+    #[error("{0}")]
+    Timeout(String),
     #[error(transparent)]
     Sqlx(#[from] sqlx::Error),
     #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
 }
 
-impl axum::response::IntoResponse for AppError {
-    fn into_response(self) -> axum::response::Response {
-        use axum::{http::StatusCode, Json};
-        #[derive(Serialize)]
-        struct ErrBody { error: String }
+#[derive(Debug, Serialize)]
+pub struct ErrorBody {
+    pub error: ErrorDetail,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErrorDetail {
+    pub code: &'static str,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+/// Stable error code for an HTTP status
+pub fn code_for_status(status: StatusCode) -> &'static str {
+    match status {
+        StatusCode::NOT_FOUND => "not_found",
+        StatusCode::TOO_MANY_REQUESTS => "rate_limited",
+        StatusCode::UNAUTHORIZED => "unauthorized",
+        StatusCode::FORBIDDEN => "forbidden",
+        StatusCode::CONFLICT => "conflict",
+        StatusCode::BAD_GATEWAY => "bad_gateway",
+        StatusCode::SERVICE_UNAVAILABLE => "unavailable",
+        StatusCode::GATEWAY_TIMEOUT => "timeout",
+        s if s.is_client_error() => "bad_request",
+        _ => "internal",
+    }
+}
+
+/// Build an error response with the standard body
+pub fn error_response(status: StatusCode, message: String, request_id: Option<String>) -> Response {
+    let body = ErrorBody {
+        error: ErrorDetail {
+            code: code_for_status(status),
+            message,
+            request_id,
+        },
+    };
+    (status, Json(body)).into_response()
+}
+
+impl AppError {
+    pub fn status(&self) -> StatusCode {
         match self {
-            AppError::NotFound => (StatusCode::NOT_FOUND, Json(ErrBody { error: "not found".into() })).into_response(),
-            other => {
-                tracing::error!("internal error: {other:#}");
-                (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrBody { error: "internal server error".into() })).into_response()
+            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::BadGateway(_) => StatusCode::BAD_GATEWAY,
+            AppError::Unavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::Timeout(_) => StatusCode::GATEWAY_TIMEOUT,
+            AppError::Internal(_) | AppError::Sqlx(_) | AppError::Anyhow(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
             }
         }
     }
+
+    pub fn code(&self) -> &'static str {
+        code_for_status(self.status())
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let request_id = current_request_id();
+        let status = self.status();
+        let message = match &self {
+            // Don't leak database internals
+            AppError::Sqlx(_) | AppError::Anyhow(_) => {
+                tracing::error!(request_id = request_id.as_deref(), "internal error: {self:#}");
+                "internal server error".to_string()
+            }
+            other => {
+                if status.is_server_error() {
+                    tracing::warn!(request_id = request_id.as_deref(), "{}: {}", other.code(), other);
+                }
+                other.to_string()
+            }
+        };
+        error_response(status, message, request_id)
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes() {
+        assert_eq!(AppError::NotFound("x".into()).code(), "not_found");
+        assert_eq!(code_for_status(StatusCode::TOO_MANY_REQUESTS), "rate_limited");
+        assert_eq!(AppError::BadRequest("x".into()).code(), "bad_request");
+        assert_eq!(AppError::Anyhow(anyhow::anyhow!("db down")).code(), "internal");
+        assert_eq!(code_for_status(StatusCode::METHOD_NOT_ALLOWED), "bad_request");
+        assert_eq!(code_for_status(StatusCode::INTERNAL_SERVER_ERROR), "internal");
+    }
+
+    #[tokio::test]
+    async fn test_body_shape() {
+        let response = REQUEST_ID
+            .scope("abc123".to_string(), async { AppError::NotFound("Round not found".into()).into_response() })
+            .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let bytes = axum::body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "error": { "code": "not_found", "message": "Round not found", "request_id": "abc123" }
+            })
+        );
+    }
+}
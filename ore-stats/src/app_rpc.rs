@@ -4,28 +4,32 @@
 //! Provides:
 //! - Rate limiting per provider
 //! - Round-robin retry with fallback across providers
+//! - Per-route first provider (`RoutingPolicy`): account reads, GPA scans and
+//!   latency-sensitive calls can each start on a different provider
 //! - Round-robin distribution for transaction fetching (to maximize throughput)
 //! - Request/response timing
 //! - Metrics logging to ClickHouse
 //! - Error tracking
 
-use std::sync::Arc;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 use anyhow::Result;
+use serde::Serialize;
 use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
 
 use crate::custom_rpc::CustomRpcClient;
+use solana_sdk::account::Account;
 use solana_sdk::pubkey::Pubkey;
 use steel::AccountDeserialize;
 use tokio::sync::RwLock;
 use tokio::time::Instant;
 
-use evore::ore_api::{
-    Board, Miner, Round, Treasury,
-    TREASURY_ADDRESS, MINT_ADDRESS, board_pda, miner_pda, round_pda,
-};
+use evore::network::NetworkConfig;
+use evore::entropy_api::Var;
+use evore::ore_api::{Board, Miner, Round, Treasury};
 
 use crate::app_state::apply_refined_ore_fix;
 use crate::clickhouse::{ClickHouseClient, RpcRequestInsert};
@@ -34,15 +38,156 @@ use crate::clickhouse::{ClickHouseClient, RpcRequestInsert};
 const MAX_RETRIES: usize = 10;
 const RETRY_DELAY_MS: u64 = 500;
 
+/// Fewest miners a full GPA snapshot may contain before it's treated as truncated
+const MIN_SNAPSHOT_MINERS: usize = 10_000;
+
+/// Most partitions of the miners GPA scan fetched at once
+const MAX_MINERS_GPA_CONCURRENCY: usize = 32;
+
 /// Rate limits per provider (milliseconds between requests)
 const FLUX_MIN_INTERVAL_MS: u64 = 33;    // ~30 rps
 const HELIUS_MIN_INTERVAL_MS: u64 = 40;  // ~25 rps
 const TRITON_MIN_INTERVAL_MS: u64 = 40;  // ~25 rps
 const SVS_MIN_INTERVAL_MS: u64 = 13;  // ~75 rps
 
+/// Kind of call, used to pick the provider tried first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RpcRoute {
+    /// Account, balance and signature history reads
+    Reads,
+    /// getProgramAccounts scans
+    Gpa,
+    /// Calls the live round depends on (board, slot, signature statuses)
+    Latency,
+}
+
+/// Provider each route tries first; retries move on through the others
+///
+/// Transaction fetching keeps its own order (round-robin live, Triton first
+/// for backfill) since it's about throughput rather than cost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoutingPolicy {
+    pub reads: String,
+    pub gpa: String,
+    pub latency: String,
+}
+
+impl Default for RoutingPolicy {
+    /// Everything starts on Flux
+    fn default() -> Self {
+        Self {
+            reads: "flux".to_string(),
+            gpa: "flux".to_string(),
+            latency: "flux".to_string(),
+        }
+    }
+}
+
+impl RoutingPolicy {
+    /// Read RPC_ROUTE_READS / RPC_ROUTE_GPA / RPC_ROUTE_LATENCY (provider names)
+    pub fn from_env() -> Self {
+        Self::from_vars(|key| std::env::var(key).ok())
+    }
+    
+    fn from_vars(get: impl Fn(&str) -> Option<String>) -> Self {
+        let defaults = Self::default();
+        let provider = |key: &str, default: String| {
+            get(key)
+                .map(|v| v.trim().to_ascii_lowercase())
+                .filter(|v| !v.is_empty())
+                .unwrap_or(default)
+        };
+        Self {
+            reads: provider("RPC_ROUTE_READS", defaults.reads),
+            gpa: provider("RPC_ROUTE_GPA", defaults.gpa),
+            latency: provider("RPC_ROUTE_LATENCY", defaults.latency),
+        }
+    }
+    
+    /// Provider name for `route`
+    pub fn provider_for(&self, route: RpcRoute) -> &str {
+        match route {
+            RpcRoute::Reads => &self.reads,
+            RpcRoute::Gpa => &self.gpa,
+            RpcRoute::Latency => &self.latency,
+        }
+    }
+}
+
+/// Calls served (and failed) per route and provider, for the admin metrics
+#[derive(Default)]
+pub struct RouteStats {
+    counts: Mutex<BTreeMap<(RpcRoute, String), (u64, u64)>>,
+}
+
+/// One route/provider pair in `RouteStats::snapshot`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RouteStatsEntry {
+    pub route: RpcRoute,
+    pub provider: String,
+    pub served: u64,
+    pub errors: u64,
+}
+
+impl RouteStats {
+    fn record(&self, route: RpcRoute, provider: &str, ok: bool) {
+        let mut counts = self.counts.lock().unwrap();
+        let entry = counts.entry((route, provider.to_string())).or_default();
+        if ok {
+            entry.0 += 1;
+        } else {
+            entry.1 += 1;
+        }
+    }
+    
+    pub fn snapshot(&self) -> Vec<RouteStatsEntry> {
+        self.counts.lock().unwrap()
+            .iter()
+            .map(|((route, provider), (served, errors))| RouteStatsEntry {
+                route: *route,
+                provider: provider.clone(),
+                served: *served,
+                errors: *errors,
+            })
+            .collect()
+    }
+}
+
+/// Duration of the full miners GPA loads, for the admin metrics
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct MinersLoadStats {
+    pub loads: u64,
+    pub failures: u64,
+    /// Duration of the most recent load (ms), failed or not
+    pub last_duration_ms: u64,
+    /// Duration of the most recent successful load (ms)
+    pub last_success_ms: u64,
+    pub last_miners: usize,
+    /// Partitions fetched at once (1 = a single unpartitioned scan)
+    pub concurrency: usize,
+}
+
+impl MinersLoadStats {
+    fn record(&mut self, elapsed: Duration, miners: Option<usize>, concurrency: usize) {
+        let ms = elapsed.as_millis() as u64;
+        self.loads += 1;
+        self.last_duration_ms = ms;
+        self.concurrency = concurrency;
+        match miners {
+            Some(count) => {
+                self.last_success_ms = ms;
+                self.last_miners = count;
+            }
+            None => self.failures += 1,
+        }
+    }
+}
+
 /// RPC metrics context for a single request
 #[derive(Debug, Clone)]
 pub struct RpcContext {
+    pub route: RpcRoute,
     pub method: String,
     pub target_type: String,
     pub target_address: String,
@@ -107,7 +252,7 @@ impl RpcProvider {
 /// Central RPC gateway with metrics tracking
 /// 
 /// Uses multiple RPC providers with round-robin retry on failure:
-/// - First attempt always uses provider[0] (Flux) for most calls
+/// - First attempt uses the provider `RoutingPolicy` names for the call's route (Flux by default)
 /// - Transaction fetching uses round-robin across ALL providers for max throughput
 /// - On failure, rotates to next provider (round-robin)
 /// - 10 total attempts with 500ms delay between retries
@@ -121,6 +266,17 @@ pub struct AppRpc {
     // Metrics tracking
     clickhouse: Option<Arc<ClickHouseClient>>,
     program_name: String,
+    
+    /// Program ids / mint used for account addresses (mainnet by default)
+    network: NetworkConfig,
+    
+    /// First provider per route
+    routing: RoutingPolicy,
+    route_stats: RouteStats,
+    
+    /// Miners GPA partitions fetched at once (1 = unpartitioned)
+    miners_gpa_concurrency: usize,
+    miners_load: Mutex<MinersLoadStats>,
 }
 
 impl AppRpc {
@@ -168,12 +324,61 @@ impl AppRpc {
             round_robin_counter: AtomicUsize::new(0),
             clickhouse,
             program_name: "ore-stats".to_string(),
+            network: NetworkConfig::default(),
+            routing: RoutingPolicy::default(),
+            route_stats: RouteStats::default(),
+            miners_gpa_concurrency: 1,
+            miners_load: Mutex::new(MinersLoadStats::default()),
         }
     }
     
-    /// Get the primary provider (Flux)
-    fn primary_provider(&self) -> &RpcProvider {
-        &self.providers[0]
+    /// Read accounts for a non-default network config (devnet / overrides)
+    pub fn with_network(mut self, network: NetworkConfig) -> Self {
+        self.network = network;
+        self
+    }
+    
+    /// Network config accounts are read for
+    pub fn network(&self) -> &NetworkConfig {
+        &self.network
+    }
+    
+    /// Start each route on the provider named in `routing`
+    /// Unknown names fall back to the primary provider (Flux)
+    pub fn with_routing(mut self, routing: RoutingPolicy) -> Self {
+        for route in [RpcRoute::Reads, RpcRoute::Gpa, RpcRoute::Latency] {
+            let name = routing.provider_for(route);
+            if !self.providers.iter().any(|p| p.name == name) {
+                tracing::warn!("RPC route {:?} names unknown provider '{}', using {}", route, name, self.providers[0].name);
+            }
+        }
+        self.routing = routing;
+        self
+    }
+    
+    /// Split the miners GPA scan into partitions fetched `concurrency` at a time
+    /// (1 keeps the single scan; capped at `MAX_MINERS_GPA_CONCURRENCY`)
+    pub fn with_miners_gpa_concurrency(mut self, concurrency: usize) -> Self {
+        self.miners_gpa_concurrency = concurrency.clamp(1, MAX_MINERS_GPA_CONCURRENCY);
+        self
+    }
+    
+    /// Duration of the full miners loads
+    pub fn miners_load_stats(&self) -> MinersLoadStats {
+        self.miners_load.lock().unwrap().clone()
+    }
+    
+    /// Calls served per route and provider
+    pub fn route_stats(&self) -> Vec<RouteStatsEntry> {
+        self.route_stats.snapshot()
+    }
+    
+    /// Provider for retry `attempt` of a call on `route`
+    /// Starts at the route's provider and rotates through the rest
+    fn routed_provider(&self, route: RpcRoute, attempt: usize) -> &RpcProvider {
+        let name = self.routing.provider_for(route);
+        let start = self.providers.iter().position(|p| p.name == name).unwrap_or(0);
+        self.provider(start + attempt)
     }
     
     /// Get provider by index (wraps around)
@@ -191,6 +396,7 @@ impl AppRpc {
     
     /// Log successful RPC call to ClickHouse
     async fn log_success(&self, provider_name: &str, api_key_id: &str, ctx: &RpcContext, duration_ms: u32, result_count: u32, response_size: u32) {
+        self.route_stats.record(ctx.route, provider_name, true);
         if let Some(ref ch) = self.clickhouse {
             let insert = RpcRequestInsert::new(
                 &self.program_name,
@@ -223,6 +429,7 @@ impl AppRpc {
     
     /// Log error RPC call to ClickHouse
     async fn log_error(&self, provider_name: &str, api_key_id: &str, ctx: &RpcContext, duration_ms: u32, error: &str) {
+        self.route_stats.record(ctx.route, provider_name, false);
         if let Some(ref ch) = self.clickhouse {
             let insert = RpcRequestInsert::new(
                 &self.program_name,
@@ -246,6 +453,7 @@ impl AppRpc {
     
     /// Log not found RPC call to ClickHouse
     async fn log_not_found(&self, provider_name: &str, api_key_id: &str, ctx: &RpcContext, duration_ms: u32) {
+        self.route_stats.record(ctx.route, provider_name, true);
         if let Some(ref ch) = self.clickhouse {
             let insert = RpcRequestInsert::new(
                 &self.program_name,
@@ -275,8 +483,9 @@ impl AppRpc {
         use solana_client::rpc_config::RpcAccountInfoConfig;
         use solana_account_decoder_client_types::UiAccountEncoding;
         
-        let address = board_pda().0;
+        let address = self.network.board_pda().0;
         let ctx = RpcContext {
+            route: RpcRoute::Latency,
             method: "getAccountInfo".to_string(),
             target_type: "board".to_string(),
             target_address: address.to_string(),
@@ -293,7 +502,7 @@ impl AppRpc {
         
         let mut last_error = String::new();
         for attempt in 0..MAX_RETRIES {
-            let provider = self.provider(attempt);
+            let provider = self.routed_provider(ctx.route, attempt);
             provider.rate_limit().await;
             let start = Instant::now();
             
@@ -328,8 +537,9 @@ impl AppRpc {
         use solana_client::rpc_config::RpcAccountInfoConfig;
         use solana_account_decoder_client_types::UiAccountEncoding;
         
-        let address = round_pda(round_id).0;
+        let address = self.network.round_pda(round_id).0;
         let ctx = RpcContext {
+            route: RpcRoute::Reads,
             method: "getAccountInfo".to_string(),
             target_type: "round".to_string(),
             target_address: address.to_string(),
@@ -346,7 +556,7 @@ impl AppRpc {
         
         let mut last_error = String::new();
         for attempt in 0..MAX_RETRIES {
-            let provider = self.provider(attempt);
+            let provider = self.routed_provider(ctx.route, attempt);
             provider.rate_limit().await;
             let start = Instant::now();
             
@@ -382,9 +592,10 @@ impl AppRpc {
         use solana_account_decoder_client_types::UiAccountEncoding;
         
         let ctx = RpcContext {
+            route: RpcRoute::Reads,
             method: "getAccountInfo".to_string(),
             target_type: "treasury".to_string(),
-            target_address: TREASURY_ADDRESS.to_string(),
+            target_address: self.network.treasury_pda().0.to_string(),
             is_batch: false,
             batch_size: 1,
         };
@@ -398,11 +609,11 @@ impl AppRpc {
         
         let mut last_error = String::new();
         for attempt in 0..MAX_RETRIES {
-            let provider = self.provider(attempt);
+            let provider = self.routed_provider(ctx.route, attempt);
             provider.rate_limit().await;
             let start = Instant::now();
             
-            match provider.client.get_account_with_config(&TREASURY_ADDRESS, config.clone()).await {
+            match provider.client.get_account_with_config(&self.network.treasury_pda().0, config.clone()).await {
                 Ok(response) => {
         let duration_ms = start.elapsed().as_millis() as u32;
                     if let Some(account) = response.value {
@@ -427,6 +638,61 @@ impl AppRpc {
         Err(anyhow::anyhow!("All {} attempts failed for get_treasury: {}", MAX_RETRIES, last_error))
     }
     
+    /// Get the ORE entropy var account (with retry across providers)
+    /// Returns the var address with its data; the var is reused across rounds,
+    /// so read it right after reset to capture the entropy that round used
+    pub async fn get_entropy_var(&self) -> Result<(Pubkey, Var)> {
+        use solana_client::rpc_config::RpcAccountInfoConfig;
+        use solana_account_decoder_client_types::UiAccountEncoding;
+        
+        let address = evore::entropy_api::var_pda(self.network.board_pda().0, 0).0;
+        let ctx = RpcContext {
+            route: RpcRoute::Reads,
+            method: "getAccountInfo".to_string(),
+            target_type: "entropy_var".to_string(),
+            target_address: address.to_string(),
+            is_batch: false,
+            batch_size: 1,
+        };
+        
+        let config = RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64Zstd),
+            data_slice: None,
+            commitment: Some(CommitmentConfig { commitment: CommitmentLevel::Confirmed }),
+            min_context_slot: None,
+        };
+        
+        let mut last_error = String::new();
+        for attempt in 0..MAX_RETRIES {
+            let provider = self.routed_provider(ctx.route, attempt);
+            provider.rate_limit().await;
+            let start = Instant::now();
+            
+            match provider.client.get_account_with_config(&address, config.clone()).await {
+                Ok(response) => {
+                    let duration_ms = start.elapsed().as_millis() as u32;
+                    if let Some(account) = response.value {
+                        self.log_success(&provider.name, &provider.api_key_id, &ctx, duration_ms, 1, response.response_size as u32).await;
+                        let var = Var::try_from_bytes(&account.data)?;
+                        return Ok((address, *var));
+                    } else {
+                        last_error = "Account not found".to_string();
+                        self.log_error(&provider.name, &provider.api_key_id, &ctx, duration_ms, &last_error).await;
+                    }
+                }
+                Err(e) => {
+                    let duration_ms = start.elapsed().as_millis() as u32;
+                    last_error = e.to_string();
+                    self.log_error(&provider.name, &provider.api_key_id, &ctx, duration_ms, &last_error).await;
+                    if attempt < MAX_RETRIES - 1 {
+                        tokio::time::sleep(Duration::from_millis(RETRY_DELAY_MS)).await;
+                    }
+                }
+            }
+        }
+        Err(anyhow::anyhow!("All {} attempts failed for get_entropy_var: {}", MAX_RETRIES, last_error))
+    }
+    
     /// Get the ORE Mint supply (with retry across providers)
     /// Returns the total supply of ORE tokens in atomic units (11 decimals)
     /// Uses Base64Zstd encoding for bandwidth efficiency
@@ -435,9 +701,10 @@ impl AppRpc {
         use solana_account_decoder_client_types::UiAccountEncoding;
         
         let ctx = RpcContext {
+            route: RpcRoute::Reads,
             method: "getAccountInfo".to_string(),
             target_type: "mint".to_string(),
-            target_address: MINT_ADDRESS.to_string(),
+            target_address: self.network.ore_mint.to_string(),
             is_batch: false,
             batch_size: 1,
         };
@@ -451,11 +718,11 @@ impl AppRpc {
         
         let mut last_error = String::new();
         for attempt in 0..MAX_RETRIES {
-            let provider = self.provider(attempt);
+            let provider = self.routed_provider(ctx.route, attempt);
             provider.rate_limit().await;
             let start = Instant::now();
         
-            match provider.client.get_account_with_config(&MINT_ADDRESS, config.clone()).await {
+            match provider.client.get_account_with_config(&self.network.ore_mint, config.clone()).await {
                 Ok(response) => {
                     let duration_ms = start.elapsed().as_millis() as u32;
                     if let Some(account) = response.value {
@@ -492,8 +759,9 @@ impl AppRpc {
         use solana_client::rpc_config::RpcAccountInfoConfig;
         use solana_account_decoder_client_types::UiAccountEncoding;
         
-        let address = miner_pda(*authority).0;
+        let address = self.network.miner_pda(*authority).0;
         let ctx = RpcContext {
+            route: RpcRoute::Reads,
             method: "getAccountInfo".to_string(),
             target_type: "miner".to_string(),
             target_address: authority.to_string(),
@@ -509,7 +777,7 @@ impl AppRpc {
         };
         
         // Use manual retry loop for special "not found" handling
-        let provider = self.primary_provider();
+        let provider = self.routed_provider(ctx.route, 0);
         provider.rate_limit().await;
         let start = Instant::now();
         
@@ -546,6 +814,7 @@ impl AppRpc {
     /// Get SOL balance for an account (with retry across providers)
     pub async fn get_balance(&self, pubkey: &Pubkey) -> Result<u64> {
         let ctx = RpcContext {
+            route: RpcRoute::Reads,
             method: "getBalance".to_string(),
             target_type: "balance".to_string(),
             target_address: pubkey.to_string(),
@@ -555,7 +824,7 @@ impl AppRpc {
         
         let mut last_error = String::new();
         for attempt in 0..MAX_RETRIES {
-            let provider = self.provider(attempt);
+            let provider = self.routed_provider(ctx.route, attempt);
             provider.rate_limit().await;
             let start = Instant::now();
             
@@ -578,9 +847,61 @@ impl AppRpc {
         Err(anyhow::anyhow!("All {} attempts failed for get_balance: {}", MAX_RETRIES, last_error))
     }
     
+    /// Get any account as stored on chain (with retry across providers)
+    /// Returns None if the account doesn't exist
+    pub async fn get_account(&self, pubkey: &Pubkey) -> Result<Option<Account>> {
+        use solana_client::rpc_config::RpcAccountInfoConfig;
+        use solana_account_decoder_client_types::UiAccountEncoding;
+        
+        let ctx = RpcContext {
+            route: RpcRoute::Reads,
+            method: "getAccountInfo".to_string(),
+            target_type: "account".to_string(),
+            target_address: pubkey.to_string(),
+            is_batch: false,
+            batch_size: 1,
+        };
+        
+        let config = RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            data_slice: None,
+            commitment: Some(CommitmentConfig { commitment: CommitmentLevel::Confirmed }),
+            min_context_slot: None,
+        };
+        
+        let mut last_error = String::new();
+        for attempt in 0..MAX_RETRIES {
+            let provider = self.routed_provider(ctx.route, attempt);
+            provider.rate_limit().await;
+            let start = Instant::now();
+            
+            match provider.client.get_account_with_config(pubkey, config.clone()).await {
+                Ok(response) => {
+                    let duration_ms = start.elapsed().as_millis() as u32;
+                    if response.value.is_some() {
+                        self.log_success(&provider.name, &provider.api_key_id, &ctx, duration_ms, 1, response.response_size as u32).await;
+                    } else {
+                        self.log_not_found(&provider.name, &provider.api_key_id, &ctx, duration_ms).await;
+                    }
+                    return Ok(response.value);
+                }
+                Err(e) => {
+                    let duration_ms = start.elapsed().as_millis() as u32;
+                    last_error = e.to_string();
+                    self.log_error(&provider.name, &provider.api_key_id, &ctx, duration_ms, &last_error).await;
+                    if attempt < MAX_RETRIES - 1 {
+                        tokio::time::sleep(Duration::from_millis(RETRY_DELAY_MS)).await;
+                    }
+                }
+            }
+        }
+        Err(anyhow::anyhow!("All {} attempts failed for get_account: {}", MAX_RETRIES, last_error))
+    }
+    
     /// Get current slot (with retry across providers)
     pub async fn get_slot(&self) -> Result<u64> {
         let ctx = RpcContext {
+            route: RpcRoute::Latency,
             method: "getSlot".to_string(),
             target_type: "slot".to_string(),
             target_address: String::new(),
@@ -590,7 +911,7 @@ impl AppRpc {
         
         let mut last_error = String::new();
         for attempt in 0..MAX_RETRIES {
-            let provider = self.provider(attempt);
+            let provider = self.routed_provider(ctx.route, attempt);
             provider.rate_limit().await;
             let start = Instant::now();
             
@@ -620,6 +941,7 @@ impl AppRpc {
         use solana_account_decoder_client_types::UiAccountEncoding;
         
         let ctx = RpcContext {
+            route: RpcRoute::Reads,
             method: "getMultipleAccounts".to_string(),
             target_type: "batch".to_string(),
             target_address: String::new(),
@@ -636,7 +958,7 @@ impl AppRpc {
         
         let mut last_error = String::new();
         for attempt in 0..MAX_RETRIES {
-            let provider = self.provider(attempt);
+            let provider = self.routed_provider(ctx.route, attempt);
             provider.rate_limit().await;
             let start = Instant::now();
             
@@ -667,6 +989,7 @@ impl AppRpc {
     /// - Some(status) = found with confirmation details
     pub async fn get_signature_statuses(&self, signatures: &[String]) -> Result<Vec<Option<SignatureStatus>>> {
         let ctx = RpcContext {
+            route: RpcRoute::Latency,
             method: "getSignatureStatuses".to_string(),
             target_type: "signature".to_string(),
             target_address: if signatures.len() == 1 { 
@@ -690,10 +1013,10 @@ impl AppRpc {
         });
         
         let mut last_error = String::new();
-        let http_client = reqwest::Client::new();
+        let http_client = crate::custom_rpc::http_client();
         
         for attempt in 0..MAX_RETRIES {
-            let provider = self.provider(attempt);
+            let provider = self.routed_provider(ctx.route, attempt);
             provider.rate_limit().await;
             let start = Instant::now();
             
@@ -767,15 +1090,65 @@ impl AppRpc {
     /// This is the source of truth for miner data - more reliable than v2 endpoint
     /// Returns a HashMap keyed by authority pubkey string
     /// If treasury is provided, applies refined_ore calculation immediately
+    ///
+    /// With `with_miners_gpa_concurrency(n > 1)` the scan is split into 256
+    /// partitions by the authority's first byte, fetched `n` at a time and
+    /// decoded as each one arrives. Every load's duration is kept in
+    /// `miners_load_stats`.
     pub async fn get_all_miners_gpa(&self, treasury: Option<&Treasury>) -> Result<std::collections::HashMap<String, Miner>> {
+        let start = Instant::now();
+        let result = if self.miners_gpa_concurrency > 1 {
+            self.get_miners_gpa_partitioned(treasury).await
+        } else {
+            self.get_miners_gpa(None, MIN_SNAPSHOT_MINERS, treasury).await
+        };
+        self.miners_load.lock().unwrap().record(
+            start.elapsed(),
+            result.as_ref().ok().map(|miners| miners.len()),
+            self.miners_gpa_concurrency,
+        );
+        result
+    }
+    
+    /// All miners as 256 authority-prefix partitions, `miners_gpa_concurrency` in flight
+    async fn get_miners_gpa_partitioned(&self, treasury: Option<&Treasury>) -> Result<std::collections::HashMap<String, Miner>> {
+        use futures::{StreamExt, TryStreamExt};
+        
+        let miners = futures::stream::iter(0..=u8::MAX)
+            .map(|prefix| self.get_miners_gpa(Some(prefix), 0, treasury))
+            .buffer_unordered(self.miners_gpa_concurrency)
+            .try_fold(std::collections::HashMap::new(), |mut all, partition| async move {
+                all.extend(partition);
+                Ok(all)
+            })
+            .await?;
+        
+        if miners.len() < MIN_SNAPSHOT_MINERS {
+            return Err(anyhow::anyhow!(
+                "Insufficient miners: got {} but need at least {}", miners.len(), MIN_SNAPSHOT_MINERS
+            ));
+        }
+        tracing::info!("GPA miners snapshot: {} miners from 256 partitions", miners.len());
+        Ok(miners)
+    }
+    
+    /// Miners whose authority starts with `authority_prefix` (all when None)
+    /// A response with fewer than `min_miners` is retried like an error
+    async fn get_miners_gpa(
+        &self,
+        authority_prefix: Option<u8>,
+        min_miners: usize,
+        treasury: Option<&Treasury>,
+    ) -> Result<std::collections::HashMap<String, Miner>> {
         use solana_client::rpc_config::{RpcProgramAccountsConfig, RpcAccountInfoConfig};
-        use solana_client::rpc_filter::RpcFilterType;
+        use solana_client::rpc_filter::{Memcmp, RpcFilterType};
         use solana_account_decoder_client_types::UiAccountEncoding;
         
         let ctx = RpcContext {
+            route: RpcRoute::Gpa,
             method: "getProgramAccounts".to_string(),
             target_type: "miner".to_string(),
-            target_address: evore::ore_api::PROGRAM_ID.to_string(),
+            target_address: self.network.ore_program_id.to_string(),
             is_batch: true,
             batch_size: 0, // Unknown until we get results
         };
@@ -784,8 +1157,13 @@ impl AppRpc {
         let miner_size = std::mem::size_of::<Miner>() as u64 + 8;
         
         // Use Base64Zstd for compression - reduces bandwidth significantly
+        let mut filters = vec![RpcFilterType::DataSize(miner_size)];
+        if let Some(prefix) = authority_prefix {
+            // Authority is the first field after the 8-byte discriminator
+            filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(8, vec![prefix])));
+        }
         let config = RpcProgramAccountsConfig {
-            filters: Some(vec![RpcFilterType::DataSize(miner_size)]),
+            filters: Some(filters),
             account_config: RpcAccountInfoConfig {
                 encoding: Some(UiAccountEncoding::Base64Zstd),
                 data_slice: None,
@@ -799,12 +1177,12 @@ impl AppRpc {
         let mut last_error = String::new();
         
         for attempt in 0..MAX_RETRIES {
-            let provider = self.provider(attempt);
+            let provider = self.routed_provider(ctx.route, attempt);
             provider.rate_limit().await;
             let start = Instant::now();
             
             let result = provider.client
-                .get_program_accounts_with_config(&evore::ore_api::PROGRAM_ID, config.clone())
+                .get_program_accounts_with_config(&self.network.ore_program_id, config.clone())
             .await;
         
         let duration_ms = start.elapsed().as_millis() as u32;
@@ -825,11 +1203,11 @@ impl AppRpc {
                     }
                 }
                     
-                    // Validate: must have at least 10,000 miners to be a successful snapshot
-                    if miners.len() < 10_000 {
+                    // Validate: a full snapshot must have at least 10,000 miners
+                    if miners.len() < min_miners {
                         last_error = format!(
-                            "Insufficient miners: got {} but need at least 10,000",
-                            miners.len()
+                            "Insufficient miners: got {} but need at least {}",
+                            miners.len(), min_miners
                         );
                         tracing::warn!(
                             "GPA miners snapshot ({}) failed validation: {} (attempt {}/{})",
@@ -842,10 +1220,16 @@ impl AppRpc {
                         continue;
                 }
                 
-                tracing::info!(
-                        "GPA miners snapshot ({}): {} accounts fetched, {} miners parsed in {}ms",
-                        provider.name, accounts.len(), miners.len(), duration_ms
-                );
+                    match authority_prefix {
+                        None => tracing::info!(
+                            "GPA miners snapshot ({}): {} accounts fetched, {} miners parsed in {}ms",
+                            provider.name, accounts.len(), miners.len(), duration_ms
+                        ),
+                        Some(prefix) => tracing::debug!(
+                            "GPA miners partition {:02x} ({}): {} miners in {}ms",
+                            prefix, provider.name, miners.len(), duration_ms
+                        ),
+                    }
                 
                     self.log_success(&provider.name, &provider.api_key_id, &ctx, duration_ms, miners.len() as u32, response_size as u32).await;
                     return Ok(miners);
@@ -877,6 +1261,7 @@ impl AppRpc {
         use crate::evore_cache::MANAGER_SIZE;
         
         let ctx = RpcContext {
+            route: RpcRoute::Gpa,
             method: "getProgramAccounts".to_string(),
             target_type: "evore_manager".to_string(),
             target_address: evore::ID.to_string(),
@@ -900,7 +1285,7 @@ impl AppRpc {
         let mut last_error = String::new();
         
         for attempt in 0..MAX_RETRIES {
-            let provider = self.provider(attempt);
+            let provider = self.routed_provider(ctx.route, attempt);
             provider.rate_limit().await;
             let start = Instant::now();
             
@@ -952,6 +1337,7 @@ impl AppRpc {
         use crate::evore_cache::DEPLOYER_SIZE;
         
         let ctx = RpcContext {
+            route: RpcRoute::Gpa,
             method: "getProgramAccounts".to_string(),
             target_type: "evore_deployer".to_string(),
             target_address: evore::ID.to_string(),
@@ -975,7 +1361,7 @@ impl AppRpc {
         let mut last_error = String::new();
         
         for attempt in 0..MAX_RETRIES {
-            let provider = self.provider(attempt);
+            let provider = self.routed_provider(ctx.route, attempt);
             provider.rate_limit().await;
             let start = Instant::now();
             
@@ -1032,6 +1418,7 @@ impl AppRpc {
         use solana_sdk::signature::Signature;
         
         let ctx = RpcContext {
+            route: RpcRoute::Reads,
             method: "getSignaturesForAddress".to_string(),
             target_type: "signatures".to_string(),
             target_address: address.to_string(),
@@ -1044,8 +1431,7 @@ impl AppRpc {
         let mut last_error = String::new();
         
         for attempt in 0..MAX_RETRIES {
-            let provider_idx = attempt % self.providers.len();
-            let provider = &self.providers[provider_idx];
+            let provider = self.routed_provider(ctx.route, attempt);
             
             provider.rate_limit().await;
             let start = Instant::now();
@@ -1154,6 +1540,7 @@ impl AppRpc {
         address: &Pubkey,
     ) -> Result<Vec<solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature>> {
         let ctx = RpcContext {
+            route: RpcRoute::Reads,
             method: "getSignaturesForAddress".to_string(),
             target_type: "signatures_backfill".to_string(),
             target_address: address.to_string(),
@@ -1278,6 +1665,7 @@ impl AppRpc {
             .map_err(|e| anyhow::anyhow!("Invalid signature: {}", e))?;
         
         let ctx = RpcContext {
+            route: RpcRoute::Reads,
             method: "getTransaction".to_string(),
             target_type: "transaction".to_string(),
             target_address: signature.to_string(),
@@ -1377,6 +1765,7 @@ impl AppRpc {
             .map_err(|e| anyhow::anyhow!("Invalid signature: {}", e))?;
         
         let ctx = RpcContext {
+            route: RpcRoute::Reads,
             method: "getTransaction".to_string(),
             target_type: "transaction_backfill".to_string(),
             target_address: signature.to_string(),
@@ -1521,12 +1910,69 @@ mod tests {
         assert_eq!(extract_provider_name("https://some-random-rpc.com"), "unknown");
     }
     
+    #[test]
+    fn test_routing_policy_from_vars() {
+        assert_eq!(RoutingPolicy::from_vars(|_| None), RoutingPolicy::default());
+        
+        let policy = RoutingPolicy::from_vars(|key| match key {
+            "RPC_ROUTE_GPA" => Some(" Helius ".to_string()),
+            "RPC_ROUTE_READS" => Some(String::new()),
+            _ => None,
+        });
+        assert_eq!(policy.provider_for(RpcRoute::Gpa), "helius");
+        assert_eq!(policy.provider_for(RpcRoute::Reads), "flux");
+        assert_eq!(policy.provider_for(RpcRoute::Latency), "flux");
+    }
+    
+    #[test]
+    fn test_routed_provider_order() {
+        let rpc = AppRpc::new("helius.example".to_string(), "flux.example".to_string(), Some("triton.example".to_string()), None)
+            .with_routing(RoutingPolicy {
+                reads: "helius".to_string(),
+                gpa: "triton".to_string(),
+                latency: "bogus".to_string(),
+            });
+        let order = |route| (0..4).map(|attempt| rpc.routed_provider(route, attempt).name.clone()).collect::<Vec<_>>();
+        assert_eq!(order(RpcRoute::Reads), ["helius", "triton", "flux", "helius"]);
+        assert_eq!(order(RpcRoute::Gpa), ["triton", "flux", "helius", "triton"]);
+        // Unknown provider falls back to the primary
+        assert_eq!(order(RpcRoute::Latency), ["flux", "helius", "triton", "flux"]);
+    }
+    
+    #[test]
+    fn test_route_stats_snapshot() {
+        let stats = RouteStats::default();
+        stats.record(RpcRoute::Gpa, "helius", true);
+        stats.record(RpcRoute::Gpa, "helius", true);
+        stats.record(RpcRoute::Gpa, "flux", false);
+        stats.record(RpcRoute::Reads, "flux", true);
+        
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.len(), 3);
+        assert_eq!(snapshot[0], RouteStatsEntry { route: RpcRoute::Reads, provider: "flux".to_string(), served: 1, errors: 0 });
+        assert_eq!(snapshot[1], RouteStatsEntry { route: RpcRoute::Gpa, provider: "flux".to_string(), served: 0, errors: 1 });
+        assert_eq!(snapshot[2], RouteStatsEntry { route: RpcRoute::Gpa, provider: "helius".to_string(), served: 2, errors: 0 });
+    }
+    
     #[test]
     fn test_extract_api_key_id() {
         assert_eq!(extract_api_key_id("https://rpc.helius.xyz?api-key=abcdefghij123"), "abcdefgh...");
         assert_eq!(extract_api_key_id("https://rpc.helius.xyz"), "");
         assert_eq!(extract_api_key_id("https://rpc.helius.xyz?api_key=12345678"), "12345678...");
     }
+    
+    #[test]
+    fn test_miners_load_stats_record() {
+        let mut stats = MinersLoadStats::default();
+        stats.record(Duration::from_millis(1_500), Some(12_000), 8);
+        stats.record(Duration::from_millis(300), None, 8);
+        
+        assert_eq!(stats.loads, 2);
+        assert_eq!(stats.failures, 1);
+        assert_eq!(stats.last_duration_ms, 300);
+        // A failure keeps the last good load's numbers
+        assert_eq!(stats.last_success_ms, 1_500);
+        assert_eq!(stats.last_miners, 12_000);
+        assert_eq!(stats.concurrency, 8);
+    }
 }
-
-
@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -6,12 +6,16 @@ use chrono::{DateTime, Utc};
 use evore::ore_api::{AutomationStrategy, Board, Miner, Round, Treasury};
 use serde::{Deserialize, Serialize};
 use steel::{Numeric, Pubkey};
-use tokio::sync::{broadcast, RwLock};
+use tokio::sync::{broadcast, Notify, RwLock};
 
 use crate::app_rpc::AppRpc;
 use crate::clickhouse::ClickHouseClient;
 use crate::evore_cache::EvoreCache;
+use crate::balance_cache::{BalanceCache, DEFAULT_BALANCE_TTL};
 use crate::helius_api::HeliusApi;
+use crate::ore_price::{OrePriceCache, OrePriceConfig};
+use crate::readiness::SubscriptionStatus;
+use crate::sse::{SseConfig, SseStats};
 
 // ============================================================================
 // Utility Functions
@@ -59,12 +63,22 @@ pub struct AppState {
     pub rpc: Arc<AppRpc>,
     
     // Helius API for bulk fetching (miners, token holders)
-    pub helius: Arc<RwLock<HeliusApi>>,
+    pub helius: Arc<HeliusApi>,
+    
+    // Short-TTL SOL balance cache (batched getMultipleAccounts)
+    pub balance_cache: Arc<BalanceCache>,
     
     // Live caches (updated by polling task)
     pub board_cache: Arc<RwLock<Option<Board>>>,
     pub treasury_cache: Arc<RwLock<Option<Treasury>>>,
     pub round_cache: Arc<RwLock<Option<LiveRound>>>,
+    /// Woken when `round_cache` moves to a new round, so the round
+    /// broadcaster sends it without waiting for its next tick
+    pub round_changed: Arc<Notify>,
+    /// Last `/live/board-vector` response and when it was built
+    pub board_vector_cache: Arc<RwLock<Option<(Instant, BoardVector)>>>,
+    /// Latest ORE price from the price oracle (`/ore-price`)
+    pub ore_price: Arc<RwLock<OrePriceCache>>,
     /// Miners cache sorted by authority (base58 string) for consistent pagination
     pub miners_cache: Arc<RwLock<BTreeMap<String, Miner>>>,
     pub miners_last_slot: Arc<RwLock<u64>>,
@@ -74,6 +88,7 @@ pub struct AppState {
     
     // ORE token holders cache (updated periodically)
     pub ore_holders_cache: Arc<RwLock<HashMap<Pubkey, u64>>>,
+    /// Slot the holders cache was last synced at (0 = not loaded yet)
     pub ore_holders_last_slot: Arc<RwLock<u64>>,
     
     // EVORE program accounts cache (Managers, Deployers, Auth balances)
@@ -83,6 +98,16 @@ pub struct AppState {
     pub round_broadcast: broadcast::Sender<LiveBroadcastData>,
     pub deployment_broadcast: broadcast::Sender<LiveBroadcastData>,
     
+    /// Board as of the last snapshot/diff broadcast, sent to new `/sse/board` clients
+    pub last_board_broadcast: Arc<RwLock<Option<BoardSnapshot>>>,
+    
+    // SSE per-client buffering / backpressure config and dropped-event counts
+    pub sse_config: SseConfig,
+    pub sse_stats: Arc<SseStats>,
+    
+    // WebSocket subscription connection state for `/readyz`
+    pub subscriptions: Arc<SubscriptionStatus>,
+    
     // Per-round deployment tracking for Phase 2 finalization
     // Maps: miner_pubkey -> { square_id -> (amount, slot) }
     // Tracks when each square was deployed for accurate slot data
@@ -103,6 +128,9 @@ pub struct AppState {
     // Automation state reconstruction task live stats
     pub automation_task_stats: Arc<RwLock<AutomationTaskStats>>,
     
+    // Latest reconstructed automation state per authority (inspected/flushed via admin)
+    pub automation_cache: Arc<RwLock<AutomationCacheStore>>,
+    
     // Rounds backfill task state and cancellation flag
     pub backfill_rounds_task_state: Arc<RwLock<BackfillRoundsTaskState>>,
     pub backfill_rounds_cancel: Arc<RwLock<bool>>,
@@ -390,10 +418,12 @@ impl AppState {
         clickhouse: Arc<ClickHouseClient>,
         postgres: sqlx::Pool<sqlx::Postgres>,
         rpc: Arc<AppRpc>,
-        helius: Arc<RwLock<HeliusApi>>,
+        helius: Arc<HeliusApi>,
+        sse_config: SseConfig,
     ) -> Self {
-        let (round_tx, _) = broadcast::channel(100);
-        let (deployment_tx, _) = broadcast::channel(1000);
+        // Rounds are throttled upstream, so they never need more than 100
+        let (round_tx, _) = broadcast::channel(sse_config.client_buffer.min(100));
+        let (deployment_tx, _) = broadcast::channel(sse_config.client_buffer);
         
         Self {
             start_time: Instant::now(),
@@ -401,10 +431,14 @@ impl AppState {
             clickhouse,
             postgres,
             rpc,
+            balance_cache: Arc::new(BalanceCache::new(helius.clone(), DEFAULT_BALANCE_TTL)),
             helius,
             board_cache: Arc::new(RwLock::new(None)),
             treasury_cache: Arc::new(RwLock::new(None)),
             round_cache: Arc::new(RwLock::new(None)),
+            round_changed: Arc::new(Notify::new()),
+            board_vector_cache: Arc::new(RwLock::new(None)),
+            ore_price: Arc::new(RwLock::new(OrePriceCache::new(OrePriceConfig::from_env()))),
             miners_cache: Arc::new(RwLock::new(BTreeMap::new())),
             miners_last_slot: Arc::new(RwLock::new(0)),
             slot_cache: Arc::new(RwLock::new(0)),
@@ -413,12 +447,17 @@ impl AppState {
             evore_cache: Arc::new(RwLock::new(EvoreCache::new())),
             round_broadcast: round_tx,
             deployment_broadcast: deployment_tx,
+            last_board_broadcast: Arc::new(RwLock::new(None)),
+            sse_config,
+            sse_stats: Arc::new(SseStats::default()),
+            subscriptions: Arc::new(SubscriptionStatus::default()),
             pending_deployments: Arc::new(RwLock::new(HashMap::new())),
             pending_round_id: Arc::new(RwLock::new(0)),
             round_snapshot: Arc::new(RwLock::new(None)),
             deployments_cache: Arc::new(RwLock::new(HashMap::new())),
             deployments_cache_round_id: Arc::new(RwLock::new(0)),
             automation_task_stats: Arc::new(RwLock::new(AutomationTaskStats::default())),
+            automation_cache: Arc::new(RwLock::new(AutomationCacheStore::from_env())),
             backfill_rounds_task_state: Arc::new(RwLock::new(BackfillRoundsTaskState::default())),
             backfill_rounds_cancel: Arc::new(RwLock::new(false)),
             backfill_queue_cache: Arc::new(RwLock::new(BackfillQueueCache::new())),
@@ -478,6 +517,78 @@ impl LiveRound {
     }
 }
 
+/// Compact 25-square view of the live round for high-frequency polling
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BoardVector {
+    pub round_id: u64,
+    pub end_slot: u64,
+    pub slots_left: u64,
+    pub squares: [u64; 25],
+    pub total: u64,
+}
+
+impl BoardVector {
+    pub fn from_live_round(round: &LiveRound, current_slot: u64) -> Self {
+        Self {
+            round_id: round.round_id,
+            end_slot: round.end_slot,
+            slots_left: round.end_slot.saturating_sub(current_slot),
+            squares: round.deployed,
+            total: round.total_deployed,
+        }
+    }
+}
+
+/// Full board as last broadcast, sent to `/sse/board` clients to (re)sync
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardSnapshot {
+    pub round_id: u64,
+    pub deployed: [u64; 25],
+}
+
+/// New total for one square
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SquareChange {
+    pub square: u8,
+    pub new_amount: u64,
+}
+
+/// Squares that changed since the previous board broadcast
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardDiff {
+    pub round_id: u64,
+    pub changes: Vec<SquareChange>,
+}
+
+impl BoardDiff {
+    /// Squares whose amount differs between `prev` and `next`
+    pub fn between(round_id: u64, prev: &[u64; 25], next: &[u64; 25]) -> Self {
+        let changes = prev.iter().zip(next).enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(square, (_, &new_amount))| SquareChange { square: square as u8, new_amount })
+            .collect();
+        Self { round_id, changes }
+    }
+}
+
+/// Board update to broadcast after a round tick: a full snapshot when the
+/// round changed (or nothing was sent yet), otherwise a diff; None if unchanged.
+/// `last` becomes the new board.
+pub fn next_board_update(last: &mut Option<BoardSnapshot>, round_id: u64, deployed: [u64; 25]) -> Option<LiveBroadcastData> {
+    let update = match last.as_ref() {
+        Some(prev) if prev.round_id == round_id => {
+            let diff = BoardDiff::between(round_id, &prev.deployed, &deployed);
+            if diff.changes.is_empty() {
+                return None;
+            }
+            LiveBroadcastData::BoardDiff(diff)
+        }
+        _ => LiveBroadcastData::BoardSnapshot(BoardSnapshot { round_id, deployed }),
+    };
+    *last = Some(BoardSnapshot { round_id, deployed });
+    Some(update)
+}
+
 /// Data broadcast over SSE channels
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", content = "data")]
@@ -488,6 +599,12 @@ pub enum LiveBroadcastData {
     /// Single deployment event
     Deployment(LiveDeployment),
     
+    /// Full board at a round transition (`/sse/board`)
+    BoardSnapshot(BoardSnapshot),
+    
+    /// Squares changed since the last board broadcast (`/sse/board`)
+    BoardDiff(BoardDiff),
+    
     /// Winning square announcement at round end
     WinningSquare {
         round_id: u64,
@@ -682,6 +799,115 @@ impl AutomationCache {
     }
 }
 
+/// Default cap on cached automation states (override with AUTOMATION_CACHE_MAX_ENTRIES)
+pub const DEFAULT_AUTOMATION_CACHE_MAX_ENTRIES: usize = 10_000;
+
+/// Per-authority automation caches with least-recently-used eviction.
+///
+/// Evicting an authority only costs a recompute: on a miss the heavy DESC
+/// scan rebuilds its automation state from history. Flushing an authority
+/// also makes its next reconstruction ignore previously stored states.
+#[derive(Debug)]
+pub struct AutomationCacheStore {
+    max_entries: usize,
+    tick: u64,
+    entries: HashMap<Pubkey, (AutomationCache, u64)>,
+    /// last-used tick -> authority, oldest first
+    recency: BTreeMap<u64, Pubkey>,
+    /// Flushed authorities whose next reconstruction must do a fresh scan
+    force_rescan: HashSet<Pubkey>,
+}
+
+impl AutomationCacheStore {
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries: max_entries.max(1),
+            tick: 0,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            force_rescan: HashSet::new(),
+        }
+    }
+
+    /// Build from AUTOMATION_CACHE_MAX_ENTRIES, falling back to the default
+    pub fn from_env() -> Self {
+        let max_entries = std::env::var("AUTOMATION_CACHE_MAX_ENTRIES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_AUTOMATION_CACHE_MAX_ENTRIES);
+        Self::new(max_entries)
+    }
+
+    fn touch(&mut self, authority: &Pubkey) -> Option<&AutomationCache> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (cache, last_used) = self.entries.get_mut(authority)?;
+        self.recency.remove(last_used);
+        *last_used = tick;
+        self.recency.insert(tick, *authority);
+        Some(cache)
+    }
+
+    /// Get a cached automation state, marking it most recently used
+    pub fn get(&mut self, authority: &Pubkey) -> Option<AutomationCache> {
+        self.touch(authority).cloned()
+    }
+
+    /// Insert or replace an authority's cache, evicting the least recently used if full
+    pub fn insert(&mut self, cache: AutomationCache) {
+        let authority = cache.authority;
+        if self.touch(&authority).is_some() {
+            if let Some((existing, _)) = self.entries.get_mut(&authority) {
+                *existing = cache;
+            }
+            return;
+        }
+
+        while self.entries.len() >= self.max_entries {
+            let Some((_, oldest)) = self.recency.pop_first() else { break };
+            self.entries.remove(&oldest);
+        }
+
+        self.entries.insert(authority, (cache, self.tick));
+        self.recency.insert(self.tick, authority);
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// All cached states, without touching recency
+    pub fn entries(&self) -> Vec<AutomationCache> {
+        self.entries.values().map(|(cache, _)| cache.clone()).collect()
+    }
+
+    /// Drop one authority's cache (or all of them) and force a fresh scan on
+    /// their next reconstruction. Returns the authorities removed.
+    pub fn flush(&mut self, authority: Option<&Pubkey>) -> Vec<Pubkey> {
+        let flushed: Vec<Pubkey> = match authority {
+            Some(authority) => {
+                // Force a fresh scan even if it isn't cached right now
+                self.force_rescan.insert(*authority);
+                self.entries.remove(authority).map(|(_, tick)| {
+                    self.recency.remove(&tick);
+                    *authority
+                }).into_iter().collect()
+            }
+            None => {
+                self.recency.clear();
+                self.entries.drain().map(|(authority, _)| authority).collect()
+            }
+        };
+        self.force_rescan.extend(flushed.iter().copied());
+        flushed
+    }
+
+    /// Whether the authority was flushed since its last reconstruction (clears the flag)
+    pub fn take_force_rescan(&mut self, authority: &Pubkey) -> bool {
+        self.force_rescan.remove(authority)
+    }
+}
+
 impl AppDeployment {
     pub fn new(pubkey: String, round_id: i64) -> Self {
         Self {
@@ -707,3 +933,107 @@ impl Default for AppDeployedSquare {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_automation_cache_store_evicts_least_recently_used() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let c = Pubkey::new_unique();
+
+        let mut store = AutomationCacheStore::new(2);
+        store.insert(AutomationCache::new(a));
+        store.insert(AutomationCache::new(b));
+
+        // Touch `a` so `b` becomes least recently used
+        assert!(store.get(&a).is_some());
+        store.insert(AutomationCache::new(c));
+
+        assert!(store.get(&b).is_none());
+        assert!(store.get(&a).is_some());
+        assert!(store.get(&c).is_some());
+        assert_eq!(store.entries.len(), 2);
+        assert_eq!(store.recency.len(), 2);
+    }
+
+    #[test]
+    fn test_automation_cache_store_flush() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let uncached = Pubkey::new_unique();
+
+        let mut store = AutomationCacheStore::new(10);
+        store.insert(AutomationCache::new(a));
+        store.insert(AutomationCache::new(b));
+
+        assert_eq!(store.flush(Some(&a)), vec![a]);
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.recency.len(), 1);
+        assert!(store.take_force_rescan(&a));
+        assert!(!store.take_force_rescan(&a));
+
+        // Not cached, but its next reconstruction still rescans
+        assert!(store.flush(Some(&uncached)).is_empty());
+        assert!(store.take_force_rescan(&uncached));
+
+        assert_eq!(store.flush(None), vec![b]);
+        assert_eq!(store.len(), 0);
+        assert!(store.recency.is_empty());
+        assert!(store.take_force_rescan(&b));
+    }
+
+    #[test]
+    fn test_next_board_update() {
+        let mut last = None;
+        let mut deployed = [0u64; 25];
+        deployed[0] = 10;
+
+        // First board is a snapshot
+        let update = next_board_update(&mut last, 1, deployed);
+        assert!(matches!(update, Some(LiveBroadcastData::BoardSnapshot(ref s)) if s.deployed == deployed));
+
+        // Unchanged board sends nothing
+        assert!(next_board_update(&mut last, 1, deployed).is_none());
+
+        deployed[0] = 15;
+        deployed[24] = 3;
+        match next_board_update(&mut last, 1, deployed) {
+            Some(LiveBroadcastData::BoardDiff(diff)) => assert_eq!(diff.changes, vec![
+                SquareChange { square: 0, new_amount: 15 },
+                SquareChange { square: 24, new_amount: 3 },
+            ]),
+            other => panic!("expected diff, got {:?}", other),
+        }
+
+        // New round resyncs with a snapshot
+        let update = next_board_update(&mut last, 2, [0; 25]);
+        assert!(matches!(update, Some(LiveBroadcastData::BoardSnapshot(ref s)) if s.round_id == 2));
+        assert_eq!(last, Some(BoardSnapshot { round_id: 2, deployed: [0; 25] }));
+    }
+
+    #[test]
+    fn test_board_vector_from_live_round() {
+        let mut deployed = [0u64; 25];
+        deployed[3] = 7;
+        deployed[24] = 5;
+        let round = LiveRound {
+            round_id: 42,
+            start_slot: 1_000,
+            end_slot: 1_150,
+            slots_remaining: 0,
+            deployed,
+            count: [0; 25],
+            total_deployed: 12,
+            unique_miners: 2,
+        };
+
+        let vector = BoardVector::from_live_round(&round, 1_100);
+        assert_eq!(vector, BoardVector { round_id: 42, end_slot: 1_150, slots_left: 50, squares: deployed, total: 12 });
+
+        // Past the end the round just has no slots left
+        assert_eq!(BoardVector::from_live_round(&round, 1_200).slots_left, 0);
+    }
+}
@@ -8,12 +8,12 @@
 //! - Multiple automate + deploy instructions in a single transaction
 //! - Protocol limit: 1 deploy per square per round (affects how automation mask is applied)
 
-use crate::admin_auth::AuthError;
+use crate::app_error::AppError;
 use crate::app_state::{AppState, AutomationCache, ReconstructedAutomation};
-use crate::clickhouse::ClickHouseError;
+use crate::clickhouse::{tables, ClickHouseError};
+use crate::helius_api::{AutomationHistoryScan, HeliusError};
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
     Json,
 };
 use evore::ore_api::{self, Automate, OreInstruction, AutomationStrategy};
@@ -204,7 +204,7 @@ impl crate::clickhouse::ClickHouseClient {
         &self,
         state: DeploymentAutomationStateInsert,
     ) -> Result<(), ClickHouseError> {
-        let mut insert = self.client.insert("deployment_automation_states")?;
+        let mut insert = self.insert(tables::DEPLOYMENT_AUTOMATION_STATES)?;
         insert.write(&state).await?;
         insert.end().await?;
         Ok(())
@@ -216,8 +216,7 @@ impl crate::clickhouse::ClickHouseClient {
         deploy_signature: &str,
         deploy_ix_index: u8,
     ) -> Result<Option<DeploymentAutomationStateRow>, ClickHouseError> {
-        let row = self.client
-            .query(r#"
+        let row = self.query(r#"
                 SELECT 
                     round_id, miner_pubkey, authority_pubkey, deploy_signature, deploy_ix_index, deploy_slot,
                     automation_found, automation_active, automation_amount, automation_mask,
@@ -237,13 +236,36 @@ impl crate::clickhouse::ClickHouseClient {
         Ok(row)
     }
     
+    /// Get all stored deployment states for a signature (one row per deploy instruction).
+    pub async fn get_deployment_by_signature(
+        &self,
+        deploy_signature: &str,
+    ) -> Result<Vec<DeploymentAutomationStateRow>, ClickHouseError> {
+        let rows = self.query(r#"
+                SELECT
+                    round_id, miner_pubkey, authority_pubkey, deploy_signature, deploy_ix_index, deploy_slot,
+                    automation_found, automation_active, automation_amount, automation_mask,
+                    automation_strategy, automation_fee, automation_executor,
+                    automate_signature, automate_ix_index, automate_slot,
+                    txns_searched, pages_fetched, fetch_duration_ms,
+                    automation_balance, is_partial_deploy, actual_squares_deployed, actual_mask, total_sol_spent,
+                    created_at
+                FROM deployment_automation_states FINAL
+                WHERE deploy_signature = ?
+                ORDER BY deploy_ix_index
+            "#)
+            .bind(deploy_signature)
+            .fetch_all::<DeploymentAutomationStateRow>()
+            .await?;
+        Ok(rows)
+    }
+
     /// Get deployments missing automation state for a round.
     pub async fn get_deployments_missing_automation(
         &self,
         round_id: u64,
     ) -> Result<Vec<DeploymentMissingState>, ClickHouseError> {
-        let rows = self.client
-            .query(r#"
+        let rows = self.query(r#"
                 SELECT 
                     d.round_id,
                     d.miner_pubkey,
@@ -282,8 +304,7 @@ impl crate::clickhouse::ClickHouseClient {
         // Find a stored state where:
         // - automate_slot < target_deploy_slot (automation was set before our target)
         // - deploy_slot > target_deploy_slot (strictly greater - we need to re-scan deploy_slot)
-        let row = self.client
-            .query(r#"
+        let row = self.query(r#"
                 SELECT 
                     round_id, miner_pubkey, authority_pubkey, deploy_signature, deploy_ix_index, deploy_slot,
                     automation_found, automation_active, automation_amount, automation_mask,
@@ -314,8 +335,7 @@ impl crate::clickhouse::ClickHouseClient {
         &self,
         authority_pubkey: &str,
     ) -> Result<Option<DeploymentAutomationStateRow>, ClickHouseError> {
-        let row = self.client
-            .query(r#"
+        let row = self.query(r#"
                 SELECT 
                     round_id, miner_pubkey, authority_pubkey, deploy_signature, deploy_ix_index, deploy_slot,
                     automation_found, automation_active, automation_amount, automation_mask,
@@ -338,8 +358,7 @@ impl crate::clickhouse::ClickHouseClient {
     
     /// Get automation state fetch statistics.
     pub async fn get_automation_fetch_stats(&self) -> Result<AutomationFetchStats, ClickHouseError> {
-        let row = self.client
-            .query(r#"
+        let row = self.query(r#"
                 SELECT
                     count() as total_fetched,
                     countIf(automation_found) as found_count,
@@ -387,7 +406,7 @@ pub struct AutomationFetchStats {
 /// Get queue statistics from PostgreSQL.
 pub async fn get_queue_stats(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<AutomationQueueStats>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<AutomationQueueStats>, AppError> {
     let row = sqlx::query_as::<_, (i64, i64, i64, i64, Option<f64>, Option<f64>)>(r#"
         SELECT 
             COUNT(*) FILTER (WHERE status = 'pending') as pending,
@@ -402,10 +421,7 @@ pub async fn get_queue_stats(
     .await
     .map_err(|e| {
         tracing::error!("Failed to get queue stats: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(AuthError { error: format!("DB error: {}", e) }),
-        )
+        AppError::Internal(format!("DB error: {}", e))
     })?;
     
     Ok(Json(AutomationQueueStats {
@@ -423,7 +439,7 @@ pub async fn get_queue_stats(
 pub async fn get_queue_items(
     State(state): State<Arc<AppState>>,
     Query(query): Query<AutomationQueueQuery>,
-) -> Result<Json<AutomationQueueResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<AutomationQueueResponse>, AppError> {
     let page = query.page.unwrap_or(1);
     let limit = query.limit.unwrap_or(50).min(200);
     let offset = (page.saturating_sub(1)) * limit;
@@ -469,20 +485,14 @@ pub async fn get_queue_items(
     .await
     .map_err(|e| {
         tracing::error!("Failed to get queue items: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(AuthError { error: format!("DB error: {}", e) }),
-        )
+        AppError::Internal(format!("DB error: {}", e))
     })?;
     
     let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM automation_state_queue")
         .fetch_one(&state.postgres)
         .await
         .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: format!("DB error: {}", e) }),
-            )
+            AppError::Internal(format!("DB error: {}", e))
         })?;
     
     Ok(Json(AutomationQueueResponse {
@@ -548,7 +558,7 @@ impl From<AutomationQueueItemRow> for AutomationQueueItem {
 pub async fn add_to_queue(
     State(state): State<Arc<AppState>>,
     Json(requests): Json<Vec<AddToQueueRequest>>,
-) -> Result<Json<AddToQueueResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<AddToQueueResponse>, AppError> {
     let mut queued = 0u32;
     let mut already_exists = 0u32;
     let mut errors = Vec::new();
@@ -600,16 +610,13 @@ pub async fn add_to_queue(
 pub async fn queue_missing_for_round(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
-) -> Result<Json<AddToQueueResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<AddToQueueResponse>, AppError> {
     // First get deployments missing automation state
     let missing = state.clickhouse
         .get_deployments_missing_automation(round_id)
         .await
         .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: format!("ClickHouse error: {}", e) }),
-            )
+            AppError::Internal(format!("ClickHouse error: {}", e))
         })?;
     
     if missing.is_empty() {
@@ -707,7 +714,7 @@ pub async fn queue_missing_for_round(
 pub async fn process_queue(
     State(state): State<Arc<AppState>>,
     Query(params): Query<ProcessQueueParams>,
-) -> Result<Json<ProcessResultResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<ProcessResultResponse>, AppError> {
     let count = params.count.unwrap_or(5).min(50);
     
     // Get pending items
@@ -727,10 +734,7 @@ pub async fn process_queue(
     .fetch_all(&state.postgres)
     .await
     .map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(AuthError { error: format!("DB error: {}", e) }),
-        )
+        AppError::Internal(format!("DB error: {}", e))
     })?;
     
     let mut results = ProcessResultResponse {
@@ -787,9 +791,15 @@ async fn process_single_item(
     // If we have a stored state where automate_slot < target_slot < deploy_slot, no scanning needed!
     // Note: Strictly less than deploy_slot because at deploy_slot there could be
     // an Automate instruction AFTER the Deploy in the same transaction.
-    let reusable = state.clickhouse
-        .get_reusable_automation_state(&item.authority_pubkey, item.deploy_slot as u64)
-        .await;
+    // A flushed authority ignores its stored states and scans from scratch
+    let fresh_scan = state.automation_cache.write().await.take_force_rescan(&authority);
+    let reusable = if fresh_scan {
+        Ok(None)
+    } else {
+        state.clickhouse
+            .get_reusable_automation_state(&item.authority_pubkey, item.deploy_slot as u64)
+            .await
+    };
     
     if let Ok(Some(existing)) = reusable {
         // Perfect! We can just copy the automation state, no transaction scanning needed
@@ -829,6 +839,7 @@ async fn process_single_item(
             total_sol_spent: existing.total_sol_spent,
         };
         
+        let cached = cache_entry(authority, &insert);
         if let Err(e) = state.clickhouse.insert_deployment_automation_state(insert).await {
             update_queue_failed(state, item.id, &format!("ClickHouse insert failed: {}", e)).await;
             return ProcessDetail {
@@ -845,6 +856,8 @@ async fn process_single_item(
             };
         }
         
+        state.automation_cache.write().await.insert(cached);
+        
         // Update queue as completed
         let _ = sqlx::query(r#"
             UPDATE automation_state_queue
@@ -873,24 +886,29 @@ async fn process_single_item(
     
     // No directly reusable state - need to scan backwards with full balance tracking
     // Check if we have any stored state we can use as an early-stop point
-    let fallback_state = state.clickhouse
-        .get_latest_automation_state_for_authority(&item.authority_pubkey)
-        .await
-        .ok()
-        .flatten();
+    let fallback_state = if fresh_scan {
+        None
+    } else {
+        state.clickhouse
+            .get_latest_automation_state_for_authority(&item.authority_pubkey)
+            .await
+            .ok()
+            .flatten()
+    };
     
     // Calculate stop_at_slot: one slot BEFORE the cached deploy_slot
     let stop_at_slot = fallback_state.as_ref().map(|fb| fb.deploy_slot.saturating_sub(1));
     
     // Perform comprehensive backwards scan with balance tracking
-    let mut helius = state.helius.write().await;
+    let helius = &state.helius;
     let scan_result = helius
         .scan_automation_history_with_balance(
             &authority, 
             item.deploy_slot as u64, 
             stop_at_slot,
         )
-        .await;
+        .await
+        .and_then(|scan| require_complete_scan(scan, fallback_state.is_some()));
     drop(helius);
     
     let duration_ms = start.elapsed().as_millis() as u64;
@@ -1003,6 +1021,7 @@ async fn process_single_item(
             let used_cache = fallback_state.is_some() && scan.automate_open.is_none();
             let cache_slot = if used_cache { fallback_state.as_ref().map(|f| f.automate_slot) } else { None };
             
+            let cached = cache_entry(authority, &insert);
             if let Err(e) = state.clickhouse.insert_deployment_automation_state(insert).await {
                 update_queue_failed(state, item.id, &format!("ClickHouse insert failed: {}", e)).await;
                 return ProcessDetail {
@@ -1019,6 +1038,8 @@ async fn process_single_item(
                 };
             }
             
+            state.automation_cache.write().await.insert(cached);
+            
             // Update queue as completed
             let _ = sqlx::query(r#"
                 UPDATE automation_state_queue
@@ -1063,6 +1084,36 @@ async fn process_single_item(
     }
 }
 
+/// A scan cut short by the Helius scan limit that found no Automate says
+/// nothing about the automation. With a cached state to fall back on it's
+/// used as usual; without one it's an error rather than "no automation".
+pub fn require_complete_scan(
+    scan: AutomationHistoryScan,
+    has_fallback: bool,
+) -> Result<AutomationHistoryScan, HeliusError> {
+    if scan.truncated && scan.automate_open.is_none() && !has_fallback {
+        return Err(HeliusError::ScanTruncated {
+            pages: scan.pages_fetched,
+            txns: scan.txns_searched,
+        });
+    }
+    Ok(scan)
+}
+
+/// In-memory cache entry for a stored automation state
+fn cache_entry(authority: Pubkey, insert: &DeploymentAutomationStateInsert) -> AutomationCache {
+    AutomationCache {
+        authority,
+        active: insert.automation_active,
+        mask: insert.automation_mask,
+        strategy: insert.automation_strategy as u64,
+        amount: insert.automation_amount,
+        fee: insert.automation_fee,
+        executor: insert.automation_executor.parse().unwrap_or_default(),
+        last_updated_slot: insert.deploy_slot,
+    }
+}
+
 async fn update_queue_failed(state: &AppState, id: i32, error: &str) {
     let _ = sqlx::query(r#"
         UPDATE automation_state_queue
@@ -1078,7 +1129,7 @@ async fn update_queue_failed(state: &AppState, id: i32, error: &str) {
 /// Retry failed items.
 pub async fn retry_failed(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<serde_json::Value>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<serde_json::Value>, AppError> {
     let result = sqlx::query(r#"
         UPDATE automation_state_queue
         SET status = 'pending', last_error = NULL
@@ -1087,10 +1138,7 @@ pub async fn retry_failed(
     .execute(&state.postgres)
     .await
     .map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(AuthError { error: format!("DB error: {}", e) }),
-        )
+        AppError::Internal(format!("DB error: {}", e))
     })?;
     
     Ok(Json(serde_json::json!({
@@ -1101,15 +1149,12 @@ pub async fn retry_failed(
 /// Get ClickHouse fetch statistics.
 pub async fn get_fetch_stats(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<AutomationFetchStats>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<AutomationFetchStats>, AppError> {
     let stats = state.clickhouse
         .get_automation_fetch_stats()
         .await
         .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: format!("ClickHouse error: {}", e) }),
-            )
+            AppError::Internal(format!("ClickHouse error: {}", e))
         })?;
     
     Ok(Json(stats))
@@ -1118,11 +1163,83 @@ pub async fn get_fetch_stats(
 /// Get live task stats.
 pub async fn get_live_stats(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<crate::app_state::AutomationTaskStats>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<crate::app_state::AutomationTaskStats>, AppError> {
     let stats = state.automation_task_stats.read().await.clone();
     Ok(Json(stats))
 }
 
+/// Query for POST /admin/automation-cache/flush
+#[derive(Debug, Deserialize)]
+pub struct FlushAutomationCacheQuery {
+    /// Only this authority (default: everything)
+    pub authority: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AutomationCacheEntry {
+    pub authority: String,
+    pub active: bool,
+    pub last_updated_slot: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AutomationCacheResponse {
+    pub authorities: usize,
+    pub active: usize,
+    pub inactive: usize,
+    /// Most recently updated first
+    pub entries: Vec<AutomationCacheEntry>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FlushAutomationCacheResponse {
+    pub flushed: usize,
+    pub authorities: Vec<String>,
+}
+
+/// GET /admin/automation-cache - Cached automation state per authority
+pub async fn get_automation_cache(
+    State(state): State<Arc<AppState>>,
+) -> Json<AutomationCacheResponse> {
+    let mut entries = state.automation_cache.read().await.entries();
+    entries.sort_by(|a, b| b.last_updated_slot.cmp(&a.last_updated_slot));
+    let active = entries.iter().filter(|e| e.active).count();
+    
+    Json(AutomationCacheResponse {
+        authorities: entries.len(),
+        active,
+        inactive: entries.len() - active,
+        entries: entries.into_iter().map(|e| AutomationCacheEntry {
+            authority: e.authority.to_string(),
+            active: e.active,
+            last_updated_slot: e.last_updated_slot,
+        }).collect(),
+    })
+}
+
+/// POST /admin/automation-cache/flush?authority= - Drop cached automation state
+/// 
+/// The flushed authorities' next reconstruction ignores previously stored
+/// states and scans their history from scratch.
+pub async fn flush_automation_cache(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<FlushAutomationCacheQuery>,
+) -> Result<Json<FlushAutomationCacheResponse>, AppError> {
+    let authority = params.authority
+        .map(|a| Pubkey::try_from(a.as_str()))
+        .transpose()
+        .map_err(|_| AppError::BadRequest("Invalid authority".to_string()))?;
+    
+    let flushed = state.automation_cache.write().await.flush(authority.as_ref());
+    tracing::info!("Flushed automation cache: {} authorities{}", flushed.len(),
+        authority.map(|a| format!(" (requested {})", a)).unwrap_or_default());
+    
+    Ok(Json(FlushAutomationCacheResponse {
+        flushed: flushed.len(),
+        authorities: flushed.iter().map(|a| a.to_string()).collect(),
+    }))
+}
+
 // ============================================================================
 // Background Task
 // ============================================================================
@@ -1246,9 +1363,15 @@ async fn process_single_item_with_stats(
     // If we have a stored state where automate_slot < target_slot < deploy_slot, no scanning needed!
     // Note: Strictly less than deploy_slot because at deploy_slot there could be
     // an Automate instruction AFTER the Deploy in the same transaction.
-    let reusable = state.clickhouse
-        .get_reusable_automation_state(&item.authority_pubkey, item.deploy_slot as u64)
-        .await;
+    // A flushed authority ignores its stored states and scans from scratch
+    let fresh_scan = state.automation_cache.write().await.take_force_rescan(&authority);
+    let reusable = if fresh_scan {
+        Ok(None)
+    } else {
+        state.clickhouse
+            .get_reusable_automation_state(&item.authority_pubkey, item.deploy_slot as u64)
+            .await
+    };
     
     if let Ok(Some(existing)) = reusable {
         // Perfect! We can just copy the automation state, no transaction scanning needed
@@ -1288,6 +1411,7 @@ async fn process_single_item_with_stats(
             total_sol_spent: existing.total_sol_spent,
         };
         
+        let cached = cache_entry(authority, &insert);
         if let Err(e) = state.clickhouse.insert_deployment_automation_state(insert).await {
             update_queue_failed(state, item.id, &format!("ClickHouse insert failed: {}", e)).await;
             return ProcessDetail {
@@ -1304,6 +1428,8 @@ async fn process_single_item_with_stats(
             };
         }
         
+        state.automation_cache.write().await.insert(cached);
+        
         // Update queue as completed
         let _ = sqlx::query(r#"
             UPDATE automation_state_queue
@@ -1331,11 +1457,15 @@ async fn process_single_item_with_stats(
     }
     
     // No directly reusable state - need to scan backwards with full balance tracking
-    let fallback_state = state.clickhouse
-        .get_latest_automation_state_for_authority(&item.authority_pubkey)
-        .await
-        .ok()
-        .flatten();
+    let fallback_state = if fresh_scan {
+        None
+    } else {
+        state.clickhouse
+            .get_latest_automation_state_for_authority(&item.authority_pubkey)
+            .await
+            .ok()
+            .flatten()
+    };
     
     let stop_at_slot = fallback_state.as_ref().map(|fb| fb.deploy_slot.saturating_sub(1));
     
@@ -1352,14 +1482,15 @@ async fn process_single_item_with_stats(
     }
     
     // Perform comprehensive backwards scan with balance tracking
-    let mut helius = state.helius.write().await;
+    let helius = &state.helius;
     let scan_result = helius
         .scan_automation_history_with_balance(
             &authority, 
             item.deploy_slot as u64, 
             stop_at_slot,
         )
-        .await;
+        .await
+        .and_then(|scan| require_complete_scan(scan, fallback_state.is_some()));
     drop(helius);
     
     let duration_ms = start.elapsed().as_millis() as u64;
@@ -1479,6 +1610,7 @@ async fn process_single_item_with_stats(
                 total_sol_spent,
             };
             
+            let cached = cache_entry(authority, &insert);
             if let Err(e) = state.clickhouse.insert_deployment_automation_state(insert).await {
                 update_queue_failed(state, item.id, &format!("ClickHouse insert failed: {}", e)).await;
                 return ProcessDetail {
@@ -1495,6 +1627,8 @@ async fn process_single_item_with_stats(
                 };
             }
             
+            state.automation_cache.write().await.insert(cached);
+            
             // Update queue as completed
             let _ = sqlx::query(r#"
                 UPDATE automation_state_queue
@@ -1558,7 +1692,7 @@ pub struct QueueRoundResponse {
 pub async fn queue_round_for_parsing(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
-) -> Result<Json<QueueRoundResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<QueueRoundResponse>, AppError> {
     // Check if already queued
     let existing: Option<(String,)> = sqlx::query_as(
         "SELECT status FROM transaction_parse_queue WHERE round_id = $1"
@@ -1566,7 +1700,7 @@ pub async fn queue_round_for_parsing(
     .bind(round_id as i64)
     .fetch_optional(&state.postgres)
     .await
-    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, Json(AuthError { error: e.to_string() })))?;
+    .map_err(|e| AppError::Internal(e.to_string()))?;
     
     if let Some((status,)) = existing {
         return Ok(Json(QueueRoundResponse {
@@ -1584,7 +1718,7 @@ pub async fn queue_round_for_parsing(
     .bind(round_id as i64)
     .execute(&state.postgres)
     .await
-    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, Json(AuthError { error: e.to_string() })))?;
+    .map_err(|e| AppError::Internal(e.to_string()))?;
     
     tracing::info!("Queued round {} for transaction parsing", round_id);
     
@@ -1608,7 +1742,7 @@ pub struct ParseQueueStats {
 
 pub async fn get_parse_queue_stats(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<ParseQueueStats>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<ParseQueueStats>, AppError> {
     let stats: (i64, i64, i64, i64) = sqlx::query_as(r#"
         SELECT 
             COUNT(*) FILTER (WHERE status = 'pending'),
@@ -1619,7 +1753,7 @@ pub async fn get_parse_queue_stats(
     "#)
     .fetch_one(&state.postgres)
     .await
-    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, Json(AuthError { error: e.to_string() })))?;
+    .map_err(|e| AppError::Internal(e.to_string()))?;
     
     Ok(Json(ParseQueueStats {
         pending: stats.0,
@@ -1647,7 +1781,7 @@ pub struct ParseQueueItem {
 pub async fn get_parse_queue_items(
     State(state): State<Arc<AppState>>,
     Query(query): Query<std::collections::HashMap<String, String>>,
-) -> Result<Json<Vec<ParseQueueItem>>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<Vec<ParseQueueItem>>, AppError> {
     let status_filter = query.get("status").map(|s| s.as_str());
     let limit = query.get("limit").and_then(|s| s.parse().ok()).unwrap_or(50);
     
@@ -1675,7 +1809,7 @@ pub async fn get_parse_queue_items(
         .bind(limit)
         .fetch_all(&state.postgres)
         .await
-    }.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, Json(AuthError { error: e.to_string() })))?;
+    }.map_err(|e| AppError::Internal(e.to_string()))?;
     
     Ok(Json(items))
 }
@@ -1908,7 +2042,7 @@ fn extract_deploy_instructions_fast(raw_json: &str, _expected_round: u64) -> Res
 pub async fn queue_from_round_transactions(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
-) -> Result<Json<AddToQueueResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<AddToQueueResponse>, AppError> {
     // Just add to the parse queue, don't process inline
     let result = queue_round_for_parsing(State(state), Path(round_id)).await?;
     let inner = result.0;
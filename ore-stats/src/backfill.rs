@@ -10,18 +10,19 @@ use std::sync::Arc;
 
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
     Json,
 };
+use evore::board::{mask_indices, mask_to_squares};
 use evore::ore_api::{self, Deploy, OreInstruction, round_pda};
 use serde::{Deserialize, Serialize};
 use solana_sdk::{bs58, pubkey::Pubkey};
 use sqlx::PgPool;
 
-use crate::admin_auth::AuthError;
+use crate::app_error::AppError;
 use crate::app_state::AppState;
-use crate::clickhouse::RoundInsert;
+use crate::clickhouse::{ClickHouseError, RoundInsert};
 use crate::external_api::get_ore_supply_rounds;
+use crate::helius_api::{compare_reconstruction, ReconstructionDiff};
 
 // ============================================================================
 // Types
@@ -74,6 +75,17 @@ pub struct ReconstructResponse {
     pub status: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct VerifyReconstructionResponse {
+    pub round_id: u64,
+    /// Automated deploys (DeployEvents with a strategy) compared
+    pub deployments_checked: u32,
+    pub deployments_matched: u32,
+    pub diffs: Vec<ReconstructionDiff>,
+    /// Authorities whose balance scan failed (not compared)
+    pub scan_errors: Vec<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct VerifyRequest {
     pub notes: Option<String>,
@@ -220,9 +232,22 @@ pub struct MissingRoundsResponse {
     pub max_stored_round: u64,
 }
 
-#[derive(Debug, Serialize)]
-pub struct ErrorResponse {
-    pub error: String,
+/// Map a ClickHouse failure to a handler error, using 504 when the query hit its time limit
+fn clickhouse_error_response(e: ClickHouseError) -> AppError {
+    if e.is_timeout() {
+        tracing::warn!("Admin round query timed out");
+        AppError::Timeout(e.to_string())
+    } else {
+        AppError::Internal(format!("ClickHouse error: {}", e))
+    }
+}
+
+/// Stats counts are best-effort (0 on error), but a timeout should still surface as 504
+fn count_or_zero(result: Result<u64, ClickHouseError>) -> Result<u64, AppError> {
+    match result {
+        Err(e) if e.is_timeout() => Err(clickhouse_error_response(e)),
+        other => Ok(other.unwrap_or(0)),
+    }
 }
 
 // ============================================================================
@@ -243,7 +268,7 @@ pub struct BackfillStartResponse {
 pub async fn backfill_rounds(
     State(state): State<Arc<AppState>>,
     Query(params): Query<BackfillRoundsQuery>,
-) -> Result<Json<BackfillStartResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<BackfillStartResponse>, AppError> {
     use crate::app_state::{BackfillTaskStatus, BackfillRoundsTaskState};
     
     let stop_at_round = params.stop_at_round.unwrap_or(0);
@@ -253,12 +278,7 @@ pub async fn backfill_rounds(
     {
         let task_state = state.backfill_rounds_task_state.read().await;
         if task_state.status == BackfillTaskStatus::Running {
-            return Err((
-                StatusCode::CONFLICT,
-                Json(ErrorResponse { 
-                    error: "Backfill task is already running. Use GET /admin/backfill/rounds/status to check progress or POST /admin/backfill/rounds/cancel to stop it.".to_string() 
-                }),
-            ));
+            return Err(AppError::Conflict("Backfill task is already running. Use GET /admin/backfill/rounds/status to check progress or POST /admin/backfill/rounds/cancel to stop it.".to_string()));
         }
     }
     
@@ -548,19 +568,14 @@ pub async fn get_backfill_rounds_status(
 /// Cancel the running backfill task
 pub async fn cancel_backfill_rounds(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<serde_json::Value>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<serde_json::Value>, AppError> {
     use crate::app_state::BackfillTaskStatus;
     
     // Check if task is running
     {
         let task_state = state.backfill_rounds_task_state.read().await;
         if task_state.status != BackfillTaskStatus::Running {
-                return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse { 
-                    error: format!("No backfill task is running. Current status: {:?}", task_state.status)
-                }),
-            ));
+                return Err(AppError::BadRequest(format!("No backfill task is running. Current status: {:?}", task_state.status)));
         }
     }
     
@@ -581,13 +596,10 @@ pub async fn cancel_backfill_rounds(
 /// List rounds that need work (not finalized)
 pub async fn get_pending_rounds(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<PendingRoundsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<PendingRoundsResponse>, AppError> {
     let pending = get_pending_rounds_from_db(&state.postgres).await
         .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse { error: format!("Database error: {}", e) }),
-            )
+            AppError::Internal(format!("Database error: {}", e))
         })?;
     
     let total = pending.len() as u32;
@@ -600,7 +612,7 @@ pub async fn get_pending_rounds(
 pub async fn fetch_round_transactions(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
-) -> Result<Json<FetchTxnsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<FetchTxnsResponse>, AppError> {
     use crate::clickhouse::{RawTransactionV2, SignatureRow};
     use crate::txn_backfill::parse_transaction_accounts;
     
@@ -615,10 +627,7 @@ pub async fn fetch_round_transactions(
         Ok(sigs) => sigs,
         Err(e) => {
             tracing::error!("Failed to fetch signatures for round {}: {}", round_id, e);
-            return Err((
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse { error: format!("RPC error: {}", e) }),
-            ));
+            return Err(AppError::Internal(format!("RPC error: {}", e)));
         }
     };
     
@@ -647,10 +656,7 @@ pub async fn fetch_round_transactions(
     
     if let Err(e) = state.clickhouse.insert_signatures(sig_rows).await {
         tracing::error!("Failed to store signatures: {}", e);
-        return Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse { error: format!("Failed to store signatures: {}", e) }),
-        ));
+        return Err(AppError::Internal(format!("Failed to store signatures: {}", e)));
     }
     
     // Filter to signatures not already stored
@@ -763,7 +769,7 @@ pub async fn fetch_round_transactions(
 pub async fn reconstruct_round(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
-) -> Result<Json<ReconstructResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ReconstructResponse>, AppError> {
     tracing::info!("Reconstructing deployments for round {}", round_id);
     
     // Call the actual backfill function
@@ -775,13 +781,225 @@ pub async fn reconstruct_round(
             deployments_reconstructed: resp.deployments_stored,
             status: resp.status,
         })),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse { error: e }),
-        )),
+        Err(e) => Err(AppError::Internal(e)),
     }
 }
 
+/// GET /admin/verify-reconstruction/{round_id}
+/// Compare DeployEvents against the automation balance scan for a round's
+/// automated deploys, reporting every deployment where the two disagree
+pub async fn verify_reconstruction(
+    State(state): State<Arc<AppState>>,
+    Path(round_id): Path<u64>,
+) -> Result<Json<VerifyReconstructionResponse>, AppError> {
+    use std::collections::HashMap;
+
+    let (round_pda, _) = round_pda(round_id);
+    let raw_transactions = state.clickhouse.get_transactions_by_account(&round_pda.to_string()).await
+        .map_err(|e| AppError::Internal(format!("Failed to get stored transactions: {}", e)))?;
+    if raw_transactions.is_empty() {
+        return Err(AppError::NotFound(format!("No transactions stored for round {}", round_id)));
+    }
+    let txs: Vec<serde_json::Value> = raw_transactions
+        .iter()
+        .filter_map(|raw_tx| serde_json::from_str(&raw_tx.raw_json).ok())
+        .collect();
+
+    let events = state.helius.parse_deploy_events_from_page(&txs)
+        .map_err(|e| AppError::Internal(format!("Failed to parse DeployEvents: {}", e)))?;
+
+    // Manual deploys carry strategy u64::MAX; only automated ones have a balance scan
+    let mut by_authority: HashMap<Pubkey, Vec<_>> = HashMap::new();
+    for event in events.into_iter().filter(|e| e.event.round_id == round_id && e.event.strategy != u64::MAX) {
+        by_authority.entry(event.event.authority).or_default().push(event);
+    }
+
+    let mut deployments_checked = 0u32;
+    let mut diffs = Vec::new();
+    let mut scan_errors = Vec::new();
+    for (authority, events) in &by_authority {
+        // One scan up to the authority's last deploy covers all its deploys this round
+        let target_slot = events.iter().map(|e| e.slot).max().unwrap_or(0);
+        let scan = state.helius
+            .scan_automation_history_with_balance(authority, target_slot, None)
+            .await
+            .and_then(|scan| crate::automation_states::require_complete_scan(scan, false));
+        let scan = match scan {
+            Ok(scan) => scan,
+            Err(e) => {
+                scan_errors.push(format!("{}: {}", authority, e));
+                continue;
+            }
+        };
+
+        for event in events {
+            deployments_checked += 1;
+            let calculated = scan.calculated_deploys.iter()
+                .find(|d| d.slot == event.slot && d.signature == event.signature);
+            if let Some(diff) = compare_reconstruction(event, calculated) {
+                diffs.push(diff);
+            }
+        }
+    }
+
+    tracing::info!(
+        "Round {} reconstruction check: {} automated deploys, {} diffs, {} scan errors",
+        round_id, deployments_checked, diffs.len(), scan_errors.len()
+    );
+
+    Ok(Json(VerifyReconstructionResponse {
+        round_id,
+        deployments_checked,
+        deployments_matched: deployments_checked - diffs.len() as u32,
+        diffs,
+        scan_errors,
+    }))
+}
+
+/// A single deploy recovered from stored transactions, ready for aggregation
+struct RoundDeploy {
+    authority: String,
+    amount_per_square: u64,
+    squares: [bool; 25],
+    slot: u64,
+}
+
+/// Deploys recovered for a round plus how they were obtained
+struct RoundDeploys {
+    deploys: Vec<RoundDeploy>,
+    /// Number of Deploy instructions found in the transactions
+    instructions_found: usize,
+}
+
+/// Collect a round's deploys, preferring DeployEvents over instruction data.
+///
+/// When every Deploy instruction has a matching DeployEvent the round is
+/// reconstructed purely from events (which carry the effective amount/mask,
+/// including for automated deploys). Otherwise covered deploys still come from
+/// events and only the `deploys_without_events` subset falls back to the
+/// instruction scan, using stored automation states where available.
+async fn collect_round_deploys(
+    state: &AppState,
+    round_id: u64,
+    txs: &[serde_json::Value],
+) -> Result<RoundDeploys, String> {
+    use std::collections::HashSet;
+    use std::time::Instant;
+
+    let started = Instant::now();
+    let (round_pda, _) = evore::ore_api::round_pda(round_id);
+
+    let helius = &state.helius;
+    let coverage = helius.check_deploy_events_coverage(round_id, txs)
+        .map_err(|e| format!("Failed to check DeployEvent coverage: {}", e))?;
+    let events = helius.parse_deploy_events_from_page(txs)
+        .map_err(|e| format!("Failed to parse DeployEvents: {}", e))?;
+
+    let mut deploys: Vec<RoundDeploy> = events
+        .iter()
+        .filter(|e| e.event.round_id == round_id)
+        .map(|e| RoundDeploy {
+            authority: e.event.authority.to_string(),
+            amount_per_square: e.event.amount,
+            squares: mask_to_squares(e.event.mask as u32),
+            slot: e.slot,
+        })
+        .collect();
+
+    if coverage.has_all_events {
+        let events_ms = started.elapsed().as_millis();
+        drop(helius);
+
+        // Estimate what the automation-state scan would have cost for comparison
+        let avg_scan_ms = state.clickhouse.get_automation_fetch_stats().await
+            .map(|s| s.avg_duration_ms)
+            .unwrap_or(0.0);
+        tracing::info!(
+            "Round {} reconstruct: events-only path, {} deploys from {} events in {}ms (skipped {} automation-state lookups, ~{:.0}ms at {:.0}ms avg)",
+            round_id, deploys.len(), coverage.event_count, events_ms,
+            coverage.deploy_count, coverage.deploy_count as f64 * avg_scan_ms, avg_scan_ms
+        );
+
+        return Ok(RoundDeploys {
+            deploys,
+            instructions_found: coverage.deploy_count,
+        });
+    }
+
+    // Partial (or no) event coverage - fall back for deploys without events only
+    let missing: HashSet<(String, String)> = coverage.deploys_without_events
+        .iter()
+        .map(|d| (d.signature.clone(), d.authority.clone()))
+        .collect();
+    let parsed_deployments = helius.parse_deployments_from_round_page(&round_pda, txs)
+        .map_err(|e| format!("Failed to parse deployments: {}", e))?;
+    drop(helius);
+
+    let mut automation_hits = 0usize;
+    for pd in parsed_deployments {
+        let authority = pd.authority.to_string();
+        if !missing.contains(&(pd.signature.clone(), authority.clone())) {
+            continue;
+        }
+
+        // Automated deploys ignore instruction data; use the stored automation state if we have one
+        let automation = state.clickhouse.get_deployment_by_signature(&pd.signature).await
+            .ok()
+            .and_then(|rows| rows.into_iter().find(|r| r.authority_pubkey == authority && r.automation_found));
+
+        let (amount_per_square, squares) = match automation {
+            Some(a) => {
+                automation_hits += 1;
+                let mask = if a.actual_mask != 0 { a.actual_mask } else { a.automation_mask };
+                (a.automation_amount, mask_to_squares(mask as u32))
+            }
+            None => (pd.amount_per_square, pd.squares),
+        };
+
+        deploys.push(RoundDeploy {
+            authority,
+            amount_per_square,
+            squares,
+            slot: pd.slot,
+        });
+    }
+
+    tracing::info!(
+        "Round {} reconstruct: mixed path, {} events + {} deploys without events ({} from automation states) in {}ms",
+        round_id, coverage.event_count, missing.len(), automation_hits, started.elapsed().as_millis()
+    );
+
+    Ok(RoundDeploys {
+        deploys,
+        instructions_found: coverage.deploy_count,
+    })
+}
+
+/// Aggregate deploys per (miner, square) into (total_amount, earliest_slot)
+fn aggregate_miner_squares(deploys: &[RoundDeploy]) -> std::collections::HashMap<(String, u8), (u64, u64)> {
+    let mut miner_squares = std::collections::HashMap::new();
+
+    for d in deploys {
+        for (square_idx, is_deployed) in d.squares.iter().enumerate() {
+            if *is_deployed {
+                let key = (d.authority.clone(), square_idx as u8);
+
+                miner_squares.entry(key)
+                    .and_modify(|(amt, slot): &mut (u64, u64)| {
+                        *amt += d.amount_per_square;
+                        // Keep earliest slot
+                        if d.slot < *slot {
+                            *slot = d.slot;
+                        }
+                    })
+                    .or_insert((d.amount_per_square, d.slot));
+            }
+        }
+    }
+
+    miner_squares
+}
+
 /// Backfill deployments for a single round
 /// 
 /// Flow:
@@ -793,7 +1011,6 @@ pub async fn backfill_round_deployments(
     round_id: u64,
 ) -> Result<BackfillDeploymentsResponse, String> {
     use crate::clickhouse::DeploymentInsert;
-    use std::collections::HashMap;
     
     tracing::info!("Starting deployment backfill for round {}", round_id);
     
@@ -871,22 +1088,15 @@ pub async fn backfill_round_deployments(
         });
     }
     
-    // Parse deployments from transactions
+    // Parse deployments from transactions (DeployEvents first, instruction scan as fallback)
     tracing::info!(
         "Round {} backfill: looking for deployments matching round PDA {}",
         round_id, round_pda
     );
     
-    let helius = state.helius.read().await;
-    let parsed_deployments = helius.parse_deployments_from_round_page(&round_pda, &all_transactions)
-        .map_err(|e| format!("Failed to parse deployments: {}", e))?;
-    
-    tracing::info!(
-        "Round {} backfill: parse_deployments_from_round_page returned {} deployments",
-        round_id, parsed_deployments.len()
-    );
+    let round_deploys = collect_round_deploys(state, round_id, &all_transactions).await?;
     
-    if parsed_deployments.is_empty() {
+    if round_deploys.deploys.is_empty() {
         return Ok(BackfillDeploymentsResponse {
             round_id,
             transactions_fetched: all_transactions.len() as u32,
@@ -899,33 +1109,12 @@ pub async fn backfill_round_deployments(
     
     tracing::info!(
         "Round {} backfill: parsed {} deployment instructions",
-        round_id, parsed_deployments.len()
+        round_id, round_deploys.instructions_found
     );
     
     // Aggregate deployments per miner per square
     // We track (total_amount, earliest_slot) per (miner, square)
-    let mut miner_squares: HashMap<(String, u8), (u64, u64)> = HashMap::new();
-    
-    for pd in &parsed_deployments {
-        let miner_pubkey = pd.authority.to_string();
-        
-        for (square_idx, is_deployed) in pd.squares.iter().enumerate() {
-            if *is_deployed {
-                let square_id = square_idx as u8;
-                let key = (miner_pubkey.clone(), square_id);
-                
-                miner_squares.entry(key)
-                    .and_modify(|(amt, slot)| {
-                        *amt += pd.amount_per_square;
-                        // Keep earliest slot
-                        if pd.slot < *slot {
-                            *slot = pd.slot;
-                        }
-                    })
-                    .or_insert((pd.amount_per_square, pd.slot));
-            }
-        }
-    }
+    let miner_squares = aggregate_miner_squares(&round_deploys.deploys);
     
     // Build deployment inserts
     let mut deployments: Vec<DeploymentInsert> = Vec::new();
@@ -986,7 +1175,7 @@ pub async fn backfill_round_deployments(
     Ok(BackfillDeploymentsResponse {
         round_id,
         transactions_fetched: all_transactions.len() as u32,
-        deployments_found: parsed_deployments.len() as u32,
+        deployments_found: round_deploys.instructions_found as u32,
         deployments_stored: 0, // Not stored to ClickHouse yet!
         status: "reconstructed_in_memory".to_string(),
         error: None,
@@ -999,7 +1188,7 @@ pub async fn backfill_round_deployments(
 pub async fn add_to_backfill_workflow(
     State(state): State<Arc<AppState>>,
     Json(req): Json<AddToBackfillRequest>,
-) -> Result<Json<AddToBackfillResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<AddToBackfillResponse>, AppError> {
     tracing::info!("Adding {} rounds to backfill workflow", req.round_ids.len());
     
     let mut added = 0u32;
@@ -1044,21 +1233,15 @@ pub async fn add_to_backfill_workflow(
 pub async fn get_round_for_verification(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
-) -> Result<Json<RoundStatus>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<RoundStatus>, AppError> {
     let status = get_round_status(&state.postgres, round_id as i64).await
         .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse { error: format!("Database error: {}", e) }),
-            )
+            AppError::Internal(format!("Database error: {}", e))
         })?;
     
     match status {
         Some(s) => Ok(Json(s)),
-        None => Err((
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse { error: format!("Round {} not found", round_id) }),
-        )),
+        None => Err(AppError::NotFound(format!("Round {} not found", round_id))),
     }
 }
 
@@ -1068,7 +1251,7 @@ pub async fn verify_round(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
     Json(req): Json<VerifyRequest>,
-) -> Result<Json<VerifyResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<VerifyResponse>, AppError> {
     let notes = req.notes.unwrap_or_default();
     
     update_round_status_verified(&state.postgres, round_id, &notes).await;
@@ -1085,7 +1268,7 @@ pub async fn verify_round(
 pub async fn finalize_backfill_round(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
-) -> Result<Json<FinalizeResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<FinalizeResponse>, AppError> {
     use crate::clickhouse::DeploymentInsert;
     
     tracing::info!("Finalizing backfill round {}", round_id);
@@ -1097,15 +1280,11 @@ pub async fn finalize_backfill_round(
     };
     
     let reconstructed = reconstructed.ok_or_else(|| {
-        (StatusCode::BAD_REQUEST, Json(ErrorResponse { 
-            error: format!("Round {} not found in memory. Run reconstruct first.", round_id) 
-        }))
+        AppError::BadRequest(format!("Round {} not found in memory. Run reconstruct first.", round_id))
     })?;
     
     if reconstructed.deployments.is_empty() {
-        return Err((StatusCode::BAD_REQUEST, Json(ErrorResponse { 
-            error: "No deployments in reconstructed data".to_string() 
-        })));
+        return Err(AppError::BadRequest("No deployments in reconstructed data".to_string()));
     }
     
     // Build deployment inserts from in-memory data
@@ -1133,9 +1312,7 @@ pub async fn finalize_backfill_round(
     
     // Store to ClickHouse
     state.clickhouse.insert_deployments(deployments).await
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { 
-            error: format!("Failed to insert deployments: {}", e) 
-        })))?;
+        .map_err(|e| AppError::Internal(format!("Failed to insert deployments: {}", e)))?;
     
     // Update status to finalized
     update_round_status_finalized(&state.postgres, round_id).await;
@@ -1163,7 +1340,7 @@ pub struct ResetTxnsResponse {
 pub async fn reset_txns_status(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
-) -> Result<Json<ResetTxnsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ResetTxnsResponse>, AppError> {
     tracing::info!("Resetting transaction status for round {}", round_id);
     
     // Delete transactions from v2 tables by querying round PDA
@@ -1226,7 +1403,7 @@ pub async fn delete_round_data(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
     Query(params): Query<DeleteQuery>,
-) -> Result<Json<DeleteResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<DeleteResponse>, AppError> {
     let delete_round = params.delete_round.unwrap_or(false);
     let delete_deployments = params.delete_deployments.unwrap_or(true);
     
@@ -1281,7 +1458,7 @@ pub async fn delete_round_data(
 pub async fn bulk_delete_rounds(
     State(state): State<Arc<AppState>>,
     Json(req): Json<BulkDeleteRequest>,
-) -> Result<Json<BulkDeleteResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<BulkDeleteResponse>, AppError> {
     let mut deleted_count = 0u32;
     let mut failed_count = 0u32;
     
@@ -1332,7 +1509,7 @@ pub async fn bulk_delete_rounds(
 pub async fn get_round_data_status(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
-) -> Result<Json<RoundDataStatus>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<RoundDataStatus>, AppError> {
     let round_exists = state.clickhouse.round_exists(round_id).await.unwrap_or(false);
     let (deployment_count, deployments_sum) = state.clickhouse
         .get_deployment_stats_for_round(round_id)
@@ -1374,7 +1551,7 @@ pub async fn get_round_data_status(
 pub async fn get_rounds_with_data(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RoundsWithDataQuery>,
-) -> Result<Json<RoundsWithDataResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<RoundsWithDataResponse>, AppError> {
     let limit = params.limit.unwrap_or(50).min(200);
     let filter_mode = params.filter_mode.as_deref().unwrap_or("all");
     
@@ -1402,12 +1579,7 @@ pub async fn get_rounds_with_data(
             let (rounds, has_more) = state.clickhouse
                 .get_rounds_with_missing_deployments(round_id_gte, round_id_lte, before_round_id, offset, limit)
                 .await
-                .map_err(|e| {
-                    (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(ErrorResponse { error: format!("ClickHouse error: {}", e) }),
-                    )
-                })?;
+                .map_err(clickhouse_error_response)?;
             
             let enriched: Vec<RoundWithData> = rounds.into_iter().map(|r| {
                 RoundWithData {
@@ -1435,12 +1607,7 @@ pub async fn get_rounds_with_data(
             let (rounds, has_more) = state.clickhouse
                 .get_rounds_with_invalid_deployments(round_id_gte, round_id_lte, before_round_id, offset, limit)
                 .await
-                .map_err(|e| {
-                    (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(ErrorResponse { error: format!("ClickHouse error: {}", e) }),
-                    )
-                })?;
+                .map_err(clickhouse_error_response)?;
             
             let enriched: Vec<RoundWithData> = rounds.into_iter().map(|r| {
                 let discrepancy = r.total_deployed as i64 - r.deployments_sum as i64;
@@ -1470,12 +1637,7 @@ pub async fn get_rounds_with_data(
             let (rounds, has_more) = state.clickhouse
                 .get_rounds_filtered_for_admin(round_id_gte, round_id_lte, before_round_id, offset, limit)
                 .await
-                .map_err(|e| {
-                    (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(ErrorResponse { error: format!("ClickHouse error: {}", e) }),
-                    )
-                })?;
+                .map_err(clickhouse_error_response)?;
             
             // Enrich with deployment counts and validation
             let mut enriched = Vec::new();
@@ -1534,7 +1696,7 @@ pub async fn get_rounds_with_data(
 pub async fn get_missing_rounds(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RoundsWithDataQuery>,
-) -> Result<Json<MissingRoundsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<MissingRoundsResponse>, AppError> {
     let limit = params.limit.unwrap_or(50).min(200);
     
     // Round ID filters
@@ -1552,12 +1714,7 @@ pub async fn get_missing_rounds(
     let (missing_ids, has_more, min_stored, max_stored) = state.clickhouse
         .get_missing_round_ids(round_id_gte, round_id_lte, offset, limit)
         .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse { error: format!("ClickHouse error: {}", e) }),
-            )
-        })?;
+        .map_err(clickhouse_error_response)?;
     
     let total = missing_ids.len() as u32;
     
@@ -1586,7 +1743,7 @@ pub struct RoundStatsResponse {
 pub async fn get_round_stats(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RoundsWithDataQuery>,
-) -> Result<Json<RoundStatsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<RoundStatsResponse>, AppError> {
     let round_id_gte = params.round_id_gte;
     let round_id_lte = params.round_id_lte;
     
@@ -1596,20 +1753,17 @@ pub async fn get_round_stats(
         .await
         .unwrap_or(0);
     
-    let missing_deployments_count = state.clickhouse
-        .get_rounds_with_missing_deployments_count(round_id_gte, round_id_lte)
-        .await
-        .unwrap_or(0);
+    let missing_deployments_count = count_or_zero(
+        state.clickhouse.get_rounds_with_missing_deployments_count(round_id_gte, round_id_lte).await
+    )?;
     
-    let invalid_deployments_count = state.clickhouse
-        .get_rounds_with_invalid_deployments_count(round_id_gte, round_id_lte)
-        .await
-        .unwrap_or(0);
+    let invalid_deployments_count = count_or_zero(
+        state.clickhouse.get_rounds_with_invalid_deployments_count(round_id_gte, round_id_lte).await
+    )?;
     
-    let missing_rounds_count = state.clickhouse
-        .get_missing_round_ids_count(round_id_gte, round_id_lte)
-        .await
-        .unwrap_or(0);
+    let missing_rounds_count = count_or_zero(
+        state.clickhouse.get_missing_round_ids_count(round_id_gte, round_id_lte).await
+    )?;
     
     // Get min/max round IDs
     let (min_stored, max_stored) = state.clickhouse
@@ -1889,7 +2043,7 @@ pub async fn get_round_transactions(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
     Query(query): Query<TransactionViewerQuery>,
-) -> Result<Json<TransactionViewerResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<TransactionViewerResponse>, AppError> {
     let limit = query.limit.unwrap_or(100);
     let offset = query.offset.unwrap_or(0);
     
@@ -1903,12 +2057,7 @@ pub async fn get_round_transactions(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get raw transactions: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { 
-                    error: format!("ClickHouse error: {}", e) 
-                }),
-            )
+            AppError::Internal(format!("ClickHouse error: {}", e))
         })?;
     
     let total_transactions = raw_txns.len();
@@ -2190,12 +2339,7 @@ fn analyze_instruction(
             let amount = u64::from_le_bytes(deploy.amount);
             let mask = u32::from_le_bytes(deploy.squares);
             
-            let mut squares = Vec::new();
-            for i in 0..25u8 {
-                if (mask & (1 << i)) != 0 {
-                    squares.push(i);
-                }
-            }
+            let squares: Vec<u8> = mask_indices(mask).map(|i| i as u8).collect();
             
             let matches = round_pda.map(|r| r == *expected_round_pda).unwrap_or(false);
             
@@ -2239,7 +2383,7 @@ fn analyze_instruction(
 pub async fn get_round_transactions_raw(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
-) -> Result<Json<Vec<crate::clickhouse::RawTransactionV2>>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<Vec<crate::clickhouse::RawTransactionV2>>, AppError> {
     let round_pda = evore::ore_api::round_pda(round_id).0.to_string();
     
     let raw_txns = state.clickhouse
@@ -2247,12 +2391,7 @@ pub async fn get_round_transactions_raw(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get raw transactions: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { 
-                    error: format!("ClickHouse error: {}", e) 
-                }),
-            )
+            AppError::Internal(format!("ClickHouse error: {}", e))
         })?;
     
     Ok(Json(raw_txns))
@@ -2346,7 +2485,7 @@ pub async fn get_round_transactions_full(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
     Query(query): Query<TransactionViewerQuery>,
-) -> Result<Json<FullAnalysisResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<FullAnalysisResponse>, AppError> {
     let request_start = std::time::Instant::now();
     let limit = query.limit.unwrap_or(500).min(1000);
     let offset = query.offset.unwrap_or(0);
@@ -2367,7 +2506,7 @@ pub async fn get_round_transactions_full(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get raw transactions: {}", e);
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(AuthError { error: e.to_string() }))
+            AppError::Internal(e.to_string())
         })?;
     
     let total_transactions = raw_txns.len();
@@ -2648,17 +2787,14 @@ fn build_round_summary(analyses: &[crate::tx_analyzer::FullTransactionAnalysis])
 pub async fn get_single_transaction(
     State(state): State<Arc<AppState>>,
     Path(signature): Path<String>,
-) -> Result<Json<crate::tx_analyzer::FullTransactionAnalysis>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<crate::tx_analyzer::FullTransactionAnalysis>, AppError> {
     // Try to find the transaction in our stored data
     let raw_tx = state.clickhouse
         .get_raw_transaction_by_signature(&signature)
         .await
         .map_err(|e| {
             tracing::error!("Failed to get transaction: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: format!("ClickHouse error: {}", e) }),
-            )
+            AppError::Internal(format!("ClickHouse error: {}", e))
         })?;
     
     match raw_tx {
@@ -2666,17 +2802,11 @@ pub async fn get_single_transaction(
             let analyzer = crate::tx_analyzer::TransactionAnalyzer::new();
             match analyzer.analyze(&tx.raw_json) {
                 Ok(analysis) => Ok(Json(analysis)),
-                Err(e) => Err((
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(AuthError { error: format!("Analysis failed: {}", e) }),
-                )),
+                Err(e) => Err(AppError::Internal(format!("Analysis failed: {}", e))),
             }
         }
         None => {
-            Err((
-                StatusCode::NOT_FOUND,
-                Json(AuthError { error: "Transaction not found in storage".to_string() }),
-            ))
+            Err(AppError::NotFound("Transaction not found in storage".to_string()))
         }
     }
 }
@@ -2704,7 +2834,7 @@ pub struct RoundsWithTransactionsQuery {
 pub async fn get_rounds_with_transactions(
     State(state): State<Arc<AppState>>,
     Query(query): Query<RoundsWithTransactionsQuery>,
-) -> Result<Json<RoundsWithTransactionsResponse>, (StatusCode, Json<AuthError>)> {
+) -> Result<Json<RoundsWithTransactionsResponse>, AppError> {
     let page = query.page.unwrap_or(1);
     let limit = query.limit.unwrap_or(50).min(200);
     let offset = (page.saturating_sub(1)) * limit;
@@ -2714,10 +2844,7 @@ pub async fn get_rounds_with_transactions(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get rounds with transactions: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: format!("ClickHouse error: {}", e) }),
-            )
+            AppError::Internal(format!("ClickHouse error: {}", e))
         })?;
     
     let total = state.clickhouse
@@ -2725,10 +2852,7 @@ pub async fn get_rounds_with_transactions(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get count: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(AuthError { error: format!("ClickHouse error: {}", e) }),
-            )
+            AppError::Internal(format!("ClickHouse error: {}", e))
         })?;
     
     Ok(Json(RoundsWithTransactionsResponse {
@@ -3128,7 +3252,6 @@ async fn execute_fetch_txns(state: &Arc<AppState>, round_id: u64) -> Result<(),
 /// Deployments are stored to ClickHouse during finalize step after verification
 async fn execute_reconstruct(state: &Arc<AppState>, round_id: u64) -> Result<(), String> {
     use crate::app_state::{BackfillReconstructedRound, BackfillDeployment};
-    use std::collections::HashMap;
     
     // Get stored transactions from ClickHouse (v2 tables - query by round PDA)
     let round_pda = evore::ore_api::round_pda(round_id).0.to_string();
@@ -3156,36 +3279,11 @@ async fn execute_reconstruct(state: &Arc<AppState>, round_id: u64) -> Result<(),
         return Err("Failed to parse any stored transactions".to_string());
     }
     
-    // Derive the round PDA
-    let (round_pda, _) = evore::ore_api::round_pda(round_id);
-    
-    // Parse deployments
-    let helius = state.helius.read().await;
-    let parsed_deployments = helius.parse_deployments_from_round_page(&round_pda, &all_transactions)
-        .map_err(|e| format!("Failed to parse deployments: {}", e))?;
+    // Parse deployments (DeployEvents first, instruction scan as fallback)
+    let round_deploys = collect_round_deploys(state, round_id, &all_transactions).await?;
     
     // Aggregate deployments per miner per square
-    let mut miner_squares: HashMap<(String, u8), (u64, u64)> = HashMap::new();
-    
-    for pd in &parsed_deployments {
-        let miner_pubkey = pd.authority.to_string();
-        
-        for (square_idx, is_deployed) in pd.squares.iter().enumerate() {
-            if *is_deployed {
-                let square_id = square_idx as u8;
-                let key = (miner_pubkey.clone(), square_id);
-                
-                miner_squares.entry(key)
-                    .and_modify(|(amt, slot)| {
-                        *amt += pd.amount_per_square;
-                        if pd.slot < *slot {
-                            *slot = pd.slot;
-                        }
-                    })
-                    .or_insert((pd.amount_per_square, pd.slot));
-            }
-        }
-    }
+    let miner_squares = aggregate_miner_squares(&round_deploys.deploys);
     
     // Build reconstructed deployments
     let deployments: Vec<BackfillDeployment> = miner_squares
@@ -0,0 +1,200 @@
+//! SOL Balance Cache
+//!
+//! Short-TTL cache in front of `HeliusApi::get_multiple_account_balances` for
+//! endpoints that poll the same accounts repeatedly (e.g. EVORE deployer auths).
+//! - Fresh entries (younger than the TTL) are served from memory
+//! - Stale entries (up to `STALE_FACTOR` x TTL) are served immediately and
+//!   refreshed in the background
+//! - Missing entries are fetched in batches behind a single-flight guard, so
+//!   concurrent requests for overlapping addresses share one RPC call
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use steel::Pubkey;
+use tokio::sync::{Mutex as AsyncMutex, RwLock};
+
+use crate::helius_api::{HeliusApi, HeliusError};
+
+/// Default time a fetched balance is considered fresh
+pub const DEFAULT_BALANCE_TTL: Duration = Duration::from_secs(5);
+
+/// Stale entries older than TTL x this are refetched before returning
+const STALE_FACTOR: u32 = 6;
+
+/// Max addresses per getMultipleAccounts call
+const MAX_BATCH: usize = 100;
+
+/// Entries kept before expired ones are pruned
+const MAX_ENTRIES: usize = 50_000;
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    /// None if the account doesn't exist
+    lamports: Option<u64>,
+    fetched_at: Instant,
+}
+
+/// How a requested address should be served
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Lookup {
+    /// Not cached (or too old) - fetch before responding
+    missing: Vec<Pubkey>,
+    /// Served from cache, refreshed in the background
+    stale: Vec<Pubkey>,
+}
+
+fn classify(
+    entries: &HashMap<Pubkey, Entry>,
+    addresses: &[Pubkey],
+    now: Instant,
+    ttl: Duration,
+) -> Lookup {
+    let mut lookup = Lookup::default();
+    let mut seen = HashSet::new();
+    for address in addresses {
+        if !seen.insert(*address) {
+            continue;
+        }
+        match entries.get(address).map(|e| now.saturating_duration_since(e.fetched_at)) {
+            Some(age) if age < ttl => {}
+            Some(age) if age < ttl * STALE_FACTOR => lookup.stale.push(*address),
+            _ => lookup.missing.push(*address),
+        }
+    }
+    lookup
+}
+
+/// Cached, batched SOL balance lookups
+pub struct BalanceCache {
+    helius: Arc<HeliusApi>,
+    ttl: Duration,
+    entries: RwLock<HashMap<Pubkey, Entry>>,
+    /// Single-flight guard: one batch fetch at a time, later callers re-check the cache
+    fetch_guard: AsyncMutex<()>,
+    /// Addresses with a background refresh already queued
+    refreshing: Mutex<HashSet<Pubkey>>,
+}
+
+impl BalanceCache {
+    pub fn new(helius: Arc<HeliusApi>, ttl: Duration) -> Self {
+        Self {
+            helius,
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+            fetch_guard: AsyncMutex::new(()),
+            refreshing: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Balances for `addresses` in request order (None = account doesn't exist)
+    pub async fn get_balances(
+        self: &Arc<Self>,
+        addresses: &[Pubkey],
+    ) -> Result<Vec<(Pubkey, Option<u64>)>, HeliusError> {
+        let lookup = {
+            let entries = self.entries.read().await;
+            classify(&entries, addresses, Instant::now(), self.ttl)
+        };
+
+        if !lookup.missing.is_empty() {
+            self.fetch(&lookup.missing).await?;
+        }
+        if !lookup.stale.is_empty() {
+            self.spawn_refresh(lookup.stale);
+        }
+
+        let entries = self.entries.read().await;
+        Ok(addresses
+            .iter()
+            .map(|a| (*a, entries.get(a).and_then(|e| e.lamports)))
+            .collect())
+    }
+
+    /// Fetch addresses that are still not fresh once we hold the single-flight guard
+    async fn fetch(&self, addresses: &[Pubkey]) -> Result<(), HeliusError> {
+        let _guard = self.fetch_guard.lock().await;
+
+        // Another caller may have fetched these while we waited
+        let todo: Vec<Pubkey> = {
+            let entries = self.entries.read().await;
+            let now = Instant::now();
+            addresses
+                .iter()
+                .filter(|a| {
+                    entries
+                        .get(a)
+                        .map(|e| now.saturating_duration_since(e.fetched_at) >= self.ttl)
+                        .unwrap_or(true)
+                })
+                .copied()
+                .collect()
+        };
+
+        for chunk in todo.chunks(MAX_BATCH) {
+            let balances = self.helius.get_multiple_account_balances(chunk).await?;
+
+            let fetched_at = Instant::now();
+            let mut entries = self.entries.write().await;
+            for (address, lamports) in balances {
+                entries.insert(address, Entry { lamports, fetched_at });
+            }
+
+            if entries.len() > MAX_ENTRIES {
+                let max_age = self.ttl * STALE_FACTOR;
+                entries.retain(|_, e| fetched_at.saturating_duration_since(e.fetched_at) < max_age);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Refresh stale addresses without blocking the caller
+    fn spawn_refresh(self: &Arc<Self>, addresses: Vec<Pubkey>) {
+        let addresses: Vec<Pubkey> = {
+            let mut refreshing = self.refreshing.lock().unwrap();
+            addresses.into_iter().filter(|a| refreshing.insert(*a)).collect()
+        };
+        if addresses.is_empty() {
+            return;
+        }
+
+        let cache = Arc::clone(self);
+        tokio::spawn(async move {
+            if let Err(e) = cache.fetch(&addresses).await {
+                tracing::warn!("Background balance refresh failed for {} accounts: {}", addresses.len(), e);
+            }
+            let mut refreshing = cache.refreshing.lock().unwrap();
+            for address in &addresses {
+                refreshing.remove(address);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_by_age() {
+        let ttl = Duration::from_secs(5);
+        let now = Instant::now() + ttl * (STALE_FACTOR + 1);
+        let (fresh, stale, expired, unknown) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        let mut entries = HashMap::new();
+        entries.insert(fresh, Entry { lamports: Some(1), fetched_at: now - Duration::from_secs(1) });
+        entries.insert(stale, Entry { lamports: Some(2), fetched_at: now - ttl * 2 });
+        entries.insert(expired, Entry { lamports: None, fetched_at: now - ttl * STALE_FACTOR });
+
+        let lookup = classify(&entries, &[fresh, stale, expired, unknown, unknown], now, ttl);
+        assert_eq!(lookup.stale, vec![stale]);
+        assert_eq!(lookup.missing, vec![expired, unknown]);
+    }
+}
@@ -7,12 +7,12 @@ use std::sync::Arc;
 
 use axum::{
     extract::{Query, State},
-    http::StatusCode,
     routing::get,
     Json, Router,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::app_error::AppError;
 use crate::app_state::AppState;
 use crate::clickhouse::{
     CostPerOreDailyRow, CostPerOreDirectRow, InflationDailyRow, InflationDirectRow,
@@ -389,14 +389,14 @@ pub struct CostPerOreDirectResponse {
 pub async fn get_rounds_hourly(
     State(state): State<Arc<AppState>>,
     Query(query): Query<HourlyQuery>,
-) -> Result<Json<Vec<RoundsHourlyResponse>>, StatusCode> {
+) -> Result<Json<Vec<RoundsHourlyResponse>>, AppError> {
     let hours = query.hours.unwrap_or(24).min(720);
 
     match state.clickhouse.get_rounds_hourly(hours).await {
         Ok(rows) => Ok(Json(rows.into_iter().map(Into::into).collect())),
         Err(e) => {
             tracing::error!("Failed to get rounds hourly: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get rounds hourly".to_string()))
         }
     }
 }
@@ -406,14 +406,14 @@ pub async fn get_rounds_hourly(
 pub async fn get_rounds_daily(
     State(state): State<Arc<AppState>>,
     Query(query): Query<DailyQuery>,
-) -> Result<Json<Vec<RoundsDailyResponse>>, StatusCode> {
+) -> Result<Json<Vec<RoundsDailyResponse>>, AppError> {
     let days = query.days.unwrap_or(30).min(365);
 
     match state.clickhouse.get_rounds_daily(days).await {
         Ok(rows) => Ok(Json(rows.into_iter().map(Into::into).collect())),
         Err(e) => {
             tracing::error!("Failed to get rounds daily: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get rounds daily".to_string()))
         }
     }
 }
@@ -423,14 +423,14 @@ pub async fn get_rounds_daily(
 pub async fn get_treasury_hourly(
     State(state): State<Arc<AppState>>,
     Query(query): Query<HourlyQuery>,
-) -> Result<Json<Vec<TreasuryHourlyResponse>>, StatusCode> {
+) -> Result<Json<Vec<TreasuryHourlyResponse>>, AppError> {
     let hours = query.hours.unwrap_or(24).min(720);
 
     match state.clickhouse.get_treasury_hourly(hours).await {
         Ok(rows) => Ok(Json(rows.into_iter().map(Into::into).collect())),
         Err(e) => {
             tracing::error!("Failed to get treasury hourly: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get treasury hourly".to_string()))
         }
     }
 }
@@ -440,14 +440,14 @@ pub async fn get_treasury_hourly(
 pub async fn get_mint_hourly(
     State(state): State<Arc<AppState>>,
     Query(query): Query<HourlyQuery>,
-) -> Result<Json<Vec<MintHourlyResponse>>, StatusCode> {
+) -> Result<Json<Vec<MintHourlyResponse>>, AppError> {
     let hours = query.hours.unwrap_or(24).min(720);
 
     match state.clickhouse.get_mint_hourly(hours).await {
         Ok(rows) => Ok(Json(rows.into_iter().map(Into::into).collect())),
         Err(e) => {
             tracing::error!("Failed to get mint hourly: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get mint hourly".to_string()))
         }
     }
 }
@@ -457,14 +457,14 @@ pub async fn get_mint_hourly(
 pub async fn get_mint_daily(
     State(state): State<Arc<AppState>>,
     Query(query): Query<DailyQuery>,
-) -> Result<Json<Vec<MintDailyResponse>>, StatusCode> {
+) -> Result<Json<Vec<MintDailyResponse>>, AppError> {
     let days = query.days.unwrap_or(30).min(365);
 
     match state.clickhouse.get_mint_daily(days).await {
         Ok(rows) => Ok(Json(rows.into_iter().map(Into::into).collect())),
         Err(e) => {
             tracing::error!("Failed to get mint daily: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get mint daily".to_string()))
         }
     }
 }
@@ -474,14 +474,14 @@ pub async fn get_mint_daily(
 pub async fn get_inflation_hourly(
     State(state): State<Arc<AppState>>,
     Query(query): Query<HourlyQuery>,
-) -> Result<Json<Vec<InflationHourlyResponse>>, StatusCode> {
+) -> Result<Json<Vec<InflationHourlyResponse>>, AppError> {
     let hours = query.hours.unwrap_or(24).min(720);
 
     match state.clickhouse.get_inflation_hourly(hours).await {
         Ok(rows) => Ok(Json(rows.into_iter().map(Into::into).collect())),
         Err(e) => {
             tracing::error!("Failed to get inflation hourly: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get inflation hourly".to_string()))
         }
     }
 }
@@ -491,14 +491,14 @@ pub async fn get_inflation_hourly(
 pub async fn get_inflation_daily(
     State(state): State<Arc<AppState>>,
     Query(query): Query<DailyQuery>,
-) -> Result<Json<Vec<InflationDailyResponse>>, StatusCode> {
+) -> Result<Json<Vec<InflationDailyResponse>>, AppError> {
     let days = query.days.unwrap_or(30).min(365);
 
     match state.clickhouse.get_inflation_daily(days).await {
         Ok(rows) => Ok(Json(rows.into_iter().map(Into::into).collect())),
         Err(e) => {
             tracing::error!("Failed to get inflation daily: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get inflation daily".to_string()))
         }
     }
 }
@@ -508,14 +508,14 @@ pub async fn get_inflation_daily(
 pub async fn get_cost_per_ore_daily(
     State(state): State<Arc<AppState>>,
     Query(query): Query<DailyQuery>,
-) -> Result<Json<Vec<CostPerOreDailyResponse>>, StatusCode> {
+) -> Result<Json<Vec<CostPerOreDailyResponse>>, AppError> {
     let days = query.days.unwrap_or(30).min(365);
 
     match state.clickhouse.get_cost_per_ore_daily(days).await {
         Ok(rows) => Ok(Json(rows.into_iter().map(Into::into).collect())),
         Err(e) => {
             tracing::error!("Failed to get cost per ore daily: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get cost per ore daily".to_string()))
         }
     }
 }
@@ -525,14 +525,14 @@ pub async fn get_cost_per_ore_daily(
 pub async fn get_miners_daily(
     State(state): State<Arc<AppState>>,
     Query(query): Query<DailyQuery>,
-) -> Result<Json<Vec<MinerActivityDailyResponse>>, StatusCode> {
+) -> Result<Json<Vec<MinerActivityDailyResponse>>, AppError> {
     let days = query.days.unwrap_or(30).min(365);
 
     match state.clickhouse.get_miner_activity_daily(days).await {
         Ok(rows) => Ok(Json(rows.into_iter().map(Into::into).collect())),
         Err(e) => {
             tracing::error!("Failed to get miner activity daily: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get miner activity daily".to_string()))
         }
     }
 }
@@ -559,7 +559,7 @@ pub struct DirectResponse<T> {
 pub async fn get_rounds_direct(
     State(state): State<Arc<AppState>>,
     Query(query): Query<DirectQuery>,
-) -> Result<Json<DirectResponse<RoundDirectResponse>>, StatusCode> {
+) -> Result<Json<DirectResponse<RoundDirectResponse>>, AppError> {
     let limit = query.limit.unwrap_or(1000).min(5000);
     let end_round = query.end_round();
     
@@ -567,7 +567,7 @@ pub async fn get_rounds_direct(
     let latest = state.clickhouse.get_latest_round_id().await
         .map_err(|e| {
             tracing::error!("Failed to get latest round: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
+            AppError::Internal("Failed to get latest round".to_string())
         })?
         .unwrap_or(0);
 
@@ -588,7 +588,7 @@ pub async fn get_rounds_direct(
         },
         Err(e) => {
             tracing::error!("Failed to get rounds direct: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get rounds direct".to_string()))
         }
     }
 }
@@ -598,14 +598,14 @@ pub async fn get_rounds_direct(
 pub async fn get_treasury_direct(
     State(state): State<Arc<AppState>>,
     Query(query): Query<DirectQuery>,
-) -> Result<Json<DirectResponse<TreasuryDirectResponse>>, StatusCode> {
+) -> Result<Json<DirectResponse<TreasuryDirectResponse>>, AppError> {
     let limit = query.limit.unwrap_or(1000).min(5000);
     let end_round = query.end_round();
     
     let latest = state.clickhouse.get_latest_round_id().await
         .map_err(|e| {
             tracing::error!("Failed to get latest round: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
+            AppError::Internal("Failed to get latest round".to_string())
         })?
         .unwrap_or(0);
 
@@ -624,7 +624,7 @@ pub async fn get_treasury_direct(
         },
         Err(e) => {
             tracing::error!("Failed to get treasury direct: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get treasury direct".to_string()))
         }
     }
 }
@@ -634,14 +634,14 @@ pub async fn get_treasury_direct(
 pub async fn get_mint_direct(
     State(state): State<Arc<AppState>>,
     Query(query): Query<DirectQuery>,
-) -> Result<Json<DirectResponse<MintDirectResponse>>, StatusCode> {
+) -> Result<Json<DirectResponse<MintDirectResponse>>, AppError> {
     let limit = query.limit.unwrap_or(1000).min(5000);
     let end_round = query.end_round();
     
     let latest = state.clickhouse.get_latest_round_id().await
         .map_err(|e| {
             tracing::error!("Failed to get latest round: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
+            AppError::Internal("Failed to get latest round".to_string())
         })?
         .unwrap_or(0);
 
@@ -657,7 +657,7 @@ pub async fn get_mint_direct(
         },
         Err(e) => {
             tracing::error!("Failed to get mint direct: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get mint direct".to_string()))
         }
     }
 }
@@ -667,14 +667,14 @@ pub async fn get_mint_direct(
 pub async fn get_inflation_direct(
     State(state): State<Arc<AppState>>,
     Query(query): Query<DirectQuery>,
-) -> Result<Json<DirectResponse<InflationDirectResponse>>, StatusCode> {
+) -> Result<Json<DirectResponse<InflationDirectResponse>>, AppError> {
     let limit = query.limit.unwrap_or(1000).min(5000);
     let end_round = query.end_round();
     
     let latest = state.clickhouse.get_latest_round_id().await
         .map_err(|e| {
             tracing::error!("Failed to get latest round: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
+            AppError::Internal("Failed to get latest round".to_string())
         })?
         .unwrap_or(0);
 
@@ -693,7 +693,7 @@ pub async fn get_inflation_direct(
         },
         Err(e) => {
             tracing::error!("Failed to get inflation direct: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get inflation direct".to_string()))
         }
     }
 }
@@ -703,14 +703,14 @@ pub async fn get_inflation_direct(
 pub async fn get_cost_per_ore_direct(
     State(state): State<Arc<AppState>>,
     Query(query): Query<DirectQuery>,
-) -> Result<Json<DirectResponse<CostPerOreDirectResponse>>, StatusCode> {
+) -> Result<Json<DirectResponse<CostPerOreDirectResponse>>, AppError> {
     let limit = query.limit.unwrap_or(1000).min(5000);
     let end_round = query.end_round();
     
     let latest = state.clickhouse.get_latest_round_id().await
         .map_err(|e| {
             tracing::error!("Failed to get latest round: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
+            AppError::Internal("Failed to get latest round".to_string())
         })?
         .unwrap_or(0);
 
@@ -727,7 +727,7 @@ pub async fn get_cost_per_ore_direct(
         },
         Err(e) => {
             tracing::error!("Failed to get cost per ore direct: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(AppError::Internal("Failed to get cost per ore direct".to_string()))
         }
     }
 }
@@ -5,26 +5,175 @@
 //! - Rounds and deployments (append-only, immutable)
 //! - Treasury and miner snapshots
 //! - RPC usage metrics
+//!
+//! Table names live in `tables`. Every query and insert goes through
+//! `ClickHouseClient::query` / `insert` / `inserter`, which prepend
+//! CLICKHOUSE_TABLE_PREFIX so several instances can share one server. The
+//! migrations create unprefixed tables; prefixed copies have to be created
+//! separately.
 
+use std::borrow::Cow;
 use std::time::Duration;
 
-use clickhouse::{Client, Row, inserter::Inserter};
+use clickhouse::{Client, Row, insert::Insert, inserter::Inserter, query::Query};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum ClickHouseError {
     #[error("ClickHouse error: {0}")]
-    Client(#[from] clickhouse::error::Error),
+    Client(clickhouse::error::Error),
     
     #[error("Configuration error: {0}")]
     Config(String),
+    
+    #[error("ClickHouse query timed out (max_execution_time exceeded); narrow the range and retry")]
+    Timeout,
+}
+
+impl ClickHouseError {
+    /// True when the query was aborted for running past max_execution_time
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, ClickHouseError::Timeout)
+    }
+}
+
+impl From<clickhouse::error::Error> for ClickHouseError {
+    fn from(err: clickhouse::error::Error) -> Self {
+        match &err {
+            // Code 159 = TIMEOUT_EXCEEDED (server hit max_execution_time)
+            clickhouse::error::Error::BadResponse(msg)
+                if msg.contains("Code: 159") || msg.contains("TIMEOUT_EXCEEDED") => ClickHouseError::Timeout,
+            clickhouse::error::Error::TimedOut => ClickHouseError::Timeout,
+            _ => ClickHouseError::Client(err),
+        }
+    }
+}
+
+/// Default max_execution_time for every query (seconds)
+pub const DEFAULT_QUERY_TIMEOUT_SECS: u64 = 30;
+
+/// Default max_execution_time for admin range scans (seconds)
+pub const DEFAULT_ADMIN_QUERY_TIMEOUT_SECS: u64 = 120;
+
+/// Unprefixed names of every ore-stats ClickHouse table
+pub mod tables {
+    pub const AUTOMATION_STATES: &str = "automation_states";
+    pub const COST_PER_ORE_DAILY: &str = "cost_per_ore_daily";
+    pub const DEPLOYMENT_AUTOMATION_STATES: &str = "deployment_automation_states";
+    pub const DEPLOYMENTS: &str = "deployments";
+    pub const INFLATION_DAILY: &str = "inflation_daily";
+    pub const INFLATION_HOURLY: &str = "inflation_hourly";
+    pub const INFLATION_PER_ROUND: &str = "inflation_per_round";
+    pub const IP_ACTIVITY_HOURLY: &str = "ip_activity_hourly";
+    pub const MINER_ACTIVITY_DAILY: &str = "miner_activity_daily";
+    pub const MINER_SNAPSHOTS: &str = "miner_snapshots";
+    pub const MINT_DAILY: &str = "mint_daily";
+    pub const MINT_HOURLY: &str = "mint_hourly";
+    pub const MINT_SNAPSHOTS: &str = "mint_snapshots";
+    pub const PARTIAL_ROUNDS: &str = "partial_rounds";
+    pub const RATE_LIMIT_EVENTS: &str = "rate_limit_events";
+    pub const RAW_TRANSACTIONS: &str = "raw_transactions";
+    pub const RAW_TRANSACTIONS_V2: &str = "raw_transactions_v2";
+    pub const REQUEST_LOGS: &str = "request_logs";
+    pub const ROUND_ADDRESSES: &str = "round_addresses";
+    pub const ROUND_ENTROPY: &str = "round_entropy";
+    pub const ROUND_TRANSACTION_STATS: &str = "round_transaction_stats";
+    pub const ROUNDS: &str = "rounds";
+    pub const ROUNDS_DAILY: &str = "rounds_daily";
+    pub const ROUNDS_HOURLY: &str = "rounds_hourly";
+    pub const RPC_METRICS_DAILY: &str = "rpc_metrics_daily";
+    pub const RPC_METRICS_MINUTE: &str = "rpc_metrics_minute";
+    pub const RPC_REQUESTS: &str = "rpc_requests";
+    pub const SERVER_METRICS: &str = "server_metrics";
+    pub const SIGNATURES: &str = "signatures";
+    pub const TREASURY_HOURLY: &str = "treasury_hourly";
+    pub const TREASURY_SNAPSHOTS: &str = "treasury_snapshots";
+    pub const WS_EVENTS: &str = "ws_events";
+    pub const WS_THROUGHPUT: &str = "ws_throughput";
+
+    /// Every table above, for prefixing table names in query text
+    pub const ALL: &[&str] = &[
+        AUTOMATION_STATES,
+        COST_PER_ORE_DAILY,
+        DEPLOYMENT_AUTOMATION_STATES,
+        DEPLOYMENTS,
+        INFLATION_DAILY,
+        INFLATION_HOURLY,
+        INFLATION_PER_ROUND,
+        IP_ACTIVITY_HOURLY,
+        MINER_ACTIVITY_DAILY,
+        MINER_SNAPSHOTS,
+        MINT_DAILY,
+        MINT_HOURLY,
+        MINT_SNAPSHOTS,
+        PARTIAL_ROUNDS,
+        RATE_LIMIT_EVENTS,
+        RAW_TRANSACTIONS,
+        RAW_TRANSACTIONS_V2,
+        REQUEST_LOGS,
+        ROUND_ADDRESSES,
+        ROUND_ENTROPY,
+        ROUND_TRANSACTION_STATS,
+        ROUNDS,
+        ROUNDS_DAILY,
+        ROUNDS_HOURLY,
+        RPC_METRICS_DAILY,
+        RPC_METRICS_MINUTE,
+        RPC_REQUESTS,
+        SERVER_METRICS,
+        SIGNATURES,
+        TREASURY_HOURLY,
+        TREASURY_SNAPSHOTS,
+        WS_EVENTS,
+        WS_THROUGHPUT,
+    ];
+}
+
+/// Prepend `prefix` to each `tables::ALL` name that follows FROM, JOIN, INTO
+/// or TABLE in `sql` (keeping any `database.` qualifier)
+fn prefix_tables<'a>(prefix: &str, sql: &'a str) -> Cow<'a, str> {
+    if prefix.is_empty() {
+        return Cow::Borrowed(sql);
+    }
+    
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '.';
+    let mut out = String::with_capacity(sql.len() + 64);
+    let mut after_keyword = false;
+    let mut rest = sql;
+    while let Some(start) = rest.find(is_ident) {
+        let (between, tail) = rest.split_at(start);
+        let len = tail.find(|c: char| !is_ident(c)).unwrap_or(tail.len());
+        let token = &tail[..len];
+        out.push_str(between);
+        
+        let (qualifier, name) = match token.rfind('.') {
+            Some(dot) => token.split_at(dot + 1),
+            None => ("", token),
+        };
+        if after_keyword && between.trim().is_empty() && tables::ALL.contains(&name) {
+            out.push_str(qualifier);
+            out.push_str(prefix);
+            out.push_str(name);
+        } else {
+            out.push_str(token);
+        }
+        
+        after_keyword = ["FROM", "JOIN", "INTO", "TABLE"].iter().any(|k| token.eq_ignore_ascii_case(k));
+        rest = &tail[len..];
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
 }
 
 /// ClickHouse client wrapper with connection pooling and batched inserts.
 #[derive(Clone)]
 pub struct ClickHouseClient {
     pub client: Client,
+    /// max_execution_time applied to admin range scans (see `admin_query`)
+    admin_query_timeout: Duration,
+    /// Prepended to every table name (CLICKHOUSE_TABLE_PREFIX, empty by default)
+    table_prefix: String,
 }
 
 /// Stats returned from v2 transaction queries
@@ -48,13 +197,75 @@ impl ClickHouseClient {
             .with_url(url)
             .with_database(database)
             .with_user(user)
-            .with_password(password);
+            .with_password(password)
+            // Dropping a request future closes its HTTP connection; have the
+            // server cancel the SELECT instead of finishing it for nobody
+            .with_option("cancel_http_readonly_queries_on_client_close", "1");
+        
+        let client = client.with_option("max_execution_time", DEFAULT_QUERY_TIMEOUT_SECS.to_string());
         
-        Self { client }
+        Self {
+            client,
+            admin_query_timeout: Duration::from_secs(DEFAULT_ADMIN_QUERY_TIMEOUT_SECS),
+            table_prefix: String::new(),
+        }
+    }
+    
+    /// Prefix every table name, e.g. "staging_" reads and writes `staging_rounds`
+    pub fn with_table_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.table_prefix = prefix.into();
+        self
+    }
+    
+    /// Name of `table` with the configured prefix
+    pub fn table(&self, table: &str) -> String {
+        format!("{}{}", self.table_prefix, table)
+    }
+    
+    /// Build a query with table names prefixed
+    pub(crate) fn query(&self, sql: &str) -> Query {
+        self.client.query(&prefix_tables(&self.table_prefix, sql))
+    }
+    
+    /// Check the server answers a trivial query
+    pub async fn ping(&self) -> Result<(), ClickHouseError> {
+        self.client.query("SELECT 1").fetch_one::<u8>().await?;
+        Ok(())
+    }
+    
+    /// Start an insert into the prefixed `table`
+    pub(crate) fn insert<T: Row>(&self, table: &str) -> Result<Insert<T>, ClickHouseError> {
+        Ok(self.client.insert(&self.table(table))?)
+    }
+    
+    /// Create a batching inserter for the prefixed `table`
+    pub(crate) fn inserter<T: Row>(&self, table: &str) -> Result<Inserter<T>, ClickHouseError> {
+        Ok(self.client.inserter(&self.table(table))?)
+    }
+    
+    /// Set server-side execution limits.
+    /// 
+    /// `default` applies to every query on this client, `admin` to the long
+    /// range scans used by the admin round tools. A query that runs past its
+    /// limit fails with `ClickHouseError::Timeout`.
+    pub fn with_query_timeouts(mut self, default: Duration, admin: Duration) -> Self {
+        self.client = self.client
+            .with_option("max_execution_time", default.as_secs().max(1).to_string());
+        self.admin_query_timeout = admin;
+        self
+    }
+    
+    /// Build a query bounded by the admin timeout instead of the default.
+    /// Used for numbers()-range and full-join scans whose cost grows with the round range.
+    fn admin_query(&self, sql: &str) -> Query {
+        self.query(sql)
+            .with_option("max_execution_time", self.admin_query_timeout.as_secs().max(1).to_string())
     }
     
     /// Create from environment variables.
     /// Expects: CLICKHOUSE_URL, CLICKHOUSE_DB, CLICKHOUSE_USER, CLICKHOUSE_PASSWORD
+    /// Optional: CLICKHOUSE_QUERY_TIMEOUT_SECS, CLICKHOUSE_ADMIN_QUERY_TIMEOUT_SECS,
+    /// CLICKHOUSE_TABLE_PREFIX
     pub fn from_env() -> Result<Self, ClickHouseError> {
         let url = std::env::var("CLICKHOUSE_URL")
             .map_err(|_| ClickHouseError::Config("CLICKHOUSE_URL not set".into()))?;
@@ -65,7 +276,14 @@ impl ClickHouseClient {
         let password = std::env::var("CLICKHOUSE_PASSWORD")
             .unwrap_or_default();
         
-        Ok(Self::new(&url, &database, &user, &password))
+        let timeout_secs = |var: &str, default: u64| {
+            std::env::var(var).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+        };
+        
+        Ok(Self::new(&url, &database, &user, &password).with_query_timeouts(
+            Duration::from_secs(timeout_secs("CLICKHOUSE_QUERY_TIMEOUT_SECS", DEFAULT_QUERY_TIMEOUT_SECS)),
+            Duration::from_secs(timeout_secs("CLICKHOUSE_ADMIN_QUERY_TIMEOUT_SECS", DEFAULT_ADMIN_QUERY_TIMEOUT_SECS)),
+        ).with_table_prefix(std::env::var("CLICKHOUSE_TABLE_PREFIX").unwrap_or_default()))
     }
     
     /// Get the underlying client for custom queries.
@@ -78,8 +296,7 @@ impl ClickHouseClient {
     /// Create an inserter for request logs.
     /// Flushes every 1000 rows or 1 second, whichever comes first.
     pub fn request_logs_inserter(&self) -> Result<Inserter<RequestLog>, ClickHouseError> {
-        let inserter = self.client
-            .inserter::<RequestLog>("request_logs")?
+        let inserter = self.inserter::<RequestLog>(tables::REQUEST_LOGS)?
             .with_max_rows(1000)
             .with_period(Some(Duration::from_secs(1)));
         Ok(inserter)
@@ -87,7 +304,7 @@ impl ClickHouseClient {
     
     /// Insert a single request log (for immediate writes, prefer inserter for batching).
     pub async fn insert_request_log(&self, log: RequestLog) -> Result<(), ClickHouseError> {
-        let mut insert = self.client.insert("request_logs")?;
+        let mut insert = self.insert(tables::REQUEST_LOGS)?;
         insert.write(&log).await?;
         insert.end().await?;
         Ok(())
@@ -97,7 +314,7 @@ impl ClickHouseClient {
     
     /// Insert server metrics snapshot.
     pub async fn insert_server_metrics(&self, metrics: ServerMetrics) -> Result<(), ClickHouseError> {
-        let mut insert = self.client.insert("server_metrics")?;
+        let mut insert = self.insert(tables::SERVER_METRICS)?;
         insert.write(&metrics).await?;
         insert.end().await?;
         Ok(())
@@ -107,7 +324,7 @@ impl ClickHouseClient {
     
     /// Insert a finalized round (from live tracker or backfill).
     pub async fn insert_round(&self, round: RoundInsert) -> Result<(), ClickHouseError> {
-        let mut insert = self.client.insert("rounds")?;
+        let mut insert = self.insert(tables::ROUNDS)?;
         insert.write(&round).await?;
         insert.end().await?;
         Ok(())
@@ -118,7 +335,7 @@ impl ClickHouseClient {
         if rounds.is_empty() {
             return Ok(());
         }
-        let mut insert = self.client.insert("rounds")?;
+        let mut insert = self.insert(tables::ROUNDS)?;
         for r in rounds {
             insert.write(&r).await?;
         }
@@ -128,8 +345,7 @@ impl ClickHouseClient {
     
     /// Check if a round exists (for skipping duplicates during backfill).
     pub async fn round_exists(&self, round_id: u64) -> Result<bool, ClickHouseError> {
-        let count: u64 = self.client
-            .query("SELECT count() FROM rounds WHERE round_id = ?")
+        let count: u64 = self.query("SELECT count() FROM rounds WHERE round_id = ?")
             .bind(round_id)
             .fetch_one()
             .await?;
@@ -138,16 +354,14 @@ impl ClickHouseClient {
     
     /// Get the highest round_id stored in the rounds table.
     pub async fn get_highest_round_id(&self) -> Result<Option<u64>, ClickHouseError> {
-        let result: Option<u64> = self.client
-            .query("SELECT max(round_id) FROM rounds FINAL")
+        let result: Option<u64> = self.query("SELECT max(round_id) FROM rounds FINAL")
             .fetch_optional()
             .await?;
         
         // max() returns 0 for empty table, so check if there are any rows
         if let Some(max_id) = result {
             if max_id == 0 {
-                let count: u64 = self.client
-                    .query("SELECT count() FROM rounds FINAL")
+                let count: u64 = self.query("SELECT count() FROM rounds FINAL")
                     .fetch_one()
                     .await?;
                 if count == 0 {
@@ -163,8 +377,7 @@ impl ClickHouseClient {
     /// Delete a round by ID (for re-backfill).
     pub async fn delete_round(&self, round_id: u64) -> Result<u64, ClickHouseError> {
         // ClickHouse uses ALTER TABLE ... DELETE for MergeTree tables
-        self.client
-            .query("ALTER TABLE rounds DELETE WHERE round_id = ?")
+        self.query("ALTER TABLE rounds DELETE WHERE round_id = ?")
             .bind(round_id)
             .execute()
             .await?;
@@ -178,7 +391,7 @@ impl ClickHouseClient {
     /// Insert a partial round (finalization timeout).
     /// Used when top_miner was not populated before reset.
     pub async fn insert_partial_round(&self, round: PartialRoundInsert) -> Result<(), ClickHouseError> {
-        let mut insert = self.client.insert("partial_rounds")?;
+        let mut insert = self.insert(tables::PARTIAL_ROUNDS)?;
         insert.write(&round).await?;
         insert.end().await?;
         Ok(())
@@ -186,8 +399,7 @@ impl ClickHouseClient {
     
     /// Get all partial rounds needing backfill.
     pub async fn get_partial_rounds(&self) -> Result<Vec<PartialRound>, ClickHouseError> {
-        let rows: Vec<PartialRound> = self.client
-            .query("SELECT * FROM partial_rounds ORDER BY round_id ASC")
+        let rows: Vec<PartialRound> = self.query("SELECT * FROM partial_rounds ORDER BY round_id ASC")
             .fetch_all()
             .await?;
         Ok(rows)
@@ -195,8 +407,7 @@ impl ClickHouseClient {
     
     /// Check if a partial round exists.
     pub async fn partial_round_exists(&self, round_id: u64) -> Result<bool, ClickHouseError> {
-        let count: u64 = self.client
-            .query("SELECT count() FROM partial_rounds WHERE round_id = ?")
+        let count: u64 = self.query("SELECT count() FROM partial_rounds WHERE round_id = ?")
             .bind(round_id)
             .fetch_one()
             .await?;
@@ -205,18 +416,35 @@ impl ClickHouseClient {
     
     /// Delete a partial round after successful backfill to rounds table.
     pub async fn delete_partial_round(&self, round_id: u64) -> Result<(), ClickHouseError> {
-        self.client
-            .query("ALTER TABLE partial_rounds DELETE WHERE round_id = ?")
+        self.query("ALTER TABLE partial_rounds DELETE WHERE round_id = ?")
             .bind(round_id)
             .execute()
             .await?;
         Ok(())
     }
     
+    // ========== Round Entropy ==========
+    
+    /// Insert the entropy var captured when a round was finalized.
+    pub async fn insert_round_entropy(&self, entropy: RoundEntropy) -> Result<(), ClickHouseError> {
+        let mut insert = self.insert(tables::ROUND_ENTROPY)?;
+        insert.write(&entropy).await?;
+        insert.end().await?;
+        Ok(())
+    }
+    
+    /// Get the entropy recorded for a round.
+    pub async fn get_round_entropy(&self, round_id: u64) -> Result<Option<RoundEntropy>, ClickHouseError> {
+        let row = self.query("SELECT * FROM round_entropy FINAL WHERE round_id = ? LIMIT 1")
+            .bind(round_id)
+            .fetch_optional()
+            .await?;
+        Ok(row)
+    }
+    
     /// Delete all deployments for a round (for re-backfill).
     pub async fn delete_deployments_for_round(&self, round_id: u64) -> Result<u64, ClickHouseError> {
-        self.client
-            .query("ALTER TABLE deployments DELETE WHERE round_id = ?")
+        self.query("ALTER TABLE deployments DELETE WHERE round_id = ?")
             .bind(round_id)
             .execute()
             .await?;
@@ -226,8 +454,7 @@ impl ClickHouseClient {
     
     /// Delete all raw transactions for a round (for re-fetch).
     pub async fn delete_raw_transactions_for_round(&self, round_id: u64) -> Result<(), ClickHouseError> {
-        self.client
-            .query("ALTER TABLE raw_transactions DELETE WHERE round_id = ?")
+        self.query("ALTER TABLE raw_transactions DELETE WHERE round_id = ?")
             .bind(round_id)
             .execute()
             .await?;
@@ -237,8 +464,7 @@ impl ClickHouseClient {
     
     /// Count deployments for a round (to check if data exists).
     pub async fn count_deployments_for_round(&self, round_id: u64) -> Result<u64, ClickHouseError> {
-        let count: u64 = self.client
-            .query("SELECT count() FROM deployments WHERE round_id = ?")
+        let count: u64 = self.query("SELECT count() FROM deployments WHERE round_id = ?")
             .bind(round_id)
             .fetch_one()
             .await?;
@@ -247,8 +473,7 @@ impl ClickHouseClient {
     
     /// Sum of all deployment amounts for a round (for validation against round total_deployed).
     pub async fn sum_deployments_for_round(&self, round_id: u64) -> Result<u64, ClickHouseError> {
-        let sum: u64 = self.client
-            .query("SELECT sum(amount) FROM deployments WHERE round_id = ?")
+        let sum: u64 = self.query("SELECT sum(amount) FROM deployments WHERE round_id = ?")
             .bind(round_id)
             .fetch_one()
             .await?;
@@ -257,8 +482,7 @@ impl ClickHouseClient {
     
     /// Get deployment count and sum for a round (combined for efficiency).
     pub async fn get_deployment_stats_for_round(&self, round_id: u64) -> Result<(u64, u64), ClickHouseError> {
-        let row: (u64, u64) = self.client
-            .query("SELECT count(), sum(amount) FROM deployments WHERE round_id = ?")
+        let row: (u64, u64) = self.query("SELECT count(), sum(amount) FROM deployments WHERE round_id = ?")
             .bind(round_id)
             .fetch_one()
             .await?;
@@ -267,8 +491,7 @@ impl ClickHouseClient {
     
     /// Get the oldest round ID in the database.
     pub async fn get_oldest_round_id(&self) -> Result<Option<u64>, ClickHouseError> {
-        let result: Option<u64> = self.client
-            .query("SELECT min(round_id) FROM rounds")
+        let result: Option<u64> = self.query("SELECT min(round_id) FROM rounds")
             .fetch_optional()
             .await?;
         Ok(result)
@@ -276,8 +499,7 @@ impl ClickHouseClient {
     
     /// Get recent rounds (for listing).
     pub async fn get_recent_rounds(&self, limit: u32) -> Result<Vec<RoundRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     round_id,
                     start_slot,
@@ -320,8 +542,7 @@ impl ClickHouseClient {
         
         let results: Vec<RoundRow> = if let Some(before_id) = before_round_id {
             // Cursor-based pagination - rounds before this ID
-            self.client
-                .query(r#"
+            self.query(r#"
                     SELECT 
                         round_id,
                         start_slot,
@@ -349,8 +570,7 @@ impl ClickHouseClient {
                 .await?
         } else if let Some(skip) = offset {
             // Offset-based pagination
-            self.client
-                .query(r#"
+            self.query(r#"
                     SELECT 
                         round_id,
                         start_slot,
@@ -377,8 +597,7 @@ impl ClickHouseClient {
                 .await?
         } else {
             // No pagination, just get latest
-            self.client
-                .query(r#"
+            self.query(r#"
                     SELECT 
                         round_id,
                         start_slot,
@@ -465,8 +684,7 @@ impl ClickHouseClient {
             LIMIT {} OFFSET {}
         "#, where_clause, fetch_limit, skip);
         
-        let results: Vec<RoundRow> = self.client
-            .query(&query)
+        let results: Vec<RoundRow> = self.query(&query)
             .fetch_all()
             .await?;
         
@@ -498,7 +716,7 @@ impl ClickHouseClient {
         };
         
         let query = format!("SELECT count() FROM rounds WHERE {}", where_clause);
-        let count: u64 = self.client.query(&query).fetch_one().await?;
+        let count: u64 = self.query(&query).fetch_one().await?;
         Ok(count)
     }
     
@@ -556,8 +774,7 @@ impl ClickHouseClient {
             LIMIT {} OFFSET {}
         "#, where_clause, fetch_limit, skip);
         
-        let results: Vec<RoundRow> = self.client
-            .query(&query)
+        let results: Vec<RoundRow> = self.admin_query(&query)
             .fetch_all()
             .await?;
         
@@ -625,8 +842,7 @@ impl ClickHouseClient {
             LIMIT {} OFFSET {}
         "#, where_clause, fetch_limit, skip);
         
-        let results: Vec<RoundRowWithDeploymentStats> = self.client
-            .query(&query)
+        let results: Vec<RoundRowWithDeploymentStats> = self.admin_query(&query)
             .fetch_all()
             .await?;
         
@@ -646,7 +862,7 @@ impl ClickHouseClient {
     ) -> Result<(Vec<u64>, bool, u64, u64), ClickHouseError> {
         // First get the range of round IDs
         let range_query = "SELECT min(round_id) as min_id, max(round_id) as max_id FROM rounds";
-        let (min_id, max_id): (u64, u64) = self.client.query(range_query).fetch_one().await?;
+        let (min_id, max_id): (u64, u64) = self.query(range_query).fetch_one().await?;
         
         if min_id == 0 && max_id == 0 {
             return Ok((vec![], false, 0, 0));
@@ -675,8 +891,7 @@ impl ClickHouseClient {
             LIMIT {} OFFSET {}
         "#, actual_min, actual_max - actual_min + 1, actual_min, actual_max, fetch_limit, skip);
         
-        let results: Vec<u64> = self.client
-            .query(&query)
+        let results: Vec<u64> = self.admin_query(&query)
             .fetch_all()
             .await?;
         
@@ -714,10 +929,53 @@ impl ClickHouseClient {
             WHERE {} AND (d.dep_count IS NULL OR d.dep_count = 0)
         "#, where_clause);
         
-        let count: u64 = self.client.query(&query).fetch_one().await?;
+        let count: u64 = self.admin_query(&query).fetch_one().await?;
         Ok(count)
     }
     
+    /// Find rounds whose stored deployments disagree with the round row.
+    /// Same checks as `sum_deployments_for_round` / `count_deployments_for_round`,
+    /// batched over a round range: deployment sum vs total_deployed, deployment
+    /// count vs total_deployments, and distinct miners vs unique_miners.
+    pub async fn get_inconsistent_rounds(
+        &self,
+        round_id_gte: u64,
+        round_id_lte: u64,
+        limit: u32,
+    ) -> Result<Vec<RoundConsistencyRow>, ClickHouseError> {
+        let rows = self.admin_query(r#"
+                SELECT
+                    r.round_id as round_id,
+                    r.total_deployed as total_deployed,
+                    ifNull(d.dep_sum, 0) as deployments_sum,
+                    toUInt64(r.total_deployments) as total_deployments,
+                    ifNull(d.dep_count, 0) as deployments_count,
+                    toUInt64(r.unique_miners) as unique_miners,
+                    ifNull(d.miner_count, 0) as distinct_miners
+                FROM rounds r FINAL
+                LEFT JOIN (
+                    SELECT round_id, sum(amount) as dep_sum, count() as dep_count, uniqExact(miner_pubkey) as miner_count
+                    FROM deployments
+                    WHERE round_id >= ? AND round_id <= ?
+                    GROUP BY round_id
+                ) d ON r.round_id = d.round_id
+                WHERE r.round_id >= ? AND r.round_id <= ?
+                  AND (deployments_sum != total_deployed
+                       OR deployments_count != total_deployments
+                       OR distinct_miners != unique_miners)
+                ORDER BY r.round_id
+                LIMIT ?
+            "#)
+            .bind(round_id_gte)
+            .bind(round_id_lte)
+            .bind(round_id_gte)
+            .bind(round_id_lte)
+            .bind(limit)
+            .fetch_all::<RoundConsistencyRow>()
+            .await?;
+        Ok(rows)
+    }
+    
     /// Get count of rounds with invalid deployments
     /// This excludes rounds with 0 deployments (those are "missing", not "invalid")
     pub async fn get_rounds_with_invalid_deployments_count(
@@ -748,7 +1006,7 @@ impl ClickHouseClient {
             WHERE {} AND d.dep_count > 0 AND d.dep_sum != r.total_deployed
         "#, where_clause);
         
-        let count: u64 = self.client.query(&query).fetch_one().await?;
+        let count: u64 = self.admin_query(&query).fetch_one().await?;
         Ok(count)
     }
     
@@ -759,7 +1017,7 @@ impl ClickHouseClient {
         round_id_lte: Option<u64>,
     ) -> Result<u64, ClickHouseError> {
         let range_query = "SELECT min(round_id) as min_id, max(round_id) as max_id FROM rounds";
-        let (min_id, max_id): (u64, u64) = self.client.query(range_query).fetch_one().await?;
+        let (min_id, max_id): (u64, u64) = self.query(range_query).fetch_one().await?;
         
         if min_id == 0 && max_id == 0 {
             return Ok(0);
@@ -780,7 +1038,7 @@ impl ClickHouseClient {
             )
         "#, actual_min, actual_max - actual_min + 1, actual_min, actual_max);
         
-        let count: u64 = self.client.query(&query).fetch_one().await?;
+        let count: u64 = self.admin_query(&query).fetch_one().await?;
         Ok(count)
     }
     
@@ -808,8 +1066,7 @@ impl ClickHouseClient {
             LIMIT 1
         "#, min_round, max_round - min_round + 1, min_round, max_round);
         
-        let result: Option<u64> = self.client
-            .query(&query)
+        let result: Option<u64> = self.admin_query(&query)
             .fetch_optional()
             .await?;
         
@@ -846,8 +1103,7 @@ impl ClickHouseClient {
             LIMIT 1
         "#, min_round, max_round - min_round + 1, min_round, max_round, exclude_clause);
         
-        let result: Option<u64> = self.client
-            .query(&query)
+        let result: Option<u64> = self.admin_query(&query)
             .fetch_optional()
             .await?;
         
@@ -856,8 +1112,7 @@ impl ClickHouseClient {
     
     /// Get total count of rounds in database.
     pub async fn get_rounds_count(&self) -> Result<u64, ClickHouseError> {
-        let result: u64 = self.client
-            .query("SELECT count() FROM rounds")
+        let result: u64 = self.query("SELECT count() FROM rounds")
             .fetch_one()
             .await?;
         Ok(result)
@@ -865,8 +1120,7 @@ impl ClickHouseClient {
     
     /// Get a single round by ID.
     pub async fn get_round_by_id(&self, round_id: u64) -> Result<Option<RoundRow>, ClickHouseError> {
-        let result = self.client
-            .query(r#"
+        let result = self.query(r#"
                 SELECT 
                     round_id,
                     start_slot,
@@ -894,8 +1148,7 @@ impl ClickHouseClient {
     
     /// Get deployments for a round.
     pub async fn get_deployments_for_round(&self, round_id: u64) -> Result<Vec<DeploymentRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     d.round_id,
                     d.miner_pubkey,
@@ -918,13 +1171,33 @@ impl ClickHouseClient {
         Ok(results)
     }
     
+    /// Total deployed per miner on a round's winning square.
+    pub async fn get_winning_square_stakes(&self, round_id: u64) -> Result<Vec<WinningSquareStake>, ClickHouseError> {
+        let results = self.query(r#"
+                SELECT 
+                    d.miner_pubkey,
+                    sum(d.amount) as amount
+                FROM deployments d
+                INNER JOIN (
+                    SELECT round_id, winning_square FROM rounds FINAL WHERE round_id = ?
+                ) r ON d.round_id = r.round_id AND d.square_id = r.winning_square
+                WHERE d.round_id = ?
+                GROUP BY d.miner_pubkey
+                ORDER BY amount DESC
+            "#)
+            .bind(round_id)
+            .bind(round_id)
+            .fetch_all()
+            .await?;
+        Ok(results)
+    }
+    
     // ========== Deployments ==========
     
     /// Create an inserter for deployments.
     /// Flushes every 500 rows or 500ms, whichever comes first.
     pub fn deployments_inserter(&self) -> Result<Inserter<DeploymentInsert>, ClickHouseError> {
-        let inserter = self.client
-            .inserter::<DeploymentInsert>("deployments")?
+        let inserter = self.inserter::<DeploymentInsert>(tables::DEPLOYMENTS)?
             .with_max_rows(500)
             .with_period(Some(Duration::from_millis(500)));
         Ok(inserter)
@@ -936,7 +1209,7 @@ impl ClickHouseClient {
             return Ok(());
         }
         
-        let mut insert = self.client.insert("deployments")?;
+        let mut insert = self.insert(tables::DEPLOYMENTS)?;
         for d in deployments {
             insert.write(&d).await?;
         }
@@ -948,7 +1221,7 @@ impl ClickHouseClient {
     
     /// Insert a treasury snapshot.
     pub async fn insert_treasury_snapshot(&self, snapshot: TreasurySnapshot) -> Result<(), ClickHouseError> {
-        let mut insert = self.client.insert("treasury_snapshots")?;
+        let mut insert = self.insert(tables::TREASURY_SNAPSHOTS)?;
         insert.write(&snapshot).await?;
         insert.end().await?;
         Ok(())
@@ -958,7 +1231,7 @@ impl ClickHouseClient {
     
     /// Insert a mint supply snapshot.
     pub async fn insert_mint_snapshot(&self, snapshot: MintSnapshot) -> Result<(), ClickHouseError> {
-        let mut insert = self.client.insert("mint_snapshots")?;
+        let mut insert = self.insert(tables::MINT_SNAPSHOTS)?;
         insert.write(&snapshot).await?;
         insert.end().await?;
         Ok(())
@@ -966,8 +1239,7 @@ impl ClickHouseClient {
     
     /// Get the latest mint supply (from most recent snapshot).
     pub async fn get_latest_mint_supply(&self) -> Result<Option<u64>, ClickHouseError> {
-        let result: Option<u64> = self.client
-            .query("SELECT supply FROM mint_snapshots ORDER BY round_id DESC LIMIT 1")
+        let result: Option<u64> = self.query("SELECT supply FROM mint_snapshots ORDER BY round_id DESC LIMIT 1")
             .fetch_optional()
             .await?;
         Ok(result)
@@ -998,7 +1270,98 @@ impl ClickHouseClient {
             hours
         );
         
-        let rows = self.client.query(&query).fetch_all().await?;
+        let rows = self.query(&query).fetch_all().await?;
+        Ok(rows)
+    }
+    
+    /// Per-day totals of finalized rounds for `from..=to` (by `toDate(created_at)`).
+    /// Days without rounds come back as zero rows so the result has one row per day.
+    pub async fn get_daily_aggregates(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<DailyAggregateRow>, ClickHouseError> {
+        let (from, to) = (from.to_string(), to.to_string());
+        let rows = self.query(r#"
+                SELECT
+                    r.day AS day,
+                    r.rounds_finalized AS rounds_finalized,
+                    r.total_deployed AS total_deployed,
+                    r.ore_minted AS ore_minted,
+                    m.unique_miners AS unique_miners,
+                    r.motherlode_hits AS motherlode_hits
+                FROM (
+                    SELECT
+                        toDate(created_at) AS day,
+                        count() AS rounds_finalized,
+                        sum(total_deployed) AS total_deployed,
+                        sum(top_miner_reward) + sumIf(motherlode, motherlode_hit = 1) AS ore_minted,
+                        countIf(motherlode_hit = 1) AS motherlode_hits
+                    FROM rounds FINAL
+                    WHERE toDate(created_at) BETWEEN toDate(?) AND toDate(?)
+                    GROUP BY day
+                ) r
+                LEFT JOIN (
+                    SELECT toDate(dr.created_at) AS day, uniqExact(d.miner_pubkey) AS unique_miners
+                    FROM deployments d
+                    INNER JOIN (
+                        SELECT round_id, created_at FROM rounds FINAL
+                        WHERE toDate(created_at) BETWEEN toDate(?) AND toDate(?)
+                    ) dr ON d.round_id = dr.round_id
+                    GROUP BY day
+                ) m ON r.day = m.day
+                ORDER BY day ASC WITH FILL FROM toDate(?) TO toDate(?) + 1 STEP 1
+            "#)
+            .bind(&from)
+            .bind(&to)
+            .bind(&from)
+            .bind(&to)
+            .bind(&from)
+            .bind(&to)
+            .fetch_all()
+            .await?;
+        Ok(rows)
+    }
+    
+    /// Reconstructed deployment automation (`deployment_automation_states`) per
+    /// strategy, per round (`from..=to` are round ids) or per day of the round's
+    /// `created_at` (`by_day`, `from..=to` are days since 1970-01-01).
+    /// Deploys without an active automation have strategy `MANUAL_STRATEGY`.
+    pub async fn get_automation_strategy_usage(
+        &self,
+        by_day: bool,
+        from: u64,
+        to: u64,
+    ) -> Result<Vec<AutomationStrategyUsageRow>, ClickHouseError> {
+        let (period, join) = if by_day {
+            (
+                "toUInt64(toDate(r.created_at))",
+                "INNER JOIN (SELECT round_id, created_at FROM rounds FINAL) r ON das.round_id = r.round_id",
+            )
+        } else {
+            ("das.round_id", "")
+        };
+        let query = format!(
+            r#"SELECT
+                {period} AS period,
+                if(das.automation_found AND das.automation_active, das.automation_strategy, {manual}) AS strategy,
+                count() AS deployments,
+                uniqExact(das.miner_pubkey) AS miners,
+                sum(das.total_sol_spent) AS total_sol_spent
+            FROM deployment_automation_states AS das FINAL
+            {join}
+            WHERE {period} BETWEEN ? AND ?
+            GROUP BY period, strategy
+            ORDER BY period ASC, strategy ASC"#,
+            period = period,
+            join = join,
+            manual = MANUAL_STRATEGY,
+        );
+        let rows = self.query(&query)
+            .bind(from)
+            .bind(to)
+            .fetch_all()
+            .await?;
         Ok(rows)
     }
     
@@ -1024,7 +1387,7 @@ impl ClickHouseClient {
             days
         );
         
-        let rows = self.client.query(&query).fetch_all().await?;
+        let rows = self.query(&query).fetch_all().await?;
         Ok(rows)
     }
     
@@ -1048,7 +1411,7 @@ impl ClickHouseClient {
             hours
         );
         
-        let rows = self.client.query(&query).fetch_all().await?;
+        let rows = self.query(&query).fetch_all().await?;
         Ok(rows)
     }
     
@@ -1071,7 +1434,7 @@ impl ClickHouseClient {
             hours
         );
         
-        let rows = self.client.query(&query).fetch_all().await?;
+        let rows = self.query(&query).fetch_all().await?;
         Ok(rows)
     }
     
@@ -1093,7 +1456,7 @@ impl ClickHouseClient {
             days
         );
         
-        let rows = self.client.query(&query).fetch_all().await?;
+        let rows = self.query(&query).fetch_all().await?;
         Ok(rows)
     }
     
@@ -1122,7 +1485,7 @@ impl ClickHouseClient {
             hours
         );
         
-        let rows = self.client.query(&query).fetch_all().await?;
+        let rows = self.query(&query).fetch_all().await?;
         Ok(rows)
     }
     
@@ -1153,7 +1516,7 @@ impl ClickHouseClient {
             days
         );
         
-        let rows = self.client.query(&query).fetch_all().await?;
+        let rows = self.query(&query).fetch_all().await?;
         Ok(rows)
     }
     
@@ -1176,7 +1539,7 @@ impl ClickHouseClient {
             days
         );
         
-        let rows = self.client.query(&query).fetch_all().await?;
+        let rows = self.query(&query).fetch_all().await?;
         Ok(rows)
     }
     
@@ -1200,7 +1563,7 @@ impl ClickHouseClient {
             days
         );
         
-        let rows = self.client.query(&query).fetch_all().await?;
+        let rows = self.query(&query).fetch_all().await?;
         Ok(rows)
     }
     
@@ -1259,7 +1622,7 @@ impl ClickHouseClient {
             ),
         };
         
-        let mut rows: Vec<RoundDirectRow> = self.client.query(&query).fetch_all().await?;
+        let mut rows: Vec<RoundDirectRow> = self.query(&query).fetch_all().await?;
         // Ensure ascending order for display
         rows.sort_by_key(|r| r.round_id);
         Ok(rows)
@@ -1315,7 +1678,7 @@ impl ClickHouseClient {
             ),
         };
         
-        let mut rows: Vec<TreasuryDirectRow> = self.client.query(&query).fetch_all().await?;
+        let mut rows: Vec<TreasuryDirectRow> = self.query(&query).fetch_all().await?;
         rows.sort_by_key(|r| r.round_id);
         Ok(rows)
     }
@@ -1365,7 +1728,7 @@ impl ClickHouseClient {
             ),
         };
         
-        let mut rows: Vec<MintDirectRow> = self.client.query(&query).fetch_all().await?;
+        let mut rows: Vec<MintDirectRow> = self.query(&query).fetch_all().await?;
         rows.sort_by_key(|r| r.round_id);
         Ok(rows)
     }
@@ -1419,7 +1782,7 @@ impl ClickHouseClient {
             ),
         };
         
-        let mut rows: Vec<InflationDirectRow> = self.client.query(&query).fetch_all().await?;
+        let mut rows: Vec<InflationDirectRow> = self.query(&query).fetch_all().await?;
         rows.sort_by_key(|r| r.round_id);
         Ok(rows)
     }
@@ -1461,7 +1824,7 @@ impl ClickHouseClient {
             ),
         };
         
-        let mut rows: Vec<CostPerOreDirectRow> = self.client.query(&query).fetch_all().await?;
+        let mut rows: Vec<CostPerOreDirectRow> = self.query(&query).fetch_all().await?;
         rows.sort_by_key(|r| r.round_id);
         Ok(rows)
     }
@@ -1469,7 +1832,7 @@ impl ClickHouseClient {
     /// Get the latest round_id.
     pub async fn get_latest_round_id(&self) -> Result<Option<u64>, ClickHouseError> {
         let query = "SELECT max(round_id) FROM rounds";
-        let result: Option<u64> = self.client.query(query).fetch_optional().await?;
+        let result: Option<u64> = self.query(query).fetch_optional().await?;
         Ok(result.filter(|&r| r > 0))
     }
     
@@ -1477,8 +1840,7 @@ impl ClickHouseClient {
     
     /// Create an inserter for miner snapshots.
     pub fn miner_snapshots_inserter(&self) -> Result<Inserter<MinerSnapshot>, ClickHouseError> {
-        let inserter = self.client
-            .inserter::<MinerSnapshot>("miner_snapshots")?
+        let inserter = self.inserter::<MinerSnapshot>(tables::MINER_SNAPSHOTS)?
             .with_max_rows(1000)
             .with_period(Some(Duration::from_secs(1)));
         Ok(inserter)
@@ -1490,7 +1852,7 @@ impl ClickHouseClient {
             return Ok(());
         }
         
-        let mut insert = self.client.insert("miner_snapshots")?;
+        let mut insert = self.insert(tables::MINER_SNAPSHOTS)?;
         for s in snapshots {
             insert.write(&s).await?;
         }
@@ -1501,7 +1863,7 @@ impl ClickHouseClient {
     /// Get the latest round_id that has miner snapshots.
     pub async fn get_latest_snapshot_round(&self) -> Result<Option<u64>, ClickHouseError> {
         let query = "SELECT max(round_id) as max_round FROM miner_snapshots";
-        let result: Option<u64> = self.client.query(query).fetch_optional().await?;
+        let result: Option<u64> = self.query(query).fetch_optional().await?;
         Ok(result.filter(|&r| r > 0))
     }
     
@@ -1543,7 +1905,7 @@ impl ClickHouseClient {
             "SELECT count(*) FROM miner_snapshots WHERE round_id = {} {}",
             round_id, search_filter
         );
-        let total_count: u64 = self.client.query(&count_query).fetch_one().await?;
+        let total_count: u64 = self.query(&count_query).fetch_one().await?;
         
         // Data query
         let query = format!(
@@ -1561,7 +1923,7 @@ impl ClickHouseClient {
             round_id, search_filter, sort_column, order, limit, offset
         );
         
-        let rows: Vec<MinerSnapshotRow> = self.client.query(&query).fetch_all().await?;
+        let rows: Vec<MinerSnapshotRow> = self.query(&query).fetch_all().await?;
         
         Ok((rows, total_count))
     }
@@ -1570,8 +1932,7 @@ impl ClickHouseClient {
     
     /// Create an inserter for RPC request metrics.
     pub fn rpc_metrics_inserter(&self) -> Result<Inserter<RpcRequestInsert>, ClickHouseError> {
-        let inserter = self.client
-            .inserter::<RpcRequestInsert>("rpc_requests")?
+        let inserter = self.inserter::<RpcRequestInsert>(tables::RPC_REQUESTS)?
             .with_max_rows(100)
             .with_period(Some(Duration::from_secs(1)));
         Ok(inserter)
@@ -1579,7 +1940,7 @@ impl ClickHouseClient {
     
     /// Insert a single RPC metric (for immediate logging).
     pub async fn insert_rpc_metric(&self, metric: RpcRequestInsert) -> Result<(), ClickHouseError> {
-        let mut insert = self.client.insert("rpc_requests")?;
+        let mut insert = self.insert(tables::RPC_REQUESTS)?;
         insert.write(&metric).await?;
         insert.end().await?;
         Ok(())
@@ -1589,8 +1950,7 @@ impl ClickHouseClient {
     
     /// Get RPC metrics summary for the last N hours, grouped by provider and method.
     pub async fn get_rpc_summary(&self, hours: u32) -> Result<Vec<RpcSummaryRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     program,
                     provider,
@@ -1622,8 +1982,7 @@ impl ClickHouseClient {
     
     /// Get RPC metrics by provider for the last N hours.
     pub async fn get_rpc_by_provider(&self, hours: u32) -> Result<Vec<RpcProviderRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     program,
                     provider,
@@ -1651,8 +2010,7 @@ impl ClickHouseClient {
     
     /// Get RPC errors for the last N hours.
     pub async fn get_rpc_errors(&self, hours: u32, limit: u32) -> Result<Vec<RpcErrorRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     timestamp,
                     program,
@@ -1679,8 +2037,7 @@ impl ClickHouseClient {
     
     /// Get RPC metrics time series for the last N hours (minute granularity).
     pub async fn get_rpc_timeseries(&self, hours: u32) -> Result<Vec<RpcTimeseriesRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     minute,
                     sum(total_requests) AS total_requests,
@@ -1704,8 +2061,7 @@ impl ClickHouseClient {
     
     /// Get daily RPC summary for the last N days.
     pub async fn get_rpc_daily(&self, days: u32) -> Result<Vec<RpcDailyRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     day,
                     program,
@@ -1730,10 +2086,29 @@ impl ClickHouseClient {
         Ok(results)
     }
     
+    /// Get provider credits consumed per day, provider and method for the last N days.
+    pub async fn get_rpc_credits_daily(&self, days: u32) -> Result<Vec<RpcCreditsRow>, ClickHouseError> {
+        let results = self.query(r#"
+                SELECT 
+                    toDate(timestamp) AS day,
+                    provider,
+                    method,
+                    count() AS total_requests,
+                    sum(credits) AS total_credits
+                FROM rpc_requests
+                WHERE timestamp > today() - INTERVAL ? DAY
+                GROUP BY day, provider, method
+                ORDER BY day DESC, total_credits DESC
+            "#)
+            .bind(days)
+            .fetch_all()
+            .await?;
+        Ok(results)
+    }
+    
     /// Get recent RPC requests (all, not just errors).
     pub async fn get_rpc_requests(&self, hours: u32, limit: u32) -> Result<Vec<RpcRequestRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     timestamp,
                     program,
@@ -1767,7 +2142,7 @@ impl ClickHouseClient {
     
     /// Insert a rate limit event.
     pub async fn insert_rate_limit_event(&self, event: RateLimitEvent) -> Result<(), ClickHouseError> {
-        let mut insert = self.client.insert("rate_limit_events")?;
+        let mut insert = self.insert(tables::RATE_LIMIT_EVENTS)?;
         insert.write(&event).await?;
         insert.end().await?;
         Ok(())
@@ -1777,7 +2152,7 @@ impl ClickHouseClient {
     
     /// Insert a WebSocket event (connect/disconnect/error).
     pub async fn insert_ws_event(&self, event: WsEventInsert) -> Result<(), ClickHouseError> {
-        let mut insert = self.client.insert("ws_events")?;
+        let mut insert = self.insert(tables::WS_EVENTS)?;
         insert.write(&event).await?;
         insert.end().await?;
         Ok(())
@@ -1785,7 +2160,7 @@ impl ClickHouseClient {
     
     /// Insert WebSocket throughput sample.
     pub async fn insert_ws_throughput(&self, sample: WsThroughputInsert) -> Result<(), ClickHouseError> {
-        let mut insert = self.client.insert("ws_throughput")?;
+        let mut insert = self.insert(tables::WS_THROUGHPUT)?;
         insert.write(&sample).await?;
         insert.end().await?;
         Ok(())
@@ -1793,8 +2168,7 @@ impl ClickHouseClient {
     
     /// Get WebSocket events for the last N hours.
     pub async fn get_ws_events(&self, hours: u32, limit: u32) -> Result<Vec<WsEventRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     timestamp,
                     program,
@@ -1821,8 +2195,7 @@ impl ClickHouseClient {
     
     /// Get WebSocket throughput summary for the last N hours.
     pub async fn get_ws_throughput_summary(&self, hours: u32) -> Result<Vec<WsThroughputSummary>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     program,
                     provider,
@@ -1845,8 +2218,7 @@ impl ClickHouseClient {
     
     /// Get server metrics for the last N hours.
     pub async fn get_server_metrics(&self, hours: u32, limit: u32) -> Result<Vec<ServerMetricsRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     timestamp,
                     requests_total,
@@ -1877,8 +2249,7 @@ impl ClickHouseClient {
     /// Get recent request logs, optionally filtered by IP hash.
     pub async fn get_request_logs(&self, hours: u32, limit: u32, ip_hash: Option<&str>) -> Result<Vec<RequestLogRow>, ClickHouseError> {
         let results = if let Some(ip) = ip_hash {
-            self.client
-                .query(r#"
+            self.query(r#"
                     SELECT 
                         timestamp,
                         endpoint,
@@ -1886,7 +2257,8 @@ impl ClickHouseClient {
                         status_code,
                         duration_ms,
                         ip_hash,
-                        user_agent
+                        user_agent,
+                        request_id
                     FROM request_logs
                     WHERE timestamp > now() - INTERVAL ? HOUR AND ip_hash = ?
                     ORDER BY timestamp DESC
@@ -1898,8 +2270,7 @@ impl ClickHouseClient {
                 .fetch_all()
                 .await?
         } else {
-            self.client
-                .query(r#"
+            self.query(r#"
                     SELECT 
                         timestamp,
                         endpoint,
@@ -1907,7 +2278,8 @@ impl ClickHouseClient {
                         status_code,
                         duration_ms,
                         ip_hash,
-                        user_agent
+                        user_agent,
+                        request_id
                     FROM request_logs
                     WHERE timestamp > now() - INTERVAL ? HOUR
                     ORDER BY timestamp DESC
@@ -1932,6 +2304,7 @@ impl ClickHouseClient {
         status_code: Option<u16>,
         status_gte: Option<u16>,
         status_lte: Option<u16>,
+        request_id: Option<&str>,
     ) -> Result<Vec<RequestLogRow>, ClickHouseError> {
         // Build dynamic WHERE clause
         let mut conditions = vec!["timestamp > now() - INTERVAL ? HOUR".to_string()];
@@ -1963,6 +2336,11 @@ impl ClickHouseClient {
             bind_values.push(lte.to_string());
         }
         
+        if let Some(id) = request_id {
+            conditions.push("request_id = ?".to_string());
+            bind_values.push(id.to_string());
+        }
+        
         let where_clause = conditions.join(" AND ");
         
         let query = format!(r#"
@@ -1973,7 +2351,8 @@ impl ClickHouseClient {
                 status_code,
                 duration_ms,
                 ip_hash,
-                user_agent
+                user_agent,
+                request_id
             FROM request_logs
             WHERE {}
             ORDER BY timestamp DESC
@@ -1981,7 +2360,7 @@ impl ClickHouseClient {
         "#, where_clause);
         
         // Build the query with bindings
-        let mut q = self.client.query(&query);
+        let mut q = self.query(&query);
         
         // Bind all values in order
         for val in &bind_values {
@@ -1995,8 +2374,7 @@ impl ClickHouseClient {
     
     /// Get request logs summary by endpoint for the last N hours.
     pub async fn get_endpoint_summary(&self, hours: u32) -> Result<Vec<EndpointSummaryRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     endpoint,
                     count() AS total_requests,
@@ -2018,8 +2396,7 @@ impl ClickHouseClient {
     
     /// Get rate limit events for the last N hours.
     pub async fn get_rate_limit_events(&self, hours: u32, limit: u32) -> Result<Vec<RateLimitEventRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     timestamp,
                     ip_hash,
@@ -2040,8 +2417,7 @@ impl ClickHouseClient {
     
     /// Get IP activity summary for the last N hours.
     pub async fn get_ip_activity(&self, hours: u32, limit: u32) -> Result<Vec<IpActivityRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT 
                     ip_hash,
                     sum(request_count) AS total_requests,
@@ -2065,8 +2441,7 @@ impl ClickHouseClient {
     
     /// Get ClickHouse database sizes for all databases
     pub async fn get_database_sizes(&self) -> Result<Vec<DatabaseSizeRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT
                     database,
                     sum(bytes_on_disk) AS bytes_on_disk,
@@ -2084,8 +2459,7 @@ impl ClickHouseClient {
     
     /// Get ClickHouse table sizes for ore_stats database (legacy - use get_all_table_sizes)
     pub async fn get_table_sizes(&self) -> Result<Vec<TableSizeRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT
                     table,
                     sum(bytes_on_disk) AS bytes_on_disk,
@@ -2103,8 +2477,7 @@ impl ClickHouseClient {
     
     /// Get ALL table sizes across all databases (including system)
     pub async fn get_all_table_sizes(&self) -> Result<Vec<DetailedTableSizeRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT
                     database,
                     table,
@@ -2125,8 +2498,7 @@ impl ClickHouseClient {
     
     /// Get ClickHouse storage engine info for tables (all databases)
     pub async fn get_table_engines(&self) -> Result<Vec<TableEngineRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT
                     database,
                     name AS table,
@@ -2145,8 +2517,7 @@ impl ClickHouseClient {
     
     /// Get request stats for the last minute (for metrics snapshot)
     pub async fn get_recent_request_stats(&self) -> Result<RecentRequestStats, ClickHouseError> {
-        let result: Option<RecentRequestStats> = self.client
-            .query(r#"
+        let result: Option<RecentRequestStats> = self.query(r#"
                 SELECT
                     count() AS total,
                     countIf(status_code >= 200 AND status_code < 400) AS success,
@@ -2168,8 +2539,7 @@ impl ClickHouseClient {
     /// Get requests per minute time series for the last N hours.
     /// Returns data points grouped by minute for graphing.
     pub async fn get_requests_per_minute(&self, hours: u32) -> Result<Vec<RequestsPerMinuteRow>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT
                     toUnixTimestamp(toStartOfMinute(timestamp)) AS minute_ts,
                     count() AS request_count,
@@ -2189,8 +2559,7 @@ impl ClickHouseClient {
     
     /// Get current requests per second (average over last minute).
     pub async fn get_requests_per_second(&self) -> Result<f64, ClickHouseError> {
-        let result: Option<RequestCountRow> = self.client
-            .query(r#"
+        let result: Option<RequestCountRow> = self.query(r#"
                 SELECT count() AS cnt
                 FROM ore_stats.request_logs
                 WHERE timestamp > now64(3) - INTERVAL 1 MINUTE
@@ -2208,8 +2577,7 @@ impl ClickHouseClient {
     /// Create an inserter for raw transactions.
     /// Flushes every 100 rows or 500ms for efficient batch inserts.
     pub fn raw_transactions_inserter(&self) -> Result<Inserter<RawTransaction>, ClickHouseError> {
-        let inserter = self.client
-            .inserter::<RawTransaction>("raw_transactions")?
+        let inserter = self.inserter::<RawTransaction>(tables::RAW_TRANSACTIONS)?
             .with_max_rows(100)
             .with_period(Some(Duration::from_millis(500)));
         Ok(inserter)
@@ -2221,7 +2589,7 @@ impl ClickHouseClient {
             return Ok(());
         }
         
-        let mut insert = self.client.insert("raw_transactions")?;
+        let mut insert = self.insert(tables::RAW_TRANSACTIONS)?;
         for tx in txs {
             insert.write(&tx).await?;
         }
@@ -2231,8 +2599,7 @@ impl ClickHouseClient {
     
     /// Get all raw transactions for a round (for reconstruction).
     pub async fn get_raw_transactions_for_round(&self, round_id: u64) -> Result<Vec<RawTransaction>, ClickHouseError> {
-        let results = self.client
-            .query(
+        let results = self.query(
                 "SELECT signature, slot, block_time, round_id, tx_type, raw_json, signer, authority 
                  FROM raw_transactions FINAL 
                  WHERE round_id = ? 
@@ -2251,8 +2618,7 @@ impl ClickHouseClient {
         limit: usize, 
         offset: usize
     ) -> Result<Vec<RawTransaction>, ClickHouseError> {
-        let results = self.client
-            .query(
+        let results = self.query(
                 "SELECT signature, slot, block_time, round_id, tx_type, raw_json, signer, authority 
                  FROM raw_transactions FINAL 
                  WHERE round_id = ? 
@@ -2269,8 +2635,7 @@ impl ClickHouseClient {
     
     /// Get raw transactions count for a round.
     pub async fn get_raw_transaction_count(&self, round_id: u64) -> Result<u32, ClickHouseError> {
-        let count: u64 = self.client
-            .query("SELECT count() FROM raw_transactions WHERE round_id = ?")
+        let count: u64 = self.query("SELECT count() FROM raw_transactions WHERE round_id = ?")
             .bind(round_id)
             .fetch_one()
             .await?;
@@ -2279,8 +2644,7 @@ impl ClickHouseClient {
     
     /// Get a single raw transaction by signature.
     pub async fn get_raw_transaction_by_signature(&self, signature: &str) -> Result<Option<RawTransaction>, ClickHouseError> {
-        let result = self.client
-            .query(r#"
+        let result = self.query(r#"
                 SELECT signature, slot, block_time, round_id, tx_type, raw_json, signer, authority
                 FROM raw_transactions FINAL 
                 WHERE signature = ?
@@ -2295,8 +2659,7 @@ impl ClickHouseClient {
     /// Get list of rounds that have stored transactions (v2), with counts.
     /// Uses the pre-computed round_transaction_stats table (populated by MV).
     pub async fn get_rounds_with_transactions(&self, limit: u32, offset: u32) -> Result<Vec<RoundTransactionInfo>, ClickHouseError> {
-        let rows = self.client
-            .query(r#"
+        let rows = self.query(r#"
                 SELECT 
                     round_id,
                     sum(transaction_count) as transaction_count,
@@ -2317,8 +2680,7 @@ impl ClickHouseClient {
     
     /// Get total count of unique rounds with stored transactions (v2).
     pub async fn get_rounds_with_transactions_count(&self) -> Result<u64, ClickHouseError> {
-        let count: u64 = self.client
-            .query(r#"
+        let count: u64 = self.query(r#"
                 SELECT count(DISTINCT round_id)
                 FROM round_transaction_stats
                 WHERE transaction_count > 0
@@ -2335,7 +2697,7 @@ impl ClickHouseClient {
         if sigs.is_empty() {
             return Ok(());
         }
-        let mut insert = self.client.insert("signatures")?;
+        let mut insert = self.insert(tables::SIGNATURES)?;
         for sig in sigs {
             insert.write(&sig).await?;
         }
@@ -2345,8 +2707,7 @@ impl ClickHouseClient {
     
     /// Check if a signature exists in the signatures table.
     pub async fn signature_exists(&self, sig: &str) -> Result<bool, ClickHouseError> {
-        let count: u64 = self.client
-            .query("SELECT count() FROM signatures FINAL WHERE signature = ?")
+        let count: u64 = self.query("SELECT count() FROM signatures FINAL WHERE signature = ?")
             .bind(sig)
             .fetch_one()
             .await?;
@@ -2355,8 +2716,7 @@ impl ClickHouseClient {
     
     /// Get the latest signature for an account (for incremental fetching).
     pub async fn get_latest_signature_for_account(&self, account: &str) -> Result<Option<String>, ClickHouseError> {
-        let result: Option<String> = self.client
-            .query(r#"
+        let result: Option<String> = self.query(r#"
                 SELECT signature 
                 FROM signatures FINAL 
                 WHERE has(accounts, ?)
@@ -2371,8 +2731,7 @@ impl ClickHouseClient {
     
     /// Delete a signature by signature string.
     pub async fn delete_signature(&self, sig: &str) -> Result<(), ClickHouseError> {
-        self.client
-            .query("ALTER TABLE signatures DELETE WHERE signature = ?")
+        self.query("ALTER TABLE signatures DELETE WHERE signature = ?")
             .bind(sig)
             .execute()
             .await?;
@@ -2386,7 +2745,7 @@ impl ClickHouseClient {
         if txs.is_empty() {
             return Ok(());
         }
-        let mut insert = self.client.insert("raw_transactions_v2")?;
+        let mut insert = self.insert(tables::RAW_TRANSACTIONS_V2)?;
         for tx in txs {
             insert.write(&tx).await?;
         }
@@ -2396,8 +2755,7 @@ impl ClickHouseClient {
     
     /// Check if a transaction exists in raw_transactions_v2.
     pub async fn transaction_exists_v2(&self, sig: &str) -> Result<bool, ClickHouseError> {
-        let count: u64 = self.client
-            .query("SELECT count() FROM raw_transactions_v2 FINAL WHERE signature = ?")
+        let count: u64 = self.query("SELECT count() FROM raw_transactions_v2 FINAL WHERE signature = ?")
             .bind(sig)
             .fetch_one()
             .await?;
@@ -2406,8 +2764,7 @@ impl ClickHouseClient {
     
     /// Get transactions by account (queries the accounts array).
     pub async fn get_transactions_by_account(&self, account: &str) -> Result<Vec<RawTransactionV2>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT signature, slot, block_time, accounts, raw_json
                 FROM raw_transactions_v2 FINAL
                 WHERE has(accounts, ?)
@@ -2426,8 +2783,7 @@ impl ClickHouseClient {
         limit: usize,
         offset: usize,
     ) -> Result<Vec<RawTransactionV2>, ClickHouseError> {
-        let results = self.client
-            .query(r#"
+        let results = self.query(r#"
                 SELECT signature, slot, block_time, accounts, raw_json
                 FROM raw_transactions_v2 FINAL
                 WHERE has(accounts, ?)
@@ -2444,8 +2800,7 @@ impl ClickHouseClient {
     
     /// Get a single transaction by signature from v2 table.
     pub async fn get_transaction_by_signature_v2(&self, sig: &str) -> Result<Option<RawTransactionV2>, ClickHouseError> {
-        let result = self.client
-            .query(r#"
+        let result = self.query(r#"
                 SELECT signature, slot, block_time, accounts, raw_json
                 FROM raw_transactions_v2 FINAL
                 WHERE signature = ?
@@ -2459,8 +2814,7 @@ impl ClickHouseClient {
     
     /// Get transaction count for an account.
     pub async fn get_transaction_count_by_account(&self, account: &str) -> Result<u32, ClickHouseError> {
-        let count: u64 = self.client
-            .query("SELECT count() FROM raw_transactions_v2 FINAL WHERE has(accounts, ?)")
+        let count: u64 = self.query("SELECT count() FROM raw_transactions_v2 FINAL WHERE has(accounts, ?)")
             .bind(account)
             .fetch_one()
             .await?;
@@ -2469,8 +2823,7 @@ impl ClickHouseClient {
     
     /// Delete a transaction by signature from v2 table.
     pub async fn delete_transaction_v2(&self, sig: &str) -> Result<(), ClickHouseError> {
-        self.client
-            .query("ALTER TABLE raw_transactions_v2 DELETE WHERE signature = ?")
+        self.query("ALTER TABLE raw_transactions_v2 DELETE WHERE signature = ?")
             .bind(sig)
             .execute()
             .await?;
@@ -2481,32 +2834,27 @@ impl ClickHouseClient {
     /// Returns (raw_transactions_count, signatures_count, raw_transactions_v2_count)
     pub async fn get_transaction_migration_stats(&self) -> Result<TransactionMigrationStats, ClickHouseError> {
         // Count raw_transactions (old table)
-        let old_count: u64 = self.client
-            .query("SELECT count() FROM raw_transactions FINAL")
+        let old_count: u64 = self.query("SELECT count() FROM raw_transactions FINAL")
             .fetch_one()
             .await?;
         
         // Count unique rounds in old table
-        let old_rounds: u64 = self.client
-            .query("SELECT count(DISTINCT round_id) FROM raw_transactions FINAL")
+        let old_rounds: u64 = self.query("SELECT count(DISTINCT round_id) FROM raw_transactions FINAL")
             .fetch_one()
             .await?;
         
         // Count signatures table
-        let signatures_count: u64 = self.client
-            .query("SELECT count() FROM signatures FINAL")
+        let signatures_count: u64 = self.query("SELECT count() FROM signatures FINAL")
             .fetch_one()
             .await?;
         
         // Count raw_transactions_v2
-        let v2_count: u64 = self.client
-            .query("SELECT count() FROM raw_transactions_v2 FINAL")
+        let v2_count: u64 = self.query("SELECT count() FROM raw_transactions_v2 FINAL")
             .fetch_one()
             .await?;
         
         // Count unmigrated transactions (in old but not in v2)
-        let unmigrated_count: u64 = self.client
-            .query(r#"
+        let unmigrated_count: u64 = self.query(r#"
                 SELECT count() 
                 FROM raw_transactions rt FINAL
                 WHERE NOT EXISTS (
@@ -2540,8 +2888,7 @@ impl ClickHouseClient {
     /// transactions not yet in raw_transactions_v2.
     pub async fn get_next_unmigrated_round(&self) -> Result<Option<u64>, ClickHouseError> {
         // Find rounds in old table that have transactions not in v2
-        let result: Option<u64> = self.client
-            .query(r#"
+        let result: Option<u64> = self.query(r#"
                 SELECT DISTINCT round_id 
                 FROM raw_transactions rt
                 WHERE NOT EXISTS (
@@ -2560,8 +2907,7 @@ impl ClickHouseClient {
     
     /// Insert a round address mapping.
     pub async fn insert_round_address(&self, round_id: u64, address: &str) -> Result<(), ClickHouseError> {
-        self.client
-            .query("INSERT INTO round_addresses (round_id, address) VALUES (?, ?)")
+        self.query("INSERT INTO round_addresses (round_id, address) VALUES (?, ?)")
             .bind(round_id)
             .bind(address)
             .execute()
@@ -2585,14 +2931,13 @@ impl ClickHouseClient {
             values.join(", ")
         );
         
-        self.client.query(&query).execute().await?;
+        self.query(&query).execute().await?;
         Ok(())
     }
     
     /// Get address for a specific round.
     pub async fn get_round_address(&self, round_id: u64) -> Result<Option<String>, ClickHouseError> {
-        let result: Option<String> = self.client
-            .query("SELECT address FROM round_addresses FINAL WHERE round_id = ? LIMIT 1")
+        let result: Option<String> = self.query("SELECT address FROM round_addresses FINAL WHERE round_id = ? LIMIT 1")
             .bind(round_id)
             .fetch_optional()
             .await?;
@@ -2601,8 +2946,7 @@ impl ClickHouseClient {
     
     /// Check if a round address exists.
     pub async fn round_address_exists(&self, round_id: u64) -> Result<bool, ClickHouseError> {
-        let count: u64 = self.client
-            .query("SELECT count() FROM round_addresses FINAL WHERE round_id = ?")
+        let count: u64 = self.query("SELECT count() FROM round_addresses FINAL WHERE round_id = ?")
             .bind(round_id)
             .fetch_one()
             .await?;
@@ -2611,8 +2955,7 @@ impl ClickHouseClient {
     
     /// Get all rounds that have addresses stored (useful for transaction analyzer).
     pub async fn get_rounds_with_addresses(&self) -> Result<Vec<u64>, ClickHouseError> {
-        let results: Vec<u64> = self.client
-            .query("SELECT round_id FROM round_addresses FINAL ORDER BY round_id DESC")
+        let results: Vec<u64> = self.query("SELECT round_id FROM round_addresses FINAL ORDER BY round_id DESC")
             .fetch_all()
             .await?;
         Ok(results)
@@ -2622,8 +2965,7 @@ impl ClickHouseClient {
     /// Uses the round_addresses table to map round_id -> address, then checks if that address
     /// has transactions in raw_transactions_v2.
     pub async fn get_rounds_with_v2_transactions(&self) -> Result<Vec<u64>, ClickHouseError> {
-        let results: Vec<u64> = self.client
-            .query(r#"
+        let results: Vec<u64> = self.query(r#"
                 SELECT DISTINCT ra.round_id
                 FROM round_addresses ra FINAL
                 WHERE EXISTS (
@@ -2639,8 +2981,7 @@ impl ClickHouseClient {
     
     /// Get count of rounds that have v2 transactions.
     pub async fn get_rounds_with_v2_transactions_count(&self) -> Result<u64, ClickHouseError> {
-        let count: u64 = self.client
-            .query(r#"
+        let count: u64 = self.query(r#"
                 SELECT count(DISTINCT ra.round_id)
                 FROM round_addresses ra FINAL
                 WHERE EXISTS (
@@ -2655,15 +2996,13 @@ impl ClickHouseClient {
     
     /// Get the maximum round_id in round_addresses table.
     pub async fn get_max_round_address_id(&self) -> Result<Option<u64>, ClickHouseError> {
-        let result: Option<u64> = self.client
-            .query("SELECT max(round_id) FROM round_addresses FINAL")
+        let result: Option<u64> = self.query("SELECT max(round_id) FROM round_addresses FINAL")
             .fetch_optional()
             .await?;
         // max() returns 0 for empty table, so check if there are any rows
         if let Some(max) = result {
             if max == 0 {
-                let count: u64 = self.client
-                    .query("SELECT count() FROM round_addresses FINAL")
+                let count: u64 = self.query("SELECT count() FROM round_addresses FINAL")
                     .fetch_one()
                     .await?;
                 if count == 0 {
@@ -2680,8 +3019,7 @@ impl ClickHouseClient {
     /// Returns a list of round_ids that don't have an address mapping yet.
     pub async fn get_missing_round_address_ids(&self, max_round_id: u64) -> Result<Vec<u64>, ClickHouseError> {
         // Generate sequence from 1 to max_round_id and find gaps
-        let results: Vec<u64> = self.client
-            .query(r#"
+        let results: Vec<u64> = self.query(r#"
                 SELECT n.number + 1 as round_id
                 FROM numbers(?) n
                 WHERE NOT EXISTS (
@@ -2699,8 +3037,7 @@ impl ClickHouseClient {
     
     /// Check if all rounds from 1 to max_round_id have addresses.
     pub async fn all_round_addresses_complete(&self, max_round_id: u64) -> Result<bool, ClickHouseError> {
-        let count: u64 = self.client
-            .query("SELECT count() FROM round_addresses FINAL WHERE round_id <= ?")
+        let count: u64 = self.query("SELECT count() FROM round_addresses FINAL WHERE round_id <= ?")
             .bind(max_round_id)
             .fetch_one()
             .await?;
@@ -2711,8 +3048,7 @@ impl ClickHouseClient {
     
     /// Get current stats for a specific round
     pub async fn get_round_stats(&self, round_id: u64) -> Result<Option<RoundTransactionInfo>, ClickHouseError> {
-        let result: Option<RoundTransactionInfo> = self.client
-            .query(r#"
+        let result: Option<RoundTransactionInfo> = self.query(r#"
                 SELECT 
                     round_id,
                     sum(transaction_count) as transaction_count,
@@ -2737,8 +3073,7 @@ impl ClickHouseClient {
             max_slot: u64,
         }
         
-        let result: Option<StatsRow> = self.client
-            .query(r#"
+        let result: Option<StatsRow> = self.query(r#"
                 SELECT 
                     count() as count,
                     min(slot) as min_slot,
@@ -2766,8 +3101,7 @@ impl ClickHouseClient {
             max_slot: u64,
         }
         
-        let result: Option<StatsRow> = self.client
-            .query(r#"
+        let result: Option<StatsRow> = self.query(r#"
                 SELECT 
                     count() as count,
                     min(slot) as min_slot,
@@ -2797,8 +3131,7 @@ impl ClickHouseClient {
         min_slot: u64,
         max_slot: u64,
     ) -> Result<(), ClickHouseError> {
-        self.client
-            .query(r#"
+        self.query(r#"
                 INSERT INTO round_transaction_stats 
                 (round_id, address, transaction_count, min_slot, max_slot)
                 VALUES (?, ?, ?, ?, ?)
@@ -2817,7 +3150,7 @@ impl ClickHouseClient {
     
     /// Insert an automation state snapshot.
     pub async fn insert_automation_state(&self, state: AutomationStateInsert) -> Result<(), ClickHouseError> {
-        let mut insert = self.client.insert("automation_states")?;
+        let mut insert = self.insert(tables::AUTOMATION_STATES)?;
         insert.write(&state).await?;
         insert.end().await?;
         Ok(())
@@ -2829,7 +3162,7 @@ impl ClickHouseClient {
             return Ok(());
         }
         
-        let mut insert = self.client.insert("automation_states")?;
+        let mut insert = self.insert(tables::AUTOMATION_STATES)?;
         for s in states {
             insert.write(&s).await?;
         }
@@ -2890,7 +3223,7 @@ impl ClickHouseClient {
             conditions.join(" AND "), order, limit
         );
         
-        let results = self.client.query(&query).fetch_all().await?;
+        let results = self.query(&query).fetch_all().await?;
         Ok(results)
     }
     
@@ -2935,7 +3268,7 @@ impl ClickHouseClient {
             conditions.join(" AND "), limit
         );
         
-        let results = self.client.query(&query).fetch_all().await?;
+        let results = self.query(&query).fetch_all().await?;
         Ok(results)
     }
     
@@ -2945,6 +3278,7 @@ impl ClickHouseClient {
         round_id_gte: Option<u64>,
         round_id_lte: Option<u64>,
         miner: Option<&str>,
+        square: Option<u8>,
         winner_only: Option<bool>,
         min_sol_earned: Option<u64>,
         max_sol_earned: Option<u64>,
@@ -2964,6 +3298,10 @@ impl ClickHouseClient {
         if let Some(m) = miner {
             conditions.push(format!("d.miner_pubkey = '{}'", m));
         }
+        // Deployments are stored one row per square of the deploy mask
+        if let Some(sq) = square {
+            conditions.push(format!("d.square_id = {}", sq));
+        }
         if winner_only == Some(true) {
             conditions.push("d.is_winner = 1".to_string());
         }
@@ -3001,7 +3339,7 @@ impl ClickHouseClient {
             conditions.join(" AND "), limit
         );
         
-        let results = self.client.query(&query).fetch_all().await?;
+        let results = self.query(&query).fetch_all().await?;
         Ok(results)
     }
     
@@ -3060,7 +3398,7 @@ impl ClickHouseClient {
             conditions.join(" AND "), limit
         );
         
-        let results = self.client.query(&query).fetch_all().await?;
+        let results = self.query(&query).fetch_all().await?;
         Ok(results)
     }
     
@@ -3103,14 +3441,14 @@ impl ClickHouseClient {
             win_count: u64,
         }
         
-        let rows: Vec<SquareStatsRow> = self.client.query(&query).fetch_all().await?;
+        let rows: Vec<SquareStatsRow> = self.query(&query).fetch_all().await?;
         
         // Get total unique rounds for this miner in range
         let rounds_query = format!(
             "SELECT count(DISTINCT round_id) FROM deployments WHERE {}",
             conditions.join(" AND ").replace("d.", "")
         );
-        let total_rounds: u64 = self.client.query(&rounds_query).fetch_one().await?;
+        let total_rounds: u64 = self.query(&rounds_query).fetch_one().await?;
         
         // Initialize arrays for all 25 squares
         let mut square_counts = vec![0u64; 25];
@@ -3171,7 +3509,7 @@ impl ClickHouseClient {
             GROUP BY d.miner_pubkey
         "#, conditions.join(" AND "));
         
-        let row: Option<MinerStatsRow> = self.client.query(&query)
+        let row: Option<MinerStatsRow> = self.query(&query)
             .fetch_optional()
             .await?;
         
@@ -3201,7 +3539,142 @@ impl ClickHouseClient {
             }
         }))
     }
-    
+
+    /// Get how often an authority's deployments covered the winning square vs chance.
+    /// Each round counts once; the expected win probability for a round is
+    /// squares_covered / 25, so expected wins and variance are summed per round.
+    pub async fn get_authority_win_rate(
+        &self,
+        authority: &str,
+        round_id_gte: Option<u64>,
+        round_id_lte: Option<u64>,
+    ) -> Result<crate::historical_routes::AuthorityWinRate, ClickHouseError> {
+        let mut conditions = vec!["d.miner_pubkey = ?".to_string()];
+
+        if let Some(gte) = round_id_gte {
+            conditions.push(format!("d.round_id >= {}", gte));
+        }
+        if let Some(lte) = round_id_lte {
+            conditions.push(format!("d.round_id <= {}", lte));
+        }
+
+        // Inner join so only finalized rounds (with a known winning square) count
+        let query = format!(r#"
+            SELECT
+                count() as deploys,
+                toUInt64(sum(won)) as wins,
+                sum(squares / 25) as expected_wins,
+                sum((squares / 25) * (1 - squares / 25)) as variance
+            FROM (
+                SELECT
+                    d.round_id as round_id,
+                    toFloat64(uniqExact(d.square_id)) as squares,
+                    max(d.square_id = r.winning_square) as won
+                FROM deployments d
+                INNER JOIN rounds r ON d.round_id = r.round_id
+                WHERE {}
+                GROUP BY d.round_id
+            )
+        "#, conditions.join(" AND "));
+
+        #[derive(Debug, Clone, clickhouse::Row, serde::Deserialize)]
+        struct WinRateRow {
+            deploys: u64,
+            wins: u64,
+            expected_wins: f64,
+            variance: f64,
+        }
+
+        let row: WinRateRow = self.query(&query)
+            .bind(authority)
+            .fetch_one()
+            .await?;
+
+        let (win_rate, expected_win_rate) = if row.deploys > 0 {
+            (
+                row.wins as f64 / row.deploys as f64,
+                row.expected_wins / row.deploys as f64,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+
+        // Normal approximation to the sum of per-round Bernoulli trials
+        let z_score = if row.variance > 0.0 {
+            Some((row.wins as f64 - row.expected_wins) / row.variance.sqrt())
+        } else {
+            None
+        };
+
+        Ok(crate::historical_routes::AuthorityWinRate {
+            authority: authority.to_string(),
+            round_id_gte,
+            round_id_lte,
+            deploys: row.deploys,
+            wins: row.wins,
+            expected_wins: row.expected_wins,
+            win_rate,
+            expected_win_rate,
+            z_score,
+        })
+    }
+
+    /// Get an authority's rounds, newest first, each joined with the round's outcome.
+    /// One row per round: the deployment rows (one per square) are folded into a
+    /// squares mask and totals. `won` uses the same winning-square match as the
+    /// win rate; rounds that aren't finalized yet come back with `finalized = 0`.
+    /// `before` is the pagination cursor (exclusive round id).
+    pub async fn get_miner_timeline(
+        &self,
+        authority: &str,
+        round_id_gte: Option<u64>,
+        round_id_lte: Option<u64>,
+        before: Option<u64>,
+        limit: u32,
+    ) -> Result<Vec<MinerTimelineRow>, ClickHouseError> {
+        let mut conditions = vec!["d.miner_pubkey = ?".to_string()];
+
+        if let Some(gte) = round_id_gte {
+            conditions.push(format!("d.round_id >= {}", gte));
+        }
+        if let Some(lte) = round_id_lte {
+            conditions.push(format!("d.round_id <= {}", lte));
+        }
+        if let Some(before) = before {
+            conditions.push(format!("d.round_id < {}", before));
+        }
+
+        // Unmatched rows of the LEFT JOIN get defaults, so finalized = 0 marks a live round
+        let query = format!(r#"
+            SELECT
+                d.round_id as round_id,
+                toUInt32(groupBitOr(bitShiftLeft(toUInt32(1), d.square_id))) as squares_mask,
+                sum(d.amount) as amount,
+                min(d.deployed_slot) as deployed_slot,
+                sum(d.sol_earned) as sol_earned,
+                sum(d.ore_earned) as ore_earned,
+                max(d.is_top_miner) as is_top_miner,
+                any(r.finalized) as finalized,
+                any(r.winning_square) as winning_square,
+                toUInt8(max(r.finalized = 1 AND d.square_id = r.winning_square)) as won,
+                any(r.motherlode_hit) as motherlode_hit
+            FROM deployments d
+            LEFT JOIN (
+                SELECT round_id, winning_square, motherlode_hit, toUInt8(1) as finalized FROM rounds FINAL
+            ) r ON d.round_id = r.round_id
+            WHERE {}
+            GROUP BY d.round_id
+            ORDER BY d.round_id DESC
+            LIMIT {}
+        "#, conditions.join(" AND "), limit);
+
+        let rows = self.query(&query)
+            .bind(authority)
+            .fetch_all()
+            .await?;
+        Ok(rows)
+    }
+
     /// Get aggregate cost per ORE stats for a round range.
     /// Returns (total_rounds, total_vaulted_lamports, total_ore_minted, cost_per_ore_lamports).
     /// Cost per ORE = total_vaulted / (total_rounds + motherlode_ore)
@@ -3240,7 +3713,7 @@ impl ClickHouseClient {
             {}
         "#, where_clause);
         
-        let mut q = self.client.query(&query);
+        let mut q = self.query(&query);
         if let Some(gte) = round_id_gte {
             q = q.bind(gte);
         }
@@ -3349,7 +3822,7 @@ impl ClickHouseClient {
                 round_filter
             )
         };
-        let total_count: u64 = self.client.query(&count_query).fetch_one().await?;
+        let total_count: u64 = self.query(&count_query).fetch_one().await?;
         
         // Get leaderboard page with all metrics
         // Use prefixed aliases to avoid conflict with column names in value_expr
@@ -3371,7 +3844,7 @@ impl ClickHouseClient {
             value_expr, round_filter, having_clause, order, limit, offset
         );
         
-        let rows: Vec<LeaderboardRow> = self.client.query(&query).fetch_all().await?;
+        let rows: Vec<LeaderboardRow> = self.query(&query).fetch_all().await?;
         
         let entries: Vec<crate::historical_routes::LeaderboardEntry> = rows
             .into_iter()
@@ -3481,7 +3954,7 @@ impl ClickHouseClient {
                 search.replace("'", "''")
             )
         };
-        let total_count: u64 = self.client.query(&count_query).fetch_one().await?;
+        let total_count: u64 = self.query(&count_query).fetch_one().await?;
         
         // Use a subquery with row_number to get rankings, then filter
         let query = format!(
@@ -3529,7 +4002,7 @@ impl ClickHouseClient {
             rank: u64,
         }
         
-        let rows: Vec<FilteredLeaderboardRow> = self.client.query(&query).fetch_all().await?;
+        let rows: Vec<FilteredLeaderboardRow> = self.query(&query).fetch_all().await?;
         
         let entries: Vec<crate::historical_routes::LeaderboardEntry> = rows
             .into_iter()
@@ -3590,7 +4063,7 @@ impl ClickHouseClient {
             conditions.join(" AND "), limit
         );
         
-        let rows: Vec<TreasurySnapshotRow> = self.client.query(&query).fetch_all().await?;
+        let rows: Vec<TreasurySnapshotRow> = self.query(&query).fetch_all().await?;
         
         Ok(rows.into_iter().map(|r| crate::historical_routes::TreasurySnapshot {
             round_id: r.round_id,
@@ -3625,7 +4098,7 @@ impl ClickHouseClient {
             limit, offset
         );
         
-        let rows: Vec<(u64, i64)> = self.client.query(&query).fetch_all().await?;
+        let rows: Vec<(u64, i64)> = self.query(&query).fetch_all().await?;
         Ok(rows)
     }
     
@@ -3642,7 +4115,7 @@ impl ClickHouseClient {
             WHERE COALESCE(d.cnt, 0) = 0
         "#;
         
-        let count: u64 = self.client.query(query).fetch_one().await?;
+        let count: u64 = self.query(query).fetch_one().await?;
         Ok(count)
     }
 }
@@ -3659,6 +4132,8 @@ pub struct RequestLog {
     pub ip_hash: String,
     #[serde(default)]
     pub user_agent: String,
+    #[serde(default)]
+    pub request_id: String,
 }
 
 /// Server metrics snapshot.
@@ -3939,6 +4414,31 @@ pub struct PartialRound {
     pub failure_reason: String,
 }
 
+/// Entropy var state behind a finalized round's winning square.
+/// The var is reused across rounds, so this is read right after reset.
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct RoundEntropy {
+    pub round_id: u64,
+    
+    // Entropy var account
+    pub var_address: String,
+    pub provider: String,
+    pub commit: [u8; 32],
+    pub seed: [u8; 32],
+    pub slot_hash: [u8; 32],
+    pub value: [u8; 32],
+    pub samples: u64,
+    pub start_at: u64,
+    pub end_at: u64,
+    
+    // Round outcome
+    pub round_slot_hash: [u8; 32],
+    pub winning_square: u8,
+    
+    // Metadata
+    pub created_at: i64,
+}
+
 /// Deployment insert data.
 #[derive(Debug, Clone, Row, Serialize, Deserialize)]
 pub struct DeploymentInsert {
@@ -4012,6 +4512,13 @@ pub struct DeploymentRow {
     pub winning_square: u8,
 }
 
+/// A miner's total on a round's winning square.
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct WinningSquareStake {
+    pub miner_pubkey: String,
+    pub amount: u64,
+}
+
 /// Treasury snapshot.
 #[derive(Debug, Clone, Row, Serialize, Deserialize)]
 pub struct TreasurySnapshot {
@@ -4115,6 +4622,10 @@ pub struct RpcRequestInsert {
     pub rate_limit_remaining: i32,
     #[serde(default = "default_rate_limit")]
     pub rate_limit_reset: i32,
+    
+    // Provider credit cost
+    #[serde(default)]
+    pub credits: u32,
 }
 
 fn default_rate_limit() -> i32 {
@@ -4152,6 +4663,7 @@ impl RpcRequestInsert {
             response_size: 0,
             rate_limit_remaining: -1,
             rate_limit_reset: -1,
+            credits: 0,
         }
     }
     
@@ -4176,6 +4688,12 @@ impl RpcRequestInsert {
         self
     }
     
+    /// Set the provider credit cost of the request.
+    pub fn with_credits(mut self, credits: u32) -> Self {
+        self.credits = credits;
+        self
+    }
+    
     /// Set filter configuration as JSON string.
     pub fn with_filters(mut self, filters_json: impl Into<String>) -> Self {
         self.filters_json = filters_json.into();
@@ -4313,6 +4831,18 @@ pub struct AutomationStateInsert {
 // RPC Metrics Query Results
 // ============================================================================
 
+/// Stored round totals vs what the deployments table adds up to.
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct RoundConsistencyRow {
+    pub round_id: u64,
+    pub total_deployed: u64,
+    pub deployments_sum: u64,
+    pub total_deployments: u64,
+    pub deployments_count: u64,
+    pub unique_miners: u64,
+    pub distinct_miners: u64,
+}
+
 /// Summary row for RPC metrics (grouped by program, provider, method, target_type).
 #[derive(Debug, Clone, Row, Serialize, Deserialize)]
 pub struct RpcSummaryRow {
@@ -4424,6 +4954,17 @@ pub struct RpcDailyRow {
     pub unique_methods: u64,
 }
 
+/// Provider credits consumed per day and method.
+/// Note: day is Date - u16 days since epoch (1970-01-01)
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct RpcCreditsRow {
+    pub day: u16,  // Date → days since 1970-01-01
+    pub provider: String,
+    pub method: String,
+    pub total_requests: u64,
+    pub total_credits: u64,
+}
+
 // ============================================================================
 // WebSocket Metrics Types
 // ============================================================================
@@ -4519,6 +5060,7 @@ pub struct RequestLogRow {
     pub duration_ms: u32,
     pub ip_hash: String,
     pub user_agent: String,
+    pub request_id: String,
 }
 
 /// Query result for request log summary by endpoint
@@ -4673,6 +5215,22 @@ pub struct MinerStatsRow {
     pub avg_slots_left: f64,
 }
 
+/// One round of an authority's timeline.
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct MinerTimelineRow {
+    pub round_id: u64,
+    pub squares_mask: u32,
+    pub amount: u64,
+    pub deployed_slot: u64,
+    pub sol_earned: u64,
+    pub ore_earned: u64,
+    pub is_top_miner: u8,
+    pub finalized: u8,
+    pub winning_square: u8,
+    pub won: u8,
+    pub motherlode_hit: u8,
+}
+
 /// Leaderboard row.
 #[derive(Debug, Clone, Row, Serialize, Deserialize)]
 pub struct LeaderboardRow {
@@ -4734,6 +5292,35 @@ pub struct RoundsDailyRow {
     pub total_motherlode: u64,
 }
 
+/// One day of `get_daily_aggregates`.
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct DailyAggregateRow {
+    pub day: u16,  // Date as days since 1970-01-01
+    pub rounds_finalized: u64,
+    pub total_deployed: u64,
+    /// Top miner rewards plus motherlodes hit (atomic units, 11 decimals)
+    pub ore_minted: u64,
+    /// Distinct miners that deployed in the day's rounds
+    pub unique_miners: u64,
+    pub motherlode_hits: u64,
+}
+
+/// `get_automation_strategy_usage` strategy for deploys made without an active automation
+pub const MANUAL_STRATEGY: u8 = u8::MAX;
+
+/// Deployments for one strategy in one round or day of `get_automation_strategy_usage`.
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct AutomationStrategyUsageRow {
+    /// Round id, or days since 1970-01-01
+    pub period: u64,
+    /// `AutomationStrategy` value, or `MANUAL_STRATEGY`
+    pub strategy: u8,
+    pub deployments: u64,
+    pub miners: u64,
+    /// Lamports
+    pub total_sol_spent: u64,
+}
+
 /// Treasury hourly chart data.
 #[derive(Debug, Clone, Row, Serialize, Deserialize)]
 pub struct TreasuryHourlyRow {
@@ -4894,10 +5481,56 @@ mod tests {
             duration_ms: 15,
             ip_hash: "abc123".to_string(),
             user_agent: "test-agent".to_string(),
+            request_id: "0f3a".to_string(),
         };
         
         // Just verify it serializes without error
         let _ = serde_json::to_string(&log).unwrap();
     }
+    
+    #[test]
+    fn test_timeout_error_mapping() {
+        let timeout = clickhouse::error::Error::BadResponse(
+            "Code: 159. DB::Exception: Timeout exceeded: elapsed 30.01 seconds, maximum: 30. (TIMEOUT_EXCEEDED)".to_string(),
+        );
+        assert!(ClickHouseError::from(timeout).is_timeout());
+        assert!(ClickHouseError::from(clickhouse::error::Error::TimedOut).is_timeout());
+        
+        let other = clickhouse::error::Error::BadResponse("Code: 60. DB::Exception: Table doesn't exist".to_string());
+        assert!(!ClickHouseError::from(other).is_timeout());
+    }
+    
+    #[test]
+    fn test_prefix_tables() {
+        let sql = "SELECT count() FROM rounds WHERE round_id = ?";
+        assert!(matches!(prefix_tables("", sql), Cow::Borrowed(_)));
+        assert_eq!(prefix_tables("b_", sql), "SELECT count() FROM b_rounds WHERE round_id = ?");
+        
+        let sql = r#"
+            SELECT r.round_id, count() AS deployments
+            FROM ore_stats.rounds r FINAL
+            LEFT JOIN
+                deployments d ON d.round_id = r.round_id
+            WHERE r.round_id IN (SELECT round_id FROM partial_rounds)
+        "#;
+        let expected = r#"
+            SELECT r.round_id, count() AS deployments
+            FROM ore_stats.b_rounds r FINAL
+            LEFT JOIN
+                b_deployments d ON d.round_id = r.round_id
+            WHERE r.round_id IN (SELECT round_id FROM b_partial_rounds)
+        "#;
+        assert_eq!(prefix_tables("b_", sql), expected);
+        
+        // Only table positions are rewritten; subqueries, system tables and string literals are left alone
+        assert_eq!(
+            prefix_tables("b_", "ALTER TABLE deployments DELETE WHERE round_id IN (SELECT round_id FROM system.parts WHERE table = 'rounds')"),
+            "ALTER TABLE b_deployments DELETE WHERE round_id IN (SELECT round_id FROM system.parts WHERE table = 'rounds')",
+        );
+        assert_eq!(
+            prefix_tables("b_", "INSERT INTO round_addresses SELECT * FROM (SELECT 1)"),
+            "INSERT INTO b_round_addresses SELECT * FROM (SELECT 1)",
+        );
+    }
 }
 
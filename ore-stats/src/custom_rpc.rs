@@ -2,6 +2,10 @@
 //!
 //! Same method names, same parameters, same return structure.
 //! Only difference: response has `.response_size` with the raw encoded response bytes.
+//!
+//! `RPC_HEADERS` (a JSON object, e.g. `{"x-token":"..."}`) adds headers to every
+//! HTTP RPC request made through `http_client`. Websocket subscriptions use
+//! Solana's PubsubClient, which only takes a URL, so ws auth must go in the URL.
 
 use anyhow::Result;
 use base64::Engine;
@@ -10,6 +14,47 @@ use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
 use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
 use solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature;
 use solana_sdk::{account::Account, pubkey::Pubkey, signature::Signature};
+use std::sync::OnceLock;
+
+static RPC_HEADERS: OnceLock<reqwest::header::HeaderMap> = OnceLock::new();
+
+/// Parse `RPC_HEADERS` (a JSON object of header name -> value)
+pub fn parse_rpc_headers(json: &str) -> Result<reqwest::header::HeaderMap, String> {
+    let mut headers = reqwest::header::HeaderMap::new();
+    if json.trim().is_empty() {
+        return Ok(headers);
+    }
+    let map: serde_json::Map<String, serde_json::Value> = serde_json::from_str(json)
+        .map_err(|e| format!("RPC_HEADERS must be a JSON object: {}", e))?;
+    for (key, value) in map {
+        let value = match value {
+            serde_json::Value::String(s) => s,
+            other => other.to_string(),
+        };
+        let name = reqwest::header::HeaderName::from_bytes(key.as_bytes())
+            .map_err(|_| format!("Invalid RPC header name '{}'", key))?;
+        let value = reqwest::header::HeaderValue::from_str(&value)
+            .map_err(|_| format!("Invalid value for RPC header '{}'", key))?;
+        headers.insert(name, value);
+    }
+    Ok(headers)
+}
+
+/// Use these headers for every RPC HTTP client built afterwards
+pub fn set_rpc_headers(headers: reqwest::header::HeaderMap) {
+    let _ = RPC_HEADERS.set(headers);
+}
+
+/// HTTP client for RPC requests, carrying the configured `RPC_HEADERS`
+pub fn http_client() -> reqwest::Client {
+    match RPC_HEADERS.get() {
+        Some(headers) if !headers.is_empty() => reqwest::Client::builder()
+            .default_headers(headers.clone())
+            .build()
+            .expect("Failed to create RPC HTTP client"),
+        _ => reqwest::Client::new(),
+    }
+}
 
 /// Response wrapper - same as solana's Response but with response_size added
 #[derive(Debug)]
@@ -40,7 +85,7 @@ impl CustomRpcClient {
         
         Self {
             url: normalized_url,
-            client: reqwest::Client::new(),
+            client: http_client(),
         }
     }
     
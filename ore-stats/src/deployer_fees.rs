@@ -0,0 +1,406 @@
+//! Deployer fee history - How a deployer's fees changed over time
+//!
+//! Replays the CreateDeployer / UpdateDeployer instructions that touched a
+//! deployer account, oldest first. UpdateDeployer always carries every field,
+//! but the program only applies the manager's half (expected fees and
+//! max_per_round) when the manager authority signs, and the deploy
+//! authority's half (the fees actually charged) when the deploy authority
+//! signs. The signer's role is worked out from the authorities tracked along
+//! the way, so each entry is the deployer's full state after that change.
+//!
+//! Every autodeploy also touches the deployer account, so the scan reads a
+//! lot of transactions to find a few changes; it is bounded by the Helius
+//! scan limit and reports when it stopped early.
+
+use evore::instruction::{CreateDeployer, Instructions, UpdateDeployer};
+use serde::Serialize;
+use serde_json::Value;
+use solana_sdk::bs58;
+use steel::Pubkey;
+
+use crate::helius_api::{HeliusApi, HeliusError};
+
+/// Who an UpdateDeployer signer acted as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SetBy {
+    Manager,
+    DeployAuthority,
+    Both,
+}
+
+/// Deployer state right after one fee-setting instruction
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeployerFeeChange {
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub signature: String,
+    /// "create" or "update"
+    pub kind: &'static str,
+    pub signer: String,
+    pub set_by: SetBy,
+    pub deploy_authority: String,
+    /// Fee actually charged (set by the deploy authority)
+    pub bps_fee: u64,
+    pub flat_fee: u64,
+    /// Max fee the manager accepts (set by the manager)
+    pub expected_bps_fee: u64,
+    pub expected_flat_fee: u64,
+    pub max_per_round: u64,
+    /// Actual fees are within what the manager accepts; autodeploys fail otherwise
+    pub within_expected: bool,
+}
+
+/// A decoded CreateDeployer / UpdateDeployer for the deployer being replayed
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeployerIx {
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub signature: String,
+    pub signer: Pubkey,
+    /// deploy_authority account passed to the instruction
+    pub deploy_authority: Pubkey,
+    pub kind: DeployerIxKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeployerIxKind {
+    Create {
+        bps_fee: u64,
+        flat_fee: u64,
+        max_per_round: u64,
+    },
+    Update {
+        bps_fee: u64,
+        flat_fee: u64,
+        expected_bps_fee: u64,
+        expected_flat_fee: u64,
+        max_per_round: u64,
+    },
+}
+
+/// Result of scanning a deployer's transaction history
+#[derive(Debug, Default)]
+pub struct DeployerHistoryScan {
+    pub instructions: Vec<DeployerIx>,
+    pub txns_searched: u32,
+    /// Stopped at the scan limit; later changes are missing
+    pub truncated: bool,
+}
+
+/// Decode the deployer instructions (top-level and inner) in one transaction
+/// that target `deployer`
+pub fn decode_deployer_ixs(tx: &Value, deployer: &Pubkey) -> Vec<DeployerIx> {
+    let mut found = Vec::new();
+    let slot = tx.get("slot").and_then(Value::as_u64).unwrap_or(0);
+    let block_time = tx.get("blockTime").and_then(Value::as_i64);
+    let signature = tx
+        .get("transaction")
+        .and_then(|t| t.get("signatures"))
+        .and_then(|s| s.get(0))
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+
+    // Static keys, then lookup-table writable / readonly
+    let mut account_keys: Vec<Pubkey> = Vec::new();
+    let key_lists = [
+        tx.pointer("/transaction/message/accountKeys"),
+        tx.pointer("/meta/loadedAddresses/writable"),
+        tx.pointer("/meta/loadedAddresses/readonly"),
+    ];
+    for keys in key_lists.into_iter().flatten().filter_map(Value::as_array) {
+        for key in keys {
+            let s = key.get("pubkey").and_then(Value::as_str).or_else(|| key.as_str());
+            match s.and_then(|s| s.parse::<Pubkey>().ok()) {
+                Some(pk) => account_keys.push(pk),
+                None => return found,
+            }
+        }
+    }
+
+    let outer = tx
+        .pointer("/transaction/message/instructions")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten();
+    let inner = tx
+        .pointer("/meta/innerInstructions")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|i| i.get("instructions").and_then(Value::as_array))
+        .flatten();
+
+    for ix in outer.chain(inner) {
+        let key = |i: usize| -> Option<Pubkey> {
+            let index = ix.get("accounts")?.as_array()?.get(i)?.as_u64()? as usize;
+            account_keys.get(index).copied()
+        };
+        let program_id = ix
+            .get("programIdIndex")
+            .and_then(Value::as_u64)
+            .and_then(|i| account_keys.get(i as usize));
+        if program_id != Some(&evore::id()) {
+            continue;
+        }
+        let Some(data) = ix
+            .get("data")
+            .and_then(Value::as_str)
+            .and_then(|d| bs58::decode(d).into_vec().ok())
+        else {
+            continue;
+        };
+        // Accounts: signer, manager, deployer, deploy_authority, system program
+        let (Some(signer), Some(ix_deployer), Some(deploy_authority)) = (key(0), key(2), key(3)) else {
+            continue;
+        };
+        if ix_deployer != *deployer || data.is_empty() {
+            continue;
+        }
+
+        let body = &data[1..];
+        let kind = if data[0] == Instructions::CreateDeployer as u8 {
+            match body.get(..std::mem::size_of::<CreateDeployer>()) {
+                Some(bytes) => {
+                    let args: &CreateDeployer = bytemuck::from_bytes(bytes);
+                    DeployerIxKind::Create {
+                        bps_fee: u64::from_le_bytes(args.bps_fee),
+                        flat_fee: u64::from_le_bytes(args.flat_fee),
+                        max_per_round: u64::from_le_bytes(args.max_per_round),
+                    }
+                }
+                None => continue,
+            }
+        } else if data[0] == Instructions::UpdateDeployer as u8 {
+            match body.get(..std::mem::size_of::<UpdateDeployer>()) {
+                Some(bytes) => {
+                    let args: &UpdateDeployer = bytemuck::from_bytes(bytes);
+                    DeployerIxKind::Update {
+                        bps_fee: u64::from_le_bytes(args.bps_fee),
+                        flat_fee: u64::from_le_bytes(args.flat_fee),
+                        expected_bps_fee: u64::from_le_bytes(args.expected_bps_fee),
+                        expected_flat_fee: u64::from_le_bytes(args.expected_flat_fee),
+                        max_per_round: u64::from_le_bytes(args.max_per_round),
+                    }
+                }
+                None => continue,
+            }
+        } else {
+            continue;
+        };
+
+        found.push(DeployerIx {
+            slot,
+            block_time,
+            signature: signature.clone(),
+            signer,
+            deploy_authority,
+            kind,
+        });
+    }
+    found
+}
+
+/// Apply deployer instructions (oldest first) the way the program does
+///
+/// The manager authority is the CreateDeployer signer; an update signed by
+/// anyone but the deploy authority must have come from the manager authority
+/// (only successful transactions are scanned), which also follows manager
+/// transfers. If the history doesn't start at CreateDeployer, state starts
+/// zeroed and unknown signers are taken to be the manager.
+pub fn replay(instructions: &[DeployerIx]) -> Vec<DeployerFeeChange> {
+    let mut manager_authority: Option<Pubkey> = None;
+    let mut deploy_authority: Option<Pubkey> = None;
+    let (mut bps_fee, mut flat_fee) = (0u64, 0u64);
+    let (mut expected_bps_fee, mut expected_flat_fee, mut max_per_round) = (0u64, 0u64, 0u64);
+
+    let mut changes = Vec::with_capacity(instructions.len());
+    for ix in instructions {
+        let (kind, set_by) = match ix.kind {
+            DeployerIxKind::Create { bps_fee: bps, flat_fee: flat, max_per_round: max } => {
+                // Actual fees start at the expected max
+                manager_authority = Some(ix.signer);
+                deploy_authority = Some(ix.deploy_authority);
+                (bps_fee, flat_fee) = (bps, flat);
+                (expected_bps_fee, expected_flat_fee, max_per_round) = (bps, flat, max);
+                ("create", SetBy::Manager)
+            }
+            DeployerIxKind::Update {
+                bps_fee: bps,
+                flat_fee: flat,
+                expected_bps_fee: expected_bps,
+                expected_flat_fee: expected_flat,
+                max_per_round: max,
+            } => {
+                let is_deploy_authority = deploy_authority == Some(ix.signer);
+                let is_manager = manager_authority == Some(ix.signer) || !is_deploy_authority;
+                if is_manager {
+                    manager_authority = Some(ix.signer);
+                    (expected_bps_fee, expected_flat_fee, max_per_round) = (expected_bps, expected_flat, max);
+                }
+                if is_deploy_authority {
+                    (bps_fee, flat_fee) = (bps, flat);
+                }
+                deploy_authority = Some(ix.deploy_authority);
+                let set_by = match (is_manager, is_deploy_authority) {
+                    (true, true) => SetBy::Both,
+                    (true, false) => SetBy::Manager,
+                    _ => SetBy::DeployAuthority,
+                };
+                ("update", set_by)
+            }
+        };
+
+        changes.push(DeployerFeeChange {
+            slot: ix.slot,
+            block_time: ix.block_time,
+            signature: ix.signature.clone(),
+            kind,
+            signer: ix.signer.to_string(),
+            set_by,
+            deploy_authority: ix.deploy_authority.to_string(),
+            bps_fee,
+            flat_fee,
+            expected_bps_fee,
+            expected_flat_fee,
+            max_per_round,
+            within_expected: bps_fee <= expected_bps_fee && flat_fee <= expected_flat_fee,
+        });
+    }
+    changes
+}
+
+/// Collect a deployer's fee-setting instructions, oldest first
+pub async fn scan_deployer_history(
+    helius: &HeliusApi,
+    deployer: &Pubkey,
+) -> Result<DeployerHistoryScan, HeliusError> {
+    let mut scan = DeployerHistoryScan::default();
+    let mut pages = 0u32;
+    let mut pagination_token: Option<String> = None;
+
+    loop {
+        let page = helius
+            .get_transactions_for_address(deployer, pagination_token.clone(), Some(100), Some("asc"), None, None)
+            .await?;
+        pages += 1;
+        scan.txns_searched += page.transactions.len() as u32;
+
+        for tx in &page.transactions {
+            scan.instructions.extend(decode_deployer_ixs(tx, deployer));
+        }
+
+        pagination_token = page.pagination_token;
+        if pagination_token.is_none() || page.transactions.is_empty() {
+            break;
+        }
+        if helius.scan_limit().reached(pages, scan.txns_searched) {
+            scan.truncated = true;
+            break;
+        }
+    }
+    Ok(scan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn ix(signer: Pubkey, deploy_authority: Pubkey, kind: DeployerIxKind) -> DeployerIx {
+        DeployerIx {
+            slot: 1,
+            block_time: None,
+            signature: String::new(),
+            signer,
+            deploy_authority,
+            kind,
+        }
+    }
+
+    fn update(bps_fee: u64, expected_bps_fee: u64) -> DeployerIxKind {
+        DeployerIxKind::Update {
+            bps_fee,
+            flat_fee: 0,
+            expected_bps_fee,
+            expected_flat_fee: 0,
+            max_per_round: 0,
+        }
+    }
+
+    #[test]
+    fn test_replay() {
+        let manager = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let changes = replay(&[
+            ix(manager, operator, DeployerIxKind::Create { bps_fee: 500, flat_fee: 10, max_per_round: 0 }),
+            // Operator lowers its fee; the expected fields it passes are ignored
+            ix(operator, operator, update(300, 9_999)),
+            // Manager raises the cap; the actual fee it passes is ignored
+            ix(manager, operator, update(9_999, 800)),
+            // Operator raises its fee above the cap
+            ix(operator, operator, update(1_000, 0)),
+        ]);
+
+        let fees: Vec<_> = changes.iter()
+            .map(|c| (c.kind, c.set_by, c.bps_fee, c.expected_bps_fee, c.within_expected))
+            .collect();
+        assert_eq!(fees, vec![
+            ("create", SetBy::Manager, 500, 500, true),
+            ("update", SetBy::DeployAuthority, 300, 500, true),
+            ("update", SetBy::Manager, 300, 800, true),
+            ("update", SetBy::DeployAuthority, 1_000, 800, false),
+        ]);
+        assert_eq!(changes[0].flat_fee, 10);
+
+        // A manager that is also the deploy authority sets both halves
+        let changes = replay(&[
+            ix(manager, manager, DeployerIxKind::Create { bps_fee: 100, flat_fee: 0, max_per_round: 0 }),
+            ix(manager, manager, update(50, 200)),
+        ]);
+        assert_eq!((changes[1].set_by, changes[1].bps_fee, changes[1].expected_bps_fee), (SetBy::Both, 50, 200));
+    }
+
+    #[test]
+    fn test_decode_deployer_ixs() {
+        let manager = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let deployer = evore::state::deployer_pda(manager).0;
+        let update = evore::instruction::update_deployer(signer, manager, operator, 1, 2, 3, 4, 5);
+
+        let keys = [signer, manager, deployer, operator, solana_sdk::system_program::id(), evore::id()];
+        let tx = json!({
+            "slot": 42,
+            "blockTime": 1_700_000_000,
+            "transaction": {
+                "signatures": ["sig"],
+                "message": {
+                    "accountKeys": keys.iter().map(|k| k.to_string()).collect::<Vec<_>>(),
+                    "instructions": [{
+                        "programIdIndex": 5,
+                        "accounts": [0, 1, 2, 3, 4],
+                        "data": bs58::encode(&update.data).into_string(),
+                    }],
+                },
+            },
+            "meta": { "innerInstructions": [] },
+        });
+
+        let found = decode_deployer_ixs(&tx, &deployer);
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].slot, found[0].block_time, found[0].signature.as_str()), (42, Some(1_700_000_000), "sig"));
+        assert_eq!((found[0].signer, found[0].deploy_authority), (signer, operator));
+        assert_eq!(found[0].kind, DeployerIxKind::Update {
+            bps_fee: 1,
+            flat_fee: 2,
+            expected_bps_fee: 3,
+            expected_flat_fee: 4,
+            max_per_round: 5,
+        });
+
+        // Someone else's deployer
+        assert!(decode_deployer_ixs(&tx, &Pubkey::new_unique()).is_empty());
+    }
+}
@@ -1,7 +1,8 @@
 //! EVORE Account API Routes (Phase 1b)
 //!
 //! Endpoints for reading EVORE program accounts (Managers, Deployers)
-//! Note: Auth balances are NOT cached - frontend fetches them manually via /balance/{pubkey}
+//! Note: Auth balances are NOT part of the account cache - frontend fetches them
+//! via /evore/balances (short-TTL cached) or /balance/{pubkey}
 //! Note: refined_ore is already calculated when miners are cached,
 //! so no additional calculation is needed when serving data.
 
@@ -9,15 +10,22 @@ use std::sync::Arc;
 
 use axum::{
     extract::{Path, Query, State},
-    routing::get,
+    routing::{get, post},
     Json, Router,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::app_error::AppError;
 use crate::app_state::AppState;
+use crate::automation_states::DeploymentAutomationStateRow;
+use crate::clickhouse::MinerTimelineRow;
+use crate::deployer_fees::{self, DeployerFeeChange};
 use crate::evore_cache::{
     AutoMinerInfo, CachedDeployer, CachedManager, EvoreCacheStats, MinerInfo,
 };
+use crate::historical_routes::CursorResponse;
+use crate::tx_analyzer::{BalanceChange, OreDeploymentInfo, TransactionAnalyzer};
+use evore::processor::process_mm_deploy::profit_fraction_fixed_s;
 
 // ============================================================================
 // Query Parameters
@@ -29,6 +37,52 @@ pub struct PaginationQuery {
     pub offset: Option<usize>,
 }
 
+/// Body for POST /evore/simulate-deploy
+#[derive(Debug, Deserialize)]
+pub struct SimulateDeployRequest {
+    pub round_id: u64,
+    /// Square index (0-24)
+    pub square: u8,
+    /// Stake in lamports
+    pub amount: u64,
+    /// Value of 1 ORE in lamports (for the ORE part of the EV)
+    pub ore_value: u64,
+}
+
+/// Query for GET /evore/contrarian
+#[derive(Debug, Deserialize)]
+pub struct ContrarianQuery {
+    /// Must match the live round when given
+    pub round_id: Option<u64>,
+    /// Stake in lamports
+    pub amount: u64,
+}
+
+/// Max pubkeys per GET /evore/balances request
+const MAX_BALANCE_PUBKEYS: usize = 100;
+
+#[derive(Debug, Deserialize)]
+pub struct BalancesQuery {
+    /// Comma-separated pubkeys
+    pub pubkeys: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeploymentLookupQuery {
+    /// Fetch and parse the transaction via RPC if it isn't stored (default: true)
+    pub fallback: Option<bool>,
+}
+
+/// Query for GET /evore/miner/{authority}/timeline (round id range, inclusive)
+#[derive(Debug, Deserialize)]
+pub struct TimelineQuery {
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    /// Round id from the previous page's `cursor`
+    pub cursor: Option<u64>,
+    pub limit: Option<u32>,
+}
+
 // ============================================================================
 // Response Types
 // ============================================================================
@@ -45,6 +99,19 @@ pub struct DeployersResponse {
     pub total: usize,
 }
 
+#[derive(Debug, Serialize)]
+pub struct DeployerFeeHistoryResponse {
+    pub manager: String,
+    pub deployer: String,
+    /// Current on-chain state from the account cache (None if not cached)
+    pub current: Option<CachedDeployer>,
+    /// Deployer state after each create/update, oldest first
+    pub changes: Vec<DeployerFeeChange>,
+    pub txns_searched: u32,
+    /// The history scan hit its limit; later changes are missing
+    pub truncated: bool,
+}
+
 #[derive(Debug, Serialize)]
 pub struct MyMinersResponse {
     pub authority: String,
@@ -52,8 +119,95 @@ pub struct MyMinersResponse {
 }
 
 #[derive(Debug, Serialize)]
-pub struct ErrorResponse {
-    pub error: String,
+pub struct DeploymentBySignatureResponse {
+    pub signature: String,
+    /// "clickhouse" if the transaction was stored, "rpc" if fetched on the fly
+    pub source: String,
+    pub slot: u64,
+    pub block_time: i64,
+    /// Parsed ORE deploy instructions (top-level and inner)
+    pub deployments: Vec<OreDeploymentInfo>,
+    /// Lamport deltas per account from pre/post balances
+    pub balance_changes: Vec<BalanceChange>,
+    /// Stored per-instruction deployment state (empty if never reconstructed)
+    pub stored: Vec<DeploymentAutomationStateRow>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SimulateDeployResponse {
+    pub round_id: u64,
+    pub square: u8,
+    pub amount: u64,
+    /// Square total before the simulated stake (lamports)
+    pub square_deployed: u64,
+    /// Round total before the simulated stake (lamports)
+    pub total_deployed: u64,
+    /// Fraction of the square owned after the stake (0.0 - 1.0)
+    pub share: f64,
+    /// Expected SOL profit in lamports (negative = -EV)
+    pub expected_sol: i64,
+    /// Expected ORE in atomic units (base 1 ORE reward only, no motherlode)
+    pub expected_ore: u64,
+    /// Expected profit in lamports including ORE valued at `ore_value`
+    pub expected_total: i64,
+}
+
+/// One square of GET /evore/contrarian
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ContrarianSquare {
+    pub square: u8,
+    /// Square total before the stake (lamports)
+    pub deployed: u64,
+    /// Miners already on the square
+    pub miners: u64,
+    /// Fraction of the square's split owned after the stake, if it wins (0.0 - 1.0)
+    pub share: f64,
+    /// That share of the winning square's 1 ORE, in atomic units
+    pub ore_if_win: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ContrarianResponse {
+    pub round_id: u64,
+    pub amount: u64,
+    /// Squares by share of the split if they win, best first
+    pub squares: Vec<ContrarianSquare>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AccountBalance {
+    pub pubkey: String,
+    /// None if the account doesn't exist
+    pub lamports: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BalancesResponse {
+    pub balances: Vec<AccountBalance>,
+}
+
+/// One round of GET /evore/miner/{authority}/timeline
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TimelineEntry {
+    pub round_id: u64,
+    /// Squares deployed to, ascending
+    pub squares: Vec<u8>,
+    /// Total deployed across those squares (lamports)
+    pub amount: u64,
+    /// Slot of the first deployment in the round
+    pub deployed_slot: u64,
+    /// None until the round is finalized
+    pub winning_square: Option<u8>,
+    /// None until the round is finalized
+    pub won: Option<bool>,
+    /// SOL payout (lamports)
+    pub sol_earned: u64,
+    /// ORE payout (atomic units)
+    pub ore_earned: u64,
+    /// sol_earned - amount
+    pub net_sol: i64,
+    pub top_miner: bool,
+    pub motherlode_hit: bool,
 }
 
 // ============================================================================
@@ -73,9 +227,27 @@ pub fn evore_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
         .route("/deployers/by-manager/{pubkey}", get(get_deployer_by_manager))
         .route("/deployers/by-authority/{pubkey}", get(get_deployers_by_authority))
         
+        // Fee changes reconstructed from the deployer's transaction history
+        .route("/deployer/{manager}/fee-history", get(get_deployer_fee_history))
+        
+        // Batched SOL balances (auth PDAs etc), cached for a few seconds
+        .route("/balances", get(get_balances))
+        
+        // EV what-if against the live round
+        .route("/simulate-deploy", post(simulate_deploy))
+        
+        // Squares with the biggest split share if they win (not EV)
+        .route("/contrarian", get(get_contrarian))
+        
+        // Single deployment lookup for debugging
+        .route("/deployment/{signature}", get(get_deployment_by_signature))
+        
         // Combined endpoint for frontend optimization
         .route("/my-miners/{authority}", get(get_my_miners))
         
+        // Per-round deploys and outcomes for a miner profile
+        .route("/miner/{authority}/timeline", get(get_miner_timeline))
+        
         // Cache stats
         .route("/stats", get(get_evore_stats))
         
@@ -112,14 +284,14 @@ async fn get_all_managers(
 async fn get_manager(
     State(state): State<Arc<AppState>>,
     Path(pubkey): Path<String>,
-) -> Result<Json<CachedManager>, Json<ErrorResponse>> {
+) -> Result<Json<CachedManager>, AppError> {
     let cache = state.evore_cache.read().await;
     
     cache.managers
         .get(&pubkey)
         .cloned()
         .map(Json)
-        .ok_or_else(|| Json(ErrorResponse { error: "Manager not found".to_string() }))
+        .ok_or_else(|| AppError::NotFound("Manager not found".to_string()))
 }
 
 /// GET /evore/managers/by-authority/{pubkey} - Managers owned by authority
@@ -170,27 +342,27 @@ async fn get_all_deployers(
 async fn get_deployer(
     State(state): State<Arc<AppState>>,
     Path(pubkey): Path<String>,
-) -> Result<Json<CachedDeployer>, Json<ErrorResponse>> {
+) -> Result<Json<CachedDeployer>, AppError> {
     let cache = state.evore_cache.read().await;
     
     cache.deployers
         .get(&pubkey)
         .cloned()
         .map(Json)
-        .ok_or_else(|| Json(ErrorResponse { error: "Deployer not found".to_string() }))
+        .ok_or_else(|| AppError::NotFound("Deployer not found".to_string()))
 }
 
 /// GET /evore/deployers/by-manager/{pubkey} - Deployer for a manager
 async fn get_deployer_by_manager(
     State(state): State<Arc<AppState>>,
     Path(pubkey): Path<String>,
-) -> Result<Json<CachedDeployer>, Json<ErrorResponse>> {
+) -> Result<Json<CachedDeployer>, AppError> {
     let cache = state.evore_cache.read().await;
     
     cache.get_deployer_for_manager(&pubkey)
         .cloned()
         .map(Json)
-        .ok_or_else(|| Json(ErrorResponse { error: "Deployer not found for manager".to_string() }))
+        .ok_or_else(|| AppError::NotFound("Deployer not found for manager".to_string()))
 }
 
 /// GET /evore/deployers/by-authority/{pubkey} - Deployers for authority's managers
@@ -213,6 +385,270 @@ async fn get_deployers_by_authority(
     })
 }
 
+/// GET /evore/deployer/{manager}/fee-history - How a deployer's fees changed
+/// 
+/// Replays every CreateDeployer / UpdateDeployer for the manager's deployer
+/// from its transaction history (Helius), so users can see whether the
+/// operator has raised fees before and how the fee compares to the cap the
+/// manager accepts. Uncached; each call rescans the history.
+async fn get_deployer_fee_history(
+    State(state): State<Arc<AppState>>,
+    Path(manager): Path<String>,
+) -> Result<Json<DeployerFeeHistoryResponse>, AppError> {
+    let manager_key = manager.parse::<steel::Pubkey>()
+        .map_err(|_| AppError::BadRequest("Invalid manager pubkey".to_string()))?;
+    let deployer = evore::state::deployer_pda(manager_key).0;
+    
+    let scan = deployer_fees::scan_deployer_history(&state.helius, &deployer)
+        .await
+        .map_err(|e| AppError::BadGateway(format!("Helius error: {}", e)))?;
+    
+    let current = state.evore_cache.read().await.deployers.get(&deployer.to_string()).cloned();
+    
+    Ok(Json(DeployerFeeHistoryResponse {
+        manager,
+        deployer: deployer.to_string(),
+        current,
+        changes: deployer_fees::replay(&scan.instructions),
+        txns_searched: scan.txns_searched,
+        truncated: scan.truncated,
+    }))
+}
+
+// ============================================================================
+// Deploy Simulation
+// ============================================================================
+
+/// 1 ORE in atomic units (minted to the winning square each round)
+const ONE_ORE: u64 = 100_000_000_000;
+
+/// POST /evore/simulate-deploy - EV of adding a stake to a square in the live round
+/// 
+/// Read-only: evaluates the stake against the live (not finalized) round
+/// deployment vector using the same EV math as the on-chain deploy.
+async fn simulate_deploy(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<SimulateDeployRequest>,
+) -> Result<Json<SimulateDeployResponse>, AppError> {
+    if req.square >= 25 {
+        return Err(AppError::BadRequest("square must be 0-24".to_string()));
+    }
+    if req.amount == 0 {
+        return Err(AppError::BadRequest("amount must be greater than 0".to_string()));
+    }
+    
+    let round = state.round_cache.read().await.clone().ok_or_else(|| {
+        AppError::Unavailable("Live round not loaded yet".to_string())
+    })?;
+    
+    if round.round_id != req.round_id {
+        return Err(AppError::Conflict(format!("Round {} is not live (current round is {})", req.round_id, round.round_id)));
+    }
+    
+    let square_deployed = round.deployed[req.square as usize];
+    let total_deployed: u64 = round.deployed.iter().sum();
+    let square_after = square_deployed.saturating_add(req.amount);
+    let share = req.amount as f64 / square_after as f64;
+    
+    // SOL-only EV (ore_value = 0), then the full EV including ORE
+    let (n_sol, d) = profit_fraction_fixed_s(
+        total_deployed as u128,
+        square_deployed as u128,
+        req.amount as u128,
+        0,
+    );
+    let (n_total, _) = profit_fraction_fixed_s(
+        total_deployed as u128,
+        square_deployed as u128,
+        req.amount as u128,
+        req.ore_value as u128,
+    );
+    let d = d.max(1) as i128;
+    let expected_sol = (n_sol / d).clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+    let expected_total = (n_total / d).clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+    
+    // Winning square (1/25) splits 1 ORE pro-rata
+    let expected_ore = ((ONE_ORE as u128 * req.amount as u128) / (25 * square_after as u128)) as u64;
+    
+    Ok(Json(SimulateDeployResponse {
+        round_id: round.round_id,
+        square: req.square,
+        amount: req.amount,
+        square_deployed,
+        total_deployed,
+        share,
+        expected_sol,
+        expected_ore,
+        expected_total,
+    }))
+}
+
+/// Rank every square by the share of its split `amount` would own if it won
+///
+/// Ties (equal share) go to the square with fewer miners, then the lower index.
+fn contrarian_squares(deployed: &[u64; 25], count: &[u64; 25], amount: u64) -> Vec<ContrarianSquare> {
+    let mut squares: Vec<ContrarianSquare> = (0..25)
+        .map(|i| {
+            let square_after = deployed[i].saturating_add(amount).max(1);
+            ContrarianSquare {
+                square: i as u8,
+                deployed: deployed[i],
+                miners: count[i],
+                share: amount as f64 / square_after as f64,
+                ore_if_win: (ONE_ORE as u128 * amount as u128 / square_after as u128) as u64,
+            }
+        })
+        .collect();
+    squares.sort_by(|a, b| {
+        b.share.total_cmp(&a.share)
+            .then(a.miners.cmp(&b.miners))
+            .then(a.square.cmp(&b.square))
+    });
+    squares
+}
+
+/// GET /evore/contrarian?round_id=&amount= - Squares where a stake owns the most of the split
+/// 
+/// A different objective from /evore/simulate-deploy: this ignores how much
+/// SOL the other squares would pay out and only ranks squares by the fraction
+/// of the winning square's split (SOL and ORE) the stake would own, i.e. the
+/// least crowded squares first. Every square is equally likely to win, so the
+/// top square here is usually not the best EV.
+async fn get_contrarian(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ContrarianQuery>,
+) -> Result<Json<ContrarianResponse>, AppError> {
+    if query.amount == 0 {
+        return Err(AppError::BadRequest("amount must be greater than 0".to_string()));
+    }
+    
+    let round = state.round_cache.read().await.clone().ok_or_else(|| {
+        AppError::Unavailable("Live round not loaded yet".to_string())
+    })?;
+    
+    if let Some(round_id) = query.round_id {
+        if round.round_id != round_id {
+            return Err(AppError::Conflict(format!("Round {} is not live (current round is {})", round_id, round.round_id)));
+        }
+    }
+    
+    Ok(Json(ContrarianResponse {
+        round_id: round.round_id,
+        amount: query.amount,
+        squares: contrarian_squares(&round.deployed, &round.count, query.amount),
+    }))
+}
+
+// ============================================================================
+// Deployment Lookup
+// ============================================================================
+
+/// GET /evore/deployment/{signature} - Parsed deployment for a single transaction
+/// 
+/// Looks up the stored transaction and deployment states in ClickHouse. If the
+/// transaction isn't stored and `fallback` isn't disabled, it is fetched via RPC
+/// and parsed on the fly (nothing is written back).
+async fn get_deployment_by_signature(
+    State(state): State<Arc<AppState>>,
+    Path(signature): Path<String>,
+    Query(params): Query<DeploymentLookupQuery>,
+) -> Result<Json<DeploymentBySignatureResponse>, AppError> {
+    let db_error = |e: String| {
+        tracing::error!("Failed to look up deployment {}: {}", signature, e);
+        AppError::Internal("Database error".to_string())
+    };
+    
+    let stored = state.clickhouse
+        .get_deployment_by_signature(&signature)
+        .await
+        .map_err(|e| db_error(e.to_string()))?;
+    
+    let stored_tx = state.clickhouse
+        .get_transaction_by_signature_v2(&signature)
+        .await
+        .map_err(|e| db_error(e.to_string()))?;
+    
+    let (raw_json, source) = match stored_tx {
+        Some(tx) => (tx.raw_json, "clickhouse"),
+        None if params.fallback.unwrap_or(true) => {
+            let fetched = state.rpc
+                .get_transaction(&signature)
+                .await
+                .map_err(|e| {
+                    tracing::warn!("RPC fallback for deployment {} failed: {}", signature, e);
+                    AppError::BadGateway(format!("RPC error: {}", e))
+                })?;
+            match fetched {
+                Some(tx) => (tx.raw_json, "rpc"),
+                None => {
+                    return Err(AppError::NotFound("Transaction not found".to_string()));
+                }
+            }
+        }
+        None => {
+            return Err(AppError::NotFound("Transaction not found in storage".to_string()));
+        }
+    };
+    
+    let analysis = TransactionAnalyzer::new()
+        .analyze(&raw_json)
+        .map_err(|e| {
+            AppError::Internal(format!("Analysis failed: {}", e))
+        })?;
+    
+    let deployments = analysis.ore_analysis
+        .map(|ore| ore.deployments)
+        .unwrap_or_default();
+    
+    if deployments.is_empty() && stored.is_empty() {
+        return Err(AppError::NotFound("Transaction contains no deployments".to_string()));
+    }
+    
+    Ok(Json(DeploymentBySignatureResponse {
+        signature: analysis.signature,
+        source: source.to_string(),
+        slot: analysis.slot,
+        block_time: analysis.block_time,
+        deployments,
+        balance_changes: analysis.balance_changes,
+        stored,
+    }))
+}
+
+// ============================================================================
+// Balance Handlers
+// ============================================================================
+
+/// GET /evore/balances?pubkeys=a,b,c - SOL balances for up to 100 accounts
+///
+/// Served from a short-TTL cache; overlapping concurrent requests share one RPC call.
+async fn get_balances(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<BalancesQuery>,
+) -> Result<Json<BalancesResponse>, AppError> {
+    let pubkeys = query.pubkeys
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(|p| p.parse::<steel::Pubkey>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| AppError::BadRequest("Invalid pubkey".to_string()))?;
+    
+    if pubkeys.is_empty() || pubkeys.len() > MAX_BALANCE_PUBKEYS {
+        return Err(AppError::BadRequest(format!("pubkeys must contain 1-{} addresses", MAX_BALANCE_PUBKEYS)));
+    }
+    
+    let balances = state.balance_cache.get_balances(&pubkeys).await.map_err(|e| {
+        AppError::BadGateway(format!("RPC error: {}", e))
+    })?;
+    
+    Ok(Json(BalancesResponse {
+        balances: balances.into_iter()
+            .map(|(pubkey, lamports)| AccountBalance { pubkey: pubkey.to_string(), lamports })
+            .collect(),
+    }))
+}
+
 // ============================================================================
 // Combined Endpoint
 // ============================================================================
@@ -316,6 +752,57 @@ async fn get_my_miners(
 // Stats
 // ============================================================================
 
+/// Max rounds per GET /evore/miner/{authority}/timeline page
+const MAX_TIMELINE_LIMIT: u32 = 500;
+
+fn timeline_entry(row: MinerTimelineRow) -> TimelineEntry {
+    let finalized = row.finalized > 0;
+    TimelineEntry {
+        round_id: row.round_id,
+        squares: (0..25u8).filter(|i| row.squares_mask & (1 << i) != 0).collect(),
+        amount: row.amount,
+        deployed_slot: row.deployed_slot,
+        winning_square: finalized.then_some(row.winning_square),
+        won: finalized.then_some(row.won > 0),
+        sol_earned: row.sol_earned,
+        ore_earned: row.ore_earned,
+        net_sol: row.sol_earned as i64 - row.amount as i64,
+        top_miner: row.is_top_miner > 0,
+        motherlode_hit: finalized && row.motherlode_hit > 0,
+    }
+}
+
+/// GET /evore/miner/{authority}/timeline?from=&to=&cursor=&limit= - Rounds the authority deployed in
+/// 
+/// Newest first, one entry per round with the round's outcome. Pass the
+/// returned `cursor` to get the next (older) page.
+async fn get_miner_timeline(
+    State(state): State<Arc<AppState>>,
+    Path(authority): Path<String>,
+    Query(params): Query<TimelineQuery>,
+) -> Result<Json<CursorResponse<TimelineEntry>>, AppError> {
+    authority.parse::<steel::Pubkey>()
+        .map_err(|_| AppError::BadRequest("Invalid pubkey".to_string()))?;
+    let limit = params.limit.unwrap_or(100).clamp(1, MAX_TIMELINE_LIMIT);
+    
+    let rows = state.clickhouse
+        .get_miner_timeline(&authority, params.from, params.to, params.cursor, limit)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get timeline for {}: {}", authority, e);
+            AppError::Internal("Database error".to_string())
+        })?;
+    
+    let has_more = rows.len() as u32 == limit;
+    let cursor = rows.last().map(|r| r.round_id.to_string());
+    
+    Ok(Json(CursorResponse {
+        data: rows.into_iter().map(timeline_entry).collect(),
+        cursor,
+        has_more,
+    }))
+}
+
 /// GET /evore/stats - Cache statistics
 async fn get_evore_stats(
     State(state): State<Arc<AppState>>,
@@ -324,3 +811,58 @@ async fn get_evore_stats(
     Json(cache.stats())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_contrarian_squares_order() {
+        let mut deployed = [1_000u64; 25];
+        let mut count = [3u64; 25];
+        deployed[7] = 0;
+        deployed[3] = 100;
+        deployed[4] = 100;
+        count[4] = 1;
+        deployed[9] = 50_000;
+        
+        let squares = contrarian_squares(&deployed, &count, 100);
+        assert_eq!(squares.len(), 25);
+        
+        // Empty square: the stake owns the whole split
+        assert_eq!(squares[0].square, 7);
+        assert_eq!(squares[0].share, 1.0);
+        assert_eq!(squares[0].ore_if_win, ONE_ORE);
+        
+        // Same occupancy: fewer miners first
+        assert_eq!((squares[1].square, squares[2].square), (4, 3));
+        assert_eq!(squares[1].share, 0.5);
+        
+        // Equal squares keep index order, most crowded last
+        assert_eq!(squares[3].square, 0);
+        assert_eq!(squares[24].square, 9);
+    }
+    
+    #[test]
+    fn test_timeline_entry() {
+        let row = MinerTimelineRow {
+            round_id: 42,
+            squares_mask: 0b1_0000_0101,
+            amount: 3_000,
+            deployed_slot: 1_000,
+            sol_earned: 5_000,
+            ore_earned: 7,
+            is_top_miner: 0,
+            finalized: 1,
+            winning_square: 8,
+            won: 1,
+            motherlode_hit: 0,
+        };
+        let entry = timeline_entry(row.clone());
+        assert_eq!(entry.squares, vec![0, 2, 8]);
+        assert_eq!((entry.winning_square, entry.won, entry.net_sol), (Some(8), Some(true), 2_000));
+        
+        // Live round: the join defaults mean nothing yet
+        let live = timeline_entry(MinerTimelineRow { finalized: 0, winning_square: 0, won: 0, sol_earned: 0, ..row });
+        assert_eq!((live.winning_square, live.won, live.net_sol), (None, None, -3_000));
+    }
+}
@@ -9,19 +9,22 @@
 //!
 //! The GPA snapshot is taken 10 seconds after round ending is detected to allow
 //! all transactions to settle before capturing the final state.
+//!
+//! Round end is detected from the round's `ResetEvent` log or by polling the
+//! round account (`FINALIZATION_SOURCE`, see `FinalizationSource`).
 
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use evore::ore_api::{Miner, Round, Treasury};
-use steel::Pubkey;
+use steel::{Pubkey, Zeroable};
 use tracing;
 
 use crate::app_state::{AppState, LiveBroadcastData, RoundSnapshot};
 use crate::clickhouse::{
     DeploymentInsert, MinerSnapshot, MintSnapshot, PartialRoundInsert, RawTransactionV2, 
-    RoundInsert, SignatureRow, TreasurySnapshot,
+    RoundEntropy, RoundInsert, SignatureRow, TreasurySnapshot,
 };
 use crate::txn_backfill::parse_transaction_accounts;
 
@@ -303,8 +306,25 @@ pub async fn finalize_round(
     }
     
     // ========== STEP 4: Try to wait for top_miner and finalize ==========
-    match wait_for_round_finalization(state, round_id, &snapshot).await {
-        Ok(finalized_round) => {
+    let finalized = match finalization_source() {
+        FinalizationSource::Events => match wait_for_reset_event(state, round_id, &snapshot, event_grace()).await {
+            Some(round) => Ok((round, "event")),
+            None => {
+                tracing::warn!(
+                    "Round {}: no ResetEvent within {}s, polling the round account",
+                    round_id, event_grace().as_secs()
+                );
+                wait_for_round_finalization(state, round_id, &snapshot).await
+                    .map(|round| (round, "accounts (event fallback)"))
+            }
+        },
+        FinalizationSource::Accounts => wait_for_round_finalization(state, round_id, &snapshot).await
+            .map(|round| (round, "accounts")),
+    };
+    
+    match finalized {
+        Ok((finalized_round, path)) => {
+            tracing::info!("Round {} finalized via {}", round_id, path);
             // SUCCESS: Full finalization with top_miner
     let rng = finalized_round.rng().ok_or_else(|| {
         anyhow::anyhow!("Round {} still has no slot_hash after waiting", round_id)
@@ -454,6 +474,13 @@ async fn store_finalized_round(
     state.clickhouse.insert_round(round_insert).await?;
     tracing::debug!("Stored round {} to ClickHouse", round_id);
     
+    // Record the entropy behind the winning square (best effort)
+    if entropy_capture_enabled() {
+        if let Err(e) = store_round_entropy(state, finalized_round, winning_square).await {
+            tracing::warn!("Failed to store entropy for round {}: {}", round_id, e);
+        }
+    }
+    
     // Store deployments (only if GPA succeeded)
     if !snapshot.gpa_failed {
         state.clickhouse.insert_deployments(all_deployments.clone()).await?;
@@ -475,6 +502,95 @@ async fn store_finalized_round(
     Ok(())
 }
 
+/// Where finalization learns that a round has reset (`FINALIZATION_SOURCE`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinalizationSource {
+    /// Watch the round's transactions for its `ResetEvent` (default): fewer
+    /// RPC reads but needs the logs, so the round account is polled instead
+    /// when no event shows up within `FINALIZATION_EVENT_GRACE_SECS`
+    Events,
+    /// Poll the round account until slot_hash and top_miner are set; works
+    /// even when logs are truncated
+    Accounts,
+}
+
+impl FinalizationSource {
+    /// `events` | `accounts` (unset or unknown = events)
+    pub fn parse(value: Option<&str>) -> Self {
+        match value.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+            Some("accounts") | Some("account") | Some("rpc") => FinalizationSource::Accounts,
+            Some("events") | Some("event") | Some("") | None => FinalizationSource::Events,
+            Some(other) => {
+                tracing::warn!("Unknown FINALIZATION_SOURCE '{}', using events", other);
+                FinalizationSource::Events
+            }
+        }
+    }
+}
+
+fn finalization_source() -> FinalizationSource {
+    FinalizationSource::parse(std::env::var("FINALIZATION_SOURCE").ok().as_deref())
+}
+
+/// How long to look for a round's ResetEvent before polling the account (`FINALIZATION_EVENT_GRACE_SECS`, default 20)
+fn event_grace() -> Duration {
+    Duration::from_secs(
+        std::env::var("FINALIZATION_EVENT_GRACE_SECS").ok().and_then(|v| v.parse().ok()).unwrap_or(20)
+    )
+}
+
+/// Whether to record each finalized round's entropy (`CAPTURE_ROUND_ENTROPY`, default on)
+fn entropy_capture_enabled() -> bool {
+    std::env::var("CAPTURE_ROUND_ENTROPY")
+        .map_or(true, |v| !matches!(v.to_ascii_lowercase().as_str(), "0" | "false" | "off"))
+}
+
+/// Winning square the ORE program derives from an entropy value
+/// (the value becomes the round's slot_hash at reset)
+pub fn winning_square_from_entropy(value: [u8; 32]) -> Option<u8> {
+    let mut round = Round::zeroed();
+    round.slot_hash = value;
+    round.rng().map(|rng| round.winning_square(rng) as u8)
+}
+
+/// Read the entropy var and store it alongside the finalized round
+async fn store_round_entropy(
+    state: &AppState,
+    finalized_round: &Round,
+    winning_square: u8,
+) -> anyhow::Result<()> {
+    let (var_address, var) = state.rpc.get_entropy_var().await?;
+    
+    // The var moves on to the next round after reset; flag it rather than drop it
+    if var.value != finalized_round.slot_hash {
+        tracing::warn!(
+            "Round {} entropy value does not match the round slot_hash (var end_at={})",
+            finalized_round.id, var.end_at
+        );
+    }
+    
+    state.clickhouse.insert_round_entropy(RoundEntropy {
+        round_id: finalized_round.id,
+        var_address: var_address.to_string(),
+        provider: var.provider.to_string(),
+        commit: var.commit,
+        seed: var.seed,
+        slot_hash: var.slot_hash,
+        value: var.value,
+        samples: var.samples,
+        start_at: var.start_at,
+        end_at: var.end_at,
+        round_slot_hash: finalized_round.slot_hash,
+        winning_square,
+        created_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as i64,
+    }).await?;
+    
+    Ok(())
+}
+
 /// Fetch and store transactions for a finalized round
 /// Runs in background after round finalization
 /// Takes Arc<AppState> to be called from spawned tasks
@@ -837,6 +953,70 @@ async fn wait_for_round_finalization(
     ))
 }
 
+/// Wait for the round's ResetEvent in its transactions, polling every 2 seconds for up to `grace`
+///
+/// The event carries the outcome but not the slot_hash (the rng behind split
+/// rewards and entropy), so the round account is read once when it shows up;
+/// the event's top_miner fills in if the account doesn't have one yet.
+/// None when no event is found in time.
+async fn wait_for_reset_event(
+    state: &AppState,
+    round_id: u64,
+    snapshot: &RoundSnapshot,
+    grace: Duration,
+) -> Option<Round> {
+    let poll_interval = Duration::from_secs(2);
+    let deadline = tokio::time::Instant::now() + grace;
+    let (round_pda, _) = state.rpc.network().round_pda(round_id);
+    
+    loop {
+        let event = match state.helius
+            .get_transactions_for_address(&round_pda, None, Some(100), Some("desc"), Some(snapshot.end_slot), None)
+            .await
+        {
+            Ok(page) => state.helius.parse_reset_event_from_round_page(round_id, &page.transactions)
+                .unwrap_or_else(|e| {
+                    tracing::warn!("Failed to parse ResetEvent for round {}: {}", round_id, e);
+                    None
+                }),
+            Err(e) => {
+                tracing::warn!("Failed to fetch transactions for round {}: {}", round_id, e);
+                None
+            }
+        };
+        
+        if let Some((event, event_slot)) = event {
+            match state.rpc.get_round(round_id).await {
+                Ok(mut round) if round.slot_hash != [0u8; 32] => {
+                    if round.top_miner == Pubkey::default() {
+                        round.top_miner = event.top_miner;
+                    }
+                    if let Some(rng) = round.rng() {
+                        if round.winning_square(rng) as u64 != event.winning_square {
+                            tracing::warn!(
+                                "Round {}: ResetEvent winning_square {} doesn't match slot_hash ({})",
+                                round_id, event.winning_square, round.winning_square(rng)
+                            );
+                        }
+                    }
+                    tracing::info!(
+                        "Round {} ResetEvent found at slot {}: top_miner={}",
+                        round_id, event_slot, round.top_miner
+                    );
+                    return Some(round);
+                }
+                Ok(_) => tracing::debug!("Round {} has a ResetEvent but no slot_hash yet", round_id),
+                Err(e) => tracing::warn!("Failed to fetch round {}: {}", round_id, e),
+            }
+        }
+        
+        if tokio::time::Instant::now() + poll_interval > deadline {
+            return None;
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
 /// Calculate the optimistic top_miner from snapshot data
 /// Returns None if calculation fails (no deployments on winning square, etc.)
 fn calculate_optimistic_top_miner(round: &Round, snapshot: &RoundSnapshot) -> Option<Pubkey> {
@@ -1016,5 +1196,33 @@ mod tests {
         // If miner deployed 1000 out of 10000 total, they get 10% of winnings
         // Mocking round for test would require more setup
     }
+    
+    #[test]
+    fn test_finalization_source() {
+        assert_eq!(FinalizationSource::parse(None), FinalizationSource::Events);
+        assert_eq!(FinalizationSource::parse(Some("events")), FinalizationSource::Events);
+        assert_eq!(FinalizationSource::parse(Some(" Accounts ")), FinalizationSource::Accounts);
+        assert_eq!(FinalizationSource::parse(Some("rpc")), FinalizationSource::Accounts);
+        assert_eq!(FinalizationSource::parse(Some("bogus")), FinalizationSource::Events);
+    }
+    
+    #[test]
+    fn test_winning_square_from_entropy() {
+        // Unset / sentinel values have no outcome
+        assert_eq!(winning_square_from_entropy([0; 32]), None);
+        assert_eq!(winning_square_from_entropy([u8::MAX; 32]), None);
+        
+        // rng = XOR of the four u64 words; only the first is set here
+        let mut value = [0u8; 32];
+        value[..8].copy_from_slice(&27u64.to_le_bytes());
+        assert_eq!(winning_square_from_entropy(value), Some(2));
+        
+        // Matches Round::rng / winning_square for an arbitrary value
+        let value: [u8; 32] = std::array::from_fn(|i| (i as u8).wrapping_mul(37).wrapping_add(11));
+        let mut round = Round::zeroed();
+        round.slot_hash = value;
+        let expected = round.winning_square(round.rng().unwrap()) as u8;
+        assert_eq!(winning_square_from_entropy(value), Some(expected));
+    }
 }
 
@@ -1,7 +1,8 @@
-use std::{mem, sync::Arc, time::Duration};
+use std::{mem, sync::{atomic::{AtomicU64, Ordering}, Arc}, time::Duration};
 
 use base64::Engine as _;
 use tracing;
+use evore::board::mask_to_squares;
 use evore::ore_api::{self, Automate, Deploy, OreInstruction, AutomationStrategy};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
@@ -13,6 +14,7 @@ use tokio::time::Instant;
 
 use crate::app_state::{AutomationCache, ReconstructedAutomation};
 use crate::clickhouse::{ClickHouseClient, RpcRequestInsert};
+use crate::rate_limiter::RateLimiter;
 
 #[derive(Debug, Error)]
 pub enum HeliusError {
@@ -30,15 +32,65 @@ pub enum HeliusError {
 
     #[error("decode error: {0}")]
     Decode(String),
+
+    #[error("rate limited: {0}")]
+    RateLimited(String),
+
+    /// A history scan hit its `ScanLimit` before finding an Automate - the
+    /// automation state is unknown, not off
+    #[error("scan truncated after {pages} pages / {txns} txns without finding an Automate")]
+    ScanTruncated { pages: u32, txns: u32 },
+}
+
+/// Default page depth for DESC automation history scans (100 txns per page)
+pub const DEFAULT_SCAN_MAX_PAGES: u32 = 50;
+
+/// How deep a DESC automation history scan may go; 0 means unbounded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScanLimit {
+    pub max_pages: u32,
+    pub max_txns: u32,
 }
 
+impl Default for ScanLimit {
+    fn default() -> Self {
+        Self { max_pages: DEFAULT_SCAN_MAX_PAGES, max_txns: 0 }
+    }
+}
+
+impl ScanLimit {
+    /// Whether a scan that has read `pages` pages / `txns` transactions must stop
+    pub fn reached(&self, pages: u32, txns: u32) -> bool {
+        (self.max_pages > 0 && pages >= self.max_pages)
+            || (self.max_txns > 0 && txns >= self.max_txns)
+    }
+}
+
+/// Retries after a 429 / rate-limit response before giving up
+const RATE_LIMIT_MAX_RETRIES: u32 = 5;
+/// First adaptive delay added on top of the self-throttle after a rate limit
+const RATE_LIMIT_INITIAL_BACKOFF_MS: u64 = 500;
+/// Upper bound for the adaptive delay
+const RATE_LIMIT_MAX_BACKOFF_MS: u64 = 10_000;
+
+/// Default request budget (Developer plan: 25 RPS)
+pub const DEFAULT_HELIUS_RPS: u32 = 25;
+/// Default requests that may go out back to back after a quiet period
+pub const DEFAULT_HELIUS_BURST: u32 = 5;
+
 
 /// Wrapper for talking to the Helius RPC endpoint.
 #[derive(Clone)]
 pub struct HeliusApi {
     rpc_url: String,
     client: Client,
-    last_request_at: Instant,
+    /// Request budget shared by every clone and concurrent caller
+    limiter: Arc<RateLimiter>,
+    /// Delay every caller is held back by on a rate limit.
+    /// Doubles on each 429 and halves on each successful request.
+    rate_limit_backoff_ms: Arc<AtomicU64>,
+    /// Depth bound for DESC automation history scans
+    scan_limit: ScanLimit,
     
     // Metrics tracking
     clickhouse: Option<Arc<ClickHouseClient>>,
@@ -67,14 +119,83 @@ impl HeliusApi {
         
         Self {
             rpc_url: full_url,
-            client: Client::new(),
-            last_request_at: Instant::now(),
+            client: crate::custom_rpc::http_client(),
+            limiter: Arc::new(RateLimiter::new(DEFAULT_HELIUS_RPS, DEFAULT_HELIUS_BURST)),
+            rate_limit_backoff_ms: Arc::new(AtomicU64::new(0)),
+            scan_limit: ScanLimit::default(),
             clickhouse,
             provider_name,
             api_key_id,
         }
     }
     
+    /// Replace the request budget (`HELIUS_RPS` / `HELIUS_BURST`)
+    pub fn with_rate_limit(mut self, rps: u32, burst: u32) -> Self {
+        self.limiter = Arc::new(RateLimiter::new(rps, burst));
+        self
+    }
+
+    /// Depth bound for history scans
+    pub fn scan_limit(&self) -> ScanLimit {
+        self.scan_limit
+    }
+
+    /// Replace the history scan depth bound (`HELIUS_SCAN_MAX_PAGES` / `HELIUS_SCAN_MAX_TXNS`)
+    pub fn with_scan_limit(mut self, limit: ScanLimit) -> Self {
+        self.scan_limit = limit;
+        self
+    }
+    
+    /// POST a JSON-RPC body, backing off and retrying when the provider rate limits us.
+    ///
+    /// Each request takes `cost` tokens of the shared budget first. A 429 status
+    /// or a rate-limit error body raises the adaptive delay, holds back every
+    /// caller by it, and retries up to `RATE_LIMIT_MAX_RETRIES` times; other
+    /// failures are returned as-is. Returns the raw response body.
+    async fn post_with_backoff(
+        &self,
+        method: &str,
+        body: &Value,
+        cost: u32,
+    ) -> Result<Vec<u8>, HeliusError> {
+        let mut attempt = 0;
+        loop {
+            self.limiter.acquire(cost).await;
+            
+            let response = self.client
+                .post(&self.rpc_url)
+                .json(body)
+                .send()
+                .await?;
+            
+            let rate_limited_status = response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS;
+            let response = if rate_limited_status { response } else { response.error_for_status()? };
+            let response_bytes = response.bytes().await?.to_vec();
+            
+            if !rate_limited_status && !is_rate_limit_body(&response_bytes) {
+                let _ = self.rate_limit_backoff_ms.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |ms| Some(ms / 2));
+                return Ok(response_bytes);
+            }
+            
+            attempt += 1;
+            if attempt > RATE_LIMIT_MAX_RETRIES {
+                return Err(HeliusError::RateLimited(format!(
+                    "{} still rate limited after {} retries",
+                    method, RATE_LIMIT_MAX_RETRIES
+                )));
+            }
+            
+            let backoff_ms = (self.rate_limit_backoff_ms.load(Ordering::Relaxed) * 2)
+                .clamp(RATE_LIMIT_INITIAL_BACKOFF_MS, RATE_LIMIT_MAX_BACKOFF_MS);
+            self.rate_limit_backoff_ms.store(backoff_ms, Ordering::Relaxed);
+            self.limiter.penalize(Duration::from_millis(backoff_ms));
+            tracing::warn!(
+                "Helius rate limited on {} (retry {}/{}), adaptive delay now {}ms",
+                method, attempt, RATE_LIMIT_MAX_RETRIES, backoff_ms
+            );
+        }
+    }
+    
     /// Log successful RPC call to ClickHouse
     fn log_success(&self, method: &str, target_type: &str, target_address: &str, duration_ms: u32, result_count: u32, response_size: u32) {
         if let Some(ref ch) = self.clickhouse {
@@ -86,6 +207,7 @@ impl HeliusApi {
                 target_type,
             )
             .with_target(target_address)
+            .with_credits(method_credits(method))
             .success(duration_ms, result_count, response_size);
             
             let ch = ch.clone();
@@ -109,6 +231,7 @@ impl HeliusApi {
             )
             .with_target(target_address)
             .with_pagination(page_num, cursor)
+            .with_credits(method_credits(method))
             .success(duration_ms, result_count, response_size);
             
             let ch = ch.clone();
@@ -133,6 +256,7 @@ impl HeliusApi {
             .with_target(target_address)
             .with_pagination(page_num, cursor)
             .with_filters(filters_json)
+            .with_credits(method_credits(method))
             .success(duration_ms, result_count, response_size);
             
             let ch = ch.clone();
@@ -155,6 +279,7 @@ impl HeliusApi {
                 target_type,
             )
             .with_target(target_address)
+            .with_credits(method_credits(method))
             .error(duration_ms, "", error);
             
             let ch = ch.clone();
@@ -176,7 +301,7 @@ impl HeliusApi {
     /// - `transactions`: a Vec of full transaction JSONs
     /// - `pagination_token`: Some(token) if there is a next page, None otherwise
     pub async fn get_transactions_for_round(
-        &mut self,
+        &self,
         round_id: u64,
         pagination_token: Option<String>,
     ) -> Result<RoundTransactionsPage, HeliusError> {
@@ -200,7 +325,7 @@ impl HeliusApi {
     /// - filters.status = "succeeded"
     /// - optional slot range (gte / lte)
     pub async fn get_transactions_for_address(
-        &mut self,
+        &self,
         address: &Pubkey,
         pagination_token: Option<String>,
         limit: Option<u32>,
@@ -208,10 +333,6 @@ impl HeliusApi {
         slot_gte: Option<u64>,      // optional slot >=
         slot_lte: Option<u64>,      // optional slot <=
     ) -> Result<AddressTransactionsPage, HeliusError> {
-        if self.last_request_at.elapsed().as_millis() < 200 {
-            tokio::time::sleep(Duration::from_millis((200 - self.last_request_at.elapsed().as_millis()) as u64)).await;
-        }
-        self.last_request_at = Instant::now();
         // Build filters object
         let mut slot_filter = serde_json::Map::new();
         if let Some(gte) = slot_gte {
@@ -264,15 +385,8 @@ impl HeliusApi {
         let start = Instant::now();
         let request_size = body.to_string().len() as u32;
         
-        let response = self
-            .client
-            .post(&self.rpc_url)
-            .json(&body)
-            .send()
-            .await?
-            .error_for_status()?;
-        
-        let response_bytes = response.bytes().await?;
+        // Heavier call: 5 requests' worth of the budget (200ms at 25 RPS)
+        let response_bytes = self.post_with_backoff("getTransactionsForAddress", &body, 5).await?;
         let duration_ms = start.elapsed().as_millis() as u32;
         let response_size = response_bytes.len() as u32;
         
@@ -313,8 +427,9 @@ impl HeliusApi {
     /// Returns:
     /// - (None, cache)  -> automation OFF as of cutoff_slot.
     /// - (Some(auto), cache) -> automation ON as of cutoff_slot.
+    /// - Err(ScanTruncated) -> the DESC search hit the scan limit first; state unknown.
     pub async fn get_latest_automate_for_authority_up_to_slot(
-        &mut self,
+        &self,
         authority: &Pubkey,
         cutoff_slot: u64,
         prev_cache: Option<AutomationCache>,
@@ -413,9 +528,9 @@ impl HeliusApi {
                 Ok(*key)
             };
 
-            let signer = get_key(0)?;        // authority
-            let autom_acc = get_key(1)?;     // automation_info
-            let executor = get_key(2)?;      // executor_info
+            let signer = get_key(ore_api::AUTOMATE_ACCOUNTS.signer)?;          // authority
+            let autom_acc = get_key(ore_api::AUTOMATE_ACCOUNTS.automation)?;   // automation_info
+            let executor = get_key(ore_api::AUTOMATE_ACCOUNTS.executor)?;      // executor_info
 
             // Must match the expected automation PDA & authority
             if autom_acc != *expected_automation_pda {
@@ -458,6 +573,7 @@ impl HeliusApi {
             // First-time heavy path: DESC, slot <= cutoff
             // ─────────────────────────────────────────────
             let mut pagination_token: Option<String> = None;
+            let (mut pages, mut txns) = (0u32, 0u32);
 
             loop {
                 let page = self
@@ -605,6 +721,11 @@ impl HeliusApi {
                 }
 
                 pagination_token = page.pagination_token;
+                pages += 1;
+                txns += page.transactions.len() as u32;
+                if pagination_token.is_some() && self.scan_limit.reached(pages, txns) {
+                    return Err(HeliusError::ScanTruncated { pages, txns });
+                }
                 if pagination_token.is_none() {
                     // No Automate at all ≤ cutoff
                     cache.active = false;
@@ -780,7 +901,7 @@ impl HeliusApi {
     /// Returns (None, cache) if no Automate found before reaching stop_at_slot.
     /// The caller should check if None is returned and use a fallback if available.
     pub async fn get_latest_automate_for_authority_up_to_slot_with_stop(
-        &mut self,
+        &self,
         authority: &Pubkey,
         cutoff_slot: u64,
         stop_at_slot: Option<u64>,
@@ -835,9 +956,10 @@ impl HeliusApi {
                 return Ok(None);
             }
 
-            let auth_idx = accts_arr[0].as_u64().unwrap_or(u64::MAX) as usize;
-            let auto_idx = accts_arr[1].as_u64().unwrap_or(u64::MAX) as usize;
-            let exec_idx = accts_arr[2].as_u64().unwrap_or(u64::MAX) as usize;
+            let layout = ore_api::AUTOMATE_ACCOUNTS;
+            let auth_idx = accts_arr[layout.signer].as_u64().unwrap_or(u64::MAX) as usize;
+            let auto_idx = accts_arr[layout.automation].as_u64().unwrap_or(u64::MAX) as usize;
+            let exec_idx = accts_arr[layout.executor].as_u64().unwrap_or(u64::MAX) as usize;
 
             let auth_pk = account_keys
                 .get(auth_idx)
@@ -878,6 +1000,7 @@ impl HeliusApi {
         }
 
         let mut pagination_token: Option<String> = None;
+        let (mut pages, mut txns) = (0u32, 0u32);
 
         loop {
             let page = self
@@ -1044,6 +1167,11 @@ impl HeliusApi {
             }
 
             pagination_token = page.pagination_token;
+            pages += 1;
+            txns += page.transactions.len() as u32;
+            if pagination_token.is_some() && self.scan_limit.reached(pages, txns) {
+                return Err(HeliusError::ScanTruncated { pages, txns });
+            }
             if pagination_token.is_none() {
                 // No Automate found at all
                 cache.active = false;
@@ -1220,13 +1348,7 @@ impl HeliusApi {
                             continue;
                         }
 
-                        // Accounts layout for Deploy:
-                        // 0: signer
-                        // 1: authority
-                        // 2: automation_pda
-                        // 3: board
-                        // 4: miner_pda
-                        // 5: round_pda
+                        // Account indices come from ore_api::DEPLOY_ACCOUNTS
                         let accounts = ix
                             .get("accounts")
                             .and_then(Value::as_array)
@@ -1241,9 +1363,9 @@ impl HeliusApi {
                             Ok(acc_idx)
                         };
 
-                        let signer_msg_idx = get_msg_index(0)?;
-                        let automation_msg_idx = get_msg_index(2)?;
-                        let round_msg_idx = get_msg_index(5)?;
+                        let signer_msg_idx = get_msg_index(ore_api::DEPLOY_ACCOUNTS.signer)?;
+                        let automation_msg_idx = get_msg_index(ore_api::DEPLOY_ACCOUNTS.automation)?;
+                        let round_msg_idx = get_msg_index(ore_api::DEPLOY_ACCOUNTS.round)?;
 
                         let signer_lamports_delta =
                             lamport_delta_for_index(signer_msg_idx);
@@ -1303,9 +1425,9 @@ impl HeliusApi {
                                         Ok(acc_idx)
                                     };
 
-                                let signer_msg_idx = get_msg_index(0)?;
-                                let automation_msg_idx = get_msg_index(2)?;
-                                let round_msg_idx = get_msg_index(5)?;
+                                let signer_msg_idx = get_msg_index(ore_api::DEPLOY_ACCOUNTS.signer)?;
+                                let automation_msg_idx = get_msg_index(ore_api::DEPLOY_ACCOUNTS.automation)?;
+                                let round_msg_idx = get_msg_index(ore_api::DEPLOY_ACCOUNTS.round)?;
 
                                 let signer_lamports_delta =
                                     lamport_delta_for_index(signer_msg_idx);
@@ -1724,19 +1846,10 @@ impl HeliusApi {
     ///
     /// Rate limit: 25 RPS (Developer plan)
     pub async fn get_program_accounts_v2(
-        &mut self,
+        &self,
         program_id: &Pubkey,
         options: GetProgramAccountsV2Options,
     ) -> Result<GetProgramAccountsV2Page, HeliusError> {
-        // Enforce minimum rate limiting (40ms between calls = 25 RPS max)
-        if self.last_request_at.elapsed().as_millis() < 40 {
-            tokio::time::sleep(Duration::from_millis(
-                (40 - self.last_request_at.elapsed().as_millis()) as u64,
-            ))
-            .await;
-        }
-        self.last_request_at = Instant::now();
-
         // Build the options object for Helius v2
         let mut opts = serde_json::Map::new();
 
@@ -1786,15 +1899,7 @@ impl HeliusApi {
         let start = Instant::now();
         let request_size = body.to_string().len() as u32;
         
-        let response = self
-            .client
-            .post(&self.rpc_url)
-            .json(&body)
-            .send()
-            .await?
-            .error_for_status()?;
-        
-        let response_bytes = response.bytes().await?;
+        let response_bytes = self.post_with_backoff("getProgramAccountsV2", &body, 1).await?;
         let duration_ms = start.elapsed().as_millis() as u32;
         let response_size = response_bytes.len() as u32;
 
@@ -1835,7 +1940,7 @@ impl HeliusApi {
     /// Fetch all ORE miner accounts using getProgramAccountsV2.
     /// Automatically paginates through all results.
     pub async fn get_all_ore_miners(
-        &mut self,
+        &self,
         limit_per_page: Option<u32>,
     ) -> Result<Vec<ProgramAccountV2>, HeliusError> {
         let mut all_accounts = Vec::new();
@@ -1873,7 +1978,7 @@ impl HeliusApi {
     /// Fetch ORE miner accounts that changed since a given slot.
     /// Used for incremental cache updates.
     pub async fn get_ore_miners_changed_since(
-        &mut self,
+        &self,
         since_slot: u64,
         limit_per_page: Option<u32>,
     ) -> Result<Vec<ProgramAccountV2>, HeliusError> {
@@ -1913,7 +2018,7 @@ impl HeliusApi {
     ///
     /// Returns token accounts with owner and balance info.
     pub async fn get_all_ore_token_holders(
-        &mut self,
+        &self,
         ore_mint: &Pubkey,
         limit_per_page: Option<u32>,
     ) -> Result<Vec<ProgramAccountV2>, HeliusError> {
@@ -1965,7 +2070,7 @@ impl HeliusApi {
     /// Fetch ORE token accounts that changed since a given slot.
     /// Used for incremental cache updates.
     pub async fn get_ore_token_holders_changed_since(
-        &mut self,
+        &self,
         ore_mint: &Pubkey,
         since_slot: u64,
         limit_per_page: Option<u32>,
@@ -2012,80 +2117,54 @@ impl HeliusApi {
         Ok(all_accounts)
     }
 
-    /// Fetch all ORE token holders with optimized dataSlice.
-    /// Only fetches owner (32 bytes) + amount (8 bytes) = 40 bytes per account.
-    /// Token account layout: [mint:32][owner:32][amount:8][...]
-    /// With dataSlice offset=32, length=40, we get owner+amount only.
-    pub async fn get_ore_token_balances(
-        &mut self,
+    /// Fetch one page of ORE token holders starting at `cursor` (None = first page).
+    /// Returns the page's balances and the cursor of the next page (None = last page),
+    /// so a caller can stop and later resume a full scan.
+    pub async fn get_ore_token_balances_page(
+        &self,
         ore_mint: &Pubkey,
+        cursor: Option<String>,
         limit_per_page: Option<u32>,
-    ) -> Result<Vec<TokenBalance>, HeliusError> {
-        let mut all_balances = Vec::new();
-        let mut cursor: Option<String> = None;
-        let mut page_num = 0u32;
-
+    ) -> Result<(Vec<TokenBalance>, Option<String>), HeliusError> {
         let mint_bytes = ore_mint.to_bytes();
         let mint_b64 = base64::Engine::encode(
             &base64::engine::general_purpose::STANDARD,
             &mint_bytes,
         );
 
-        loop {
-            page_num += 1;
-            let page = self
-                .get_program_accounts_v2(
-                    &spl_token::ID,
-                    GetProgramAccountsV2Options {
-                        encoding: Some("base64".to_string()),
-                        limit: Some(limit_per_page.unwrap_or(5000)),
-                        cursor: cursor.clone(),
-                        changed_since_slot: None,
-                        filters: vec![
-                            ProgramAccountFilter::DataSize(165),
-                            ProgramAccountFilter::Memcmp(MemcmpFilter {
-                                offset: 0,
-                                bytes: mint_b64.clone(),
-                                encoding: Some("base64".to_string()),
-                            }),
-                        ],
-                        data_slice: None, // Helius v2 doesn't support dataSlice
-                    },
-                )
-                .await?;
-
-            let page_count = page.accounts.len();
-            
-            // Parse full token account data
-            for acc in &page.accounts {
-                if let Some(balance) = Self::parse_token_balance_from_full_account(acc) {
-                    all_balances.push(balance);
-                }
-            }
-
-            tracing::debug!(
-                "ORE token holders page {}: {} accounts fetched, {} total so far, cursor: {}",
-                page_num,
-                page_count,
-                all_balances.len(),
-                page.cursor.is_some()
-            );
+        let page = self
+            .get_program_accounts_v2(
+                &spl_token::ID,
+                GetProgramAccountsV2Options {
+                    encoding: Some("base64".to_string()),
+                    limit: Some(limit_per_page.unwrap_or(5000)),
+                    cursor,
+                    changed_since_slot: None,
+                    filters: vec![
+                        ProgramAccountFilter::DataSize(165),
+                        ProgramAccountFilter::Memcmp(MemcmpFilter {
+                            offset: 0,
+                            bytes: mint_b64,
+                            encoding: Some("base64".to_string()),
+                        }),
+                    ],
+                    data_slice: None, // Helius v2 doesn't support dataSlice
+                },
+            )
+            .await?;
 
-            // Continue until cursor is null (end of pagination)
-            if page.cursor.is_none() {
-                tracing::info!("ORE token holders pagination complete: {} pages, {} holders", page_num, all_balances.len());
-                break;
-            }
-            cursor = page.cursor;
-        }
+        // Parse full token account data
+        let balances = page.accounts.iter()
+            .filter_map(Self::parse_token_balance_from_full_account)
+            .collect();
 
-        Ok(all_balances)
+        Ok((balances, page.cursor))
     }
 
     /// Fetch ORE token balances that changed since a given slot.
     /// Uses dataSlice for efficiency.
     pub async fn get_ore_token_balances_changed_since(
-        &mut self,
+        &self,
         ore_mint: &Pubkey,
         since_slot: u64,
         limit_per_page: Option<u32>,
@@ -2178,7 +2257,7 @@ impl HeliusApi {
     
     /// Fetch all EVORE Manager accounts using getProgramAccountsV2.
     pub async fn get_all_evore_managers(
-        &mut self,
+        &self,
         limit_per_page: Option<u32>,
     ) -> Result<Vec<ProgramAccountV2>, HeliusError> {
         self.get_evore_managers_since(None, limit_per_page).await
@@ -2186,7 +2265,7 @@ impl HeliusApi {
     
     /// Fetch EVORE Manager accounts changed since a slot (incremental updates).
     pub async fn get_evore_managers_changed_since(
-        &mut self,
+        &self,
         since_slot: u64,
         limit_per_page: Option<u32>,
     ) -> Result<Vec<ProgramAccountV2>, HeliusError> {
@@ -2195,7 +2274,7 @@ impl HeliusApi {
     
     /// Internal: Fetch EVORE managers with optional changedSinceSlot.
     async fn get_evore_managers_since(
-        &mut self,
+        &self,
         since_slot: Option<u64>,
         limit_per_page: Option<u32>,
     ) -> Result<Vec<ProgramAccountV2>, HeliusError> {
@@ -2240,7 +2319,7 @@ impl HeliusApi {
     
     /// Fetch all EVORE Deployer accounts using getProgramAccountsV2.
     pub async fn get_all_evore_deployers(
-        &mut self,
+        &self,
         limit_per_page: Option<u32>,
     ) -> Result<Vec<ProgramAccountV2>, HeliusError> {
         self.get_evore_deployers_since(None, limit_per_page).await
@@ -2248,7 +2327,7 @@ impl HeliusApi {
     
     /// Fetch EVORE Deployer accounts changed since a slot (incremental updates).
     pub async fn get_evore_deployers_changed_since(
-        &mut self,
+        &self,
         since_slot: u64,
         limit_per_page: Option<u32>,
     ) -> Result<Vec<ProgramAccountV2>, HeliusError> {
@@ -2257,7 +2336,7 @@ impl HeliusApi {
     
     /// Internal: Fetch EVORE deployers with optional changedSinceSlot.
     async fn get_evore_deployers_since(
-        &mut self,
+        &self,
         since_slot: Option<u64>,
         limit_per_page: Option<u32>,
     ) -> Result<Vec<ProgramAccountV2>, HeliusError> {
@@ -2301,7 +2380,7 @@ impl HeliusApi {
     }
     
     /// Fetch a single account's lamport balance (for ManagedMinerAuth PDAs)
-    pub async fn get_account_balance(&mut self, address: &Pubkey) -> Result<Option<u64>, HeliusError> {
+    pub async fn get_account_balance(&self, address: &Pubkey) -> Result<Option<u64>, HeliusError> {
         // Use getAccountInfo to get just the lamports
         let body = json!({
             "jsonrpc": "2.0",
@@ -2315,14 +2394,7 @@ impl HeliusApi {
         
         let start = Instant::now();
         
-        let response = self.client
-            .post(&self.rpc_url)
-            .json(&body)
-            .send()
-            .await?
-            .error_for_status()?;
-        
-        let response_bytes = response.bytes().await?;
+        let response_bytes = self.post_with_backoff("getAccountInfo", &body, 1).await?;
         let duration_ms = start.elapsed().as_millis() as u32;
         
         let resp: serde_json::Value = serde_json::from_slice(&response_bytes)
@@ -2351,7 +2423,7 @@ impl HeliusApi {
     }
     
     /// Fetch multiple account balances in a batch
-    pub async fn get_multiple_account_balances(&mut self, addresses: &[Pubkey]) -> Result<Vec<(Pubkey, Option<u64>)>, HeliusError> {
+    pub async fn get_multiple_account_balances(&self, addresses: &[Pubkey]) -> Result<Vec<(Pubkey, Option<u64>)>, HeliusError> {
         if addresses.is_empty() {
             return Ok(Vec::new());
         }
@@ -2371,14 +2443,7 @@ impl HeliusApi {
         
         let start = Instant::now();
         
-        let response = self.client
-            .post(&self.rpc_url)
-            .json(&body)
-            .send()
-            .await?
-            .error_for_status()?;
-        
-        let response_bytes = response.bytes().await?;
+        let response_bytes = self.post_with_backoff("getMultipleAccounts", &body, 1).await?;
         let duration_ms = start.elapsed().as_millis() as u32;
         
         let resp: serde_json::Value = serde_json::from_slice(&response_bytes)
@@ -2719,6 +2784,35 @@ struct DecodedDeployIx {
 // Helper Functions
 // ============================================================================
 
+/// Helius credit cost of one call to `method`
+///
+/// Standard RPC methods cost 1 credit; getProgramAccounts(V2) and the
+/// enhanced history methods are billed higher. Unknown methods count as 1.
+fn method_credits(method: &str) -> u32 {
+    match method {
+        "getProgramAccounts" | "getProgramAccountsV2" => 10,
+        "getTransactionsForAddress" => 100,
+        _ => 1,
+    }
+}
+
+/// Whether a JSON-RPC response body is a rate-limit error (some providers return these with 200 OK)
+fn is_rate_limit_body(body: &[u8]) -> bool {
+    // Error bodies are tiny; skip re-parsing large successful pages
+    if body.len() > 4096 {
+        return false;
+    }
+    let Ok(value) = serde_json::from_slice::<Value>(body) else {
+        return false;
+    };
+    let Some(err) = value.get("error") else {
+        return false;
+    };
+    let code = err.get("code").and_then(|c| c.as_i64()).unwrap_or(0);
+    let message = err.get("message").and_then(|m| m.as_str()).unwrap_or("").to_lowercase();
+    code == 429 || code == -32429 || message.contains("rate limit") || message.contains("too many requests")
+}
+
 /// Extract provider name from RPC URL for metrics
 fn extract_provider_name(url: &str) -> String {
     if url.contains("helius") {
@@ -2808,13 +2902,7 @@ fn decode_ore_deploy_ix(
     let body = &data[1..1 + DEPLOY_BODY_SIZE];
     let deploy: &Deploy = bytemuck::from_bytes(body);
 
-    // Accounts layout from SDK deploy helper:
-    // 0: signer
-    // 1: authority
-    // 2: automation_pda
-    // 3: board
-    // 4: miner_pda
-    // 5: round_pda
+    // Account indices come from ore_api::DEPLOY_ACCOUNTS
     let accounts = ix
         .get("accounts")
         .and_then(Value::as_array)
@@ -2832,18 +2920,16 @@ fn decode_ore_deploy_ix(
         Ok(*key)
     };
 
-    let signer = get_key(0)?;
-    let authority = get_key(1)?;
-    let miner_pda = get_key(4)?;
-    let round_pda = get_key(5)?;
+    let layout = ore_api::DEPLOY_ACCOUNTS;
+    let signer = get_key(layout.signer)?;
+    let authority = get_key(layout.authority)?;
+    let miner_pda = get_key(layout.miner)?;
+    let round_pda = get_key(layout.round)?;
 
     let ix_amount = u64::from_le_bytes(deploy.amount);
     let mask_u32 = u32::from_le_bytes(deploy.squares);
 
-    let mut ix_squares = [false; 25];
-    for i in 0..25 {
-        ix_squares[i] = (mask_u32 & (1 << i)) != 0;
-    }
+    let ix_squares = mask_to_squares(mask_u32);
 
     Ok(Some(DecodedDeployIx {
         signer,
@@ -2934,6 +3020,8 @@ pub struct CalculatedDeployment {
     pub actual_mask: u64,
     /// Number of squares actually deployed
     pub actual_squares: u8,
+    /// Amount per square from the automation settings in effect
+    pub amount_per_square: u64,
     /// Total SOL spent (squares * amount + fee)
     pub total_spent: u64,
     /// Was this a partial deploy (ran out of balance)?
@@ -3018,6 +3106,64 @@ pub fn calculate_actual_deployment(
     (actual_mask, actual_squares, total_spent, is_partial)
 }
 
+/// A deployment where the balance-scan reconstruction disagrees with its DeployEvent
+#[derive(Debug, Clone, Serialize)]
+pub struct ReconstructionDiff {
+    pub signature: String,
+    pub authority: String,
+    pub slot: u64,
+    pub event_mask: u64,
+    pub event_amount: u64,
+    pub event_squares: u64,
+    /// None when the balance scan found no matching deploy
+    pub scan_mask: Option<u64>,
+    pub scan_amount: Option<u64>,
+    pub scan_squares: Option<u8>,
+    /// Fields that differ ("mask", "amount", "squares"), or "missing"
+    pub mismatches: Vec<&'static str>,
+}
+
+/// Compare a DeployEvent against the balance-scan deploy for the same signature.
+/// Returns None when they agree.
+pub fn compare_reconstruction(
+    event: &ParsedDeployEvent,
+    calculated: Option<&CalculatedDeployment>,
+) -> Option<ReconstructionDiff> {
+    let mismatches = match calculated {
+        None => vec!["missing"],
+        Some(calc) => {
+            let mut fields = Vec::new();
+            if calc.actual_mask != event.event.mask {
+                fields.push("mask");
+            }
+            if calc.amount_per_square != event.event.amount {
+                fields.push("amount");
+            }
+            if calc.actual_squares as u64 != event.event.total_squares {
+                fields.push("squares");
+            }
+            fields
+        }
+    };
+
+    if mismatches.is_empty() {
+        return None;
+    }
+
+    Some(ReconstructionDiff {
+        signature: event.signature.clone(),
+        authority: event.event.authority.to_string(),
+        slot: event.slot,
+        event_mask: event.event.mask,
+        event_amount: event.event.amount,
+        event_squares: event.event.total_squares,
+        scan_mask: calculated.map(|c| c.actual_mask),
+        scan_amount: calculated.map(|c| c.amount_per_square),
+        scan_squares: calculated.map(|c| c.actual_squares),
+        mismatches,
+    })
+}
+
 /// Result of scanning automation history with full balance tracking
 #[derive(Debug, Clone)]
 pub struct AutomationHistoryScan {
@@ -3030,6 +3176,8 @@ pub struct AutomationHistoryScan {
     /// Stats
     pub txns_searched: u32,
     pub pages_fetched: u32,
+    /// The scan stopped at its `ScanLimit` with history left unread
+    pub truncated: bool,
 }
 
 /// Info about an Automate Open instruction
@@ -3054,7 +3202,7 @@ impl HeliusApi {
     /// 
     /// Returns events sorted chronologically and pre-calculated deployment info.
     pub async fn scan_automation_history_with_balance(
-        &mut self,
+        &self,
         authority: &Pubkey,
         target_slot: u64,
         stop_at_slot: Option<u64>,
@@ -3064,6 +3212,7 @@ impl HeliusApi {
         let mut txns_searched = 0u32;
         let mut pages_fetched = 0u32;
         let mut found_automate_open = false;
+        let mut truncated = false;
         
         let mut pagination_token: Option<String> = None;
         
@@ -3208,6 +3357,10 @@ impl HeliusApi {
             if pagination_token.is_none() {
                 break;
             }
+            if self.scan_limit.reached(pages_fetched, txns_searched) {
+                truncated = true;
+                break;
+            }
         }
         
         // Sort events chronologically (oldest first)
@@ -3248,6 +3401,7 @@ impl HeliusApi {
             calculated_deploys,
             txns_searched,
             pages_fetched,
+            truncated,
         })
     }
     
@@ -3317,15 +3471,15 @@ impl HeliusApi {
                     account_keys.get(acc_idx).copied()
                 };
                 
-                let signer = match get_key(0) {
+                let signer = match get_key(ore_api::AUTOMATE_ACCOUNTS.signer) {
                     Some(k) => k,
                     None => return Ok(None),
                 };
-                let autom_acc = match get_key(1) {
+                let autom_acc = match get_key(ore_api::AUTOMATE_ACCOUNTS.automation) {
                     Some(k) => k,
                     None => return Ok(None),
                 };
-                let executor = get_key(2).unwrap_or_default();
+                let executor = get_key(ore_api::AUTOMATE_ACCOUNTS.executor).unwrap_or_default();
                 
                 // Must match expected authority and automation PDA
                 if signer != *authority || autom_acc != *automation_pda {
@@ -3384,17 +3538,17 @@ impl HeliusApi {
                 }
                 let accounts = accounts.unwrap();
                 
-                // Accounts: [signer, automation_info, miner_info, round_info, treasury, system_program]
+                // Account indices come from ore_api::DEPLOY_ACCOUNTS
                 let get_key = |idx: usize| -> Option<Pubkey> {
                     let acc_idx = accounts.get(idx)?.as_u64()? as usize;
                     account_keys.get(acc_idx).copied()
                 };
                 
-                let _signer = match get_key(0) {
+                let _signer = match get_key(ore_api::DEPLOY_ACCOUNTS.signer) {
                     Some(k) => k,
                     None => return Ok(None),
                 };
-                let autom_acc = get_key(1);
+                let autom_acc = get_key(ore_api::DEPLOY_ACCOUNTS.automation);
                 
                 // Check if this deploy uses automation (automation account matches)
                 let uses_automation = autom_acc.map(|a| a == *automation_pda).unwrap_or(false);
@@ -3492,6 +3646,7 @@ impl HeliusApi {
                         balance_after: balance,
                         actual_mask,
                         actual_squares,
+                        amount_per_square: current_amount,
                         total_spent,
                         is_partial,
                     });
@@ -3502,3 +3657,85 @@ impl HeliusApi {
         results
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_method_credits() {
+        assert_eq!(method_credits("getAccountInfo"), 1);
+        assert_eq!(method_credits("getMultipleAccounts"), 1);
+        assert_eq!(method_credits("getProgramAccountsV2"), 10);
+        assert_eq!(method_credits("getTransactionsForAddress"), 100);
+    }
+
+    #[test]
+    fn test_is_rate_limit_body() {
+        assert!(is_rate_limit_body(br#"{"jsonrpc":"2.0","id":1,"error":{"code":429,"message":"Too many requests"}}"#));
+        assert!(is_rate_limit_body(br#"{"jsonrpc":"2.0","id":1,"error":{"code":-32429,"message":"rate limited"}}"#));
+        assert!(is_rate_limit_body(br#"{"jsonrpc":"2.0","id":1,"error":{"code":-32000,"message":"Rate limit exceeded"}}"#));
+        assert!(!is_rate_limit_body(br#"{"jsonrpc":"2.0","id":1,"error":{"code":-32602,"message":"Invalid params"}}"#));
+        assert!(!is_rate_limit_body(br#"{"jsonrpc":"2.0","id":1,"result":{"value":null}}"#));
+        assert!(!is_rate_limit_body(b"not json"));
+    }
+
+    #[test]
+    fn test_scan_limit_reached() {
+        let limit = ScanLimit::default();
+        assert!(!limit.reached(DEFAULT_SCAN_MAX_PAGES - 1, u32::MAX));
+        assert!(limit.reached(DEFAULT_SCAN_MAX_PAGES, 0));
+
+        let by_txns = ScanLimit { max_pages: 0, max_txns: 250 };
+        assert!(!by_txns.reached(1_000, 249));
+        assert!(by_txns.reached(3, 250));
+
+        let unbounded = ScanLimit { max_pages: 0, max_txns: 0 };
+        assert!(!unbounded.reached(u32::MAX, u32::MAX));
+    }
+
+    #[test]
+    fn test_compare_reconstruction() {
+        let authority = Pubkey::new_unique();
+        let parsed = ParsedDeployEvent {
+            event: DeployEvent {
+                disc: 1,
+                authority,
+                amount: 1_000,
+                mask: 0b111,
+                round_id: 7,
+                strategy: 0,
+                total_squares: 3,
+                ..Default::default()
+            },
+            slot: 100,
+            signature: "sig".to_string(),
+            instruction_index: 0,
+        };
+        let mut calc = CalculatedDeployment {
+            slot: 100,
+            signature: "sig".to_string(),
+            ix_index: 0,
+            round_id: 7,
+            balance_before: 10_000,
+            balance_after: 7_000,
+            actual_mask: 0b111,
+            actual_squares: 3,
+            amount_per_square: 1_000,
+            total_spent: 3_000,
+            is_partial: false,
+        };
+        assert!(compare_reconstruction(&parsed, Some(&calc)).is_none());
+
+        // Partial deploy computed where the event shows a full one
+        calc.actual_mask = 0b011;
+        calc.actual_squares = 2;
+        let diff = compare_reconstruction(&parsed, Some(&calc)).unwrap();
+        assert_eq!(diff.mismatches, vec!["mask", "squares"]);
+        assert_eq!(diff.scan_mask, Some(0b011));
+
+        let diff = compare_reconstruction(&parsed, None).unwrap();
+        assert_eq!(diff.mismatches, vec!["missing"]);
+        assert_eq!(diff.authority, authority.to_string());
+    }
+}
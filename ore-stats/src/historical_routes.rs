@@ -7,12 +7,14 @@ use std::sync::Arc;
 
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
     routing::get,
     Json, Router,
 };
+use evore::board::SQUARES;
 use serde::{Deserialize, Serialize};
+use steel::Pubkey;
 
+use crate::app_error::AppError;
 use crate::app_state::AppState;
 
 // ============================================================================
@@ -79,10 +81,15 @@ pub struct DeploymentsQuery {
     pub cursor: Option<String>,
     pub limit: Option<u32>,
     // Range filters
+    #[serde(alias = "round_from")]
     pub round_id_gte: Option<u64>,
+    #[serde(alias = "round_to")]
     pub round_id_lte: Option<u64>,
-    // Miner filter
+    // Miner (deploy authority) filter
+    #[serde(alias = "authority")]
     pub miner: Option<String>,
+    /// Only deployments to this square (0-24)
+    pub square: Option<u8>,
     // Boolean filters
     pub winner_only: Option<bool>,
     // Amount filters
@@ -215,6 +222,23 @@ pub struct MinerSquareStats {
     pub total_rounds: u64,
 }
 
+#[derive(Debug, Serialize)]
+pub struct AuthorityWinRate {
+    pub authority: String,
+    pub round_id_gte: Option<u64>,
+    pub round_id_lte: Option<u64>,
+    /// Finalized rounds the authority deployed in
+    pub deploys: u64,
+    /// Rounds where one of the deployed squares was the winning square
+    pub wins: u64,
+    /// Expected wins by chance (sum of squares_covered / 25 per round)
+    pub expected_wins: f64,
+    pub win_rate: f64,
+    pub expected_win_rate: f64,
+    /// (wins - expected) / stddev; None when there is no variance (no rounds, or all 25 squares every round)
+    pub z_score: Option<f64>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct LeaderboardEntry {
     pub rank: u32,
@@ -259,11 +283,6 @@ pub struct TreasurySnapshot {
     pub created_at: String,
 }
 
-#[derive(Debug, Serialize)]
-pub struct ErrorResponse {
-    pub error: String,
-}
-
 // ============================================================================
 // Router
 // ============================================================================
@@ -283,6 +302,9 @@ pub fn historical_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
         .route("/miner/{pubkey}/stats", get(get_miner_stats))
         .route("/miner/{pubkey}/square-stats", get(get_miner_square_stats))
         
+        // Authority analysis
+        .route("/authority/{authority}/win-rate", get(get_authority_win_rate))
+        
         // Miner snapshots (all miners from latest round snapshot)
         .route("/miners", get(get_miner_snapshots))
         
@@ -298,6 +320,12 @@ pub fn historical_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
         // Treasury history
         .route("/treasury/history", get(get_treasury_history))
         
+        // Daily aggregates
+        .route("/daily", get(get_daily_stats))
+        
+        // Automation strategy mix
+        .route("/automation-strategies", get(get_automation_strategies))
+        
         .with_state(state)
 }
 
@@ -309,7 +337,7 @@ pub fn historical_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
 async fn get_rounds(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RoundsQuery>,
-) -> Result<Json<CursorResponse<HistoricalRound>>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<CursorResponse<HistoricalRound>>, AppError> {
     let limit = params.limit.unwrap_or(50).min(100);
     let order_desc = params.order.as_deref() != Some("asc");
     
@@ -328,7 +356,7 @@ async fn get_rounds(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get rounds: {}", e);
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { error: "Database error".to_string() }))
+            AppError::Internal("Database error".to_string())
         })?;
     
     let has_more = rounds.len() as u32 == limit;
@@ -360,16 +388,16 @@ async fn get_rounds(
 async fn get_round_detail(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
-) -> Result<Json<HistoricalRound>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<HistoricalRound>, AppError> {
     let round = state.clickhouse
         .get_round_by_id(round_id)
         .await
         .map_err(|e| {
             tracing::error!("Failed to get round {}: {}", round_id, e);
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { error: "Database error".to_string() }))
+            AppError::Internal("Database error".to_string())
         })?
         .ok_or_else(|| {
-            (StatusCode::NOT_FOUND, Json(ErrorResponse { error: "Round not found".to_string() }))
+            AppError::NotFound("Round not found".to_string())
         })?;
     
     Ok(Json(HistoricalRound {
@@ -393,7 +421,7 @@ async fn get_round_deployments(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
     Query(params): Query<DeploymentsQuery>,
-) -> Result<Json<CursorResponse<HistoricalDeployment>>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<CursorResponse<HistoricalDeployment>>, AppError> {
     let limit = params.limit.unwrap_or(100).min(500);
     
     let deployments = state.clickhouse
@@ -408,7 +436,7 @@ async fn get_round_deployments(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get deployments for round {}: {}", round_id, e);
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { error: "Database error".to_string() }))
+            AppError::Internal("Database error".to_string())
         })?;
     
     let has_more = deployments.len() as u32 == limit;
@@ -442,14 +470,23 @@ async fn get_round_deployments(
 async fn get_deployments(
     State(state): State<Arc<AppState>>,
     Query(params): Query<DeploymentsQuery>,
-) -> Result<Json<CursorResponse<HistoricalDeployment>>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<CursorResponse<HistoricalDeployment>>, AppError> {
     let limit = params.limit.unwrap_or(100).min(500);
     
+    if params.square.is_some_and(|square| square as usize >= SQUARES) {
+        return Err(AppError::BadRequest(format!("square must be 0-{}", SQUARES - 1)));
+    }
+    if let Some(miner) = &params.miner {
+        miner.parse::<Pubkey>()
+            .map_err(|_| AppError::BadRequest("Invalid authority pubkey".to_string()))?;
+    }
+    
     let deployments = state.clickhouse
         .get_deployments_filtered(
             params.round_id_gte,
             params.round_id_lte,
             params.miner.as_deref(),
+            params.square,
             params.winner_only,
             params.min_sol_earned,
             params.max_sol_earned,
@@ -461,7 +498,7 @@ async fn get_deployments(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get deployments: {}", e);
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { error: "Database error".to_string() }))
+            AppError::Internal("Database error".to_string())
         })?;
     
     let has_more = deployments.len() as u32 == limit;
@@ -496,7 +533,7 @@ async fn get_miner_deployments(
     State(state): State<Arc<AppState>>,
     Path(pubkey): Path<String>,
     Query(params): Query<MinerHistoryQuery>,
-) -> Result<Json<CursorResponse<HistoricalDeployment>>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<CursorResponse<HistoricalDeployment>>, AppError> {
     let limit = params.limit.unwrap_or(100).min(500);
     
     let deployments = state.clickhouse
@@ -513,7 +550,7 @@ async fn get_miner_deployments(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get miner deployments for {}: {}", pubkey, e);
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { error: "Database error".to_string() }))
+            AppError::Internal("Database error".to_string())
         })?;
     
     let has_more = deployments.len() as u32 == limit;
@@ -551,16 +588,16 @@ async fn get_miner_stats(
     State(state): State<Arc<AppState>>,
     Path(pubkey): Path<String>,
     Query(params): Query<MinerStatsQuery>,
-) -> Result<Json<MinerStats>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<MinerStats>, AppError> {
     let stats = state.clickhouse
         .get_miner_stats(&pubkey, params.round_id_gte, params.round_id_lte)
         .await
         .map_err(|e| {
             tracing::error!("Failed to get miner stats for {}: {}", pubkey, e);
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { error: "Database error".to_string() }))
+            AppError::Internal("Database error".to_string())
         })?
         .ok_or_else(|| {
-            (StatusCode::NOT_FOUND, Json(ErrorResponse { error: "Miner not found in historical data".to_string() }))
+            AppError::NotFound("Miner not found in historical data".to_string())
         })?;
     
     Ok(Json(stats))
@@ -578,13 +615,46 @@ async fn get_miner_square_stats(
     State(state): State<Arc<AppState>>,
     Path(pubkey): Path<String>,
     Query(params): Query<SquareStatsQuery>,
-) -> Result<Json<MinerSquareStats>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<MinerSquareStats>, AppError> {
     let stats = state.clickhouse
         .get_miner_square_stats(&pubkey, params.round_id_gte, params.round_id_lte)
         .await
         .map_err(|e| {
             tracing::error!("Failed to get square stats for {}: {}", pubkey, e);
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { error: "Database error".to_string() }))
+            AppError::Internal("Database error".to_string())
+        })?;
+    
+    Ok(Json(stats))
+}
+
+// ============================================================================
+// Authority Analysis Handlers
+// ============================================================================
+
+/// Win-rate query params (round id range, inclusive)
+#[derive(Debug, Deserialize)]
+pub struct WinRateQuery {
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+}
+
+/// GET /history/authority/{authority}/win-rate - Winning-square hit rate vs chance
+/// 
+/// Counts the finalized rounds where the authority's deployments covered the
+/// winning square and compares against the expected rate given how many squares
+/// were covered each round. A large positive z-score suggests the authority
+/// lands on the winning square more often than chance.
+async fn get_authority_win_rate(
+    State(state): State<Arc<AppState>>,
+    Path(authority): Path<String>,
+    Query(params): Query<WinRateQuery>,
+) -> Result<Json<AuthorityWinRate>, AppError> {
+    let stats = state.clickhouse
+        .get_authority_win_rate(&authority, params.from, params.to)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get win rate for {}: {}", authority, e);
+            AppError::Internal("Database error".to_string())
         })?;
     
     Ok(Json(stats))
@@ -598,7 +668,7 @@ async fn get_miner_square_stats(
 async fn get_miner_snapshots(
     State(state): State<Arc<AppState>>,
     Query(params): Query<MinerSnapshotsQuery>,
-) -> Result<Json<MinerSnapshotsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<MinerSnapshotsResponse>, AppError> {
     // Get round_id (use latest if not specified)
     let round_id = if let Some(id) = params.round_id {
         id
@@ -608,10 +678,10 @@ async fn get_miner_snapshots(
             .await
             .map_err(|e| {
                 tracing::error!("Failed to get latest snapshot round: {}", e);
-                (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { error: "Database error".to_string() }))
+                AppError::Internal("Database error".to_string())
             })?
             .ok_or_else(|| {
-                (StatusCode::NOT_FOUND, Json(ErrorResponse { error: "No miner snapshots found".to_string() }))
+                AppError::NotFound("No miner snapshots found".to_string())
             })?
     };
     
@@ -626,7 +696,7 @@ async fn get_miner_snapshots(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get miner snapshots: {}", e);
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { error: "Database error".to_string() }))
+            AppError::Internal("Database error".to_string())
         })?;
     
     let data: Vec<MinerSnapshotEntry> = rows
@@ -660,7 +730,7 @@ async fn get_miner_snapshots(
 async fn get_leaderboard(
     State(state): State<Arc<AppState>>,
     Query(params): Query<LeaderboardQuery>,
-) -> Result<Json<OffsetResponse<LeaderboardEntry>>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<OffsetResponse<LeaderboardEntry>>, AppError> {
     get_leaderboard_internal(state, params, "net_sol").await
 }
 
@@ -668,7 +738,7 @@ async fn get_leaderboard(
 async fn get_leaderboard_sol(
     State(state): State<Arc<AppState>>,
     Query(params): Query<LeaderboardQuery>,
-) -> Result<Json<OffsetResponse<LeaderboardEntry>>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<OffsetResponse<LeaderboardEntry>>, AppError> {
     get_leaderboard_internal(state, params, "sol_earned").await
 }
 
@@ -676,7 +746,7 @@ async fn get_leaderboard_sol(
 async fn get_leaderboard_ore(
     State(state): State<Arc<AppState>>,
     Query(params): Query<LeaderboardQuery>,
-) -> Result<Json<OffsetResponse<LeaderboardEntry>>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<OffsetResponse<LeaderboardEntry>>, AppError> {
     get_leaderboard_internal(state, params, "ore_earned").await
 }
 
@@ -684,7 +754,7 @@ async fn get_leaderboard_ore(
 async fn get_leaderboard_winners(
     State(state): State<Arc<AppState>>,
     Query(params): Query<LeaderboardQuery>,
-) -> Result<Json<OffsetResponse<LeaderboardEntry>>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<OffsetResponse<LeaderboardEntry>>, AppError> {
     get_leaderboard_internal(state, params, "rounds_won").await
 }
 
@@ -692,7 +762,7 @@ async fn get_leaderboard_internal(
     state: Arc<AppState>,
     params: LeaderboardQuery,
     default_metric: &str,
-) -> Result<Json<OffsetResponse<LeaderboardEntry>>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<OffsetResponse<LeaderboardEntry>>, AppError> {
     let metric = params.metric.as_deref().unwrap_or(default_metric);
     let page = params.page.unwrap_or(1).max(1);
     let limit = params.limit.unwrap_or(50).min(100);
@@ -718,7 +788,7 @@ async fn get_leaderboard_internal(
             .await
     }.map_err(|e| {
         tracing::error!("Failed to get leaderboard: {}", e);
-        (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { error: e.to_string() }))
+        AppError::Internal(e.to_string())
     })?;
     
     let total_pages = ((total_count as f64) / (limit as f64)).ceil() as u32;
@@ -750,13 +820,13 @@ struct CostPerOreQuery {
 async fn get_cost_per_ore(
     State(state): State<Arc<AppState>>,
     Query(params): Query<CostPerOreQuery>,
-) -> Result<Json<crate::clickhouse::CostPerOreStats>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<crate::clickhouse::CostPerOreStats>, AppError> {
     let stats = state.clickhouse
         .get_cost_per_ore_stats(params.round_id_gte, params.round_id_lte)
         .await
         .map_err(|e| {
             tracing::error!("Failed to get cost per ORE stats: {}", e);
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { error: e.to_string() }))
+            AppError::Internal(e.to_string())
         })?;
     
     Ok(Json(stats))
@@ -770,7 +840,7 @@ async fn get_cost_per_ore(
 async fn get_treasury_history(
     State(state): State<Arc<AppState>>,
     Query(params): Query<TreasuryHistoryQuery>,
-) -> Result<Json<CursorResponse<TreasurySnapshot>>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<CursorResponse<TreasurySnapshot>>, AppError> {
     let limit = params.limit.unwrap_or(50).min(100);
     
     let snapshots = state.clickhouse
@@ -783,7 +853,7 @@ async fn get_treasury_history(
         .await
         .map_err(|e| {
             tracing::error!("Failed to get treasury history: {}", e);
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { error: "Database error".to_string() }))
+            AppError::Internal("Database error".to_string())
         })?;
     
     let has_more = snapshots.len() as u32 == limit;
@@ -796,13 +866,293 @@ async fn get_treasury_history(
     }))
 }
 
+// ============================================================================
+// Daily Aggregates Handler
+// ============================================================================
+
+/// Longest range `/history/daily` returns
+const MAX_DAILY_DAYS: u32 = 365;
+
+#[derive(Debug, Deserialize)]
+struct DailyStatsQuery {
+    /// Last day (YYYY-MM-DD, UTC); default today
+    date: Option<String>,
+    /// Number of days ending at `date` (default 1, max 365)
+    days: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DailyStats {
+    /// YYYY-MM-DD (UTC)
+    pub date: String,
+    pub rounds_finalized: u64,
+    /// Lamports
+    pub total_deployed: u64,
+    /// Atomic units (11 decimals)
+    pub ore_minted: u64,
+    pub unique_miners: u64,
+    pub motherlode_hits: u64,
+}
+
+/// First and last day for a daily query
+fn daily_range(
+    date: Option<&str>,
+    days: Option<u32>,
+    today: chrono::NaiveDate,
+) -> Result<(chrono::NaiveDate, chrono::NaiveDate), AppError> {
+    let to = match date {
+        Some(d) => chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d")
+            .map_err(|_| AppError::BadRequest(format!("Invalid date '{}', expected YYYY-MM-DD", d)))?,
+        None => today,
+    };
+    let days = days.unwrap_or(1).clamp(1, MAX_DAILY_DAYS);
+    Ok((to - chrono::Days::new(days as u64 - 1), to))
+}
+
+/// GET /history/daily - Per-day totals of finalized rounds
+///
+/// `?date=YYYY-MM-DD` for one day, `?days=7` for the last week, or both for
+/// the `days` ending at `date`. One row per day, oldest first, zero rows for
+/// days without rounds.
+async fn get_daily_stats(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<DailyStatsQuery>,
+) -> Result<Json<Vec<DailyStats>>, AppError> {
+    let today = chrono::Utc::now().date_naive();
+    let (from, to) = daily_range(params.date.as_deref(), params.days, today)?;
+    
+    let rows = state.clickhouse
+        .get_daily_aggregates(from, to)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get daily aggregates: {}", e);
+            AppError::Internal("Database error".to_string())
+        })?;
+    
+    Ok(Json(rows.into_iter().map(|r| DailyStats {
+        date: format_day(r.day),
+        rounds_finalized: r.rounds_finalized,
+        total_deployed: r.total_deployed,
+        ore_minted: r.ore_minted,
+        unique_miners: r.unique_miners,
+        motherlode_hits: r.motherlode_hits,
+    }).collect()))
+}
+
+// ============================================================================
+// Automation Strategy Handler
+// ============================================================================
+
+/// Longest round range `/history/automation-strategies?group_by=round` returns
+const MAX_STRATEGY_ROUNDS: u64 = 1000;
+
+#[derive(Debug, Deserialize)]
+struct AutomationStrategiesQuery {
+    /// "day" (default) or "round"
+    group_by: Option<String>,
+    /// First day (YYYY-MM-DD) or round id; days default to the 30 ending at `to`
+    from: Option<String>,
+    /// Last day (YYYY-MM-DD, default today) or round id
+    to: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StrategyUsage {
+    /// "random", "preferred", "discretionary", or "manual" (no active automation)
+    pub strategy: &'static str,
+    pub deployments: u64,
+    pub miners: u64,
+    /// Lamports
+    pub total_sol_spent: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AutomationStrategyPeriod {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub round_id: Option<u64>,
+    /// YYYY-MM-DD (UTC)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    pub strategies: Vec<StrategyUsage>,
+}
+
+/// `(by_day, from, to)` for an automation strategy query; days are days since 1970-01-01
+fn strategy_usage_range(
+    params: &AutomationStrategiesQuery,
+    today: chrono::NaiveDate,
+) -> Result<(bool, u64, u64), AppError> {
+    match params.group_by.as_deref().unwrap_or("day") {
+        "day" => {
+            let day = |d: &str| {
+                chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d")
+                    .map_err(|_| AppError::BadRequest(format!("Invalid date '{}', expected YYYY-MM-DD", d)))
+            };
+            let to = params.to.as_deref().map(day).transpose()?.unwrap_or(today);
+            let from = match params.from.as_deref() {
+                Some(d) => day(d)?,
+                None => to - chrono::Days::new(29),
+            };
+            let (from, to) = (days_since_epoch(from), days_since_epoch(to));
+            if from > to || to - from >= MAX_DAILY_DAYS as u64 {
+                return Err(AppError::BadRequest(format!("Range must be 1 to {} days", MAX_DAILY_DAYS)));
+            }
+            Ok((true, from, to))
+        }
+        "round" => {
+            let round = |r: Option<&str>, name: &str| {
+                r.ok_or_else(|| AppError::BadRequest(format!("'{}' round id is required with group_by=round", name)))?
+                    .parse::<u64>()
+                    .map_err(|_| AppError::BadRequest(format!("Invalid round id for '{}'", name)))
+            };
+            let (from, to) = (round(params.from.as_deref(), "from")?, round(params.to.as_deref(), "to")?);
+            if from > to || to - from >= MAX_STRATEGY_ROUNDS {
+                return Err(AppError::BadRequest(format!("Range must be 1 to {} rounds", MAX_STRATEGY_ROUNDS)));
+            }
+            Ok((false, from, to))
+        }
+        other => Err(AppError::BadRequest(format!("Invalid group_by '{}', expected 'day' or 'round'", other))),
+    }
+}
+
+fn strategy_name(strategy: u8) -> &'static str {
+    match strategy {
+        crate::clickhouse::MANUAL_STRATEGY => "manual",
+        s => match evore::ore_api::AutomationStrategy::try_from(s) {
+            Ok(evore::ore_api::AutomationStrategy::Random) => "random",
+            Ok(evore::ore_api::AutomationStrategy::Preferred) => "preferred",
+            Ok(evore::ore_api::AutomationStrategy::Discretionary) => "discretionary",
+            Err(_) => "unknown",
+        },
+    }
+}
+
+/// One entry per round or day (in `rows` order) with its strategies
+fn group_strategy_usage(
+    by_day: bool,
+    rows: Vec<crate::clickhouse::AutomationStrategyUsageRow>,
+) -> Vec<AutomationStrategyPeriod> {
+    let mut periods: Vec<(u64, AutomationStrategyPeriod)> = Vec::new();
+    for row in rows {
+        if periods.last().map_or(true, |(period, _)| *period != row.period) {
+            periods.push((row.period, AutomationStrategyPeriod {
+                round_id: (!by_day).then_some(row.period),
+                date: by_day.then(|| format_day(row.period as u16)),
+                strategies: Vec::new(),
+            }));
+        }
+        if let Some((_, period)) = periods.last_mut() {
+            period.strategies.push(StrategyUsage {
+                strategy: strategy_name(row.strategy),
+                deployments: row.deployments,
+                miners: row.miners,
+                total_sol_spent: row.total_sol_spent,
+            });
+        }
+    }
+    periods.into_iter().map(|(_, period)| period).collect()
+}
+
+/// GET /history/automation-strategies - Deployment mix by automation strategy
+///
+/// Counts reconstructed deploy automation per strategy, per day
+/// (`?from=YYYY-MM-DD&to=YYYY-MM-DD`, default the last 30 days) or per round
+/// (`?group_by=round&from=<round>&to=<round>`). Only deploys whose automation
+/// has been reconstructed are counted; periods without any are omitted.
+async fn get_automation_strategies(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<AutomationStrategiesQuery>,
+) -> Result<Json<Vec<AutomationStrategyPeriod>>, AppError> {
+    let (by_day, from, to) = strategy_usage_range(&params, chrono::Utc::now().date_naive())?;
+    
+    let rows = state.clickhouse
+        .get_automation_strategy_usage(by_day, from, to)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get automation strategy usage: {}", e);
+            AppError::Internal("Database error".to_string())
+        })?;
+    
+    Ok(Json(group_strategy_usage(by_day, rows)))
+}
+
 // ============================================================================
 // Helpers
 // ============================================================================
 
+/// Days since 1970-01-01 (ClickHouse Date)
+fn days_since_epoch(date: chrono::NaiveDate) -> u64 {
+    (date - chrono::NaiveDate::default()).num_days().max(0) as u64
+}
+
+/// ClickHouse Date (days since 1970-01-01) as YYYY-MM-DD
+fn format_day(day: u16) -> String {
+    (chrono::NaiveDate::default() + chrono::Days::new(day as u64)).to_string()
+}
+
 fn format_timestamp(ts: i64) -> String {
     chrono::DateTime::from_timestamp(ts, 0)
         .map(|dt| dt.to_rfc3339())
         .unwrap_or_else(|| ts.to_string())
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    
+    #[test]
+    fn test_daily_range() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let day = |d: u32| NaiveDate::from_ymd_opt(2025, 3, d).unwrap();
+        
+        assert_eq!(daily_range(None, None, today).unwrap(), (today, today));
+        assert_eq!(daily_range(None, Some(7), today).unwrap(), (day(4), today));
+        assert_eq!(daily_range(Some("2025-03-02"), None, today).unwrap(), (day(2), day(2)));
+        assert_eq!(daily_range(Some("2025-03-02"), Some(2), today).unwrap(), (day(1), day(2)));
+        
+        // days is clamped to 1..=365
+        assert_eq!(daily_range(None, Some(0), today).unwrap(), (today, today));
+        let (from, _) = daily_range(None, Some(10_000), today).unwrap();
+        assert_eq!((today - from).num_days(), 364);
+        
+        assert!(matches!(daily_range(Some("03/02/2025"), None, today), Err(AppError::BadRequest(_))));
+        assert_eq!(format_day(0), "1970-01-01");
+        assert_eq!(format_day(20_157), "2025-03-10");
+    }
+    
+    #[test]
+    fn test_automation_strategies() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let query = |group_by: Option<&str>, from: Option<&str>, to: Option<&str>| AutomationStrategiesQuery {
+            group_by: group_by.map(String::from),
+            from: from.map(String::from),
+            to: to.map(String::from),
+        };
+        
+        assert_eq!(strategy_usage_range(&query(None, None, None), today).unwrap(), (true, 20_128, 20_157));
+        assert_eq!(strategy_usage_range(&query(Some("day"), Some("2025-03-09"), Some("2025-03-10")), today).unwrap(), (true, 20_156, 20_157));
+        assert_eq!(strategy_usage_range(&query(Some("round"), Some("5"), Some("7")), today).unwrap(), (false, 5, 7));
+        assert!(strategy_usage_range(&query(Some("round"), Some("5"), None), today).is_err());
+        assert!(strategy_usage_range(&query(Some("round"), Some("7"), Some("5")), today).is_err());
+        assert!(strategy_usage_range(&query(Some("round"), Some("0"), Some("1000")), today).is_err());
+        assert!(strategy_usage_range(&query(Some("week"), None, None), today).is_err());
+        
+        let row = |period, strategy, deployments| crate::clickhouse::AutomationStrategyUsageRow {
+            period, strategy, deployments, miners: 1, total_sol_spent: 0,
+        };
+        let periods = group_strategy_usage(false, vec![
+            row(5, 0, 3), row(5, crate::clickhouse::MANUAL_STRATEGY, 2), row(6, 1, 1),
+        ]);
+        assert_eq!(periods.len(), 2);
+        assert_eq!(periods[0].round_id, Some(5));
+        assert!(periods[0].date.is_none());
+        let names: Vec<_> = periods[0].strategies.iter().map(|s| (s.strategy, s.deployments)).collect();
+        assert_eq!(names, vec![("random", 3), ("manual", 2)]);
+        assert_eq!(periods[1].strategies[0].strategy, "preferred");
+        
+        let periods = group_strategy_usage(true, vec![row(20_157, 2, 1)]);
+        assert_eq!(periods[0].date.as_deref(), Some("2025-03-10"));
+        assert_eq!(periods[0].strategies[0].strategy, "discretionary");
+    }
+}
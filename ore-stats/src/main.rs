@@ -11,12 +11,13 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 
+use evore::network::{Network, NetworkConfig};
+use steel::Pubkey;
 use axum::{
     middleware::from_fn_with_state,
-    routing::get,
+    routing::{get, post},
     Router,
 };
-use tokio::sync::RwLock;
 // CORS is handled by nginx - no tower_http::cors needed
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
@@ -26,13 +27,20 @@ mod app_state;
 mod app_error;
 mod app_rpc;
 mod automation_states;
+mod balance_cache;
 mod chart_routes;
 mod clickhouse;
 mod database;
+mod deployer_fees;
 mod entropy_api;
 mod external_api;
 mod helius_api;
+mod ore_price;
 mod ore_token_cache;
+mod rate_limiter;
+mod readiness;
+mod round_payouts;
+mod round_sim;
 mod routes;
 mod rpc;
 mod sse;
@@ -57,7 +65,7 @@ mod round_addresses;
 mod txn_backfill;
 
 use app_state::AppState;
-use app_rpc::AppRpc;
+use app_rpc::{AppRpc, RoutingPolicy};
 use clickhouse::ClickHouseClient;
 use helius_api::HeliusApi;
 use ore_token_cache::OreTokenCache;
@@ -90,10 +98,29 @@ async fn main() -> anyhow::Result<()> {
     let clickhouse_db = env::var("CLICKHOUSE_DATABASE")
         .unwrap_or_else(|_| "ore_stats".to_string());
     
+    let clickhouse_query_timeout = env::var("CLICKHOUSE_QUERY_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(crate::clickhouse::DEFAULT_QUERY_TIMEOUT_SECS);
+    let clickhouse_admin_query_timeout = env::var("CLICKHOUSE_ADMIN_QUERY_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(crate::clickhouse::DEFAULT_ADMIN_QUERY_TIMEOUT_SECS);
+    
+    let clickhouse_table_prefix = env::var("CLICKHOUSE_TABLE_PREFIX").unwrap_or_default();
+    
     let clickhouse: Arc<ClickHouseClient> = Arc::new(
         ClickHouseClient::new(&clickhouse_url, &clickhouse_db, &clickhouse_user, &clickhouse_password)
+            .with_query_timeouts(
+                Duration::from_secs(clickhouse_query_timeout),
+                Duration::from_secs(clickhouse_admin_query_timeout),
+            )
+            .with_table_prefix(clickhouse_table_prefix.clone())
     );
     tracing::info!("Connected to ClickHouse at {}", clickhouse_url);
+    if !clickhouse_table_prefix.is_empty() {
+        tracing::info!("ClickHouse table prefix: {}", clickhouse_table_prefix);
+    }
     
     // PostgreSQL
     let postgres_url = env::var("DATABASE_URL")
@@ -105,17 +132,66 @@ async fn main() -> anyhow::Result<()> {
         .await?;
     tracing::info!("Connected to PostgreSQL");
     
+    // ========== Network ==========
+    
+    // NETWORK=mainnet|devnet, with optional ORE_PROGRAM_ID / EVORE_PROGRAM_ID / ORE_MINT overrides
+    let network: Network = env::var("NETWORK")
+        .map(|v| v.parse().expect("NETWORK must be mainnet or devnet"))
+        .unwrap_or_default();
+    let pubkey_override = |key: &str| {
+        env::var(key).ok().map(|v| {
+            v.parse::<Pubkey>().unwrap_or_else(|_| panic!("{} must be a valid pubkey", key))
+        })
+    };
+    let network_config = NetworkConfig::for_network(network).with_overrides(
+        pubkey_override("ORE_PROGRAM_ID"),
+        pubkey_override("EVORE_PROGRAM_ID"),
+        pubkey_override("ORE_MINT"),
+    );
+    tracing::info!(
+        "Network: {} (ore program {}, mint {})",
+        network, network_config.ore_program_id, network_config.ore_mint
+    );
+    
     // ========== RPC Clients ==========
 
     let rpc_url = env::var("RPC_URL").expect("RPC_URL must be set");
     let flux_rpc_url = env::var("FLUX_RPC_URL").expect("FLUX_RPC_URL must be set");
     let triton_rpc_url = env::var("TRITON_RPC_URL").ok(); // Optional
-    let rpc = Arc::new(AppRpc::new(rpc_url.clone(), flux_rpc_url.clone(), triton_rpc_url, Some(clickhouse.clone())));
+    if let Ok(json) = env::var("RPC_HEADERS") {
+        let headers = custom_rpc::parse_rpc_headers(&json).expect("Invalid RPC_HEADERS");
+        tracing::info!("RPC headers: {}", headers.keys().map(|k| k.as_str()).collect::<Vec<_>>().join(", "));
+        custom_rpc::set_rpc_headers(headers);
+    }
+    let routing = RoutingPolicy::from_env();
+    tracing::info!(
+        "RPC routing: reads -> {}, gpa -> {}, latency -> {}",
+        routing.reads, routing.gpa, routing.latency
+    );
+    let rpc = Arc::new(
+        AppRpc::new(rpc_url.clone(), flux_rpc_url.clone(), triton_rpc_url, Some(clickhouse.clone()))
+            .with_network(network_config)
+            .with_routing(routing)
+            .with_miners_gpa_concurrency(
+                env::var("MINERS_GPA_CONCURRENCY").ok().and_then(|v| v.parse().ok()).unwrap_or(1)
+            )
+    );
     tracing::info!("RPC clients initialized (Flux + Helius + Triton)");
     
     // ========== Helius API for token holders ==========
     
-    let helius = Arc::new(RwLock::new(HeliusApi::with_clickhouse(rpc_url.clone(), Some(clickhouse.clone()))));
+    // One request budget shared by every task using it
+    let helius = Arc::new(
+        HeliusApi::with_clickhouse(rpc_url.clone(), Some(clickhouse.clone()))
+            .with_rate_limit(
+                env::var("HELIUS_RPS").ok().and_then(|v| v.parse().ok()).unwrap_or(helius_api::DEFAULT_HELIUS_RPS),
+                env::var("HELIUS_BURST").ok().and_then(|v| v.parse().ok()).unwrap_or(helius_api::DEFAULT_HELIUS_BURST),
+            )
+            .with_scan_limit(helius_api::ScanLimit {
+                max_pages: env::var("HELIUS_SCAN_MAX_PAGES").ok().and_then(|v| v.parse().ok()).unwrap_or(helius_api::DEFAULT_SCAN_MAX_PAGES),
+                max_txns: env::var("HELIUS_SCAN_MAX_TXNS").ok().and_then(|v| v.parse().ok()).unwrap_or(0),
+            })
+    );
     
     // ========== Admin Password ==========
     
@@ -127,12 +203,19 @@ async fn main() -> anyhow::Result<()> {
     
     // ========== Application State ==========
     
+    let sse_config = sse::SseConfig::from_env();
+    tracing::info!(
+        "SSE client buffer: {} events, backpressure: {:?}",
+        sse_config.client_buffer,
+        sse_config.policy,
+    );
     let state = Arc::new(AppState::new(
         admin_password_hash,
         clickhouse.clone(),
         postgres.clone(),
         rpc.clone(),
         helius.clone(),
+        sse_config,
     ));
     
     // ========== Background Tasks ==========
@@ -141,7 +224,7 @@ async fn main() -> anyhow::Result<()> {
     let ws_manager = WebSocketManager::with_clickhouse(rpc_url.clone(), Some(clickhouse.clone()));
     
     // Slot subscription
-    let slot_handle = ws_manager.spawn_slot_subscription(state.slot_cache.clone());
+    let slot_handle = ws_manager.spawn_slot_subscription(state.slot_cache.clone(), state.subscriptions.clone());
     tracing::info!("Slot subscription started");
     
     // Round broadcaster (sends to SSE every 500ms)
@@ -152,15 +235,21 @@ async fn main() -> anyhow::Result<()> {
     let program_sub_state = state.clone();
     let program_sub_url = rpc_url.clone();
     let program_sub_clickhouse = Some(clickhouse.clone());
+    let program_sub_filters = websocket::ProgramSubFilters::from_env();
     let program_sub_handle = tokio::spawn(async move {
+        let mut backoff = websocket::ReconnectBackoff::new();
         loop {
             tracing::info!("Starting ORE program account subscription for SSE...");
-            if let Err(e) = websocket::subscribe_to_program_accounts(&program_sub_url, program_sub_state.clone(), program_sub_clickhouse.clone()).await {
-                tracing::error!("Program account subscription error: {}, reconnecting in 5s...", e);
+            let started = std::time::Instant::now();
+            if let Err(e) = websocket::subscribe_to_program_accounts(&program_sub_url, program_sub_state.clone(), program_sub_clickhouse.clone(), &program_sub_filters).await {
+                tracing::error!("Program account subscription error: {}", e);
             } else {
-                tracing::warn!("Program account subscription ended unexpectedly, reconnecting...");
+                tracing::warn!("Program account subscription ended unexpectedly");
             }
-            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            program_sub_state.subscriptions.set_program(false);
+            let delay = backoff.next_delay(started.elapsed());
+            tracing::info!("Reconnecting program account subscription in {:.1}s", delay.as_secs_f64());
+            tokio::time::sleep(delay).await;
         }
     });
     tracing::info!("Program account subscription started");
@@ -170,13 +259,25 @@ async fn main() -> anyhow::Result<()> {
         helius.clone(),
         state.ore_holders_cache.clone(),
         state.slot_cache.clone(),
-    ));
+        state.ore_holders_last_slot.clone(),
+    ).with_mint(network_config.ore_mint));
     let token_cache_handle = token_cache.spawn_update_task();
     tracing::info!("ORE token cache started");
     
-    // RPC polling task (Board, Treasury, Round every 2 seconds)
-    let polling_handle = tasks::spawn_rpc_polling(state.clone());
-    tracing::info!("RPC polling started");
+    // ORE price oracle
+    let _ore_price_handle = ore_price::spawn_ore_price_polling(state.clone(), network_config.ore_mint);
+    tracing::info!("ORE price polling started");
+    
+    // RPC polling task (Board, Treasury, Round)
+    let poll_intervals = tasks::PollIntervals::from_env();
+    let polling_handle = tasks::spawn_rpc_polling(state.clone(), poll_intervals);
+    tracing::info!(
+        "RPC polling started (board {}ms, treasury {}ms, round {}ms, miners {})",
+        poll_intervals.board.as_millis(),
+        poll_intervals.treasury.as_millis(),
+        poll_intervals.round.as_millis(),
+        poll_intervals.miners.map_or("per round".to_string(), |d| format!("{}ms", d.as_millis())),
+    );
     
     // Initial miners cache load via GPA (will be refreshed each round via GPA snapshot)
     tracing::info!("Loading initial miners cache via GPA...");
@@ -192,6 +293,10 @@ async fn main() -> anyhow::Result<()> {
         }
     }
     
+    // Periodic miners refresh on top of the per-round GPA snapshot (opt-in)
+    let miners_polling_handle = poll_intervals.miners
+        .map(|every| tasks::spawn_miners_polling(state.clone(), every));
+    
     // Metrics snapshot task
     let metrics_handle = tasks::spawn_metrics_snapshot(state.clone());
     tracing::info!("Metrics snapshot task started");
@@ -230,24 +335,30 @@ async fn main() -> anyhow::Result<()> {
     // ========== Axum Router ==========
     
     let app = Router::new()
-        // Health check
+        // Health checks: liveness (/health kept as an alias) and readiness
         .route("/health", get(routes::health))
+        .route("/livez", get(routes::health))
+        .route("/readyz", get(routes::readyz))
         
         // ORE Account endpoints (from cache)
         .route("/treasury", get(routes::get_treasury))
         .route("/board", get(routes::get_board))
         .route("/round", get(routes::get_round))
+        .route("/round/simulate", post(routes::simulate_round))
         .route("/miners", get(routes::get_miners))
         .route("/miner/{pubkey}", get(routes::get_miner))
         
         // Live data
         .route("/live/round", get(routes::get_live_round))
         .route("/live/deployments", get(routes::get_live_deployments))
+        .route("/live/board-vector", get(routes::get_live_board_vector))
         .route("/slot", get(routes::get_slot))
+        .route("/ore-price", get(routes::get_ore_price))
         
         // RPC proxy
         .route("/balance/{pubkey}", get(routes::get_balance))
         .route("/signature/{signature}", get(routes::get_signature_status))
+        .route("/analyze/{signature}", get(routes::analyze_transaction))
         
         // ORE token balances
         .route("/ore-balance/{owner}", get(routes::get_ore_balance))
@@ -268,10 +379,15 @@ async fn main() -> anyhow::Result<()> {
         // Historical rounds (from ClickHouse)
         .route("/rounds", get(routes::get_rounds))
         .route("/rounds/{round_id}", get(routes::get_round_by_id))
+        .route("/rounds/{round_id}/entropy", get(routes::get_round_entropy))
+        .route("/rounds/{round_id}/payouts", get(routes::get_round_payouts))
         
         // SSE streams
         .route("/sse/rounds", get(sse::sse_rounds))
+        .route("/sse/board", get(sse::sse_board))
         .route("/sse/deployments", get(sse::sse_deployments))
+        .route("/sse/slot", get(sse::sse_slot))
+        .route("/live/deployments.ndjson", get(sse::ndjson_deployments))
         
         // Admin routes (nested under /admin)
         .nest("/admin", admin_routes::admin_router(state.clone()))
@@ -305,6 +421,9 @@ async fn main() -> anyhow::Result<()> {
     program_sub_handle.abort();
     token_cache_handle.abort();
     polling_handle.abort();
+    if let Some(handle) = miners_polling_handle {
+        handle.abort();
+    }
     metrics_handle.abort();
 
     Ok(())
@@ -4,6 +4,9 @@
 //! - Request/response timing and logging to ClickHouse
 //! - Real IP tracking from X-Forwarded-For
 //! - Rate limit event detection
+//! - Per-request ids (X-Request-Id header, error bodies, request logs)
+//! - Wrapping non-JSON error responses (e.g. extractor rejections) in the
+//!   standard error body
 
 use std::sync::Arc;
 use std::time::Instant;
@@ -11,12 +14,13 @@ use std::time::Instant;
 use axum::{
     body::Body,
     extract::{ConnectInfo, State},
-    http::{Request, StatusCode},
+    http::{header, HeaderValue, Request},
     middleware::Next,
     response::Response,
 };
 use std::net::SocketAddr;
 
+use crate::app_error::{error_response, REQUEST_ID};
 use crate::app_state::AppState;
 use crate::clickhouse::{RequestLog, RateLimitEvent};
 
@@ -48,8 +52,14 @@ pub async fn request_logging_middleware(
         .map(|s| s.trim().to_string())
         .unwrap_or_else(|| addr.ip().to_string());
     
-    // Execute the request
-    let response = next.run(request).await;
+    let request_id = uuid::Uuid::new_v4().simple().to_string();
+    
+    // Execute the request with the id visible to AppError
+    let response = REQUEST_ID.scope(request_id.clone(), next.run(request)).await;
+    let mut response = wrap_plain_error(response, &request_id).await;
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert("x-request-id", value);
+    }
     
     // Measure in microseconds for sub-ms precision, store as ms (rounded up for sub-ms)
     let duration_us = start.elapsed().as_micros() as u32;
@@ -65,6 +75,7 @@ pub async fn request_logging_middleware(
         duration_ms,
         ip_hash: client_ip.clone(),  // Named ip_hash in schema but stores real IP
         user_agent: truncate_string(&user_agent, 256),
+        request_id,
     };
     
     let clickhouse = state.clickhouse.clone();
@@ -94,6 +105,43 @@ pub async fn request_logging_middleware(
     response
 }
 
+/// Max body read when rewrapping a plain-text error
+const MAX_PLAIN_ERROR_BYTES: usize = 4096;
+
+/// Rewrite error responses that aren't JSON (axum rejections, fallbacks)
+/// into the standard error body, keeping the status
+async fn wrap_plain_error(response: Response, request_id: &str) -> Response {
+    let status = response.status();
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|h| h.to_str().ok())
+        .map(|ct| ct.starts_with("application/json"))
+        .unwrap_or(false);
+    if !(status.is_client_error() || status.is_server_error()) || is_json {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let text = axum::body::to_bytes(body, MAX_PLAIN_ERROR_BYTES)
+        .await
+        .map(|b| String::from_utf8_lossy(&b).trim().to_string())
+        .unwrap_or_default();
+    let message = if text.is_empty() {
+        status.canonical_reason().unwrap_or("error").to_string()
+    } else {
+        text
+    };
+
+    let mut wrapped = error_response(status, message, Some(request_id.to_string()));
+    for (name, value) in parts.headers.iter() {
+        if name != header::CONTENT_TYPE && name != header::CONTENT_LENGTH {
+            wrapped.headers_mut().insert(name.clone(), value.clone());
+        }
+    }
+    wrapped
+}
+
 /// Truncate a string to max length, adding ... if truncated
 fn truncate_string(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
@@ -106,11 +154,31 @@ fn truncate_string(s: &str, max_len: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use axum::http::StatusCode;
     
     #[test]
     fn test_truncate_string() {
         assert_eq!(truncate_string("hello", 10), "hello");
         assert_eq!(truncate_string("hello world", 8), "hello...");
     }
+    
+    #[tokio::test]
+    async fn test_wrap_plain_error() {
+        let plain = Response::builder()
+            .status(StatusCode::UNPROCESSABLE_ENTITY)
+            .body(Body::from("missing field `bets`"))
+            .unwrap();
+        let wrapped = wrap_plain_error(plain, "req1").await;
+        assert_eq!(wrapped.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        
+        let bytes = axum::body::to_bytes(wrapped.into_body(), 1024).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["error"]["code"], "bad_request");
+        assert_eq!(body["error"]["message"], "missing field `bets`");
+        assert_eq!(body["error"]["request_id"], "req1");
+        
+        let ok = Response::new(Body::from("fine"));
+        assert_eq!(wrap_plain_error(ok, "req2").await.status(), StatusCode::OK);
+    }
 }
 
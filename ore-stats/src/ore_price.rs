@@ -0,0 +1,202 @@
+//! ORE price oracle
+//!
+//! Polls ORE and SOL USD prices from a Jupiter-style price API
+//! (`{ "<mint>": { "usdPrice": ... } }`) and keeps the latest ORE value in
+//! SOL and USD for `GET /ore-price`. A failed fetch keeps the last good price
+//! and is reported as stale until the next success.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::Value;
+use steel::Pubkey;
+use tokio::sync::RwLock;
+
+use crate::app_state::AppState;
+
+/// Wrapped SOL mint, priced alongside ORE to convert to SOL
+const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+const DEFAULT_ORE_PRICE_URL: &str = "https://lite-api.jup.ag/price/v3";
+const DEFAULT_ORE_PRICE_POLL_SECS: u64 = 60;
+
+/// Price source settings (`ORE_PRICE_URL`, `ORE_PRICE_POLL_SECS`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrePriceConfig {
+    pub url: String,
+    pub poll_interval: Duration,
+}
+
+impl OrePriceConfig {
+    pub fn from_env() -> Self {
+        Self::from_vars(|key| std::env::var(key).ok())
+    }
+
+    fn from_vars(get: impl Fn(&str) -> Option<String>) -> Self {
+        Self {
+            url: get("ORE_PRICE_URL").filter(|v| !v.is_empty()).unwrap_or_else(|| DEFAULT_ORE_PRICE_URL.to_string()),
+            poll_interval: Duration::from_secs(
+                get("ORE_PRICE_POLL_SECS")
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .unwrap_or(DEFAULT_ORE_PRICE_POLL_SECS)
+                    .max(1),
+            ),
+        }
+    }
+}
+
+/// One successful price fetch
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct OrePrice {
+    /// Value of 1 ORE in SOL
+    pub ore_sol: f64,
+    pub ore_usd: f64,
+    pub sol_usd: f64,
+    /// Unix timestamp of the fetch
+    pub fetched_at: i64,
+}
+
+/// Latest price plus the outcome of the most recent fetch
+#[derive(Debug, Clone)]
+pub struct OrePriceCache {
+    pub config: OrePriceConfig,
+    pub price: Option<OrePrice>,
+    /// Error from the last fetch, cleared by the next success
+    pub last_error: Option<String>,
+    pub last_attempt_at: Option<i64>,
+}
+
+impl OrePriceCache {
+    pub fn new(config: OrePriceConfig) -> Self {
+        Self { config, price: None, last_error: None, last_attempt_at: None }
+    }
+
+    /// Whether the cached price can't be trusted as current: the last fetch
+    /// failed or it's older than two poll intervals
+    pub fn is_stale(&self, now: i64) -> bool {
+        match &self.price {
+            Some(price) => self.last_error.is_some() || now - price.fetched_at > 2 * self.config.poll_interval.as_secs() as i64,
+            None => true,
+        }
+    }
+}
+
+/// ORE and SOL USD prices from a price API response
+pub fn parse_prices(body: &Value, ore_mint: &Pubkey, fetched_at: i64) -> Result<OrePrice, String> {
+    let usd_price = |mint: &str| {
+        body.get(mint)
+            .and_then(|p| p.get("usdPrice"))
+            .and_then(|p| match p {
+                Value::String(s) => s.parse::<f64>().ok(),
+                p => p.as_f64(),
+            })
+            .filter(|p| p.is_finite() && *p > 0.0)
+            .ok_or_else(|| format!("no usdPrice for {}", mint))
+    };
+    let ore_usd = usd_price(&ore_mint.to_string())?;
+    let sol_usd = usd_price(SOL_MINT)?;
+    Ok(OrePrice {
+        ore_sol: ore_usd / sol_usd,
+        ore_usd,
+        sol_usd,
+        fetched_at,
+    })
+}
+
+async fn fetch_prices(client: &reqwest::Client, config: &OrePriceConfig, ore_mint: &Pubkey) -> Result<OrePrice, String> {
+    let response = client
+        .get(&config.url)
+        .query(&[("ids", format!("{},{}", ore_mint, SOL_MINT))])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("price source returned {}", response.status()));
+    }
+    let body: Value = response.json().await.map_err(|e| e.to_string())?;
+    parse_prices(&body, ore_mint, chrono::Utc::now().timestamp())
+}
+
+/// Spawn the price polling task
+pub fn spawn_ore_price_polling(state: Arc<AppState>, ore_mint: Pubkey) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let config = state.ore_price.read().await.config.clone();
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .expect("Failed to create HTTP client");
+        let mut ticker = tokio::time::interval(config.poll_interval);
+
+        loop {
+            ticker.tick().await;
+            let result = fetch_prices(&client, &config, &ore_mint).await;
+            update_cache(&state.ore_price, result).await;
+        }
+    })
+}
+
+async fn update_cache(cache: &RwLock<OrePriceCache>, result: Result<OrePrice, String>) {
+    let mut cache = cache.write().await;
+    cache.last_attempt_at = Some(chrono::Utc::now().timestamp());
+    match result {
+        Ok(price) => {
+            cache.price = Some(price);
+            cache.last_error = None;
+        }
+        Err(e) => {
+            tracing::warn!("ORE price fetch failed: {}", e);
+            cache.last_error = Some(e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_prices() {
+        let ore_mint = Pubkey::new_unique();
+        let body = json!({
+            ore_mint.to_string(): { "usdPrice": 300.0 },
+            SOL_MINT: { "usdPrice": "150" },
+        });
+        let price = parse_prices(&body, &ore_mint, 10).unwrap();
+        assert_eq!(price, OrePrice { ore_sol: 2.0, ore_usd: 300.0, sol_usd: 150.0, fetched_at: 10 });
+
+        let body = json!({ SOL_MINT: { "usdPrice": 150.0 } });
+        assert!(parse_prices(&body, &ore_mint, 10).unwrap_err().contains("no usdPrice"));
+        let body = json!({ ore_mint.to_string(): { "usdPrice": 0 }, SOL_MINT: { "usdPrice": 150.0 } });
+        assert!(parse_prices(&body, &ore_mint, 10).is_err());
+    }
+
+    #[test]
+    fn test_staleness() {
+        let mut cache = OrePriceCache::new(OrePriceConfig::from_vars(|_| None));
+        assert!(cache.is_stale(0));
+
+        cache.price = Some(OrePrice { ore_sol: 1.0, ore_usd: 1.0, sol_usd: 1.0, fetched_at: 1_000 });
+        assert!(!cache.is_stale(1_120));
+        assert!(cache.is_stale(1_121));
+
+        cache.last_error = Some("timeout".to_string());
+        assert!(cache.is_stale(1_001));
+    }
+
+    #[test]
+    fn test_config_from_vars() {
+        let config = OrePriceConfig::from_vars(|_| None);
+        assert_eq!(config.url, DEFAULT_ORE_PRICE_URL);
+        assert_eq!(config.poll_interval, Duration::from_secs(60));
+
+        let config = OrePriceConfig::from_vars(|key| match key {
+            "ORE_PRICE_URL" => Some("http://localhost/price".to_string()),
+            "ORE_PRICE_POLL_SECS" => Some("0".to_string()),
+            _ => None,
+        });
+        assert_eq!(config.url, "http://localhost/price");
+        assert_eq!(config.poll_interval, Duration::from_secs(1));
+    }
+}
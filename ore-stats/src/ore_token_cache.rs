@@ -1,88 +1,149 @@
 //! ORE Token Holders Cache
 //!
 //! Maintains a live cache of all ORE token holders using Helius v2 API.
-//! - Initial load: Fetches all holders via getProgramAccountsV2 with dataSlice
-//! - Incremental updates: Uses changedSinceSlot for efficient updates
+//! - Full refresh: Fetches all holders via getProgramAccountsV2, page by page.
+//!   On startup and then every `FULL_REFRESH_INTERVAL`; a refresh that fails
+//!   mid-pagination resumes from its last cursor on the next attempt
+//! - Incremental updates: Uses changedSinceSlot for efficient updates between
+//!   full refreshes, keyed by the last-synced slot
 
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use steel::Pubkey;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 use tokio::time::interval;
 
-use crate::helius_api::HeliusApi;
+use crate::helius_api::{HeliusApi, TokenBalance};
 
-/// ORE token mint address (from evore::ore_api::MINT_ADDRESS)
+/// How often the holders are reloaded in full (incremental updates in between)
+const FULL_REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Wait before resuming a full refresh that failed
+const FULL_REFRESH_RETRY: Duration = Duration::from_secs(30);
+
+/// Mainnet ORE token mint address (from evore::ore_api::MINT_ADDRESS)
 pub fn ore_mint() -> Pubkey {
     evore::ore_api::MINT_ADDRESS
 }
 
 /// Cache for ORE token holders
 pub struct OreTokenCache {
-    helius: Arc<RwLock<HeliusApi>>,
+    helius: Arc<HeliusApi>,
     holders: Arc<RwLock<HashMap<Pubkey, u64>>>,
     /// Uses the slot cache from WebSocket for tracking changes
     slot_cache: Arc<RwLock<u64>>,
-    /// Last slot we synced at
+    /// Last slot we synced at (shared with AppState for /ore-holders)
     last_sync_slot: Arc<RwLock<u64>>,
+    /// Mint whose holders are tracked (mainnet ORE unless overridden)
+    mint: Pubkey,
+    /// Full refresh in progress, kept when a page fails so the retry resumes
+    refresh_progress: Mutex<Option<FullRefreshProgress>>,
+}
+
+/// Pages fetched so far by a full refresh
+#[derive(Default)]
+struct FullRefreshProgress {
+    /// Slot when the refresh started; incremental updates continue from here
+    start_slot: u64,
+    /// Cursor of the next page (None = first page)
+    cursor: Option<String>,
+    /// Balances from the pages fetched so far
+    balances: Vec<TokenBalance>,
+    pages: u32,
 }
 
 impl OreTokenCache {
     /// Create a new cache with shared state from AppState
     pub fn new(
-        helius: Arc<RwLock<HeliusApi>>,
+        helius: Arc<HeliusApi>,
         holders: Arc<RwLock<HashMap<Pubkey, u64>>>,
         slot_cache: Arc<RwLock<u64>>,
+        last_sync_slot: Arc<RwLock<u64>>,
     ) -> Self {
         Self {
             helius,
             holders,
             slot_cache,
-            last_sync_slot: Arc::new(RwLock::new(0)),
+            last_sync_slot,
+            mint: ore_mint(),
+            refresh_progress: Mutex::new(None),
         }
     }
     
-    /// Initial full load of all ORE token holders
-    pub async fn initial_load(&self) -> Result<usize> {
-        tracing::info!("Starting initial load of ORE token holders...");
-        
-        // Get current slot before fetch (for tracking incremental updates)
-        let current_slot = *self.slot_cache.read().await;
+    /// Track holders of a different mint (e.g. devnet)
+    pub fn with_mint(mut self, mint: Pubkey) -> Self {
+        self.mint = mint;
+        self
+    }
+    
+    /// Full load of all ORE token holders, resuming a previously failed
+    /// refresh from its last cursor
+    pub async fn full_refresh(&self) -> Result<usize> {
+        let mut guard = self.refresh_progress.lock().await;
+        let progress = match guard.as_mut() {
+            Some(progress) => {
+                tracing::info!(
+                    "Resuming ORE token holders refresh at page {} ({} holders so far)",
+                    progress.pages + 1,
+                    progress.balances.len()
+                );
+                progress
+            }
+            None => {
+                tracing::info!("Starting full load of ORE token holders...");
+                let start_slot = *self.slot_cache.read().await;
+                if start_slot == 0 {
+                    tracing::warn!("Slot cache is 0 - WebSocket may not be connected yet. Incremental updates won't work until next full load.");
+                }
+                guard.insert(FullRefreshProgress { start_slot, ..Default::default() })
+            }
+        };
         
-        if current_slot == 0 {
-            tracing::warn!("Slot cache is 0 - WebSocket may not be connected yet. Incremental updates won't work until next full load.");
+        // A failed page returns early and leaves the progress in place
+        loop {
+            let (balances, next_cursor) = self.helius
+                .get_ore_token_balances_page(&self.mint, progress.cursor.clone(), Some(5000))
+                .await?;
+            progress.balances.extend(balances);
+            progress.pages += 1;
+            progress.cursor = next_cursor;
+            if progress.cursor.is_none() {
+                break;
+            }
         }
+        let done = guard.take().unwrap_or_default();
         
-        let balances = {
-            let mut helius = self.helius.write().await;
-            helius.get_ore_token_balances(&ore_mint(), Some(5000)).await?
+        // Changes made while the pages were fetched are picked up by the next
+        // incremental update, since it starts from the refresh's start slot
+        let final_slot = if done.start_slot > 0 {
+            done.start_slot
+        } else {
+            *self.slot_cache.read().await
         };
         
-        // Get slot after fetch for more accurate tracking
-        let sync_slot_value = *self.slot_cache.read().await;
-        let final_slot = if sync_slot_value > current_slot { sync_slot_value } else { current_slot };
-        
         tracing::info!(
-            "Loaded {} ORE token holders at slot {} (will use changedSinceSlot for future updates)",
-            balances.len(),
+            "Loaded {} ORE token holders in {} pages at slot {} (will use changedSinceSlot for future updates)",
+            done.balances.len(),
+            done.pages,
             final_slot
         );
         
         // Update cache
         let mut cache = self.holders.write().await;
         cache.clear();
-        for balance in &balances {
+        for balance in &done.balances {
             cache.insert(balance.owner, balance.amount);
         }
+        drop(cache);
         
         // Update last sync slot
         let mut sync_slot = self.last_sync_slot.write().await;
         *sync_slot = final_slot;
         
-        Ok(balances.len())
+        Ok(done.balances.len())
     }
     
     /// Incremental update using changedSinceSlot
@@ -110,10 +171,9 @@ impl OreTokenCache {
             current_slot - since_slot
         );
         
-        let changes = {
-            let mut helius = self.helius.write().await;
-            helius.get_ore_token_balances_changed_since(&ore_mint(), since_slot, Some(5000)).await?
-        };
+        let changes = self.helius
+            .get_ore_token_balances_changed_since(&self.mint, since_slot, Some(5000))
+            .await?;
         
         if changes.is_empty() {
             // Update sync slot even if no changes
@@ -216,27 +276,28 @@ impl OreTokenCache {
             // Wait a bit for WebSocket to establish and get slot
             tokio::time::sleep(Duration::from_secs(5)).await;
             
-            // First do initial load
-            match self.initial_load().await {
-                Ok(count) => {
-                    tracing::info!("Initial ORE token holders load complete: {} holders", count);
-                }
-                Err(e) => {
-                    tracing::error!("Failed initial ORE token holders load: {}", e);
-                    // Retry after delay
-                    tokio::time::sleep(Duration::from_secs(30)).await;
-                    if let Err(e) = self.initial_load().await {
-                        tracing::error!("Retry of initial load also failed: {}", e);
-                    }
-                }
-            }
-            
-            // Then run incremental updates every 10 seconds
+            // Full refresh right away, then every FULL_REFRESH_INTERVAL with
+            // incremental updates every 10 seconds in between
+            let mut next_full_refresh = Instant::now();
             let mut ticker = interval(Duration::from_secs(10));
             
             loop {
                 ticker.tick().await;
                 
+                if Instant::now() >= next_full_refresh {
+                    match self.full_refresh().await {
+                        Ok(count) => {
+                            tracing::info!("ORE token holders full refresh complete: {} holders", count);
+                            next_full_refresh = Instant::now() + FULL_REFRESH_INTERVAL;
+                            continue;
+                        }
+                        Err(e) => {
+                            tracing::error!("ORE token holders full refresh failed (will resume from last cursor): {}", e);
+                            next_full_refresh = Instant::now() + FULL_REFRESH_RETRY;
+                        }
+                    }
+                }
+                
                 match self.incremental_update().await {
                     Ok(count) if count > 0 => {
                         tracing::debug!("Updated {} ORE token holder balances", count);
@@ -0,0 +1,92 @@
+//! Shared async rate limiter (token bucket)
+//!
+//! Callers reserve their tokens up front instead of serializing behind a
+//! lock: `acquire` takes the tokens right away (the balance may go negative)
+//! and sleeps until the bucket would have refilled them. Concurrent callers
+//! are spread evenly at `rps`, with up to `burst` requests back to back after
+//! a quiet period. The lock is only held for the arithmetic, never across the
+//! sleep or the request itself.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+#[derive(Debug)]
+pub struct RateLimiter {
+    rps: f64,
+    burst: f64,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    /// `rps` tokens refill per second, holding at most `burst` (both at least 1)
+    pub fn new(rps: u32, burst: u32) -> Self {
+        let burst = burst.max(1) as f64;
+        Self {
+            rps: rps.max(1) as f64,
+            burst,
+            bucket: Mutex::new(Bucket { tokens: burst, updated: Instant::now() }),
+        }
+    }
+
+    /// Wait until `cost` tokens are ours
+    pub async fn acquire(&self, cost: u32) {
+        let wait = self.reserve(cost as f64, Instant::now());
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Hold everyone back for `delay` (the provider rate limited us)
+    pub fn penalize(&self, delay: Duration) {
+        self.reserve(delay.as_secs_f64() * self.rps, Instant::now());
+    }
+
+    /// Take `cost` tokens at `now`, returning how long until they're refilled
+    fn reserve(&self, cost: f64, now: Instant) -> Duration {
+        let mut bucket = self.bucket.lock().unwrap();
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.rps).min(self.burst) - cost;
+        bucket.updated = bucket.updated.max(now);
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / self.rps)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserve_spreads_callers() {
+        let limiter = RateLimiter::new(25, 2);
+        let now = Instant::now() + Duration::from_secs(1);
+
+        // The burst goes out at once, then callers queue 40ms apart
+        let waits: Vec<u128> = (0..5).map(|_| limiter.reserve(1.0, now).as_millis()).collect();
+        assert_eq!(waits, vec![0, 0, 40, 80, 120]);
+
+        // Refilled after a quiet second, but never beyond the burst
+        let later = now + Duration::from_secs(2);
+        assert_eq!(limiter.reserve(2.0, later), Duration::ZERO);
+        assert_eq!(limiter.reserve(1.0, later).as_millis(), 40);
+
+        // A heavier request costs more of the budget
+        let limiter = RateLimiter::new(25, 1);
+        assert_eq!(limiter.reserve(5.0, now).as_millis(), 160);
+
+        // A penalty of half a second's tokens holds back the next caller
+        let limiter = RateLimiter::new(10, 1);
+        limiter.reserve(1.0, now);
+        limiter.reserve(0.5 * 10.0, now);
+        assert_eq!(limiter.reserve(1.0, now).as_millis(), 600);
+    }
+}
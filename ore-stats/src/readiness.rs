@@ -0,0 +1,86 @@
+//! Liveness and readiness probes
+//!
+//! `/livez` only says the process is serving requests, so Kubernetes restarts
+//! it when it hangs. `/readyz` says it can serve real data: both WebSocket
+//! subscriptions are connected, ClickHouse and Postgres answer, and the first
+//! round has been loaded. It returns 503 until all of them hold.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use serde::Serialize;
+
+/// How long a database may take to answer a readiness ping
+pub const DB_PING_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Connection state of the WebSocket subscriptions, set by their tasks
+#[derive(Debug, Default)]
+pub struct SubscriptionStatus {
+    slot: AtomicBool,
+    program: AtomicBool,
+}
+
+impl SubscriptionStatus {
+    pub fn set_slot(&self, connected: bool) {
+        self.slot.store(connected, Ordering::Relaxed);
+    }
+
+    pub fn set_program(&self, connected: bool) {
+        self.program.store(connected, Ordering::Relaxed);
+    }
+
+    pub fn slot(&self) -> bool {
+        self.slot.load(Ordering::Relaxed)
+    }
+
+    pub fn program(&self) -> bool {
+        self.program.load(Ordering::Relaxed)
+    }
+}
+
+/// Result of each readiness check
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ReadinessChecks {
+    pub slot_subscription: bool,
+    pub program_subscription: bool,
+    pub clickhouse: bool,
+    pub postgres: bool,
+    /// Board, treasury and round caches populated by the first poll
+    pub round_loaded: bool,
+}
+
+impl ReadinessChecks {
+    pub fn ready(&self) -> bool {
+        self.slot_subscription
+            && self.program_subscription
+            && self.clickhouse
+            && self.postgres
+            && self.round_loaded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ready_needs_every_check() {
+        let all = ReadinessChecks {
+            slot_subscription: true,
+            program_subscription: true,
+            clickhouse: true,
+            postgres: true,
+            round_loaded: true,
+        };
+        assert!(all.ready());
+        assert!(!ReadinessChecks { round_loaded: false, ..all }.ready());
+        assert!(!ReadinessChecks { program_subscription: false, ..all }.ready());
+        assert!(!ReadinessChecks::default().ready());
+
+        let status = SubscriptionStatus::default();
+        status.set_slot(true);
+        assert!(status.slot() && !status.program());
+        status.set_slot(false);
+        assert!(!status.slot());
+    }
+}
@@ -0,0 +1,146 @@
+//! Round Payouts
+//!
+//! Per-deployer payout breakdown of a finalized round's winning square, used
+//! by `GET /rounds/{round_id}/payouts`. Follows the ORE checkpoint formula:
+//! a winning stake gets itself back minus the admin fee (1%, at least 1
+//! lamport) plus its pro-rata share of `total_winnings`. ORE is the top miner
+//! reward (pro-rata on split rounds, otherwise all to the top miner) plus a
+//! pro-rata share of the motherlode when it hit.
+
+use serde::Serialize;
+
+/// One deployer's total on the winning square
+#[derive(Debug, Clone)]
+pub struct WinningStake {
+    pub miner_pubkey: String,
+    pub amount: u64,
+}
+
+/// Round totals the payouts are computed from
+#[derive(Debug, Clone)]
+pub struct PayoutRound {
+    pub total_winnings: u64,
+    pub top_miner: String,
+    pub top_miner_reward: u64,
+    /// 0 unless the motherlode hit
+    pub motherlode: u64,
+}
+
+/// One deployer's payout (lamports / ORE grams)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Payout {
+    pub miner_pubkey: String,
+    pub deployed: u64,
+    /// Share of the winning square in basis points
+    pub share_bps: u64,
+    pub admin_fee: u64,
+    /// Pro-rata share of total_winnings
+    pub winnings: u64,
+    /// Stake minus admin fee plus winnings
+    pub sol: u64,
+    pub ore: u64,
+    pub is_top_miner: bool,
+}
+
+/// Whether the top miner reward was split (the top miner didn't deploy on the winning square)
+pub fn is_split(round: &PayoutRound, stakes: &[WinningStake]) -> bool {
+    !stakes.iter().any(|s| s.amount > 0 && s.miner_pubkey == round.top_miner)
+}
+
+/// Payouts for every stake on the winning square, largest SOL payout first
+pub fn compute_payouts(round: &PayoutRound, stakes: &[WinningStake]) -> Vec<Payout> {
+    let square_total: u128 = stakes.iter().map(|s| s.amount as u128).sum();
+    if square_total == 0 {
+        return Vec::new();
+    }
+    let split = is_split(round, stakes);
+    let pro_rata = |total: u64, amount: u64| (total as u128 * amount as u128 / square_total) as u64;
+
+    let mut payouts: Vec<Payout> = stakes.iter()
+        .filter(|s| s.amount > 0)
+        .map(|s| {
+            let is_top_miner = !split && s.miner_pubkey == round.top_miner;
+            let admin_fee = (s.amount / 100).max(1);
+            let winnings = pro_rata(round.total_winnings, s.amount);
+
+            let top_reward = if split {
+                pro_rata(round.top_miner_reward, s.amount)
+            } else if is_top_miner {
+                round.top_miner_reward
+            } else {
+                0
+            };
+
+            Payout {
+                miner_pubkey: s.miner_pubkey.clone(),
+                deployed: s.amount,
+                share_bps: (s.amount as u128 * 10_000 / square_total) as u64,
+                admin_fee,
+                winnings,
+                sol: s.amount.saturating_sub(admin_fee).saturating_add(winnings),
+                ore: top_reward.saturating_add(pro_rata(round.motherlode, s.amount)),
+                is_top_miner,
+            }
+        })
+        .collect();
+
+    payouts.sort_by(|a, b| b.sol.cmp(&a.sol).then_with(|| a.miner_pubkey.cmp(&b.miner_pubkey)));
+    payouts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stake(miner: &str, amount: u64) -> WinningStake {
+        WinningStake { miner_pubkey: miner.to_string(), amount }
+    }
+
+    fn round(top_miner: &str, motherlode: u64) -> PayoutRound {
+        PayoutRound {
+            total_winnings: 3_000_000_000,
+            top_miner: top_miner.to_string(),
+            top_miner_reward: 100_000_000_000,
+            motherlode,
+        }
+    }
+
+    #[test]
+    fn test_compute_payouts_top_miner() {
+        let stakes = vec![stake("a", 1_000_000_000), stake("b", 3_000_000_000), stake("c", 0)];
+        let payouts = compute_payouts(&round("a", 0), &stakes);
+
+        assert_eq!(payouts.len(), 2);
+        let b = &payouts[0];
+        assert_eq!(b.miner_pubkey, "b");
+        assert_eq!(b.share_bps, 7_500);
+        assert_eq!(b.admin_fee, 30_000_000);
+        assert_eq!(b.winnings, 2_250_000_000);
+        assert_eq!(b.sol, 3_000_000_000 - 30_000_000 + 2_250_000_000);
+        assert_eq!(b.ore, 0);
+
+        let a = &payouts[1];
+        assert!(a.is_top_miner);
+        assert_eq!(a.sol, 1_000_000_000 - 10_000_000 + 750_000_000);
+        assert_eq!(a.ore, 100_000_000_000);
+    }
+
+    #[test]
+    fn test_compute_payouts_split_with_motherlode() {
+        let stakes = vec![stake("a", 1_000_000_000), stake("b", 3_000_000_000), stake("dust", 50)];
+        let payouts = compute_payouts(&round("someone-else", 400_000_000_000), &stakes);
+
+        assert!(is_split(&round("someone-else", 0), &stakes));
+        let a = payouts.iter().find(|p| p.miner_pubkey == "a").unwrap();
+        assert!(!a.is_top_miner);
+        // 1/4 of the top miner reward and of the motherlode (less the dust's sliver)
+        assert_eq!(a.ore, 24_999_999_687 + 99_999_998_750);
+
+        // Admin fee is at least 1 lamport
+        let dust = payouts.last().unwrap();
+        assert_eq!(dust.miner_pubkey, "dust");
+        assert_eq!(dust.admin_fee, 1);
+
+        assert!(compute_payouts(&round("a", 0), &[]).is_empty());
+    }
+}
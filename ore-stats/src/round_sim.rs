@@ -0,0 +1,136 @@
+//! Round Outcome Simulation
+//!
+//! Monte Carlo of the SOL return of a bet vector for one round, used by
+//! `POST /round/simulate`. Payouts follow the on-chain EV formula used by
+//! evore's mm_deploy: a winning stake gets its share of 89.1% of the losing
+//! squares minus a 1% fee, every other stake is lost. ORE rewards are ignored.
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::Serialize;
+
+/// Default trials per simulation
+pub const DEFAULT_TRIALS: u32 = 10_000;
+
+/// Upper bound on trials per request
+pub const MAX_TRIALS: u32 = 100_000;
+
+/// Share of the losing squares paid to winners (per mille)
+const WINNERS_SHARE_PER_MILLE: u128 = 891;
+
+/// Fee on a winning stake (per mille)
+const WIN_FEE_PER_MILLE: u128 = 10;
+
+/// Distribution of SOL returns over simulated rounds (lamports, signed)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OutcomeDistribution {
+    pub trials: u32,
+    pub mean: f64,
+    pub p5: i64,
+    pub p50: i64,
+    pub p95: i64,
+    /// Fraction of trials with a positive return
+    pub win_rate: f64,
+}
+
+/// Net SOL return of `bets` if `winner` is the winning square
+///
+/// `pot` is everyone else's deployment.
+pub fn round_return(bets: &[u64; 25], pot: &[u64; 25], winner: usize) -> i64 {
+    let stake = bets[winner] as u128;
+    let lost: u128 = bets.iter().enumerate()
+        .filter(|(i, _)| *i != winner)
+        .map(|(_, b)| *b as u128)
+        .sum();
+
+    let won = if stake == 0 {
+        0
+    } else {
+        let losers: u128 = (0..25)
+            .filter(|i| *i != winner)
+            .map(|i| pot[i] as u128 + bets[i] as u128)
+            .sum();
+        let share = stake * WINNERS_SHARE_PER_MILLE * losers / (1000 * (pot[winner] as u128 + stake));
+        share.saturating_sub(stake * WIN_FEE_PER_MILLE / 1000)
+    };
+
+    (won as i128 - lost as i128).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Monte Carlo of one round's SOL return for `bets`
+///
+/// Each trial draws a winning square with probability proportional to
+/// `weights`; None (or no positive weight) means every square is equally
+/// likely. The same `seed` gives the same result.
+pub fn simulate_round(
+    bets: &[u64; 25],
+    pot: &[u64; 25],
+    weights: Option<&[f64; 25]>,
+    trials: u32,
+    seed: u64,
+) -> OutcomeDistribution {
+    let trials = trials.clamp(1, MAX_TRIALS);
+    let weights: Vec<f64> = match weights {
+        Some(w) if w.iter().any(|w| *w > 0.0) => w.iter().map(|w| w.max(0.0)).collect(),
+        _ => vec![1.0; 25],
+    };
+    // Weights that can't be sampled (e.g. sum overflows) fall back to uniform
+    let squares = WeightedIndex::new(&weights)
+        .unwrap_or_else(|_| WeightedIndex::new([1.0; 25]).expect("uniform weights"));
+    let returns_by_square: Vec<i64> = (0..25).map(|i| round_return(bets, pot, i)).collect();
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut returns: Vec<i64> = (0..trials)
+        .map(|_| returns_by_square[squares.sample(&mut rng)])
+        .collect();
+    returns.sort_unstable();
+
+    let percentile = |q: f64| returns[((returns.len() - 1) as f64 * q).round() as usize];
+    OutcomeDistribution {
+        trials,
+        mean: returns.iter().map(|r| *r as f64).sum::<f64>() / trials as f64,
+        p5: percentile(0.05),
+        p50: percentile(0.50),
+        p95: percentile(0.95),
+        win_rate: returns.iter().filter(|r| **r > 0).count() as f64 / trials as f64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_return() {
+        let mut pot = [0u64; 25];
+        pot[0] = 1_000_000_000;
+        pot[1] = 1_000_000_000;
+        let mut bets = [0u64; 25];
+        bets[0] = 1_000_000_000;
+        bets[2] = 500_000_000;
+
+        // Win on 0: half of 89.1% of 1.5 SOL, minus 1% of the stake, minus the lost 0.5 SOL
+        assert_eq!(round_return(&bets, &pot, 0), 668_250_000 - 10_000_000 - 500_000_000);
+        assert_eq!(round_return(&bets, &pot, 1), -1_500_000_000);
+    }
+
+    #[test]
+    fn test_simulate_round() {
+        let mut bets = [0u64; 25];
+        bets[0] = 1_000;
+        let pot = [1_000u64; 25];
+
+        let mut weights = [0.0; 25];
+        weights[0] = 1.0;
+        let certain = simulate_round(&bets, &pot, Some(&weights), 100, 7);
+        let win = round_return(&bets, &pot, 0);
+        assert_eq!((certain.p5, certain.p95), (win, win));
+        assert_eq!(certain.win_rate, 1.0);
+
+        let uniform = simulate_round(&bets, &pot, None, 10_000, 7);
+        assert_eq!(uniform, simulate_round(&bets, &pot, Some(&[0.0; 25]), 10_000, 7));
+        assert_eq!(uniform.p95, -1_000);
+        assert!((uniform.win_rate - 0.04).abs() < 0.01);
+    }
+}
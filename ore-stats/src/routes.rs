@@ -5,16 +5,20 @@
 //! so no additional calculation is needed when serving data.
 
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use axum::{
-    extract::{Path, Query, State},
     http::StatusCode,
+    extract::{Path, Query, State},
     response::{IntoResponse, Json},
 };
 use serde::{Deserialize, Serialize};
 use steel::Pubkey;
 
-use crate::app_state::AppState;
+use crate::app_error::AppError;
+use crate::app_state::{AppState, BoardVector};
+use crate::finalization::winning_square_from_entropy;
+use crate::readiness::{ReadinessChecks, DB_PING_TIMEOUT};
 
 // ============================================================================
 // Response Types
@@ -87,12 +91,28 @@ pub struct SignatureStatusResponse {
     pub err: Option<String>,
 }
 
+#[derive(Serialize)]
+pub struct AnalyzeTransactionResponse {
+    pub signature: String,
+    /// Where the transaction came from: "clickhouse" or "rpc"
+    pub source: &'static str,
+    pub slot: u64,
+    pub block_time: i64,
+    pub success: bool,
+    pub error: Option<String>,
+    pub instructions: Vec<crate::tx_analyzer::DecodedInstruction>,
+    /// Memo instruction messages (deploy attribution tags)
+    pub memos: Vec<String>,
+}
+
 #[derive(Serialize)]
 pub struct OreHoldersResponse {
     pub holders: Vec<OreHolderEntry>,
     pub total: usize,
     pub page: usize,
     pub per_page: usize,
+    /// Slot of the last successful holders sync (0 = not loaded yet)
+    pub last_sync_slot: u64,
 }
 
 #[derive(Serialize)]
@@ -115,9 +135,14 @@ pub struct CacheMetrics {
     pub round_id: u64,
 }
 
+/// Response for POST /round/simulate (returns in lamports)
 #[derive(Serialize)]
-pub struct ErrorResponse {
-    pub error: String,
+pub struct SimulateResponse {
+    /// Live round the pot came from (None when the pot was supplied)
+    pub round_id: Option<u64>,
+    pub total_bet: u64,
+    #[serde(flatten)]
+    pub outcome: crate::round_sim::OutcomeDistribution,
 }
 
 // ============================================================================
@@ -142,14 +167,52 @@ pub struct RoundsPaginationParams {
     pub before: Option<u64>,
 }
 
+#[derive(Deserialize)]
+pub struct SimulateRequest {
+    /// Proposed lamports per square
+    pub bets: [u64; 25],
+    /// Everyone else's deployment per square (default: live round)
+    pub pot: Option<[u64; 25]>,
+    /// Relative chance of each square winning (default: uniform)
+    pub weights: Option<[f64; 25]>,
+    /// Number of simulated rounds (default 10000, max 100000)
+    pub trials: Option<u32>,
+    /// RNG seed (default: round id, or 0 with a supplied pot)
+    pub seed: Option<u64>,
+}
+
 // ============================================================================
 // Route Handlers
 // ============================================================================
 
+/// POST /round/simulate - Monte Carlo SOL return distribution for a bet vector
+pub async fn simulate_round(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<SimulateRequest>,
+) -> Result<Json<SimulateResponse>, AppError> {
+    let (round_id, pot) = match req.pot {
+        Some(pot) => (None, pot),
+        None => match state.round_cache.read().await.as_ref() {
+            Some(round) => (Some(round.round_id), round.deployed),
+            None => return Err(AppError::Unavailable("Round data not yet available".to_string())),
+        },
+    };
+    
+    let trials = req.trials.unwrap_or(crate::round_sim::DEFAULT_TRIALS);
+    let seed = req.seed.or(round_id).unwrap_or(0);
+    let outcome = crate::round_sim::simulate_round(&req.bets, &pot, req.weights.as_ref(), trials, seed);
+    
+    Ok(Json(SimulateResponse {
+        round_id,
+        total_bet: req.bets.iter().sum(),
+        outcome,
+    }))
+}
+
 /// GET /treasury - Current treasury state
 pub async fn get_treasury(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<TreasuryResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<TreasuryResponse>, AppError> {
     let cache = state.treasury_cache.read().await;
     
     match cache.as_ref() {
@@ -160,17 +223,58 @@ pub async fn get_treasury(
             total_unclaimed: treasury.total_unclaimed,
             total_refined: treasury.total_refined,
         })),
-        None => Err((
-            StatusCode::SERVICE_UNAVAILABLE,
-            Json(ErrorResponse { error: "Treasury data not yet available".to_string() }),
-        )),
+        None => Err(AppError::Unavailable("Treasury data not yet available".to_string())),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct OrePriceResponse {
+    /// Value of 1 ORE in SOL
+    pub ore_sol: f64,
+    pub ore_usd: f64,
+    pub sol_usd: f64,
+    /// Price API the value came from
+    pub source: String,
+    /// Unix timestamp of the fetch the price is from
+    pub fetched_at: i64,
+    pub age_secs: i64,
+    /// The last fetch failed or the price is older than two poll intervals
+    pub stale: bool,
+    pub last_error: Option<String>,
+    /// Unix timestamp of the last fetch attempt, successful or not
+    pub last_attempt_at: Option<i64>,
+}
+
+/// GET /ore-price - Current ORE value from the price oracle
+pub async fn get_ore_price(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<OrePriceResponse>, AppError> {
+    let cache = state.ore_price.read().await;
+    let now = chrono::Utc::now().timestamp();
+    
+    match cache.price {
+        Some(price) => Ok(Json(OrePriceResponse {
+            ore_sol: price.ore_sol,
+            ore_usd: price.ore_usd,
+            sol_usd: price.sol_usd,
+            source: cache.config.url.clone(),
+            fetched_at: price.fetched_at,
+            age_secs: now - price.fetched_at,
+            stale: cache.is_stale(now),
+            last_error: cache.last_error.clone(),
+            last_attempt_at: cache.last_attempt_at,
+        })),
+        None => Err(AppError::Unavailable(match &cache.last_error {
+            Some(e) => format!("ORE price not yet available: {}", e),
+            None => "ORE price not yet available".to_string(),
+        })),
     }
 }
 
 /// GET /board - Current board state
 pub async fn get_board(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<BoardResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<BoardResponse>, AppError> {
     let cache = state.board_cache.read().await;
     
     match cache.as_ref() {
@@ -179,17 +283,14 @@ pub async fn get_board(
             start_slot: board.start_slot,
             end_slot: board.end_slot,
         })),
-        None => Err((
-            StatusCode::SERVICE_UNAVAILABLE,
-            Json(ErrorResponse { error: "Board data not yet available".to_string() }),
-        )),
+        None => Err(AppError::Unavailable("Board data not yet available".to_string())),
     }
 }
 
 /// GET /round - Current round with live data
 pub async fn get_round(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<RoundResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<RoundResponse>, AppError> {
     let cache = state.round_cache.read().await;
     let current_slot = *state.slot_cache.read().await;
     
@@ -211,10 +312,7 @@ pub async fn get_round(
             }
             Ok(Json(response))
         }
-        None => Err((
-            StatusCode::SERVICE_UNAVAILABLE,
-            Json(ErrorResponse { error: "Round data not yet available".to_string() }),
-        )),
+        None => Err(AppError::Unavailable("Round data not yet available".to_string())),
     }
 }
 
@@ -230,10 +328,10 @@ pub async fn get_slot(
 pub async fn get_miner(
     State(state): State<Arc<AppState>>,
     Path(pubkey): Path<String>,
-) -> Result<Json<MinerResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<MinerResponse>, AppError> {
     // Validate pubkey format
     let _ = pubkey.parse::<Pubkey>().map_err(|_| {
-        (StatusCode::BAD_REQUEST, Json(ErrorResponse { error: "Invalid pubkey".to_string() }))
+        AppError::BadRequest("Invalid pubkey".to_string())
     })?;
     
     let cache = state.miners_cache.read().await;
@@ -255,10 +353,7 @@ pub async fn get_miner(
                 lifetime_rewards_ore: miner.lifetime_rewards_ore,
             }))
         }
-        None => Err((
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse { error: "Miner not found".to_string() }),
-        )),
+        None => Err(AppError::NotFound("Miner not found".to_string())),
     }
 }
 
@@ -302,9 +397,9 @@ pub async fn get_miners(
 pub async fn get_balance(
     State(state): State<Arc<AppState>>,
     Path(pubkey): Path<String>,
-) -> Result<Json<BalanceResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<BalanceResponse>, AppError> {
     let pk = pubkey.parse::<Pubkey>().map_err(|_| {
-        (StatusCode::BAD_REQUEST, Json(ErrorResponse { error: "Invalid pubkey".to_string() }))
+        AppError::BadRequest("Invalid pubkey".to_string())
     })?;
     
     match state.rpc.get_balance(&pk).await {
@@ -312,21 +407,68 @@ pub async fn get_balance(
             pubkey,
             lamports,
         })),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse { error: format!("RPC error: {}", e) }),
-        )),
+        Err(e) => Err(AppError::Internal(format!("RPC error: {}", e))),
+    }
+}
+
+/// GET /analyze/{signature} - Decoded ORE / EVORE instructions of a transaction
+///
+/// Uses the stored transaction when there is one, otherwise fetches it via RPC.
+/// Lookup-table accounts are resolved from the transaction's loadedAddresses.
+pub async fn analyze_transaction(
+    State(state): State<Arc<AppState>>,
+    Path(signature): Path<String>,
+) -> Result<Json<AnalyzeTransactionResponse>, AppError> {
+    if signature.len() < 80 || signature.len() > 100 {
+        return Err(AppError::BadRequest("Invalid signature format".to_string()));
     }
+    
+    let stored = state.clickhouse
+        .get_transaction_by_signature_v2(&signature)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to look up transaction {}: {}", signature, e);
+            AppError::Internal("Database error".to_string())
+        })?;
+    
+    let (raw_json, source) = match stored {
+        Some(tx) => (tx.raw_json, "clickhouse"),
+        None => {
+            let fetched = state.rpc
+                .get_transaction(&signature)
+                .await
+                .map_err(|e| AppError::BadGateway(format!("RPC error: {}", e)))?;
+            match fetched {
+                Some(tx) => (tx.raw_json, "rpc"),
+                None => return Err(AppError::NotFound("Transaction not found".to_string())),
+            }
+        }
+    };
+    
+    let analysis = crate::tx_analyzer::TransactionAnalyzer::new()
+        .analyze(&raw_json)
+        .map_err(|e| AppError::Internal(format!("Analysis failed: {}", e)))?;
+    
+    Ok(Json(AnalyzeTransactionResponse {
+        instructions: analysis.ore_evore_instructions(),
+        memos: analysis.memos(),
+        signature: analysis.signature,
+        source,
+        slot: analysis.slot,
+        block_time: analysis.block_time,
+        success: analysis.success,
+        error: analysis.error,
+    }))
 }
 
 /// GET /signature/{signature} - Transaction signature status (RPC proxy)
 pub async fn get_signature_status(
     State(state): State<Arc<AppState>>,
     Path(signature): Path<String>,
-) -> Result<Json<SignatureStatusResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<SignatureStatusResponse>, AppError> {
     // Basic validation - signature should be base58 encoded, typically 87-88 chars
     if signature.len() < 80 || signature.len() > 100 {
-        return Err((StatusCode::BAD_REQUEST, Json(ErrorResponse { error: "Invalid signature format".to_string() })));
+        return Err(AppError::BadRequest("Invalid signature format".to_string()));
     }
     
     match state.rpc.get_signature_statuses(&[signature.clone()]).await {
@@ -359,10 +501,7 @@ pub async fn get_signature_status(
                 err,
             }))
         }
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse { error: format!("RPC error: {}", e) }),
-        )),
+        Err(e) => Err(AppError::Internal(format!("RPC error: {}", e))),
     }
 }
 
@@ -370,9 +509,9 @@ pub async fn get_signature_status(
 pub async fn get_ore_balance(
     State(state): State<Arc<AppState>>,
     Path(owner): Path<String>,
-) -> Result<Json<OreBalanceResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<OreBalanceResponse>, AppError> {
     let pk = owner.parse::<Pubkey>().map_err(|_| {
-        (StatusCode::BAD_REQUEST, Json(ErrorResponse { error: "Invalid pubkey".to_string() }))
+        AppError::BadRequest("Invalid pubkey".to_string())
     })?;
     
     let cache = state.ore_holders_cache.read().await;
@@ -419,11 +558,15 @@ pub async fn get_ore_holders(
         .take(per_page)
         .collect();
     
+    drop(cache);
+    let last_sync_slot = *state.ore_holders_last_slot.read().await;
+    
     Json(OreHoldersResponse {
         holders: page_holders,
         total,
         page,
         per_page,
+        last_sync_slot,
     })
 }
 
@@ -455,10 +598,34 @@ pub async fn get_metrics(
 /// GET /live/round - Live round with unique miners (same as /round)
 pub async fn get_live_round(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<RoundResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<RoundResponse>, AppError> {
     get_round(State(state)).await
 }
 
+/// How long a `/live/board-vector` response is reused
+const BOARD_VECTOR_TTL: Duration = Duration::from_millis(250);
+
+/// GET /live/board-vector - Live round as `{ round_id, end_slot, slots_left, squares, total }`
+/// Built from the cached round and reused for BOARD_VECTOR_TTL, so it can be polled cheaply
+pub async fn get_live_board_vector(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<BoardVector>, AppError> {
+    if let Some((built_at, vector)) = state.board_vector_cache.read().await.as_ref() {
+        if built_at.elapsed() < BOARD_VECTOR_TTL {
+            return Ok(Json(vector.clone()));
+        }
+    }
+    
+    let vector = {
+        let round = state.round_cache.read().await;
+        let round = round.as_ref()
+            .ok_or_else(|| AppError::Unavailable("Round data not yet available".to_string()))?;
+        BoardVector::from_live_round(round, *state.slot_cache.read().await)
+    };
+    *state.board_vector_cache.write().await = Some((Instant::now(), vector.clone()));
+    Ok(Json(vector))
+}
+
 /// Live deployment info for one miner
 #[derive(Serialize)]
 pub struct LiveDeploymentEntry {
@@ -513,11 +680,40 @@ pub async fn get_live_deployments(
     })
 }
 
-/// Health check
+/// GET /livez (and /health) - Process is up and serving requests
 pub async fn health() -> &'static str {
     "OK"
 }
 
+#[derive(Serialize)]
+pub struct ReadinessResponse {
+    pub ready: bool,
+    #[serde(flatten)]
+    pub checks: ReadinessChecks,
+}
+
+/// GET /readyz - Subscriptions connected, databases reachable and first round loaded
+///
+/// 503 until every check passes, so traffic is held back during the initial load.
+pub async fn readyz(State(state): State<Arc<AppState>>) -> (StatusCode, Json<ReadinessResponse>) {
+    let clickhouse = tokio::time::timeout(DB_PING_TIMEOUT, state.clickhouse.ping());
+    let postgres = tokio::time::timeout(DB_PING_TIMEOUT, sqlx::query("SELECT 1").execute(&state.postgres));
+    let (clickhouse, postgres) = tokio::join!(clickhouse, postgres);
+    
+    let checks = ReadinessChecks {
+        slot_subscription: state.subscriptions.slot(),
+        program_subscription: state.subscriptions.program(),
+        clickhouse: matches!(clickhouse, Ok(Ok(_))),
+        postgres: matches!(postgres, Ok(Ok(_))),
+        round_loaded: state.board_cache.read().await.is_some()
+            && state.treasury_cache.read().await.is_some()
+            && state.round_cache.read().await.is_some(),
+    };
+    let ready = checks.ready();
+    let status = if ready { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    (status, Json(ReadinessResponse { ready, checks }))
+}
+
 // ============================================================================
 // Historical Data Endpoints
 // ============================================================================
@@ -534,7 +730,7 @@ pub async fn health() -> &'static str {
 pub async fn get_rounds(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RoundsPaginationParams>,
-) -> Result<Json<RoundsListResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<RoundsListResponse>, AppError> {
     let per_page = params.per_page.unwrap_or(50).min(100);
     let limit = per_page as u32;
     
@@ -579,10 +775,7 @@ pub async fn get_rounds(
                 page: params.page,
             }))
         }
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse { error: format!("Database error: {}", e) }),
-        )),
+        Err(e) => Err(AppError::Internal(format!("Database error: {}", e))),
     }
 }
 
@@ -590,27 +783,18 @@ pub async fn get_rounds(
 pub async fn get_round_by_id(
     State(state): State<Arc<AppState>>,
     Path(round_id): Path<u64>,
-) -> Result<Json<RoundDetailResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<RoundDetailResponse>, AppError> {
     // Get round
     let round = match state.clickhouse.get_round_by_id(round_id).await {
         Ok(Some(r)) => r,
-        Ok(None) => return Err((
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse { error: "Round not found".to_string() }),
-        )),
-        Err(e) => return Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse { error: format!("Database error: {}", e) }),
-        )),
+        Ok(None) => return Err(AppError::NotFound("Round not found".to_string())),
+        Err(e) => return Err(AppError::Internal(format!("Database error: {}", e))),
     };
     
     // Get deployments
     let deployments = match state.clickhouse.get_deployments_for_round(round_id).await {
         Ok(d) => d,
-        Err(e) => return Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse { error: format!("Database error: {}", e) }),
-        )),
+        Err(e) => return Err(AppError::Internal(format!("Database error: {}", e))),
     };
     
     Ok(Json(RoundDetailResponse {
@@ -640,6 +824,119 @@ pub async fn get_round_by_id(
     }))
 }
 
+#[derive(Serialize)]
+pub struct RoundPayoutsResponse {
+    pub round_id: u64,
+    pub winning_square: u8,
+    pub total_winnings: u64,
+    /// Total deployed on the winning square
+    pub winning_square_deployed: u64,
+    pub top_miner: String,
+    pub top_miner_reward: u64,
+    /// Whether the top miner reward was split across the winning square
+    pub split: bool,
+    pub motherlode: u64,
+    /// Largest SOL payout first
+    pub payouts: Vec<crate::round_payouts::Payout>,
+}
+
+/// GET /rounds/{round_id}/payouts - Payout breakdown of the winning square
+pub async fn get_round_payouts(
+    State(state): State<Arc<AppState>>,
+    Path(round_id): Path<u64>,
+) -> Result<Json<RoundPayoutsResponse>, AppError> {
+    use crate::round_payouts::{compute_payouts, is_split, PayoutRound, WinningStake};
+    
+    let round = match state.clickhouse.get_round_by_id(round_id).await {
+        Ok(Some(r)) => r,
+        Ok(None) => return Err(AppError::NotFound("Round not found".to_string())),
+        Err(e) => return Err(AppError::Internal(format!("Database error: {}", e))),
+    };
+    
+    let stakes: Vec<WinningStake> = match state.clickhouse.get_winning_square_stakes(round_id).await {
+        Ok(rows) => rows.into_iter()
+            .map(|r| WinningStake { miner_pubkey: r.miner_pubkey, amount: r.amount })
+            .collect(),
+        Err(e) => return Err(AppError::Internal(format!("Database error: {}", e))),
+    };
+    
+    let payout_round = PayoutRound {
+        total_winnings: round.total_winnings,
+        top_miner: round.top_miner.clone(),
+        top_miner_reward: round.top_miner_reward,
+        motherlode: if round.motherlode_hit > 0 { round.motherlode } else { 0 },
+    };
+    
+    Ok(Json(RoundPayoutsResponse {
+        round_id: round.round_id,
+        winning_square: round.winning_square,
+        total_winnings: round.total_winnings,
+        winning_square_deployed: stakes.iter().map(|s| s.amount).sum(),
+        top_miner: round.top_miner,
+        top_miner_reward: round.top_miner_reward,
+        split: !stakes.is_empty() && is_split(&payout_round, &stakes),
+        motherlode: payout_round.motherlode,
+        payouts: compute_payouts(&payout_round, &stakes),
+    }))
+}
+
+#[derive(Serialize)]
+pub struct RoundEntropyResponse {
+    pub round_id: u64,
+    pub var_address: String,
+    pub provider: String,
+    /// Hex-encoded entropy var fields as read at finalization
+    pub commit: String,
+    pub seed: String,
+    pub slot_hash: String,
+    pub value: String,
+    pub samples: u64,
+    pub start_at: u64,
+    pub end_at: u64,
+    /// Hex-encoded slot_hash of the round itself
+    pub round_slot_hash: String,
+    /// Winning square recorded for the round
+    pub winning_square: u8,
+    /// Winning square re-derived from the recorded entropy value
+    pub derived_winning_square: Option<u8>,
+    /// Whether the entropy value is the round's slot_hash and yields its winning square
+    pub verified: bool,
+}
+
+/// GET /rounds/{round_id}/entropy - Entropy recorded at finalization and the
+/// winning square derived from it
+pub async fn get_round_entropy(
+    State(state): State<Arc<AppState>>,
+    Path(round_id): Path<u64>,
+) -> Result<Json<RoundEntropyResponse>, AppError> {
+    let entropy = match state.clickhouse.get_round_entropy(round_id).await {
+        Ok(Some(e)) => e,
+        Ok(None) => return Err(AppError::NotFound("No entropy recorded for round".to_string())),
+        Err(e) => return Err(AppError::Internal(format!("Database error: {}", e))),
+    };
+    
+    let derived_winning_square = winning_square_from_entropy(entropy.value);
+    let verified = entropy.value == entropy.round_slot_hash
+        && derived_winning_square == Some(entropy.winning_square);
+    
+    Ok(Json(RoundEntropyResponse {
+        round_id: entropy.round_id,
+        var_address: entropy.var_address,
+        provider: entropy.provider,
+        commit: hex::encode(entropy.commit),
+        seed: hex::encode(entropy.seed),
+        slot_hash: hex::encode(entropy.slot_hash),
+        value: hex::encode(entropy.value),
+        samples: entropy.samples,
+        start_at: entropy.start_at,
+        end_at: entropy.end_at,
+        round_slot_hash: hex::encode(entropy.round_slot_hash),
+        winning_square: entropy.winning_square,
+        derived_winning_square,
+        verified,
+    }))
+}
+
 #[derive(Serialize)]
 pub struct RoundsListResponse {
     pub rounds: Vec<RoundSummary>,
@@ -3,6 +3,7 @@ use std::{collections::{HashMap, HashSet}, time::Duration, u64};
 use anyhow::{anyhow, Result};
 use chrono::Utc;
 use const_crypto::ed25519;
+use evore::board::mask_to_squares;
 use evore::ore_api::{BOARD, ROUND, TREASURY_ADDRESS, round_pda, AutomationStrategy, Board, Miner, Round, Treasury};
 use solana_account_decoder_client_types::UiAccountEncoding;
 use solana_client::{nonblocking::rpc_client::RpcClient, rpc_filter::RpcFilterType};
@@ -10,7 +11,7 @@ use solana_sdk::{commitment_config::{CommitmentConfig, CommitmentLevel}, keccak:
 use steel::{AccountDeserialize, Pubkey};
 use tokio::time::Instant;
 
-use crate::{app_state::{AppDeployedSquare, AppDeployment, AppRound, AutomationCache, ReconstructedAutomation, ReconstructedRound}, helius_api::{HeliusApi, ParsedDeployment, ResetEvent}};
+use crate::{app_state::{AppDeployedSquare, AppDeployment, AppRound, AutomationCacheStore, ReconstructedAutomation, ReconstructedRound}, helius_api::{HeliusApi, HeliusError, ParsedDeployment, ResetEvent}};
 
 /// Program id for const pda derivations
 const PROGRAM_ID_BYTES: [u8; 32] = evore::ore_api::PROGRAM_ID.to_bytes();
@@ -29,7 +30,7 @@ pub struct AppRPC {
     helius: HeliusApi,
     connection: RpcClient,
     last_request_at: Instant,
-    automation_cache: HashMap<Pubkey, AutomationCache>
+    automation_cache: AutomationCacheStore,
 }
 
 impl AppRPC {
@@ -42,7 +43,7 @@ impl AppRPC {
             helius,
             connection,
             last_request_at: Instant::now(),
-            automation_cache: HashMap::new(),
+            automation_cache: AutomationCacheStore::from_env(),
         }
     }
 
@@ -282,18 +283,27 @@ impl AppRPC {
                 // and filters to successful txns only.
                 let mut auto = None;
                 if let Some(_) = automation_users.get(authority) {
-                    let prev_cache = self.automation_cache.get(authority).cloned();
-                    let (auto_opt, new_cache) = self
+                    let prev_cache = self.automation_cache.get(authority);
+                    match self
                         .helius
                         .get_latest_automate_for_authority_up_to_slot(authority, effective_cutoff_slot, prev_cache)
-                        .await?;
-
-                    auto = auto_opt;
-
-                    self.automation_cache.insert(*authority, new_cache);
-                    match &auto {
-                        Some(_) => {println!("Found automation for miner: {}", authority.to_string());},
-                        None => {}
+                        .await
+                    {
+                        Ok((auto_opt, new_cache)) => {
+                            auto = auto_opt;
+
+                            self.automation_cache.insert(new_cache);
+                            match &auto {
+                                Some(_) => {println!("Found automation for miner: {}", authority.to_string());},
+                                None => {}
+                            }
+                        }
+                        // Unknown isn't off: replay as manual this time, but leave the
+                        // cache alone so the next reconstruction scans again
+                        Err(e @ HeliusError::ScanTruncated { .. }) => {
+                            println!("Automation state unknown for miner {}: {}", authority, e);
+                        }
+                        Err(e) => return Err(e.into()),
                     }
                 }
 
@@ -345,11 +355,7 @@ impl AppRPC {
                 match auto.strategy {
                     AutomationStrategy::Preferred => {
                         // Use automation.mask bits directly
-                        let mut squares = [false; 25];
-                        for i in 0..25 {
-                            squares[i] = (auto.mask & (1 << i)) != 0;
-                        }
-                        pd.squares = squares;
+                        pd.squares = mask_to_squares(auto.mask as u32);
                     }
                     AutomationStrategy::Random => {
                         // num_squares = lower 8 bits of mask, capped at 25
@@ -365,11 +371,7 @@ impl AppRPC {
                     AutomationStrategy::Discretionary => {
                         // Discretionary strategy - executor decides squares at deploy time
                         // For now, treat same as Preferred using mask
-                        let mut squares = [false; 25];
-                        for i in 0..25 {
-                            squares[i] = (auto.mask & (1 << i)) != 0;
-                        }
-                        pd.squares = squares;
+                        pd.squares = mask_to_squares(auto.mask as u32);
                     }
                 }
             } else {
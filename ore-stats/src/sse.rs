@@ -1,47 +1,266 @@
 //! Server-Sent Events (SSE) endpoints
 //!
-//! - `/sse/rounds` - Round updates (throttled to 500ms)
-//! - `/sse/deployments` - Deployment events (batched: 10 items or 200ms)
+//! - `/sse/rounds` - The current round on connect, then round updates (throttled
+//!   to 500ms, sent at once on a round transition)
+//! - `/sse/board` - Board as a `snapshot` on connect and at each new round, then
+//!   `diff` events with only the squares that changed (`{ square, new_amount }`)
+//! - `/sse/deployments` - Deployment events, one per deployment; `?batch_ms=`
+//!   coalesces them into periodic `deployments` array events instead
+//! - `/sse/slot` - Current slot with the active round's slots left, sent when
+//!   the slot advances (checked every `SLOT_POLL_INTERVAL`)
+//!
+//! Plus a plain NDJSON variant for clients without an SSE parser:
+//! - `/live/deployments.ndjson` - One deployment object per line
+//!
+//! Each client reads from a bounded buffer (`SSE_CLIENT_BUFFER` events). A client
+//! that falls further behind is handled per `SSE_BACKPRESSURE`: `drop_oldest`
+//! (default) skips the missed events and sends a `gap` marker, `disconnect`
+//! closes the stream. Dropped events are counted per stream in `SseStats`.
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
 use axum::{
-    extract::State,
-    response::sse::{Event, Sse},
+    body::Body,
+    extract::{Query, State},
+    http::header,
+    response::{
+        sse::{Event, Sse},
+        IntoResponse,
+    },
 };
-use futures_util::stream::Stream;
-use tokio_stream::wrappers::BroadcastStream;
+use futures_util::stream::{BoxStream, Stream};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::{self, error::RecvError};
 use tokio_stream::StreamExt;
 
-use crate::app_state::{AppState, LiveBroadcastData};
+use crate::app_state::{AppState, LiveBroadcastData, LiveDeployment, LiveRound};
+
+/// Shortest accepted `batch_ms`
+const MIN_BATCH_MS: u64 = 50;
+/// Longest accepted `batch_ms`
+const MAX_BATCH_MS: u64 = 10_000;
+
+/// How often `/sse/slot` checks the slot cache (about half a slot, so no
+/// slot is reported late by more than that and none more than once)
+const SLOT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Default per-client buffer, in events
+const DEFAULT_CLIENT_BUFFER: usize = 1000;
+/// Smallest accepted per-client buffer
+const MIN_CLIENT_BUFFER: usize = 16;
+
+/// What to do with a client that falls more than a buffer behind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackpressurePolicy {
+    /// Skip the missed events and send a `gap` marker in their place
+    #[default]
+    DropOldest,
+    /// Close the slow client's stream
+    Disconnect,
+}
+
+/// SSE buffering config (`SSE_CLIENT_BUFFER`, `SSE_BACKPRESSURE`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SseConfig {
+    /// Events buffered per client before it counts as lagging
+    pub client_buffer: usize,
+    pub policy: BackpressurePolicy,
+}
+
+impl Default for SseConfig {
+    fn default() -> Self {
+        Self {
+            client_buffer: DEFAULT_CLIENT_BUFFER,
+            policy: BackpressurePolicy::default(),
+        }
+    }
+}
+
+impl SseConfig {
+    /// Read the config from the environment, falling back to defaults on bad values
+    pub fn from_env() -> Self {
+        Self::from_vars(|key| std::env::var(key).ok())
+    }
+    
+    fn from_vars(get: impl Fn(&str) -> Option<String>) -> Self {
+        let client_buffer = get("SSE_CLIENT_BUFFER")
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(DEFAULT_CLIENT_BUFFER);
+        if client_buffer < MIN_CLIENT_BUFFER {
+            tracing::warn!("SSE_CLIENT_BUFFER={} is below the minimum, using {}", client_buffer, MIN_CLIENT_BUFFER);
+        }
+        
+        let policy = match get("SSE_BACKPRESSURE").as_deref().map(str::to_ascii_lowercase).as_deref() {
+            None | Some("drop_oldest") => BackpressurePolicy::DropOldest,
+            Some("disconnect") => BackpressurePolicy::Disconnect,
+            Some(other) => {
+                tracing::warn!("Unknown SSE_BACKPRESSURE={}, using drop_oldest", other);
+                BackpressurePolicy::DropOldest
+            }
+        };
+        
+        Self {
+            client_buffer: client_buffer.max(MIN_CLIENT_BUFFER),
+            policy,
+        }
+    }
+}
+
+/// Live streams tracked in `SseStats`
+#[derive(Debug, Clone, Copy)]
+pub enum SseStream {
+    Rounds,
+    Board,
+    Deployments,
+    DeploymentsNdjson,
+}
+
+/// Counters for a single stream
+#[derive(Debug, Default)]
+struct StreamCounters {
+    dropped_events: AtomicU64,
+    disconnects: AtomicU64,
+}
+
+/// Dropped-event and slow-client disconnect counts per stream
+#[derive(Debug, Default)]
+pub struct SseStats {
+    rounds: StreamCounters,
+    board: StreamCounters,
+    deployments: StreamCounters,
+    deployments_ndjson: StreamCounters,
+}
+
+/// Point-in-time copy of one stream's counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SseStreamStats {
+    pub dropped_events: u64,
+    pub disconnects: u64,
+}
+
+/// Point-in-time copy of `SseStats`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SseStatsSnapshot {
+    pub rounds: SseStreamStats,
+    pub board: SseStreamStats,
+    pub deployments: SseStreamStats,
+    pub deployments_ndjson: SseStreamStats,
+}
+
+impl SseStats {
+    fn counters(&self, stream: SseStream) -> &StreamCounters {
+        match stream {
+            SseStream::Rounds => &self.rounds,
+            SseStream::Board => &self.board,
+            SseStream::Deployments => &self.deployments,
+            SseStream::DeploymentsNdjson => &self.deployments_ndjson,
+        }
+    }
+    
+    pub fn snapshot(&self) -> SseStatsSnapshot {
+        let copy = |c: &StreamCounters| SseStreamStats {
+            dropped_events: c.dropped_events.load(Ordering::Relaxed),
+            disconnects: c.disconnects.load(Ordering::Relaxed),
+        };
+        SseStatsSnapshot {
+            rounds: copy(&self.rounds),
+            board: copy(&self.board),
+            deployments: copy(&self.deployments),
+            deployments_ndjson: copy(&self.deployments_ndjson),
+        }
+    }
+}
+
+/// Item read from a client's buffer
+#[derive(Debug)]
+enum Buffered {
+    Data(LiveBroadcastData),
+    /// The client fell behind and this many events were skipped
+    Gap(u64),
+}
+
+/// Body of a `gap` marker
+#[derive(Debug, Serialize)]
+struct GapMarker {
+    missed: u64,
+}
+
+impl GapMarker {
+    fn json(missed: u64) -> String {
+        serde_json::to_string(&GapMarker { missed }).unwrap_or_default()
+    }
+}
+
+/// Read a client's buffer, applying `policy` (and counting drops) when it lags
+fn buffered(
+    policy: BackpressurePolicy,
+    stats: Arc<SseStats>,
+    stream: SseStream,
+    mut rx: broadcast::Receiver<LiveBroadcastData>,
+) -> impl Stream<Item = Buffered> + Send + 'static {
+    async_stream::stream! {
+        loop {
+            match rx.recv().await {
+                Ok(data) => yield Buffered::Data(data),
+                Err(RecvError::Lagged(missed)) => {
+                    let counters = stats.counters(stream);
+                    counters.dropped_events.fetch_add(missed, Ordering::Relaxed);
+                    match policy {
+                        BackpressurePolicy::DropOldest => yield Buffered::Gap(missed),
+                        BackpressurePolicy::Disconnect => {
+                            counters.disconnects.fetch_add(1, Ordering::Relaxed);
+                            tracing::debug!("Disconnecting slow {:?} client ({} events behind)", stream, missed);
+                            break;
+                        }
+                    }
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+}
+
+/// SSE `gap` event telling the client `missed` events were skipped
+fn gap_event(missed: u64) -> Event {
+    Event::default().event("gap").data(GapMarker::json(missed))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SseDeploymentsQuery {
+    /// Coalesce deployments and flush every N ms (unset / 0 = one event per deployment)
+    pub batch_ms: Option<u64>,
+}
 
 /// GET /sse/rounds - Stream round updates (throttled)
+///
+/// Starts with the current round right away instead of waiting for the
+/// broadcaster's next tick.
 pub async fn sse_rounds(
     State(state): State<Arc<AppState>>,
 ) -> Sse<impl Stream<Item = Result<Event, std::convert::Infallible>>> {
+    // Subscribe before reading the round so no update falls between them
     let rx = state.subscribe_rounds();
-    let stream = BroadcastStream::new(rx);
+    let initial = crate::websocket::current_live_round(&state).await;
+    let stream = buffered(state.sse_config.policy, state.sse_stats.clone(), SseStream::Rounds, rx);
     
-    let event_stream = stream
-        .filter_map(|result| {
-            match result {
-                Ok(data) => {
-                    match &data {
-                        LiveBroadcastData::Round(_) => {
-                            let json = serde_json::to_string(&data).ok()?;
-                            Some(Ok(Event::default().event("round").data(json)))
-                        }
-                        LiveBroadcastData::WinningSquare { .. } => {
-                            let json = serde_json::to_string(&data).ok()?;
-                            Some(Ok(Event::default().event("winning_square").data(json)))
-                        }
-                        _ => None,
+    let event_stream = async_stream::stream! {
+        if let Some(event) = initial.and_then(|round| round_event(&LiveBroadcastData::Round(round))) {
+            yield Ok(event);
+        }
+        let mut stream = std::pin::pin!(stream);
+        while let Some(item) = stream.next().await {
+            match item {
+                Buffered::Data(data) => {
+                    if let Some(event) = round_event(&data) {
+                        yield Ok(event);
                     }
                 }
-                Err(_) => None,
+                Buffered::Gap(missed) => yield Ok(gap_event(missed)),
             }
-        });
+        }
+    };
     
     Sse::new(event_stream).keep_alive(
         axum::response::sse::KeepAlive::new()
@@ -50,17 +269,109 @@ pub async fn sse_rounds(
     )
 }
 
-/// GET /sse/deployments - Stream deployment events (batched)
+/// `round` or `winning_square` event for the rounds stream
+fn round_event(data: &LiveBroadcastData) -> Option<Event> {
+    let name = match data {
+        LiveBroadcastData::Round(_) => "round",
+        LiveBroadcastData::WinningSquare { .. } => "winning_square",
+        _ => return None,
+    };
+    Some(Event::default().event(name).data(serde_json::to_string(data).ok()?))
+}
+
+/// GET /sse/board - Stream board changes as diffs
+///
+/// Starts with a `snapshot` of the board as last broadcast, then sends a `diff`
+/// with only the changed squares each time the board moves. Clients apply the
+/// new amounts to their copy. A new round sends a fresh `snapshot`, and so
+/// does a gap (the client fell behind and missed diffs).
+pub async fn sse_board(
+    State(state): State<Arc<AppState>>,
+) -> Sse<impl Stream<Item = Result<Event, std::convert::Infallible>>> {
+    // Subscribe before reading the snapshot so no diff falls between them
+    let rx = state.subscribe_rounds();
+    let initial = state.last_board_broadcast.read().await.clone();
+    let stream = buffered(state.sse_config.policy, state.sse_stats.clone(), SseStream::Board, rx);
+    
+    let last_board = state.last_board_broadcast.clone();
+    let event_stream = async_stream::stream! {
+        if let Some(snapshot) = initial {
+            if let Some(event) = board_event(&LiveBroadcastData::BoardSnapshot(snapshot)) {
+                yield Ok(event);
+            }
+        }
+        let mut stream = std::pin::pin!(stream);
+        while let Some(item) = stream.next().await {
+            match item {
+                Buffered::Data(data) => {
+                    if let Some(event) = board_event(&data) {
+                        yield Ok(event);
+                    }
+                }
+                Buffered::Gap(missed) => {
+                    yield Ok(gap_event(missed));
+                    // Missed diffs can't be replayed, resync instead
+                    let snapshot = last_board.read().await.clone();
+                    if let Some(event) = snapshot.and_then(|s| board_event(&LiveBroadcastData::BoardSnapshot(s))) {
+                        yield Ok(event);
+                    }
+                }
+            }
+        }
+    };
+    
+    Sse::new(event_stream).keep_alive(
+        axum::response::sse::KeepAlive::new()
+            .interval(Duration::from_secs(15))
+            .text("ping"),
+    )
+}
+
+/// `snapshot`, `diff` or `winning_square` event for the board stream
+fn board_event(data: &LiveBroadcastData) -> Option<Event> {
+    let (name, json) = match data {
+        LiveBroadcastData::BoardSnapshot(snapshot) => ("snapshot", serde_json::to_string(snapshot).ok()?),
+        LiveBroadcastData::BoardDiff(diff) => ("diff", serde_json::to_string(diff).ok()?),
+        LiveBroadcastData::WinningSquare { .. } => ("winning_square", serde_json::to_string(data).ok()?),
+        _ => return None,
+    };
+    Some(Event::default().event(name).data(json))
+}
+
+/// GET /sse/deployments - Stream deployment events
+///
+/// Unbatched by default for low-latency consumers. With `?batch_ms=N` deployments
+/// are sent as `deployments` array events every N ms, and the batch is always
+/// flushed at a round boundary so no array spans two rounds.
 pub async fn sse_deployments(
     State(state): State<Arc<AppState>>,
+    Query(params): Query<SseDeploymentsQuery>,
 ) -> Sse<impl Stream<Item = Result<Event, std::convert::Infallible>>> {
-    let rx = state.subscribe_deployments();
-    let stream = BroadcastStream::new(rx);
+    let rx = buffered(state.sse_config.policy, state.sse_stats.clone(), SseStream::Deployments, state.subscribe_deployments());
     
-    let event_stream = stream
-        .filter_map(|result| {
-            match result {
-                Ok(data) => {
+    let event_stream: BoxStream<'static, _> = match params.batch_ms.filter(|ms| *ms > 0) {
+        Some(ms) => {
+            let every = Duration::from_millis(ms.clamp(MIN_BATCH_MS, MAX_BATCH_MS));
+            Box::pin(batched_deployment_events(rx, every))
+        }
+        None => Box::pin(deployment_events(rx)),
+    };
+    
+    Sse::new(event_stream).keep_alive(
+        axum::response::sse::KeepAlive::new()
+            .interval(Duration::from_secs(15))
+            .text("ping"),
+    )
+}
+
+/// One SSE event per deployment
+fn deployment_events(
+    rx: impl Stream<Item = Buffered>,
+) -> impl Stream<Item = Result<Event, std::convert::Infallible>> {
+    rx
+        .filter_map(|item| {
+            match item {
+                Buffered::Data(data) => {
                     match &data {
                         LiveBroadcastData::Deployment(_) => {
                             let json = serde_json::to_string(&data).ok()?;
@@ -73,10 +384,171 @@ pub async fn sse_deployments(
                         _ => None,
                     }
                 }
-                Err(_) => None,
+                Buffered::Gap(missed) => Some(Ok(gap_event(missed))),
+            }
+        })
+}
+
+/// Deployments coalesced into a `deployments` array event every `every`
+fn batched_deployment_events(
+    rx: impl Stream<Item = Buffered> + Send + 'static,
+    every: Duration,
+) -> impl Stream<Item = Result<Event, std::convert::Infallible>> {
+    async_stream::stream! {
+        let mut rx = std::pin::pin!(rx);
+        let mut batch = DeploymentBatch::default();
+        let mut ticker = tokio::time::interval(every);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        
+        loop {
+            let events: Vec<Event> = tokio::select! {
+                _ = ticker.tick() => batch_event(batch.take()).into_iter().collect(),
+                msg = rx.next() => match msg {
+                    Some(Buffered::Data(LiveBroadcastData::Deployment(deployment))) => {
+                        // A new round flushes the previous round's batch right away
+                        batch.push(deployment).and_then(batch_event).into_iter().collect()
+                    }
+                    Some(Buffered::Data(data @ LiveBroadcastData::WinningSquare { .. })) => {
+                        // Round ended: flush what's pending before the announcement
+                        let mut events: Vec<Event> = batch_event(batch.take()).into_iter().collect();
+                        if let Ok(json) = serde_json::to_string(&data) {
+                            events.push(Event::default().event("winning_square").data(json));
+                        }
+                        events
+                    }
+                    Some(Buffered::Gap(missed)) => vec![gap_event(missed)],
+                    Some(Buffered::Data(_)) => Vec::new(),
+                    // Closed, or a slow client was disconnected: send what's pending
+                    None => {
+                        if let Some(event) = batch_event(batch.take()) {
+                            yield Ok(event);
+                        }
+                        break;
+                    }
+                },
+            };
+            
+            for event in events {
+                yield Ok(event);
+            }
+        }
+    }
+}
+
+/// `deployments` event for a flushed batch (None when empty)
+fn batch_event(deployments: Vec<LiveDeployment>) -> Option<Event> {
+    if deployments.is_empty() {
+        return None;
+    }
+    let json = serde_json::to_string(&deployments).ok()?;
+    Some(Event::default().event("deployments").data(json))
+}
+
+/// Pending deployments for one round
+#[derive(Debug, Default)]
+struct DeploymentBatch {
+    pending: Vec<LiveDeployment>,
+}
+
+impl DeploymentBatch {
+    /// Add a deployment; returns the previous round's batch if this one starts a new round
+    fn push(&mut self, deployment: LiveDeployment) -> Option<Vec<LiveDeployment>> {
+        let new_round = self.pending.first().map_or(false, |p| p.round_id != deployment.round_id);
+        let flushed = new_round.then(|| self.take());
+        self.pending.push(deployment);
+        flushed
+    }
+    
+    /// Drain everything pending
+    fn take(&mut self) -> Vec<LiveDeployment> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+/// GET /live/deployments.ndjson - Stream deployments as newline-delimited JSON
+///
+/// Same source as `/sse/deployments`, but each deployment is written as a
+/// bare JSON object followed by `\n` (no event framing or keep-alives). A
+/// `{"missed":N}` line marks a gap when the client fell behind.
+pub async fn ndjson_deployments(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let rx = state.subscribe_deployments();
+    let stream = buffered(state.sse_config.policy, state.sse_stats.clone(), SseStream::DeploymentsNdjson, rx);
+    
+    let line_stream = stream
+        .filter_map(|item| {
+            match item {
+                Buffered::Data(LiveBroadcastData::Deployment(deployment)) => {
+                    let mut line = serde_json::to_string(&deployment).ok()?;
+                    line.push('\n');
+                    Some(Ok::<_, std::convert::Infallible>(line))
+                }
+                Buffered::Gap(missed) => Some(Ok(GapMarker::json(missed) + "\n")),
+                Buffered::Data(_) => None,
             }
         });
     
+    (
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(line_stream),
+    )
+}
+
+/// Body of a `/sse/slot` event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SlotTick {
+    pub slot: u64,
+    pub round_id: Option<u64>,
+    pub start_slot: Option<u64>,
+    pub end_slot: Option<u64>,
+    /// Slots until the round ends (None until the round has an end slot)
+    pub slots_left: Option<u64>,
+}
+
+impl SlotTick {
+    fn new(slot: u64, round: Option<&LiveRound>) -> Self {
+        // end_slot is u64::MAX until the round's first deploy starts the clock
+        let end_slot = round.map(|r| r.end_slot).filter(|&end| end != u64::MAX);
+        Self {
+            slot,
+            round_id: round.map(|r| r.round_id),
+            start_slot: round.map(|r| r.start_slot),
+            end_slot,
+            slots_left: end_slot.map(|end| end.saturating_sub(slot)),
+        }
+    }
+}
+
+/// GET /sse/slot - Stream the current slot with round context
+///
+/// Sends a `slot` event on connect and then whenever the slot or round
+/// changes. The cache is checked every `SLOT_POLL_INTERVAL`, so slots that
+/// advance faster than that are coalesced into one event. The stream holds no
+/// background task; it stops when the client disconnects and axum drops it.
+pub async fn sse_slot(
+    State(state): State<Arc<AppState>>,
+) -> Sse<impl Stream<Item = Result<Event, std::convert::Infallible>>> {
+    let event_stream = async_stream::stream! {
+        let mut ticker = tokio::time::interval(SLOT_POLL_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut last: Option<SlotTick> = None;
+        
+        loop {
+            ticker.tick().await;
+            let slot = *state.slot_cache.read().await;
+            if slot == 0 {
+                continue;
+            }
+            let tick = SlotTick::new(slot, state.round_cache.read().await.as_ref());
+            if last.map_or(false, |l| (l.slot, l.round_id) == (tick.slot, tick.round_id)) {
+                continue;
+            }
+            last = Some(tick);
+            if let Ok(json) = serde_json::to_string(&tick) {
+                yield Ok(Event::default().event("slot").data(json));
+            }
+        }
+    };
+    
     Sse::new(event_stream).keep_alive(
         axum::response::sse::KeepAlive::new()
             .interval(Duration::from_secs(15))
@@ -84,3 +556,116 @@ pub async fn sse_deployments(
     )
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn deployment(round_id: u64, slot: u64) -> LiveDeployment {
+        LiveDeployment {
+            round_id,
+            miner_pubkey: "miner".to_string(),
+            amounts: [0; 25],
+            slot,
+        }
+    }
+    
+    #[test]
+    fn test_deployment_batch_flushes_on_round_change() {
+        let mut batch = DeploymentBatch::default();
+        assert!(batch.push(deployment(1, 10)).is_none());
+        assert!(batch.push(deployment(1, 11)).is_none());
+        
+        // First deployment of round 2 hands back all of round 1
+        let flushed = batch.push(deployment(2, 20)).unwrap();
+        assert_eq!(flushed.iter().map(|d| d.slot).collect::<Vec<_>>(), vec![10, 11]);
+        
+        let rest = batch.take();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].round_id, 2);
+        assert!(batch.take().is_empty());
+        assert!(batch_event(Vec::new()).is_none());
+    }
+    
+    #[test]
+    fn test_slot_tick() {
+        let mut round = LiveRound {
+            round_id: 7,
+            start_slot: 1_000,
+            end_slot: u64::MAX,
+            slots_remaining: 0,
+            deployed: [0; 25],
+            count: [0; 25],
+            total_deployed: 0,
+            unique_miners: 0,
+        };
+        let tick = SlotTick::new(1_010, Some(&round));
+        assert_eq!((tick.round_id, tick.end_slot, tick.slots_left), (Some(7), None, None));
+        
+        round.end_slot = 1_150;
+        assert_eq!(SlotTick::new(1_100, Some(&round)).slots_left, Some(50));
+        assert_eq!(SlotTick::new(1_200, Some(&round)).slots_left, Some(0));
+        
+        let tick = SlotTick::new(5, None);
+        assert_eq!((tick.slot, tick.round_id, tick.slots_left), (5, None, None));
+    }
+    
+    #[test]
+    fn test_sse_config_from_vars() {
+        let config = SseConfig::from_vars(|_| None);
+        assert_eq!(config, SseConfig::default());
+        
+        let config = SseConfig::from_vars(|key| match key {
+            "SSE_CLIENT_BUFFER" => Some("4".to_string()),
+            "SSE_BACKPRESSURE" => Some("Disconnect".to_string()),
+            _ => None,
+        });
+        assert_eq!(config.client_buffer, MIN_CLIENT_BUFFER);
+        assert_eq!(config.policy, BackpressurePolicy::Disconnect);
+        
+        let config = SseConfig::from_vars(|key| (key == "SSE_BACKPRESSURE").then(|| "bogus".to_string()));
+        assert_eq!(config.policy, BackpressurePolicy::DropOldest);
+    }
+    
+    /// Receiver that has fallen 3 events behind a 2-event buffer
+    fn lagged_receiver() -> (broadcast::Sender<LiveBroadcastData>, broadcast::Receiver<LiveBroadcastData>) {
+        let (tx, rx) = broadcast::channel(2);
+        for slot in 0..5 {
+            tx.send(LiveBroadcastData::Deployment(deployment(1, slot))).unwrap();
+        }
+        (tx, rx)
+    }
+    
+    #[tokio::test]
+    async fn test_buffered_drop_oldest_sends_gap() {
+        let stats = Arc::new(SseStats::default());
+        let (tx, rx) = lagged_receiver();
+        drop(tx);
+        
+        let items: Vec<_> = buffered(BackpressurePolicy::DropOldest, stats.clone(), SseStream::Deployments, rx)
+            .collect()
+            .await;
+        assert!(matches!(items[0], Buffered::Gap(3)));
+        let slots: Vec<_> = items[1..].iter().map(|item| match item {
+            Buffered::Data(LiveBroadcastData::Deployment(d)) => d.slot,
+            other => panic!("unexpected {:?}", other),
+        }).collect();
+        assert_eq!(slots, vec![3, 4]);
+        
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.deployments, SseStreamStats { dropped_events: 3, disconnects: 0 });
+        assert_eq!(snapshot.rounds, SseStreamStats::default());
+    }
+    
+    #[tokio::test]
+    async fn test_buffered_disconnect_closes_stream() {
+        let stats = Arc::new(SseStats::default());
+        let (_tx, rx) = lagged_receiver();
+        
+        // Ends even though the sender is still open
+        let items: Vec<_> = buffered(BackpressurePolicy::Disconnect, stats.clone(), SseStream::DeploymentsNdjson, rx)
+            .collect()
+            .await;
+        assert!(items.is_empty());
+        assert_eq!(stats.snapshot().deployments_ndjson, SseStreamStats { dropped_events: 3, disconnects: 1 });
+    }
+}
@@ -1,24 +1,87 @@
 //! Background tasks for ore-stats
 //!
-//! - RPC polling (every 2 seconds) - Board, Treasury, Round
+//! - RPC polling (every 2 seconds by default) - Board, Treasury, Round
+//! - Optional periodic miners GPA refresh
 //! - Round transition detection and finalization
 //! - Metrics snapshots
 //! - EVORE accounts polling
 
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use tokio::time::interval;
 
 use crate::app_state::{AppState, LiveRound};
 use crate::finalization::{capture_round_snapshot, finalize_round};
 
+/// Default Board / Treasury / Round poll interval
+const DEFAULT_POLL_MS: u64 = 2_000;
+
+/// Lowest accepted Board / Round interval
+const MIN_BOARD_POLL_MS: u64 = 400;
+
+/// Lowest accepted Treasury interval
+const MIN_TREASURY_POLL_MS: u64 = 1_000;
+
+/// Lowest accepted miners GPA interval (a GPA over every miner is expensive)
+const MIN_MINERS_POLL_MS: u64 = 30_000;
+
+/// RPC poll intervals (`BOARD_POLL_MS`, `TREASURY_POLL_MS`, `ROUND_POLL_MS`, `MINERS_POLL_MS`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollIntervals {
+    pub board: Duration,
+    pub treasury: Duration,
+    pub round: Duration,
+    /// Periodic miners GPA refresh; None keeps the once-per-round snapshot only
+    pub miners: Option<Duration>,
+}
+
+impl PollIntervals {
+    /// Read intervals from the environment, raising values below the minimums
+    pub fn from_env() -> Self {
+        Self::from_vars(|key| std::env::var(key).ok())
+    }
+    
+    fn from_vars(get: impl Fn(&str) -> Option<String>) -> Self {
+        let ms = |key: &str, default: u64, min: u64| {
+            let value = get(key).and_then(|v| v.parse::<u64>().ok()).unwrap_or(default);
+            if value < min {
+                tracing::warn!("{}={} is below the minimum, using {}ms", key, value, min);
+            }
+            Duration::from_millis(value.max(min))
+        };
+        
+        Self {
+            board: ms("BOARD_POLL_MS", DEFAULT_POLL_MS, MIN_BOARD_POLL_MS),
+            treasury: ms("TREASURY_POLL_MS", DEFAULT_POLL_MS, MIN_TREASURY_POLL_MS),
+            round: ms("ROUND_POLL_MS", DEFAULT_POLL_MS, MIN_BOARD_POLL_MS),
+            // 0 / unset = disabled
+            miners: get("MINERS_POLL_MS")
+                .and_then(|v| v.parse::<u64>().ok())
+                .filter(|v| *v > 0)
+                .map(|_| ms("MINERS_POLL_MS", 0, MIN_MINERS_POLL_MS)),
+        }
+    }
+}
+
+/// Whether `every` has passed since `last`; records the time when it has
+fn is_due(last: &mut Option<Instant>, every: Duration) -> bool {
+    let now = Instant::now();
+    if last.map_or(true, |t| now.duration_since(t) >= every) {
+        *last = Some(now);
+        true
+    } else {
+        false
+    }
+}
+
 /// Spawn the RPC polling task
-/// Updates Board, Treasury, Round caches every 2 seconds
+/// Polls Board every `intervals.board` and Treasury / Round on their own
+/// intervals (a new round id from the Board always triggers a Round fetch)
 /// Also handles round transition detection, snapshot capture, and finalization
-pub fn spawn_rpc_polling(state: Arc<AppState>) -> tokio::task::JoinHandle<()> {
+pub fn spawn_rpc_polling(state: Arc<AppState>, intervals: PollIntervals) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
-        let mut ticker = interval(Duration::from_secs(2));
+        let mut ticker = interval(intervals.board);
         // Skip missed ticks during long operations like GPA snapshots
         // to avoid burst of RPC calls when the operation completes
         ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
@@ -26,6 +89,8 @@ pub fn spawn_rpc_polling(state: Arc<AppState>) -> tokio::task::JoinHandle<()> {
         let mut last_round_id: u64 = 0;
         let mut round_ending_detected = false;
         let mut initialized = false;
+        let mut last_treasury_poll = None;
+        let mut last_round_poll = None;
         
         loop {
             ticker.tick().await;
@@ -45,13 +110,15 @@ pub fn spawn_rpc_polling(state: Arc<AppState>) -> tokio::task::JoinHandle<()> {
             };
             
             // Fetch Treasury
-            match state.rpc.get_treasury().await {
-                Ok(treasury) => {
-                    let mut cache = state.treasury_cache.write().await;
-                    *cache = Some(treasury);
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to fetch treasury: {}", e);
+            if is_due(&mut last_treasury_poll, intervals.treasury) {
+                match state.rpc.get_treasury().await {
+                    Ok(treasury) => {
+                        let mut cache = state.treasury_cache.write().await;
+                        *cache = Some(treasury);
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to fetch treasury: {}", e);
+                    }
                 }
             }
             
@@ -71,6 +138,12 @@ pub fn spawn_rpc_polling(state: Arc<AppState>) -> tokio::task::JoinHandle<()> {
                     );
                 }
                 
+                let round_due = is_due(&mut last_round_poll, intervals.round);
+                let new_round = current_round_id != last_round_id;
+                if !round_due && !new_round {
+                    continue;
+                }
+                
                 match state.rpc.get_round(current_round_id).await {
                     Ok(round) => {
                         let current_slot = *state.slot_cache.read().await;
@@ -102,7 +175,12 @@ pub fn spawn_rpc_polling(state: Arc<AppState>) -> tokio::task::JoinHandle<()> {
                             cache = state.round_cache.write().await;
                         }
                         
+                        let changed = cache.as_ref().map_or(true, |r| r.round_id != live.round_id);
                         *cache = Some(live);
+                        drop(cache);
+                        if changed {
+                            state.round_changed.notify_one();
+                        }
                     }
                     Err(e) => {
                         tracing::warn!("Failed to fetch round {}: {}", current_round_id, e);
@@ -188,6 +266,28 @@ pub fn spawn_rpc_polling(state: Arc<AppState>) -> tokio::task::JoinHandle<()> {
     })
 }
 
+/// Spawn the periodic miners cache refresh (only when `MINERS_POLL_MS` is set)
+pub fn spawn_miners_polling(state: Arc<AppState>, every: Duration) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = interval(every);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        // The first tick fires immediately; startup already loaded the cache
+        ticker.tick().await;
+        
+        loop {
+            ticker.tick().await;
+            match state.rpc.get_all_miners_gpa(None).await {
+                Ok(miners) => {
+                    let count = miners.len();
+                    *state.miners_cache.write().await = miners.into_iter().collect();
+                    tracing::debug!("Miners cache refreshed: {} miners", count);
+                }
+                Err(e) => tracing::warn!("Failed to refresh miners cache: {}", e),
+            }
+        }
+    })
+}
+
 /// Spawn the metrics snapshot task
 /// Stores server metrics to ClickHouse periodically
 pub fn spawn_metrics_snapshot(state: Arc<AppState>) -> tokio::task::JoinHandle<()> {
@@ -277,3 +377,35 @@ pub fn spawn_cleanup_task(state: Arc<AppState>) -> tokio::task::JoinHandle<()> {
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn intervals(vars: &[(&str, &str)]) -> PollIntervals {
+        let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        PollIntervals::from_vars(|key| vars.get(key).cloned())
+    }
+
+    #[test]
+    fn test_poll_intervals_defaults_and_minimums() {
+        let defaults = intervals(&[]);
+        assert_eq!(defaults.board, Duration::from_millis(DEFAULT_POLL_MS));
+        assert_eq!(defaults.treasury, Duration::from_millis(DEFAULT_POLL_MS));
+        assert_eq!(defaults.miners, None);
+
+        let tuned = intervals(&[
+            ("BOARD_POLL_MS", "50"),
+            ("TREASURY_POLL_MS", "10000"),
+            ("ROUND_POLL_MS", "abc"),
+            ("MINERS_POLL_MS", "1000"),
+        ]);
+        assert_eq!(tuned.board, Duration::from_millis(MIN_BOARD_POLL_MS));
+        assert_eq!(tuned.treasury, Duration::from_secs(10));
+        assert_eq!(tuned.round, Duration::from_millis(DEFAULT_POLL_MS));
+        assert_eq!(tuned.miners, Some(Duration::from_millis(MIN_MINERS_POLL_MS)));
+
+        assert_eq!(intervals(&[("MINERS_POLL_MS", "0")]).miners, None);
+    }
+}
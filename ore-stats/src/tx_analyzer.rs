@@ -11,6 +11,8 @@
 //! - EVORE Program
 //! - Unknown programs (raw data display)
 
+use evore::board::mask_indices;
+use evore::instruction::{MMAutodeploy, MMDeploy};
 use evore::ore_api::{self, Deploy, OreInstruction};
 use regex::Regex;
 use serde::Serialize;
@@ -332,6 +334,32 @@ pub enum ParsedInstruction {
         accounts_count: usize,
     },
     
+    // EVORE Program
+    // MM* accounts all start with [signer, manager, ...]
+    EvoreMMDeploy {
+        signer: String,
+        manager: String,
+        auth_id: u64,
+        allow_multi_deploy: bool,
+        strategy: EvoreDeployStrategy,
+    },
+    // MMAutodeploy / MMFullAutodeploy
+    EvoreMMAutodeploy {
+        signer: String,
+        manager: String,
+        auth_id: u64,
+        amount_per_square: u64,
+        squares_mask: u32,
+        squares: Vec<u8>,
+        total_lamports: u64,
+    },
+    // MMCheckpoint / MMClaimSOL / MMClaimORE / MMAutocheckpoint / MMCreateMiner
+    EvoreMMAuth {
+        signer: String,
+        manager: String,
+        auth_id: u64,
+    },
+    
     // Token Program
     TokenTransfer {
         source: String,
@@ -396,6 +424,50 @@ pub enum ParsedInstruction {
     },
 }
 
+/// Strategy carried by an EVORE MMDeploy instruction
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EvoreDeployStrategy {
+    Ev {
+        bankroll: u64,
+        max_per_square: u64,
+        min_bet: u64,
+        ore_value: u64,
+        slots_left: u64,
+        attempts: u64,
+    },
+    Percentage {
+        bankroll: u64,
+        percentage: u64,
+        squares_count: u64,
+    },
+    Manual {
+        amounts: Vec<u64>,
+    },
+    Split {
+        amount: u64,
+    },
+    Unknown {
+        discriminant: u8,
+    },
+}
+
+impl From<evore::instruction::DeployStrategy> for EvoreDeployStrategy {
+    fn from(strategy: evore::instruction::DeployStrategy) -> Self {
+        use evore::instruction::DeployStrategy;
+        match strategy {
+            DeployStrategy::EV { bankroll, max_per_square, min_bet, ore_value, slots_left, attempts } => {
+                Self::Ev { bankroll, max_per_square, min_bet, ore_value, slots_left, attempts }
+            }
+            DeployStrategy::Percentage { bankroll, percentage, squares_count } => {
+                Self::Percentage { bankroll, percentage, squares_count }
+            }
+            DeployStrategy::Manual { amounts } => Self::Manual { amounts: amounts.to_vec() },
+            DeployStrategy::Split { amount } => Self::Split { amount },
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct OreTransactionAnalysis {
     pub has_ore_instructions: bool,
@@ -442,6 +514,66 @@ pub struct TransactionSummaryInfo {
     pub primary_action: String,
 }
 
+/// One ORE or EVORE instruction from a transaction, top-level or inner
+#[derive(Debug, Clone, Serialize)]
+pub struct DecodedInstruction {
+    /// Top-level instruction index (the parent for inner instructions)
+    pub index: usize,
+    /// Position within the parent's inner instructions, None for top-level
+    pub inner_index: Option<usize>,
+    pub program_id: String,
+    pub program_name: String,
+    pub instruction_type: String,
+    pub parsed: Option<ParsedInstruction>,
+    pub parse_error: Option<String>,
+}
+
+impl FullTransactionAnalysis {
+    /// ORE and EVORE instructions in execution order (each top-level
+    /// instruction followed by its inner instructions)
+    pub fn ore_evore_instructions(&self) -> Vec<DecodedInstruction> {
+        let ore_program_id = ore_api::PROGRAM_ID.to_string();
+        let wanted = |ix: &InstructionAnalysis| {
+            ix.program_id == ore_program_id || ix.program_id == EVORE_PROGRAM_ID
+        };
+        let decoded = |ix: &InstructionAnalysis, index: usize, inner_index: Option<usize>| DecodedInstruction {
+            index,
+            inner_index,
+            program_id: ix.program_id.clone(),
+            program_name: ix.program_name.clone(),
+            instruction_type: ix.instruction_type.clone(),
+            parsed: ix.parsed.clone(),
+            parse_error: ix.parse_error.clone(),
+        };
+        
+        let mut out = Vec::new();
+        for ix in &self.instructions {
+            if wanted(ix) {
+                out.push(decoded(ix, ix.index, None));
+            }
+            let inner = self.inner_instructions.iter()
+                .filter(|group| group.parent_index == ix.index)
+                .flat_map(|group| group.instructions.iter().enumerate());
+            for (inner_index, inner_ix) in inner {
+                if wanted(inner_ix) {
+                    out.push(decoded(inner_ix, ix.index, Some(inner_index)));
+                }
+            }
+        }
+        out
+    }
+    
+    /// Messages of the top-level Memo instructions, e.g. a bot's deploy attribution tag
+    pub fn memos(&self) -> Vec<String> {
+        self.instructions.iter()
+            .filter_map(|ix| match &ix.parsed {
+                Some(ParsedInstruction::Memo { message }) => Some(message.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
 // ============================================================================
 // Analyzer Implementation
 // ============================================================================
@@ -558,6 +690,8 @@ impl TransactionAnalyzer {
         
         // For versioned transactions (v0), append loaded addresses from lookup tables
         // These are in meta.loadedAddresses.writable and meta.loadedAddresses.readonly
+        let num_static_keys = account_keys.len();
+        let mut num_loaded_writable = 0;
         if let Some(loaded_addresses) = meta.get("loadedAddresses") {
             // Add writable addresses first (they come after static accounts)
             if let Some(writable) = loaded_addresses.get("writable").and_then(|w| w.as_array()) {
@@ -569,6 +703,7 @@ impl TransactionAnalyzer {
                     }
                 }
             }
+            num_loaded_writable = account_keys.len() - num_static_keys;
             // Then readonly addresses
             if let Some(readonly) = loaded_addresses.get("readonly").and_then(|r| r.as_array()) {
                 for key_val in readonly {
@@ -618,8 +753,11 @@ impl TransactionAnalyzer {
             let is_signer = i < num_required_signatures;
             let is_readonly = if is_signer {
                 i >= num_required_signatures - num_readonly_signed
+            } else if i < num_static_keys {
+                i >= num_static_keys - num_readonly_unsigned
             } else {
-                i >= account_keys.len() - num_readonly_unsigned
+                // Lookup table addresses: writable ones are loaded first
+                i >= num_static_keys + num_loaded_writable
             };
             let is_writable = !is_readonly;
             
@@ -1450,17 +1588,12 @@ impl TransactionAnalyzer {
                 let amount = u64::from_le_bytes(deploy.amount);
                 let mask = u32::from_le_bytes(deploy.squares);
                 
-                let mut squares = Vec::new();
-                for i in 0..25u8 {
-                    if (mask & (1 << i)) != 0 {
-                        squares.push(i);
-                    }
-                }
+                let squares: Vec<u8> = mask_indices(mask).map(|i| i as u8).collect();
                 
                 let total = amount * squares.len() as u64;
                 
-                // Deploy accounts: [0:signer, 1:authority, 2:automation, 3:board, 4:config, 5:miner, 6:round, ...]
-                let round_str = accounts.get(6).map(|a| a.pubkey.clone()).unwrap_or_default();
+                let layout = evore::ore_api::DEPLOY_ACCOUNTS;
+                let round_str = accounts.get(layout.round).map(|a| a.pubkey.clone()).unwrap_or_default();
                 let round_id = Pubkey::from_str(&round_str)
                     .ok()
                     .and_then(|pk| self.check_round_pda(&pk));
@@ -1476,12 +1609,12 @@ impl TransactionAnalyzer {
                 (
                     "Deploy".to_string(),
                     Some(ParsedInstruction::OreDeploy {
-                        signer: accounts.get(0).map(|a| a.pubkey.clone()).unwrap_or_default(),
-                        authority: accounts.get(1).map(|a| a.pubkey.clone()).unwrap_or_default(),
-                        automation_pda: accounts.get(2).map(|a| a.pubkey.clone()).unwrap_or_default(),
-                        board: accounts.get(3).map(|a| a.pubkey.clone()).unwrap_or_default(),
-                        config: accounts.get(4).map(|a| a.pubkey.clone()).unwrap_or_default(),
-                        miner: accounts.get(5).map(|a| a.pubkey.clone()).unwrap_or_default(),
+                        signer: accounts.get(layout.signer).map(|a| a.pubkey.clone()).unwrap_or_default(),
+                        authority: accounts.get(layout.authority).map(|a| a.pubkey.clone()).unwrap_or_default(),
+                        automation_pda: accounts.get(layout.automation).map(|a| a.pubkey.clone()).unwrap_or_default(),
+                        board: accounts.get(layout.board).map(|a| a.pubkey.clone()).unwrap_or_default(),
+                        config: accounts.get(layout.config).map(|a| a.pubkey.clone()).unwrap_or_default(),
+                        miner: accounts.get(layout.miner).map(|a| a.pubkey.clone()).unwrap_or_default(),
                         round: round_str,
                         round_id,
                         amount_per_square: amount,
@@ -1550,14 +1683,14 @@ impl TransactionAnalyzer {
                 )
             }
             Ok(OreInstruction::Automate) => {
-                // Automate accounts: [0:signer, 1:automation, 2:executor, 3:miner, 4:system_program]
+                let layout = evore::ore_api::AUTOMATE_ACCOUNTS;
                 (
                     "Automate".to_string(),
                     Some(ParsedInstruction::OreAutomate {
-                        signer: accounts.get(0).map(|a| a.pubkey.clone()).unwrap_or_default(),
-                        automation_pda: accounts.get(1).map(|a| a.pubkey.clone()).unwrap_or_default(),
-                        executor: accounts.get(2).map(|a| a.pubkey.clone()).unwrap_or_default(),
-                        miner: accounts.get(3).map(|a| a.pubkey.clone()).unwrap_or_default(),
+                        signer: accounts.get(layout.signer).map(|a| a.pubkey.clone()).unwrap_or_default(),
+                        automation_pda: accounts.get(layout.automation).map(|a| a.pubkey.clone()).unwrap_or_default(),
+                        executor: accounts.get(layout.executor).map(|a| a.pubkey.clone()).unwrap_or_default(),
+                        miner: accounts.get(layout.miner).map(|a| a.pubkey.clone()).unwrap_or_default(),
                     }),
                     None,
                 )
@@ -1702,7 +1835,7 @@ impl TransactionAnalyzer {
     fn parse_evore_instruction(
         &self,
         data: &[u8],
-        _accounts: &[InstructionAccount],
+        accounts: &[InstructionAccount],
     ) -> (String, Option<ParsedInstruction>, Option<String>) {
         if data.is_empty() {
             return ("Empty".to_string(), None, Some("Empty instruction data".to_string()));
@@ -1741,19 +1874,89 @@ impl TransactionAnalyzer {
             }
         };
         
-        // For known instructions, return the name with data preview
-        (
+        let account = |i: usize| accounts.get(i).map(|a| a.pubkey.clone()).unwrap_or_default();
+        let body = &data[1..];
+        let too_short = || (
             instruction_name.to_string(),
-            Some(ParsedInstruction::Unknown {
-                program: EVORE_PROGRAM_ID.to_string(),
-                data_preview: if data.len() > 32 {
-                    format!("{}...", hex::encode(&data[..32]))
-                } else {
-                    hex::encode(data)
-                },
-            }),
             None,
-        )
+            Some(format!("{} data too short", instruction_name)),
+        );
+        
+        match tag {
+            // MMDeploy
+            1 => {
+                let Some(deploy) = body.get(..std::mem::size_of::<MMDeploy>()) else {
+                    return too_short();
+                };
+                let deploy: &MMDeploy = bytemuck::from_bytes(deploy);
+                let strategy = deploy.get_strategy()
+                    .map(EvoreDeployStrategy::from)
+                    .unwrap_or(EvoreDeployStrategy::Unknown { discriminant: deploy.data[0] });
+                (
+                    instruction_name.to_string(),
+                    Some(ParsedInstruction::EvoreMMDeploy {
+                        signer: account(0),
+                        manager: account(1),
+                        auth_id: u64::from_le_bytes(deploy.auth_id),
+                        allow_multi_deploy: deploy.get_allow_multi_deploy(),
+                        strategy,
+                    }),
+                    None,
+                )
+            }
+            // MMAutodeploy / MMFullAutodeploy (same data layout)
+            7 | 12 => {
+                let Some(autodeploy) = body.get(..std::mem::size_of::<MMAutodeploy>()) else {
+                    return too_short();
+                };
+                let autodeploy: &MMAutodeploy = bytemuck::from_bytes(autodeploy);
+                let amount = u64::from_le_bytes(autodeploy.amount);
+                let mask = u32::from_le_bytes(autodeploy.squares_mask);
+                let squares: Vec<u8> = mask_indices(mask).map(|i| i as u8).collect();
+                (
+                    instruction_name.to_string(),
+                    Some(ParsedInstruction::EvoreMMAutodeploy {
+                        signer: account(0),
+                        manager: account(1),
+                        auth_id: u64::from_le_bytes(autodeploy.auth_id),
+                        amount_per_square: amount,
+                        squares_mask: mask,
+                        total_lamports: amount.saturating_mul(squares.len() as u64),
+                        squares,
+                    }),
+                    None,
+                )
+            }
+            // MMCheckpoint / MMClaimSOL / MMClaimORE / MMAutocheckpoint / MMCreateMiner
+            // all start with the auth_id
+            2 | 3 | 4 | 11 | 14 => {
+                let Some(auth_id) = body.get(..8) else {
+                    return too_short();
+                };
+                (
+                    instruction_name.to_string(),
+                    Some(ParsedInstruction::EvoreMMAuth {
+                        signer: account(0),
+                        manager: account(1),
+                        auth_id: u64::from_le_bytes(auth_id.try_into().unwrap()),
+                    }),
+                    None,
+                )
+            }
+            // Other known instructions: name with data preview
+            _ => (
+                instruction_name.to_string(),
+                Some(ParsedInstruction::Unknown {
+                    program: EVORE_PROGRAM_ID.to_string(),
+                    data_preview: if data.len() > 32 {
+                        format!("{}...", hex::encode(&data[..32]))
+                    } else {
+                        hex::encode(data)
+                    },
+                }),
+                None,
+            ),
+        }
     }
     
     /// Parse Entropy program instructions
@@ -2004,3 +2207,105 @@ impl Default for TransactionAnalyzer {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use evore::instruction::DeployStrategy;
+
+    #[test]
+    fn test_decodes_evore_mm_deploy_with_lookup_table() {
+        let signer = Pubkey::new_unique();
+        let manager = Pubkey::new_unique();
+        let looked_up = Pubkey::new_unique();
+        let strategy = DeployStrategy::Split { amount: 25_000 };
+        let mut data = vec![1u8];
+        data.extend_from_slice(bytemuck::bytes_of(&MMDeploy::new(7, 254, true, strategy)));
+
+        // v0 tx: manager comes from a lookup table (index 3, after the 3 static keys)
+        let tx = serde_json::json!({
+            "slot": 1,
+            "blockTime": 0,
+            "meta": {
+                "err": null,
+                "fee": 5000,
+                "logMessages": [],
+                "preBalances": [0, 0, 0, 0],
+                "postBalances": [0, 0, 0, 0],
+                "innerInstructions": [],
+                "loadedAddresses": { "writable": [manager.to_string()], "readonly": [] },
+            },
+            "transaction": {
+                "signatures": ["sig"],
+                "message": {
+                    "accountKeys": [signer.to_string(), looked_up.to_string(), EVORE_PROGRAM_ID],
+                    "header": {
+                        "numRequiredSignatures": 1,
+                        "numReadonlySignedAccounts": 0,
+                        "numReadonlyUnsignedAccounts": 1,
+                    },
+                    "instructions": [{
+                        "programIdIndex": 2,
+                        "accounts": [0, 3],
+                        "data": bs58::encode(&data).into_string(),
+                    }],
+                },
+            },
+        });
+
+        let analysis = TransactionAnalyzer::new().analyze_value(&tx).unwrap();
+        // Lookup-table writable address isn't mistaken for a readonly static key
+        assert!(analysis.all_accounts[3].is_writable);
+        assert!(!analysis.all_accounts[2].is_writable);
+
+        let decoded = analysis.ore_evore_instructions();
+        assert_eq!(decoded.len(), 1);
+        assert_eq!((decoded[0].index, decoded[0].inner_index), (0, None));
+        assert_eq!(decoded[0].instruction_type, "MMDeploy");
+        match &decoded[0].parsed {
+            Some(ParsedInstruction::EvoreMMDeploy { signer: s, manager: m, auth_id, allow_multi_deploy, strategy }) => {
+                assert_eq!((s.as_str(), m.as_str()), (signer.to_string().as_str(), manager.to_string().as_str()));
+                assert_eq!((*auth_id, *allow_multi_deploy), (7, true));
+                assert!(matches!(strategy, EvoreDeployStrategy::Split { amount: 25_000 }));
+            }
+            other => panic!("unexpected parse: {:?}", other),
+        }
+        assert!(analysis.memos().is_empty());
+    }
+
+    #[test]
+    fn test_surfaces_deploy_memo() {
+        let signer = Pubkey::new_unique();
+        let tx = serde_json::json!({
+            "slot": 1,
+            "blockTime": 0,
+            "meta": {
+                "err": null,
+                "fee": 5000,
+                "logMessages": [],
+                "preBalances": [0, 0],
+                "postBalances": [0, 0],
+                "innerInstructions": [],
+            },
+            "transaction": {
+                "signatures": ["sig"],
+                "message": {
+                    "accountKeys": [signer.to_string(), MEMO_PROGRAM_ID],
+                    "header": {
+                        "numRequiredSignatures": 1,
+                        "numReadonlySignedAccounts": 0,
+                        "numReadonlyUnsignedAccounts": 1,
+                    },
+                    "instructions": [{
+                        "programIdIndex": 1,
+                        "accounts": [],
+                        "data": bs58::encode(b"strategy-a").into_string(),
+                    }],
+                },
+            },
+        });
+
+        let analysis = TransactionAnalyzer::new().analyze_value(&tx).unwrap();
+        assert_eq!(analysis.memos(), vec!["strategy-a".to_string()]);
+    }
+}
@@ -3,6 +3,7 @@
 //! Provides:
 //! - Slot subscription for live slot tracking
 //! - Account subscriptions for SSE broadcasting
+//! - `ReconnectBackoff`, the jittered exponential delay between reconnects
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -10,13 +11,149 @@ use std::time::Duration;
 
 use anyhow::Result;
 use futures_util::StreamExt;
+use evore::ore_api::OreAccount;
 use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
 use solana_sdk::commitment_config::CommitmentConfig;
 use tokio::sync::RwLock;
 use tokio::time::{interval, Instant};
 
-use crate::app_state::{AppState, LiveBroadcastData, LiveDeployment};
+use crate::app_state::{next_board_update, AppState, LiveBroadcastData, LiveDeployment, LiveRound};
 use crate::clickhouse::{ClickHouseClient, WsEventInsert, WsThroughputInsert};
+use crate::readiness::SubscriptionStatus;
+
+/// First reconnect delay
+const RECONNECT_BASE_DELAY: Duration = Duration::from_secs(1);
+/// Longest reconnect delay
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(60);
+/// A connection that stayed up this long resets the backoff
+const RECONNECT_STABLE_AFTER: Duration = Duration::from_secs(60);
+
+/// Delay between subscription reconnects
+///
+/// Doubles from `RECONNECT_BASE_DELAY` up to `RECONNECT_MAX_DELAY` on each
+/// consecutive failure, and each delay is drawn from its upper half so
+/// instances restarted together don't reconnect in lockstep.
+#[derive(Debug, Default)]
+pub struct ReconnectBackoff {
+    failures: u32,
+}
+
+impl ReconnectBackoff {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Delay before reconnecting after a connection that lasted `uptime`
+    pub fn next_delay(&mut self, uptime: Duration) -> Duration {
+        self.delay_with(uptime, rand::random::<f64>())
+    }
+    
+    /// `next_delay` with the jitter fraction (0.0 - 1.0) supplied
+    fn delay_with(&mut self, uptime: Duration, jitter: f64) -> Duration {
+        if uptime >= RECONNECT_STABLE_AFTER {
+            self.failures = 0;
+        }
+        let cap = RECONNECT_BASE_DELAY
+            .saturating_mul(1u32 << self.failures.min(16))
+            .min(RECONNECT_MAX_DELAY);
+        self.failures = self.failures.saturating_add(1);
+        cap.mul_f64(0.5 + 0.5 * jitter.clamp(0.0, 1.0))
+    }
+}
+
+/// ORE account types the program subscription receives (`PROGRAM_SUB_ACCOUNTS`)
+///
+/// A comma-separated list of `round`, `miner`, `board`, `treasury`,
+/// `automation`, `stake` and `config`. Each type gets its own subscription
+/// filtered by data size and discriminator, so the RPC only sends those
+/// accounts. `all` subscribes once without filters. Defaults to `round,miner`,
+/// the only types the SSE deployment feed decodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramSubFilters {
+    /// Empty = a single unfiltered subscription
+    pub accounts: Vec<OreAccount>,
+}
+
+impl Default for ProgramSubFilters {
+    fn default() -> Self {
+        Self { accounts: vec![OreAccount::Round, OreAccount::Miner] }
+    }
+}
+
+impl ProgramSubFilters {
+    pub fn from_env() -> Self {
+        match std::env::var("PROGRAM_SUB_ACCOUNTS") {
+            Ok(value) => Self::parse(&value).unwrap_or_else(|e| {
+                tracing::warn!("PROGRAM_SUB_ACCOUNTS: {}, using round,miner", e);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+    
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut accounts = Vec::new();
+        for name in value.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let account = match name.to_ascii_lowercase().as_str() {
+                "all" => return Ok(Self { accounts: Vec::new() }),
+                "round" => OreAccount::Round,
+                "miner" => OreAccount::Miner,
+                "board" => OreAccount::Board,
+                "treasury" => OreAccount::Treasury,
+                "automation" => OreAccount::Automation,
+                "stake" => OreAccount::Stake,
+                "config" => OreAccount::Config,
+                other => return Err(format!("unknown account type '{}'", other)),
+            };
+            if !accounts.contains(&account) {
+                accounts.push(account);
+            }
+        }
+        if accounts.is_empty() {
+            return Err("no account types".to_string());
+        }
+        Ok(Self { accounts })
+    }
+    
+    /// Filters for each subscription to open (one `None` when unfiltered)
+    pub fn subscriptions(&self) -> Vec<Option<Vec<RpcFilterType>>> {
+        if self.accounts.is_empty() {
+            return vec![None];
+        }
+        self.accounts.iter()
+            .map(|account| Some(vec![
+                RpcFilterType::DataSize(8 + account_size(*account) as u64),
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, vec![*account as u8])),
+            ]))
+            .collect()
+    }
+    
+    /// Names for logs, e.g. "round,miner"
+    pub fn describe(&self) -> String {
+        if self.accounts.is_empty() {
+            return "all".to_string();
+        }
+        self.accounts.iter()
+            .map(|a| format!("{:?}", a).to_lowercase())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Size of an ORE account's data, without the 8-byte discriminator
+fn account_size(account: OreAccount) -> usize {
+    use evore::ore_api::{Automation, Board, Config, Miner, Round, Stake, Treasury};
+    match account {
+        OreAccount::Automation => std::mem::size_of::<Automation>(),
+        OreAccount::Config => std::mem::size_of::<Config>(),
+        OreAccount::Miner => std::mem::size_of::<Miner>(),
+        OreAccount::Treasury => std::mem::size_of::<Treasury>(),
+        OreAccount::Board => std::mem::size_of::<Board>(),
+        OreAccount::Stake => std::mem::size_of::<Stake>(),
+        OreAccount::Round => std::mem::size_of::<Round>(),
+    }
+}
 
 /// WebSocket manager for all subscriptions
 pub struct WebSocketManager {
@@ -79,6 +216,7 @@ impl WebSocketManager {
     pub fn spawn_slot_subscription(
         &self,
         slot_cache: Arc<RwLock<u64>>,
+        subscriptions: Arc<SubscriptionStatus>,
     ) -> tokio::task::JoinHandle<()> {
         let ws_url = self.ws_url.clone();
         let clickhouse = self.clickhouse.clone();
@@ -86,6 +224,7 @@ impl WebSocketManager {
         
         tokio::spawn(async move {
             let mut reconnect_count: u16 = 0;
+            let mut backoff = ReconnectBackoff::new();
             
             loop {
                 tracing::info!("Connecting to slot subscription at {}", ws_url);
@@ -97,29 +236,36 @@ impl WebSocketManager {
                 let messages_received = Arc::new(AtomicU64::new(0));
                 let messages_ref = messages_received.clone();
                 
-                match subscribe_to_slot_with_metrics(&ws_url, slot_cache.clone(), messages_ref, &clickhouse, &provider_name).await {
+                let result = subscribe_to_slot_with_metrics(&ws_url, slot_cache.clone(), messages_ref, &subscriptions, &clickhouse, &provider_name).await;
+                subscriptions.set_slot(false);
+                match result {
                     Ok(_) => {
                         let uptime = start_time.elapsed().as_secs() as u32;
                         let msgs = messages_received.load(Ordering::Relaxed);
                         log_ws_event_async(&clickhouse, &provider_name, "slot", "", "disconnected", "", "stream_ended", uptime, msgs, reconnect_count);
-                        tracing::warn!("Slot subscription ended unexpectedly, reconnecting...");
+                        tracing::warn!("Slot subscription ended unexpectedly");
                     }
                     Err(e) => {
                         let uptime = start_time.elapsed().as_secs() as u32;
                         let msgs = messages_received.load(Ordering::Relaxed);
                         log_ws_event_async(&clickhouse, &provider_name, "slot", "", "error", &e.to_string(), "error", uptime, msgs, reconnect_count);
-                        tracing::error!("Slot subscription error: {}, reconnecting in 5s...", e);
+                        tracing::error!("Slot subscription error: {}", e);
                     }
                 }
                 
                 reconnect_count = reconnect_count.saturating_add(1);
-                tokio::time::sleep(Duration::from_secs(5)).await;
+                let delay = backoff.next_delay(start_time.elapsed());
+                tracing::info!("Reconnecting slot subscription in {:.1}s", delay.as_secs_f64());
+                tokio::time::sleep(delay).await;
             }
         })
     }
     
     /// Start the round broadcast task
-    /// Sends round updates to SSE clients at a throttled rate
+    /// Sends round updates to SSE clients at a throttled rate, followed by
+    /// the board diff since the previous tick (a snapshot on a new round).
+    /// A round transition is sent as soon as the round cache has it, and the
+    /// cadence restarts from there.
     pub fn spawn_round_broadcaster(
         &self,
         state: Arc<AppState>,
@@ -128,23 +274,38 @@ impl WebSocketManager {
             let mut interval = interval(Duration::from_millis(500));
             
             loop {
-                interval.tick().await;
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    _ = state.round_changed.notified() => interval.reset(),
+                }
                 
-                // Read current round and slot
-                let round_opt = state.round_cache.read().await.clone();
-                let current_slot = *state.slot_cache.read().await;
+                let Some(round) = current_live_round(&state).await else {
+                    continue;
+                };
                 
-                if let Some(mut round) = round_opt {
-                    round.update_slots_remaining(current_slot);
-                    
-                    // Broadcast to SSE subscribers
-                    let _ = state.round_broadcast.send(LiveBroadcastData::Round(round));
+                // Board diff (or snapshot on a new round) for /sse/board clients
+                let board_update = {
+                    let mut last = state.last_board_broadcast.write().await;
+                    next_board_update(&mut last, round.round_id, round.deployed)
+                };
+                
+                // Broadcast to SSE subscribers
+                let _ = state.round_broadcast.send(LiveBroadcastData::Round(round));
+                if let Some(update) = board_update {
+                    let _ = state.round_broadcast.send(update);
                 }
             }
         })
     }
 }
 
+/// Cached round with slots remaining as of the current slot
+pub async fn current_live_round(state: &AppState) -> Option<LiveRound> {
+    let mut round = state.round_cache.read().await.clone()?;
+    round.update_slots_remaining(*state.slot_cache.read().await);
+    Some(round)
+}
+
 /// Helper to log WS events from async contexts
 fn log_ws_event_async(
     clickhouse: &Option<Arc<ClickHouseClient>>,
@@ -203,12 +364,14 @@ async fn subscribe_to_slot_with_metrics(
     ws_url: &str,
     slot_cache: Arc<RwLock<u64>>,
     messages_received: Arc<AtomicU64>,
+    subscriptions: &SubscriptionStatus,
     clickhouse: &Option<Arc<ClickHouseClient>>,
     provider_name: &str,
 ) -> Result<()> {
     let client = PubsubClient::new(ws_url).await?;
     
     let (mut stream, _unsub) = client.slot_subscribe().await?;
+    subscriptions.set_slot(true);
     
     // Log connected event
     log_ws_event_async(clickhouse, provider_name, "slot", "", "connected", "", "", 0, 0, 0);
@@ -237,6 +400,7 @@ pub async fn subscribe_to_program_accounts(
     rpc_url: &str,
     state: Arc<AppState>,
     clickhouse: Option<Arc<ClickHouseClient>>,
+    filters: &ProgramSubFilters,
 ) -> Result<()> {
     use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
     use evore::ore_api::{Miner, Round, id as ore_program_id};
@@ -265,25 +429,33 @@ pub async fn subscribe_to_program_accounts(
     
     let client = PubsubClient::new(&ws_url).await?;
     
-    let config = RpcProgramAccountsConfig {
-        filters: None,
-        account_config: RpcAccountInfoConfig {
-            encoding: Some(UiAccountEncoding::Base64),
-            data_slice: None,
-            commitment: Some(CommitmentConfig::confirmed()),
-            min_context_slot: None,
-        },
-        with_context: Some(true),
-        sort_results: None,
-    };
-    
-    let (mut stream, _unsub) = client
-        .program_subscribe(&ore_program_id(), Some(config))
-        .await?;
+    // One subscription per filtered account type, merged into one stream
+    let mut streams = Vec::new();
+    let mut unsubscribes = Vec::new();
+    for filter in filters.subscriptions() {
+        let config = RpcProgramAccountsConfig {
+            filters: filter,
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: None,
+                commitment: Some(CommitmentConfig::confirmed()),
+                min_context_slot: None,
+            },
+            with_context: Some(true),
+            sort_results: None,
+        };
+        let (stream, unsub) = client
+            .program_subscribe(&ore_program_id(), Some(config))
+            .await?;
+        streams.push(stream);
+        unsubscribes.push(unsub);
+    }
+    let mut stream = futures_util::stream::select_all(streams);
     
     log_ws_event_async(&clickhouse, &provider_name, "program", &evore::ore_api::PROGRAM_ID.to_string(), 
                        "connected", "", "", 0, 0, 0);
-    tracing::info!("ORE program subscription established");
+    state.subscriptions.set_program(true);
+    tracing::info!("ORE program subscription established (accounts: {})", filters.describe());
     
     // Wait for RPC polling to initialize pending_round_id (max 30 seconds)
     // This ensures we don't miss deployments due to race condition at startup
@@ -315,6 +487,10 @@ pub async fn subscribe_to_program_accounts(
     throughput_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
     let mut last_message_count: u64 = 0;
     let throughput_subscription = "program".to_string();
+    // Notifications used by the SSE feed vs ignored (other account types, old
+    // rounds, miners outside the current round, undecodable data)
+    let mut processed: u64 = 0;
+    let mut ignored: u64 = 0;
     
     // Sync interval (every 5 seconds) - ensures we stay in sync with RPC polling
     let mut sync_interval = tokio::time::interval(Duration::from_secs(5));
@@ -332,10 +508,16 @@ pub async fn subscribe_to_program_accounts(
             solana_account_decoder_client_types::UiAccountData::Binary(b64, _) => {
                 match BASE64.decode(b64) {
                     Ok(bytes) => bytes,
-                    Err(_) => continue,
+                    Err(_) => {
+                        ignored += 1;
+                        continue;
+                    }
                 }
             }
-            _ => continue,
+            _ => {
+                ignored += 1;
+                continue;
+            }
         };
         
         // Try to parse as Round
@@ -343,6 +525,7 @@ pub async fn subscribe_to_program_accounts(
             // Only process if this is a NEW round (id > current)
             // Ignore old rounds from checkpoints
             if round.id > current_round_id {
+                processed += 1;
                 tracing::info!("WebSocket: New round detected: {} -> {}", current_round_id, round.id);
                 current_round_id = round.id;
                 
@@ -350,6 +533,8 @@ pub async fn subscribe_to_program_accounts(
                 // These are the ONLY caches WebSocket is allowed to update
                 *state.pending_round_id.write().await = round.id;
                 state.pending_deployments.write().await.clear();
+            } else {
+                ignored += 1;
             }
             // NOTE: We do NOT update round_cache here - that's done by RPC polling only
             // WebSocket data can be unreliable, so RPC is the source of truth for caches
@@ -358,6 +543,7 @@ pub async fn subscribe_to_program_accounts(
         else if let Ok(miner) = Miner::try_from_bytes(&data) {
             // Only process if this miner deployed in the current round
             if miner.round_id == current_round_id && current_round_id > 0 {
+                processed += 1;
                 let miner_pubkey = miner.authority.to_string();
                 
                 // Get pending deployments for this miner
@@ -404,9 +590,13 @@ pub async fn subscribe_to_program_accounts(
                 }
                 
                 drop(pending);
+            } else {
+                ignored += 1;
             }
             // NOTE: We do NOT update miners_cache here - that's done by RPC polling only
             // WebSocket data can be unreliable, so RPC is the source of truth for caches
+        } else {
+            ignored += 1;
         }
             }
             _ = sync_interval.tick() => {
@@ -453,6 +643,10 @@ pub async fn subscribe_to_program_accounts(
                     "WS throughput: {} msgs in 10s window, avg {:.2} msg/s",
                     messages_in_window, avg_rate
                 );
+                tracing::info!(
+                    "Program subscription ({}): {} processed, {} ignored of {} notifications",
+                    filters.describe(), processed, ignored, current_count
+                );
             }
             else => {
                 // Stream closed
@@ -479,5 +673,44 @@ mod tests {
         let manager = WebSocketManager::new("rpc.helius.xyz".to_string());
         assert!(manager.ws_url.starts_with("wss://"));
     }
+    
+    #[test]
+    fn test_program_sub_filters() {
+        let filters = ProgramSubFilters::parse("Miner, round,miner").unwrap();
+        assert_eq!(filters.accounts, vec![OreAccount::Miner, OreAccount::Round]);
+        assert_eq!(filters.describe(), "miner,round");
+        
+        let subscriptions = filters.subscriptions();
+        assert_eq!(subscriptions.len(), 2);
+        let miner = subscriptions[0].as_ref().unwrap();
+        assert_eq!(miner[0], RpcFilterType::DataSize(8 + std::mem::size_of::<evore::ore_api::Miner>() as u64));
+        assert_eq!(miner[1], RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, vec![OreAccount::Miner as u8])));
+        
+        assert_eq!(ProgramSubFilters::parse("round,all").unwrap().subscriptions(), vec![None]);
+        assert_eq!(ProgramSubFilters::default().describe(), "round,miner");
+        assert!(ProgramSubFilters::parse("round,bogus").is_err());
+        assert!(ProgramSubFilters::parse(" , ").is_err());
+    }
+    
+    #[test]
+    fn test_reconnect_backoff() {
+        let mut backoff = ReconnectBackoff::new();
+        let quick = Duration::from_secs(1);
+        
+        // Full jitter draws the cap, zero jitter half of it
+        assert_eq!(backoff.delay_with(quick, 1.0), Duration::from_secs(1));
+        assert_eq!(backoff.delay_with(quick, 1.0), Duration::from_secs(2));
+        assert_eq!(backoff.delay_with(quick, 0.0), Duration::from_secs(2));
+        for _ in 0..20 {
+            backoff.delay_with(quick, 1.0);
+        }
+        assert_eq!(backoff.delay_with(quick, 1.0), RECONNECT_MAX_DELAY);
+        
+        // A sustained connection starts over
+        assert_eq!(backoff.delay_with(RECONNECT_STABLE_AFTER, 1.0), RECONNECT_BASE_DELAY);
+        
+        let delay = backoff.next_delay(quick);
+        assert!(delay >= Duration::from_secs(1) && delay <= Duration::from_secs(2));
+    }
 }
 
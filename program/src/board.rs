@@ -0,0 +1,162 @@
+//! Typed view of the 25-square ORE board for off-chain clients.
+//!
+//! Per-square amounts (a round's deployments, a bot's bets, win counts) are
+//! `[u64; 25]` on chain. `Board25` wraps that array with the handful of
+//! operations clients keep rewriting: totals, shares, the largest square and
+//! conversion to and from the square bitmask used by deploy instructions.
+
+use std::ops::{Index, IndexMut};
+
+use serde::{Deserialize, Serialize};
+
+/// Number of squares on the board
+pub const SQUARES: usize = 25;
+
+/// Mask with every square selected
+pub const ALL_SQUARES_MASK: u32 = (1 << SQUARES) - 1;
+
+/// Per-square amounts for one board
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Board25(pub [u64; SQUARES]);
+
+impl Board25 {
+    pub const fn new(amounts: [u64; SQUARES]) -> Self {
+        Self(amounts)
+    }
+
+    /// `amount` on every square selected in `mask` (bits above 24 are ignored)
+    pub fn from_mask(mask: u32, amount: u64) -> Self {
+        let mut board = Self::default();
+        for i in mask_indices(mask) {
+            board.0[i] = amount;
+        }
+        board
+    }
+
+    /// Mask of the squares with a non-zero amount
+    pub fn to_mask(&self) -> u32 {
+        self.squares().fold(0, |mask, i| mask | (1 << i))
+    }
+
+    /// Sum of all squares (saturating)
+    pub fn total(&self) -> u64 {
+        self.0.iter().fold(0u64, |total, amount| total.saturating_add(*amount))
+    }
+
+    /// Fraction of the total on square `i` (0.0 for an empty board or an out-of-range square)
+    pub fn share(&self, i: usize) -> f64 {
+        let total = self.total();
+        match self.0.get(i) {
+            Some(amount) if total > 0 => *amount as f64 / total as f64,
+            _ => 0.0,
+        }
+    }
+
+    /// Square with the largest amount (lowest index on ties), None if the board is empty
+    pub fn max_square(&self) -> Option<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, amount)| **amount > 0)
+            .fold(None, |best: Option<(usize, u64)>, (i, amount)| match best {
+                Some((_, max)) if max >= *amount => best,
+                _ => Some((i, *amount)),
+            })
+            .map(|(i, _)| i)
+    }
+
+    /// Indices of the squares with a non-zero amount, ascending
+    pub fn squares(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().enumerate().filter(|(_, amount)| **amount > 0).map(|(i, _)| i)
+    }
+
+    /// Number of squares with a non-zero amount
+    pub fn count(&self) -> usize {
+        self.squares().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|amount| *amount == 0)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, u64> {
+        self.0.iter()
+    }
+
+    /// `(square, amount)` for every square, including empty ones
+    pub fn enumerate(&self) -> impl Iterator<Item = (usize, u64)> + '_ {
+        self.0.iter().copied().enumerate()
+    }
+
+    pub fn as_array(&self) -> &[u64; SQUARES] {
+        &self.0
+    }
+}
+
+impl From<[u64; SQUARES]> for Board25 {
+    fn from(amounts: [u64; SQUARES]) -> Self {
+        Self(amounts)
+    }
+}
+
+impl From<Board25> for [u64; SQUARES] {
+    fn from(board: Board25) -> Self {
+        board.0
+    }
+}
+
+impl Index<usize> for Board25 {
+    type Output = u64;
+
+    fn index(&self, i: usize) -> &u64 {
+        &self.0[i]
+    }
+}
+
+impl IndexMut<usize> for Board25 {
+    fn index_mut(&mut self, i: usize) -> &mut u64 {
+        &mut self.0[i]
+    }
+}
+
+impl<'a> IntoIterator for &'a Board25 {
+    type Item = &'a u64;
+    type IntoIter = std::slice::Iter<'a, u64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl IntoIterator for Board25 {
+    type Item = u64;
+    type IntoIter = std::array::IntoIter<u64, SQUARES>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Indices of the squares selected in `mask`, ascending (bits above 24 are ignored)
+pub fn mask_indices(mask: u32) -> impl Iterator<Item = usize> {
+    (0..SQUARES).filter(move |i| mask & (1 << i) != 0)
+}
+
+/// Selected-square flags for `mask`, as taken by the ORE deploy instruction
+pub fn mask_to_squares(mask: u32) -> [bool; SQUARES] {
+    let mut squares = [false; SQUARES];
+    for i in mask_indices(mask) {
+        squares[i] = true;
+    }
+    squares
+}
+
+/// Mask for selected-square flags
+pub fn squares_to_mask(squares: &[bool; SQUARES]) -> u32 {
+    squares
+        .iter()
+        .enumerate()
+        .filter(|(_, selected)| **selected)
+        .fold(0, |mask, (i, _)| mask | (1 << i))
+}
@@ -13,6 +13,8 @@ pub mod state;
 pub mod consts;
 pub mod ore_api;
 pub mod entropy_api;
+pub mod network;
+pub mod board;
 
 declare_id!("8jaLKWLJAj5jVCZbxpe3zRUvLB3LD48MRtaQ2AjfCfxa");
 
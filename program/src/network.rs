@@ -0,0 +1,199 @@
+//! Network selection for off-chain clients (bot, crank, ore-stats).
+//!
+//! The program itself always uses the compiled-in ids. Clients derive every
+//! ORE/Evore address through a `NetworkConfig` so the same binaries can point
+//! at devnet (or a local validator) without a rebuild.
+
+use std::fmt;
+use std::str::FromStr;
+
+use steel::Pubkey;
+
+use crate::consts::{DEPLOYER, FEE_COLLECTOR, MANAGED_MINER_AUTH};
+use crate::entropy_api;
+use crate::ore_api::{Config, AUTOMATION, BOARD, CONFIG, MINER, ROUND, STAKE, TREASURY};
+
+/// Known clusters with preset addresses
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Network {
+    #[default]
+    Mainnet,
+    Devnet,
+}
+
+impl Network {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Network::Mainnet => "mainnet",
+            Network::Devnet => "devnet",
+        }
+    }
+
+    /// Public RPC endpoint for the cluster (clients usually override this)
+    pub fn default_rpc_url(&self) -> &'static str {
+        match self {
+            Network::Mainnet => "https://api.mainnet-beta.solana.com",
+            Network::Devnet => "https://api.devnet.solana.com",
+        }
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Network {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "mainnet" | "mainnet-beta" => Ok(Network::Mainnet),
+            "devnet" => Ok(Network::Devnet),
+            other => Err(format!("unknown network '{}' (expected mainnet or devnet)", other)),
+        }
+    }
+}
+
+/// Program ids and mint for one cluster, plus the PDAs derived from them
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NetworkConfig {
+    pub network: Network,
+    pub ore_program_id: Pubkey,
+    pub evore_program_id: Pubkey,
+    pub ore_mint: Pubkey,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self::mainnet()
+    }
+}
+
+impl NetworkConfig {
+    pub fn mainnet() -> Self {
+        Self {
+            network: Network::Mainnet,
+            ore_program_id: crate::ore_api::PROGRAM_ID,
+            evore_program_id: crate::ID,
+            ore_mint: crate::ore_api::MINT_ADDRESS,
+        }
+    }
+
+    /// Devnet preset. Reuses the mainnet addresses, so a devnet deployment at
+    /// different ids needs `with_overrides`.
+    pub fn devnet() -> Self {
+        Self {
+            network: Network::Devnet,
+            ..Self::mainnet()
+        }
+    }
+
+    pub fn for_network(network: Network) -> Self {
+        match network {
+            Network::Mainnet => Self::mainnet(),
+            Network::Devnet => Self::devnet(),
+        }
+    }
+
+    /// Apply explicit address overrides on top of the preset
+    pub fn with_overrides(
+        mut self,
+        ore_program_id: Option<Pubkey>,
+        evore_program_id: Option<Pubkey>,
+        ore_mint: Option<Pubkey>,
+    ) -> Self {
+        if let Some(id) = ore_program_id {
+            self.ore_program_id = id;
+        }
+        if let Some(id) = evore_program_id {
+            self.evore_program_id = id;
+        }
+        if let Some(mint) = ore_mint {
+            self.ore_mint = mint;
+        }
+        self
+    }
+
+    /// True when the program ids match the ones compiled into the instruction
+    /// builders, i.e. transactions built by this crate will target this network
+    pub fn uses_compiled_program_ids(&self) -> bool {
+        self.ore_program_id == crate::ore_api::PROGRAM_ID && self.evore_program_id == crate::ID
+    }
+
+    // ========== ORE PDAs ==========
+
+    pub fn board_pda(&self) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[BOARD], &self.ore_program_id)
+    }
+
+    pub fn config_pda(&self) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[CONFIG], &self.ore_program_id)
+    }
+
+    pub fn miner_pda(&self, authority: Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[MINER, &authority.to_bytes()], &self.ore_program_id)
+    }
+
+    pub fn round_pda(&self, id: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[ROUND, &id.to_le_bytes()], &self.ore_program_id)
+    }
+
+    pub fn automation_pda(&self, authority: Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[AUTOMATION, &authority.to_bytes()], &self.ore_program_id)
+    }
+
+    pub fn treasury_pda(&self) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[TREASURY], &self.ore_program_id)
+    }
+
+    pub fn stake_pda(&self, authority: Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[STAKE, &authority.to_bytes()], &self.ore_program_id)
+    }
+
+    pub fn treasury_tokens_address(&self) -> Pubkey {
+        spl_associated_token_account::get_associated_token_address(&self.treasury_pda().0, &self.ore_mint)
+    }
+
+    /// Entropy var the deploy builders pass (var 0 of the board)
+    pub fn entropy_var_address(&self) -> Pubkey {
+        entropy_api::var_pda(self.board_pda().0, 0).0
+    }
+
+    // ========== Evore fees ==========
+
+    /// Destination of the Evore deploy fee. The program only accepts the
+    /// compiled-in FEE_COLLECTOR, so this can't be redirected per network.
+    pub fn fee_collector(&self) -> Pubkey {
+        FEE_COLLECTOR
+    }
+
+    /// Check the static accounts the deploy builders derive against ORE's
+    /// on-chain Config, so a moved account fails at startup instead of on
+    /// every deploy. ORE's `Config.fee_collector` receives ORE admin fees and
+    /// is unrelated to the Evore fee collector, so it isn't compared.
+    pub fn check_ore_config(&self, config: &Config) -> Result<(), String> {
+        let entropy_var = self.entropy_var_address();
+        if config.var_address != entropy_var {
+            return Err(format!(
+                "ORE config entropy var {} does not match the derived {} - deploys would fail",
+                config.var_address, entropy_var
+            ));
+        }
+        Ok(())
+    }
+
+    // ========== Evore PDAs ==========
+
+    pub fn managed_miner_auth_pda(&self, manager: Pubkey, auth_id: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[MANAGED_MINER_AUTH, &manager.to_bytes(), &auth_id.to_le_bytes()],
+            &self.evore_program_id,
+        )
+    }
+
+    pub fn deployer_pda(&self, manager: Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[DEPLOYER, &manager.to_bytes()], &self.evore_program_id)
+    }
+}
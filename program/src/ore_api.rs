@@ -482,6 +482,66 @@ impl Stake {
 
 account!(OreAccount, Stake);
 
+/// Version of the ORE instruction account layouts below.
+/// Bump this (and the layouts) if the ORE program reorders instruction accounts.
+pub const ORE_IX_LAYOUT_VERSION: u8 = 1;
+
+/// Account indices of the ORE Deploy instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeployAccountLayout {
+    pub signer: usize,
+    pub authority: usize,
+    pub automation: usize,
+    pub board: usize,
+    pub config: usize,
+    pub miner: usize,
+    pub round: usize,
+    pub system_program: usize,
+    pub ore_program: usize,
+    pub entropy_var: usize,
+    pub entropy_program: usize,
+    /// Total number of accounts
+    pub len: usize,
+}
+
+/// Current Deploy account layout (see `ORE_IX_LAYOUT_VERSION`).
+pub const DEPLOY_ACCOUNTS: DeployAccountLayout = DeployAccountLayout {
+    signer: 0,
+    authority: 1,
+    automation: 2,
+    board: 3,
+    config: 4,
+    miner: 5,
+    round: 6,
+    system_program: 7,
+    ore_program: 8,
+    entropy_var: 9,
+    entropy_program: 10,
+    len: 11,
+};
+
+/// Account indices of the ORE Automate instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AutomateAccountLayout {
+    pub signer: usize,
+    pub automation: usize,
+    pub executor: usize,
+    pub miner: usize,
+    pub system_program: usize,
+    /// Total number of accounts
+    pub len: usize,
+}
+
+/// Current Automate account layout (see `ORE_IX_LAYOUT_VERSION`).
+pub const AUTOMATE_ACCOUNTS: AutomateAccountLayout = AutomateAccountLayout {
+    signer: 0,
+    automation: 1,
+    executor: 2,
+    miner: 3,
+    system_program: 4,
+    len: 5,
+};
+
 pub fn deploy(
     signer: Pubkey,
     authority: Pubkey,
@@ -505,22 +565,24 @@ pub fn deploy(
         }
     }
 
+    let layout = DEPLOY_ACCOUNTS;
+    let mut accounts = vec![AccountMeta::new_readonly(Pubkey::default(), false); layout.len];
+    accounts[layout.signer] = AccountMeta::new(signer, true);
+    accounts[layout.authority] = AccountMeta::new(authority, false);
+    accounts[layout.automation] = AccountMeta::new(automation_address, false);
+    accounts[layout.board] = AccountMeta::new(board_address, false);
+    accounts[layout.config] = AccountMeta::new(config_address, false);
+    accounts[layout.miner] = AccountMeta::new(miner_address, false);
+    accounts[layout.round] = AccountMeta::new(round_address, false);
+    accounts[layout.system_program] = AccountMeta::new_readonly(system_program::ID, false);
+    accounts[layout.ore_program] = AccountMeta::new_readonly(PROGRAM_ID, false);
+    // Entropy accounts.
+    accounts[layout.entropy_var] = AccountMeta::new(entropy_var_address, false);
+    accounts[layout.entropy_program] = AccountMeta::new_readonly(entropy_api::id(), false);
+
     Instruction {
         program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(signer, true),
-            AccountMeta::new(authority, false),
-            AccountMeta::new(automation_address, false),
-            AccountMeta::new(board_address, false),
-            AccountMeta::new(config_address, false),
-            AccountMeta::new(miner_address, false),
-            AccountMeta::new(round_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(PROGRAM_ID, false),
-            // Entropy accounts.
-            AccountMeta::new(entropy_var_address, false),
-            AccountMeta::new_readonly(entropy_api::id(), false),
-        ],
+        accounts,
         data: Deploy {
             amount: amount.to_le_bytes(),
             squares: mask.to_le_bytes(),
@@ -585,15 +647,16 @@ pub fn automate(
 ) -> Instruction {
   let automation_address = automation_pda(signer).0;
   let miner_address = miner_pda(signer).0;
+  let layout = AUTOMATE_ACCOUNTS;
+  let mut accounts = vec![AccountMeta::new_readonly(Pubkey::default(), false); layout.len];
+  accounts[layout.signer] = AccountMeta::new(signer, true);
+  accounts[layout.automation] = AccountMeta::new(automation_address, false);
+  accounts[layout.executor] = AccountMeta::new(executor, false);
+  accounts[layout.miner] = AccountMeta::new(miner_address, false);
+  accounts[layout.system_program] = AccountMeta::new_readonly(system_program::ID, false);
   Instruction {
       program_id: PROGRAM_ID,
-      accounts: vec![
-          AccountMeta::new(signer, true),
-          AccountMeta::new(automation_address, false),
-          AccountMeta::new(executor, false),
-          AccountMeta::new(miner_address, false),
-          AccountMeta::new_readonly(system_program::ID, false),
-      ],
+      accounts,
       data: Automate {
           amount: amount.to_le_bytes(),
           deposit: deposit.to_le_bytes(),
@@ -290,7 +290,7 @@ pub fn process_mm_deploy(
 /// 
 /// Formula to own P% of square: amount = P * T / (10000 - P)
 /// Max affordable percentage: P_max = 10000 * B / (Total + B)
-fn calculate_percentage_deployments(
+pub fn calculate_percentage_deployments(
     round: &Round,
     bankroll: u64,
     percentage: u64,      // In basis points (1000 = 10%)
@@ -485,7 +485,7 @@ fn calculate_manual_deployments(
 }
 
 /// Calculate deployments using EV waterfill strategy
-fn calculate_ev_deployments(
+pub fn calculate_ev_deployments(
     round: &Round,
     bankroll: u64,
     min_bet: u64,
@@ -585,7 +585,10 @@ fn snap_down_u64(amount: u64, min_bet: u64, tick: u64) -> u64 {
 /// EV numerator/denominator with fixed total_sum (S0) and base T_i.
 /// This matches the old profit_fraction but uses explicit S0 instead of
 /// recomputing the sum.
-fn profit_fraction_fixed_s(
+///
+/// Public so off-chain tooling can evaluate a stake with the exact on-chain math;
+/// the expected profit in lamports is `n / d`.
+pub fn profit_fraction_fixed_s(
     total_sum: u128,       // S0
     ti: u128,              // T_i
     x: u128,               // stake on this square
@@ -0,0 +1,107 @@
+//! EV calculator regression tests against round fixtures
+//!
+//! Each file in `tests/fixtures/ev` is a round's 25-square deployed vector at
+//! some point before its end, the EV parameters to plan with, and the squares
+//! and amounts `calculate_ev_deployments` chose when the fixture was blessed.
+//! Any change to what the calculator picks for these rounds fails here.
+//!
+//! New fixtures come from ore-stats' ClickHouse via `fixtures/ev/regenerate.sh`.
+//! After an intended change to the EV logic, re-bless the expected outputs with
+//! `EV_FIXTURES_BLESS=1 cargo test -p evore --test ev_fixtures` and review the diff.
+
+use std::path::{Path, PathBuf};
+
+use evore::{board::Board25, ore_api::Round, processor::process_mm_deploy::calculate_ev_deployments};
+use serde::{Deserialize, Serialize};
+use steel::Zeroable;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Expected {
+    per_square: Board25,
+    total: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Fixture {
+    description: String,
+    round_id: u64,
+    slots_left: u64,
+    deployed: Board25,
+    bankroll: u64,
+    min_bet: u64,
+    max_per_square: u64,
+    ore_value: u64,
+    /// None until blessed
+    expected: Option<Expected>,
+}
+
+impl Fixture {
+    /// What the calculator chooses for this round today
+    fn plan(&self) -> Expected {
+        let mut round = Round::zeroed();
+        round.id = self.round_id;
+        round.deployed = self.deployed.into();
+        round.total_deployed = self.deployed.total();
+
+        let (batches, total) = calculate_ev_deployments(&round, self.bankroll, self.min_bet, self.max_per_square, self.ore_value);
+        let mut per_square = Board25::default();
+        for batch in batches {
+            for (square, _) in batch.squares.iter().enumerate().filter(|(_, on)| **on) {
+                per_square[square] += batch.amount;
+            }
+        }
+        Expected { per_square, total }
+    }
+}
+
+fn fixture_paths() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/ev");
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+#[test]
+fn test_ev_fixtures() {
+    let bless = std::env::var_os("EV_FIXTURES_BLESS").is_some();
+    let paths = fixture_paths();
+    assert!(!paths.is_empty(), "no EV fixtures found");
+
+    let mut failures = Vec::new();
+    for path in &paths {
+        let name = path.file_name().unwrap().to_string_lossy();
+        let mut fixture: Fixture = serde_json::from_str(&std::fs::read_to_string(path).unwrap())
+            .unwrap_or_else(|e| panic!("{}: {}", name, e));
+        let actual = fixture.plan();
+
+        // Invariants that hold whatever the expected values are
+        assert_eq!(actual.per_square.total(), actual.total, "{}", name);
+        assert!(actual.total <= fixture.bankroll, "{}: spent more than the bankroll", name);
+        for (square, amount) in actual.per_square.enumerate() {
+            if amount > 0 {
+                assert!(amount >= fixture.min_bet, "{}: square {} below min_bet", name, square);
+                assert!(amount <= fixture.max_per_square, "{}: square {} above max_per_square", name, square);
+                assert!(fixture.deployed[square] > 0, "{}: bet on empty square {}", name, square);
+            }
+        }
+
+        if bless {
+            fixture.expected = Some(actual);
+            std::fs::write(path, serde_json::to_string_pretty(&fixture).unwrap() + "\n").unwrap();
+            continue;
+        }
+        match &fixture.expected {
+            Some(expected) if *expected == actual => {}
+            Some(expected) => failures.push(format!(
+                "{} ({}):\n  expected {:?} (total {})\n  actual   {:?} (total {})",
+                name, fixture.description, expected.per_square.0, expected.total, actual.per_square.0, actual.total,
+            )),
+            None => failures.push(format!("{}: not blessed yet (run with EV_FIXTURES_BLESS=1)", name)),
+        }
+    }
+    assert!(failures.is_empty(), "EV plan changed:\n{}", failures.join("\n"));
+}
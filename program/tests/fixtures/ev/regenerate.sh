@@ -0,0 +1,65 @@
+#!/bin/bash
+#
+# Write an EV fixture from a round stored in ore-stats' ClickHouse
+#
+# Usage: regenerate.sh <round_id> <slots_left> <name> [bankroll] [min_bet] [max_per_square] [ore_value]
+#
+# The deployed vector is the round's deployments up to <slots_left> slots
+# before its end. Uses the same CLICKHOUSE_* variables as ore-stats. The
+# fixture is written unblessed; fill in the expected plan with
+#   EV_FIXTURES_BLESS=1 cargo test -p evore --test ev_fixtures
+#
+set -e
+
+if [ $# -lt 3 ]; then
+    sed -n '5p' "$0" | sed 's/^# //'
+    exit 1
+fi
+
+ROUND_ID=$1
+SLOTS_LEFT=$2
+NAME=$3
+BANKROLL=${4:-2000000000}
+MIN_BET=${5:-10000}
+MAX_PER_SQUARE=${6:-100000000}
+ORE_VALUE=${7:-800000000}
+
+: "${CLICKHOUSE_URL:?CLICKHOUSE_URL not set}"
+DB=${CLICKHOUSE_DB:-ore_stats}
+PREFIX=${CLICKHOUSE_TABLE_PREFIX:-}
+
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+OUT="$SCRIPT_DIR/$NAME.json"
+
+QUERY="
+SELECT arrayStringConcat(arrayMap(i -> toString(sumIf(d.amount, d.square_id = i)), range(25)), ',')
+FROM ${DB}.${PREFIX}deployments d
+INNER JOIN (SELECT round_id, end_slot FROM ${DB}.${PREFIX}rounds FINAL WHERE round_id = ${ROUND_ID}) r
+    ON d.round_id = r.round_id
+WHERE d.round_id = ${ROUND_ID} AND d.deployed_slot + ${SLOTS_LEFT} <= r.end_slot
+FORMAT TSVRaw"
+
+DEPLOYED=$(curl -sS --fail-with-body \
+    --user "${CLICKHOUSE_USER:-default}:${CLICKHOUSE_PASSWORD:-}" \
+    --data-binary "$QUERY" "$CLICKHOUSE_URL")
+
+if [ -z "$DEPLOYED" ] || [ "$DEPLOYED" = "$(printf '0,%.0s' {1..24})0" ]; then
+    echo "No deployments found for round $ROUND_ID with $SLOTS_LEFT slots left"
+    exit 1
+fi
+
+cat > "$OUT" <<EOF
+{
+  "description": "Round $ROUND_ID with $SLOTS_LEFT slots left",
+  "round_id": $ROUND_ID,
+  "slots_left": $SLOTS_LEFT,
+  "deployed": [$DEPLOYED],
+  "bankroll": $BANKROLL,
+  "min_bet": $MIN_BET,
+  "max_per_square": $MAX_PER_SQUARE,
+  "ore_value": $ORE_VALUE,
+  "expected": null
+}
+EOF
+
+echo "✓ Wrote $OUT"
@@ -459,6 +459,97 @@ mod create_manager {
     }
 }
 
+mod transfer_manager {
+    use super::*;
+
+    /// Sends transfer_manager for `manager`, signed by `signer` (payer covers fees)
+    async fn send_transfer(
+        program_test: ProgramTest,
+        signer: &Keypair,
+        manager: Pubkey,
+        new_authority: Pubkey,
+        sign: bool,
+    ) -> (solana_program_test::ProgramTestContext, Result<(), solana_program_test::BanksClientError>) {
+        let context = program_test.start_with_context().await;
+        
+        let mut ix = evore::instruction::transfer_manager(signer.pubkey(), manager, new_authority);
+        let mut signers = vec![&context.payer];
+        if sign {
+            signers.push(signer);
+        } else {
+            ix.accounts[0].is_signer = false;
+        }
+        let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &signers,
+            blockhash,
+        );
+        let result = context.banks_client.process_transaction(tx).await;
+        (context, result)
+    }
+
+    async fn authority_of(context: &solana_program_test::ProgramTestContext, manager: Pubkey) -> Pubkey {
+        let account = context.banks_client.get_account(manager).await.unwrap().unwrap();
+        Manager::try_from_bytes(&account.data).unwrap().authority
+    }
+
+    #[tokio::test]
+    async fn test_success() {
+        let mut program_test = setup_programs();
+        
+        let authority = Keypair::new();
+        let manager = Keypair::new().pubkey();
+        let new_authority = Keypair::new().pubkey();
+        add_manager_account(&mut program_test, manager, authority.pubkey());
+        
+        let (context, result) = send_transfer(program_test, &authority, manager, new_authority, true).await;
+        result.expect("transfer_manager should succeed");
+        assert_eq!(authority_of(&context, manager).await, new_authority);
+    }
+
+    #[tokio::test]
+    async fn test_wrong_authority() {
+        let mut program_test = setup_programs();
+        
+        let authority = Keypair::new();
+        let attacker = Keypair::new();
+        let manager = Keypair::new().pubkey();
+        add_manager_account(&mut program_test, manager, authority.pubkey());
+        
+        // A signer that isn't the current authority can't take the manager
+        let (context, result) = send_transfer(program_test, &attacker, manager, attacker.pubkey(), true).await;
+        assert!(result.is_err(), "should fail when signer is not the authority");
+        assert_eq!(authority_of(&context, manager).await, authority.pubkey());
+    }
+
+    #[tokio::test]
+    async fn test_missing_signature() {
+        let mut program_test = setup_programs();
+        
+        let authority = Keypair::new();
+        let manager = Keypair::new().pubkey();
+        add_manager_account(&mut program_test, manager, authority.pubkey());
+        
+        // Naming the real authority without its signature isn't enough
+        let (context, result) = send_transfer(program_test, &authority, manager, Keypair::new().pubkey(), false).await;
+        assert!(result.is_err(), "should fail without the authority's signature");
+        assert_eq!(authority_of(&context, manager).await, authority.pubkey());
+    }
+
+    #[tokio::test]
+    async fn test_manager_not_initialized() {
+        let program_test = setup_programs();
+        
+        let authority = Keypair::new();
+        let manager = Keypair::new().pubkey();
+        
+        let (_, result) = send_transfer(program_test, &authority, manager, Keypair::new().pubkey(), true).await;
+        assert!(result.is_err(), "should fail for a manager that doesn't exist");
+    }
+}
+
 mod ev_deploy {
     use super::*;
 
@@ -3225,6 +3316,154 @@ mod mm_autodeploy_fee_tests {
     }
 }
 
+// ============================================================================
+// Executor (Deployer deploy_authority) Authorization Tests
+// ============================================================================
+
+/// Who may trigger an autodeploy and who may change the executor.
+///
+/// The Deployer's `deploy_authority` is the managed miner's executor: it alone
+/// can sign MMAutodeploy. The manager authority deploys through the
+/// authority-signed deploy instructions instead, and can rotate the executor
+/// with UpdateDeployer; nobody else can do either.
+mod executor_authorization {
+    use super::*;
+    use evore::error::EvoreError;
+    use solana_program_test::{BanksClientError, ProgramTestContext};
+
+    const AUTH_ID: u64 = 0;
+
+    struct ExecutorTest {
+        context: ProgramTestContext,
+        manager: Pubkey,
+        authority: Keypair,
+        executor: Keypair,
+        stranger: Keypair,
+    }
+
+    async fn setup() -> ExecutorTest {
+        let mut program_test = setup_programs();
+        
+        let authority = Keypair::new();
+        let executor = Keypair::new();
+        let stranger = Keypair::new();
+        let manager = Keypair::new().pubkey();
+        let (managed_miner_auth, _) = managed_miner_auth_pda(manager, AUTH_ID);
+        
+        add_manager_account(&mut program_test, manager, authority.pubkey());
+        add_deployer_account(&mut program_test, deployer_pda(manager).0, manager, executor.pubkey(), 0, 0, 0, 0);
+        
+        let current_slot = 1000;
+        let _board = setup_deploy_test_accounts(&mut program_test, TEST_ROUND_ID, current_slot, 100);
+        add_ore_miner_account(&mut program_test, managed_miner_auth, [0u64; 25], 0, 0, TEST_ROUND_ID - 1, TEST_ROUND_ID - 1);
+        add_autodeploy_balance(&mut program_test, managed_miner_auth, 10_000_000_000);
+        
+        let mut context = program_test.start_with_context().await;
+        let _ = context.warp_to_slot(current_slot + 3);
+        
+        // Fund fee collector and every signer
+        let payer = context.payer.pubkey();
+        let mut ixs = vec![system_instruction::transfer(&payer, &FEE_COLLECTOR, 1_000_000)];
+        for signer in [&authority, &executor, &stranger] {
+            ixs.push(system_instruction::transfer(&payer, &signer.pubkey(), 100_000_000));
+        }
+        let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(&ixs, Some(&payer), &[&context.payer], blockhash);
+        context.banks_client.process_transaction(tx).await.unwrap();
+        
+        ExecutorTest { context, manager, authority, executor, stranger }
+    }
+
+    async fn send(test: &mut ExecutorTest, ix: solana_program::instruction::Instruction, signer: &Keypair) -> Result<(), BanksClientError> {
+        let cu_limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let blockhash = test.context.banks_client.get_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(&[cu_limit_ix, ix], Some(&signer.pubkey()), &[signer], blockhash);
+        test.context.banks_client.process_transaction(tx).await
+    }
+
+    async fn autodeploy_as(test: &mut ExecutorTest, signer: &Keypair, squares_mask: u32) -> Result<(), BanksClientError> {
+        let ix = evore::instruction::mm_autodeploy(signer.pubkey(), test.manager, AUTH_ID, TEST_ROUND_ID, 100_000, squares_mask);
+        send(test, ix, signer).await
+    }
+
+    async fn set_executor_as(test: &mut ExecutorTest, signer: &Keypair, executor: Pubkey) -> Result<(), BanksClientError> {
+        let ix = evore::instruction::update_deployer(signer.pubkey(), test.manager, executor, 0, 0, 0, 0, 1_000_000_000);
+        send(test, ix, signer).await
+    }
+
+    /// EvoreError code the transaction failed with
+    fn evore_error(result: Result<(), BanksClientError>) -> Option<u32> {
+        use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+        match result {
+            Err(BanksClientError::TransactionError(TransactionError::InstructionError(_, InstructionError::Custom(code)))) => Some(code),
+            _ => None,
+        }
+    }
+
+    async fn executor_of(test: &mut ExecutorTest) -> Pubkey {
+        let account = test.context.banks_client.get_account(deployer_pda(test.manager).0).await.unwrap().unwrap();
+        Deployer::try_from_bytes(&account.data).unwrap().deploy_authority
+    }
+
+    #[tokio::test]
+    async fn test_executor_can_deploy() {
+        let mut test = setup().await;
+        let executor = test.executor.insecure_clone();
+        autodeploy_as(&mut test, &executor, 0b111).await.expect("executor should be able to autodeploy");
+    }
+
+    #[tokio::test]
+    async fn test_authority_cannot_autodeploy() {
+        let mut test = setup().await;
+        let authority = test.authority.insecure_clone();
+        let result = autodeploy_as(&mut test, &authority, 0b111).await;
+        assert_eq!(evore_error(result), Some(EvoreError::InvalidDeployAuthority as u32), "manager authority isn't the executor");
+    }
+
+    #[tokio::test]
+    async fn test_stranger_cannot_autodeploy() {
+        let mut test = setup().await;
+        let stranger = test.stranger.insecure_clone();
+        let result = autodeploy_as(&mut test, &stranger, 0b111).await;
+        assert_eq!(evore_error(result), Some(EvoreError::InvalidDeployAuthority as u32), "a random signer can't deploy");
+    }
+
+    #[tokio::test]
+    async fn test_authority_rotates_executor() {
+        let mut test = setup().await;
+        let authority = test.authority.insecure_clone();
+        let old_executor = test.executor.insecure_clone();
+        let new_executor = test.stranger.insecure_clone();
+        
+        set_executor_as(&mut test, &authority, new_executor.pubkey()).await.expect("authority can set the executor");
+        assert_eq!(executor_of(&mut test).await, new_executor.pubkey());
+        
+        let result = autodeploy_as(&mut test, &old_executor, 0b111).await;
+        assert_eq!(evore_error(result), Some(EvoreError::InvalidDeployAuthority as u32), "old executor is revoked");
+        autodeploy_as(&mut test, &new_executor, 0b111000).await.expect("new executor can deploy");
+    }
+
+    #[tokio::test]
+    async fn test_executor_hands_off() {
+        let mut test = setup().await;
+        let executor = test.executor.insecure_clone();
+        let next = Keypair::new().pubkey();
+        
+        set_executor_as(&mut test, &executor, next).await.expect("executor can hand off to another key");
+        assert_eq!(executor_of(&mut test).await, next);
+    }
+
+    #[tokio::test]
+    async fn test_stranger_cannot_set_executor() {
+        let mut test = setup().await;
+        let stranger = test.stranger.insecure_clone();
+        
+        let result = set_executor_as(&mut test, &stranger, stranger.pubkey()).await;
+        assert_eq!(evore_error(result), Some(EvoreError::NotAuthorized as u32), "a random signer can't take over as executor");
+        assert_eq!(executor_of(&mut test).await, test.executor.pubkey());
+    }
+}
+
 // ============================================================================
 // MMCreateMiner Tests
 // ============================================================================
@@ -3372,4 +3611,233 @@ mod test_mm_create_miner {
         let automation_account = ctx.banks_client.get_account(automation_address).await.unwrap();
         assert!(automation_account.is_none(), "Automation account should be closed");
     }
-}
\ No newline at end of file
+}
+mod ore_account_layouts {
+    use super::*;
+    use evore::ore_api::{
+        automation_pda, AutomateAccountLayout, DeployAccountLayout, AUTOMATE_ACCOUNTS,
+        DEPLOY_ACCOUNTS, ORE_IX_LAYOUT_VERSION,
+    };
+
+    /// Pins the Deploy/Automate account layouts to the current ORE program.
+    /// If this fails, the ORE layout changed: update the descriptors and bump ORE_IX_LAYOUT_VERSION.
+    #[test]
+    fn test_layouts_match_current_ore_program() {
+        assert_eq!(ORE_IX_LAYOUT_VERSION, 1);
+        assert_eq!(
+            DEPLOY_ACCOUNTS,
+            DeployAccountLayout {
+                signer: 0,
+                authority: 1,
+                automation: 2,
+                board: 3,
+                config: 4,
+                miner: 5,
+                round: 6,
+                system_program: 7,
+                ore_program: 8,
+                entropy_var: 9,
+                entropy_program: 10,
+                len: 11,
+            }
+        );
+        assert_eq!(
+            AUTOMATE_ACCOUNTS,
+            AutomateAccountLayout {
+                signer: 0,
+                automation: 1,
+                executor: 2,
+                miner: 3,
+                system_program: 4,
+                len: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn test_deploy_builder_uses_layout() {
+        let signer = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let ix = ore_api::deploy(signer, authority, 1_000, TEST_ROUND_ID, [true; 25]);
+        let layout = DEPLOY_ACCOUNTS;
+
+        assert_eq!(ix.accounts.len(), layout.len);
+        assert_eq!(ix.accounts[layout.signer].pubkey, signer);
+        assert!(ix.accounts[layout.signer].is_signer);
+        assert_eq!(ix.accounts[layout.authority].pubkey, authority);
+        assert_eq!(ix.accounts[layout.automation].pubkey, automation_pda(authority).0);
+        assert_eq!(ix.accounts[layout.board].pubkey, board_pda().0);
+        assert_eq!(ix.accounts[layout.config].pubkey, config_pda().0);
+        assert_eq!(ix.accounts[layout.miner].pubkey, miner_pda(authority).0);
+        assert_eq!(ix.accounts[layout.round].pubkey, round_pda(TEST_ROUND_ID).0);
+        assert_eq!(ix.accounts[layout.ore_program].pubkey, ore_api::id());
+        assert_eq!(ix.accounts[layout.entropy_var].pubkey, var_pda(board_pda().0, 0).0);
+        assert_eq!(ix.accounts[layout.entropy_program].pubkey, entropy_api::id());
+    }
+
+    #[test]
+    fn test_automate_builder_uses_layout() {
+        let signer = Pubkey::new_unique();
+        let executor = Pubkey::new_unique();
+        let ix = ore_api::automate(signer, 1_000, 0, executor, 0, 0, 0, false);
+        let layout = AUTOMATE_ACCOUNTS;
+
+        assert_eq!(ix.accounts.len(), layout.len);
+        assert_eq!(ix.accounts[layout.signer].pubkey, signer);
+        assert!(ix.accounts[layout.signer].is_signer);
+        assert_eq!(ix.accounts[layout.automation].pubkey, automation_pda(signer).0);
+        assert_eq!(ix.accounts[layout.executor].pubkey, executor);
+        assert_eq!(ix.accounts[layout.miner].pubkey, miner_pda(signer).0);
+    }
+}
+
+mod network_config {
+    use super::*;
+    use evore::network::{Network, NetworkConfig};
+    use evore::ore_api::{automation_pda, treasury_pda};
+
+    #[test]
+    fn test_mainnet_matches_compiled_addresses() {
+        let network = NetworkConfig::mainnet();
+        let authority = Pubkey::new_unique();
+        let manager = Pubkey::new_unique();
+
+        assert!(network.uses_compiled_program_ids());
+        assert_eq!(network.board_pda(), board_pda());
+        assert_eq!(network.round_pda(42), round_pda(42));
+        assert_eq!(network.miner_pda(authority), miner_pda(authority));
+        assert_eq!(network.automation_pda(authority), automation_pda(authority));
+        assert_eq!(network.treasury_pda(), treasury_pda());
+        assert_eq!(network.treasury_pda().0, TREASURY_ADDRESS);
+        assert_eq!(network.managed_miner_auth_pda(manager, 7), managed_miner_auth_pda(manager, 7));
+    }
+
+    #[test]
+    fn test_overrides_change_derivations() {
+        let ore_program_id = Pubkey::new_unique();
+        let evore_program_id = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let network = NetworkConfig::devnet().with_overrides(Some(ore_program_id), Some(evore_program_id), Some(mint));
+        let manager = Pubkey::new_unique();
+
+        assert_eq!(network.network, Network::Devnet);
+        assert_eq!(network.ore_mint, mint);
+        assert!(!network.uses_compiled_program_ids());
+        assert_ne!(network.board_pda(), board_pda());
+        assert_eq!(network.board_pda(), Pubkey::find_program_address(&[evore::ore_api::BOARD], &ore_program_id));
+        assert_ne!(network.managed_miner_auth_pda(manager, 1), managed_miner_auth_pda(manager, 1));
+    }
+
+    #[test]
+    fn test_check_ore_config() {
+        use evore::ore_api::Config;
+        use steel::Zeroable;
+
+        let network = NetworkConfig::mainnet();
+        let mut config = Config::zeroed();
+        config.fee_collector = Pubkey::new_unique();
+        config.var_address = evore::entropy_api::var_pda(board_pda().0, 0).0;
+        assert_eq!(network.entropy_var_address(), config.var_address);
+        assert_eq!(network.fee_collector(), evore::consts::FEE_COLLECTOR);
+        assert!(network.check_ore_config(&config).is_ok());
+
+        config.var_address = Pubkey::new_unique();
+        let err = network.check_ore_config(&config).unwrap_err();
+        assert!(err.contains("entropy var"));
+    }
+
+    #[test]
+    fn test_parse_network() {
+        assert_eq!("mainnet".parse::<Network>(), Ok(Network::Mainnet));
+        assert_eq!("Mainnet-Beta".parse::<Network>(), Ok(Network::Mainnet));
+        assert_eq!("devnet".parse::<Network>(), Ok(Network::Devnet));
+        assert!("testnet".parse::<Network>().is_err());
+    }
+}
+
+mod board25 {
+    use evore::board::{mask_indices, mask_to_squares, squares_to_mask, Board25, ALL_SQUARES_MASK, SQUARES};
+
+    #[test]
+    fn test_mask_round_trip() {
+        for mask in [0, 1, 1 << 24, 0b1010_1010, 0x0F0F0F, ALL_SQUARES_MASK] {
+            assert_eq!(Board25::from_mask(mask, 7).to_mask(), mask);
+            assert_eq!(squares_to_mask(&mask_to_squares(mask)), mask);
+        }
+        // Every single-square mask maps to that index
+        for i in 0..SQUARES {
+            assert_eq!(mask_indices(1 << i).collect::<Vec<_>>(), vec![i]);
+            assert_eq!(Board25::from_mask(1 << i, 3).squares().collect::<Vec<_>>(), vec![i]);
+        }
+    }
+
+    #[test]
+    fn test_mask_ignores_high_bits() {
+        let mask = 0b101 | (1 << 25) | (1 << 31);
+        assert_eq!(mask_indices(mask).collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(Board25::from_mask(mask, 1).to_mask(), 0b101);
+        assert_eq!(Board25::from_mask(u32::MAX, 1).to_mask(), ALL_SQUARES_MASK);
+        assert_eq!(mask_to_squares(u32::MAX), [true; SQUARES]);
+    }
+
+    #[test]
+    fn test_from_mask_amounts() {
+        let board = Board25::from_mask(0b1001, 500);
+        assert_eq!(board[0], 500);
+        assert_eq!(board[3], 500);
+        assert_eq!(board.total(), 1_000);
+        assert_eq!(board.count(), 2);
+
+        // A zero amount selects nothing
+        assert!(Board25::from_mask(ALL_SQUARES_MASK, 0).is_empty());
+        assert_eq!(Board25::from_mask(ALL_SQUARES_MASK, 0).to_mask(), 0);
+    }
+
+    #[test]
+    fn test_total_and_share() {
+        let mut board = Board25::default();
+        assert_eq!(board.total(), 0);
+        assert_eq!(board.share(0), 0.0);
+
+        board[1] = 1_000;
+        board[4] = 3_000;
+        assert_eq!(board.total(), 4_000);
+        assert_eq!(board.share(1), 0.25);
+        assert_eq!(board.share(4), 0.75);
+        assert_eq!(board.share(0), 0.0);
+        assert_eq!(board.share(SQUARES), 0.0);
+        assert!(((0..SQUARES).map(|i| board.share(i)).sum::<f64>() - 1.0).abs() < 1e-12);
+
+        // Totals saturate rather than overflow
+        assert_eq!(Board25::new([u64::MAX; SQUARES]).total(), u64::MAX);
+    }
+
+    #[test]
+    fn test_max_square() {
+        assert_eq!(Board25::default().max_square(), None);
+
+        let mut board = Board25::default();
+        board[7] = 10;
+        board[3] = 20;
+        board[12] = 20;
+        // Ties go to the lowest index
+        assert_eq!(board.max_square(), Some(3));
+        board[24] = 21;
+        assert_eq!(board.max_square(), Some(24));
+    }
+
+    #[test]
+    fn test_iteration() {
+        let amounts: [u64; SQUARES] = std::array::from_fn(|i| i as u64);
+        let board = Board25::from(amounts);
+
+        assert_eq!(board.iter().copied().collect::<Vec<_>>(), amounts.to_vec());
+        assert_eq!((&board).into_iter().sum::<u64>(), board.total());
+        assert_eq!(board.into_iter().collect::<Vec<_>>(), amounts.to_vec());
+        assert_eq!(board.enumerate().nth(5), Some((5, 5)));
+        // Square 0 is empty
+        assert_eq!(board.squares().next(), Some(1));
+        assert_eq!(board.count(), SQUARES - 1);
+        assert_eq!(<[u64; SQUARES]>::from(board), amounts);
+    }
+}